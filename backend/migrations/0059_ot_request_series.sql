@@ -0,0 +1,47 @@
+-- ═══════════════════════════════════════════════════════════════════════════════
+-- 0059_ot_request_series.sql — Recurring OT request series
+--
+-- A series is a recurrence rule (weekdays, week interval, date range, holiday
+-- exclusion) that materializes one ot_requests row per matching date. The
+-- occurrences stay ordinary OT requests so volunteering, assignment and
+-- reporting work unchanged; series_id links them back for bulk edit/cancel.
+-- ═══════════════════════════════════════════════════════════════════════════════
+
+CREATE TABLE ot_request_series (
+    id                UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    org_id            UUID NOT NULL REFERENCES organizations(id),
+    classification_id UUID NOT NULL REFERENCES classifications(id),
+    ot_reason_id      UUID REFERENCES ot_reasons(id),
+    start_time        TIME NOT NULL,
+    end_time          TIME NOT NULL,
+    hours             NUMERIC(5,2) NOT NULL,
+    location          TEXT,
+    is_fixed_coverage BOOLEAN NOT NULL DEFAULT FALSE,
+    notes             TEXT,
+    -- 0=Sunday..6=Saturday (matches PostgreSQL EXTRACT(DOW))
+    days_of_week      SMALLINT[] NOT NULL
+                      CHECK (cardinality(days_of_week) > 0 AND days_of_week <@ ARRAY[0,1,2,3,4,5,6]::SMALLINT[]),
+    -- Repeat every N weeks, counted from the week containing start_date
+    interval_weeks    SMALLINT NOT NULL DEFAULT 1 CHECK (interval_weeks BETWEEN 1 AND 52),
+    start_date        DATE NOT NULL,
+    end_date          DATE NOT NULL CHECK (end_date >= start_date),
+    -- Skip dates listed in holiday_calendar for the org
+    skip_holidays     BOOLEAN NOT NULL DEFAULT TRUE,
+    -- Dates removed from the series (individually cancelled occurrences);
+    -- never regenerated by later edits
+    excluded_dates    DATE[] NOT NULL DEFAULT '{}',
+    created_by        UUID NOT NULL REFERENCES users(id),
+    created_at        TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at        TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    cancelled_at      TIMESTAMPTZ,
+    cancelled_by      UUID REFERENCES users(id)
+);
+
+CREATE INDEX idx_ot_request_series_org ON ot_request_series(org_id, start_date DESC);
+
+ALTER TABLE ot_requests ADD COLUMN series_id UUID REFERENCES ot_request_series(id);
+
+-- One occurrence per series per date; cancelled occurrences don't block regeneration
+CREATE UNIQUE INDEX idx_ot_requests_series_date
+    ON ot_requests(series_id, date)
+    WHERE series_id IS NOT NULL AND status != 'cancelled';
@@ -0,0 +1,52 @@
+-- ═══════════════════════════════════════════════════════════════════════════════
+-- 0060_ot_gap_postings.sql — Auto-posted OT requests from coverage gaps
+--
+-- A background job scans upcoming coverage for slots below min_headcount and
+-- records each merged gap window here. Depending on the org's
+-- `ot_autopost_mode` setting the window is either left as a draft for a
+-- supervisor to publish/dismiss, or immediately published as an ot_requests row.
+-- Every window the job has acted on stays in this table so later runs never
+-- re-post a gap a supervisor already handled.
+-- ═══════════════════════════════════════════════════════════════════════════════
+
+CREATE TYPE ot_gap_posting_status AS ENUM ('draft', 'published', 'dismissed');
+
+CREATE TABLE ot_gap_postings (
+    id                UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    org_id            UUID NOT NULL REFERENCES organizations(id),
+    classification_id UUID NOT NULL REFERENCES classifications(id),
+    date              DATE NOT NULL,
+    start_time        TIME NOT NULL,
+    -- end_time <= start_time means the window crosses midnight (same as ot_requests)
+    end_time          TIME NOT NULL,
+    hours             NUMERIC(5,2) NOT NULL,
+    -- Largest per-slot shortage (min_headcount - actual) inside the window
+    shortage          SMALLINT NOT NULL CHECK (shortage > 0),
+    status            ot_gap_posting_status NOT NULL DEFAULT 'draft',
+    ot_request_id     UUID REFERENCES ot_requests(id),
+    created_at        TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    reviewed_by       UUID REFERENCES users(id),
+    reviewed_at       TIMESTAMPTZ,
+    CHECK ((status = 'published') = (ot_request_id IS NOT NULL))
+);
+
+CREATE UNIQUE INDEX idx_ot_gap_postings_window
+    ON ot_gap_postings(org_id, classification_id, date, start_time, end_time);
+
+CREATE INDEX idx_ot_gap_postings_org_status ON ot_gap_postings(org_id, status, date);
+
+-- ── Settings ─────────────────────────────────────────────────────────────────
+-- ot_autopost_mode: off | draft | publish. Off by default so orgs opt in.
+-- ot_autopost_days_ahead: how many days after today to scan.
+-- ot_autopost_min_hours / ot_autopost_max_hours: shorter gaps are left for
+-- supervisors to handle, longer ones are split into pieces no longer than the maximum.
+INSERT INTO org_settings (id, org_id, key, value, updated_at)
+SELECT gen_random_uuid(), o.id, s.key, s.value::JSONB, NOW()
+FROM organizations o,
+(VALUES
+    ('ot_autopost_mode',       '"off"'),
+    ('ot_autopost_days_ahead', '7'),
+    ('ot_autopost_min_hours',  '2'),
+    ('ot_autopost_max_hours',  '12')
+) AS s(key, value)
+ON CONFLICT (org_id, key) DO NOTHING;
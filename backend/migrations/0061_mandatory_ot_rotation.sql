@@ -0,0 +1,70 @@
+-- ═══════════════════════════════════════════════════════════════════════════════
+-- 0061_mandatory_ot_rotation.sql — Forced (mandatory) OT rotation
+--
+-- The rotation order per classification is derived from an event log: an
+-- employee's most recent counted turn moves them to the back, never-forced
+-- employees go first in inverse seniority order. Assigning mandatory OT records
+-- a 'forced' event automatically; supervisors record refusals and disciplinary
+-- overrides by hand. Voided events (e.g. the assignment was cancelled) no
+-- longer count.
+-- ═══════════════════════════════════════════════════════════════════════════════
+
+CREATE TYPE mandatory_ot_event_type AS ENUM ('forced', 'refused', 'override');
+
+CREATE TABLE mandatory_ot_events (
+    id                       UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    org_id                   UUID NOT NULL REFERENCES organizations(id),
+    classification_id        UUID NOT NULL REFERENCES classifications(id),
+    user_id                  UUID NOT NULL REFERENCES users(id),
+    event_type               mandatory_ot_event_type NOT NULL,
+    -- Whether the event used up the employee's turn (moves them to the back)
+    counts_as_turn           BOOLEAN NOT NULL,
+    ot_request_assignment_id UUID REFERENCES ot_request_assignments(id) ON DELETE SET NULL,
+    callout_event_id         UUID REFERENCES callout_events(id) ON DELETE SET NULL,
+    notes                    TEXT,
+    recorded_by              UUID NOT NULL REFERENCES users(id),
+    occurred_at              TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    voided_at                TIMESTAMPTZ,
+    voided_by                UUID REFERENCES users(id)
+);
+
+CREATE INDEX idx_mandatory_ot_events_rotation
+    ON mandatory_ot_events(org_id, classification_id, user_id)
+    WHERE voided_at IS NULL;
+
+CREATE INDEX idx_mandatory_ot_events_assignment
+    ON mandatory_ot_events(ot_request_assignment_id)
+    WHERE ot_request_assignment_id IS NOT NULL;
+
+-- ── Exemptions ───────────────────────────────────────────────────────────────
+-- Time-bound exemptions from forced OT (light duty, documented hardship, ...).
+-- classification_id NULL exempts the employee in every classification.
+
+CREATE TABLE mandatory_ot_exemptions (
+    id                UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    org_id            UUID NOT NULL REFERENCES organizations(id),
+    user_id           UUID NOT NULL REFERENCES users(id),
+    classification_id UUID REFERENCES classifications(id),
+    start_date        DATE NOT NULL,
+    end_date          DATE NOT NULL CHECK (end_date >= start_date),
+    reason            TEXT NOT NULL,
+    created_by        UUID NOT NULL REFERENCES users(id),
+    created_at        TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    revoked_at        TIMESTAMPTZ,
+    revoked_by        UUID REFERENCES users(id)
+);
+
+CREATE INDEX idx_mandatory_ot_exemptions_user
+    ON mandatory_ot_exemptions(org_id, user_id, start_date, end_date)
+    WHERE revoked_at IS NULL;
+
+-- Seed the log from existing mandatory OT request assignments so current
+-- rotations keep their order.
+INSERT INTO mandatory_ot_events
+    (org_id, classification_id, user_id, event_type, counts_as_turn,
+     ot_request_assignment_id, recorded_by, occurred_at)
+SELECT r.org_id, r.classification_id, a.user_id, 'forced', TRUE, a.id, a.assigned_by, a.assigned_at
+FROM ot_request_assignments a
+JOIN ot_requests r ON r.id = a.ot_request_id
+WHERE a.ot_type IN ('mandatory', 'mandatory_day_off')
+  AND a.cancelled_at IS NULL;
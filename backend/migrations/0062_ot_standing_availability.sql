@@ -0,0 +1,36 @@
+-- ═══════════════════════════════════════════════════════════════════════════════
+-- 0062_ot_standing_availability.sql — Standing OT availability sign-up
+--
+-- Employees pre-register when they are available for OT instead of waiting for
+-- a specific callout or OT request: a date range, optionally limited to some
+-- weekdays and a time window. A single specific date is a one-day range.
+-- Matching employees are surfaced first in the callout list / volunteers step
+-- and notified when a matching OT request is posted.
+-- ═══════════════════════════════════════════════════════════════════════════════
+
+CREATE TABLE ot_standing_availability (
+    id           UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    org_id       UUID NOT NULL REFERENCES organizations(id),
+    user_id      UUID NOT NULL REFERENCES users(id),
+    start_date   DATE NOT NULL,
+    end_date     DATE NOT NULL CHECK (end_date >= start_date),
+    -- 0=Sunday..6=Saturday (matches PostgreSQL EXTRACT(DOW)); empty = every day
+    days_of_week SMALLINT[] NOT NULL DEFAULT '{}'
+                 CHECK (days_of_week <@ ARRAY[0,1,2,3,4,5,6]::SMALLINT[]),
+    -- Both NULL = available all day; end_time <= start_time crosses midnight
+    start_time   TIME,
+    end_time     TIME,
+    notes        TEXT,
+    created_at   TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at   TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    cancelled_at TIMESTAMPTZ,
+    CHECK ((start_time IS NULL) = (end_time IS NULL))
+);
+
+CREATE INDEX idx_ot_standing_availability_dates
+    ON ot_standing_availability(org_id, start_date, end_date)
+    WHERE cancelled_at IS NULL;
+
+CREATE INDEX idx_ot_standing_availability_user
+    ON ot_standing_availability(user_id)
+    WHERE cancelled_at IS NULL;
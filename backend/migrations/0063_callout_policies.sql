@@ -0,0 +1,32 @@
+-- ═══════════════════════════════════════════════════════════════════════════════
+-- 0063_callout_policies.sql — Declarative callout step policy
+--
+-- A callout policy defines the step sequence of a callout, and for each step the
+-- sort keys and eligibility filters used to order the callout list. Policies are
+-- stored per org (bargaining_unit NULL) or per bargaining unit code; a
+-- classification's bargaining_unit decides which one applies. Orgs without a
+-- policy keep the built-in Article 15 behavior.
+-- ═══════════════════════════════════════════════════════════════════════════════
+
+-- Bargaining unit (code in bargaining_units) whose contract governs OT callouts
+-- for this classification. NULL = org default policy.
+ALTER TABLE classifications ADD COLUMN bargaining_unit TEXT;
+
+CREATE TABLE callout_policies (
+    id              UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    org_id          UUID NOT NULL REFERENCES organizations(id),
+    -- NULL = org-wide default; otherwise a bargaining_units.code
+    bargaining_unit TEXT,
+    name            TEXT NOT NULL,
+    -- [{ "step": "volunteers", "label": "...", "sort": [{"field": "ot_hours"}], "filters": {...} }, ...]
+    steps           JSONB NOT NULL CHECK (jsonb_typeof(steps) = 'array'),
+    created_by      UUID NOT NULL REFERENCES users(id),
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at      TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE UNIQUE INDEX idx_callout_policies_org_default
+    ON callout_policies(org_id) WHERE bargaining_unit IS NULL;
+
+CREATE UNIQUE INDEX idx_callout_policies_org_unit
+    ON callout_policies(org_id, bargaining_unit) WHERE bargaining_unit IS NOT NULL;
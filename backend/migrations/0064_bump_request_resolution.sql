@@ -0,0 +1,65 @@
+-- ═══════════════════════════════════════════════════════════════════════════════
+-- 0064_bump_request_resolution.sql — Bump request deadlines and audit trail
+--
+-- Pending bump requests now carry a resolution deadline (expires_at). A
+-- background sweep resolves requests past the deadline: they expire, or are
+-- auto-approved when the org's bump_auto_approve setting is on. Every outcome
+-- is written to bump_request_audit so each callout event has a bump history.
+-- ═══════════════════════════════════════════════════════════════════════════════
+
+ALTER TABLE bump_requests DROP CONSTRAINT bump_requests_status_check;
+ALTER TABLE bump_requests
+    ADD CONSTRAINT bump_requests_status_check
+    CHECK (status IN ('pending', 'approved', 'denied', 'expired'));
+
+-- When a pending request is resolved automatically (NULL on legacy rows)
+ALTER TABLE bump_requests ADD COLUMN expires_at TIMESTAMPTZ;
+-- True when the request was resolved by the deadline sweep rather than a supervisor
+ALTER TABLE bump_requests ADD COLUMN auto_resolved BOOLEAN NOT NULL DEFAULT false;
+
+-- Backfill pending requests: default review window (12h), capped at shift start
+UPDATE bump_requests br
+SET expires_at = LEAST(
+        br.created_at + INTERVAL '12 hours',
+        (ss.date + st.start_time) AT TIME ZONE o.timezone
+    )
+FROM callout_events ce
+JOIN scheduled_shifts ss ON ss.id = ce.scheduled_shift_id
+JOIN shift_templates st ON st.id = ss.shift_template_id
+JOIN organizations o ON o.id = ss.org_id
+WHERE ce.id = br.event_id AND br.status = 'pending';
+
+CREATE INDEX idx_bump_requests_pending_expiry
+    ON bump_requests (expires_at) WHERE status = 'pending';
+
+CREATE TYPE bump_audit_action AS ENUM (
+    'submitted',
+    'approved',
+    'denied',
+    'auto_approved',
+    'expired'
+);
+
+CREATE TABLE bump_request_audit (
+    id              UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    org_id          UUID NOT NULL REFERENCES organizations(id),
+    bump_request_id UUID NOT NULL REFERENCES bump_requests(id) ON DELETE CASCADE,
+    event_id        UUID NOT NULL REFERENCES callout_events(id) ON DELETE CASCADE,
+    action          bump_audit_action NOT NULL,
+    -- NULL = system (deadline sweep)
+    actor_id        UUID REFERENCES users(id),
+    notes           TEXT,
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_bump_request_audit_event ON bump_request_audit (event_id, created_at);
+
+-- Backfill history for existing requests
+INSERT INTO bump_request_audit (org_id, bump_request_id, event_id, action, actor_id, notes, created_at)
+SELECT org_id, id, event_id, 'submitted', requesting_user_id, NULL, created_at
+FROM bump_requests;
+
+INSERT INTO bump_request_audit (org_id, bump_request_id, event_id, action, actor_id, notes, created_at)
+SELECT org_id, id, event_id, status::bump_audit_action, reviewed_by, NULL, COALESCE(reviewed_at, created_at)
+FROM bump_requests
+WHERE status IN ('approved', 'denied');
@@ -0,0 +1,69 @@
+-- ═══════════════════════════════════════════════════════════════════════════════
+-- 0065_coverage_overrides.sql — Date-specific coverage overrides and holiday profiles
+--
+-- Coverage requirements are resolved in three layers:
+--   1. the coverage plan slots for the date's weekday,
+--   2. a holiday coverage profile when the date is in holiday_calendar (the
+--      holiday's own profile, else the org's default profile) — the profile
+--      replaces the plan rows for every classification it defines,
+--   3. sparse per-date overrides ("+2 COI 20:00–03:00 on 2026-12-31"), applied
+--      last. A window whose end_time <= start_time runs into the next day.
+-- ═══════════════════════════════════════════════════════════════════════════════
+
+CREATE TABLE holiday_coverage_profiles (
+    id          UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    org_id      UUID NOT NULL REFERENCES organizations(id),
+    name        TEXT NOT NULL,
+    description TEXT,
+    -- Applied to holidays that don't name a profile of their own
+    is_default  BOOLEAN NOT NULL DEFAULT false,
+    created_by  UUID NOT NULL REFERENCES users(id),
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (org_id, name)
+);
+
+CREATE UNIQUE INDEX idx_holiday_coverage_profiles_one_default
+    ON holiday_coverage_profiles (org_id) WHERE is_default = true;
+
+CREATE TABLE holiday_coverage_profile_slots (
+    id                UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    profile_id        UUID NOT NULL REFERENCES holiday_coverage_profiles(id) ON DELETE CASCADE,
+    classification_id UUID NOT NULL REFERENCES classifications(id),
+    slot_index        SMALLINT NOT NULL CHECK (slot_index BETWEEN 0 AND 47),
+    min_headcount     SMALLINT NOT NULL DEFAULT 0 CHECK (min_headcount >= 0),
+    target_headcount  SMALLINT NOT NULL DEFAULT 0 CHECK (target_headcount >= 0),
+    max_headcount     SMALLINT NOT NULL DEFAULT 0 CHECK (max_headcount >= 0),
+    CHECK (min_headcount <= target_headcount),
+    CHECK (target_headcount <= max_headcount),
+    UNIQUE (profile_id, classification_id, slot_index)
+);
+
+ALTER TABLE holiday_calendar
+    ADD COLUMN coverage_profile_id UUID
+        REFERENCES holiday_coverage_profiles(id) ON DELETE SET NULL;
+
+CREATE TYPE coverage_override_mode AS ENUM ('add', 'set');
+
+CREATE TABLE coverage_date_overrides (
+    id                UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    org_id            UUID NOT NULL REFERENCES organizations(id),
+    date              DATE NOT NULL,
+    classification_id UUID NOT NULL REFERENCES classifications(id),
+    start_time        TIME NOT NULL,
+    end_time          TIME NOT NULL,
+    -- 'add': headcounts are deltas on the lower layers; 'set': absolute values
+    mode              coverage_override_mode NOT NULL,
+    min_headcount     SMALLINT NOT NULL,
+    target_headcount  SMALLINT NOT NULL,
+    -- NULL: add mode moves max with target; set mode keeps max >= target
+    max_headcount     SMALLINT,
+    notes             TEXT,
+    created_by        UUID NOT NULL REFERENCES users(id),
+    created_at        TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (start_time <> end_time),
+    CHECK (mode = 'add' OR (min_headcount >= 0 AND min_headcount <= target_headcount))
+);
+
+CREATE INDEX idx_coverage_date_overrides_org_date
+    ON coverage_date_overrides (org_id, date);
@@ -0,0 +1,18 @@
+-- ═══════════════════════════════════════════════════════════════════════════════
+-- 0066_call_volume_history.sql — Historical call volume per half-hour slot
+--
+-- Call counts imported from CAD/ALI exports, one row per org/date/slot. Feeds
+-- the seasonal forecast (same weekday/slot averages over the lookback window,
+-- scaled on holiday_calendar dates) and the Erlang C staffing calculator.
+-- ═══════════════════════════════════════════════════════════════════════════════
+
+CREATE TABLE call_volume_history (
+    id          UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    org_id      UUID NOT NULL REFERENCES organizations(id),
+    date        DATE NOT NULL,
+    slot_index  SMALLINT NOT NULL CHECK (slot_index BETWEEN 0 AND 47),
+    calls       INTEGER NOT NULL CHECK (calls >= 0),
+    imported_by UUID NOT NULL REFERENCES users(id),
+    imported_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (org_id, date, slot_index)
+);
@@ -0,0 +1,103 @@
+-- ═══════════════════════════════════════════════════════════════════════════════
+-- 0067_coverage_gap_incidents.sql — Proactive coverage-gap monitoring
+--
+-- A background monitor re-evaluates coverage for the next few hours on a
+-- timer and whenever staffing changes. Each contiguous understaffed window is
+-- tracked as an incident: alerted in-app when it appears or worsens, escalated
+-- by SMS to on-duty supervisors if nobody acknowledges it, and resolved once
+-- the gap closes or passes.
+-- ═══════════════════════════════════════════════════════════════════════════════
+
+CREATE TYPE gap_incident_status AS ENUM ('open', 'resolved');
+
+CREATE TABLE coverage_gap_incidents (
+    id                UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    org_id            UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+    classification_id UUID NOT NULL REFERENCES classifications(id) ON DELETE CASCADE,
+    date              DATE NOT NULL,
+    start_time        TIME NOT NULL,
+    -- end_time <= start_time means the window ends on the following day
+    end_time          TIME NOT NULL,
+    shortage          INT NOT NULL CHECK (shortage > 0),
+    -- Shortage at the time of the last alert; a larger shortage re-alerts
+    alerted_shortage  INT NOT NULL DEFAULT 0,
+    -- 0 = none yet, 1 = in-app, 2 = escalated (SMS to on-duty supervisors)
+    alert_level       SMALLINT NOT NULL DEFAULT 0 CHECK (alert_level BETWEEN 0 AND 2),
+    status            gap_incident_status NOT NULL DEFAULT 'open',
+    detected_at       TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    last_alerted_at   TIMESTAMPTZ,
+    escalated_at      TIMESTAMPTZ,
+    acknowledged_by   UUID REFERENCES users(id) ON DELETE SET NULL,
+    acknowledged_at   TIMESTAMPTZ,
+    resolved_at       TIMESTAMPTZ,
+    updated_at        TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_gap_incidents_org_open ON coverage_gap_incidents(org_id, date)
+    WHERE status = 'open';
+CREATE INDEX idx_gap_incidents_org_date ON coverage_gap_incidents(org_id, date DESC);
+
+-- Every alert sent for an incident
+CREATE TABLE coverage_gap_alerts (
+    id              UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    incident_id     UUID NOT NULL REFERENCES coverage_gap_incidents(id) ON DELETE CASCADE,
+    alert_level     SMALLINT NOT NULL CHECK (alert_level BETWEEN 1 AND 2),
+    channel         TEXT NOT NULL CHECK (channel IN ('in_app', 'sms')),
+    shortage        INT NOT NULL,
+    recipient_count INT NOT NULL DEFAULT 0,
+    sent_at         TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_gap_alerts_incident ON coverage_gap_alerts(incident_id, sent_at);
+
+-- Per-org monitor bookkeeping. `dirty_at` is bumped by triggers whenever
+-- assignments, leave or OT change so the next tick rescans immediately.
+CREATE TABLE coverage_monitor_state (
+    org_id       UUID PRIMARY KEY REFERENCES organizations(id) ON DELETE CASCADE,
+    dirty_at     TIMESTAMPTZ,
+    last_scan_at TIMESTAMPTZ
+);
+
+CREATE FUNCTION mark_coverage_dirty() RETURNS TRIGGER AS $$
+DECLARE
+    rec RECORD;
+    target_org UUID;
+BEGIN
+    IF TG_OP = 'DELETE' THEN
+        rec := OLD;
+    ELSE
+        rec := NEW;
+    END IF;
+
+    IF TG_TABLE_NAME = 'assignments' THEN
+        SELECT org_id INTO target_org FROM scheduled_shifts WHERE id = rec.scheduled_shift_id;
+    ELSIF TG_TABLE_NAME = 'ot_request_assignments' THEN
+        SELECT org_id INTO target_org FROM ot_requests WHERE id = rec.ot_request_id;
+    ELSIF TG_TABLE_NAME = 'leave_requests' THEN
+        SELECT org_id INTO target_org FROM users WHERE id = rec.user_id;
+    ELSE
+        target_org := rec.org_id;
+    END IF;
+
+    IF target_org IS NOT NULL THEN
+        INSERT INTO coverage_monitor_state (org_id, dirty_at)
+        SELECT target_org, NOW()
+        WHERE EXISTS (SELECT 1 FROM organizations WHERE id = target_org)
+        ON CONFLICT (org_id) DO UPDATE SET dirty_at = EXCLUDED.dirty_at;
+    END IF;
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER trg_assignments_coverage_dirty
+    AFTER INSERT OR UPDATE OR DELETE ON assignments
+    FOR EACH ROW EXECUTE FUNCTION mark_coverage_dirty();
+CREATE TRIGGER trg_ot_request_assignments_coverage_dirty
+    AFTER INSERT OR UPDATE OR DELETE ON ot_request_assignments
+    FOR EACH ROW EXECUTE FUNCTION mark_coverage_dirty();
+CREATE TRIGGER trg_ot_requests_coverage_dirty
+    AFTER UPDATE OR DELETE ON ot_requests
+    FOR EACH ROW EXECUTE FUNCTION mark_coverage_dirty();
+CREATE TRIGGER trg_leave_requests_coverage_dirty
+    AFTER INSERT OR UPDATE OR DELETE ON leave_requests
+    FOR EACH ROW EXECUTE FUNCTION mark_coverage_dirty();
@@ -0,0 +1,21 @@
+-- ═══════════════════════════════════════════════════════════════════════════════
+-- 0068_qualification_coverage.sql — Qualification-based minimum coverage
+--
+-- Classification headcounts say nothing about who is on the floor: a slot can
+-- meet its DISP minimum with nobody EMD-certified. These rows add "at least N
+-- people holding qualification Q" per half-hour slot to a coverage plan. They
+-- count everyone working the slot regardless of classification, and come from
+-- the plan layer only (holiday profiles and date overrides are headcount-only).
+-- ═══════════════════════════════════════════════════════════════════════════════
+
+CREATE TABLE coverage_plan_qualification_slots (
+    id               UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    plan_id          UUID NOT NULL REFERENCES coverage_plans(id) ON DELETE CASCADE,
+    qualification_id UUID NOT NULL REFERENCES qualifications(id) ON DELETE CASCADE,
+    day_of_week      SMALLINT NOT NULL CHECK (day_of_week BETWEEN 0 AND 6),
+    slot_index       SMALLINT NOT NULL CHECK (slot_index BETWEEN 0 AND 47),
+    min_count        SMALLINT NOT NULL CHECK (min_count >= 0),
+    UNIQUE (plan_id, qualification_id, day_of_week, slot_index)
+);
+
+CREATE INDEX idx_cpqs_plan_dow ON coverage_plan_qualification_slots (plan_id, day_of_week);
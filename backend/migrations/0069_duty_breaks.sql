@@ -0,0 +1,30 @@
+-- ═══════════════════════════════════════════════════════════════════════════════
+-- 0069_duty_breaks.sql — Break and meal relief on the duty board
+--
+-- A break belongs to someone seated on the board: it sits inside one of their
+-- assigned 2-hour blocks, and the position they hold in that block is the one
+-- needing relief. The position is looked up from duty_assignments when read
+-- rather than stored, so re-running auto-fill or reseating someone moves the
+-- break with them. relief_user_id is who covers the console meanwhile.
+-- ═══════════════════════════════════════════════════════════════════════════════
+
+CREATE TYPE duty_break_kind AS ENUM ('break', 'meal');
+
+CREATE TABLE duty_breaks (
+    id             UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    org_id         UUID NOT NULL REFERENCES organizations(id),
+    user_id        UUID NOT NULL REFERENCES users(id),
+    date           DATE NOT NULL,
+    block_index    SMALLINT NOT NULL CHECK (block_index BETWEEN 0 AND 11),
+    start_time     TIME NOT NULL,
+    end_time       TIME NOT NULL,
+    kind           duty_break_kind NOT NULL DEFAULT 'break',
+    relief_user_id UUID REFERENCES users(id),
+    created_by     UUID NOT NULL REFERENCES users(id),
+    created_at     TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at     TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (end_time > start_time),
+    CHECK (relief_user_id IS DISTINCT FROM user_id)
+);
+
+CREATE INDEX idx_duty_breaks_date ON duty_breaks (org_id, date);
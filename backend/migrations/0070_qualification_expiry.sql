@@ -0,0 +1,25 @@
+-- ═══════════════════════════════════════════════════════════════════════════════
+-- 0070_qualification_expiry.sql — Qualification expiry and recertification
+--
+-- Certifications like EMD and CPR lapse. A qualification can now carry a
+-- renewal period, and each holder's grant an issue and expiry date (expiry
+-- defaults to issued_on + renewal_months). A grant counts through its
+-- expires_on date and is ignored afterwards by duty board eligibility and
+-- qualification coverage. Re-granting records a recertification.
+--
+-- reminded_days is the smallest reminder threshold (90/30/7 days) already sent
+-- for the current expiry; a recertification clears it.
+-- ═══════════════════════════════════════════════════════════════════════════════
+
+ALTER TABLE qualifications
+    ADD COLUMN renewal_months SMALLINT CHECK (renewal_months > 0);
+
+ALTER TABLE user_qualifications
+    ADD COLUMN issued_on     DATE,
+    ADD COLUMN expires_on    DATE,
+    ADD COLUMN reminded_days SMALLINT,
+    ADD CONSTRAINT chk_user_qualifications_dates
+        CHECK (issued_on IS NULL OR expires_on IS NULL OR expires_on >= issued_on);
+
+CREATE INDEX idx_user_qualifications_expiry
+    ON user_qualifications (expires_on) WHERE expires_on IS NOT NULL;
@@ -0,0 +1,23 @@
+-- ═══════════════════════════════════════════════════════════════════════════════
+-- 0071_bid_window_scheduler.sql — Background bid window scheduler
+--
+-- A background worker now advances expired shift and vacation bid windows on
+-- time instead of waiting for someone to open the bidding page, and tells each
+-- bidder when their window is about to open, is open, and is about to close.
+--
+-- notice_stage is the furthest notice already sent for the window:
+--   0 = none, 1 = opens soon, 2 = open now, 3 = closes soon.
+-- A bidder first seen partway through gets the current notice only.
+--
+-- Vacation windows open on time alone (no approval cascade), but an unsubmitted
+-- window that closes is recorded in auto_advanced_at like shift windows.
+-- ═══════════════════════════════════════════════════════════════════════════════
+
+ALTER TABLE bid_windows
+    ADD COLUMN notice_stage SMALLINT NOT NULL DEFAULT 0
+        CHECK (notice_stage BETWEEN 0 AND 3);
+
+ALTER TABLE vacation_bid_windows
+    ADD COLUMN notice_stage SMALLINT NOT NULL DEFAULT 0
+        CHECK (notice_stage BETWEEN 0 AND 3),
+    ADD COLUMN auto_advanced_at TIMESTAMPTZ;
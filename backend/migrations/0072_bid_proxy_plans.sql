@@ -0,0 +1,71 @@
+-- ═══════════════════════════════════════════════════════════════════════════════
+-- 0072_bid_proxy_plans.sql — Pre-filed bid preferences and proxy bidders
+--
+-- An employee who can't bid live (on vacation, on shift) files a plan for a
+-- shift or vacation bid period ahead of time: a ranked list of slots or date
+-- ranges, and/or a coworker allowed to submit on their behalf.
+--
+-- submit_on decides when the system bids from the ranked list: as soon as the
+-- window opens, or only once it has closed without a submission. Either
+-- way it submits the highest-ranked choices still available.
+--
+-- Every bid placed by the system or a proxy coworker is recorded in
+-- bid_proxy_actions. window ids there are references only (no FK) so the trail
+-- survives a window being regenerated.
+-- ═══════════════════════════════════════════════════════════════════════════════
+
+CREATE TABLE bid_proxy_plans (
+    id                     UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    org_id                 UUID NOT NULL REFERENCES organizations(id),
+    user_id                UUID NOT NULL REFERENCES users(id),
+    schedule_period_id     UUID REFERENCES schedule_periods(id) ON DELETE CASCADE,
+    vacation_bid_period_id UUID REFERENCES vacation_bid_periods(id) ON DELETE CASCADE,
+    submit_on              TEXT NOT NULL DEFAULT 'expiry' CHECK (submit_on IN ('open', 'expiry')),
+    proxy_user_id          UUID REFERENCES users(id),
+    created_at             TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at             TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK ((schedule_period_id IS NULL) <> (vacation_bid_period_id IS NULL)),
+    CHECK (proxy_user_id IS NULL OR proxy_user_id <> user_id),
+    UNIQUE (user_id, schedule_period_id),
+    UNIQUE (user_id, vacation_bid_period_id)
+);
+CREATE INDEX idx_bid_proxy_plans_shift ON bid_proxy_plans (schedule_period_id) WHERE schedule_period_id IS NOT NULL;
+CREATE INDEX idx_bid_proxy_plans_vacation ON bid_proxy_plans (vacation_bid_period_id) WHERE vacation_bid_period_id IS NOT NULL;
+
+-- Ranked shift slots for a shift bid plan
+CREATE TABLE bid_proxy_slot_choices (
+    plan_id         UUID NOT NULL REFERENCES bid_proxy_plans(id) ON DELETE CASCADE,
+    slot_id         UUID NOT NULL REFERENCES shift_slots(id),
+    preference_rank INT NOT NULL,
+    PRIMARY KEY (plan_id, slot_id),
+    UNIQUE (plan_id, preference_rank)
+);
+
+-- Ranked date ranges for a vacation bid plan
+CREATE TABLE bid_proxy_date_choices (
+    plan_id         UUID NOT NULL REFERENCES bid_proxy_plans(id) ON DELETE CASCADE,
+    start_date      DATE NOT NULL,
+    end_date        DATE NOT NULL,
+    preference_rank INT NOT NULL,
+    PRIMARY KEY (plan_id, preference_rank),
+    CHECK (end_date >= start_date)
+);
+
+CREATE TABLE bid_proxy_actions (
+    id                     UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    org_id                 UUID NOT NULL REFERENCES organizations(id),
+    user_id                UUID NOT NULL REFERENCES users(id),
+    -- NULL when the system bid from the pre-filed list
+    actor_id               UUID REFERENCES users(id),
+    schedule_period_id     UUID REFERENCES schedule_periods(id) ON DELETE CASCADE,
+    vacation_bid_period_id UUID REFERENCES vacation_bid_periods(id) ON DELETE CASCADE,
+    window_id              UUID NOT NULL,
+    trigger                TEXT NOT NULL CHECK (trigger IN ('window_open', 'window_expired', 'proxy')),
+    -- The choices submitted, in rank order, and the pre-filed ones passed over
+    submitted              JSONB NOT NULL DEFAULT '[]',
+    unavailable            JSONB NOT NULL DEFAULT '[]',
+    created_at             TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK ((schedule_period_id IS NULL) <> (vacation_bid_period_id IS NULL))
+);
+CREATE INDEX idx_bid_proxy_actions_shift ON bid_proxy_actions (schedule_period_id, created_at);
+CREATE INDEX idx_bid_proxy_actions_vacation ON bid_proxy_actions (vacation_bid_period_id, created_at);
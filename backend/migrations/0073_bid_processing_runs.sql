@@ -0,0 +1,30 @@
+-- ═══════════════════════════════════════════════════════════════════════════════
+-- 0073_bid_processing_runs.sql — Auditable bid processing and rollback
+--
+-- Every time a shift or vacation bid period is processed or rolled back, one row
+-- is written to bid_processing_runs with the award table (or what was undone).
+--
+-- vacation_bids.leave_request_id links an awarded pick to the approved leave
+-- request created for it, so a rollback can delete exactly those requests and
+-- refund their hours.
+-- ═══════════════════════════════════════════════════════════════════════════════
+
+ALTER TABLE vacation_bids
+    ADD COLUMN leave_request_id UUID REFERENCES leave_requests(id) ON DELETE SET NULL;
+
+CREATE TABLE bid_processing_runs (
+    id                     UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    org_id                 UUID NOT NULL REFERENCES organizations(id),
+    schedule_period_id     UUID REFERENCES schedule_periods(id) ON DELETE CASCADE,
+    vacation_bid_period_id UUID REFERENCES vacation_bid_periods(id) ON DELETE CASCADE,
+    action                 TEXT NOT NULL CHECK (action IN ('process', 'rollback')),
+    run_by                 UUID NOT NULL REFERENCES users(id),
+    awards_count           INT NOT NULL DEFAULT 0,
+    reason                 TEXT,
+    -- The award table for 'process'; the awards undone for 'rollback'
+    detail                 JSONB NOT NULL DEFAULT '{}',
+    created_at             TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK ((schedule_period_id IS NULL) <> (vacation_bid_period_id IS NULL))
+);
+CREATE INDEX idx_bid_processing_runs_shift ON bid_processing_runs (schedule_period_id, created_at);
+CREATE INDEX idx_bid_processing_runs_vacation ON bid_processing_runs (vacation_bid_period_id, created_at);
@@ -0,0 +1,38 @@
+-- ═══════════════════════════════════════════════════════════════════════════════
+-- 0074_job_share_pairs.sql — Job-share pairs bid together
+--
+-- job_share_pairs links two employees sharing one position. In shift bidding the
+-- pair gets a single joint bid window at the senior partner's rank
+-- (bid_windows.partner_user_id). Each ranked slot in the joint submission says
+-- which of the slot's days the partner works (bid_submissions.partner_days_of_week);
+-- the window owner works the rest.
+--
+-- An award then creates two complementary slot_assignments for the slot, each
+-- limited to that partner's days (slot_assignments.days_of_week). NULL days mean
+-- the whole slot, which stays unique per period as before.
+-- ═══════════════════════════════════════════════════════════════════════════════
+
+CREATE TABLE job_share_pairs (
+    id          UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    org_id      UUID NOT NULL REFERENCES organizations(id),
+    user_a_id   UUID NOT NULL REFERENCES users(id),
+    user_b_id   UUID NOT NULL REFERENCES users(id),
+    created_by  UUID REFERENCES users(id),
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (user_a_id <> user_b_id)
+);
+CREATE INDEX idx_job_share_pairs_org ON job_share_pairs (org_id);
+CREATE UNIQUE INDEX idx_job_share_pairs_a ON job_share_pairs (user_a_id);
+CREATE UNIQUE INDEX idx_job_share_pairs_b ON job_share_pairs (user_b_id);
+
+ALTER TABLE bid_windows ADD COLUMN partner_user_id UUID REFERENCES users(id);
+
+ALTER TABLE bid_submissions ADD COLUMN partner_days_of_week INT[];
+
+ALTER TABLE slot_assignments ADD COLUMN days_of_week INT[];
+
+DROP INDEX idx_slot_assignments_slot_period;
+CREATE UNIQUE INDEX idx_slot_assignments_slot_period
+    ON slot_assignments (slot_id, period_id) WHERE days_of_week IS NULL;
+CREATE UNIQUE INDEX idx_slot_assignments_split
+    ON slot_assignments (slot_id, period_id, user_id) WHERE days_of_week IS NOT NULL;
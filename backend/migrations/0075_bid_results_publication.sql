@@ -0,0 +1,12 @@
+-- ═══════════════════════════════════════════════════════════════════════════════
+-- 0075_bid_results_publication.sql — Posted shift bid results
+--
+-- Once a period's bids are processed, an admin publishes the results sheet so
+-- every employee (and their steward) can check that seniority order was
+-- honored. Until then only schedule managers see it. Rolling the bids back
+-- withdraws the sheet.
+-- ═══════════════════════════════════════════════════════════════════════════════
+
+ALTER TABLE schedule_periods
+    ADD COLUMN bid_results_published_at TIMESTAMPTZ,
+    ADD COLUMN bid_results_published_by UUID REFERENCES users(id);
@@ -0,0 +1,35 @@
+-- ═══════════════════════════════════════════════════════════════════════════════
+-- 0076_shift_bid_rounds.sql — Multi-round shift bidding
+--
+-- A shift bid period may be split into ordered rounds, e.g. "supervisors pick
+-- teams", then "COII by seniority", then "everyone else takes the leftovers".
+-- Each round limits who bids (classifications and/or bargaining units; NULL
+-- means anyone not already placed in an earlier round), which slots they may
+-- bid on (NULL means any), how long each window lasts and how many slots it
+-- awards (flex slots per classification, and optionally in total).
+--
+-- Opening bidding opens round 1; each following round opens once every window
+-- of the one before has been submitted or passed over. Windows keep counting
+-- seniority_rank across rounds, so awards honor round order then seniority.
+-- A period without rounds bids in a single pass as before.
+-- ═══════════════════════════════════════════════════════════════════════════════
+
+CREATE TABLE shift_bid_rounds (
+    id                    UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    period_id             UUID NOT NULL REFERENCES schedule_periods(id) ON DELETE CASCADE,
+    round_number          INT NOT NULL CHECK (round_number >= 1),
+    name                  TEXT NOT NULL,
+    classification_ids    UUID[],
+    bargaining_units      TEXT[],
+    slot_ids              UUID[],
+    window_duration_hours INT NOT NULL CHECK (window_duration_hours >= 1),
+    flex_cap              INT NOT NULL DEFAULT 2 CHECK (flex_cap >= 0),
+    max_awards            INT CHECK (max_awards >= 1),
+    opened_at             TIMESTAMPTZ,
+    closed_at             TIMESTAMPTZ,
+    created_at            TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (period_id, round_number)
+);
+
+ALTER TABLE bid_windows ADD COLUMN round_id UUID REFERENCES shift_bid_rounds(id) ON DELETE CASCADE;
+CREATE INDEX idx_bid_windows_round ON bid_windows (round_id);
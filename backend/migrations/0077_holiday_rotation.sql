@@ -0,0 +1,35 @@
+-- Holiday rotation in vacation bidding. Major holidays in holiday_calendar are
+-- grouped (e.g. every year's Christmas Day), and whoever received a group's
+-- holiday within the last `lockout_years` can't bid it again in vacation
+-- rounds up to `restricted_through_round`.
+
+CREATE TABLE holiday_groups (
+    id                       UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    org_id                   UUID NOT NULL REFERENCES organizations(id),
+    name                     TEXT NOT NULL,
+    lockout_years            INT NOT NULL DEFAULT 1 CHECK (lockout_years >= 1),
+    restricted_through_round INT NOT NULL DEFAULT 1 CHECK (restricted_through_round IN (1, 2)),
+    created_at               TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (org_id, name)
+);
+
+CREATE TABLE holiday_group_members (
+    group_id   UUID NOT NULL REFERENCES holiday_groups(id) ON DELETE CASCADE,
+    holiday_id UUID NOT NULL REFERENCES holiday_calendar(id) ON DELETE CASCADE,
+    PRIMARY KEY (group_id, holiday_id)
+);
+CREATE INDEX idx_holiday_group_members_holiday ON holiday_group_members (holiday_id);
+
+-- Who received (was awarded time off on) each holiday. Vacation bid processing
+-- records these; admins can also enter history from before the system.
+CREATE TABLE holiday_awards (
+    id              UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    holiday_id      UUID NOT NULL REFERENCES holiday_calendar(id) ON DELETE CASCADE,
+    user_id         UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    vacation_bid_id UUID REFERENCES vacation_bids(id) ON DELETE CASCADE,
+    recorded_by     UUID REFERENCES users(id),
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (holiday_id, user_id)
+);
+CREATE INDEX idx_holiday_awards_user ON holiday_awards (user_id);
+CREATE INDEX idx_holiday_awards_bid ON holiday_awards (vacation_bid_id);
@@ -0,0 +1,79 @@
+-- Published seniority lists. seniority_records is edited in place, so each
+-- published list is an immutable snapshot of the order at the time (overall,
+-- one bargaining unit, or one classification), with the org's tie-break rules
+-- applied. Employees dispute their placement on a list; an upheld dispute
+-- corrects seniority_records. Every change to a seniority date is audited,
+-- and a bid period can pin the list its windows were ordered by.
+
+CREATE TYPE seniority_list_type AS ENUM ('overall', 'bargaining_unit', 'classification');
+
+CREATE TABLE seniority_lists (
+    id                UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    org_id            UUID NOT NULL REFERENCES organizations(id),
+    list_type         seniority_list_type NOT NULL,
+    bargaining_unit   TEXT,
+    classification_id UUID REFERENCES classifications(id),
+    title             TEXT NOT NULL,
+    -- Tie-break rules applied, in order, when seniority dates are equal
+    tie_break_rules   TEXT[] NOT NULL,
+    published_by      UUID NOT NULL REFERENCES users(id),
+    published_at      TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK ((list_type = 'bargaining_unit') = (bargaining_unit IS NOT NULL)),
+    CHECK ((list_type = 'classification') = (classification_id IS NOT NULL))
+);
+CREATE INDEX idx_seniority_lists_org ON seniority_lists (org_id, published_at DESC);
+
+CREATE TABLE seniority_list_entries (
+    list_id        UUID NOT NULL REFERENCES seniority_lists(id) ON DELETE CASCADE,
+    rank           INT NOT NULL CHECK (rank >= 1),
+    user_id        UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    employee_id    TEXT,
+    first_name     TEXT NOT NULL,
+    last_name      TEXT NOT NULL,
+    seniority_date DATE,
+    -- The rule that placed this entry below the one before when their dates tied
+    tie_broken_by  TEXT,
+    PRIMARY KEY (list_id, rank),
+    UNIQUE (list_id, user_id)
+);
+
+CREATE TYPE seniority_dispute_status AS ENUM ('open', 'upheld', 'denied', 'withdrawn');
+
+CREATE TABLE seniority_disputes (
+    id               UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    org_id           UUID NOT NULL REFERENCES organizations(id),
+    list_id          UUID NOT NULL REFERENCES seniority_lists(id) ON DELETE CASCADE,
+    user_id          UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    reason           TEXT NOT NULL,
+    requested_date   DATE,
+    status           seniority_dispute_status NOT NULL DEFAULT 'open',
+    -- Upheld disputes: the date seniority_records was corrected to
+    corrected_date   DATE,
+    resolution_notes TEXT,
+    resolved_by      UUID REFERENCES users(id),
+    resolved_at      TIMESTAMPTZ,
+    created_at       TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+CREATE UNIQUE INDEX idx_seniority_disputes_one_open
+    ON seniority_disputes (list_id, user_id) WHERE status = 'open';
+CREATE INDEX idx_seniority_disputes_org ON seniority_disputes (org_id, status);
+
+CREATE TABLE seniority_audit (
+    id             UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    org_id         UUID NOT NULL REFERENCES organizations(id),
+    user_id        UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    seniority_type seniority_list_type NOT NULL,
+    old_date       DATE,
+    new_date       DATE,
+    source         TEXT NOT NULL CHECK (source IN ('profile', 'status_change', 'dispute')),
+    dispute_id     UUID REFERENCES seniority_disputes(id) ON DELETE SET NULL,
+    -- NULL = system
+    changed_by     UUID REFERENCES users(id),
+    created_at     TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+CREATE INDEX idx_seniority_audit_user ON seniority_audit (user_id, created_at);
+
+ALTER TABLE schedule_periods
+    ADD COLUMN seniority_list_id UUID REFERENCES seniority_lists(id);
+ALTER TABLE vacation_bid_periods
+    ADD COLUMN seniority_list_id UUID REFERENCES seniority_lists(id);
@@ -0,0 +1,51 @@
+-- Employment status history. Seniority used to be paused by stamping
+-- seniority_records.accrual_pause_started_at and, on return to active, shifting
+-- the stored dates by the days away, so the absence itself was lost and a wrong
+-- shift couldn't be undone. Unpaid absences are now kept as effective-dated
+-- intervals, and each seniority date is computed from the date it counts from
+-- (the *_base_date columns; overall falls back to the hire date) plus the days
+-- of every completed, non-exception absence since then.
+
+CREATE TABLE employment_status_history (
+    id           UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    org_id       UUID NOT NULL REFERENCES organizations(id),
+    user_id      UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    status       employee_status_enum NOT NULL CHECK (status IN ('unpaid_loa', 'lwop', 'layoff')),
+    starts_on    DATE NOT NULL,
+    -- The return-to-active date; NULL while the absence is ongoing
+    ends_on      DATE,
+    -- OJI/L&I, pregnancy/maternity, military: seniority keeps accruing
+    is_exception BOOLEAN NOT NULL DEFAULT false,
+    notes        TEXT,
+    -- NULL = carried over from the pause in progress when this table was added
+    recorded_by  UUID REFERENCES users(id),
+    created_at   TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (ends_on IS NULL OR ends_on > starts_on)
+);
+CREATE INDEX idx_employment_status_history_user ON employment_status_history (user_id, starts_on);
+CREATE UNIQUE INDEX idx_employment_status_history_ongoing
+    ON employment_status_history (user_id) WHERE ends_on IS NULL;
+
+ALTER TABLE seniority_records
+    ADD COLUMN overall_base_date         DATE,
+    ADD COLUMN bargaining_unit_base_date DATE,
+    ADD COLUMN classification_base_date  DATE;
+
+-- Pauses already applied have no intervals to recompute from, so they're
+-- folded into the base dates (accrual_paused_days_total keeps their total).
+UPDATE seniority_records SET
+    overall_base_date         = overall_seniority_date,
+    bargaining_unit_base_date = bargaining_unit_seniority_date,
+    classification_base_date  = classification_seniority_date;
+
+-- A pause in progress becomes an ongoing absence
+INSERT INTO employment_status_history (org_id, user_id, status, starts_on)
+SELECT sr.org_id, sr.user_id, u.employee_status, sr.accrual_pause_started_at
+FROM seniority_records sr
+JOIN users u ON u.id = sr.user_id
+WHERE sr.accrual_pause_started_at IS NOT NULL
+  AND u.employee_status IN ('unpaid_loa', 'lwop', 'layoff');
+
+ALTER TABLE seniority_audit DROP CONSTRAINT seniority_audit_source_check;
+ALTER TABLE seniority_audit ADD CONSTRAINT seniority_audit_source_check
+    CHECK (source IN ('profile', 'status_change', 'dispute', 'recompute'));
@@ -0,0 +1,12 @@
+-- Editing a recurring OT series from a date forward used to rewrite the one
+-- series row, so its rule no longer described the occurrences before the
+-- cut-off. The edit now ends the series the day before and continues it as a
+-- successor series holding the new rule; previous_series_id links the two.
+
+ALTER TABLE ot_request_series
+    ADD COLUMN previous_series_id UUID REFERENCES ot_request_series(id);
+
+-- A series is continued at most once
+CREATE UNIQUE INDEX idx_ot_request_series_previous
+    ON ot_request_series(previous_series_id)
+    WHERE previous_series_id IS NOT NULL;
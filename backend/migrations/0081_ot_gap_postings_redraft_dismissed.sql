@@ -0,0 +1,11 @@
+-- A dismissed auto-post used to block its window for good: the scan skipped
+-- every recorded posting, and the window index refused a new row. A dismissal
+-- still holds the window, but once the gap's shortage grows past the one it
+-- was dismissed at a later run drafts it again, keeping the dismissed row as
+-- the review record.
+
+DROP INDEX idx_ot_gap_postings_window;
+
+CREATE UNIQUE INDEX idx_ot_gap_postings_window
+    ON ot_gap_postings(org_id, classification_id, date, start_time, end_time)
+    WHERE status <> 'dismissed';
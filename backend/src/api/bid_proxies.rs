@@ -0,0 +1,435 @@
+//! Pre-filed bid plans and the proxy bid audit trail.
+//!
+//! An employee files at most one plan per shift or vacation bid period: ranked
+//! choices the system bids from (see `services::bid_proxy`), a coworker allowed
+//! to submit on their behalf, or both.
+
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{
+    api::{
+        bidding::validate_preferences,
+        helpers::{ensure_rows_affected, json_ok},
+        vacation_bids::{fetch_vacation_hours_config, validate_picks},
+    },
+    auth::AuthUser,
+    error::{AppError, Result},
+    models::{
+        bid_proxy::{
+            BidProxyAction, ProxySubmitOn, ShiftProxyPlan, ShiftProxyPlanRequest, VacationProxyPlan,
+            VacationProxyPlanRequest,
+        },
+        bidding::BidPreference,
+        vacation_bid::VacationPick,
+    },
+    org_guard,
+    services::holiday_rotation,
+};
+
+/// A proxy must be someone else in the org.
+async fn validate_proxy(pool: &PgPool, auth: &AuthUser, proxy_user_id: Option<Uuid>) -> Result<()> {
+    let Some(proxy) = proxy_user_id else {
+        return Ok(());
+    };
+    if proxy == auth.id {
+        return Err(AppError::BadRequest("You cannot be your own proxy".into()));
+    }
+    org_guard::verify_user(pool, proxy, auth.org_id).await
+}
+
+async fn load_shift_plan(pool: &PgPool, user_id: Uuid, period_id: Uuid) -> Result<Option<ShiftProxyPlan>> {
+    let Some(plan) = sqlx::query!(
+        r#"
+        SELECT p.id, p.submit_on, p.proxy_user_id, p.updated_at,
+               pu.first_name AS "proxy_first_name?", pu.last_name AS "proxy_last_name?"
+        FROM bid_proxy_plans p
+        LEFT JOIN users pu ON pu.id = p.proxy_user_id
+        WHERE p.user_id = $1 AND p.schedule_period_id = $2
+        "#,
+        user_id,
+        period_id,
+    )
+    .fetch_optional(pool)
+    .await?
+    else {
+        return Ok(None);
+    };
+
+    let preferences = sqlx::query_as!(
+        BidPreference,
+        r#"
+        SELECT slot_id, preference_rank, NULL::INT[] AS partner_days
+        FROM bid_proxy_slot_choices
+        WHERE plan_id = $1
+        ORDER BY preference_rank
+        "#,
+        plan.id,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(Some(ShiftProxyPlan {
+        id: plan.id,
+        period_id,
+        user_id,
+        submit_on: ProxySubmitOn::from_db(&plan.submit_on),
+        proxy_user_id: plan.proxy_user_id,
+        proxy_first_name: plan.proxy_first_name,
+        proxy_last_name: plan.proxy_last_name,
+        preferences,
+        updated_at: plan.updated_at,
+    }))
+}
+
+async fn load_vacation_plan(pool: &PgPool, user_id: Uuid, period_id: Uuid) -> Result<Option<VacationProxyPlan>> {
+    let Some(plan) = sqlx::query!(
+        r#"
+        SELECT p.id, p.submit_on, p.proxy_user_id, p.updated_at,
+               pu.first_name AS "proxy_first_name?", pu.last_name AS "proxy_last_name?"
+        FROM bid_proxy_plans p
+        LEFT JOIN users pu ON pu.id = p.proxy_user_id
+        WHERE p.user_id = $1 AND p.vacation_bid_period_id = $2
+        "#,
+        user_id,
+        period_id,
+    )
+    .fetch_optional(pool)
+    .await?
+    else {
+        return Ok(None);
+    };
+
+    let picks = sqlx::query_as!(
+        VacationPick,
+        r#"
+        SELECT start_date, end_date, preference_rank
+        FROM bid_proxy_date_choices
+        WHERE plan_id = $1
+        ORDER BY preference_rank
+        "#,
+        plan.id,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(Some(VacationProxyPlan {
+        id: plan.id,
+        vacation_bid_period_id: period_id,
+        user_id,
+        submit_on: ProxySubmitOn::from_db(&plan.submit_on),
+        proxy_user_id: plan.proxy_user_id,
+        proxy_first_name: plan.proxy_first_name,
+        proxy_last_name: plan.proxy_last_name,
+        picks,
+        updated_at: plan.updated_at,
+    }))
+}
+
+/// GET /api/schedule/periods/:id/proxy-plan
+pub async fn get_shift_plan(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(period_id): Path<Uuid>,
+) -> Result<Json<ShiftProxyPlan>> {
+    org_guard::verify_period(&pool, period_id, auth.org_id).await?;
+
+    load_shift_plan(&pool, auth.id, period_id)
+        .await?
+        .map(Json)
+        .ok_or_else(|| AppError::NotFound("No proxy plan for this period".into()))
+}
+
+/// PUT /api/schedule/periods/:id/proxy-plan
+/// File or replace the caller's plan for a shift bid that hasn't been processed.
+pub async fn put_shift_plan(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(period_id): Path<Uuid>,
+    Json(req): Json<ShiftProxyPlanRequest>,
+) -> Result<Json<ShiftProxyPlan>> {
+    org_guard::verify_period(&pool, period_id, auth.org_id).await?;
+
+    let status = sqlx::query_scalar!(
+        r#"SELECT status::TEXT AS "status!" FROM schedule_periods WHERE id = $1"#,
+        period_id,
+    )
+    .fetch_one(&pool)
+    .await?;
+    if status != "draft" && status != "open" {
+        return Err(AppError::BadRequest(
+            "Proxy plans can only be filed before bids are processed".into(),
+        ));
+    }
+
+    if req.preferences.is_empty() && req.proxy_user_id.is_none() {
+        return Err(AppError::BadRequest(
+            "A proxy plan needs ranked preferences, a proxy bidder, or both".into(),
+        ));
+    }
+    if !req.preferences.is_empty() {
+        // A pair's joint bid splits each slot's days, which a plan doesn't carry
+        let job_shared = sqlx::query_scalar!(
+            r#"SELECT EXISTS(SELECT 1 FROM job_share_pairs WHERE user_a_id = $1 OR user_b_id = $1) AS "exists!""#,
+            auth.id,
+        )
+        .fetch_one(&pool)
+        .await?;
+        if job_shared {
+            return Err(AppError::BadRequest(
+                "Job-share partners bid jointly; name a proxy bidder instead of ranking slots".into(),
+            ));
+        }
+        validate_preferences(&pool, auth.org_id, &req.preferences, false).await?;
+    }
+    validate_proxy(&pool, &auth, req.proxy_user_id).await?;
+
+    let mut tx = pool.begin().await?;
+
+    let plan_id = sqlx::query_scalar!(
+        r#"
+        INSERT INTO bid_proxy_plans (org_id, user_id, schedule_period_id, submit_on, proxy_user_id)
+        VALUES ($1, $2, $3, $4, $5)
+        ON CONFLICT (user_id, schedule_period_id) DO UPDATE
+        SET submit_on = EXCLUDED.submit_on,
+            proxy_user_id = EXCLUDED.proxy_user_id,
+            updated_at = NOW()
+        RETURNING id
+        "#,
+        auth.org_id,
+        auth.id,
+        period_id,
+        req.submit_on.as_str(),
+        req.proxy_user_id,
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+
+    sqlx::query!("DELETE FROM bid_proxy_slot_choices WHERE plan_id = $1", plan_id)
+        .execute(&mut *tx)
+        .await?;
+    for pref in &req.preferences {
+        sqlx::query!(
+            "INSERT INTO bid_proxy_slot_choices (plan_id, slot_id, preference_rank) VALUES ($1, $2, $3)",
+            plan_id,
+            pref.slot_id,
+            pref.preference_rank,
+        )
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    tx.commit().await?;
+
+    load_shift_plan(&pool, auth.id, period_id)
+        .await?
+        .map(Json)
+        .ok_or_else(|| AppError::NotFound("No proxy plan for this period".into()))
+}
+
+/// DELETE /api/schedule/periods/:id/proxy-plan
+pub async fn delete_shift_plan(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(period_id): Path<Uuid>,
+) -> Result<Json<serde_json::Value>> {
+    let rows = sqlx::query!(
+        "DELETE FROM bid_proxy_plans WHERE user_id = $1 AND schedule_period_id = $2 AND org_id = $3",
+        auth.id,
+        period_id,
+        auth.org_id,
+    )
+    .execute(&pool)
+    .await?
+    .rows_affected();
+    ensure_rows_affected(rows, "Proxy plan")?;
+
+    Ok(json_ok())
+}
+
+/// GET /api/vacation-bids/periods/:id/proxy-plan
+pub async fn get_vacation_plan(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(period_id): Path<Uuid>,
+) -> Result<Json<VacationProxyPlan>> {
+    load_vacation_plan(&pool, auth.id, period_id)
+        .await?
+        .map(Json)
+        .ok_or_else(|| AppError::NotFound("No proxy plan for this period".into()))
+}
+
+/// PUT /api/vacation-bids/periods/:id/proxy-plan
+/// File or replace the caller's plan; picks follow the same rules as a live bid.
+pub async fn put_vacation_plan(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(period_id): Path<Uuid>,
+    Json(req): Json<VacationProxyPlanRequest>,
+) -> Result<Json<VacationProxyPlan>> {
+    let period = sqlx::query!(
+        r#"
+        SELECT status, year, round, allowance_hours, min_block_hours
+        FROM vacation_bid_periods
+        WHERE id = $1 AND org_id = $2
+        "#,
+        period_id,
+        auth.org_id,
+    )
+    .fetch_optional(&pool)
+    .await?
+    .ok_or_else(|| AppError::NotFound("Vacation bid period not found".into()))?;
+
+    if period.status != "draft" && period.status != "open" {
+        return Err(AppError::BadRequest(
+            "Proxy plans can only be filed before bids are processed".into(),
+        ));
+    }
+
+    if req.picks.is_empty() && req.proxy_user_id.is_none() {
+        return Err(AppError::BadRequest(
+            "A proxy plan needs ranked picks, a proxy bidder, or both".into(),
+        ));
+    }
+    if !req.picks.is_empty() {
+        let hours_config = fetch_vacation_hours_config(&pool, auth.org_id, &[auth.id]).await?;
+        let mut conn = pool.acquire().await?;
+        let lockouts =
+            holiday_rotation::lockouts(&mut conn, auth.org_id, &[auth.id], period.year, period.round)
+                .await?
+                .remove(&auth.id)
+                .unwrap_or_default();
+        validate_picks(
+            &req.picks,
+            auth.id,
+            period.round,
+            period.allowance_hours,
+            period.min_block_hours,
+            &hours_config,
+            &lockouts,
+        )?;
+    }
+    validate_proxy(&pool, &auth, req.proxy_user_id).await?;
+
+    let mut tx = pool.begin().await?;
+
+    let plan_id = sqlx::query_scalar!(
+        r#"
+        INSERT INTO bid_proxy_plans (org_id, user_id, vacation_bid_period_id, submit_on, proxy_user_id)
+        VALUES ($1, $2, $3, $4, $5)
+        ON CONFLICT (user_id, vacation_bid_period_id) DO UPDATE
+        SET submit_on = EXCLUDED.submit_on,
+            proxy_user_id = EXCLUDED.proxy_user_id,
+            updated_at = NOW()
+        RETURNING id
+        "#,
+        auth.org_id,
+        auth.id,
+        period_id,
+        req.submit_on.as_str(),
+        req.proxy_user_id,
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+
+    sqlx::query!("DELETE FROM bid_proxy_date_choices WHERE plan_id = $1", plan_id)
+        .execute(&mut *tx)
+        .await?;
+    for pick in &req.picks {
+        sqlx::query!(
+            r#"
+            INSERT INTO bid_proxy_date_choices (plan_id, start_date, end_date, preference_rank)
+            VALUES ($1, $2, $3, $4)
+            "#,
+            plan_id,
+            pick.start_date,
+            pick.end_date,
+            pick.preference_rank,
+        )
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    tx.commit().await?;
+
+    load_vacation_plan(&pool, auth.id, period_id)
+        .await?
+        .map(Json)
+        .ok_or_else(|| AppError::NotFound("No proxy plan for this period".into()))
+}
+
+/// DELETE /api/vacation-bids/periods/:id/proxy-plan
+pub async fn delete_vacation_plan(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(period_id): Path<Uuid>,
+) -> Result<Json<serde_json::Value>> {
+    let rows = sqlx::query!(
+        "DELETE FROM bid_proxy_plans WHERE user_id = $1 AND vacation_bid_period_id = $2 AND org_id = $3",
+        auth.id,
+        period_id,
+        auth.org_id,
+    )
+    .execute(&pool)
+    .await?
+    .rows_affected();
+    ensure_rows_affected(rows, "Proxy plan")?;
+
+    Ok(json_ok())
+}
+
+/// Proxy actions for a period, newest first. Managers see everyone's; others
+/// see only bids placed for them or by them.
+async fn list_actions(
+    pool: &PgPool,
+    auth: &AuthUser,
+    schedule_period_id: Option<Uuid>,
+    vacation_bid_period_id: Option<Uuid>,
+) -> Result<Vec<BidProxyAction>> {
+    let rows = sqlx::query_as!(
+        BidProxyAction,
+        r#"
+        SELECT a.id, a.user_id, u.first_name, u.last_name,
+               a.actor_id, au.first_name AS "actor_first_name?", au.last_name AS "actor_last_name?",
+               a.window_id, a.trigger, a.submitted, a.unavailable, a.created_at
+        FROM bid_proxy_actions a
+        JOIN users u ON u.id = a.user_id
+        LEFT JOIN users au ON au.id = a.actor_id
+        WHERE a.org_id = $1
+          AND (a.schedule_period_id = $2 OR a.vacation_bid_period_id = $3)
+          AND ($4 OR a.user_id = $5 OR a.actor_id = $5)
+        ORDER BY a.created_at DESC
+        "#,
+        auth.org_id,
+        schedule_period_id,
+        vacation_bid_period_id,
+        auth.role.can_manage_schedule(),
+        auth.id,
+    )
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+/// GET /api/schedule/periods/:id/proxy-actions
+pub async fn list_shift_actions(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(period_id): Path<Uuid>,
+) -> Result<Json<Vec<BidProxyAction>>> {
+    org_guard::verify_period(&pool, period_id, auth.org_id).await?;
+    Ok(Json(list_actions(&pool, &auth, Some(period_id), None).await?))
+}
+
+/// GET /api/vacation-bids/periods/:id/proxy-actions
+pub async fn list_vacation_actions(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(period_id): Path<Uuid>,
+) -> Result<Json<Vec<BidProxyAction>>> {
+    Ok(Json(list_actions(&pool, &auth, None, Some(period_id)).await?))
+}
@@ -1,5 +1,8 @@
+use std::collections::{HashMap, HashSet};
+
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
+    response::Response,
     Json,
 };
 use sqlx::PgPool;
@@ -7,20 +10,30 @@ use time::OffsetDateTime;
 use uuid::Uuid;
 
 use crate::{
-    api::helpers::validate_sequential_ranks,
+    api::helpers::{export_response, validate_sequential_ranks},
     api::notifications::{create_notification, CreateNotificationParams},
     auth::AuthUser,
     error::{AppError, Result},
+    models::bid_processing::{BidProcessingRun, RollbackBidsRequest, ShiftAwardTable},
     models::bidding::{
-        AvailableSlot, BidPeriodStatus, BidSubmissionView, BidWindow, BidWindowDetail,
-        OpenBiddingRequest, SubmitBidRequest,
+        AvailableSlot, BidPeriodStatus, BidPreference, BidStatusSummary, BidSubmissionView, BidWindow,
+        BidWindowDetail, OpenBiddingRequest, PutShiftBidRoundsRequest, ShiftBidRound,
+        SubmitBidRequest,
     },
+    models::report::ExportQuery,
     org_guard,
-    services::bidding::advance_expired_windows,
+    services::{
+        bid_processing::{list_runs, plan_shift_awards, record_run, FLEX_CAP},
+        bid_proxy::{is_proxy_for, notify_submitted, BidPeriod, ProxyAction, ProxySubmission, Trigger},
+        bid_results,
+        bid_rounds::{advance_rounds, create_windows, open_due_rounds, Eligibility},
+        bidding::{advance_expired_windows, shift_bid_status},
+    },
 };
 
 /// POST /api/schedule/periods/:id/open-bidding
-/// Admin only. Generates bid_windows ordered by seniority.
+/// Admin only. Generates bid_windows ordered by seniority. A period bidding in
+/// rounds gets round 1's windows; later rounds open as each one finishes.
 pub async fn open_bidding(
     State(pool): State<PgPool>,
     auth: AuthUser,
@@ -49,39 +62,6 @@ pub async fn open_bidding(
 
     let duration = time::Duration::hours(req.window_duration_hours);
 
-    // M3: Fetch bargaining_unit from schedule period for BU-specific filtering
-    let period_bu = sqlx::query_scalar!(
-        "SELECT bargaining_unit AS \"bargaining_unit?\" FROM schedule_periods WHERE id = $1",
-        period_id
-    )
-    .fetch_one(&pool)
-    .await?;
-
-    // M1+M3: Get active users ordered by seniority, job_share last, optional BU filter
-    let users = sqlx::query!(
-        r#"
-        SELECT u.id, u.first_name, u.last_name,
-               (u.employee_type::TEXT = 'job_share') AS "is_job_share!"
-        FROM users u
-        LEFT JOIN seniority_records sr ON sr.user_id = u.id
-        WHERE u.org_id = $1
-          AND u.is_active = true
-          AND ($2::TEXT IS NULL OR u.bargaining_unit = $2)
-        ORDER BY
-          (u.employee_type::TEXT = 'job_share') ASC,
-          sr.overall_seniority_date ASC NULLS LAST,
-          u.last_name, u.first_name
-        "#,
-        auth.org_id,
-        period_bu as Option<String>,
-    )
-    .fetch_all(&pool)
-    .await?;
-
-    if users.is_empty() {
-        return Err(AppError::BadRequest("No active users found".into()));
-    }
-
     let mut tx = pool.begin().await?;
 
     // Check period is in draft status inside transaction with FOR UPDATE
@@ -104,50 +84,27 @@ pub async fn open_bidding(
         .execute(&mut *tx)
         .await?;
 
-    let mut windows = Vec::new();
-
-    for (i, user) in users.iter().enumerate() {
-        let rank = (i + 1) as i32;
-        let opens = start_at + duration * i as u32;
-        let closes = opens + duration;
-
-        // M2: Only unlock rank 1 immediately; others wait for approval cascade
-        let unlocked = if i == 0 { Some(opens) } else { None };
-
-        let row = sqlx::query_as!(
-            BidWindow,
-            r#"
-            INSERT INTO bid_windows (id, period_id, user_id, seniority_rank, opens_at, closes_at, unlocked_at)
-            VALUES ($1, $2, $3, $4, $5, $6, $7)
-            RETURNING id, period_id, user_id,
-                      $8::text AS "first_name!",
-                      $9::text AS "last_name!",
-                      seniority_rank, opens_at, closes_at, submitted_at,
-                      unlocked_at, approved_at,
-                      approved_by AS "approved_by?",
-                      $10::bool AS "is_job_share!",
-                      auto_advanced_at
-            "#,
-            Uuid::new_v4(),
-            period_id,
-            user.id,
-            rank,
-            opens,
-            closes,
-            unlocked,
-            user.first_name,
-            user.last_name,
-            user.is_job_share,
-        )
-        .fetch_one(&mut *tx)
-        .await?;
+    // A period bidding in rounds opens round 1 (and any empty rounds after it);
+    // otherwise every eligible employee gets a window now.
+    let has_rounds = sqlx::query_scalar!(
+        r#"SELECT EXISTS (SELECT 1 FROM shift_bid_rounds WHERE period_id = $1) AS "exists!""#,
+        period_id
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+    let windows = if has_rounds {
+        open_due_rounds(&mut tx, auth.org_id, period_id, start_at).await?
+    } else {
+        create_windows(&mut tx, auth.org_id, period_id, &Eligibility::default(), 1, start_at, duration).await?
+    };
 
-        windows.push(row);
+    if windows.is_empty() {
+        return Err(AppError::BadRequest("No active users found".into()));
     }
 
     // Calculate overall bid period open/close times
     let bid_opens_at = start_at;
-    let bid_closes_at = start_at + duration * users.len() as u32;
+    let bid_closes_at = windows.iter().map(|w| w.closes_at).max().unwrap_or(start_at);
 
     // Update period status to 'open'
     sqlx::query!(
@@ -168,6 +125,176 @@ pub async fn open_bidding(
     Ok(Json(windows))
 }
 
+/// GET /api/schedule/periods/:id/bid-rounds
+/// The period's bid rounds in order; empty when it bids in a single pass.
+pub async fn list_bid_rounds(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(period_id): Path<Uuid>,
+) -> Result<Json<Vec<ShiftBidRound>>> {
+    if !auth.role.can_manage_schedule() {
+        return Err(AppError::Forbidden);
+    }
+
+    org_guard::verify_period(&pool, period_id, auth.org_id).await?;
+
+    let rounds = sqlx::query_as!(
+        ShiftBidRound,
+        r#"
+        SELECT r.id, r.period_id, r.round_number, r.name, r.classification_ids,
+               r.bargaining_units, r.slot_ids, r.window_duration_hours, r.flex_cap,
+               r.max_awards,
+               (SELECT COUNT(*) FROM bid_windows bw WHERE bw.round_id = r.id) AS "window_count!",
+               r.opened_at, r.closed_at
+        FROM shift_bid_rounds r
+        WHERE r.period_id = $1
+        ORDER BY r.round_number
+        "#,
+        period_id
+    )
+    .fetch_all(&pool)
+    .await?;
+
+    Ok(Json(rounds))
+}
+
+/// PUT /api/schedule/periods/:id/bid-rounds
+/// Admin only. Replace the rounds of a period that hasn't opened bidding yet.
+pub async fn put_bid_rounds(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(period_id): Path<Uuid>,
+    Json(req): Json<PutShiftBidRoundsRequest>,
+) -> Result<Json<Vec<ShiftBidRound>>> {
+    if !auth.role.is_admin() {
+        return Err(AppError::Forbidden);
+    }
+
+    org_guard::verify_period(&pool, period_id, auth.org_id).await?;
+
+    let mut classification_ids = Vec::new();
+    let mut slot_ids = Vec::new();
+    for round in &req.rounds {
+        if round.name.trim().is_empty() {
+            return Err(AppError::BadRequest("Each round needs a name".into()));
+        }
+        if round.window_duration_hours < 1 {
+            return Err(AppError::BadRequest(
+                "window_duration_hours must be at least 1".into(),
+            ));
+        }
+        if round.flex_cap.is_some_and(|cap| cap < 0) || round.max_awards.is_some_and(|max| max < 1) {
+            return Err(AppError::BadRequest(
+                "flex_cap can't be negative and max_awards must be at least 1".into(),
+            ));
+        }
+        if round.classification_ids.as_ref().is_some_and(|ids| ids.is_empty())
+            || round.bargaining_units.as_ref().is_some_and(|units| units.is_empty())
+            || round.slot_ids.as_ref().is_some_and(|ids| ids.is_empty())
+        {
+            return Err(AppError::BadRequest(
+                "Leave a round restriction out rather than sending an empty list".into(),
+            ));
+        }
+        classification_ids.extend(round.classification_ids.iter().flatten().copied());
+        slot_ids.extend(round.slot_ids.iter().flatten().copied());
+    }
+    classification_ids.sort();
+    classification_ids.dedup();
+    slot_ids.sort();
+    slot_ids.dedup();
+
+    let found = sqlx::query_scalar!(
+        r#"SELECT COUNT(*) AS "count!" FROM classifications WHERE id = ANY($1) AND org_id = $2"#,
+        &classification_ids,
+        auth.org_id,
+    )
+    .fetch_one(&pool)
+    .await?;
+    if found != classification_ids.len() as i64 {
+        return Err(AppError::NotFound("Classification not found".into()));
+    }
+    let found = sqlx::query_scalar!(
+        r#"
+        SELECT COUNT(*) AS "count!"
+        FROM shift_slots ss
+        JOIN teams t ON t.id = ss.team_id
+        WHERE ss.id = ANY($1) AND t.org_id = $2
+        "#,
+        &slot_ids,
+        auth.org_id,
+    )
+    .fetch_one(&pool)
+    .await?;
+    if found != slot_ids.len() as i64 {
+        return Err(AppError::NotFound("Shift slot not found".into()));
+    }
+
+    let mut tx = pool.begin().await?;
+
+    let current_status = sqlx::query_scalar!(
+        r#"SELECT status AS "status: BidPeriodStatus" FROM schedule_periods WHERE id = $1 FOR UPDATE"#,
+        period_id
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+    if current_status != BidPeriodStatus::Draft {
+        return Err(AppError::BadRequest(
+            "Bid rounds can only be changed before bidding opens".into(),
+        ));
+    }
+
+    sqlx::query!("DELETE FROM shift_bid_rounds WHERE period_id = $1", period_id)
+        .execute(&mut *tx)
+        .await?;
+    let mut rounds = Vec::with_capacity(req.rounds.len());
+    for (i, round) in req.rounds.into_iter().enumerate() {
+        let row = sqlx::query_as!(
+            ShiftBidRound,
+            r#"
+            INSERT INTO shift_bid_rounds
+                (period_id, round_number, name, classification_ids, bargaining_units, slot_ids,
+                 window_duration_hours, flex_cap, max_awards)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            RETURNING id, period_id, round_number, name, classification_ids, bargaining_units,
+                      slot_ids, window_duration_hours, flex_cap, max_awards,
+                      0::BIGINT AS "window_count!", opened_at, closed_at
+            "#,
+            period_id,
+            i as i32 + 1,
+            round.name.trim(),
+            round.classification_ids.as_deref(),
+            round.bargaining_units.as_deref(),
+            round.slot_ids.as_deref(),
+            round.window_duration_hours,
+            round.flex_cap.unwrap_or(FLEX_CAP),
+            round.max_awards,
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+        rounds.push(row);
+    }
+
+    tx.commit().await?;
+
+    Ok(Json(rounds))
+}
+
+/// GET /api/schedule/periods/:id/bid-status
+/// Live progress of the bid for the bid administrator.
+pub async fn bid_status(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(period_id): Path<Uuid>,
+) -> Result<Json<BidStatusSummary>> {
+    if !auth.role.can_manage_schedule() {
+        return Err(AppError::Forbidden);
+    }
+    org_guard::verify_period(&pool, period_id, auth.org_id).await?;
+
+    Ok(Json(shift_bid_status(&pool, period_id).await?))
+}
+
 /// GET /api/schedule/periods/:id/bid-windows
 /// List windows for a period. Admin/supervisor sees all, employee sees only theirs.
 pub async fn list_bid_windows(
@@ -189,10 +316,15 @@ pub async fn list_bid_windows(
                    bw.seniority_rank, bw.opens_at, bw.closes_at, bw.submitted_at,
                    bw.unlocked_at, bw.approved_at,
                    bw.approved_by AS "approved_by?",
-                   (u.employee_type::TEXT = 'job_share') AS "is_job_share!",
-                   bw.auto_advanced_at
+                   (u.employee_type::TEXT = 'job_share' OR bw.partner_user_id IS NOT NULL) AS "is_job_share!",
+                   bw.auto_advanced_at,
+                   bw.partner_user_id,
+                   pu.first_name AS "partner_first_name?",
+                   pu.last_name AS "partner_last_name?",
+                   bw.round_id
             FROM bid_windows bw
             JOIN users u ON u.id = bw.user_id
+            LEFT JOIN users pu ON pu.id = bw.partner_user_id
             WHERE bw.period_id = $1
             ORDER BY bw.seniority_rank
             "#,
@@ -209,11 +341,16 @@ pub async fn list_bid_windows(
                    bw.seniority_rank, bw.opens_at, bw.closes_at, bw.submitted_at,
                    bw.unlocked_at, bw.approved_at,
                    bw.approved_by AS "approved_by?",
-                   (u.employee_type::TEXT = 'job_share') AS "is_job_share!",
-                   bw.auto_advanced_at
+                   (u.employee_type::TEXT = 'job_share' OR bw.partner_user_id IS NOT NULL) AS "is_job_share!",
+                   bw.auto_advanced_at,
+                   bw.partner_user_id,
+                   pu.first_name AS "partner_first_name?",
+                   pu.last_name AS "partner_last_name?",
+                   bw.round_id
             FROM bid_windows bw
             JOIN users u ON u.id = bw.user_id
-            WHERE bw.period_id = $1 AND bw.user_id = $2
+            LEFT JOIN users pu ON pu.id = bw.partner_user_id
+            WHERE bw.period_id = $1 AND (bw.user_id = $2 OR bw.partner_user_id = $2)
             ORDER BY bw.seniority_rank
             "#,
             period_id,
@@ -242,10 +379,15 @@ pub async fn get_bid_window(
                bw.seniority_rank, bw.opens_at, bw.closes_at, bw.submitted_at,
                bw.unlocked_at, bw.approved_at,
                bw.approved_by AS "approved_by?",
-               (u.employee_type::TEXT = 'job_share') AS "is_job_share!",
-               bw.auto_advanced_at
+               (u.employee_type::TEXT = 'job_share' OR bw.partner_user_id IS NOT NULL) AS "is_job_share!",
+               bw.auto_advanced_at,
+               bw.partner_user_id,
+               pu.first_name AS "partner_first_name?",
+               pu.last_name AS "partner_last_name?",
+               bw.round_id
         FROM bid_windows bw
         JOIN users u ON u.id = bw.user_id
+        LEFT JOIN users pu ON pu.id = bw.partner_user_id
         JOIN schedule_periods sp ON sp.id = bw.period_id
         WHERE bw.id = $1 AND sp.org_id = $2
         "#,
@@ -256,8 +398,13 @@ pub async fn get_bid_window(
     .await?
     .ok_or_else(|| AppError::NotFound("Bid window not found".into()))?;
 
-    // Only the window's user or admin/supervisor can view
-    if window.user_id != auth.id && !auth.role.can_manage_schedule() {
+    // Only the window's user, their job-share partner, their proxy bidder, or
+    // admin/supervisor can view
+    if window.user_id != auth.id
+        && window.partner_user_id != Some(auth.id)
+        && !auth.role.can_manage_schedule()
+        && !is_proxy_for(&pool, BidPeriod::Shift(window.period_id), window.user_id, auth.id).await?
+    {
         return Err(AppError::Forbidden);
     }
 
@@ -273,10 +420,15 @@ pub async fn get_bid_window(
                bw.seniority_rank, bw.opens_at, bw.closes_at, bw.submitted_at,
                bw.unlocked_at, bw.approved_at,
                bw.approved_by AS "approved_by?",
-               (u.employee_type::TEXT = 'job_share') AS "is_job_share!",
-               bw.auto_advanced_at
+               (u.employee_type::TEXT = 'job_share' OR bw.partner_user_id IS NOT NULL) AS "is_job_share!",
+               bw.auto_advanced_at,
+               bw.partner_user_id,
+               pu.first_name AS "partner_first_name?",
+               pu.last_name AS "partner_last_name?",
+               bw.round_id
         FROM bid_windows bw
         JOIN users u ON u.id = bw.user_id
+        LEFT JOIN users pu ON pu.id = bw.partner_user_id
         JOIN schedule_periods sp ON sp.id = bw.period_id
         WHERE bw.id = $1 AND sp.org_id = $2
         "#,
@@ -286,6 +438,36 @@ pub async fn get_bid_window(
     .fetch_one(&pool)
     .await?;
 
+    // A round limits the slots on offer. In a later round, slots that earlier
+    // rounds' bids would win count as taken, so bidders see the leftovers.
+    let round = match window.round_id {
+        Some(round_id) => sqlx::query!(
+            r#"
+            SELECT r.slot_ids,
+                   (SELECT MIN(seniority_rank) FROM bid_windows WHERE round_id = r.id) AS "first_rank!"
+            FROM shift_bid_rounds r
+            WHERE r.id = $1
+            "#,
+            round_id
+        )
+        .fetch_optional(&pool)
+        .await?,
+        None => None,
+    };
+    let earlier_awards: HashSet<Uuid> = match &round {
+        Some(round) if round.first_rank > 1 => {
+            let mut conn = pool.acquire().await?;
+            plan_shift_awards(&mut conn, window.period_id)
+                .await?
+                .rows
+                .into_iter()
+                .filter(|r| r.seniority_rank < round.first_rank)
+                .filter_map(|r| r.award.map(|a| a.slot_id))
+                .collect()
+        }
+        _ => HashSet::new(),
+    };
+
     // Available slots: active slots for the org, marking ones already awarded
     let slots = sqlx::query!(
         r#"
@@ -299,20 +481,24 @@ pub async fn get_bid_window(
             cl.abbreviation AS classification_abbreviation,
             sl.days_of_week,
             sl.label,
-            CASE WHEN sa.id IS NOT NULL THEN true ELSE false END AS "already_awarded!",
+            EXISTS(
+                SELECT 1 FROM slot_assignments sa
+                WHERE sa.slot_id = sl.id AND sa.period_id = $2
+            ) AS "already_awarded!",
             sl.is_flex AS "is_flex!"
         FROM shift_slots sl
         JOIN teams t ON t.id = sl.team_id
         JOIN shift_templates st ON st.id = sl.shift_template_id
         JOIN classifications cl ON cl.id = sl.classification_id
-        LEFT JOIN slot_assignments sa ON sa.slot_id = sl.id AND sa.period_id = $2
         WHERE t.org_id = $1
           AND t.is_active = true
           AND sl.is_active = true
+          AND ($3::UUID[] IS NULL OR sl.id = ANY($3))
         ORDER BY t.name, st.start_time, cl.abbreviation
         "#,
         auth.org_id,
         window.period_id,
+        round.as_ref().and_then(|r| r.slot_ids.as_deref()),
     )
     .fetch_all(&pool)
     .await?;
@@ -329,7 +515,7 @@ pub async fn get_bid_window(
             classification_abbreviation: s.classification_abbreviation,
             days_of_week: s.days_of_week,
             label: s.label,
-            already_awarded: s.already_awarded,
+            already_awarded: s.already_awarded || earlier_awards.contains(&s.slot_id),
             is_flex: s.is_flex,
         })
         .collect();
@@ -344,6 +530,7 @@ pub async fn get_bid_window(
             t.name AS team_name,
             cl.name AS classification_name,
             sl.days_of_week,
+            bs.partner_days_of_week,
             bs.preference_rank,
             bs.awarded
         FROM bid_submissions bs
@@ -368,6 +555,7 @@ pub async fn get_bid_window(
             team_name: s.team_name,
             classification_name: s.classification_name,
             days_of_week: s.days_of_week,
+            partner_days_of_week: s.partner_days_of_week,
             preference_rank: s.preference_rank,
             awarded: s.awarded,
         })
@@ -380,8 +568,102 @@ pub async fn get_bid_window(
     }))
 }
 
+/// Validate a ranked list of slot preferences: sequential ranks, no duplicates,
+/// and every slot active in the org. A `joint` (job-share) bid must split each
+/// slot's days between the partners; any other bid can't.
+pub(crate) async fn validate_preferences(
+    pool: &PgPool,
+    org_id: Uuid,
+    preferences: &[BidPreference],
+    joint: bool,
+) -> Result<()> {
+    if preferences.is_empty() {
+        return Err(AppError::BadRequest(
+            "At least one preference is required".into(),
+        ));
+    }
+
+    if preferences.len() > 50 {
+        return Err(AppError::BadRequest(
+            "Maximum 50 preferences allowed".into(),
+        ));
+    }
+
+    // Validate ranks are sequential starting from 1
+    let ranks: Vec<i32> = preferences.iter().map(|p| p.preference_rank).collect();
+    validate_sequential_ranks(&ranks)?;
+
+    // Check for duplicate slot_ids
+    let mut slot_ids: Vec<Uuid> = preferences.iter().map(|p| p.slot_id).collect();
+    slot_ids.sort();
+    slot_ids.dedup();
+    if slot_ids.len() != preferences.len() {
+        return Err(AppError::BadRequest(
+            "Duplicate slot_ids in preferences".into(),
+        ));
+    }
+
+    // Verify all slot_ids exist and belong to org (batch query instead of N+1)
+    let all_slot_ids: Vec<Uuid> = preferences.iter().map(|p| p.slot_id).collect();
+    let slot_days: HashMap<Uuid, Vec<i32>> = sqlx::query!(
+        r#"
+        SELECT ss.id, ss.days_of_week
+        FROM shift_slots ss
+        JOIN teams t ON t.id = ss.team_id
+        WHERE ss.id = ANY($1::uuid[]) AND t.org_id = $2
+          AND ss.is_active = true
+        "#,
+        &all_slot_ids,
+        org_id,
+    )
+    .fetch_all(pool)
+    .await?
+    .into_iter()
+    .map(|r| (r.id, r.days_of_week))
+    .collect();
+
+    if slot_days.len() != all_slot_ids.len() {
+        return Err(AppError::NotFound("Shift slot not found".into()));
+    }
+
+    for pref in preferences {
+        match (&pref.partner_days, joint) {
+            (None, false) => {}
+            (Some(_), false) => {
+                return Err(AppError::BadRequest(
+                    "partner_days only apply to job-share bids".into(),
+                ));
+            }
+            (None, true) => {
+                return Err(AppError::BadRequest(
+                    "A job-share bid must give partner_days for every slot".into(),
+                ));
+            }
+            (Some(days), true) => {
+                let slot = &slot_days[&pref.slot_id];
+                let mut unique = days.clone();
+                unique.sort_unstable();
+                unique.dedup();
+                if unique.len() != days.len() || days.iter().any(|d| !slot.contains(d)) {
+                    return Err(AppError::BadRequest(
+                        "partner_days must be distinct days the slot works".into(),
+                    ));
+                }
+                if days.is_empty() || days.len() == slot.len() {
+                    return Err(AppError::BadRequest(
+                        "Each job-share partner must work at least one of the slot's days".into(),
+                    ));
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
 /// POST /api/bid-windows/:id/submit
-/// Submit ranked preferences. Only the window's user can submit, only during open window.
+/// Submit ranked preferences. Only the window's user or their designated proxy
+/// can submit, only during open window.
 pub async fn submit_bid(
     State(pool): State<PgPool>,
     auth: AuthUser,
@@ -391,10 +673,11 @@ pub async fn submit_bid(
     // Fetch window and verify ownership
     let window = sqlx::query!(
         r#"
-        SELECT bw.id, bw.period_id, bw.user_id, bw.opens_at, bw.closes_at,
-               bw.unlocked_at, bw.approved_at, sp.org_id
+        SELECT bw.id, bw.period_id, bw.user_id, bw.partner_user_id, bw.opens_at, bw.closes_at,
+               bw.unlocked_at, bw.approved_at, sp.org_id, r.slot_ids AS "round_slot_ids?"
         FROM bid_windows bw
         JOIN schedule_periods sp ON sp.id = bw.period_id
+        LEFT JOIN shift_bid_rounds r ON r.id = bw.round_id
         WHERE bw.id = $1
         "#,
         window_id
@@ -407,7 +690,9 @@ pub async fn submit_bid(
         return Err(AppError::NotFound("Bid window not found".into()));
     }
 
-    if window.user_id != auth.id {
+    // A job-share partner bids in the joint window as its co-owner
+    let by_proxy = window.user_id != auth.id && window.partner_user_id != Some(auth.id);
+    if by_proxy && !is_proxy_for(&pool, BidPeriod::Shift(window.period_id), window.user_id, auth.id).await? {
         return Err(AppError::Forbidden);
     }
 
@@ -436,51 +721,13 @@ pub async fn submit_bid(
         ));
     }
 
-    // Validate preferences
-    if req.preferences.is_empty() {
-        return Err(AppError::BadRequest(
-            "At least one preference is required".into(),
-        ));
-    }
-
-    if req.preferences.len() > 50 {
-        return Err(AppError::BadRequest(
-            "Maximum 50 preferences allowed".into(),
-        ));
-    }
-
-    // Validate ranks are sequential starting from 1
-    let ranks: Vec<i32> = req.preferences.iter().map(|p| p.preference_rank).collect();
-    validate_sequential_ranks(&ranks)?;
-
-    // Check for duplicate slot_ids
-    let mut slot_ids: Vec<Uuid> = req.preferences.iter().map(|p| p.slot_id).collect();
-    slot_ids.sort();
-    slot_ids.dedup();
-    if slot_ids.len() != req.preferences.len() {
-        return Err(AppError::BadRequest(
-            "Duplicate slot_ids in preferences".into(),
-        ));
-    }
-
-    // Verify all slot_ids exist and belong to org (batch query instead of N+1)
-    let all_slot_ids: Vec<Uuid> = req.preferences.iter().map(|p| p.slot_id).collect();
-    let valid_count = sqlx::query_scalar!(
-        r#"
-        SELECT COUNT(*) AS "count!"
-        FROM shift_slots ss
-        JOIN teams t ON t.id = ss.team_id
-        WHERE ss.id = ANY($1::uuid[]) AND t.org_id = $2
-          AND ss.is_active = true
-        "#,
-        &all_slot_ids,
-        auth.org_id,
-    )
-    .fetch_one(&pool)
-    .await?;
-
-    if valid_count != all_slot_ids.len() as i64 {
-        return Err(AppError::NotFound("Shift slot not found".into()));
+    validate_preferences(&pool, auth.org_id, &req.preferences, window.partner_user_id.is_some()).await?;
+    if let Some(pool_slots) = &window.round_slot_ids {
+        if req.preferences.iter().any(|p| !pool_slots.contains(&p.slot_id)) {
+            return Err(AppError::BadRequest(
+                "Only slots offered in this bid round can be bid on".into(),
+            ));
+        }
     }
 
     let mut tx = pool.begin().await?;
@@ -497,13 +744,14 @@ pub async fn submit_bid(
     for pref in &req.preferences {
         sqlx::query!(
             r#"
-            INSERT INTO bid_submissions (id, bid_window_id, slot_id, preference_rank)
-            VALUES ($1, $2, $3, $4)
+            INSERT INTO bid_submissions (id, bid_window_id, slot_id, preference_rank, partner_days_of_week)
+            VALUES ($1, $2, $3, $4, $5)
             "#,
             Uuid::new_v4(),
             window_id,
             pref.slot_id,
             pref.preference_rank,
+            pref.partner_days.as_deref(),
         )
         .execute(&mut *tx)
         .await?;
@@ -518,8 +766,39 @@ pub async fn submit_bid(
     .execute(&mut *tx)
     .await?;
 
+    let proxy_submission = if by_proxy {
+        ProxyAction {
+            org_id: auth.org_id,
+            user_id: window.user_id,
+            actor_id: Some(auth.id),
+            period: BidPeriod::Shift(window.period_id),
+            window_id,
+            trigger: Trigger::Proxy,
+            submitted: serde_json::to_value(&req.preferences).unwrap_or_default(),
+            unavailable: serde_json::json!([]),
+        }
+        .record(&mut tx)
+        .await?;
+        Some(ProxySubmission {
+            org_id: auth.org_id,
+            user_id: window.user_id,
+            window_id,
+            period: BidPeriod::Shift(window.period_id),
+            trigger: Trigger::Proxy,
+            count: req.preferences.len(),
+        })
+    } else {
+        None
+    };
+
     tx.commit().await?;
 
+    if let Some(submission) = proxy_submission {
+        notify_submitted(&pool, &submission).await;
+    }
+    // The last bid of a round opens the next one
+    advance_rounds(&pool, window.period_id).await?;
+
     Ok(Json(
         serde_json::json!({ "ok": true, "submitted_at": now.to_string() }),
     ))
@@ -615,8 +894,51 @@ pub async fn approve_bid_window(
     })))
 }
 
+/// Lock a shift bid period that's ready for processing and work out its award table.
+async fn plan_open_period(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    period_id: Uuid,
+) -> Result<ShiftAwardTable> {
+    // Check period status inside transaction with FOR UPDATE to prevent concurrent processing
+    let current_status = sqlx::query_scalar!(
+        r#"SELECT status AS "status: BidPeriodStatus" FROM schedule_periods WHERE id = $1 FOR UPDATE"#,
+        period_id
+    )
+    .fetch_one(&mut **tx)
+    .await?;
+
+    if current_status != BidPeriodStatus::Open && current_status != BidPeriodStatus::InProgress {
+        return Err(AppError::BadRequest(
+            "Bids can only be processed for periods in 'open' or 'in_progress' status".into(),
+        ));
+    }
+
+    plan_shift_awards(tx, period_id).await
+}
+
+/// GET /api/schedule/periods/:id/process-bids/preview
+/// Admin only. The award table `process_bids` would apply right now, without applying it.
+pub async fn preview_bids(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(period_id): Path<Uuid>,
+) -> Result<Json<ShiftAwardTable>> {
+    if !auth.role.is_admin() {
+        return Err(AppError::Forbidden);
+    }
+
+    org_guard::verify_period(&pool, period_id, auth.org_id).await?;
+
+    let mut tx = pool.begin().await?;
+    let table = plan_open_period(&mut tx, period_id).await?;
+    tx.rollback().await?;
+
+    Ok(Json(table))
+}
+
 /// POST /api/schedule/periods/:id/process-bids
-/// Admin trigger to process all bids and create slot assignments.
+/// Admin trigger to process all bids and create slot assignments. The award
+/// table is kept as a processing run.
 pub async fn process_bids(
     State(pool): State<PgPool>,
     auth: AuthUser,
@@ -630,183 +952,326 @@ pub async fn process_bids(
 
     let mut tx = pool.begin().await?;
 
-    // Check period status inside transaction with FOR UPDATE to prevent concurrent processing
-    let current_status = sqlx::query_scalar!(
-        r#"SELECT status AS "status: BidPeriodStatus" FROM schedule_periods WHERE id = $1 FOR UPDATE"#,
+    let table = plan_open_period(&mut tx, period_id).await?;
+
+    let rounds_pending = sqlx::query_scalar!(
+        r#"SELECT EXISTS (SELECT 1 FROM shift_bid_rounds WHERE period_id = $1 AND opened_at IS NULL) AS "exists!""#,
         period_id
     )
     .fetch_one(&mut *tx)
     .await?;
-
-    if current_status != BidPeriodStatus::Open && current_status != BidPeriodStatus::InProgress {
+    if rounds_pending {
         return Err(AppError::BadRequest(
-            "Bids can only be processed for periods in 'open' or 'in_progress' status".into(),
+            "Bids can't be processed until every bid round has opened".into(),
         ));
     }
 
-    // Set status to in_progress during processing
-    sqlx::query!(
-        "UPDATE schedule_periods SET status = 'in_progress' WHERE id = $1",
+    // Fetch the period name for notification messages
+    let period_name = sqlx::query_scalar!(
+        "SELECT name FROM schedule_periods WHERE id = $1",
         period_id
     )
-    .execute(&mut *tx)
+    .fetch_one(&mut *tx)
     .await?;
 
-    // Get all bid windows in seniority order
-    let windows = sqlx::query!(
-        r#"
-        SELECT id, user_id
-        FROM bid_windows
-        WHERE period_id = $1
-        ORDER BY seniority_rank ASC
-        "#,
+    for row in &table.rows {
+        let Some(award) = &row.award else { continue };
+
+        sqlx::query!(
+            "UPDATE bid_submissions SET awarded = true WHERE bid_window_id = $1 AND slot_id = $2",
+            row.window_id,
+            award.slot_id,
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        // Create slot assignment; a job-share pair gets two complementary ones
+        let mut holders = vec![(row.user_id, award.days_of_week.as_deref())];
+        if let (Some(partner), Some(days)) = (row.partner_user_id, award.partner_days_of_week.as_deref()) {
+            holders.push((partner, Some(days)));
+        }
+        for (user_id, days) in holders {
+            sqlx::query!(
+                r#"
+                INSERT INTO slot_assignments (id, slot_id, user_id, period_id, days_of_week)
+                VALUES ($1, $2, $3, $4, $5)
+                "#,
+                Uuid::new_v4(),
+                award.slot_id,
+                user_id,
+                period_id,
+                days,
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+    }
+
+    let run_id = record_run(
+        &mut tx,
+        auth.org_id,
+        BidPeriod::Shift(period_id),
+        "process",
+        auth.id,
+        table.awards_count,
+        None,
+        serde_json::to_value(&table).unwrap_or_default(),
+    )
+    .await?;
+
+    // Set period status to completed
+    sqlx::query!(
+        "UPDATE schedule_periods SET status = 'completed' WHERE id = $1",
         period_id
     )
-    .fetch_all(&mut *tx)
+    .execute(&mut *tx)
     .await?;
 
-    // Fetch the period name for notification messages
-    let period_name = sqlx::query_scalar!(
-        "SELECT name FROM schedule_periods WHERE id = $1",
+    tx.commit().await?;
+
+    // Send notifications for awarded bids (best-effort, after commit)
+    for row in &table.rows {
+        let Some(award) = &row.award else { continue };
+        let message = format!(
+            "Your bid for {} was awarded for period {}",
+            award.slot_name, period_name,
+        );
+        for user_id in std::iter::once(row.user_id).chain(row.partner_user_id) {
+            let _ = create_notification(
+                &pool,
+                CreateNotificationParams {
+                    org_id: auth.org_id,
+                    user_id,
+                    notification_type: "bid_awarded",
+                    title: "Shift bid awarded",
+                    message: &message,
+                    link: Some("/schedule"),
+                    source_type: Some("schedule_period"),
+                    source_id: Some(period_id),
+                },
+            )
+            .await;
+        }
+    }
+
+    Ok(Json(serde_json::json!({
+        "ok": true,
+        "run_id": run_id,
+        "awards_count": table.awards_count,
+        "total_bidders": table.total_bidders,
+    })))
+}
+
+/// POST /api/schedule/periods/:id/rollback-bids
+/// Admin only. Undo a processed period: delete the awarded slot assignments and
+/// reopen the period for processing.
+pub async fn rollback_bids(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(period_id): Path<Uuid>,
+    Json(req): Json<RollbackBidsRequest>,
+) -> Result<Json<serde_json::Value>> {
+    if !auth.role.is_admin() {
+        return Err(AppError::Forbidden);
+    }
+
+    org_guard::verify_period(&pool, period_id, auth.org_id).await?;
+
+    let mut tx = pool.begin().await?;
+
+    let period = sqlx::query!(
+        r#"SELECT name, status AS "status: BidPeriodStatus" FROM schedule_periods WHERE id = $1 FOR UPDATE"#,
         period_id
     )
     .fetch_one(&mut *tx)
     .await?;
 
-    let mut awards_count = 0;
-    // Pre-load all already-assigned slot IDs to avoid N+1 queries
-    let mut assigned_slots: std::collections::HashSet<Uuid> = sqlx::query_scalar!(
-        "SELECT slot_id FROM slot_assignments WHERE period_id = $1",
-        period_id,
+    if period.status != BidPeriodStatus::Completed {
+        return Err(AppError::BadRequest(
+            "Only processed periods can be rolled back".into(),
+        ));
+    }
+
+    let awards = sqlx::query!(
+        r#"
+        SELECT bs.id, bw.user_id, bw.partner_user_id, bs.slot_id
+        FROM bid_submissions bs
+        JOIN bid_windows bw ON bw.id = bs.bid_window_id
+        WHERE bw.period_id = $1 AND bs.awarded = true
+        ORDER BY bw.seniority_rank
+        "#,
+        period_id
     )
     .fetch_all(&mut *tx)
-    .await?
-    .into_iter()
-    .collect();
-    // M4: Track flex slot awards per classification (max 2 per classification per cycle)
-    let mut flex_awards: std::collections::HashMap<Uuid, u32> = std::collections::HashMap::new();
-    // Collect award info for post-commit notifications
-    struct AwardInfo {
-        user_id: Uuid,
-        slot_id: Uuid,
-    }
-    let mut awarded_bids: Vec<AwardInfo> = Vec::new();
-
-    for win in &windows {
-        // Get this window's submissions in preference order, with flex/classification info
-        let submissions = sqlx::query!(
-            r#"
-            SELECT bs.id, bs.slot_id, bs.preference_rank,
-                   ss.is_flex AS "is_flex!", ss.classification_id
-            FROM bid_submissions bs
-            JOIN shift_slots ss ON ss.id = bs.slot_id
-            WHERE bs.bid_window_id = $1
-            ORDER BY bs.preference_rank ASC
-            "#,
-            win.id
+    .await?;
+
+    for award in &awards {
+        sqlx::query!(
+            "DELETE FROM slot_assignments WHERE period_id = $1 AND slot_id = $2 AND (user_id = $3 OR user_id = $4)",
+            period_id,
+            award.slot_id,
+            award.user_id,
+            award.partner_user_id,
         )
-        .fetch_all(&mut *tx)
+        .execute(&mut *tx)
         .await?;
-
-        // Find the highest-preference slot that hasn't been awarded to someone else
-        for sub in &submissions {
-            // M4: Check flex slot constraint (max 2 flex slots per classification per cycle)
-            if sub.is_flex {
-                let count = flex_awards
-                    .get(&sub.classification_id)
-                    .copied()
-                    .unwrap_or(0);
-                if count >= 2 {
-                    continue; // Flex cap reached for this classification
-                }
-            }
-
-            // Check if this slot already has an assignment for this period
-            if assigned_slots.contains(&sub.slot_id) {
-                continue;
-            }
-
-            {
-                // Award this slot
-                sqlx::query!(
-                    "UPDATE bid_submissions SET awarded = true WHERE id = $1",
-                    sub.id
-                )
-                .execute(&mut *tx)
-                .await?;
-
-                // Create slot assignment
-                sqlx::query!(
-                    r#"
-                    INSERT INTO slot_assignments (id, slot_id, user_id, period_id)
-                    VALUES ($1, $2, $3, $4)
-                    "#,
-                    Uuid::new_v4(),
-                    sub.slot_id,
-                    win.user_id,
-                    period_id,
-                )
-                .execute(&mut *tx)
-                .await?;
-
-                // Track this slot as assigned
-                assigned_slots.insert(sub.slot_id);
-
-                // M4: Track flex award
-                if sub.is_flex {
-                    *flex_awards.entry(sub.classification_id).or_insert(0) += 1;
-                }
-
-                awarded_bids.push(AwardInfo {
-                    user_id: win.user_id,
-                    slot_id: sub.slot_id,
-                });
-                awards_count += 1;
-                break;
-            }
-        }
     }
 
-    // Set period status to completed
+    let submission_ids: Vec<Uuid> = awards.iter().map(|a| a.id).collect();
     sqlx::query!(
-        "UPDATE schedule_periods SET status = 'completed' WHERE id = $1",
+        "UPDATE bid_submissions SET awarded = false WHERE id = ANY($1)",
+        &submission_ids,
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    // Reopen the period; the posted results no longer stand
+    sqlx::query!(
+        r#"
+        UPDATE schedule_periods
+        SET status = 'open', bid_results_published_at = NULL, bid_results_published_by = NULL
+        WHERE id = $1
+        "#,
         period_id
     )
     .execute(&mut *tx)
     .await?;
 
+    let undone: Vec<serde_json::Value> = awards
+        .iter()
+        .map(|a| serde_json::json!({ "user_id": a.user_id, "partner_user_id": a.partner_user_id, "slot_id": a.slot_id }))
+        .collect();
+    let run_id = record_run(
+        &mut tx,
+        auth.org_id,
+        BidPeriod::Shift(period_id),
+        "rollback",
+        auth.id,
+        awards.len(),
+        req.reason.as_deref(),
+        serde_json::json!({ "awards": undone }),
+    )
+    .await?;
+
     tx.commit().await?;
 
-    // Send notifications for awarded bids (best-effort, after commit)
-    for award in &awarded_bids {
-        // Fetch slot name for the notification message
-        let slot_name = sqlx::query_scalar!(
-            r#"
-            SELECT CONCAT(t.name, ' - ', st.name) AS "name!"
-            FROM shift_slots ss
-            JOIN teams t ON t.id = ss.team_id
-            JOIN shift_templates st ON st.id = ss.shift_template_id
-            WHERE ss.id = $1
-            "#,
-            award.slot_id,
+    let message = format!(
+        "Your shift bid award for period {} was rolled back and will be processed again.",
+        period.name,
+    );
+    for user_id in awards.iter().flat_map(|a| std::iter::once(a.user_id).chain(a.partner_user_id)) {
+        let _ = create_notification(
+            &pool,
+            CreateNotificationParams {
+                org_id: auth.org_id,
+                user_id,
+                notification_type: "bid_award_rolled_back",
+                title: "Shift bid award rolled back",
+                message: &message,
+                link: Some("/schedule"),
+                source_type: Some("schedule_period"),
+                source_id: Some(period_id),
+            },
         )
-        .fetch_optional(&pool)
-        .await
-        .ok()
-        .flatten()
-        .unwrap_or_else(|| "a shift slot".to_string());
+        .await;
+    }
 
-        let message = format!(
-            "Your bid for {} was awarded for period {}",
-            slot_name, period_name,
-        );
+    Ok(Json(serde_json::json!({
+        "ok": true,
+        "run_id": run_id,
+        "awards_rolled_back": awards.len(),
+    })))
+}
+
+/// GET /api/schedule/periods/:id/bid-runs
+/// Processing and rollback runs for the period, newest first.
+pub async fn list_bid_runs(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(period_id): Path<Uuid>,
+) -> Result<Json<Vec<BidProcessingRun>>> {
+    if !auth.role.can_manage_schedule() {
+        return Err(AppError::Forbidden);
+    }
+
+    org_guard::verify_period(&pool, period_id, auth.org_id).await?;
+
+    Ok(Json(list_runs(&pool, auth.org_id, BidPeriod::Shift(period_id)).await?))
+}
+
+/// GET /api/schedule/periods/:id/bid-results?format=json|csv|pdf
+/// The results sheet: every window in seniority order with its bids, award,
+/// skips and approver. Schedule managers can always see it; other employees
+/// once it has been published.
+pub async fn bid_results(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(period_id): Path<Uuid>,
+    Query(q): Query<ExportQuery>,
+) -> Result<Response> {
+    org_guard::verify_period(&pool, period_id, auth.org_id).await?;
+
+    let report = bid_results::load(&pool, period_id).await?;
+    if report.published_at.is_none() && !auth.role.can_manage_schedule() {
+        return Err(AppError::Forbidden);
+    }
+
+    Ok(export_response(
+        q.format,
+        report,
+        bid_results::to_table,
+        &format!("bid-results-{period_id}"),
+    ))
+}
+
+/// POST /api/schedule/periods/:id/bid-results/publish
+/// Admin only. Post the results of a processed period to every employee and
+/// notify the bidders.
+pub async fn publish_bid_results(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(period_id): Path<Uuid>,
+) -> Result<Json<serde_json::Value>> {
+    if !auth.role.is_admin() {
+        return Err(AppError::Forbidden);
+    }
+
+    org_guard::verify_period(&pool, period_id, auth.org_id).await?;
+
+    let period = sqlx::query!(
+        r#"
+        UPDATE schedule_periods
+        SET bid_results_published_at = NOW(), bid_results_published_by = $2
+        WHERE id = $1 AND status = 'completed'
+        RETURNING name, bid_results_published_at AS "published_at!"
+        "#,
+        period_id,
+        auth.id,
+    )
+    .fetch_optional(&pool)
+    .await?
+    .ok_or_else(|| AppError::BadRequest("Only processed periods can have their results published".into()))?;
+
+    let bidders = sqlx::query_scalar!(
+        r#"
+        SELECT user_id AS "user_id!" FROM bid_windows WHERE period_id = $1
+        UNION
+        SELECT partner_user_id FROM bid_windows WHERE period_id = $1 AND partner_user_id IS NOT NULL
+        "#,
+        period_id,
+    )
+    .fetch_all(&pool)
+    .await?;
+
+    let message = format!("Shift bid results for period {} have been posted", period.name);
+    for user_id in bidders {
         let _ = create_notification(
             &pool,
             CreateNotificationParams {
                 org_id: auth.org_id,
-                user_id: award.user_id,
-                notification_type: "bid_awarded",
-                title: "Shift bid awarded",
+                user_id,
+                notification_type: "bid_results_published",
+                title: "Shift bid results posted",
                 message: &message,
                 link: Some("/schedule"),
                 source_type: Some("schedule_period"),
@@ -818,7 +1283,6 @@ pub async fn process_bids(
 
     Ok(Json(serde_json::json!({
         "ok": true,
-        "awards_count": awards_count,
-        "total_bidders": windows.len(),
+        "published_at": period.published_at.to_string(),
     })))
 }
@@ -0,0 +1,90 @@
+use axum::{
+    extract::{Query, State},
+    Json,
+};
+use sqlx::PgPool;
+
+use crate::{
+    api::helpers::validate_date_range,
+    auth::AuthUser,
+    error::{AppError, Result},
+    models::call_volume::{
+        CallForecastDay, CallVolumeQuery, CallVolumeRow, ImportCallVolumeRequest,
+        ImportCallVolumeResult,
+    },
+    services::call_forecast,
+};
+
+/// GET /api/call-volume?start_date=&end_date=
+pub async fn list(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Query(q): Query<CallVolumeQuery>,
+) -> Result<Json<Vec<CallVolumeRow>>> {
+    if !auth.role.can_manage_schedule() {
+        return Err(AppError::Forbidden);
+    }
+    validate_date_range(q.start_date, q.end_date, Some(366))?;
+
+    let rows = sqlx::query_as!(
+        CallVolumeRow,
+        r#"
+        SELECT date, slot_index, calls
+        FROM call_volume_history
+        WHERE org_id = $1 AND date BETWEEN $2 AND $3
+        ORDER BY date, slot_index
+        "#,
+        auth.org_id,
+        q.start_date,
+        q.end_date,
+    )
+    .fetch_all(&pool)
+    .await?;
+
+    Ok(Json(rows))
+}
+
+/// POST /api/call-volume/import
+pub async fn import(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Json(req): Json<ImportCallVolumeRequest>,
+) -> Result<Json<ImportCallVolumeResult>> {
+    if !auth.role.can_manage_schedule() {
+        return Err(AppError::Forbidden);
+    }
+
+    let counts = call_forecast::parse_import_csv(&req.csv)?;
+    call_forecast::import(&pool, auth.org_id, auth.id, &counts).await?;
+
+    let dates: std::collections::HashSet<_> = counts.keys().map(|(d, _)| *d).collect();
+    Ok(Json(ImportCallVolumeResult {
+        dates_imported: dates.len() as i64,
+        slots_imported: counts.len() as i64,
+        total_calls: counts.values().sum(),
+    }))
+}
+
+/// GET /api/call-volume/forecast?start_date=&end_date=
+pub async fn forecast(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Query(q): Query<CallVolumeQuery>,
+) -> Result<Json<Vec<CallForecastDay>>> {
+    if !auth.role.can_manage_schedule() {
+        return Err(AppError::Forbidden);
+    }
+    validate_date_range(q.start_date, q.end_date, Some(92))?;
+
+    let mut dates = Vec::new();
+    let mut d = q.start_date;
+    while d <= q.end_date {
+        dates.push(d);
+        d = d
+            .next_day()
+            .ok_or(AppError::BadRequest("Date range exceeds maximum date".into()))?;
+    }
+
+    let days = call_forecast::forecast(&pool, auth.org_id, &auth.org_timezone, &dates).await?;
+    Ok(Json(days))
+}
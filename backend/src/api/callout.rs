@@ -11,15 +11,21 @@ use crate::{
     error::{AppError, Result},
     models::{
         callout::{
-            BumpRequest, BumpRequestWithNames, CalloutAttempt, CalloutEvent, CalloutListEntry,
+            BumpAuditAction, BumpAuditEntry, BumpRequest, BumpRequestWithNames, BumpSweepResult,
+            CalloutAttempt, CalloutEvent, CalloutListEntry,
             CalloutStatus, CreateBumpRequest, CreateCalloutEventRequest, RecordAttemptRequest,
             ReviewBumpRequest,
         },
         common::{OtType, Paginated, PaginationParams},
+        mandatory_ot::MandatoryOtEventType,
         ot::CalloutStep,
     },
     org_guard,
-    services::availability::compute_available_employees,
+    services::{
+        availability::compute_available_employees,
+        bump,
+        mandatory_ot::{EventSource, NewMandatoryOtEvent},
+    },
 };
 
 /// Fetch a single callout event by ID with org isolation.
@@ -208,7 +214,8 @@ pub async fn callout_list(
     let event = sqlx::query!(
         r#"
         SELECT ce.scheduled_shift_id, ce.classification_id, ss.date AS shift_date,
-               st.start_time AS shift_start_time, st.duration_minutes AS shift_duration
+               st.start_time AS shift_start_time, st.duration_minutes AS shift_duration,
+               ce.current_step AS "current_step?: CalloutStep"
         FROM callout_events ce
         JOIN scheduled_shifts ss ON ss.id = ce.scheduled_shift_id
         JOIN shift_templates st ON st.id = ss.shift_template_id
@@ -230,6 +237,9 @@ pub async fn callout_list(
         event.shift_date,
         event.shift_start_time,
         event.shift_duration,
+        Some(event_id),
+        event.current_step.as_ref(),
+        None,
     )
     .await?;
 
@@ -297,6 +307,32 @@ async fn handle_attempt_accepted(
     };
     let ot_type_str = ot_type.to_string();
 
+    // Forced OT: honor mandatory OT exemptions and advance the rotation.
+    if ot_type == OtType::Mandatory {
+        if let Some(reason) = crate::services::mandatory_ot::active_exemption(
+            tx, org_id, user_id, ctx.classification_id, ctx.shift_date,
+        ).await? {
+            return Err(AppError::BadRequest(format!(
+                "This employee is exempt from mandatory OT on {}: {}",
+                ctx.shift_date, reason,
+            )));
+        }
+        crate::services::mandatory_ot::record_event(
+            tx,
+            NewMandatoryOtEvent {
+                org_id,
+                classification_id: ctx.classification_id,
+                user_id,
+                event_type: MandatoryOtEventType::Forced,
+                counts_as_turn: true,
+                source: EventSource::CalloutEvent(event_id),
+                notes: None,
+                recorded_by: supervisor_id,
+                occurred_at: None,
+            },
+        ).await?;
+    }
+
     // Create an OT assignment. Skip if the user is already on this shift.
     sqlx::query!(
         r#"
@@ -338,9 +374,12 @@ async fn handle_attempt_accepted(
 /// Handle a declined callout attempt:
 /// - Stamp OT queue (CBA: employee was contacted and moves to back regardless of response)
 /// - Upsert hours_declined
+/// - Record a refusal in the mandatory rotation when declined at the mandatory step
 async fn handle_attempt_declined(
     tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    event_id: Uuid,
     user_id: Uuid,
+    supervisor_id: Uuid,
     org_id: Uuid,
     fiscal_year: i32,
     ctx: &CalloutEventCtx,
@@ -358,6 +397,25 @@ async fn handle_attempt_declined(
         tx, user_id, fiscal_year, ctx.classification_id, shift_hours,
     ).await?;
 
+    // A refused forced order does not use up the turn — the employee stays at the
+    // front of the mandatory rotation.
+    if ctx.current_step == Some(CalloutStep::Mandatory) {
+        crate::services::mandatory_ot::record_event(
+            tx,
+            NewMandatoryOtEvent {
+                org_id,
+                classification_id: ctx.classification_id,
+                user_id,
+                event_type: MandatoryOtEventType::Refused,
+                counts_as_turn: false,
+                source: EventSource::CalloutEvent(event_id),
+                notes: None,
+                recorded_by: supervisor_id,
+                occurred_at: None,
+            },
+        ).await?;
+    }
+
     Ok(())
 }
 
@@ -491,7 +549,7 @@ pub async fn record_attempt(
         }
         "declined" => {
             handle_attempt_declined(
-                &mut tx, req.user_id, auth.org_id, fiscal_year, &ctx,
+                &mut tx, event_id, req.user_id, auth.id, auth.org_id, fiscal_year, &ctx,
             ).await?;
         }
         // "no_answer" — CBA: no queue stamp; nothing to do beyond the attempt row itself.
@@ -602,15 +660,14 @@ pub async fn cancel_ot_assignment(
                     )));
                 }
             }
-            Some("mandatory") => {
-                if now < shift_start {
-                    return Err(AppError::Conflict(
-                        "Cannot cancel mandatory OT before shift start".into(),
-                    ));
-                }
-                // now >= shift_start -> end-of-shift release, allowed
+            Some("mandatory") if now < shift_start => {
+                return Err(AppError::Conflict(
+                    "Cannot cancel mandatory OT before shift start".into(),
+                ));
             }
-            _ => {} // NULL ot_type or unknown -- allow (shouldn't happen)
+            // Started mandatory OT is an end-of-shift release; NULL ot_type or
+            // unknown shouldn't happen -- allow
+            _ => {}
         }
     }
 
@@ -622,6 +679,10 @@ pub async fn cancel_ot_assignment(
     .execute(&mut *tx)
     .await?;
 
+    crate::services::mandatory_ot::void_for_callout(
+        &mut tx, event_id, assignment.user_id, auth.id,
+    ).await?;
+
     // 6b. Reverse OT hours_worked for the cancelled assignment.
     let shift_hours = event.duration_minutes as f64 / 60.0;
     let fiscal_year = crate::services::ot::org_fiscal_year(&pool, auth.org_id, event.shift_date).await;
@@ -947,15 +1008,18 @@ pub async fn create_bump_request(
         ));
     }
 
-    // 6. Insert bump request
+    // 6. Insert bump request. Unreviewed requests are resolved automatically
+    // after the org's review window, and in time to swap before shift start.
+    let review_hours = bump::review_hours(&pool, auth.org_id).await;
+    let expires_at = bump::review_deadline(now, review_hours, shift_start);
     let new_id = Uuid::new_v4();
     let bump = sqlx::query_as!(
         BumpRequest,
         r#"
-        INSERT INTO bump_requests (id, org_id, event_id, requesting_user_id, displaced_user_id, reason)
-        VALUES ($1, $2, $3, $4, $5, $6)
+        INSERT INTO bump_requests (id, org_id, event_id, requesting_user_id, displaced_user_id, reason, expires_at)
+        VALUES ($1, $2, $3, $4, $5, $6, $7)
         RETURNING id, org_id, event_id, requesting_user_id, displaced_user_id, status,
-                  reason, created_at, reviewed_at, reviewed_by
+                  reason, created_at, reviewed_at, reviewed_by, expires_at, auto_resolved
         "#,
         new_id,
         auth.org_id,
@@ -963,10 +1027,13 @@ pub async fn create_bump_request(
         auth.id,
         req.displaced_user_id,
         req.reason,
+        expires_at,
     )
     .fetch_one(&mut *tx)
     .await?;
 
+    bump::record_audit(&mut tx, &bump, BumpAuditAction::Submitted, Some(auth.id), None).await?;
+
     tx.commit().await?;
 
     Ok(Json(bump))
@@ -993,7 +1060,7 @@ pub async fn review_bump_request(
         r#"
         SELECT br.id, br.org_id, br.event_id, br.requesting_user_id,
                br.displaced_user_id, br.status, br.reason, br.created_at,
-               br.reviewed_at, br.reviewed_by
+               br.reviewed_at, br.reviewed_by, br.expires_at, br.auto_resolved
         FROM bump_requests br WHERE br.id = $1 AND br.org_id = $2 FOR UPDATE
         "#,
         id,
@@ -1008,159 +1075,11 @@ pub async fn review_bump_request(
         ));
     }
 
-    // 2. Fetch the callout event's scheduled_shift_id
-    let scheduled_shift_id = sqlx::query_scalar!(
-        "SELECT scheduled_shift_id FROM callout_events WHERE id = $1",
-        br.event_id
-    )
-    .fetch_one(&mut *tx)
-    .await?;
-
-    // Verify shift hasn't started and event is still filled; also fetch classification
-    // and duration for OT hour accounting on bump approval.
-    let shift_check = sqlx::query!(
-        r#"
-        SELECT ss.date AS shift_date, st.start_time,
-               ce.status AS "status: CalloutStatus",
-               ce.classification_id,
-               st.duration_minutes
-        FROM callout_events ce
-        JOIN scheduled_shifts ss ON ss.id = ce.scheduled_shift_id
-        JOIN shift_templates st ON st.id = ss.shift_template_id
-        WHERE ce.id = $1
-        "#,
-        br.event_id
-    )
-    .fetch_one(&mut *tx)
-    .await?;
-
-    if shift_check.status != CalloutStatus::Filled {
-        return Err(AppError::Conflict(
-            "Callout event is no longer filled".into(),
-        ));
-    }
-    let shift_start = crate::services::timezone::local_to_utc(
-        shift_check.shift_date,
-        shift_check.start_time,
-        &auth.org_timezone,
-    );
-    if time::OffsetDateTime::now_utc() >= shift_start {
-        return Err(AppError::Conflict(
-            "Cannot approve bump after the shift has started".into(),
-        ));
-    }
-
-    if req.approved {
-        // 3a. Re-verify requesting user is still active in this org.
-        let requester_ok = sqlx::query_scalar!(
-            "SELECT EXISTS(SELECT 1 FROM users WHERE id = $1 AND org_id = $2 AND is_active = true)",
-            br.requesting_user_id,
-            auth.org_id,
-        )
-        .fetch_one(&mut *tx)
-        .await?
-        .unwrap_or(false);
-
-        if !requester_ok {
-            return Err(AppError::Conflict(
-                "Requesting user is no longer active".into(),
-            ));
-        }
-
-        // 3b. Fetch ot_type from displaced user's assignment, then cancel it.
-        let displaced_ot_type: Option<String> = sqlx::query_scalar!(
-            r#"
-            SELECT ot_type FROM assignments
-            WHERE scheduled_shift_id = $1 AND user_id = $2
-              AND is_overtime = true AND cancelled_at IS NULL
-            "#,
-            scheduled_shift_id,
-            br.displaced_user_id,
-        )
-        .fetch_optional(&mut *tx)
-        .await?
-        .flatten();
-
-        let cancelled = sqlx::query!(
-            r#"
-            UPDATE assignments SET cancelled_at = NOW()
-            WHERE scheduled_shift_id = $1 AND user_id = $2
-              AND is_overtime = true AND cancelled_at IS NULL
-            "#,
-            scheduled_shift_id,
-            br.displaced_user_id,
-        )
-        .execute(&mut *tx)
-        .await?;
-
-        if cancelled.rows_affected() == 0 {
-            return Err(AppError::Conflict(
-                "Displaced user's OT assignment is no longer active".into(),
-            ));
-        }
-
-        // 3c. Insert new OT assignment for requester, copying ot_type from displaced.
-        let inserted = sqlx::query!(
-            r#"
-            INSERT INTO assignments (id, scheduled_shift_id, user_id, is_overtime, created_by, ot_type)
-            VALUES (gen_random_uuid(), $1, $2, true, $3, $4)
-            ON CONFLICT (scheduled_shift_id, user_id) DO NOTHING
-            "#,
-            scheduled_shift_id,
-            br.requesting_user_id,
-            auth.id,
-            displaced_ot_type,
-        )
-        .execute(&mut *tx)
-        .await?;
-
-        if inserted.rows_affected() == 0 {
-            return Err(AppError::Conflict(
-                "Requesting user already has an assignment for this shift".into(),
-            ));
-        }
-
-        // 3d. Update OT hours and queue positions for the bump swap.
-        let shift_hours = shift_check.duration_minutes as f64 / 60.0;
-        let fiscal_year = crate::services::ot::org_fiscal_year(
-            &pool, auth.org_id, shift_check.shift_date,
-        ).await;
-
-        // Revert displaced user's OT hours (they're no longer working this shift).
-        crate::services::ot::revert_ot_hours_worked(
-            &mut tx, br.displaced_user_id, fiscal_year,
-            Some(shift_check.classification_id), shift_hours,
-        ).await?;
-
-        // Credit requesting user's OT hours (they're taking over the shift).
-        crate::services::ot::upsert_ot_hours_worked(
-            &mut tx, br.requesting_user_id, fiscal_year,
-            shift_check.classification_id, shift_hours,
-        ).await?;
-
-        // Stamp requesting user's queue position (they were contacted/assigned OT).
-        crate::services::ot::stamp_ot_queue(
-            &mut tx, auth.org_id, shift_check.classification_id,
-            br.requesting_user_id, fiscal_year,
-        ).await?;
-
-        // 3e. Restore displaced user's queue position — they were bumped off, not
-        // contacted for new OT, so their last_ot_event_at should be reset.
-        sqlx::query!(
-            r#"
-            UPDATE ot_queue_positions
-            SET last_ot_event_at = NULL, updated_at = NOW()
-            WHERE user_id = $1 AND classification_id = $2 AND org_id = $3 AND fiscal_year = $4
-            "#,
-            br.displaced_user_id,
-            shift_check.classification_id,
-            auth.org_id,
-            fiscal_year,
-        )
-        .execute(&mut *tx)
-        .await?;
+    let action = if req.approved {
+        // 2. Swap the assignment (fails with Conflict if no longer possible)
+        bump::apply_approval(&pool, &mut tx, &br, &auth.org_timezone, auth.id).await?;
 
-        // 3f. Update bump request to approved
+        // 3. Update bump request to approved
         sqlx::query!(
             r#"
             UPDATE bump_requests
@@ -1173,6 +1092,7 @@ pub async fn review_bump_request(
         )
         .execute(&mut *tx)
         .await?;
+        BumpAuditAction::Approved
     } else {
         // 4. Denied
         sqlx::query!(
@@ -1187,16 +1107,21 @@ pub async fn review_bump_request(
         )
         .execute(&mut *tx)
         .await?;
-    }
+        BumpAuditAction::Denied
+    };
+
+    bump::record_audit(&mut tx, &br, action, Some(auth.id), req.reason.as_deref()).await?;
 
     tx.commit().await?;
 
+    bump::notify_outcome(&pool, &br, action).await;
+
     // 5. Return the updated bump request
     let updated = sqlx::query_as!(
         BumpRequest,
         r#"
         SELECT id, org_id, event_id, requesting_user_id, displaced_user_id,
-               status, reason, created_at, reviewed_at, reviewed_by
+               status, reason, created_at, reviewed_at, reviewed_by, expires_at, auto_resolved
         FROM bump_requests WHERE id = $1
         "#,
         id
@@ -1235,7 +1160,8 @@ pub async fn list_bump_requests(
                br.displaced_user_id,
                du.first_name AS displaced_user_first_name,
                du.last_name  AS displaced_user_last_name,
-               br.status, br.reason, br.created_at, br.reviewed_at, br.reviewed_by
+               br.status, br.reason, br.created_at, br.reviewed_at, br.reviewed_by,
+               br.expires_at, br.auto_resolved
         FROM bump_requests br
         JOIN users ru ON ru.id = br.requesting_user_id
         JOIN users du ON du.id = br.displaced_user_id
@@ -1250,3 +1176,81 @@ pub async fn list_bump_requests(
 
     Ok(Json(rows))
 }
+
+/// GET /api/callout/events/:id/bump-audit
+///
+/// Every bump outcome for the event, oldest first: submissions, supervisor
+/// reviews, and deadline expiries / auto-approvals (actor `None`).
+pub async fn list_bump_audit(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(event_id): Path<Uuid>,
+) -> Result<Json<Vec<BumpAuditEntry>>> {
+    if !auth.role.can_manage_schedule() {
+        return Err(AppError::Forbidden);
+    }
+
+    sqlx::query_scalar!(
+        "SELECT ce.id FROM callout_events ce JOIN scheduled_shifts ss ON ss.id = ce.scheduled_shift_id WHERE ce.id = $1 AND ss.org_id = $2",
+        event_id,
+        auth.org_id
+    )
+    .fetch_optional(&pool)
+    .await?
+    .ok_or_else(|| AppError::NotFound("Callout event not found".into()))?;
+
+    let rows = sqlx::query!(
+        r#"
+        SELECT a.id, a.bump_request_id, a.action AS "action: BumpAuditAction",
+               a.actor_id, (au.first_name || ' ' || au.last_name) AS actor_name,
+               (ru.first_name || ' ' || ru.last_name) AS "requesting_user_name!",
+               (du.first_name || ' ' || du.last_name) AS "displaced_user_name!",
+               a.notes, a.created_at
+        FROM bump_request_audit a
+        JOIN bump_requests br ON br.id = a.bump_request_id
+        JOIN users ru ON ru.id = br.requesting_user_id
+        JOIN users du ON du.id = br.displaced_user_id
+        LEFT JOIN users au ON au.id = a.actor_id
+        WHERE a.event_id = $1 AND a.org_id = $2
+        ORDER BY a.created_at, a.id
+        "#,
+        event_id,
+        auth.org_id,
+    )
+    .fetch_all(&pool)
+    .await?;
+
+    let entries = rows
+        .into_iter()
+        .map(|r| BumpAuditEntry {
+            id: r.id,
+            bump_request_id: r.bump_request_id,
+            action: r.action,
+            actor_id: r.actor_id,
+            actor_name: r.actor_name,
+            requesting_user_name: r.requesting_user_name,
+            displaced_user_name: r.displaced_user_name,
+            notes: r.notes,
+            created_at: r.created_at,
+        })
+        .collect();
+
+    Ok(Json(entries))
+}
+
+/// POST /api/callout/bump-requests/resolve-expired
+///
+/// Runs the bump deadline sweep for the caller's org immediately (the
+/// background task runs it every 15 minutes).
+pub async fn resolve_expired_bumps(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+) -> Result<Json<BumpSweepResult>> {
+    if !auth.role.can_manage_schedule() {
+        return Err(AppError::Forbidden);
+    }
+
+    Ok(Json(
+        bump::resolve_expired(&pool, auth.org_id, &auth.org_timezone).await?,
+    ))
+}
@@ -0,0 +1,220 @@
+use axum::{
+    extract::{Path, Query, State},
+    Json,
+};
+use serde_json::Value;
+use sqlx::PgPool;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    api::helpers::json_ok,
+    auth::AuthUser,
+    error::{AppError, Result},
+    models::callout_policy::{
+        CalloutPolicy, EffectiveCalloutPolicy, EffectiveCalloutPolicyQuery,
+        UpsertCalloutPolicy,
+    },
+    services::callout_policy,
+};
+
+async fn fetch_policy(pool: &PgPool, id: Uuid, org_id: Uuid) -> Result<CalloutPolicy> {
+    let r = sqlx::query!(
+        r#"
+        SELECT id, org_id, bargaining_unit, name, steps, created_at, updated_at
+        FROM callout_policies
+        WHERE id = $1 AND org_id = $2
+        "#,
+        id,
+        org_id,
+    )
+    .fetch_optional(pool)
+    .await?
+    .ok_or_else(|| AppError::NotFound("Callout policy not found".into()))?;
+
+    Ok(CalloutPolicy {
+        id: r.id,
+        org_id: r.org_id,
+        bargaining_unit: r.bargaining_unit,
+        name: r.name,
+        steps: callout_policy::parse_steps(r.steps)?,
+        created_at: r.created_at,
+        updated_at: r.updated_at,
+    })
+}
+
+/// GET /api/callout-policies
+pub async fn list(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+) -> Result<Json<Vec<CalloutPolicy>>> {
+    if !auth.role.can_manage_schedule() {
+        return Err(AppError::Forbidden);
+    }
+
+    let rows = sqlx::query!(
+        r#"
+        SELECT id, org_id, bargaining_unit, name, steps, created_at, updated_at
+        FROM callout_policies
+        WHERE org_id = $1
+        ORDER BY bargaining_unit NULLS FIRST
+        "#,
+        auth.org_id,
+    )
+    .fetch_all(&pool)
+    .await?;
+
+    let policies = rows
+        .into_iter()
+        .map(|r| {
+            Ok(CalloutPolicy {
+                id: r.id,
+                org_id: r.org_id,
+                bargaining_unit: r.bargaining_unit,
+                name: r.name,
+                steps: callout_policy::parse_steps(r.steps)?,
+                created_at: r.created_at,
+                updated_at: r.updated_at,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Json(policies))
+}
+
+/// PUT /api/callout-policies
+///
+/// Creates or replaces the policy for a scope: the org default when
+/// `bargaining_unit` is omitted, otherwise that bargaining unit's policy.
+pub async fn upsert(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Json(req): Json<UpsertCalloutPolicy>,
+) -> Result<Json<CalloutPolicy>> {
+    if !auth.role.is_admin() {
+        return Err(AppError::Forbidden);
+    }
+    req.validate()?;
+    callout_policy::validate_steps(&req.steps)?;
+
+    if let Some(code) = &req.bargaining_unit {
+        let exists = sqlx::query_scalar!(
+            r#"SELECT EXISTS(SELECT 1 FROM bargaining_units WHERE org_id = $1 AND code = $2) AS "exists!""#,
+            auth.org_id,
+            code,
+        )
+        .fetch_one(&pool)
+        .await?;
+        if !exists {
+            return Err(AppError::BadRequest(format!("Unknown bargaining unit '{code}'")));
+        }
+    }
+
+    let steps = serde_json::to_value(&req.steps)
+        .map_err(|e| AppError::Internal(anyhow::anyhow!("Failed to encode callout policy: {e}")))?;
+
+    let mut tx = pool.begin().await?;
+
+    let existing = sqlx::query_scalar!(
+        r#"
+        SELECT id FROM callout_policies
+        WHERE org_id = $1 AND bargaining_unit IS NOT DISTINCT FROM $2
+        FOR UPDATE
+        "#,
+        auth.org_id,
+        req.bargaining_unit,
+    )
+    .fetch_optional(&mut *tx)
+    .await?;
+
+    let id = match existing {
+        Some(id) => {
+            sqlx::query!(
+                r#"
+                UPDATE callout_policies
+                SET name = $2, steps = $3, updated_at = NOW()
+                WHERE id = $1
+                "#,
+                id,
+                req.name,
+                steps,
+            )
+            .execute(&mut *tx)
+            .await?;
+            id
+        }
+        None => {
+            sqlx::query_scalar!(
+                r#"
+                INSERT INTO callout_policies (org_id, bargaining_unit, name, steps, created_by)
+                VALUES ($1, $2, $3, $4, $5)
+                RETURNING id
+                "#,
+                auth.org_id,
+                req.bargaining_unit,
+                req.name,
+                steps,
+                auth.id,
+            )
+            .fetch_one(&mut *tx)
+            .await?
+        }
+    };
+
+    tx.commit().await?;
+
+    Ok(Json(fetch_policy(&pool, id, auth.org_id).await?))
+}
+
+/// DELETE /api/callout-policies/:id
+///
+/// Classifications covered by the deleted policy fall back to the org default
+/// (or the built-in policy).
+pub async fn delete(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(id): Path<Uuid>,
+) -> Result<Json<Value>> {
+    if !auth.role.is_admin() {
+        return Err(AppError::Forbidden);
+    }
+
+    let result = sqlx::query!(
+        "DELETE FROM callout_policies WHERE id = $1 AND org_id = $2",
+        id,
+        auth.org_id,
+    )
+    .execute(&pool)
+    .await?;
+    if result.rows_affected() == 0 {
+        return Err(AppError::NotFound("Callout policy not found".into()));
+    }
+
+    Ok(json_ok())
+}
+
+/// GET /api/callout-policies/effective?classification_id=
+pub async fn effective(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Query(params): Query<EffectiveCalloutPolicyQuery>,
+) -> Result<Json<EffectiveCalloutPolicy>> {
+    if !auth.role.can_manage_schedule() {
+        return Err(AppError::Forbidden);
+    }
+
+    let exists = sqlx::query_scalar!(
+        r#"SELECT EXISTS(SELECT 1 FROM classifications WHERE id = $1 AND org_id = $2) AS "exists!""#,
+        params.classification_id,
+        auth.org_id,
+    )
+    .fetch_one(&pool)
+    .await?;
+    if !exists {
+        return Err(AppError::NotFound("Classification not found".into()));
+    }
+
+    Ok(Json(
+        callout_policy::effective_policy(&pool, auth.org_id, params.classification_id).await?,
+    ))
+}
@@ -14,6 +14,22 @@ use crate::{
     },
 };
 
+/// Bargaining unit codes must exist in the org's `bargaining_units`.
+async fn check_bargaining_unit(pool: &PgPool, org_id: Uuid, code: Option<&str>) -> Result<()> {
+    let Some(code) = code else { return Ok(()) };
+    let exists = sqlx::query_scalar!(
+        r#"SELECT EXISTS(SELECT 1 FROM bargaining_units WHERE org_id = $1 AND code = $2) AS "exists!""#,
+        org_id,
+        code,
+    )
+    .fetch_one(pool)
+    .await?;
+    if !exists {
+        return Err(AppError::BadRequest(format!("Unknown bargaining unit '{code}'")));
+    }
+    Ok(())
+}
+
 #[derive(Debug, Deserialize)]
 pub struct ClassificationListParams {
     pub include_inactive: Option<bool>,
@@ -28,7 +44,7 @@ pub async fn list(
     let rows = sqlx::query_as!(
         Classification,
         r#"
-        SELECT id, org_id, name, abbreviation, display_order, is_active, created_at
+        SELECT id, org_id, name, abbreviation, display_order, is_active, bargaining_unit, created_at
         FROM classifications
         WHERE org_id = $1 AND ($2::bool = false OR is_active = true)
         ORDER BY display_order, name
@@ -55,19 +71,21 @@ pub async fn create(
     }
 
     let display_order = req.display_order.unwrap_or(0);
+    check_bargaining_unit(&pool, auth.org_id, req.bargaining_unit.as_deref()).await?;
 
     let row = sqlx::query_as!(
         Classification,
         r#"
-        INSERT INTO classifications (id, org_id, name, abbreviation, display_order)
-        VALUES ($1, $2, $3, $4, $5)
-        RETURNING id, org_id, name, abbreviation, display_order, is_active, created_at
+        INSERT INTO classifications (id, org_id, name, abbreviation, display_order, bargaining_unit)
+        VALUES ($1, $2, $3, $4, $5, $6)
+        RETURNING id, org_id, name, abbreviation, display_order, is_active, bargaining_unit, created_at
         "#,
         Uuid::new_v4(),
         auth.org_id,
         req.name,
         req.abbreviation,
         display_order,
+        req.bargaining_unit,
     )
     .fetch_one(&pool)
     .await?;
@@ -85,6 +103,9 @@ pub async fn update(
         return Err(AppError::Forbidden);
     }
 
+    let bargaining_unit = req.bargaining_unit.as_ref().map(|bu| bu.as_deref());
+    check_bargaining_unit(&pool, auth.org_id, bargaining_unit.flatten()).await?;
+
     let row = sqlx::query_as!(
         Classification,
         r#"
@@ -92,9 +113,10 @@ pub async fn update(
         SET name          = COALESCE($2, name),
             abbreviation  = COALESCE($3, abbreviation),
             display_order = COALESCE($4, display_order),
-            is_active     = COALESCE($5, is_active)
+            is_active     = COALESCE($5, is_active),
+            bargaining_unit = CASE WHEN $7 THEN $8 ELSE bargaining_unit END
         WHERE id = $1 AND org_id = $6
-        RETURNING id, org_id, name, abbreviation, display_order, is_active, created_at
+        RETURNING id, org_id, name, abbreviation, display_order, is_active, bargaining_unit, created_at
         "#,
         id,
         req.name,
@@ -102,6 +124,8 @@ pub async fn update(
         req.display_order,
         req.is_active,
         auth.org_id,
+        bargaining_unit.is_some(),
+        bargaining_unit.flatten(),
     )
     .fetch_optional(&pool)
     .await?
@@ -0,0 +1,416 @@
+use axum::{
+    extract::{Path, Query, State},
+    Json,
+};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{
+    api::helpers::{ensure_rows_affected, json_ok, validate_date_range},
+    auth::AuthUser,
+    error::{AppError, Result},
+    models::coverage_override::{
+        CoverageDateOverride, CoverageOverrideMode, CoverageOverrideQuery,
+        CreateCoverageOverrideRequest, CreateHolidayCoverageProfileRequest,
+        HolidayCoverageProfile, HolidayCoverageProfileSlot, ReplaceProfileSlotsRequest,
+        UpdateHolidayCoverageProfileRequest,
+    },
+    org_guard,
+};
+
+// ── Date overrides ────────────────────────────────────────────────────────────
+
+/// GET /api/coverage-overrides?start_date=&end_date=
+pub async fn list_overrides(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Query(q): Query<CoverageOverrideQuery>,
+) -> Result<Json<Vec<CoverageDateOverride>>> {
+    if !auth.role.can_manage_schedule() {
+        return Err(AppError::Forbidden);
+    }
+    validate_date_range(q.start_date, q.end_date, Some(366))?;
+
+    let rows = sqlx::query_as!(
+        CoverageDateOverride,
+        r#"
+        SELECT
+            cdo.id, cdo.org_id, cdo.date, cdo.classification_id,
+            cl.abbreviation AS classification_abbreviation,
+            cdo.start_time, cdo.end_time,
+            cdo.mode AS "mode: CoverageOverrideMode",
+            cdo.min_headcount, cdo.target_headcount, cdo.max_headcount,
+            cdo.notes, cdo.created_by, cdo.created_at
+        FROM coverage_date_overrides cdo
+        JOIN classifications cl ON cl.id = cdo.classification_id
+        WHERE cdo.org_id = $1 AND cdo.date BETWEEN $2 AND $3
+        ORDER BY cdo.date, cdo.start_time, cl.abbreviation
+        "#,
+        auth.org_id,
+        q.start_date,
+        q.end_date,
+    )
+    .fetch_all(&pool)
+    .await?;
+
+    Ok(Json(rows))
+}
+
+/// POST /api/coverage-overrides
+pub async fn create_override(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Json(req): Json<CreateCoverageOverrideRequest>,
+) -> Result<Json<CoverageDateOverride>> {
+    use validator::Validate;
+    req.validate()?;
+
+    if !auth.role.can_manage_schedule() {
+        return Err(AppError::Forbidden);
+    }
+
+    if req.start_time == req.end_time {
+        return Err(AppError::BadRequest(
+            "start_time and end_time must differ".into(),
+        ));
+    }
+    if req.mode == CoverageOverrideMode::Set {
+        if req.min_headcount < 0 || req.min_headcount > req.target_headcount {
+            return Err(AppError::BadRequest(
+                "Set overrides must have 0 <= min <= target".into(),
+            ));
+        }
+        if req.max_headcount.is_some_and(|max| max < req.target_headcount) {
+            return Err(AppError::BadRequest(
+                "Set overrides must have target <= max".into(),
+            ));
+        }
+    }
+
+    org_guard::verify_classification(&pool, req.classification_id, auth.org_id).await?;
+
+    let row = sqlx::query_as!(
+        CoverageDateOverride,
+        r#"
+        WITH ins AS (
+            INSERT INTO coverage_date_overrides
+                (org_id, date, classification_id, start_time, end_time, mode,
+                 min_headcount, target_headcount, max_headcount, notes, created_by)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+            RETURNING *
+        )
+        SELECT
+            ins.id, ins.org_id, ins.date, ins.classification_id,
+            cl.abbreviation AS classification_abbreviation,
+            ins.start_time, ins.end_time,
+            ins.mode AS "mode: CoverageOverrideMode",
+            ins.min_headcount, ins.target_headcount, ins.max_headcount,
+            ins.notes, ins.created_by, ins.created_at
+        FROM ins
+        JOIN classifications cl ON cl.id = ins.classification_id
+        "#,
+        auth.org_id,
+        req.date,
+        req.classification_id,
+        req.start_time,
+        req.end_time,
+        req.mode as CoverageOverrideMode,
+        req.min_headcount,
+        req.target_headcount,
+        req.max_headcount,
+        req.notes,
+        auth.id,
+    )
+    .fetch_one(&pool)
+    .await?;
+
+    Ok(Json(row))
+}
+
+/// DELETE /api/coverage-overrides/:id
+pub async fn delete_override(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(id): Path<Uuid>,
+) -> Result<Json<serde_json::Value>> {
+    if !auth.role.can_manage_schedule() {
+        return Err(AppError::Forbidden);
+    }
+
+    let rows = sqlx::query!(
+        "DELETE FROM coverage_date_overrides WHERE id = $1 AND org_id = $2",
+        id,
+        auth.org_id,
+    )
+    .execute(&pool)
+    .await?
+    .rows_affected();
+
+    ensure_rows_affected(rows, "Coverage override")?;
+    Ok(json_ok())
+}
+
+// ── Holiday coverage profiles ─────────────────────────────────────────────────
+
+pub async fn list_profiles(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+) -> Result<Json<Vec<HolidayCoverageProfile>>> {
+    let rows = sqlx::query_as!(
+        HolidayCoverageProfile,
+        r#"
+        SELECT id, org_id, name, description, is_default,
+               created_by, created_at, updated_at
+        FROM holiday_coverage_profiles
+        WHERE org_id = $1
+        ORDER BY is_default DESC, name
+        "#,
+        auth.org_id,
+    )
+    .fetch_all(&pool)
+    .await?;
+
+    Ok(Json(rows))
+}
+
+pub async fn create_profile(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Json(req): Json<CreateHolidayCoverageProfileRequest>,
+) -> Result<Json<HolidayCoverageProfile>> {
+    use validator::Validate;
+    req.validate()?;
+
+    if !auth.role.is_admin() {
+        return Err(AppError::Forbidden);
+    }
+
+    let mut tx = pool.begin().await?;
+
+    if req.is_default {
+        sqlx::query!(
+            "UPDATE holiday_coverage_profiles SET is_default = FALSE, updated_at = NOW()
+             WHERE org_id = $1 AND is_default = TRUE",
+            auth.org_id,
+        )
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    let row = sqlx::query_as!(
+        HolidayCoverageProfile,
+        r#"
+        INSERT INTO holiday_coverage_profiles (org_id, name, description, is_default, created_by)
+        VALUES ($1, $2, $3, $4, $5)
+        RETURNING id, org_id, name, description, is_default,
+                  created_by, created_at, updated_at
+        "#,
+        auth.org_id,
+        req.name,
+        req.description,
+        req.is_default,
+        auth.id,
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+    Ok(Json(row))
+}
+
+pub async fn update_profile(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(id): Path<Uuid>,
+    Json(req): Json<UpdateHolidayCoverageProfileRequest>,
+) -> Result<Json<HolidayCoverageProfile>> {
+    use validator::Validate;
+    req.validate()?;
+
+    if !auth.role.is_admin() {
+        return Err(AppError::Forbidden);
+    }
+
+    org_guard::verify_holiday_coverage_profile(&pool, id, auth.org_id).await?;
+
+    let mut tx = pool.begin().await?;
+
+    if req.is_default == Some(true) {
+        sqlx::query!(
+            "UPDATE holiday_coverage_profiles SET is_default = FALSE, updated_at = NOW()
+             WHERE org_id = $1 AND is_default = TRUE AND id != $2",
+            auth.org_id,
+            id,
+        )
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    let row = sqlx::query_as!(
+        HolidayCoverageProfile,
+        r#"
+        UPDATE holiday_coverage_profiles
+        SET name        = COALESCE($3, name),
+            description = COALESCE($4, description),
+            is_default  = COALESCE($5, is_default),
+            updated_at  = NOW()
+        WHERE id = $1 AND org_id = $2
+        RETURNING id, org_id, name, description, is_default,
+                  created_by, created_at, updated_at
+        "#,
+        id,
+        auth.org_id,
+        req.name,
+        req.description,
+        req.is_default,
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+    Ok(Json(row))
+}
+
+pub async fn delete_profile(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(id): Path<Uuid>,
+) -> Result<Json<serde_json::Value>> {
+    if !auth.role.is_admin() {
+        return Err(AppError::Forbidden);
+    }
+
+    let rows = sqlx::query!(
+        "DELETE FROM holiday_coverage_profiles WHERE id = $1 AND org_id = $2",
+        id,
+        auth.org_id,
+    )
+    .execute(&pool)
+    .await?
+    .rows_affected();
+
+    ensure_rows_affected(rows, "Holiday coverage profile")?;
+    Ok(json_ok())
+}
+
+pub async fn list_profile_slots(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(profile_id): Path<Uuid>,
+) -> Result<Json<Vec<HolidayCoverageProfileSlot>>> {
+    org_guard::verify_holiday_coverage_profile(&pool, profile_id, auth.org_id).await?;
+
+    let rows = sqlx::query_as!(
+        HolidayCoverageProfileSlot,
+        r#"
+        SELECT id, profile_id, classification_id, slot_index,
+               min_headcount, target_headcount, max_headcount
+        FROM holiday_coverage_profile_slots
+        WHERE profile_id = $1
+        ORDER BY classification_id, slot_index
+        "#,
+        profile_id,
+    )
+    .fetch_all(&pool)
+    .await?;
+
+    Ok(Json(rows))
+}
+
+/// POST /api/holiday-coverage-profiles/:id/slots/bulk
+///
+/// For each classification in the request, replaces that classification's
+/// slots in the profile. Classifications not in the request are untouched.
+pub async fn replace_profile_slots(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(profile_id): Path<Uuid>,
+    Json(req): Json<ReplaceProfileSlotsRequest>,
+) -> Result<Json<Vec<HolidayCoverageProfileSlot>>> {
+    if !auth.role.is_admin() {
+        return Err(AppError::Forbidden);
+    }
+
+    org_guard::verify_holiday_coverage_profile(&pool, profile_id, auth.org_id).await?;
+
+    if req.slots.is_empty() {
+        return Ok(Json(vec![]));
+    }
+
+    let mut classifications = std::collections::HashSet::new();
+    for s in &req.slots {
+        if s.slot_index < 0 || s.slot_index > 47 {
+            return Err(AppError::BadRequest(format!(
+                "slot_index {} out of range 0-47",
+                s.slot_index
+            )));
+        }
+        if s.min_headcount < 0 {
+            return Err(AppError::BadRequest(
+                "Headcounts must be non-negative".into(),
+            ));
+        }
+        if s.min_headcount > s.target_headcount || s.target_headcount > s.max_headcount {
+            return Err(AppError::BadRequest(
+                "Must have min <= target <= max for all slots".into(),
+            ));
+        }
+        classifications.insert(s.classification_id);
+    }
+
+    for class_id in &classifications {
+        org_guard::verify_classification(&pool, *class_id, auth.org_id).await?;
+    }
+
+    let class_list: Vec<Uuid> = classifications.into_iter().collect();
+    let class_ids: Vec<Uuid> = req.slots.iter().map(|s| s.classification_id).collect();
+    let slot_idxs: Vec<i16> = req.slots.iter().map(|s| s.slot_index).collect();
+    let mins: Vec<i16> = req.slots.iter().map(|s| s.min_headcount).collect();
+    let targets: Vec<i16> = req.slots.iter().map(|s| s.target_headcount).collect();
+    let maxes: Vec<i16> = req.slots.iter().map(|s| s.max_headcount).collect();
+
+    let mut tx = pool.begin().await?;
+
+    sqlx::query!(
+        "DELETE FROM holiday_coverage_profile_slots
+         WHERE profile_id = $1 AND classification_id = ANY($2)",
+        profile_id,
+        &class_list,
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    let rows = sqlx::query_as!(
+        HolidayCoverageProfileSlot,
+        r#"
+        INSERT INTO holiday_coverage_profile_slots
+            (profile_id, classification_id, slot_index,
+             min_headcount, target_headcount, max_headcount)
+        SELECT $1, * FROM UNNEST(
+            $2::uuid[], $3::smallint[],
+            $4::smallint[], $5::smallint[], $6::smallint[]
+        )
+        RETURNING id, profile_id, classification_id, slot_index,
+                  min_headcount, target_headcount, max_headcount
+        "#,
+        profile_id,
+        &class_ids,
+        &slot_idxs,
+        &mins,
+        &targets,
+        &maxes,
+    )
+    .fetch_all(&mut *tx)
+    .await?;
+
+    sqlx::query!(
+        "UPDATE holiday_coverage_profiles SET updated_at = NOW() WHERE id = $1",
+        profile_id,
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(Json(rows))
+}
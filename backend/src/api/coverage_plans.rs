@@ -10,12 +10,16 @@ use crate::{
     auth::AuthUser,
     error::{AppError, Result},
     models::schedule::{
-        BlockEmployee, BulkUpsertSlotsRequest, ClassificationBlock, ClassificationGap,
-        CoverageBlock, CoveragePlan, CoveragePlanAssignment, CoveragePlanSlot, CoveragePlanView,
+        ApplyStaffingRequest, BlockEmployee, BulkUpsertQualificationSlotsRequest, BulkUpsertSlotsRequest,
+        ClassificationBlock, ClassificationGap, ClassificationGapBlocks, CoverageBlock, CoverageGapBlock,
+        CoveragePlan, CoveragePlanAssignment, CoveragePlanQualificationSlot, CoveragePlanSlot, CoveragePlanView,
         CreateCoveragePlanAssignmentRequest, CreateCoveragePlanRequest, DayGridClassification,
-        DayGridResponse, SlotCoverage, UpdateCoveragePlanRequest,
+        DayGridQualification, DayGridResponse, QualificationBlock, QualificationGapBlocks,
+        QualificationSlotCoverage, SlotCoverage, UpdateCoveragePlanRequest,
     },
+    models::{call_volume::CallForecastDay, coverage_override::CoverageSource},
     org_guard,
+    services::{call_forecast, coverage_layers},
     AppState,
 };
 
@@ -389,6 +393,262 @@ pub async fn bulk_upsert_slots(
     Ok(Json(rows))
 }
 
+/// POST /api/coverage-plans/:id/slots/apply-staffing
+///
+/// Upserts min/target headcounts for one classification on each selected
+/// weekday, leaving other slots untouched. Max is raised to the target when needed.
+pub async fn apply_staffing(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(plan_id): Path<Uuid>,
+    Json(req): Json<ApplyStaffingRequest>,
+) -> Result<Json<Vec<CoveragePlanSlot>>> {
+    if !auth.role.can_manage_schedule() {
+        return Err(AppError::Forbidden);
+    }
+
+    org_guard::verify_coverage_plan(&pool, plan_id, auth.org_id).await?;
+    org_guard::verify_classification(&pool, req.classification_id, auth.org_id).await?;
+
+    if req.days_of_week.is_empty() || req.slots.is_empty() {
+        return Err(AppError::BadRequest(
+            "days_of_week and slots must not be empty".into(),
+        ));
+    }
+    if let Some(dow) = req.days_of_week.iter().find(|d| !(0..=6).contains(*d)) {
+        return Err(AppError::BadRequest(format!(
+            "day_of_week {} out of range 0-6",
+            dow
+        )));
+    }
+    let mut seen = std::collections::HashSet::new();
+    for s in &req.slots {
+        if !(0..=47).contains(&s.slot_index) {
+            return Err(AppError::BadRequest(format!(
+                "slot_index {} out of range 0-47",
+                s.slot_index
+            )));
+        }
+        if s.min_headcount < 0 || s.min_headcount > s.target_headcount {
+            return Err(AppError::BadRequest(
+                "Must have 0 <= min <= target for all slots".into(),
+            ));
+        }
+        if !seen.insert(s.slot_index) {
+            return Err(AppError::BadRequest(format!(
+                "Duplicate slot_index {}",
+                s.slot_index
+            )));
+        }
+    }
+
+    let days: std::collections::BTreeSet<i16> = req.days_of_week.iter().copied().collect();
+    let mut dows = Vec::new();
+    let mut slot_idxs = Vec::new();
+    let mut mins = Vec::new();
+    let mut targets = Vec::new();
+    for dow in &days {
+        for s in &req.slots {
+            dows.push(*dow);
+            slot_idxs.push(s.slot_index);
+            mins.push(s.min_headcount);
+            targets.push(s.target_headcount);
+        }
+    }
+
+    let rows = sqlx::query_as!(
+        CoveragePlanSlot,
+        r#"
+        INSERT INTO coverage_plan_slots
+            (plan_id, classification_id, day_of_week, slot_index,
+             min_headcount, target_headcount, max_headcount)
+        SELECT $1, $2, u.dow, u.slot, u.min_hc, u.target_hc, u.target_hc
+        FROM UNNEST($3::smallint[], $4::smallint[], $5::smallint[], $6::smallint[])
+            AS u(dow, slot, min_hc, target_hc)
+        ON CONFLICT (plan_id, classification_id, day_of_week, slot_index) DO UPDATE
+        SET min_headcount    = EXCLUDED.min_headcount,
+            target_headcount = EXCLUDED.target_headcount,
+            max_headcount    = GREATEST(coverage_plan_slots.max_headcount, EXCLUDED.target_headcount)
+        RETURNING id, plan_id, classification_id, day_of_week, slot_index,
+                  min_headcount, target_headcount, max_headcount
+        "#,
+        plan_id,
+        req.classification_id,
+        &dows,
+        &slot_idxs,
+        &mins,
+        &targets,
+    )
+    .fetch_all(&pool)
+    .await?;
+
+    sqlx::query!(
+        "UPDATE coverage_plans SET updated_at = NOW() WHERE id = $1",
+        plan_id,
+    )
+    .execute(&pool)
+    .await?;
+
+    Ok(Json(rows))
+}
+
+// ── Qualification Requirements ────────────────────────────────────────────────
+
+#[derive(Debug, serde::Deserialize)]
+pub struct QualificationSlotQuery {
+    pub qualification_id: Option<Uuid>,
+    pub day_of_week: Option<i16>,
+}
+
+async fn fetch_qualification_slots(
+    pool: &PgPool,
+    plan_id: Uuid,
+    q: &QualificationSlotQuery,
+) -> Result<Vec<CoveragePlanQualificationSlot>> {
+    let rows = sqlx::query_as!(
+        CoveragePlanQualificationSlot,
+        r#"
+        SELECT qs.id, qs.plan_id, qs.qualification_id, q.name AS qualification_name,
+               qs.day_of_week, qs.slot_index, qs.min_count
+        FROM coverage_plan_qualification_slots qs
+        JOIN qualifications q ON q.id = qs.qualification_id
+        WHERE qs.plan_id = $1
+          AND ($2::uuid IS NULL OR qs.qualification_id = $2)
+          AND ($3::smallint IS NULL OR qs.day_of_week = $3)
+        ORDER BY qs.day_of_week, q.name, qs.slot_index
+        "#,
+        plan_id,
+        q.qualification_id,
+        q.day_of_week,
+    )
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+/// GET /api/coverage-plans/:id/qualification-slots?qualification_id=&day_of_week=
+pub async fn list_qualification_slots(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(plan_id): Path<Uuid>,
+    Query(q): Query<QualificationSlotQuery>,
+) -> Result<Json<Vec<CoveragePlanQualificationSlot>>> {
+    org_guard::verify_coverage_plan(&pool, plan_id, auth.org_id).await?;
+    Ok(Json(fetch_qualification_slots(&pool, plan_id, &q).await?))
+}
+
+/// POST /api/coverage-plans/:id/qualification-slots/bulk
+///
+/// Same replace semantics as `slots/bulk`: each distinct (qualification_id,
+/// day_of_week) group in the request replaces the stored rows for that group.
+/// A `min_count` of 0 clears the slot.
+pub async fn bulk_upsert_qualification_slots(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(plan_id): Path<Uuid>,
+    Json(req): Json<BulkUpsertQualificationSlotsRequest>,
+) -> Result<Json<Vec<CoveragePlanQualificationSlot>>> {
+    if !auth.role.is_admin() {
+        return Err(AppError::Forbidden);
+    }
+
+    org_guard::verify_coverage_plan(&pool, plan_id, auth.org_id).await?;
+
+    let mut seen_qualifications = std::collections::HashSet::new();
+    let mut seen_slots = std::collections::HashSet::new();
+    for s in &req.slots {
+        if !(0..=47).contains(&s.slot_index) {
+            return Err(AppError::BadRequest(format!(
+                "slot_index {} out of range 0-47",
+                s.slot_index
+            )));
+        }
+        if !(0..=6).contains(&s.day_of_week) {
+            return Err(AppError::BadRequest(format!(
+                "day_of_week {} out of range 0-6",
+                s.day_of_week
+            )));
+        }
+        if s.min_count < 0 {
+            return Err(AppError::BadRequest("min_count must be non-negative".into()));
+        }
+        if !seen_slots.insert((s.qualification_id, s.day_of_week, s.slot_index)) {
+            return Err(AppError::BadRequest(format!(
+                "Duplicate slot {} on day {}",
+                s.slot_index, s.day_of_week
+            )));
+        }
+        seen_qualifications.insert(s.qualification_id);
+    }
+
+    for qual_id in &seen_qualifications {
+        org_guard::verify_qualification(&pool, *qual_id, auth.org_id).await?;
+    }
+
+    let groups: std::collections::HashSet<(Uuid, i16)> = req
+        .slots
+        .iter()
+        .map(|s| (s.qualification_id, s.day_of_week))
+        .collect();
+    let kept: Vec<_> = req.slots.iter().filter(|s| s.min_count > 0).collect();
+
+    let mut tx = pool.begin().await?;
+
+    for (qual_id, dow) in &groups {
+        sqlx::query!(
+            "DELETE FROM coverage_plan_qualification_slots
+             WHERE plan_id = $1 AND qualification_id = $2 AND day_of_week = $3",
+            plan_id,
+            qual_id,
+            *dow,
+        )
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    let qual_ids: Vec<Uuid> = kept.iter().map(|s| s.qualification_id).collect();
+    let dows: Vec<i16> = kept.iter().map(|s| s.day_of_week).collect();
+    let slot_idxs: Vec<i16> = kept.iter().map(|s| s.slot_index).collect();
+    let mins: Vec<i16> = kept.iter().map(|s| s.min_count).collect();
+
+    sqlx::query!(
+        r#"
+        INSERT INTO coverage_plan_qualification_slots
+            (plan_id, qualification_id, day_of_week, slot_index, min_count)
+        SELECT $1, u.qual, u.dow, u.slot, u.min_count
+        FROM UNNEST($2::uuid[], $3::smallint[], $4::smallint[], $5::smallint[])
+            AS u(qual, dow, slot, min_count)
+        "#,
+        plan_id,
+        &qual_ids,
+        &dows,
+        &slot_idxs,
+        &mins,
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query!(
+        "UPDATE coverage_plans SET updated_at = NOW() WHERE id = $1",
+        plan_id,
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    let rows = fetch_qualification_slots(
+        &pool,
+        plan_id,
+        &QualificationSlotQuery {
+            qualification_id: None,
+            day_of_week: None,
+        },
+    )
+    .await?;
+    Ok(Json(rows))
+}
+
 // ── Plan Assignments (date ranges) ────────────────────────────────────────────
 
 pub async fn list_assignments(
@@ -479,341 +739,116 @@ pub async fn delete_assignment(
 ///
 /// Returns per-slot coverage for the given date using the active plan.
 /// Includes actual headcount computed from shift assignments.
-pub async fn resolved_coverage(
-    State(pool): State<PgPool>,
-    auth: AuthUser,
-    Path(date_str): Path<String>,
-) -> Result<Json<Vec<SlotCoverage>>> {
-    let result = compute_slot_coverage(&pool, auth.org_id, &date_str).await?;
-    Ok(Json(result))
-}
-
-/// Shared logic for computing per-slot coverage. Used by the resolved endpoint
-/// and can be called from schedule.rs handlers.
-pub(crate) async fn compute_slot_coverage(
-    pool: &PgPool,
-    org_id: Uuid,
-    date_str: &str,
-) -> Result<Vec<SlotCoverage>> {
-    let date = parse_date(date_str)?;
-
-    let plan_id = match resolve_plan_id(pool, org_id, date).await? {
-        Some(id) => id,
-        None => return Ok(vec![]),
-    };
-
-    let dow = date.weekday().number_days_from_sunday() as i16;
-
-    // Fetch slot requirements for this plan + day of week
-    let slots = sqlx::query!(
-        r#"
-        SELECT
-            cps.slot_index,
-            cps.classification_id,
-            cl.abbreviation AS classification_abbreviation,
-            cps.min_headcount,
-            cps.target_headcount,
-            cps.max_headcount
-        FROM coverage_plan_slots cps
-        JOIN classifications cl ON cl.id = cps.classification_id
-        WHERE cps.plan_id = $1 AND cps.day_of_week = $2
-        ORDER BY cps.classification_id, cps.slot_index
-        "#,
-        plan_id,
-        dow,
-    )
-    .fetch_all(pool)
-    .await?;
-
-    if slots.is_empty() {
-        return Ok(vec![]);
-    }
-
-    // Fetch all active shift assignments for this date
-    let assignments = sqlx::query!(
-        r#"
-        SELECT
-            a.user_id,
-            u.classification_id AS "classification_id?",
-            st.start_time,
-            st.end_time,
-            st.crosses_midnight
-        FROM assignments a
-        JOIN scheduled_shifts ss ON ss.id = a.scheduled_shift_id
-        JOIN shift_templates  st ON st.id = ss.shift_template_id
-        JOIN users            u  ON u.id  = a.user_id
-        WHERE ss.org_id = $1 AND ss.date = $2
-          AND a.cancelled_at IS NULL
-        "#,
-        org_id,
-        date,
-    )
-    .fetch_all(pool)
-    .await?;
-
-    // Fetch overnight shifts from the previous day that spill into this date
-    let prev_date = date.previous_day().unwrap_or(date);
-    let overnight = sqlx::query!(
-        r#"
-        SELECT
-            a.user_id,
-            u.classification_id AS "classification_id?",
-            st.end_time
-        FROM assignments a
-        JOIN scheduled_shifts ss ON ss.id = a.scheduled_shift_id
-        JOIN shift_templates  st ON st.id = ss.shift_template_id
-        JOIN users            u  ON u.id  = a.user_id
-        WHERE ss.org_id = $1 AND ss.date = $2
-          AND st.crosses_midnight = true
-          AND a.cancelled_at IS NULL
-        "#,
-        org_id,
-        prev_date,
-    )
-    .fetch_all(pool)
-    .await?;
-
-    // Build actual headcount map: (classification_id, slot_index) -> count
-    use std::collections::{HashMap, HashSet};
-    let mut actual: HashMap<(Uuid, i16), i32> = HashMap::new();
-    // Track which (user_id, classification_id, slot) are covered by regular assignments
-    // so OT doesn't double-count the same person.
-    let mut user_slot_covered: HashSet<(Uuid, Uuid, i16)> = HashSet::new();
-
-    for a in &assignments {
-        let Some(class_id) = a.classification_id else {
-            continue;
-        };
-        let (start_slot, end_slot) = time_to_slot_range(a.start_time, a.end_time, a.crosses_midnight);
-        for slot in start_slot..=end_slot {
-            *actual.entry((class_id, slot)).or_insert(0) += 1;
-            user_slot_covered.insert((a.user_id, class_id, slot));
-        }
-    }
-
-    // Add overnight shift morning contributions (00:00 to end_time on this date)
-    for a in &overnight {
-        let Some(class_id) = a.classification_id else {
-            continue;
-        };
-        let Some(end_slot) = overnight_end_slot(a.end_time) else {
-            continue;
-        };
-        for slot in 0..=end_slot {
-            *actual.entry((class_id, slot)).or_insert(0) += 1;
-            user_slot_covered.insert((a.user_id, class_id, slot));
-        }
-    }
-
-    // Add active OT request assignments (mandatory OT, voluntary OT, etc.)
-    // These live in ot_request_assignments → ot_requests, separate from regular assignments.
-    let ot_assignments = sqlx::query!(
-        r#"
-        SELECT
-            ora.user_id,
-            otr.classification_id,
-            otr.start_time,
-            otr.end_time
-        FROM ot_request_assignments ora
-        JOIN ot_requests otr ON otr.id = ora.ot_request_id
-        WHERE otr.org_id = $1 AND otr.date = $2
-          AND ora.cancelled_at IS NULL
-          AND otr.status != 'cancelled'
-        "#,
-        org_id,
-        date,
-    )
-    .fetch_all(pool)
-    .await?;
-
-    for ot in &ot_assignments {
-        let crosses_midnight = ot.end_time < ot.start_time;
-        let (start_slot, end_slot) = time_to_slot_range(ot.start_time, ot.end_time, crosses_midnight);
-        for slot in start_slot..=end_slot {
-            if !user_slot_covered.contains(&(ot.user_id, ot.classification_id, slot)) {
-                *actual.entry((ot.classification_id, slot)).or_insert(0) += 1;
-            }
-        }
-    }
-
-    // Also count OT that crosses midnight from the previous day into today's early slots
-    let ot_overnight = sqlx::query!(
-        r#"
-        SELECT
-            ora.user_id,
-            otr.classification_id,
-            otr.end_time
-        FROM ot_request_assignments ora
-        JOIN ot_requests otr ON otr.id = ora.ot_request_id
-        WHERE otr.org_id = $1 AND otr.date = $2
-          AND otr.end_time < otr.start_time
-          AND ora.cancelled_at IS NULL
-          AND otr.status != 'cancelled'
-        "#,
-        org_id,
-        prev_date,
-    )
-    .fetch_all(pool)
-    .await?;
-
-    for ot in &ot_overnight {
-        let Some(end_slot) = overnight_end_slot(ot.end_time) else {
-            continue;
-        };
-        for slot in 0..=end_slot {
-            if !user_slot_covered.contains(&(ot.user_id, ot.classification_id, slot)) {
-                *actual.entry((ot.classification_id, slot)).or_insert(0) += 1;
-            }
-        }
-    }
-
-    let result = slots
-        .into_iter()
-        .map(|s| {
-            let count = actual
-                .get(&(s.classification_id, s.slot_index))
-                .copied()
-                .unwrap_or(0);
-            let status = if s.min_headcount == 0 || count >= s.min_headcount as i32 {
-                "green"
-            } else {
-                "red"
-            };
-            SlotCoverage {
-                slot_index: s.slot_index,
-                classification_id: s.classification_id,
-                classification_abbreviation: s.classification_abbreviation,
-                min_headcount: s.min_headcount,
-                target_headcount: s.target_headcount,
-                max_headcount: s.max_headcount,
-                actual_headcount: count,
-                status: status.to_string(),
-            }
-        })
-        .collect();
+pub async fn resolved_coverage(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(date_str): Path<String>,
+) -> Result<Json<Vec<SlotCoverage>>> {
+    let result = compute_slot_coverage(&pool, auth.org_id, &date_str).await?;
+    Ok(Json(result))
+}
 
-    Ok(result)
+/// Shared logic for computing per-slot coverage. Used by the resolved endpoint
+/// and can be called from schedule.rs handlers.
+pub(crate) async fn compute_slot_coverage(
+    pool: &PgPool,
+    org_id: Uuid,
+    date_str: &str,
+) -> Result<Vec<SlotCoverage>> {
+    let date = parse_date(date_str)?;
+    let mut by_date = compute_slot_coverage_batch(pool, org_id, &[date]).await?;
+    Ok(by_date.remove(&date).unwrap_or_default())
 }
 
 /// Batch version of `compute_slot_coverage` for multiple dates.
 ///
 /// Reduces N×6 serial DB round-trips (one `compute_slot_coverage` call per date)
-/// to ~7 queries total by fetching coverage plan data and assignments for the
+/// to a fixed number of queries by fetching requirements and assignments for the
 /// entire date range at once, then computing per-date slot coverage in memory.
 ///
-/// Returns a map of date → SlotCoverage vec (dates with no coverage plan are absent).
+/// Returns a map of date → SlotCoverage vec (dates with no requirements are absent).
 pub(crate) async fn compute_slot_coverage_batch(
     pool: &PgPool,
     org_id: Uuid,
     dates: &[time::Date],
 ) -> Result<std::collections::HashMap<time::Date, Vec<SlotCoverage>>> {
-    use std::collections::{HashMap, HashSet};
-
     if dates.is_empty() {
-        return Ok(HashMap::new());
+        return Ok(std::collections::HashMap::new());
     }
+    let inputs = load_coverage_inputs(pool, org_id, dates).await?;
+    Ok(inputs.slot_coverage(dates))
+}
 
-    let min_date = *dates.iter().min().unwrap();
-    let max_date = *dates.iter().max().unwrap();
-    let prev_min = min_date.previous_day().unwrap_or(min_date);
-    let prev_max = max_date.previous_day().unwrap_or(max_date);
-
-    // 1. Resolve plan_id for each date — fetch all assignments covering the range.
-    let plan_assignments = sqlx::query!(
-        r#"
-        SELECT cpa.plan_id, cpa.start_date, cpa.end_date
-        FROM coverage_plan_assignments cpa
-        JOIN coverage_plans cp ON cp.id = cpa.plan_id
-        WHERE cpa.org_id = $1
-          AND cpa.start_date <= $3
-          AND (cpa.end_date IS NULL OR cpa.end_date >= $2)
-          AND cp.is_active = TRUE
-        ORDER BY cpa.start_date DESC
-        "#,
-        org_id,
-        min_date,
-        max_date,
-    )
-    .fetch_all(pool)
-    .await?;
-
-    let default_plan_id = sqlx::query_scalar!(
-        r#"
-        SELECT id AS "id!"
-        FROM coverage_plans
-        WHERE org_id = $1 AND is_default = TRUE AND is_active = TRUE
-        LIMIT 1
-        "#,
-        org_id,
-    )
-    .fetch_optional(pool)
-    .await?;
+/// One staffed shift (regular assignment or OT) that counts toward coverage.
+#[derive(Debug, Clone)]
+pub(crate) struct StaffedShift {
+    /// Date the shift starts on; cross-midnight shifts also count the next morning.
+    pub date: time::Date,
+    pub user_id: Uuid,
+    pub classification_id: Option<Uuid>,
+    pub start_time: time::Time,
+    pub end_time: time::Time,
+    pub crosses_midnight: bool,
+    pub is_ot: bool,
+}
 
-    // Map each date to its plan_id (first matching assignment wins due to ORDER BY start_date DESC)
-    let mut plan_per_date: HashMap<time::Date, Uuid> = HashMap::new();
-    for &d in dates {
-        if let Some(pa) = plan_assignments
-            .iter()
-            .find(|pa| pa.start_date <= d && pa.end_date.map_or(true, |ed| ed >= d))
-        {
-            plan_per_date.insert(d, pa.plan_id);
-        } else if let Some(id) = default_plan_id {
-            plan_per_date.insert(d, id);
+impl StaffedShift {
+    /// Slot range this shift covers on `date`, including the morning spill of
+    /// an overnight shift that started the day before.
+    fn slots_on(&self, date: time::Date) -> Option<(i16, i16)> {
+        if self.date == date {
+            Some(time_to_slot_range(self.start_time, self.end_time, self.crosses_midnight))
+        } else if Some(self.date) == date.previous_day() && self.crosses_midnight {
+            overnight_end_slot(self.end_time).map(|end| (0, end))
+        } else {
+            None
         }
     }
+}
 
-    if plan_per_date.is_empty() {
-        return Ok(HashMap::new());
-    }
-
-    // 2. Collect unique plan_ids and day-of-week values needed
-    let needed_plan_ids: Vec<Uuid> = plan_per_date
-        .values()
-        .copied()
-        .collect::<HashSet<Uuid>>()
-        .into_iter()
-        .collect();
-    let needed_dows: Vec<i16> = dates
-        .iter()
-        .map(|d| d.weekday().number_days_from_sunday() as i16)
-        .collect::<HashSet<i16>>()
-        .into_iter()
-        .collect();
+/// Everything needed to compute slot coverage for a set of dates, loaded once
+/// so callers (e.g. the what-if simulator) can adjust it in memory.
+#[derive(Debug, Clone)]
+pub(crate) struct CoverageInputs {
+    pub requirements: std::collections::HashMap<time::Date, Vec<coverage_layers::RequiredSlot>>,
+    pub qualification_requirements:
+        std::collections::HashMap<time::Date, Vec<coverage_layers::RequiredQualificationSlot>>,
+    /// Shifts starting between the day before the earliest date and the latest date.
+    pub shifts: Vec<StaffedShift>,
+    /// Qualifications held, with expiry; only loaded when qualification requirements exist.
+    pub user_qualifications: coverage_layers::HeldQualifications,
+}
 
-    // 3. Fetch coverage plan slots for all needed (plan_id, day_of_week) combinations
-    let slot_rows = sqlx::query!(
-        r#"
-        SELECT
-            cps.plan_id,
-            cps.slot_index,
-            cps.day_of_week,
-            cps.classification_id,
-            cl.abbreviation AS classification_abbreviation,
-            cps.min_headcount,
-            cps.target_headcount,
-            cps.max_headcount
-        FROM coverage_plan_slots cps
-        JOIN classifications cl ON cl.id = cps.classification_id
-        WHERE cps.plan_id = ANY($1)
-          AND cps.day_of_week = ANY($2)
-        ORDER BY cps.classification_id, cps.slot_index
-        "#,
-        &needed_plan_ids as &[Uuid],
-        &needed_dows as &[i16],
-    )
-    .fetch_all(pool)
-    .await?;
+pub(crate) async fn load_coverage_inputs(
+    pool: &PgPool,
+    org_id: Uuid,
+    dates: &[time::Date],
+) -> Result<CoverageInputs> {
+    let Some(&min_date) = dates.iter().min() else {
+        return Ok(CoverageInputs {
+            requirements: Default::default(),
+            qualification_requirements: Default::default(),
+            shifts: vec![],
+            user_qualifications: Default::default(),
+        });
+    };
+    let max_date = *dates.iter().max().unwrap();
+    let prev_min = min_date.previous_day().unwrap_or(min_date);
 
-    // Build slot lookup: (plan_id, day_of_week) → rows
-    let mut slots_by_plan_dow: HashMap<(Uuid, i16), Vec<_>> = HashMap::new();
-    for s in &slot_rows {
-        slots_by_plan_dow
-            .entry((s.plan_id, s.day_of_week))
-            .or_default()
-            .push(s);
+    // Layered requirements (plan → holiday profile → date overrides)
+    let requirements = coverage_layers::resolve_requirements(pool, org_id, dates).await?;
+    let qualification_requirements =
+        coverage_layers::resolve_qualification_requirements(pool, org_id, dates).await?;
+    if requirements.is_empty() && qualification_requirements.is_empty() {
+        return Ok(CoverageInputs {
+            requirements,
+            qualification_requirements,
+            shifts: vec![],
+            user_qualifications: Default::default(),
+        });
     }
 
-    // 4. Fetch all regular assignments for the date range
+    // Regular assignments, including the previous day for overnight spill
     let assignment_rows = sqlx::query!(
         r#"
         SELECT
@@ -831,36 +866,13 @@ pub(crate) async fn compute_slot_coverage_batch(
           AND a.cancelled_at IS NULL
         "#,
         org_id,
-        min_date,
-        max_date,
-    )
-    .fetch_all(pool)
-    .await?;
-
-    // 5. Fetch overnight assignments from the previous-day range that cross midnight
-    let overnight_rows = sqlx::query!(
-        r#"
-        SELECT
-            ss.date AS assign_date,
-            a.user_id,
-            u.classification_id AS "classification_id?",
-            st.end_time
-        FROM assignments a
-        JOIN scheduled_shifts ss ON ss.id = a.scheduled_shift_id
-        JOIN shift_templates  st ON st.id = ss.shift_template_id
-        JOIN users            u  ON u.id  = a.user_id
-        WHERE ss.org_id = $1 AND ss.date BETWEEN $2 AND $3
-          AND st.crosses_midnight = true
-          AND a.cancelled_at IS NULL
-        "#,
-        org_id,
         prev_min,
-        prev_max,
+        max_date,
     )
     .fetch_all(pool)
     .await?;
 
-    // 6. Fetch OT request assignments for the date range
+    // OT request assignments over the same range
     let ot_rows = sqlx::query!(
         r#"
         SELECT
@@ -876,161 +888,170 @@ pub(crate) async fn compute_slot_coverage_batch(
           AND otr.status != 'cancelled'
         "#,
         org_id,
-        min_date,
-        max_date,
-    )
-    .fetch_all(pool)
-    .await?;
-
-    // 7. Fetch OT overnight (cross-midnight OT from previous-day range)
-    let ot_overnight_rows = sqlx::query!(
-        r#"
-        SELECT
-            otr.date AS assign_date,
-            ora.user_id,
-            otr.classification_id,
-            otr.end_time
-        FROM ot_request_assignments ora
-        JOIN ot_requests otr ON otr.id = ora.ot_request_id
-        WHERE otr.org_id = $1 AND otr.date BETWEEN $2 AND $3
-          AND otr.end_time < otr.start_time
-          AND ora.cancelled_at IS NULL
-          AND otr.status != 'cancelled'
-        "#,
-        org_id,
         prev_min,
-        prev_max,
+        max_date,
     )
     .fetch_all(pool)
     .await?;
 
-    // Group pre-fetched data by date for O(1) per-date lookup
-    let mut assignments_by_date: HashMap<time::Date, Vec<_>> = HashMap::new();
-    for a in &assignment_rows {
-        assignments_by_date.entry(a.date).or_default().push(a);
-    }
+    let mut shifts: Vec<StaffedShift> = assignment_rows
+        .into_iter()
+        .map(|a| StaffedShift {
+            date: a.date,
+            user_id: a.user_id,
+            classification_id: a.classification_id,
+            start_time: a.start_time,
+            end_time: a.end_time,
+            crosses_midnight: a.crosses_midnight,
+            is_ot: false,
+        })
+        .collect();
+    shifts.extend(ot_rows.into_iter().map(|a| StaffedShift {
+        date: a.date,
+        user_id: a.user_id,
+        classification_id: Some(a.classification_id),
+        start_time: a.start_time,
+        end_time: a.end_time,
+        crosses_midnight: a.end_time < a.start_time,
+        is_ot: true,
+    }));
+
+    let user_qualifications = if qualification_requirements.is_empty() {
+        Default::default()
+    } else {
+        coverage_layers::user_qualifications(pool, org_id).await?
+    };
 
-    // overnight rows: indexed by target date (assign_date + 1 day = the date they spill into)
-    let mut overnight_by_target: HashMap<time::Date, Vec<_>> = HashMap::new();
-    for a in &overnight_rows {
-        let target = a.assign_date.next_day().unwrap_or(a.assign_date);
-        overnight_by_target.entry(target).or_default().push(a);
-    }
+    Ok(CoverageInputs {
+        requirements,
+        qualification_requirements,
+        shifts,
+        user_qualifications,
+    })
+}
 
-    let mut ot_by_date: HashMap<time::Date, Vec<_>> = HashMap::new();
-    for a in &ot_rows {
-        ot_by_date.entry(a.date).or_default().push(a);
-    }
+impl CoverageInputs {
+    /// Per-date slot coverage. OT only counts where the same user isn't already
+    /// covering that classification/slot on a regular shift.
+    pub(crate) fn slot_coverage(
+        &self,
+        dates: &[time::Date],
+    ) -> std::collections::HashMap<time::Date, Vec<SlotCoverage>> {
+        use std::collections::{HashMap, HashSet};
 
-    let mut ot_overnight_by_target: HashMap<time::Date, Vec<_>> = HashMap::new();
-    for a in &ot_overnight_rows {
-        let target = a.assign_date.next_day().unwrap_or(a.assign_date);
-        ot_overnight_by_target.entry(target).or_default().push(a);
-    }
+        let mut result: HashMap<time::Date, Vec<SlotCoverage>> = HashMap::new();
 
-    // Compute per-date slot coverage using the same logic as compute_slot_coverage
-    let mut result: HashMap<time::Date, Vec<SlotCoverage>> = HashMap::new();
+        for &d in dates {
+            let Some(slots) = self.requirements.get(&d) else {
+                continue;
+            };
 
-    for &d in dates {
-        let plan_id = match plan_per_date.get(&d) {
-            Some(&id) => id,
-            None => continue,
-        };
-        let dow = d.weekday().number_days_from_sunday() as i16;
-        let slots = match slots_by_plan_dow.get(&(plan_id, dow)) {
-            Some(s) if !s.is_empty() => s,
-            _ => continue,
-        };
+            // Slot ranges each shift contributes to date `d`
+            let spans = |ot: bool| {
+                self.shifts.iter().filter(move |s| s.is_ot == ot).filter_map(move |s| {
+                    let class_id = s.classification_id?;
+                    let (start, end) = s.slots_on(d)?;
+                    Some((s.user_id, class_id, start, end))
+                })
+            };
 
-        let mut actual: HashMap<(Uuid, i16), i32> = HashMap::new();
-        let mut user_slot_covered: HashSet<(Uuid, Uuid, i16)> = HashSet::new();
+            let mut actual: HashMap<(Uuid, i16), i32> = HashMap::new();
+            let mut user_slot_covered: HashSet<(Uuid, Uuid, i16)> = HashSet::new();
 
-        // Regular assignments for this date
-        if let Some(day_assigns) = assignments_by_date.get(&d) {
-            for a in day_assigns.iter() {
-                let Some(class_id) = a.classification_id else {
-                    continue;
-                };
-                let (start_slot, end_slot) = time_to_slot_range(a.start_time, a.end_time, a.crosses_midnight);
-                for slot in start_slot..=end_slot {
+            for (user_id, class_id, start, end) in spans(false) {
+                for slot in start..=end {
                     *actual.entry((class_id, slot)).or_insert(0) += 1;
-                    user_slot_covered.insert((a.user_id, class_id, slot));
+                    user_slot_covered.insert((user_id, class_id, slot));
                 }
             }
-        }
-
-        // Overnight assignments from previous day
-        if let Some(overnight) = overnight_by_target.get(&d) {
-            for a in overnight.iter() {
-                let Some(class_id) = a.classification_id else {
-                    continue;
-                };
-                let Some(end_slot) = overnight_end_slot(a.end_time) else {
-                    continue;
-                };
-                for slot in 0..=end_slot {
-                    *actual.entry((class_id, slot)).or_insert(0) += 1;
-                    user_slot_covered.insert((a.user_id, class_id, slot));
+            for (user_id, class_id, start, end) in spans(true) {
+                for slot in start..=end {
+                    if !user_slot_covered.contains(&(user_id, class_id, slot)) {
+                        *actual.entry((class_id, slot)).or_insert(0) += 1;
+                    }
                 }
             }
-        }
 
-        // OT assignments for this date
-        if let Some(ot) = ot_by_date.get(&d) {
-            for a in ot.iter() {
-                let crosses_midnight = a.end_time < a.start_time;
-                let (start_slot, end_slot) = time_to_slot_range(a.start_time, a.end_time, crosses_midnight);
-                for slot in start_slot..=end_slot {
-                    if !user_slot_covered.contains(&(a.user_id, a.classification_id, slot)) {
-                        *actual.entry((a.classification_id, slot)).or_insert(0) += 1;
+            let coverage: Vec<SlotCoverage> = slots
+                .iter()
+                .map(|s| {
+                    let count = actual
+                        .get(&(s.classification_id, s.slot_index))
+                        .copied()
+                        .unwrap_or(0);
+                    let status = if s.min_headcount == 0 || count >= s.min_headcount as i32 {
+                        "green"
+                    } else {
+                        "red"
+                    };
+                    SlotCoverage {
+                        slot_index: s.slot_index,
+                        classification_id: s.classification_id,
+                        classification_abbreviation: s.classification_abbreviation.clone(),
+                        min_headcount: s.min_headcount,
+                        target_headcount: s.target_headcount,
+                        max_headcount: s.max_headcount,
+                        actual_headcount: count,
+                        status: status.to_string(),
+                        source: s.source,
                     }
-                }
-            }
+                })
+                .collect();
+
+            result.insert(d, coverage);
         }
 
-        // OT overnight from previous day
-        if let Some(ot_overnight) = ot_overnight_by_target.get(&d) {
-            for a in ot_overnight.iter() {
-                let Some(end_slot) = overnight_end_slot(a.end_time) else {
-                    continue;
-                };
-                for slot in 0..=end_slot {
-                    if !user_slot_covered.contains(&(a.user_id, a.classification_id, slot)) {
-                        *actual.entry((a.classification_id, slot)).or_insert(0) += 1;
-                    }
+        result
+    }
+
+    /// Per-date qualification coverage. Each person working a slot counts once
+    /// toward every qualification they hold, regular shift or OT alike.
+    pub(crate) fn qualification_coverage(
+        &self,
+        dates: &[time::Date],
+    ) -> std::collections::HashMap<time::Date, Vec<QualificationSlotCoverage>> {
+        use std::collections::{HashMap, HashSet};
+
+        let mut result: HashMap<time::Date, Vec<QualificationSlotCoverage>> = HashMap::new();
+
+        for &d in dates {
+            let Some(slots) = self.qualification_requirements.get(&d) else {
+                continue;
+            };
+
+            let mut on_duty: HashSet<(Uuid, i16)> = HashSet::new();
+            for s in self.shifts.iter().filter(|s| s.classification_id.is_some()) {
+                if let Some((start, end)) = s.slots_on(d) {
+                    on_duty.extend((start..=end).map(|slot| (s.user_id, slot)));
                 }
             }
-        }
-
-        let coverage: Vec<SlotCoverage> = slots
-            .iter()
-            .map(|s| {
-                let count = actual
-                    .get(&(s.classification_id, s.slot_index))
-                    .copied()
-                    .unwrap_or(0);
-                let status = if s.min_headcount == 0 || count >= s.min_headcount as i32 {
-                    "green"
-                } else {
-                    "red"
-                };
-                SlotCoverage {
-                    slot_index: s.slot_index,
-                    classification_id: s.classification_id,
-                    classification_abbreviation: s.classification_abbreviation.clone(),
-                    min_headcount: s.min_headcount,
-                    target_headcount: s.target_headcount,
-                    max_headcount: s.max_headcount,
-                    actual_headcount: count,
-                    status: status.to_string(),
+            let held = coverage_layers::valid_on(&self.user_qualifications, d);
+            let mut actual: HashMap<(Uuid, i16), i32> = HashMap::new();
+            for (user_id, slot) in on_duty {
+                for qual_id in held.get(&user_id).into_iter().flatten() {
+                    *actual.entry((*qual_id, slot)).or_insert(0) += 1;
                 }
-            })
-            .collect();
+            }
 
-        result.insert(d, coverage);
-    }
+            let coverage = slots
+                .iter()
+                .map(|q| {
+                    let count = actual.get(&(q.qualification_id, q.slot_index)).copied().unwrap_or(0);
+                    QualificationSlotCoverage {
+                        slot_index: q.slot_index,
+                        qualification_id: q.qualification_id,
+                        qualification_name: q.qualification_name.clone(),
+                        min_count: q.min_count,
+                        actual_count: count,
+                        status: if count >= q.min_count as i32 { "green" } else { "red" }.to_string(),
+                    }
+                })
+                .collect();
+            result.insert(d, coverage);
+        }
 
-    Ok(result)
+        result
+    }
 }
 
 // ── Classification Gaps ───────────────────────────────────────────────────────
@@ -1126,7 +1147,7 @@ pub async fn classification_gaps(
     }
 
     // Sort by shortage descending (worst gaps first)
-    gaps.sort_by(|a, b| b.shortage.cmp(&a.shortage));
+    gaps.sort_by_key(|g| std::cmp::Reverse(g.shortage));
 
     Ok(Json(gaps))
 }
@@ -1143,24 +1164,23 @@ pub async fn gap_blocks(
     State(pool): State<PgPool>,
     auth: AuthUser,
     Path(date_str): Path<String>,
-) -> Result<Json<Vec<crate::models::schedule::ClassificationGapBlocks>>> {
-    use crate::models::schedule::{ClassificationGapBlocks, CoverageGapBlock};
-
+) -> Result<Json<Vec<ClassificationGapBlocks>>> {
     if !auth.role.can_manage_schedule() {
         return Err(AppError::Forbidden);
     }
 
     let slot_coverage = compute_slot_coverage(&pool, auth.org_id, &date_str).await?;
-    if slot_coverage.is_empty() {
-        return Ok(Json(vec![]));
-    }
+    Ok(Json(gap_blocks_from_coverage(&slot_coverage)))
+}
 
+/// Merge per-slot shortages below minimum into contiguous blocks per classification.
+pub(crate) fn gap_blocks_from_coverage(slot_coverage: &[SlotCoverage]) -> Vec<ClassificationGapBlocks> {
     // Gather classification info and index by (class_id, slot_index)
     use std::collections::HashMap;
     let mut class_info: HashMap<Uuid, String> = HashMap::new();
     let mut by_class_slot: HashMap<Uuid, HashMap<i16, &SlotCoverage>> = HashMap::new();
 
-    for sc in &slot_coverage {
+    for sc in slot_coverage {
         class_info
             .entry(sc.classification_id)
             .or_insert_with(|| sc.classification_abbreviation.clone());
@@ -1193,47 +1213,7 @@ pub async fn gap_blocks(
             continue;
         }
 
-        // Find contiguous runs and merge into blocks
-        let mut blocks: Vec<CoverageGapBlock> = Vec::new();
-        let mut block_start = shortage_slots[0].0;
-        let mut block_max = shortage_slots[0].1;
-        let mut prev_slot = shortage_slots[0].0;
-
-        for &(slot_idx, shortage) in &shortage_slots[1..] {
-            if slot_idx == prev_slot + 1 {
-                block_max = block_max.max(shortage);
-                prev_slot = slot_idx;
-            } else {
-                blocks.push(CoverageGapBlock {
-                    start_time: slot_start_time(block_start),
-                    end_time: slot_end_time(prev_slot),
-                    shortage: block_max,
-                });
-                block_start = slot_idx;
-                block_max = shortage;
-                prev_slot = slot_idx;
-            }
-        }
-        blocks.push(CoverageGapBlock {
-            start_time: slot_start_time(block_start),
-            end_time: slot_end_time(prev_slot),
-            shortage: block_max,
-        });
-
-        // Wrap-around merge: if last block ends at 00:00 and first starts at 00:00,
-        // they're actually one continuous overnight block
-        if blocks.len() >= 2 {
-            let last_ends_midnight = blocks.last().unwrap().end_time == "00:00";
-            let first_starts_midnight = blocks[0].start_time == "00:00";
-            if last_ends_midnight && first_starts_midnight {
-                let first_end = blocks[0].end_time.clone();
-                let first_shortage = blocks[0].shortage;
-                let last = blocks.last_mut().unwrap();
-                last.end_time = first_end;
-                last.shortage = last.shortage.max(first_shortage);
-                blocks.remove(0);
-            }
-        }
+        let blocks = merge_shortage_runs(&shortage_slots);
 
         result.push(ClassificationGapBlocks {
             classification_id: *class_id,
@@ -1245,7 +1225,110 @@ pub async fn gap_blocks(
     // Sort by abbreviation for consistent display
     result.sort_by(|a, b| a.classification_abbreviation.cmp(&b.classification_abbreviation));
 
-    Ok(Json(result))
+    result
+}
+
+/// GET /api/coverage-plans/gaps/:date/qualifications
+///
+/// Like `gaps/:date/blocks`, but for qualification requirements: contiguous
+/// time ranges where fewer qualified people are on duty than the plan requires.
+pub async fn qualification_gap_blocks(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(date_str): Path<String>,
+) -> Result<Json<Vec<QualificationGapBlocks>>> {
+    if !auth.role.can_manage_schedule() {
+        return Err(AppError::Forbidden);
+    }
+
+    let date = parse_date(&date_str)?;
+    let coverage = load_coverage_inputs(&pool, auth.org_id, &[date])
+        .await?
+        .qualification_coverage(&[date])
+        .remove(&date)
+        .unwrap_or_default();
+    Ok(Json(qualification_gap_blocks_from_coverage(&coverage)))
+}
+
+/// Merge per-slot qualified-headcount shortages into contiguous blocks per qualification.
+pub(crate) fn qualification_gap_blocks_from_coverage(
+    coverage: &[QualificationSlotCoverage],
+) -> Vec<QualificationGapBlocks> {
+    use std::collections::BTreeMap;
+
+    // Coverage rows arrive ordered by qualification name, then slot
+    let mut by_qual: BTreeMap<(String, Uuid), Vec<(i16, i32)>> = BTreeMap::new();
+    for qc in coverage {
+        let shortage = qc.min_count as i32 - qc.actual_count;
+        let runs = by_qual
+            .entry((qc.qualification_name.clone(), qc.qualification_id))
+            .or_default();
+        if shortage > 0 {
+            runs.push((qc.slot_index, shortage));
+        }
+    }
+
+    by_qual
+        .into_iter()
+        .filter(|(_, shortage_slots)| !shortage_slots.is_empty())
+        .map(|((name, id), shortage_slots)| QualificationGapBlocks {
+            qualification_id: id,
+            qualification_name: name,
+            blocks: merge_shortage_runs(&shortage_slots),
+        })
+        .collect()
+}
+
+/// Merge ascending `(slot_index, shortage)` pairs into contiguous blocks, each
+/// carrying its worst shortage. A run ending at midnight joins one starting at
+/// midnight, since they're the same overnight gap.
+fn merge_shortage_runs(shortage_slots: &[(i16, i32)]) -> Vec<CoverageGapBlock> {
+    if shortage_slots.is_empty() {
+        return vec![];
+    }
+
+    let mut blocks: Vec<CoverageGapBlock> = Vec::new();
+    let mut block_start = shortage_slots[0].0;
+    let mut block_max = shortage_slots[0].1;
+    let mut prev_slot = shortage_slots[0].0;
+
+    for &(slot_idx, shortage) in &shortage_slots[1..] {
+        if slot_idx == prev_slot + 1 {
+            block_max = block_max.max(shortage);
+            prev_slot = slot_idx;
+        } else {
+            blocks.push(CoverageGapBlock {
+                start_time: slot_start_time(block_start),
+                end_time: slot_end_time(prev_slot),
+                shortage: block_max,
+            });
+            block_start = slot_idx;
+            block_max = shortage;
+            prev_slot = slot_idx;
+        }
+    }
+    blocks.push(CoverageGapBlock {
+        start_time: slot_start_time(block_start),
+        end_time: slot_end_time(prev_slot),
+        shortage: block_max,
+    });
+
+    // Wrap-around merge: if last block ends at 00:00 and first starts at 00:00,
+    // they're actually one continuous overnight block
+    if blocks.len() >= 2 {
+        let last_ends_midnight = blocks.last().unwrap().end_time == "00:00";
+        let first_starts_midnight = blocks[0].start_time == "00:00";
+        if last_ends_midnight && first_starts_midnight {
+            let first_end = blocks[0].end_time.clone();
+            let first_shortage = blocks[0].shortage;
+            let last = blocks.last_mut().unwrap();
+            last.end_time = first_end;
+            last.shortage = last.shortage.max(first_shortage);
+            blocks.remove(0);
+        }
+    }
+
+    blocks
 }
 
 fn slot_start_time(slot: i16) -> String {
@@ -1421,6 +1504,9 @@ pub async fn send_sms_alert(
 // Raw data fetched from DB for a single day's grid computation.
 struct DayGridData {
     slots: Vec<DayGridSlotRow>,
+    qualification_slots: Vec<coverage_layers::RequiredQualificationSlot>,
+    // user_id -> qualifications held and unexpired on the day; empty when the day has no qualification requirements
+    user_qualifications: std::collections::HashMap<Uuid, std::collections::HashSet<Uuid>>,
     assignments: Vec<DayGridAssignmentRow>,
     overnight: Vec<DayGridAssignmentRow>,
     ot_assignments: Vec<DayGridOtRow>,
@@ -1436,6 +1522,7 @@ struct DayGridSlotRow {
     classification_abbreviation: String,
     min_headcount: i16,
     target_headcount: i16,
+    source: CoverageSource,
 }
 
 struct DayGridAssignmentRow {
@@ -1482,32 +1569,14 @@ struct DayGridMaps {
 async fn fetch_day_grid_data(
     pool: &PgPool,
     org_id: Uuid,
-    plan_id: Uuid,
     date: time::Date,
 ) -> Result<DayGridData> {
-    let dow = date.weekday().number_days_from_sunday() as i16;
     let prev_date = date.previous_day().unwrap_or(date);
 
-    let slot_rows = sqlx::query!(
-        r#"
-        SELECT
-            cps.slot_index,
-            cps.classification_id,
-            cl.abbreviation AS classification_abbreviation,
-            cps.min_headcount,
-            cps.target_headcount
-        FROM coverage_plan_slots cps
-        JOIN classifications cl ON cl.id = cps.classification_id
-        WHERE cps.plan_id = $1 AND cps.day_of_week = $2
-        ORDER BY cps.classification_id, cps.slot_index
-        "#,
-        plan_id,
-        dow,
-    )
-    .fetch_all(pool)
-    .await?;
-
-    let slots = slot_rows
+    let slots = coverage_layers::resolve_requirements(pool, org_id, &[date])
+        .await?
+        .remove(&date)
+        .unwrap_or_default()
         .into_iter()
         .map(|r| DayGridSlotRow {
             slot_index: r.slot_index,
@@ -1515,6 +1584,7 @@ async fn fetch_day_grid_data(
             classification_abbreviation: r.classification_abbreviation,
             min_headcount: r.min_headcount,
             target_headcount: r.target_headcount,
+            source: r.source,
         })
         .collect();
 
@@ -1674,8 +1744,20 @@ async fn fetch_day_grid_data(
         })
         .collect();
 
+    let qualification_slots = coverage_layers::resolve_qualification_requirements(pool, org_id, &[date])
+        .await?
+        .remove(&date)
+        .unwrap_or_default();
+    let user_qualifications = if qualification_slots.is_empty() {
+        Default::default()
+    } else {
+        coverage_layers::valid_on(&coverage_layers::user_qualifications(pool, org_id).await?, date)
+    };
+
     Ok(DayGridData {
         slots,
+        qualification_slots,
+        user_qualifications,
         assignments,
         overnight,
         ot_assignments,
@@ -1833,8 +1915,8 @@ async fn build_classification_blocks(
 ) -> Result<Vec<DayGridClassification>> {
     use std::collections::{BTreeMap, BTreeSet, HashMap};
 
-    // Build lookup: (classification_id, slot_index) -> (min, target)
-    let mut slot_targets: HashMap<(Uuid, i16), (i16, i16)> = HashMap::new();
+    // Build lookup: (classification_id, slot_index) -> (min, target, source)
+    let mut slot_targets: HashMap<(Uuid, i16), (i16, i16, CoverageSource)> = HashMap::new();
     let mut class_info: BTreeMap<Uuid, String> = BTreeMap::new();
 
     for s in &data.slots {
@@ -1843,13 +1925,13 @@ async fn build_classification_blocks(
             .or_insert_with(|| s.classification_abbreviation.clone());
         slot_targets.insert(
             (s.classification_id, s.slot_index),
-            (s.min_headcount, s.target_headcount),
+            (s.min_headcount, s.target_headcount, s.source),
         );
     }
 
     // Include classifications that have employees but no coverage plan slots
     let mut all_class_ids: BTreeSet<Uuid> = class_info.keys().copied().collect();
-    for ((cid, _), _) in &maps.actual {
+    for (cid, _) in maps.actual.keys() {
         all_class_ids.insert(*cid);
     }
 
@@ -1892,12 +1974,12 @@ async fn build_classification_blocks(
             let mut peak_target: i16 = 0;
             let mut peak_min: i16 = 0;
             let mut min_actual: i32 = i32::MAX;
-            let mut has_target = false;
+            let mut source: Option<CoverageSource> = None;
 
             for offset in 0..4i16 {
                 let si = base_slot + offset;
-                if let Some(&(mn, tgt)) = slot_targets.get(&(class_id, si)) {
-                    has_target = true;
+                if let Some(&(mn, tgt, src)) = slot_targets.get(&(class_id, si)) {
+                    source = source.max(Some(src));
                     peak_target = peak_target.max(tgt);
                     peak_min = peak_min.max(mn);
                 }
@@ -1909,9 +1991,11 @@ async fn build_classification_blocks(
                 min_actual = 0;
             }
 
-            let status = if !has_target && min_actual == 0 {
-                "green" // no requirement, no employees — fine
-            } else if peak_min == 0 || min_actual >= peak_min as i32 {
+            // No requirement (with or without employees) or requirement met — fine
+            let status = if (source.is_none() && min_actual == 0)
+                || peak_min == 0
+                || min_actual >= peak_min as i32
+            {
                 "green"
             } else {
                 "red"
@@ -1939,6 +2023,7 @@ async fn build_classification_blocks(
                 target: peak_target,
                 actual: min_actual,
                 status: status.to_string(),
+                source,
                 employees: block_employees,
             });
         }
@@ -1953,8 +2038,91 @@ async fn build_classification_blocks(
     Ok(classifications)
 }
 
-/// Phase 4 — build aggregate per-block coverage totals from the classification grid.
-fn build_coverage_blocks(classifications: &[DayGridClassification]) -> Vec<CoverageBlock> {
+/// Phase 3b — build the 2-hour qualification blocks. Everyone present in a
+/// slot counts once toward each qualification they hold, whatever their
+/// classification; a block is red when any of its slots falls short.
+fn build_qualification_blocks(data: &DayGridData, maps: &DayGridMaps) -> Vec<DayGridQualification> {
+    use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+    // Employees present per slot, across classifications
+    let mut present: HashMap<i16, BTreeMap<Uuid, &BlockEmployee>> = HashMap::new();
+    for ((_, slot), emps) in &maps.emp_map {
+        let at_slot = present.entry(*slot).or_default();
+        for e in emps {
+            at_slot.entry(e.user_id).or_insert(e);
+        }
+    }
+
+    // Requirements arrive ordered by qualification name, then slot
+    let mut quals: Vec<(Uuid, String)> = Vec::new();
+    let mut mins: HashMap<(Uuid, i16), i16> = HashMap::new();
+    for q in &data.qualification_slots {
+        if !quals.iter().any(|(id, _)| *id == q.qualification_id) {
+            quals.push((q.qualification_id, q.qualification_name.clone()));
+        }
+        mins.insert((q.qualification_id, q.slot_index), q.min_count);
+    }
+
+    quals
+        .into_iter()
+        .map(|(qual_id, name)| {
+            let holds = |user_id: &Uuid| {
+                data.user_qualifications
+                    .get(user_id)
+                    .is_some_and(|q| q.contains(&qual_id))
+            };
+            let blocks = (0u8..12)
+                .map(|block_idx| {
+                    let base_slot = block_idx as i16 * 4;
+                    let mut peak_min: i16 = 0;
+                    let mut short = false;
+                    let mut min_actual = i32::MAX;
+                    let mut seen: BTreeSet<Uuid> = BTreeSet::new();
+                    let mut employees: Vec<BlockEmployee> = Vec::new();
+
+                    for si in base_slot..base_slot + 4 {
+                        let qualified: Vec<&BlockEmployee> = present
+                            .get(&si)
+                            .map(|m| m.values().copied().filter(|e| holds(&e.user_id)).collect())
+                            .unwrap_or_default();
+                        let min = mins.get(&(qual_id, si)).copied().unwrap_or(0);
+                        peak_min = peak_min.max(min);
+                        short |= (qualified.len() as i32) < min as i32;
+                        min_actual = min_actual.min(qualified.len() as i32);
+                        for e in qualified {
+                            if seen.insert(e.user_id) {
+                                employees.push(e.clone());
+                            }
+                        }
+                    }
+
+                    QualificationBlock {
+                        block_index: block_idx,
+                        start_time: format!("{:02}:00", block_idx * 2),
+                        end_time: format!("{:02}:00", block_idx * 2 + 2),
+                        min: peak_min,
+                        actual: min_actual,
+                        status: if short { "red" } else { "green" }.to_string(),
+                        employees,
+                    }
+                })
+                .collect();
+
+            DayGridQualification {
+                qualification_id: qual_id,
+                name,
+                blocks,
+            }
+        })
+        .collect()
+}
+
+/// Phase 4 — build aggregate per-block coverage totals from the classification grid,
+/// with the forecast call volume alongside when history is available.
+fn build_coverage_blocks(
+    classifications: &[DayGridClassification],
+    forecast: Option<&CallForecastDay>,
+) -> Vec<CoverageBlock> {
     (0u8..12)
         .map(|block_idx| {
             let total_min: i32 = classifications
@@ -1973,6 +2141,12 @@ fn build_coverage_blocks(classifications: &[DayGridClassification]) -> Vec<Cover
                 total_target: total_min, // field name kept for API compat, value is min
                 total_actual,
                 status: if any_red { "red" } else { "green" }.to_string(),
+                forecast_calls: forecast.filter(|f| f.sample_days > 0).map(|f| {
+                    f.slots[block_idx as usize * 4..block_idx as usize * 4 + 4]
+                        .iter()
+                        .map(|s| s.calls)
+                        .sum()
+                }),
             }
         })
         .collect()
@@ -1992,26 +2166,27 @@ pub async fn day_grid(
 
     let date = parse_date(&date_str)?;
 
-    let plan_id = match resolve_plan_id(&pool, auth.org_id, date).await? {
-        Some(id) => id,
-        None => {
-            return Ok(Json(DayGridResponse {
-                date: date_str,
-                classifications: vec![],
-                blocks: vec![],
-            }));
-        }
-    };
-
-    let data = fetch_day_grid_data(&pool, auth.org_id, plan_id, date).await?;
+    let data = fetch_day_grid_data(&pool, auth.org_id, date).await?;
+    if data.slots.is_empty() && resolve_plan_id(&pool, auth.org_id, date).await?.is_none() {
+        return Ok(Json(DayGridResponse {
+            date: date_str,
+            classifications: vec![],
+            blocks: vec![],
+            qualifications: vec![],
+        }));
+    }
     let maps = build_slot_maps(&data);
     let classifications = build_classification_blocks(&pool, &data, &maps).await?;
-    let blocks = build_coverage_blocks(&classifications);
+    let qualifications = build_qualification_blocks(&data, &maps);
+    let forecast =
+        call_forecast::forecast(&pool, auth.org_id, &auth.org_timezone, &[date]).await?;
+    let blocks = build_coverage_blocks(&classifications, forecast.first());
 
     Ok(Json(DayGridResponse {
         date: date_str,
         classifications,
         blocks,
+        qualifications,
     }))
 }
 
@@ -2149,7 +2324,7 @@ pub(crate) fn coverage_status_per_shift(
         }
 
         // Sort by shortage descending for consistent display
-        by_classification.sort_by(|a, b| b.shortage.cmp(&a.shortage));
+        by_classification.sort_by_key(|c| std::cmp::Reverse(c.shortage));
 
         let status = if total_shortage > 0 { "red" } else { "green" };
         result.insert(
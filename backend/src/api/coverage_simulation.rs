@@ -0,0 +1,195 @@
+use std::collections::{BTreeSet, HashMap};
+
+use axum::{extract::State, Json};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{
+    api::{
+        coverage_plans::{gap_blocks_from_coverage, load_coverage_inputs, StaffedShift},
+        helpers::validate_date_range,
+    },
+    auth::AuthUser,
+    error::{AppError, Result},
+    models::coverage_simulation::{SimulateCoverageRequest, SimulateCoverageResponse, SimulatedDay},
+    models::schedule::SlotCoverage,
+    org_guard,
+};
+
+/// Upper bound on the number of dates one simulation may touch.
+const MAX_SIMULATED_DAYS: usize = 93;
+const MAX_CHANGES: usize = 50;
+
+/// POST /api/coverage-plans/simulate
+///
+/// Applies hypothetical staffing changes in memory and returns the coverage
+/// gap blocks before and after for every affected date. Nothing is written.
+pub async fn simulate(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Json(req): Json<SimulateCoverageRequest>,
+) -> Result<Json<SimulateCoverageResponse>> {
+    if !auth.role.can_manage_schedule() {
+        return Err(AppError::Forbidden);
+    }
+
+    let change_count = req.remove_users.len() + req.swap_shifts.len() + req.add_shifts.len();
+    if change_count == 0 {
+        return Err(AppError::BadRequest("Provide at least one change to simulate".into()));
+    }
+    if change_count > MAX_CHANGES {
+        return Err(AppError::BadRequest(format!(
+            "Cannot simulate more than {MAX_CHANGES} changes at once"
+        )));
+    }
+
+    // Affected dates: every changed date plus the next morning for overnight spill
+    let mut affected: BTreeSet<time::Date> = BTreeSet::new();
+    for r in &req.remove_users {
+        validate_date_range(r.start_date, r.end_date, Some(MAX_SIMULATED_DAYS as i64))?;
+        let mut d = r.start_date;
+        while d <= r.end_date && affected.len() <= MAX_SIMULATED_DAYS {
+            affected.insert(d);
+            let Some(next) = d.next_day() else { break };
+            d = next;
+        }
+    }
+    for d in req.swap_shifts.iter().map(|s| s.date).chain(req.add_shifts.iter().map(|a| a.date)) {
+        affected.insert(d);
+    }
+    let spill: Vec<time::Date> = affected.iter().filter_map(|d| d.next_day()).collect();
+    affected.extend(spill);
+    if affected.len() > MAX_SIMULATED_DAYS {
+        return Err(AppError::BadRequest(format!(
+            "Simulation cannot span more than {MAX_SIMULATED_DAYS} days"
+        )));
+    }
+    let dates: Vec<time::Date> = affected.into_iter().collect();
+
+    // Users and templates referenced by the changes must belong to this org
+    let user_ids: Vec<Uuid> = req
+        .remove_users
+        .iter()
+        .map(|r| r.user_id)
+        .chain(req.swap_shifts.iter().map(|s| s.user_id))
+        .chain(req.add_shifts.iter().map(|a| a.user_id))
+        .collect();
+    let user_classes: HashMap<Uuid, Option<Uuid>> = sqlx::query!(
+        "SELECT id, classification_id FROM users WHERE org_id = $1 AND id = ANY($2)",
+        auth.org_id,
+        &user_ids,
+    )
+    .fetch_all(&pool)
+    .await?
+    .into_iter()
+    .map(|u| (u.id, u.classification_id))
+    .collect();
+    if let Some(missing) = user_ids.iter().find(|id| !user_classes.contains_key(id)) {
+        return Err(AppError::NotFound(format!("User {missing} not found")));
+    }
+
+    let template_ids: Vec<Uuid> = req
+        .swap_shifts
+        .iter()
+        .map(|s| s.shift_template_id)
+        .chain(req.add_shifts.iter().map(|a| a.shift_template_id))
+        .collect();
+    let templates: HashMap<Uuid, (time::Time, time::Time, bool)> = sqlx::query!(
+        r#"
+        SELECT id, start_time, end_time, crosses_midnight
+        FROM shift_templates
+        WHERE org_id = $1 AND id = ANY($2)
+        "#,
+        auth.org_id,
+        &template_ids,
+    )
+    .fetch_all(&pool)
+    .await?
+    .into_iter()
+    .map(|t| (t.id, (t.start_time, t.end_time, t.crosses_midnight)))
+    .collect();
+    if template_ids.iter().any(|id| !templates.contains_key(id)) {
+        return Err(AppError::NotFound("Shift template not found".into()));
+    }
+
+    for a in &req.add_shifts {
+        if let Some(class_id) = a.classification_id {
+            org_guard::verify_classification(&pool, class_id, auth.org_id).await?;
+        }
+    }
+
+    let before = load_coverage_inputs(&pool, auth.org_id, &dates).await?;
+    let mut after = before.clone();
+
+    for r in &req.remove_users {
+        after
+            .shifts
+            .retain(|s| !(s.user_id == r.user_id && s.date >= r.start_date && s.date <= r.end_date));
+    }
+
+    for sw in &req.swap_shifts {
+        let existing = after
+            .shifts
+            .iter()
+            .position(|s| s.user_id == sw.user_id && s.date == sw.date && !s.is_ot)
+            .ok_or_else(|| {
+                AppError::BadRequest(format!(
+                    "User {} has no scheduled shift on {} to swap",
+                    sw.user_id, sw.date
+                ))
+            })?;
+        let (start_time, end_time, crosses_midnight) = templates[&sw.shift_template_id];
+        let shift = &mut after.shifts[existing];
+        shift.start_time = start_time;
+        shift.end_time = end_time;
+        shift.crosses_midnight = crosses_midnight;
+    }
+
+    for a in &req.add_shifts {
+        let classification_id = a.classification_id.or(user_classes[&a.user_id]).ok_or_else(|| {
+            AppError::BadRequest(format!(
+                "User {} has no classification; specify classification_id",
+                a.user_id
+            ))
+        })?;
+        let (start_time, end_time, crosses_midnight) = templates[&a.shift_template_id];
+        after.shifts.push(StaffedShift {
+            date: a.date,
+            user_id: a.user_id,
+            classification_id: Some(classification_id),
+            start_time,
+            end_time,
+            crosses_midnight,
+            is_ot: false,
+        });
+    }
+
+    let mut before_cov = before.slot_coverage(&dates);
+    let mut after_cov = after.slot_coverage(&dates);
+
+    let total_shortage = |cov: &[SlotCoverage]| -> i32 {
+        cov.iter()
+            .map(|sc| (sc.min_headcount as i32 - sc.actual_headcount).max(0))
+            .sum()
+    };
+
+    let days: Vec<SimulatedDay> = dates
+        .into_iter()
+        .map(|date| {
+            let b = before_cov.remove(&date).unwrap_or_default();
+            let a = after_cov.remove(&date).unwrap_or_default();
+            SimulatedDay {
+                date,
+                before: gap_blocks_from_coverage(&b),
+                after: gap_blocks_from_coverage(&a),
+                before_shortage: total_shortage(&b),
+                after_shortage: total_shortage(&a),
+            }
+        })
+        .collect();
+
+    Ok(Json(SimulateCoverageResponse {
+        worsens_coverage: days.iter().any(|d| d.after_shortage > d.before_shortage),
+        days,
+    }))
+}
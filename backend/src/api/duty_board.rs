@@ -7,12 +7,18 @@ use std::collections::{HashMap, HashSet};
 use uuid::Uuid;
 
 use crate::{
-    api::helpers::{json_ok, validate_date_range},
+    api::helpers::{ensure_rows_affected, json_ok, validate_date_range},
     auth::AuthUser,
     error::{AppError, Result},
     models::duty_position::{
-        AvailableEmployee, AvailableStaffQuery, BoardAssignment, BoardPosition, CellAction,
-        CellActionKind, ConsoleHoursEntry, ConsoleHoursQuery, DutyBoardResponse,
+        AutoFillCell, AutoFillRequest, AutoFillResponse, AvailableEmployee, AvailableStaffQuery,
+        BoardAssignment, BoardPosition, CellAction, CellActionKind, ConsoleHoursEntry,
+        ConsoleHoursQuery, CreateDutyBreakRequest, DutyBoardResponse, DutyBreakKind,
+        DutyBreakView, PlanReliefRequest, UnfilledCell, UpdateDutyBreakRequest,
+    },
+    services::{
+        duty_autofill,
+        duty_relief::{self, BreakRow, BreakSpec},
     },
 };
 
@@ -20,7 +26,7 @@ use crate::{
 /// are open for a position on a given day-of-week.
 ///
 /// If no hours row exists for that day, all 12 blocks are open (24/7).
-fn compute_open_blocks(
+pub(crate) fn compute_open_blocks(
     open_time: Option<time::Time>,
     close_time: Option<time::Time>,
     crosses_midnight: bool,
@@ -192,10 +198,13 @@ pub async fn get_board(
         })
         .collect();
 
+    let breaks = duty_relief::load_views(&pool, auth.org_id, date).await?;
+
     Ok(Json(DutyBoardResponse {
         date: date.to_string(),
         positions,
         assignments,
+        breaks,
     }))
 }
 
@@ -410,19 +419,21 @@ pub async fn available_staff(
     if !required_qual_ids.is_empty() {
         let user_ids: Vec<Uuid> = candidates.iter().map(|e| e.user_id).collect();
 
-        // Get users who have ALL required qualifications
+        // Get users who have ALL required qualifications, unexpired on the date
         let qualified_users: HashSet<Uuid> = sqlx::query_scalar!(
             r#"
             SELECT uq.user_id
             FROM user_qualifications uq
             WHERE uq.user_id = ANY($1)
               AND uq.qualification_id = ANY($2)
+              AND (uq.expires_on IS NULL OR uq.expires_on >= $4)
             GROUP BY uq.user_id
             HAVING COUNT(DISTINCT uq.qualification_id) = $3
             "#,
             &user_ids,
             &required_qual_ids,
             required_qual_ids.len() as i64,
+            date,
         )
         .fetch_all(&pool)
         .await?
@@ -546,6 +557,321 @@ pub async fn available_staff(
     Ok(Json(result))
 }
 
+// ============================================================
+// POST /api/duty-board/:date/auto-fill — Fill open cells from on-shift staff
+// ============================================================
+
+/// Fills every open, unstaffed cell for the date with on-shift staff who meet
+/// the position's classification and qualifications, rotating people between
+/// positions and favouring the least console time this month. See
+/// `services::duty_autofill` for the selection order.
+pub async fn auto_fill(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(date): Path<time::Date>,
+    Json(req): Json<AutoFillRequest>,
+) -> Result<Json<AutoFillResponse>> {
+    if !auth.role.can_manage_schedule() {
+        return Err(AppError::Forbidden);
+    }
+
+    let input = duty_autofill::load_input(&pool, auth.org_id, date, req.overwrite).await?;
+    let planned = duty_autofill::plan(&input);
+
+    let position_names: HashMap<Uuid, &str> =
+        input.positions.iter().map(|p| (p.id, p.name.as_str())).collect();
+    let people: HashMap<Uuid, (&str, &str)> = input
+        .candidates
+        .iter()
+        .map(|c| (c.user_id, (c.first_name.as_str(), c.last_name.as_str())))
+        .collect();
+
+    let mut assigned = Vec::new();
+    let mut unfilled = Vec::new();
+    for cell in &planned {
+        let position_name = position_names.get(&cell.position_id).copied().unwrap_or_default().to_string();
+        match cell.user_id {
+            Some(user_id) => {
+                let (first, last) = people.get(&user_id).copied().unwrap_or_default();
+                assigned.push(AutoFillCell {
+                    duty_position_id: cell.position_id,
+                    position_name,
+                    block_index: cell.block_index,
+                    user_id,
+                    first_name: first.to_string(),
+                    last_name: last.to_string(),
+                });
+            }
+            None => unfilled.push(UnfilledCell {
+                duty_position_id: cell.position_id,
+                position_name,
+                block_index: cell.block_index,
+            }),
+        }
+    }
+
+    if !req.dry_run {
+        let mut tx = pool.begin().await?;
+
+        if req.overwrite {
+            sqlx::query!(
+                "DELETE FROM duty_assignments WHERE org_id = $1 AND date = $2 AND status = 'assigned'",
+                auth.org_id,
+                date,
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        let position_ids: Vec<Uuid> = assigned.iter().map(|a| a.duty_position_id).collect();
+        let user_ids: Vec<Uuid> = assigned.iter().map(|a| a.user_id).collect();
+        let blocks: Vec<i16> = assigned.iter().map(|a| a.block_index).collect();
+        sqlx::query!(
+            r#"
+            INSERT INTO duty_assignments (org_id, duty_position_id, user_id, date, block_index, status, assigned_by)
+            SELECT $1, u.position_id, u.user_id, $2, u.block_index, 'assigned', $3
+            FROM UNNEST($4::uuid[], $5::uuid[], $6::smallint[]) AS u(position_id, user_id, block_index)
+            ON CONFLICT (duty_position_id, date, block_index) DO NOTHING
+            "#,
+            auth.org_id,
+            date,
+            auth.id,
+            &position_ids,
+            &user_ids,
+            &blocks,
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+    }
+
+    Ok(Json(AutoFillResponse { assigned, unfilled }))
+}
+
+// ============================================================
+// Breaks and relief — /api/duty-board/:date/breaks, /api/duty-board/breaks/:id
+// ============================================================
+
+async fn break_view(pool: &PgPool, org_id: Uuid, date: time::Date, id: Uuid) -> Result<DutyBreakView> {
+    duty_relief::load_views(pool, org_id, date)
+        .await?
+        .into_iter()
+        .find(|b| b.id == id)
+        .ok_or_else(|| AppError::NotFound("Break not found".into()))
+}
+
+async fn verify_relief(pool: &PgPool, org_id: Uuid, user_id: Uuid, relief_user_id: Uuid) -> Result<()> {
+    if relief_user_id == user_id {
+        return Err(AppError::BadRequest("An employee can't relieve their own break".into()));
+    }
+    crate::org_guard::verify_user(pool, relief_user_id, org_id).await
+}
+
+pub async fn list_breaks(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(date): Path<time::Date>,
+) -> Result<Json<Vec<DutyBreakView>>> {
+    Ok(Json(duty_relief::load_views(&pool, auth.org_id, date).await?))
+}
+
+/// Schedule a break for someone seated on the board. The break must fall inside
+/// one 2-hour block in which they hold a position.
+pub async fn create_break(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(date): Path<time::Date>,
+    Json(req): Json<CreateDutyBreakRequest>,
+) -> Result<Json<DutyBreakView>> {
+    if !auth.role.can_manage_schedule() {
+        return Err(AppError::Forbidden);
+    }
+
+    crate::org_guard::verify_user(&pool, req.user_id, auth.org_id).await?;
+    if let Some(relief) = req.relief_user_id {
+        verify_relief(&pool, auth.org_id, req.user_id, relief).await?;
+    }
+
+    let (start, end) = (duty_relief::mins(req.start_time), duty_relief::mins(req.end_time));
+    if end <= start {
+        return Err(AppError::BadRequest("end_time must be after start_time".into()));
+    }
+    let block_index = (start / 120) as i16;
+    if end > (block_index as i32 + 1) * 120 {
+        return Err(AppError::BadRequest("A break must fall within a single 2-hour block".into()));
+    }
+
+    let seated = sqlx::query_scalar!(
+        r#"
+        SELECT EXISTS(
+            SELECT 1 FROM duty_assignments
+            WHERE org_id = $1 AND date = $2 AND block_index = $3 AND user_id = $4 AND status = 'assigned'
+        ) AS "exists!"
+        "#,
+        auth.org_id,
+        date,
+        block_index,
+        req.user_id,
+    )
+    .fetch_one(&pool)
+    .await?;
+    if !seated {
+        return Err(AppError::BadRequest(
+            "Employee isn't assigned to a duty position during that block".into(),
+        ));
+    }
+
+    let overlapping = sqlx::query_scalar!(
+        r#"
+        SELECT EXISTS(
+            SELECT 1 FROM duty_breaks
+            WHERE org_id = $1 AND date = $2 AND user_id = $3 AND start_time < $5 AND end_time > $4
+        ) AS "exists!"
+        "#,
+        auth.org_id,
+        date,
+        req.user_id,
+        req.start_time,
+        req.end_time,
+    )
+    .fetch_one(&pool)
+    .await?;
+    if overlapping {
+        return Err(AppError::Conflict("Employee already has a break at that time".into()));
+    }
+
+    let id = sqlx::query_scalar!(
+        r#"
+        INSERT INTO duty_breaks (org_id, user_id, date, block_index, start_time, end_time, kind, relief_user_id, created_by)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+        RETURNING id
+        "#,
+        auth.org_id,
+        req.user_id,
+        date,
+        block_index,
+        req.start_time,
+        req.end_time,
+        req.kind.unwrap_or(DutyBreakKind::Break) as DutyBreakKind,
+        req.relief_user_id,
+        auth.id,
+    )
+    .fetch_one(&pool)
+    .await?;
+
+    Ok(Json(break_view(&pool, auth.org_id, date, id).await?))
+}
+
+pub async fn update_break(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(id): Path<Uuid>,
+    Json(req): Json<UpdateDutyBreakRequest>,
+) -> Result<Json<DutyBreakView>> {
+    if !auth.role.can_manage_schedule() {
+        return Err(AppError::Forbidden);
+    }
+
+    let existing = sqlx::query!(
+        "SELECT user_id, date FROM duty_breaks WHERE id = $1 AND org_id = $2",
+        id,
+        auth.org_id,
+    )
+    .fetch_optional(&pool)
+    .await?
+    .ok_or_else(|| AppError::NotFound("Break not found".into()))?;
+
+    if let Some(Some(relief)) = req.relief_user_id {
+        verify_relief(&pool, auth.org_id, existing.user_id, relief).await?;
+    }
+
+    sqlx::query!(
+        r#"
+        UPDATE duty_breaks
+        SET kind = COALESCE($3, kind),
+            relief_user_id = CASE WHEN $4 THEN $5 ELSE relief_user_id END,
+            updated_at = NOW()
+        WHERE id = $1 AND org_id = $2
+        "#,
+        id,
+        auth.org_id,
+        req.kind as Option<DutyBreakKind>,
+        req.relief_user_id.is_some(),
+        req.relief_user_id.flatten(),
+    )
+    .execute(&pool)
+    .await?;
+
+    Ok(Json(break_view(&pool, auth.org_id, existing.date, id).await?))
+}
+
+pub async fn delete_break(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(id): Path<Uuid>,
+) -> Result<Json<serde_json::Value>> {
+    if !auth.role.can_manage_schedule() {
+        return Err(AppError::Forbidden);
+    }
+
+    let rows = sqlx::query!(
+        "DELETE FROM duty_breaks WHERE id = $1 AND org_id = $2",
+        id,
+        auth.org_id,
+    )
+    .execute(&pool)
+    .await?
+    .rows_affected();
+    ensure_rows_affected(rows, "Break")?;
+
+    Ok(json_ok())
+}
+
+/// Find a relief for every seated break on the date that lacks one (or for
+/// all of them with `replace`). See `services::duty_relief` for who is
+/// eligible. Returns every break with its resulting coverage; any still
+/// `uncovered` leave its position empty.
+pub async fn plan_relief(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(date): Path<time::Date>,
+    Json(req): Json<PlanReliefRequest>,
+) -> Result<Json<Vec<DutyBreakView>>> {
+    if !auth.role.can_manage_schedule() {
+        return Err(AppError::Forbidden);
+    }
+
+    let input = duty_autofill::load_input(&pool, auth.org_id, date, false).await?;
+    let rows = duty_relief::load_breaks(&pool, auth.org_id, date).await?;
+    let mut specs: Vec<BreakSpec> = rows.iter().map(BreakRow::spec).collect();
+    let changed = duty_relief::plan_relief(&input, &mut specs, req.replace);
+
+    if !req.dry_run {
+        let (ids, reliefs): (Vec<Uuid>, Vec<Option<Uuid>>) = specs
+            .iter()
+            .filter(|s| (req.replace && s.position_id.is_some()) || changed.contains(&s.id))
+            .map(|s| (s.id, s.relief_user_id))
+            .unzip();
+        sqlx::query!(
+            r#"
+            UPDATE duty_breaks db
+            SET relief_user_id = u.relief_user_id, updated_at = NOW()
+            FROM UNNEST($2::uuid[], $3::uuid[]) AS u(id, relief_user_id)
+            WHERE db.id = u.id AND db.org_id = $1
+            "#,
+            auth.org_id,
+            &ids,
+            &reliefs as &[Option<Uuid>],
+        )
+        .execute(&pool)
+        .await?;
+    }
+
+    let seated = duty_relief::seating(&input);
+    Ok(Json(duty_relief::views(&pool, rows, &specs, &seated).await?))
+}
+
 // ============================================================
 // GET /api/duty-board/console-hours — Console hours report
 // ============================================================
@@ -12,11 +12,13 @@ use crate::{
     models::duty_position::{
         CreateDutyAssignmentRequest, CreateDutyPositionRequest, CreatePositionHoursRequest,
         CreateQualificationRequest, DutyAssignment, DutyAssignmentQuery, DutyAssignmentView,
-        DutyPosition, DutyPositionHours, Qualification, QualificationMappingRequest,
+        DutyPosition, DutyPositionHours, GrantQualificationRequest, Qualification,
+        QualificationMappingRequest,
         UpdateDutyAssignmentRequest, UpdateDutyPositionRequest, UpdatePositionHoursRequest,
         UpdateQualificationRequest, UserQualificationView,
     },
     org_guard,
+    services::timezone::org_today,
 };
 
 /// Parse a time string in HH:MM format (with ISO8601 fallback).
@@ -312,7 +314,7 @@ pub async fn list_qualifications(
     let quals = sqlx::query_as!(
         Qualification,
         r#"
-        SELECT id, org_id, name, description, created_at
+        SELECT id, org_id, name, description, renewal_months, created_at
         FROM qualifications
         WHERE org_id = $1
         ORDER BY name
@@ -340,14 +342,15 @@ pub async fn create_qualification(
     let qual = sqlx::query_as!(
         Qualification,
         r#"
-        INSERT INTO qualifications (id, org_id, name, description)
-        VALUES ($1, $2, $3, $4)
-        RETURNING id, org_id, name, description, created_at
+        INSERT INTO qualifications (id, org_id, name, description, renewal_months)
+        VALUES ($1, $2, $3, $4, $5)
+        RETURNING id, org_id, name, description, renewal_months, created_at
         "#,
         Uuid::new_v4(),
         auth.org_id,
         req.name,
         req.description,
+        req.renewal_months,
     )
     .fetch_one(&pool)
     .await?;
@@ -370,21 +373,29 @@ pub async fn update_qualification(
 
     let desc_provided = req.description.is_some();
     let desc_value = req.description.flatten();
+    let renewal_provided = req.renewal_months.is_some();
+    let renewal_value = req.renewal_months.flatten();
+    if renewal_value.is_some_and(|m| !(1..=120).contains(&m)) {
+        return Err(AppError::BadRequest("renewal_months must be between 1 and 120".into()));
+    }
 
     let qual = sqlx::query_as!(
         Qualification,
         r#"
         UPDATE qualifications
         SET name        = COALESCE($2, name),
-            description = CASE WHEN $3 THEN $4 ELSE description END
+            description = CASE WHEN $3 THEN $4 ELSE description END,
+            renewal_months = CASE WHEN $6 THEN $7 ELSE renewal_months END
         WHERE id = $1 AND org_id = $5
-        RETURNING id, org_id, name, description, created_at
+        RETURNING id, org_id, name, description, renewal_months, created_at
         "#,
         id,
         req.name.as_deref(),
         desc_provided,
         desc_value,
         auth.org_id,
+        renewal_provided,
+        renewal_value,
     )
     .fetch_optional(&pool)
     .await?
@@ -429,7 +440,7 @@ pub async fn list_position_qualifications(
     let quals = sqlx::query_as!(
         Qualification,
         r#"
-        SELECT q.id, q.org_id, q.name, q.description, q.created_at
+        SELECT q.id, q.org_id, q.name, q.description, q.renewal_months, q.created_at
         FROM qualifications q
         JOIN duty_position_qualifications dpq ON dpq.qualification_id = q.id
         WHERE dpq.duty_position_id = $1 AND q.org_id = $2
@@ -508,7 +519,9 @@ pub async fn list_user_qualifications(
     let quals = sqlx::query_as!(
         UserQualificationView,
         r#"
-        SELECT uq.user_id, uq.qualification_id, q.name AS qualification_name, uq.granted_at
+        SELECT uq.user_id, uq.qualification_id, q.name AS qualification_name, uq.granted_at,
+               uq.issued_on, uq.expires_on,
+               COALESCE(uq.expires_on < $3, false) AS "is_expired!"
         FROM user_qualifications uq
         JOIN qualifications q ON q.id = uq.qualification_id
         WHERE uq.user_id = $1 AND q.org_id = $2
@@ -516,6 +529,7 @@ pub async fn list_user_qualifications(
         "#,
         user_id,
         auth.org_id,
+        org_today(&auth.org_timezone),
     )
     .fetch_all(&pool)
     .await?;
@@ -523,11 +537,13 @@ pub async fn list_user_qualifications(
     Ok(Json(quals))
 }
 
+/// Grant a qualification, or record a recertification when the user already
+/// holds it (new dates replace the old and expiry reminders start over).
 pub async fn add_user_qualification(
     State(pool): State<PgPool>,
     auth: AuthUser,
     Path(user_id): Path<Uuid>,
-    Json(req): Json<QualificationMappingRequest>,
+    Json(req): Json<GrantQualificationRequest>,
 ) -> Result<Json<serde_json::Value>> {
     if !auth.role.can_manage_schedule() {
         return Err(AppError::Forbidden);
@@ -536,14 +552,27 @@ pub async fn add_user_qualification(
     org_guard::verify_user(&pool, user_id, auth.org_id).await?;
     org_guard::verify_qualification(&pool, req.qualification_id, auth.org_id).await?;
 
+    let issued_on = req.issued_on.unwrap_or_else(|| org_today(&auth.org_timezone));
+    if req.expires_on.is_some_and(|e| e < issued_on) {
+        return Err(AppError::BadRequest("expires_on must be on or after issued_on".into()));
+    }
+
     sqlx::query!(
         r#"
-        INSERT INTO user_qualifications (user_id, qualification_id)
-        VALUES ($1, $2)
-        ON CONFLICT DO NOTHING
+        INSERT INTO user_qualifications (user_id, qualification_id, issued_on, expires_on)
+        SELECT $1, q.id, $3::date, COALESCE($4::date, ($3::date + make_interval(months => q.renewal_months::int))::date)
+        FROM qualifications q
+        WHERE q.id = $2
+        ON CONFLICT (user_id, qualification_id) DO UPDATE
+        SET issued_on = EXCLUDED.issued_on,
+            expires_on = EXCLUDED.expires_on,
+            granted_at = NOW(),
+            reminded_days = NULL
         "#,
         user_id,
         req.qualification_id,
+        issued_on,
+        req.expires_on,
     )
     .execute(&pool)
     .await?;
@@ -0,0 +1,159 @@
+use axum::{
+    extract::{Path, Query, State},
+    Json,
+};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{
+    auth::AuthUser,
+    error::{AppError, Result},
+    models::gap_incident::{
+        GapAlert, GapIncident, GapIncidentDetail, GapIncidentQuery, GapIncidentStatus,
+        GapScanResult,
+    },
+    services::gap_monitor,
+    AppState,
+};
+
+async fn fetch_incident(pool: &PgPool, id: Uuid, org_id: Uuid) -> Result<GapIncident> {
+    sqlx::query_as!(
+        GapIncident,
+        r#"
+        SELECT
+            i.id, i.org_id, i.classification_id,
+            cl.abbreviation AS classification_abbreviation,
+            i.date, i.start_time, i.end_time, i.shortage, i.alert_level,
+            i.status AS "status: GapIncidentStatus",
+            i.detected_at, i.last_alerted_at, i.escalated_at,
+            i.acknowledged_by, i.acknowledged_at, i.resolved_at
+        FROM coverage_gap_incidents i
+        JOIN classifications cl ON cl.id = i.classification_id
+        WHERE i.id = $1 AND i.org_id = $2
+        "#,
+        id,
+        org_id,
+    )
+    .fetch_optional(pool)
+    .await?
+    .ok_or_else(|| AppError::NotFound("Gap incident not found".into()))
+}
+
+/// GET /api/coverage-gap-incidents?status=&date_from=&date_to=
+pub async fn list(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Query(params): Query<GapIncidentQuery>,
+) -> Result<Json<Vec<GapIncident>>> {
+    if !auth.role.can_manage_schedule() {
+        return Err(AppError::Forbidden);
+    }
+
+    let rows = sqlx::query_as!(
+        GapIncident,
+        r#"
+        SELECT
+            i.id, i.org_id, i.classification_id,
+            cl.abbreviation AS classification_abbreviation,
+            i.date, i.start_time, i.end_time, i.shortage, i.alert_level,
+            i.status AS "status: GapIncidentStatus",
+            i.detected_at, i.last_alerted_at, i.escalated_at,
+            i.acknowledged_by, i.acknowledged_at, i.resolved_at
+        FROM coverage_gap_incidents i
+        JOIN classifications cl ON cl.id = i.classification_id
+        WHERE i.org_id = $1
+          AND ($2::gap_incident_status IS NULL OR i.status = $2)
+          AND ($3::DATE IS NULL OR i.date >= $3)
+          AND ($4::DATE IS NULL OR i.date <= $4)
+        ORDER BY i.date, i.start_time, cl.abbreviation
+        LIMIT 500
+        "#,
+        auth.org_id,
+        params.status as Option<GapIncidentStatus>,
+        params.date_from,
+        params.date_to,
+    )
+    .fetch_all(&pool)
+    .await?;
+
+    Ok(Json(rows))
+}
+
+/// GET /api/coverage-gap-incidents/:id
+pub async fn get_one(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(id): Path<Uuid>,
+) -> Result<Json<GapIncidentDetail>> {
+    if !auth.role.can_manage_schedule() {
+        return Err(AppError::Forbidden);
+    }
+
+    let incident = fetch_incident(&pool, id, auth.org_id).await?;
+    let alerts = sqlx::query_as!(
+        GapAlert,
+        r#"
+        SELECT id, alert_level, channel, shortage, recipient_count, sent_at
+        FROM coverage_gap_alerts
+        WHERE incident_id = $1
+        ORDER BY sent_at
+        "#,
+        id,
+    )
+    .fetch_all(&pool)
+    .await?;
+
+    Ok(Json(GapIncidentDetail { incident, alerts }))
+}
+
+/// POST /api/coverage-gap-incidents/:id/acknowledge
+///
+/// Marks an open incident as being handled, which stops SMS escalation. A later
+/// worsening still re-alerts.
+pub async fn acknowledge(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(id): Path<Uuid>,
+) -> Result<Json<GapIncident>> {
+    if !auth.role.can_manage_schedule() {
+        return Err(AppError::Forbidden);
+    }
+
+    let incident = fetch_incident(&pool, id, auth.org_id).await?;
+    if incident.status != GapIncidentStatus::Open {
+        return Err(AppError::Conflict("Gap incident is already resolved".into()));
+    }
+
+    sqlx::query!(
+        r#"
+        UPDATE coverage_gap_incidents
+        SET acknowledged_by = $2, acknowledged_at = NOW(), updated_at = NOW()
+        WHERE id = $1 AND acknowledged_at IS NULL
+        "#,
+        id,
+        auth.id,
+    )
+    .execute(&pool)
+    .await?;
+
+    Ok(Json(fetch_incident(&pool, id, auth.org_id).await?))
+}
+
+/// POST /api/coverage-gap-incidents/scan
+///
+/// Run the gap monitor for the caller's org now, regardless of
+/// `gap_alert_enabled` or when it last ran.
+pub async fn scan(State(state): State<AppState>, auth: AuthUser) -> Result<Json<GapScanResult>> {
+    if !auth.role.can_manage_schedule() {
+        return Err(AppError::Forbidden);
+    }
+
+    let result = gap_monitor::scan_org(
+        &state.pool,
+        state.twilio.as_ref(),
+        auth.org_id,
+        &auth.org_timezone,
+    )
+    .await?;
+    Ok(Json(result))
+}
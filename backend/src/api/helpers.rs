@@ -1,14 +1,53 @@
-use axum::Json;
+use axum::{
+    http::header,
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::Serialize;
 use serde_json::Value;
 use time::Date;
 
-use crate::error::AppError;
+use crate::{
+    error::AppError,
+    models::report::ExportFormat,
+    services::report_export::{self, ReportTable},
+};
 
 /// Standard success response for mutations that don't return data
 pub fn json_ok() -> Json<Value> {
     Json(serde_json::json!({ "ok": true }))
 }
 
+/// Respond with `report` as JSON, or as a CSV or PDF download of `table(&report)`
+/// saved as `filename` plus the format's extension.
+pub fn export_response<T: Serialize>(
+    format: ExportFormat,
+    report: T,
+    table: impl FnOnce(&T) -> ReportTable,
+    filename: &str,
+) -> Response {
+    let (content_type, extension, body) = match format {
+        ExportFormat::Json => return Json(report).into_response(),
+        ExportFormat::Csv => (
+            "text/csv; charset=utf-8",
+            "csv",
+            report_export::to_csv(&table(&report)).into_bytes(),
+        ),
+        ExportFormat::Pdf => ("application/pdf", "pdf", report_export::to_pdf(&table(&report))),
+    };
+    (
+        [
+            (header::CONTENT_TYPE, content_type.to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{filename}.{extension}\""),
+            ),
+        ],
+        body,
+    )
+        .into_response()
+}
+
 /// Check that a DELETE/UPDATE affected at least one row, or return NotFound
 pub fn ensure_rows_affected(rows: u64, entity: &str) -> Result<(), AppError> {
     if rows == 0 {
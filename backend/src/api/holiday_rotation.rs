@@ -0,0 +1,295 @@
+use axum::{
+    extract::{Path, Query, State},
+    response::Response,
+    Json,
+};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{
+    api::helpers::{ensure_rows_affected, export_response, json_ok},
+    auth::AuthUser,
+    error::{AppError, Result},
+    models::holiday::{
+        CreateHolidayAwardRequest, HolidayAward, HolidayAwardQuery, HolidayGroup,
+        HolidayGroupRequest, HolidayRotationQuery,
+    },
+    org_guard,
+    services::{holiday_rotation, timezone::org_today},
+};
+
+/// Replace a group's holidays, checking they're all the org's.
+async fn set_members(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    group_id: Uuid,
+    org_id: Uuid,
+    holiday_ids: &[Uuid],
+) -> Result<()> {
+    let mut ids = holiday_ids.to_vec();
+    ids.sort();
+    ids.dedup();
+    let found = sqlx::query_scalar!(
+        r#"SELECT COUNT(*) AS "count!" FROM holiday_calendar WHERE id = ANY($1) AND org_id = $2"#,
+        &ids,
+        org_id,
+    )
+    .fetch_one(&mut **tx)
+    .await?;
+    if found != ids.len() as i64 {
+        return Err(AppError::NotFound("Holiday not found".into()));
+    }
+
+    sqlx::query!("DELETE FROM holiday_group_members WHERE group_id = $1", group_id)
+        .execute(&mut **tx)
+        .await?;
+    sqlx::query!(
+        r#"
+        INSERT INTO holiday_group_members (group_id, holiday_id)
+        SELECT $1, UNNEST($2::UUID[])
+        "#,
+        group_id,
+        &ids,
+    )
+    .execute(&mut **tx)
+    .await?;
+    Ok(())
+}
+
+async fn fetch_group(pool: &PgPool, id: Uuid, org_id: Uuid) -> Result<HolidayGroup> {
+    holiday_rotation::list_groups(pool, org_id)
+        .await?
+        .into_iter()
+        .find(|g| g.id == id)
+        .ok_or_else(|| AppError::NotFound("Holiday group not found".into()))
+}
+
+/// GET /api/holiday-groups
+pub async fn list_groups(State(pool): State<PgPool>, auth: AuthUser) -> Result<Json<Vec<HolidayGroup>>> {
+    Ok(Json(holiday_rotation::list_groups(&pool, auth.org_id).await?))
+}
+
+/// POST /api/holiday-groups
+/// Admin only.
+pub async fn create_group(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Json(req): Json<HolidayGroupRequest>,
+) -> Result<Json<HolidayGroup>> {
+    use validator::Validate;
+    req.validate()?;
+
+    if !auth.role.is_admin() {
+        return Err(AppError::Forbidden);
+    }
+
+    let mut tx = pool.begin().await?;
+    let id = sqlx::query_scalar!(
+        r#"
+        INSERT INTO holiday_groups (org_id, name, lockout_years, restricted_through_round)
+        VALUES ($1, $2, $3, $4)
+        RETURNING id
+        "#,
+        auth.org_id,
+        req.name,
+        req.lockout_years.unwrap_or(1),
+        req.restricted_through_round.unwrap_or(1),
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+    set_members(&mut tx, id, auth.org_id, &req.holiday_ids).await?;
+    tx.commit().await?;
+
+    Ok(Json(fetch_group(&pool, id, auth.org_id).await?))
+}
+
+/// PUT /api/holiday-groups/:id
+/// Admin only. Replaces the group's settings and holidays. Received holidays
+/// stay on record, so the new rules apply to past receipts too.
+pub async fn update_group(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(id): Path<Uuid>,
+    Json(req): Json<HolidayGroupRequest>,
+) -> Result<Json<HolidayGroup>> {
+    use validator::Validate;
+    req.validate()?;
+
+    if !auth.role.is_admin() {
+        return Err(AppError::Forbidden);
+    }
+
+    let mut tx = pool.begin().await?;
+    let rows = sqlx::query!(
+        r#"
+        UPDATE holiday_groups
+        SET name = $3, lockout_years = $4, restricted_through_round = $5
+        WHERE id = $1 AND org_id = $2
+        "#,
+        id,
+        auth.org_id,
+        req.name,
+        req.lockout_years.unwrap_or(1),
+        req.restricted_through_round.unwrap_or(1),
+    )
+    .execute(&mut *tx)
+    .await?
+    .rows_affected();
+    ensure_rows_affected(rows, "Holiday group")?;
+    set_members(&mut tx, id, auth.org_id, &req.holiday_ids).await?;
+    tx.commit().await?;
+
+    Ok(Json(fetch_group(&pool, id, auth.org_id).await?))
+}
+
+/// DELETE /api/holiday-groups/:id
+/// Admin only.
+pub async fn delete_group(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(id): Path<Uuid>,
+) -> Result<Json<serde_json::Value>> {
+    if !auth.role.is_admin() {
+        return Err(AppError::Forbidden);
+    }
+
+    let rows = sqlx::query!(
+        "DELETE FROM holiday_groups WHERE id = $1 AND org_id = $2",
+        id,
+        auth.org_id,
+    )
+    .execute(&pool)
+    .await?
+    .rows_affected();
+
+    ensure_rows_affected(rows, "Holiday group")?;
+    Ok(json_ok())
+}
+
+/// GET /api/holiday-awards
+/// Who received each holiday. Employees only see their own.
+pub async fn list_awards(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Query(q): Query<HolidayAwardQuery>,
+) -> Result<Json<Vec<HolidayAward>>> {
+    let user_id = if auth.role.can_manage_schedule() {
+        q.user_id
+    } else {
+        Some(auth.id)
+    };
+
+    let rows = sqlx::query_as!(
+        HolidayAward,
+        r#"
+        SELECT ha.id, ha.holiday_id, hc.name AS holiday_name, hc.date,
+               ha.user_id, u.first_name, u.last_name,
+               ha.vacation_bid_id, ha.recorded_by, ha.created_at
+        FROM holiday_awards ha
+        JOIN holiday_calendar hc ON hc.id = ha.holiday_id
+        JOIN users u ON u.id = ha.user_id
+        WHERE hc.org_id = $1
+          AND ($2::UUID IS NULL OR ha.user_id = $2)
+          AND ($3::INT IS NULL OR EXTRACT(YEAR FROM hc.date)::INT = $3)
+        ORDER BY hc.date DESC, u.last_name, u.first_name
+        "#,
+        auth.org_id,
+        user_id,
+        q.year,
+    )
+    .fetch_all(&pool)
+    .await?;
+
+    Ok(Json(rows))
+}
+
+/// POST /api/holiday-awards
+/// Admin only. Record a holiday received outside vacation bidding, such as
+/// history from before the system.
+pub async fn create_award(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Json(req): Json<CreateHolidayAwardRequest>,
+) -> Result<Json<HolidayAward>> {
+    if !auth.role.is_admin() {
+        return Err(AppError::Forbidden);
+    }
+    org_guard::verify_user(&pool, req.user_id, auth.org_id).await?;
+
+    let row = sqlx::query_as!(
+        HolidayAward,
+        r#"
+        WITH inserted AS (
+            INSERT INTO holiday_awards (holiday_id, user_id, recorded_by)
+            SELECT id, $3, $4 FROM holiday_calendar WHERE id = $1 AND org_id = $2
+            RETURNING *
+        )
+        SELECT ha.id, ha.holiday_id, hc.name AS holiday_name, hc.date,
+               ha.user_id, u.first_name, u.last_name,
+               ha.vacation_bid_id, ha.recorded_by, ha.created_at
+        FROM inserted ha
+        JOIN holiday_calendar hc ON hc.id = ha.holiday_id
+        JOIN users u ON u.id = ha.user_id
+        "#,
+        req.holiday_id,
+        auth.org_id,
+        req.user_id,
+        auth.id,
+    )
+    .fetch_optional(&pool)
+    .await?
+    .ok_or_else(|| AppError::NotFound("Holiday not found".into()))?;
+
+    Ok(Json(row))
+}
+
+/// DELETE /api/holiday-awards/:id
+/// Admin only. Awards from vacation bidding go when the period is rolled back.
+pub async fn delete_award(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(id): Path<Uuid>,
+) -> Result<Json<serde_json::Value>> {
+    if !auth.role.is_admin() {
+        return Err(AppError::Forbidden);
+    }
+
+    let rows = sqlx::query!(
+        r#"
+        DELETE FROM holiday_awards ha
+        USING holiday_calendar hc
+        WHERE ha.id = $1 AND hc.id = ha.holiday_id AND hc.org_id = $2
+          AND ha.vacation_bid_id IS NULL
+        "#,
+        id,
+        auth.org_id,
+    )
+    .execute(&pool)
+    .await?
+    .rows_affected();
+
+    ensure_rows_affected(rows, "Holiday award")?;
+    Ok(json_ok())
+}
+
+/// GET /api/reports/holiday-rotation?year=&format=json|csv|pdf
+/// How often each employee has received each holiday group, and who the
+/// rotation keeps out of restricted rounds in `year`.
+pub async fn report(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Query(q): Query<HolidayRotationQuery>,
+) -> Result<Response> {
+    if !auth.role.can_manage_schedule() {
+        return Err(AppError::Forbidden);
+    }
+
+    let year = q.year.unwrap_or_else(|| org_today(&auth.org_timezone).year());
+    let report = holiday_rotation::report(&pool, auth.org_id, year).await?;
+
+    Ok(export_response(
+        q.format,
+        report,
+        holiday_rotation::to_table,
+        &format!("holiday-rotation-{year}"),
+    ))
+}
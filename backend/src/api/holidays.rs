@@ -10,6 +10,7 @@ use crate::{
     auth::AuthUser,
     error::{AppError, Result},
     models::holiday::{CreateHolidayRequest, Holiday, HolidayQuery, UpdateHolidayRequest},
+    org_guard,
 };
 
 pub async fn list(
@@ -20,7 +21,7 @@ pub async fn list(
     let rows = sqlx::query_as!(
         Holiday,
         r#"
-        SELECT id, org_id, date, name, is_premium_pay, created_at
+        SELECT id, org_id, date, name, is_premium_pay, coverage_profile_id, created_at
         FROM holiday_calendar
         WHERE org_id = $1
           AND ($2::int IS NULL OR EXTRACT(YEAR FROM date) = $2)
@@ -48,19 +49,23 @@ pub async fn create(
     }
 
     let premium = req.is_premium_pay.unwrap_or(false);
+    if let Some(profile_id) = req.coverage_profile_id {
+        org_guard::verify_holiday_coverage_profile(&pool, profile_id, auth.org_id).await?;
+    }
 
     let row = sqlx::query_as!(
         Holiday,
         r#"
-        INSERT INTO holiday_calendar (id, org_id, date, name, is_premium_pay)
-        VALUES ($1, $2, $3, $4, $5)
-        RETURNING id, org_id, date, name, is_premium_pay, created_at
+        INSERT INTO holiday_calendar (id, org_id, date, name, is_premium_pay, coverage_profile_id)
+        VALUES ($1, $2, $3, $4, $5, $6)
+        RETURNING id, org_id, date, name, is_premium_pay, coverage_profile_id, created_at
         "#,
         Uuid::new_v4(),
         auth.org_id,
         req.date,
         req.name,
         premium,
+        req.coverage_profile_id,
     )
     .fetch_one(&pool)
     .await?;
@@ -81,19 +86,26 @@ pub async fn update(
         return Err(AppError::Forbidden);
     }
 
+    if let Some(Some(profile_id)) = req.coverage_profile_id {
+        org_guard::verify_holiday_coverage_profile(&pool, profile_id, auth.org_id).await?;
+    }
+
     let row = sqlx::query_as!(
         Holiday,
         r#"
         UPDATE holiday_calendar
         SET name = COALESCE($3, name),
-            is_premium_pay = COALESCE($4, is_premium_pay)
+            is_premium_pay = COALESCE($4, is_premium_pay),
+            coverage_profile_id = CASE WHEN $5 THEN $6 ELSE coverage_profile_id END
         WHERE id = $1 AND org_id = $2
-        RETURNING id, org_id, date, name, is_premium_pay, created_at
+        RETURNING id, org_id, date, name, is_premium_pay, coverage_profile_id, created_at
         "#,
         id,
         auth.org_id,
         req.name,
         req.is_premium_pay,
+        req.coverage_profile_id.is_some(),
+        req.coverage_profile_id.flatten(),
     )
     .fetch_optional(&pool)
     .await?
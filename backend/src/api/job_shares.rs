@@ -0,0 +1,144 @@
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{
+    api::helpers::{ensure_rows_affected, json_ok},
+    auth::AuthUser,
+    error::{AppError, Result},
+    models::job_share::{CreateJobSharePairRequest, JobSharePair},
+    org_guard,
+};
+
+/// GET /api/job-share-pairs
+pub async fn list(State(pool): State<PgPool>, auth: AuthUser) -> Result<Json<Vec<JobSharePair>>> {
+    if !auth.role.can_manage_schedule() {
+        return Err(AppError::Forbidden);
+    }
+
+    let rows = sqlx::query_as!(
+        JobSharePair,
+        r#"
+        SELECT p.id,
+               p.user_a_id, a.first_name AS user_a_first_name, a.last_name AS user_a_last_name,
+               p.user_b_id, b.first_name AS user_b_first_name, b.last_name AS user_b_last_name,
+               p.created_at
+        FROM job_share_pairs p
+        JOIN users a ON a.id = p.user_a_id
+        JOIN users b ON b.id = p.user_b_id
+        WHERE p.org_id = $1
+        ORDER BY a.last_name, a.first_name
+        "#,
+        auth.org_id,
+    )
+    .fetch_all(&pool)
+    .await?;
+
+    Ok(Json(rows))
+}
+
+/// POST /api/job-share-pairs
+/// Admin only. Pair two employees; each can be in at most one pair.
+pub async fn create(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Json(req): Json<CreateJobSharePairRequest>,
+) -> Result<Json<JobSharePair>> {
+    if !auth.role.is_admin() {
+        return Err(AppError::Forbidden);
+    }
+    if req.user_a_id == req.user_b_id {
+        return Err(AppError::BadRequest(
+            "A job-share pair needs two different employees".into(),
+        ));
+    }
+    org_guard::verify_user(&pool, req.user_a_id, auth.org_id).await?;
+    org_guard::verify_user(&pool, req.user_b_id, auth.org_id).await?;
+
+    let mut tx = pool.begin().await?;
+
+    let user_ids = [req.user_a_id, req.user_b_id];
+
+    // Lock both employees so concurrent pairings can't put either in two pairs
+    sqlx::query!("SELECT id FROM users WHERE id = ANY($1) FOR UPDATE", &user_ids[..])
+        .fetch_all(&mut *tx)
+        .await?;
+    let already_paired = sqlx::query_scalar!(
+        r#"
+        SELECT EXISTS(
+            SELECT 1 FROM job_share_pairs
+            WHERE user_a_id = ANY($1) OR user_b_id = ANY($1)
+        ) AS "exists!"
+        "#,
+        &user_ids[..],
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+    if already_paired {
+        return Err(AppError::Conflict(
+            "An employee can only be in one job-share pair".into(),
+        ));
+    }
+
+    let id = sqlx::query_scalar!(
+        r#"
+        INSERT INTO job_share_pairs (org_id, user_a_id, user_b_id, created_by)
+        VALUES ($1, $2, $3, $4)
+        RETURNING id
+        "#,
+        auth.org_id,
+        req.user_a_id,
+        req.user_b_id,
+        auth.id,
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+
+    let pair = sqlx::query_as!(
+        JobSharePair,
+        r#"
+        SELECT p.id,
+               p.user_a_id, a.first_name AS user_a_first_name, a.last_name AS user_a_last_name,
+               p.user_b_id, b.first_name AS user_b_first_name, b.last_name AS user_b_last_name,
+               p.created_at
+        FROM job_share_pairs p
+        JOIN users a ON a.id = p.user_a_id
+        JOIN users b ON b.id = p.user_b_id
+        WHERE p.id = $1
+        "#,
+        id,
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(Json(pair))
+}
+
+/// DELETE /api/job-share-pairs/:id
+/// Admin only. Windows already opened for a period stay joint.
+pub async fn delete(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(id): Path<Uuid>,
+) -> Result<Json<serde_json::Value>> {
+    if !auth.role.is_admin() {
+        return Err(AppError::Forbidden);
+    }
+
+    let rows = sqlx::query!(
+        "DELETE FROM job_share_pairs WHERE id = $1 AND org_id = $2",
+        id,
+        auth.org_id,
+    )
+    .execute(&pool)
+    .await?
+    .rows_affected();
+
+    ensure_rows_affected(rows, "Job-share pair")?;
+    Ok(json_ok())
+}
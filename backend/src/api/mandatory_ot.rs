@@ -0,0 +1,392 @@
+use axum::{
+    extract::{Path, Query, State},
+    Json,
+};
+use sqlx::PgPool;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    auth::AuthUser,
+    error::{AppError, Result},
+    models::mandatory_ot::{
+        CreateMandatoryOtEvent, CreateMandatoryOtExemption, MandatoryOtEvent,
+        MandatoryOtEventQuery, MandatoryOtEventType, MandatoryOtExemption,
+        MandatoryOtExemptionQuery,
+    },
+    services::mandatory_ot::{self, EventSource, NewMandatoryOtEvent},
+};
+
+/// Verify the user belongs to the caller's org.
+async fn ensure_org_user(pool: &PgPool, user_id: Uuid, org_id: Uuid) -> Result<()> {
+    let exists = sqlx::query_scalar!(
+        r#"SELECT EXISTS(SELECT 1 FROM users WHERE id = $1 AND org_id = $2) AS "exists!""#,
+        user_id,
+        org_id,
+    )
+    .fetch_one(pool)
+    .await?;
+    if !exists {
+        return Err(AppError::NotFound("User not found".into()));
+    }
+    Ok(())
+}
+
+/// Verify the classification belongs to the caller's org.
+async fn ensure_org_classification(pool: &PgPool, id: Uuid, org_id: Uuid) -> Result<()> {
+    let exists = sqlx::query_scalar!(
+        r#"SELECT EXISTS(SELECT 1 FROM classifications WHERE id = $1 AND org_id = $2) AS "exists!""#,
+        id,
+        org_id,
+    )
+    .fetch_one(pool)
+    .await?;
+    if !exists {
+        return Err(AppError::NotFound("Classification not found".into()));
+    }
+    Ok(())
+}
+
+async fn fetch_event(pool: &PgPool, id: Uuid, org_id: Uuid) -> Result<MandatoryOtEvent> {
+    let r = sqlx::query!(
+        r#"
+        SELECT
+            e.id, e.classification_id, cl.name AS classification_name,
+            e.user_id, (u.first_name || ' ' || u.last_name) AS "user_name!",
+            e.event_type AS "event_type: MandatoryOtEventType",
+            e.counts_as_turn, e.ot_request_assignment_id, e.callout_event_id,
+            e.notes, e.recorded_by, e.occurred_at, e.voided_at, e.voided_by
+        FROM mandatory_ot_events e
+        JOIN classifications cl ON cl.id = e.classification_id
+        JOIN users u ON u.id = e.user_id
+        WHERE e.id = $1 AND e.org_id = $2
+        "#,
+        id,
+        org_id,
+    )
+    .fetch_optional(pool)
+    .await?
+    .ok_or_else(|| AppError::NotFound("Mandatory OT event not found".into()))?;
+
+    Ok(MandatoryOtEvent {
+        id: r.id,
+        classification_id: r.classification_id,
+        classification_name: r.classification_name,
+        user_id: r.user_id,
+        user_name: r.user_name,
+        event_type: r.event_type,
+        counts_as_turn: r.counts_as_turn,
+        ot_request_assignment_id: r.ot_request_assignment_id,
+        callout_event_id: r.callout_event_id,
+        notes: r.notes,
+        recorded_by: r.recorded_by,
+        occurred_at: r.occurred_at,
+        voided_at: r.voided_at,
+        voided_by: r.voided_by,
+    })
+}
+
+async fn fetch_exemption(pool: &PgPool, id: Uuid, org_id: Uuid) -> Result<MandatoryOtExemption> {
+    let r = sqlx::query!(
+        r#"
+        SELECT
+            x.id, x.user_id, (u.first_name || ' ' || u.last_name) AS "user_name!",
+            x.classification_id, x.start_date, x.end_date, x.reason,
+            x.created_by, x.created_at, x.revoked_at
+        FROM mandatory_ot_exemptions x
+        JOIN users u ON u.id = x.user_id
+        WHERE x.id = $1 AND x.org_id = $2
+        "#,
+        id,
+        org_id,
+    )
+    .fetch_optional(pool)
+    .await?
+    .ok_or_else(|| AppError::NotFound("Mandatory OT exemption not found".into()))?;
+
+    Ok(MandatoryOtExemption {
+        id: r.id,
+        user_id: r.user_id,
+        user_name: r.user_name,
+        classification_id: r.classification_id,
+        start_date: r.start_date,
+        end_date: r.end_date,
+        reason: r.reason,
+        created_by: r.created_by,
+        created_at: r.created_at,
+        revoked_at: r.revoked_at,
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Rotation events
+// ---------------------------------------------------------------------------
+
+pub async fn list_events(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Query(params): Query<MandatoryOtEventQuery>,
+) -> Result<Json<Vec<MandatoryOtEvent>>> {
+    if !auth.role.can_manage_schedule() {
+        return Err(AppError::Forbidden);
+    }
+
+    let rows = sqlx::query!(
+        r#"
+        SELECT
+            e.id, e.classification_id, cl.name AS classification_name,
+            e.user_id, (u.first_name || ' ' || u.last_name) AS "user_name!",
+            e.event_type AS "event_type: MandatoryOtEventType",
+            e.counts_as_turn, e.ot_request_assignment_id, e.callout_event_id,
+            e.notes, e.recorded_by, e.occurred_at, e.voided_at, e.voided_by
+        FROM mandatory_ot_events e
+        JOIN classifications cl ON cl.id = e.classification_id
+        JOIN users u ON u.id = e.user_id
+        WHERE e.org_id = $1
+          AND ($2::UUID IS NULL OR e.classification_id = $2)
+          AND ($3::UUID IS NULL OR e.user_id = $3)
+          AND ($4::mandatory_ot_event_type IS NULL OR e.event_type = $4)
+          AND ($5 OR e.voided_at IS NULL)
+        ORDER BY e.occurred_at DESC
+        LIMIT 500
+        "#,
+        auth.org_id,
+        params.classification_id,
+        params.user_id,
+        params.event_type as Option<MandatoryOtEventType>,
+        params.include_voided.unwrap_or(false),
+    )
+    .fetch_all(&pool)
+    .await?;
+
+    let events = rows
+        .into_iter()
+        .map(|r| MandatoryOtEvent {
+            id: r.id,
+            classification_id: r.classification_id,
+            classification_name: r.classification_name,
+            user_id: r.user_id,
+            user_name: r.user_name,
+            event_type: r.event_type,
+            counts_as_turn: r.counts_as_turn,
+            ot_request_assignment_id: r.ot_request_assignment_id,
+            callout_event_id: r.callout_event_id,
+            notes: r.notes,
+            recorded_by: r.recorded_by,
+            occurred_at: r.occurred_at,
+            voided_at: r.voided_at,
+            voided_by: r.voided_by,
+        })
+        .collect();
+
+    Ok(Json(events))
+}
+
+/// Record a refusal or a disciplinary override by hand. Forced turns are
+/// recorded automatically when mandatory OT is assigned.
+pub async fn create_event(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Json(req): Json<CreateMandatoryOtEvent>,
+) -> Result<Json<MandatoryOtEvent>> {
+    req.validate()?;
+
+    if !auth.role.can_manage_schedule() {
+        return Err(AppError::Forbidden);
+    }
+
+    if req.event_type == MandatoryOtEventType::Forced {
+        return Err(AppError::BadRequest(
+            "Forced turns are recorded by assigning mandatory OT; record a refusal or override instead"
+                .into(),
+        ));
+    }
+
+    ensure_org_user(&pool, req.user_id, auth.org_id).await?;
+    ensure_org_classification(&pool, req.classification_id, auth.org_id).await?;
+
+    let counts_as_turn = req
+        .counts_as_turn
+        .unwrap_or(req.event_type == MandatoryOtEventType::Override);
+
+    let mut tx = pool.begin().await?;
+    let id = mandatory_ot::record_event(
+        &mut tx,
+        NewMandatoryOtEvent {
+            org_id: auth.org_id,
+            classification_id: req.classification_id,
+            user_id: req.user_id,
+            event_type: req.event_type,
+            counts_as_turn,
+            source: EventSource::Manual,
+            notes: req.notes.as_deref(),
+            recorded_by: auth.id,
+            occurred_at: req.occurred_at,
+        },
+    )
+    .await?;
+    tx.commit().await?;
+
+    Ok(Json(fetch_event(&pool, id, auth.org_id).await?))
+}
+
+/// Void an event recorded in error. The event stays in the log for audit but
+/// no longer affects the rotation.
+pub async fn void_event(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(id): Path<Uuid>,
+) -> Result<Json<MandatoryOtEvent>> {
+    if !auth.role.can_manage_schedule() {
+        return Err(AppError::Forbidden);
+    }
+
+    let rows = sqlx::query!(
+        r#"
+        UPDATE mandatory_ot_events
+        SET voided_at = NOW(), voided_by = $3
+        WHERE id = $1 AND org_id = $2 AND voided_at IS NULL
+        "#,
+        id,
+        auth.org_id,
+        auth.id,
+    )
+    .execute(&pool)
+    .await?
+    .rows_affected();
+
+    if rows == 0 {
+        fetch_event(&pool, id, auth.org_id).await?;
+        return Err(AppError::Conflict("Event is already voided".into()));
+    }
+
+    Ok(Json(fetch_event(&pool, id, auth.org_id).await?))
+}
+
+// ---------------------------------------------------------------------------
+// Exemptions
+// ---------------------------------------------------------------------------
+
+pub async fn list_exemptions(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Query(params): Query<MandatoryOtExemptionQuery>,
+) -> Result<Json<Vec<MandatoryOtExemption>>> {
+    if !auth.role.can_manage_schedule() {
+        return Err(AppError::Forbidden);
+    }
+
+    let rows = sqlx::query!(
+        r#"
+        SELECT
+            x.id, x.user_id, (u.first_name || ' ' || u.last_name) AS "user_name!",
+            x.classification_id, x.start_date, x.end_date, x.reason,
+            x.created_by, x.created_at, x.revoked_at
+        FROM mandatory_ot_exemptions x
+        JOIN users u ON u.id = x.user_id
+        WHERE x.org_id = $1
+          AND ($2::UUID IS NULL OR x.user_id = $2)
+          AND ($3::DATE IS NULL OR (x.start_date <= $3 AND x.end_date >= $3))
+          AND ($4 OR x.revoked_at IS NULL)
+        ORDER BY x.start_date DESC, u.last_name, u.first_name
+        "#,
+        auth.org_id,
+        params.user_id,
+        params.active_on,
+        params.include_revoked.unwrap_or(false),
+    )
+    .fetch_all(&pool)
+    .await?;
+
+    let exemptions = rows
+        .into_iter()
+        .map(|r| MandatoryOtExemption {
+            id: r.id,
+            user_id: r.user_id,
+            user_name: r.user_name,
+            classification_id: r.classification_id,
+            start_date: r.start_date,
+            end_date: r.end_date,
+            reason: r.reason,
+            created_by: r.created_by,
+            created_at: r.created_at,
+            revoked_at: r.revoked_at,
+        })
+        .collect();
+
+    Ok(Json(exemptions))
+}
+
+pub async fn create_exemption(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Json(req): Json<CreateMandatoryOtExemption>,
+) -> Result<Json<MandatoryOtExemption>> {
+    req.validate()?;
+
+    if !auth.role.can_manage_schedule() {
+        return Err(AppError::Forbidden);
+    }
+
+    if req.end_date < req.start_date {
+        return Err(AppError::BadRequest(
+            "end_date must be on or after start_date".into(),
+        ));
+    }
+
+    ensure_org_user(&pool, req.user_id, auth.org_id).await?;
+    if let Some(cid) = req.classification_id {
+        ensure_org_classification(&pool, cid, auth.org_id).await?;
+    }
+
+    let id = sqlx::query_scalar!(
+        r#"
+        INSERT INTO mandatory_ot_exemptions
+            (org_id, user_id, classification_id, start_date, end_date, reason, created_by)
+        VALUES ($1, $2, $3, $4, $5, $6, $7)
+        RETURNING id
+        "#,
+        auth.org_id,
+        req.user_id,
+        req.classification_id,
+        req.start_date,
+        req.end_date,
+        req.reason,
+        auth.id,
+    )
+    .fetch_one(&pool)
+    .await?;
+
+    Ok(Json(fetch_exemption(&pool, id, auth.org_id).await?))
+}
+
+pub async fn revoke_exemption(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(id): Path<Uuid>,
+) -> Result<Json<MandatoryOtExemption>> {
+    if !auth.role.can_manage_schedule() {
+        return Err(AppError::Forbidden);
+    }
+
+    let rows = sqlx::query!(
+        r#"
+        UPDATE mandatory_ot_exemptions
+        SET revoked_at = NOW(), revoked_by = $3
+        WHERE id = $1 AND org_id = $2 AND revoked_at IS NULL
+        "#,
+        id,
+        auth.org_id,
+        auth.id,
+    )
+    .execute(&pool)
+    .await?
+    .rows_affected();
+
+    if rows == 0 {
+        fetch_exemption(&pool, id, auth.org_id).await?;
+        return Err(AppError::Conflict("Exemption is already revoked".into()));
+    }
+
+    Ok(Json(fetch_exemption(&pool, id, auth.org_id).await?))
+}
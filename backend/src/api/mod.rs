@@ -1,30 +1,43 @@
 pub mod auth;
 pub mod bargaining_units;
+pub mod bid_proxies;
 pub mod helpers;
 pub mod bidding;
+pub mod call_volume;
 pub mod callout;
+pub mod callout_policies;
 pub mod classifications;
+pub mod coverage_overrides;
 pub mod coverage_plans;
+pub mod coverage_simulation;
 pub mod duty_board;
 pub mod duty_positions;
+pub mod gap_incidents;
 pub mod employee;
+pub mod holiday_rotation;
 pub mod holidays;
+pub mod job_shares;
 pub mod leave;
 pub mod leave_balances;
 pub mod leave_donation;
 pub mod leave_sellback;
+pub mod mandatory_ot;
 pub mod nav;
 pub mod notifications;
 pub mod organizations;
 pub mod ot;
+pub mod ot_autopost;
 pub mod ot_request;
+pub mod ot_request_series;
 pub mod reports;
 pub mod saved_filters;
 pub mod schedule;
+pub mod seniority;
 pub mod shift_patterns;
 pub mod shifts;
 pub mod special_assignments;
 pub mod staffing;
+pub mod standing_availability;
 pub mod teams;
 pub mod trades;
 pub mod users;
@@ -34,7 +47,7 @@ use crate::AppState;
 use axum::{
     extract::State,
     http::StatusCode,
-    routing::{delete, get, patch, post},
+    routing::{delete, get, patch, post, put},
     Json, Router,
 };
 use sqlx::PgPool;
@@ -74,6 +87,26 @@ pub fn router(state: AppState) -> Router {
         )
         // Bargaining units
         .route("/api/bargaining-units", get(bargaining_units::list))
+        // Published seniority lists and disputes
+        .route(
+            "/api/seniority-lists",
+            get(seniority::list).post(seniority::publish),
+        )
+        .route("/api/seniority-lists/:id", get(seniority::get_one))
+        .route(
+            "/api/seniority-lists/:id/disputes",
+            post(seniority::create_dispute),
+        )
+        .route("/api/seniority-disputes", get(seniority::list_disputes))
+        .route(
+            "/api/seniority-disputes/:id/resolve",
+            post(seniority::resolve_dispute),
+        )
+        .route(
+            "/api/seniority-disputes/:id/withdraw",
+            post(seniority::withdraw_dispute),
+        )
+        .route("/api/seniority/recompute", post(seniority::recompute))
         // Classifications
         .route(
             "/api/classifications",
@@ -125,6 +158,19 @@ pub fn router(state: AppState) -> Router {
                 .delete(users::deactivate),
         )
         .route("/api/users/:id/activate", post(users::activate))
+        .route("/api/users/:id/seniority-history", get(seniority::history))
+        .route(
+            "/api/users/:id/seniority-explanation",
+            get(seniority::explanation),
+        )
+        .route(
+            "/api/users/:id/status-history",
+            get(seniority::status_history).post(seniority::create_status_interval),
+        )
+        .route(
+            "/api/users/:id/status-history/:interval_id",
+            patch(seniority::update_status_interval).delete(seniority::delete_status_interval),
+        )
         // Shift templates
         .route(
             "/api/shifts/templates",
@@ -176,14 +222,56 @@ pub fn router(state: AppState) -> Router {
             "/api/schedule/periods/:id/open-bidding",
             post(bidding::open_bidding),
         )
+        .route(
+            "/api/schedule/periods/:id/bid-rounds",
+            get(bidding::list_bid_rounds).put(bidding::put_bid_rounds),
+        )
+        .route(
+            "/api/schedule/periods/:id/seniority-list",
+            put(seniority::pin_schedule_period),
+        )
         .route(
             "/api/schedule/periods/:id/bid-windows",
             get(bidding::list_bid_windows),
         )
+        .route(
+            "/api/schedule/periods/:id/bid-status",
+            get(bidding::bid_status),
+        )
+        .route(
+            "/api/schedule/periods/:id/proxy-plan",
+            get(bid_proxies::get_shift_plan)
+                .put(bid_proxies::put_shift_plan)
+                .delete(bid_proxies::delete_shift_plan),
+        )
+        .route(
+            "/api/schedule/periods/:id/proxy-actions",
+            get(bid_proxies::list_shift_actions),
+        )
         .route(
             "/api/schedule/periods/:id/process-bids",
             post(bidding::process_bids),
         )
+        .route(
+            "/api/schedule/periods/:id/process-bids/preview",
+            get(bidding::preview_bids),
+        )
+        .route(
+            "/api/schedule/periods/:id/rollback-bids",
+            post(bidding::rollback_bids),
+        )
+        .route(
+            "/api/schedule/periods/:id/bid-runs",
+            get(bidding::list_bid_runs),
+        )
+        .route(
+            "/api/schedule/periods/:id/bid-results",
+            get(bidding::bid_results),
+        )
+        .route(
+            "/api/schedule/periods/:id/bid-results/publish",
+            post(bidding::publish_bid_results),
+        )
         .route("/api/bid-windows/:id", get(bidding::get_bid_window))
         .route("/api/bid-windows/:id/submit", post(bidding::submit_bid))
         .route(
@@ -203,6 +291,39 @@ pub fn router(state: AppState) -> Router {
             "/api/schedule/annotations/:id",
             delete(schedule::delete_annotation),
         )
+        // Proactive coverage-gap incidents (static sub-path before /:id)
+        .route("/api/coverage-gap-incidents", get(gap_incidents::list))
+        .route("/api/coverage-gap-incidents/scan", post(gap_incidents::scan))
+        .route("/api/coverage-gap-incidents/:id", get(gap_incidents::get_one))
+        .route(
+            "/api/coverage-gap-incidents/:id/acknowledge",
+            post(gap_incidents::acknowledge),
+        )
+        // Date-specific coverage overrides and holiday coverage profiles
+        .route(
+            "/api/coverage-overrides",
+            get(coverage_overrides::list_overrides).post(coverage_overrides::create_override),
+        )
+        .route(
+            "/api/coverage-overrides/:id",
+            delete(coverage_overrides::delete_override),
+        )
+        .route(
+            "/api/holiday-coverage-profiles",
+            get(coverage_overrides::list_profiles).post(coverage_overrides::create_profile),
+        )
+        .route(
+            "/api/holiday-coverage-profiles/:id",
+            patch(coverage_overrides::update_profile).delete(coverage_overrides::delete_profile),
+        )
+        .route(
+            "/api/holiday-coverage-profiles/:id/slots",
+            get(coverage_overrides::list_profile_slots),
+        )
+        .route(
+            "/api/holiday-coverage-profiles/:id/slots/bulk",
+            post(coverage_overrides::replace_profile_slots),
+        )
         // Coverage plans (per-half-hour-slot system)
         // Static sub-paths before /:id to avoid param capture
         .route(
@@ -229,10 +350,18 @@ pub fn router(state: AppState) -> Router {
             "/api/coverage-plans/gaps/:date/blocks",
             get(coverage_plans::gap_blocks),
         )
+        .route(
+            "/api/coverage-plans/gaps/:date/qualifications",
+            get(coverage_plans::qualification_gap_blocks),
+        )
         .route(
             "/api/coverage-plans/resolved/:date",
             get(coverage_plans::resolved_coverage),
         )
+        .route(
+            "/api/coverage-plans/simulate",
+            post(coverage_simulation::simulate),
+        )
         .route(
             "/api/coverage-plans/:id",
             get(coverage_plans::get_plan)
@@ -247,6 +376,18 @@ pub fn router(state: AppState) -> Router {
             "/api/coverage-plans/:id/slots/bulk",
             post(coverage_plans::bulk_upsert_slots),
         )
+        .route(
+            "/api/coverage-plans/:id/slots/apply-staffing",
+            post(coverage_plans::apply_staffing),
+        )
+        .route(
+            "/api/coverage-plans/:id/qualification-slots",
+            get(coverage_plans::list_qualification_slots),
+        )
+        .route(
+            "/api/coverage-plans/:id/qualification-slots/bulk",
+            post(coverage_plans::bulk_upsert_qualification_slots),
+        )
         // Duty Positions
         .route(
             "/api/duty-positions",
@@ -318,6 +459,22 @@ pub fn router(state: AppState) -> Router {
             "/api/duty-board/:date/available",
             get(duty_board::available_staff),
         )
+        .route(
+            "/api/duty-board/:date/auto-fill",
+            post(duty_board::auto_fill),
+        )
+        .route(
+            "/api/duty-board/:date/breaks",
+            get(duty_board::list_breaks).post(duty_board::create_break),
+        )
+        .route(
+            "/api/duty-board/:date/breaks/plan-relief",
+            post(duty_board::plan_relief),
+        )
+        .route(
+            "/api/duty-board/breaks/:id",
+            patch(duty_board::update_break).delete(duty_board::delete_break),
+        )
         // Leave types
         .route("/api/leave/types", get(leave::list_types))
         // Leave balances (put /adjust before /:user_id to avoid param capture)
@@ -416,11 +573,26 @@ pub fn router(state: AppState) -> Router {
             "/api/callout/events/:id/bump-requests",
             get(callout::list_bump_requests),
         )
+        .route(
+            "/api/callout/events/:id/bump-audit",
+            get(callout::list_bump_audit),
+        )
+        .route(
+            "/api/callout/bump-requests/resolve-expired",
+            post(callout::resolve_expired_bumps),
+        )
         .route(
             "/api/callout/bump-requests/:id/review",
             patch(callout::review_bump_request),
         )
         .route("/api/callout/events/:id/step", patch(ot::advance_step))
+        // Callout policies (static sub-path before /:id)
+        .route(
+            "/api/callout-policies",
+            get(callout_policies::list).put(callout_policies::upsert),
+        )
+        .route("/api/callout-policies/effective", get(callout_policies::effective))
+        .route("/api/callout-policies/:id", delete(callout_policies::delete))
         // Vacation Bids
         .route(
             "/api/vacation-bids/periods",
@@ -438,14 +610,44 @@ pub fn router(state: AppState) -> Router {
             "/api/vacation-bids/periods/:id/open-bidding",
             post(vacation_bids::open_bidding),
         )
+        .route(
+            "/api/vacation-bids/periods/:id/seniority-list",
+            put(seniority::pin_vacation_period),
+        )
         .route(
             "/api/vacation-bids/periods/:id/bid-windows",
             get(vacation_bids::list_windows),
         )
+        .route(
+            "/api/vacation-bids/periods/:id/bid-status",
+            get(vacation_bids::bid_status),
+        )
+        .route(
+            "/api/vacation-bids/periods/:id/proxy-plan",
+            get(bid_proxies::get_vacation_plan)
+                .put(bid_proxies::put_vacation_plan)
+                .delete(bid_proxies::delete_vacation_plan),
+        )
+        .route(
+            "/api/vacation-bids/periods/:id/proxy-actions",
+            get(bid_proxies::list_vacation_actions),
+        )
         .route(
             "/api/vacation-bids/periods/:id/process-bids",
             post(vacation_bids::process_bids),
         )
+        .route(
+            "/api/vacation-bids/periods/:id/process-bids/preview",
+            get(vacation_bids::preview_bids),
+        )
+        .route(
+            "/api/vacation-bids/periods/:id/rollback-bids",
+            post(vacation_bids::rollback_bids),
+        )
+        .route(
+            "/api/vacation-bids/periods/:id/bid-runs",
+            get(vacation_bids::list_bid_runs),
+        )
         .route(
             "/api/vacation-bids/bid-windows/:id",
             get(vacation_bids::get_window),
@@ -454,12 +656,32 @@ pub fn router(state: AppState) -> Router {
             "/api/vacation-bids/bid-windows/:id/submit",
             post(vacation_bids::submit_bid),
         )
+        // Job-share pairs
+        .route(
+            "/api/job-share-pairs",
+            get(job_shares::list).post(job_shares::create),
+        )
+        .route("/api/job-share-pairs/:id", delete(job_shares::delete))
         // Holidays
         .route("/api/holidays", get(holidays::list).post(holidays::create))
         .route(
             "/api/holidays/:id",
             patch(holidays::update).delete(holidays::delete),
         )
+        // Holiday rotation groups and who received each holiday
+        .route(
+            "/api/holiday-groups",
+            get(holiday_rotation::list_groups).post(holiday_rotation::create_group),
+        )
+        .route(
+            "/api/holiday-groups/:id",
+            delete(holiday_rotation::delete_group).put(holiday_rotation::update_group),
+        )
+        .route(
+            "/api/holiday-awards",
+            get(holiday_rotation::list_awards).post(holiday_rotation::create_award),
+        )
+        .route("/api/holiday-awards/:id", delete(holiday_rotation::delete_award))
         // Notifications (static sub-paths before /:id to avoid param capture)
         .route("/api/notifications", get(notifications::list))
         .route(
@@ -481,6 +703,11 @@ pub fn router(state: AppState) -> Router {
         .route("/api/reports/leave-summary", get(reports::leave_summary))
         .route("/api/reports/ot-by-period", get(reports::ot_by_period))
         .route("/api/reports/work-summary", get(reports::work_summary))
+        .route(
+            "/api/reports/qualification-expirations",
+            get(reports::qualification_expirations),
+        )
+        .route("/api/reports/holiday-rotation", get(holiday_rotation::report))
         // Saved Filters
         .route(
             "/api/saved-filters",
@@ -519,16 +746,71 @@ pub fn router(state: AppState) -> Router {
         .route("/api/staffing/available", get(staffing::available_employees))
         .route("/api/staffing/block-available", get(staffing::block_available))
         .route("/api/staffing/mandatory-ot-order", get(staffing::mandatory_ot_order))
+        .route("/api/staffing/erlang-c", post(staffing::erlang_c))
+        // Call-volume history & forecast
+        .route("/api/call-volume", get(call_volume::list))
+        .route("/api/call-volume/import", post(call_volume::import))
+        .route("/api/call-volume/forecast", get(call_volume::forecast))
         // OT Queue & Hours
         .route("/api/ot/queue", get(ot::get_queue))
         .route("/api/ot/queue/set-position", patch(ot::set_queue_position))
         .route("/api/ot/hours", get(ot::get_hours))
         .route("/api/ot/hours/adjust", post(ot::adjust_hours))
+        // Standing OT availability
+        .route(
+            "/api/ot/availability",
+            get(standing_availability::list).post(standing_availability::create),
+        )
+        .route(
+            "/api/ot/availability/:id/cancel",
+            patch(standing_availability::cancel),
+        )
+        // Mandatory OT rotation
+        .route(
+            "/api/ot/mandatory/events",
+            get(mandatory_ot::list_events).post(mandatory_ot::create_event),
+        )
+        .route(
+            "/api/ot/mandatory/events/:id/void",
+            patch(mandatory_ot::void_event),
+        )
+        .route(
+            "/api/ot/mandatory/exemptions",
+            get(mandatory_ot::list_exemptions).post(mandatory_ot::create_exemption),
+        )
+        .route(
+            "/api/ot/mandatory/exemptions/:id/revoke",
+            patch(mandatory_ot::revoke_exemption),
+        )
         // OT Requests (standalone, decoupled from callout)
         .route(
             "/api/ot-requests",
             get(ot_request::list).post(ot_request::create),
         )
+        // Recurring OT request series (static sub-path before /:id)
+        .route(
+            "/api/ot-requests/series",
+            get(ot_request_series::list).post(ot_request_series::create),
+        )
+        .route(
+            "/api/ot-requests/series/:id",
+            get(ot_request_series::get_one).patch(ot_request_series::update),
+        )
+        .route(
+            "/api/ot-requests/series/:id/cancel",
+            patch(ot_request_series::cancel),
+        )
+        // OT requests auto-posted from coverage gaps (static sub-path before /:id)
+        .route("/api/ot-requests/auto-posts", get(ot_autopost::list))
+        .route("/api/ot-requests/auto-posts/run", post(ot_autopost::run))
+        .route(
+            "/api/ot-requests/auto-posts/:id/publish",
+            post(ot_autopost::publish),
+        )
+        .route(
+            "/api/ot-requests/auto-posts/:id/dismiss",
+            patch(ot_autopost::dismiss),
+        )
         .route(
             "/api/ot-requests/:id",
             get(ot_request::get_one).patch(ot_request::update),
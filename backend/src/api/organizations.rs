@@ -108,6 +108,19 @@ const ALLOWED_SETTING_KEYS: &[&str] = &[
     "max_concurrent_vacation",
     "enable_bump_requests",
     "sellback_periods",
+    "ot_autopost_mode",
+    "ot_autopost_days_ahead",
+    "ot_autopost_min_hours",
+    "ot_autopost_max_hours",
+    "bump_review_hours",
+    "bump_auto_approve",
+    "call_forecast_lookback_weeks",
+    "gap_alert_enabled",
+    "gap_alert_hours_ahead",
+    "gap_alert_escalation_minutes",
+    "gap_alert_scan_minutes",
+    "duty_rotation_max_consecutive_blocks",
+    "seniority_tie_breakers",
 ];
 
 /// Set/update an org setting (admin only). Upserts by key.
@@ -290,11 +290,13 @@ pub async fn list_volunteers(
     Path(event_id): Path<Uuid>,
 ) -> Result<Json<Vec<OtVolunteer>>> {
     // Verify event belongs to org
-    sqlx::query_scalar!(
+    let event = sqlx::query!(
         r#"
-        SELECT ce.id
+        SELECT ce.classification_id, ss.date AS shift_date,
+               st.start_time, st.end_time
         FROM callout_events ce
         JOIN scheduled_shifts ss ON ss.id = ce.scheduled_shift_id
+        JOIN shift_templates st ON st.id = ss.shift_template_id
         WHERE ce.id = $1 AND ss.org_id = $2
         "#,
         event_id,
@@ -320,7 +322,7 @@ pub async fn list_volunteers(
     .fetch_all(&pool)
     .await?;
 
-    let views = volunteers
+    let mut views: Vec<OtVolunteer> = volunteers
         .into_iter()
         .map(|r| OtVolunteer {
             id: r.id,
@@ -329,9 +331,61 @@ pub async fn list_volunteers(
             first_name: r.first_name,
             last_name: r.last_name,
             volunteered_at: r.volunteered_at,
+            is_standing: false,
         })
         .collect();
 
+    // Employees with standing availability covering the shift count as volunteers
+    // too, after those who volunteered for this callout.
+    let standing_ids = crate::services::standing_availability::available_user_ids(
+        &pool,
+        auth.org_id,
+        Some(event.classification_id),
+        event.shift_date,
+        event.start_time,
+        event.end_time,
+    )
+    .await?;
+    let standing_ids: Vec<Uuid> = standing_ids
+        .into_iter()
+        .filter(|id| !views.iter().any(|v| v.user_id == *id))
+        .collect();
+
+    if !standing_ids.is_empty() {
+        let standing = sqlx::query!(
+            r#"
+            SELECT DISTINCT ON (a.user_id)
+                   a.id, a.user_id, u.first_name, u.last_name, a.created_at
+            FROM ot_standing_availability a
+            JOIN users u ON u.id = a.user_id
+            WHERE a.org_id = $1 AND a.user_id = ANY($2)
+              AND a.cancelled_at IS NULL
+              AND a.start_date <= $3 AND a.end_date >= $3
+            ORDER BY a.user_id, a.created_at
+            "#,
+            auth.org_id,
+            &standing_ids,
+            event.shift_date,
+        )
+        .fetch_all(&pool)
+        .await?;
+
+        let mut standing: Vec<OtVolunteer> = standing
+            .into_iter()
+            .map(|r| OtVolunteer {
+                id: r.id,
+                callout_event_id: event_id,
+                user_id: r.user_id,
+                first_name: r.first_name,
+                last_name: r.last_name,
+                volunteered_at: r.created_at,
+                is_standing: true,
+            })
+            .collect();
+        standing.sort_by_key(|v| v.volunteered_at);
+        views.extend(standing);
+    }
+
     Ok(Json(views))
 }
 
@@ -355,7 +409,8 @@ pub async fn advance_step(
     let event = sqlx::query!(
         r#"
         SELECT ce.status AS "status: CalloutStatus",
-               ce.current_step AS "current_step?: CalloutStep"
+               ce.current_step AS "current_step?: CalloutStep",
+               ce.classification_id
         FROM callout_events ce
         JOIN scheduled_shifts ss ON ss.id = ce.scheduled_shift_id
         WHERE ce.id = $1 AND ss.org_id = $2
@@ -375,8 +430,22 @@ pub async fn advance_step(
 
     // CBA (CBA Article 15): Callout steps follow this ordering:
     // 1. Volunteers → 2. Low OT Hours → 3. Inverse Seniority → 4. Equal OT Hours → 5. Mandatory
+    // unless the classification's callout policy defines its own sequence.
     // Supervisors can move forward or backward between steps as needed
     // (e.g., revisit volunteers after checking low OT hours).
+    let policy = crate::services::callout_policy::effective_policy(
+        &pool,
+        auth.org_id,
+        event.classification_id,
+    )
+    .await?;
+    if !policy.contains(&req.step) {
+        return Err(AppError::BadRequest(format!(
+            "Step is not part of the callout policy '{}'",
+            policy.name
+        )));
+    }
+
     if event.current_step.as_ref() == Some(&req.step) {
         return Err(AppError::BadRequest(
             "Already on that step".into(),
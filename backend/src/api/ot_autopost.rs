@@ -0,0 +1,257 @@
+use axum::{
+    extract::{Path, Query, State},
+    Json,
+};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{
+    auth::AuthUser,
+    error::{AppError, Result},
+    models::ot_gap_posting::{
+        OtAutopostRunQuery, OtGapPosting, OtGapPostingQuery, OtGapPostingStatus,
+    },
+    services::ot_autopost::{self, AutopostMode, AutopostRunResult},
+};
+
+/// Fetch a single posting with joined display fields.
+async fn fetch_posting(pool: &PgPool, id: Uuid, org_id: Uuid) -> Result<OtGapPosting> {
+    let r = sqlx::query!(
+        r#"
+        SELECT
+            p.id, p.org_id, p.classification_id,
+            cl.name AS classification_name,
+            p.date, p.start_time, p.end_time,
+            CAST(p.hours AS FLOAT8) AS "hours!",
+            p.shortage,
+            p.status AS "status: OtGapPostingStatus",
+            p.ot_request_id, p.created_at, p.reviewed_by, p.reviewed_at
+        FROM ot_gap_postings p
+        JOIN classifications cl ON cl.id = p.classification_id
+        WHERE p.id = $1 AND p.org_id = $2
+        "#,
+        id,
+        org_id,
+    )
+    .fetch_optional(pool)
+    .await?
+    .ok_or_else(|| AppError::NotFound("OT gap posting not found".into()))?;
+
+    Ok(OtGapPosting {
+        id: r.id,
+        org_id: r.org_id,
+        classification_id: r.classification_id,
+        classification_name: r.classification_name,
+        date: r.date,
+        start_time: r.start_time,
+        end_time: r.end_time,
+        hours: r.hours,
+        shortage: r.shortage,
+        status: r.status,
+        ot_request_id: r.ot_request_id,
+        created_at: r.created_at,
+        reviewed_by: r.reviewed_by,
+        reviewed_at: r.reviewed_at,
+    })
+}
+
+// ---------------------------------------------------------------------------
+// List
+// ---------------------------------------------------------------------------
+
+pub async fn list(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Query(params): Query<OtGapPostingQuery>,
+) -> Result<Json<Vec<OtGapPosting>>> {
+    if !auth.role.can_manage_schedule() {
+        return Err(AppError::Forbidden);
+    }
+
+    let rows = sqlx::query!(
+        r#"
+        SELECT
+            p.id, p.org_id, p.classification_id,
+            cl.name AS classification_name,
+            p.date, p.start_time, p.end_time,
+            CAST(p.hours AS FLOAT8) AS "hours!",
+            p.shortage,
+            p.status AS "status: OtGapPostingStatus",
+            p.ot_request_id, p.created_at, p.reviewed_by, p.reviewed_at
+        FROM ot_gap_postings p
+        JOIN classifications cl ON cl.id = p.classification_id
+        WHERE p.org_id = $1
+          AND ($2::ot_gap_posting_status IS NULL OR p.status = $2)
+          AND ($3::DATE IS NULL OR p.date >= $3)
+          AND ($4::DATE IS NULL OR p.date <= $4)
+          AND ($5::UUID IS NULL OR p.classification_id = $5)
+        ORDER BY p.date, p.start_time, cl.name
+        "#,
+        auth.org_id,
+        params.status as Option<OtGapPostingStatus>,
+        params.date_from,
+        params.date_to,
+        params.classification_id,
+    )
+    .fetch_all(&pool)
+    .await?;
+
+    let results = rows
+        .into_iter()
+        .map(|r| OtGapPosting {
+            id: r.id,
+            org_id: r.org_id,
+            classification_id: r.classification_id,
+            classification_name: r.classification_name,
+            date: r.date,
+            start_time: r.start_time,
+            end_time: r.end_time,
+            hours: r.hours,
+            shortage: r.shortage,
+            status: r.status,
+            ot_request_id: r.ot_request_id,
+            created_at: r.created_at,
+            reviewed_by: r.reviewed_by,
+            reviewed_at: r.reviewed_at,
+        })
+        .collect();
+
+    Ok(Json(results))
+}
+
+// ---------------------------------------------------------------------------
+// Run now (manual trigger)
+// ---------------------------------------------------------------------------
+
+/// Scan for gaps immediately using the org's settings. When auto-posting is
+/// turned off for the org, a manual run still produces drafts.
+pub async fn run(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Query(params): Query<OtAutopostRunQuery>,
+) -> Result<Json<AutopostRunResult>> {
+    if !auth.role.can_manage_schedule() {
+        return Err(AppError::Forbidden);
+    }
+
+    let mode = match AutopostMode::from_setting(
+        &crate::services::org_settings::get_str(&pool, auth.org_id, "ot_autopost_mode", "off")
+            .await,
+    ) {
+        AutopostMode::Off => AutopostMode::Draft,
+        m => m,
+    };
+
+    let result = ot_autopost::run_org_autopost(
+        &pool,
+        auth.org_id,
+        &auth.org_timezone,
+        auth.id,
+        mode,
+        params.dry_run.unwrap_or(false),
+    )
+    .await?;
+
+    Ok(Json(result))
+}
+
+// ---------------------------------------------------------------------------
+// Publish / Dismiss drafts
+// ---------------------------------------------------------------------------
+
+pub async fn publish(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(id): Path<Uuid>,
+) -> Result<Json<OtGapPosting>> {
+    if !auth.role.can_manage_schedule() {
+        return Err(AppError::Forbidden);
+    }
+
+    let mut tx = pool.begin().await?;
+
+    let status = sqlx::query_scalar!(
+        r#"
+        SELECT status AS "status: OtGapPostingStatus"
+        FROM ot_gap_postings
+        WHERE id = $1 AND org_id = $2
+        FOR UPDATE
+        "#,
+        id,
+        auth.org_id,
+    )
+    .fetch_optional(&mut *tx)
+    .await?
+    .ok_or_else(|| AppError::NotFound("OT gap posting not found".into()))?;
+
+    if status != OtGapPostingStatus::Draft {
+        return Err(AppError::Conflict(
+            "Only draft postings can be published".into(),
+        ));
+    }
+
+    let staffing_reason = sqlx::query_scalar!(
+        "SELECT id FROM ot_reasons WHERE org_id = $1 AND code = 'staffing' AND is_active = true",
+        auth.org_id,
+    )
+    .fetch_optional(&mut *tx)
+    .await?;
+
+    let request_id =
+        ot_autopost::publish_posting(&mut tx, id, auth.org_id, auth.id, staffing_reason).await?;
+
+    sqlx::query!(
+        "UPDATE ot_gap_postings SET reviewed_by = $2, reviewed_at = NOW() WHERE id = $1",
+        id,
+        auth.id,
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    crate::services::standing_availability::notify_matching_requests(
+        &pool,
+        auth.org_id,
+        &[request_id],
+    )
+    .await;
+
+    Ok(Json(fetch_posting(&pool, id, auth.org_id).await?))
+}
+
+/// Dismiss a draft. Later runs leave the window alone unless its shortage grows
+/// past the one dismissed, when it is drafted afresh.
+pub async fn dismiss(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(id): Path<Uuid>,
+) -> Result<Json<OtGapPosting>> {
+    if !auth.role.can_manage_schedule() {
+        return Err(AppError::Forbidden);
+    }
+
+    let rows = sqlx::query!(
+        r#"
+        UPDATE ot_gap_postings
+        SET status = 'dismissed', reviewed_by = $3, reviewed_at = NOW()
+        WHERE id = $1 AND org_id = $2 AND status = 'draft'
+        "#,
+        id,
+        auth.org_id,
+        auth.id,
+    )
+    .execute(&pool)
+    .await?
+    .rows_affected();
+
+    if rows == 0 {
+        // Distinguish "missing" from "already reviewed"
+        fetch_posting(&pool, id, auth.org_id).await?;
+        return Err(AppError::Conflict(
+            "Only draft postings can be dismissed".into(),
+        ));
+    }
+
+    Ok(Json(fetch_posting(&pool, id, auth.org_id).await?))
+}
@@ -11,11 +11,13 @@ use crate::{
     auth::AuthUser,
     error::{AppError, Result},
     models::common::{OtType, Paginated},
+    models::mandatory_ot::MandatoryOtEventType,
     models::ot_request::{
         CreateOtRequest, CreateOtRequestAssignment, OtRequestAssignmentRow, OtRequestDetail,
         OtRequestQuery, OtRequestRow, OtRequestStatus, OtRequestVolunteerRow, UpdateOtRequest,
     },
     org_guard,
+    services::mandatory_ot::{EventSource, NewMandatoryOtEvent},
 };
 
 // ---------------------------------------------------------------------------
@@ -48,6 +50,7 @@ pub async fn list(
             r.updated_at,
             r.cancelled_at,
             r.cancelled_by,
+            r.series_id,
             (SELECT COUNT(*) FROM ot_request_volunteers v
              WHERE v.ot_request_id = r.id AND v.withdrawn_at IS NULL) AS "volunteer_count!",
             (SELECT COUNT(*) FROM ot_request_assignments a
@@ -65,6 +68,7 @@ pub async fn list(
           AND ($3::DATE IS NULL OR r.date >= $3)
           AND ($4::DATE IS NULL OR r.date <= $4)
           AND ($5::UUID IS NULL OR r.classification_id = $5)
+          AND ($10::UUID IS NULL OR r.series_id = $10)
           AND (NOT $8::BOOL OR EXISTS (
             SELECT 1 FROM ot_request_volunteers v
             WHERE v.ot_request_id = r.id AND v.user_id = $9 AND v.withdrawn_at IS NULL
@@ -81,6 +85,7 @@ pub async fn list(
         params.offset(),
         volunteered_by_me,
         auth.id,
+        params.series_id as Option<Uuid>,
     )
     .fetch_all(&pool)
     .await?;
@@ -108,6 +113,7 @@ pub async fn list(
             updated_at: r.updated_at,
             cancelled_at: r.cancelled_at,
             cancelled_by: r.cancelled_by,
+            series_id: r.series_id,
             volunteer_count: r.volunteer_count,
             assignment_count: r.assignment_count,
             user_volunteered: r.user_volunteered,
@@ -145,7 +151,8 @@ pub async fn get_one(
             r.created_at,
             r.updated_at,
             r.cancelled_at,
-            r.cancelled_by
+            r.cancelled_by,
+            r.series_id
         FROM ot_requests r
         JOIN classifications cl ON cl.id = r.classification_id
         JOIN users cu ON cu.id = r.created_by
@@ -258,6 +265,7 @@ pub async fn get_one(
         updated_at: row.updated_at,
         cancelled_at: row.cancelled_at,
         cancelled_by: row.cancelled_by,
+        series_id: row.series_id,
         volunteers,
         assignments,
     };
@@ -269,6 +277,39 @@ pub async fn get_one(
 // Create OT Request (admin/supervisor only)
 // ---------------------------------------------------------------------------
 
+/// Compute the length of an OT block in hours, treating `end_time <= start_time`
+/// as crossing midnight.
+pub(crate) fn ot_request_hours(start_time: time::Time, end_time: time::Time) -> Result<f64> {
+    // Reject identical start and end times (would compute as 24h due to midnight-crossing branch)
+    if start_time == end_time {
+        return Err(AppError::BadRequest(
+            "Start time and end time cannot be the same".into(),
+        ));
+    }
+
+    let start_secs = start_time.hour() as f64 * 3600.0
+        + start_time.minute() as f64 * 60.0
+        + start_time.second() as f64;
+    let end_secs = end_time.hour() as f64 * 3600.0
+        + end_time.minute() as f64 * 60.0
+        + end_time.second() as f64;
+    let diff_secs = if end_secs > start_secs {
+        end_secs - start_secs
+    } else {
+        // Crosses midnight
+        (86400.0 - start_secs) + end_secs
+    };
+    let hours = diff_secs / 3600.0;
+
+    if hours <= 0.0 || hours > 24.0 {
+        return Err(AppError::BadRequest(
+            "Invalid time range: hours must be between 0 and 24".into(),
+        ));
+    }
+
+    Ok(hours)
+}
+
 pub async fn create(
     State(pool): State<PgPool>,
     auth: AuthUser,
@@ -289,33 +330,7 @@ pub async fn create(
         org_guard::verify_ot_reason(&pool, reason_id, auth.org_id).await?;
     }
 
-    // Reject identical start and end times (would compute as 24h due to midnight-crossing branch)
-    if req.start_time == req.end_time {
-        return Err(AppError::BadRequest(
-            "Start time and end time cannot be the same".into(),
-        ));
-    }
-
-    // Compute hours from start_time and end_time
-    let start_secs = req.start_time.hour() as f64 * 3600.0
-        + req.start_time.minute() as f64 * 60.0
-        + req.start_time.second() as f64;
-    let end_secs = req.end_time.hour() as f64 * 3600.0
-        + req.end_time.minute() as f64 * 60.0
-        + req.end_time.second() as f64;
-    let diff_secs = if end_secs > start_secs {
-        end_secs - start_secs
-    } else {
-        // Crosses midnight
-        (86400.0 - start_secs) + end_secs
-    };
-    let hours = diff_secs / 3600.0;
-
-    if hours <= 0.0 || hours > 24.0 {
-        return Err(AppError::BadRequest(
-            "Invalid time range: hours must be between 0 and 24".into(),
-        ));
-    }
+    let hours = ot_request_hours(req.start_time, req.end_time)?;
 
     let is_fixed = req.is_fixed_coverage.unwrap_or(false);
 
@@ -343,6 +358,13 @@ pub async fn create(
     .execute(&pool)
     .await?;
 
+    crate::services::standing_availability::notify_matching_requests(
+        &pool,
+        auth.org_id,
+        &[new_id],
+    )
+    .await;
+
     // Re-fetch with joins for the response
     let row = sqlx::query!(
         r#"
@@ -362,7 +384,8 @@ pub async fn create(
             r.created_at,
             r.updated_at,
             r.cancelled_at,
-            r.cancelled_by
+            r.cancelled_by,
+            r.series_id
         FROM ot_requests r
         JOIN classifications cl ON cl.id = r.classification_id
         JOIN users cu ON cu.id = r.created_by
@@ -396,6 +419,7 @@ pub async fn create(
         updated_at: row.updated_at,
         cancelled_at: row.cancelled_at,
         cancelled_by: row.cancelled_by,
+        series_id: row.series_id,
         volunteer_count: 0,
         assignment_count: 0,
         user_volunteered: false,
@@ -545,6 +569,7 @@ pub async fn update(
             r.updated_at,
             r.cancelled_at,
             r.cancelled_by,
+            r.series_id,
             (SELECT COUNT(*) FROM ot_request_volunteers v
              WHERE v.ot_request_id = r.id AND v.withdrawn_at IS NULL) AS "volunteer_count!",
             (SELECT COUNT(*) FROM ot_request_assignments a
@@ -587,6 +612,7 @@ pub async fn update(
         updated_at: row.updated_at,
         cancelled_at: row.cancelled_at,
         cancelled_by: row.cancelled_by,
+        series_id: row.series_id,
         volunteer_count: row.volunteer_count,
         assignment_count: row.assignment_count,
         user_volunteered: row.user_volunteered,
@@ -646,6 +672,23 @@ pub async fn cancel(
     .execute(&mut *tx)
     .await?;
 
+    crate::services::mandatory_ot::void_for_ot_request(&mut tx, id, None, auth.id).await?;
+
+    // A cancelled series occurrence becomes an exception date so later series
+    // edits don't regenerate it.
+    sqlx::query!(
+        r#"
+        UPDATE ot_request_series s
+        SET excluded_dates = array_append(s.excluded_dates, r.date), updated_at = NOW()
+        FROM ot_requests r
+        WHERE r.id = $1 AND s.id = r.series_id
+          AND NOT (r.date = ANY(s.excluded_dates))
+        "#,
+        id,
+    )
+    .execute(&mut *tx)
+    .await?;
+
     tx.commit().await?;
 
     Ok(json_ok())
@@ -989,6 +1032,16 @@ async fn validate_ot_assignment(
             tx, req.user_id, org_id,
             request.date, request.start_time, request.end_time,
         ).await?;
+
+        // Rule 4: Employees with an active mandatory OT exemption cannot be forced.
+        if let Some(reason) = crate::services::mandatory_ot::active_exemption(
+            tx, org_id, req.user_id, request.classification_id, request.date,
+        ).await? {
+            return Err(AppError::BadRequest(format!(
+                "This employee is exempt from mandatory OT on {}: {}",
+                request.date, reason,
+            )));
+        }
     }
     // ────────────────────────────────────────────────────────────────────────
 
@@ -1055,6 +1108,25 @@ async fn create_ot_assignment_record(
     .execute(&mut **tx)
     .await?;
 
+    // Forced OT uses up the employee's turn in the mandatory rotation.
+    if matches!(validated.ot_type, OtType::Mandatory | OtType::MandatoryDayOff) {
+        crate::services::mandatory_ot::record_event(
+            tx,
+            NewMandatoryOtEvent {
+                org_id,
+                classification_id: validated.classification_id,
+                user_id,
+                event_type: MandatoryOtEventType::Forced,
+                counts_as_turn: true,
+                source: EventSource::OtRequestAssignment(assignment_id),
+                notes: None,
+                recorded_by: assigned_by,
+                occurred_at: None,
+            },
+        )
+        .await?;
+    }
+
     // Update request status based on coverage type:
     // Fixed coverage = single-slot, so one assignment fills it.
     // Non-fixed coverage = may need more assignments, so mark partially_filled.
@@ -1220,6 +1292,9 @@ pub async fn cancel_assignment(
         ));
     }
 
+    crate::services::mandatory_ot::void_for_ot_request(&mut tx, id, Some(user_id), auth.id)
+        .await?;
+
     // Revert OT hours tracking (only for non-fixed-coverage, matching the assign logic)
     if !request.is_fixed_coverage {
         let fiscal_year = crate::services::ot::org_fiscal_year(&pool, auth.org_id, request.date).await;
@@ -0,0 +1,594 @@
+use axum::{
+    extract::{Path, Query, State},
+    Json,
+};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{
+    api::ot_request::ot_request_hours,
+    auth::AuthUser,
+    error::{AppError, Result},
+    models::ot_request::OtRequestStatus,
+    models::ot_request_series::{
+        CancelOtRequestSeries, CreateOtRequestSeries, OtRequestSeries, OtRequestSeriesDetail,
+        OtRequestSeriesOccurrence, OtRequestSeriesQuery, OtRequestSeriesSyncResponse,
+        SeriesSyncSummary, UpdateOtRequestSeries,
+    },
+    org_guard,
+    services::ot_series::{sync_occurrences, RecurrenceRule},
+};
+
+/// Tell employees with matching standing availability about newly created
+/// occurrences (best-effort).
+async fn notify_created_occurrences(
+    pool: &PgPool,
+    series_id: Uuid,
+    org_id: Uuid,
+    created: &[time::Date],
+) {
+    if created.is_empty() {
+        return;
+    }
+    match sqlx::query_scalar!(
+        r#"
+        SELECT id FROM ot_requests
+        WHERE series_id = $1 AND org_id = $2 AND date = ANY($3) AND status = 'open'
+        "#,
+        series_id,
+        org_id,
+        created,
+    )
+    .fetch_all(pool)
+    .await
+    {
+        Ok(ids) => {
+            crate::services::standing_availability::notify_matching_requests(pool, org_id, &ids)
+                .await
+        }
+        Err(e) => {
+            tracing::warn!(series_id = %series_id, error = %e, "Failed to load new series occurrences");
+        }
+    }
+}
+
+/// Series with joined display fields, newest first. `id` narrows to one
+/// series (cancelled or not); otherwise `include_cancelled` and
+/// `classification_id` filter the org's series.
+async fn query_series(
+    pool: &PgPool,
+    org_id: Uuid,
+    id: Option<Uuid>,
+    include_cancelled: bool,
+    classification_id: Option<Uuid>,
+) -> Result<Vec<OtRequestSeries>> {
+    let rows = sqlx::query_as!(
+        OtRequestSeries,
+        r#"
+        SELECT
+            s.id, s.org_id, s.classification_id,
+            cl.name AS classification_name,
+            s.ot_reason_id,
+            orr.name AS "ot_reason_name?",
+            s.start_time, s.end_time,
+            CAST(s.hours AS FLOAT8) AS "hours!",
+            s.location, s.is_fixed_coverage, s.notes,
+            s.days_of_week, s.interval_weeks, s.start_date, s.end_date,
+            s.skip_holidays, s.excluded_dates, s.previous_series_id,
+            s.created_by,
+            (cu.first_name || ' ' || cu.last_name) AS "created_by_name!",
+            s.created_at, s.updated_at, s.cancelled_at, s.cancelled_by,
+            (SELECT COUNT(*) FROM ot_requests r
+             WHERE r.series_id = s.id AND r.status != 'cancelled') AS "occurrence_count!"
+        FROM ot_request_series s
+        JOIN classifications cl ON cl.id = s.classification_id
+        JOIN users cu ON cu.id = s.created_by
+        LEFT JOIN ot_reasons orr ON orr.id = s.ot_reason_id
+        WHERE s.org_id = $1
+          AND ($2::UUID IS NULL OR s.id = $2)
+          AND ($2::UUID IS NOT NULL OR $3::BOOL OR s.cancelled_at IS NULL)
+          AND ($4::UUID IS NULL OR s.classification_id = $4)
+        ORDER BY s.start_date DESC, s.start_time
+        "#,
+        org_id,
+        id,
+        include_cancelled,
+        classification_id,
+    )
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+/// Fetch a single series with joined display fields.
+async fn fetch_series(pool: &PgPool, id: Uuid, org_id: Uuid) -> Result<OtRequestSeries> {
+    query_series(pool, org_id, Some(id), true, None)
+        .await?
+        .pop()
+        .ok_or_else(|| AppError::NotFound("OT request series not found".into()))
+}
+
+// ---------------------------------------------------------------------------
+// List / Get
+// ---------------------------------------------------------------------------
+
+pub async fn list(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Query(params): Query<OtRequestSeriesQuery>,
+) -> Result<Json<Vec<OtRequestSeries>>> {
+    if !auth.role.can_manage_schedule() {
+        return Err(AppError::Forbidden);
+    }
+
+    let results = query_series(
+        &pool,
+        auth.org_id,
+        None,
+        params.include_cancelled.unwrap_or(false),
+        params.classification_id,
+    )
+    .await?;
+
+    Ok(Json(results))
+}
+
+pub async fn get_one(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(id): Path<Uuid>,
+) -> Result<Json<OtRequestSeriesDetail>> {
+    if !auth.role.can_manage_schedule() {
+        return Err(AppError::Forbidden);
+    }
+
+    let series = fetch_series(&pool, id, auth.org_id).await?;
+
+    let occurrences = sqlx::query!(
+        r#"
+        SELECT
+            r.id, r.date, r.start_time, r.end_time,
+            r.status AS "status: OtRequestStatus",
+            (SELECT COUNT(*) FROM ot_request_volunteers v
+             WHERE v.ot_request_id = r.id AND v.withdrawn_at IS NULL) AS "volunteer_count!",
+            (SELECT COUNT(*) FROM ot_request_assignments a
+             WHERE a.ot_request_id = r.id AND a.cancelled_at IS NULL) AS "assignment_count!"
+        FROM ot_requests r
+        WHERE r.series_id = $1 AND r.org_id = $2
+        ORDER BY r.date, r.created_at
+        "#,
+        id,
+        auth.org_id,
+    )
+    .fetch_all(&pool)
+    .await?
+    .into_iter()
+    .map(|r| OtRequestSeriesOccurrence {
+        id: r.id,
+        date: r.date,
+        start_time: r.start_time,
+        end_time: r.end_time,
+        status: r.status,
+        volunteer_count: r.volunteer_count,
+        assignment_count: r.assignment_count,
+    })
+    .collect();
+
+    Ok(Json(OtRequestSeriesDetail {
+        series,
+        occurrences,
+    }))
+}
+
+// ---------------------------------------------------------------------------
+// Create Series (admin/supervisor only)
+// ---------------------------------------------------------------------------
+
+pub async fn create(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Json(req): Json<CreateOtRequestSeries>,
+) -> Result<Json<OtRequestSeriesSyncResponse>> {
+    use validator::Validate;
+    req.validate()?;
+
+    if !auth.role.can_manage_schedule() {
+        return Err(AppError::Forbidden);
+    }
+
+    org_guard::verify_classification(&pool, req.classification_id, auth.org_id).await?;
+    if let Some(reason_id) = req.ot_reason_id {
+        org_guard::verify_ot_reason(&pool, reason_id, auth.org_id).await?;
+    }
+
+    let hours = ot_request_hours(req.start_time, req.end_time)?;
+    let interval_weeks = req.interval_weeks.unwrap_or(1);
+
+    let mut days_of_week = req.days_of_week.clone();
+    days_of_week.sort_unstable();
+    days_of_week.dedup();
+
+    RecurrenceRule {
+        days_of_week: &days_of_week,
+        interval_weeks,
+        start_date: req.start_date,
+        end_date: req.end_date,
+        excluded_dates: &req.excluded_dates,
+    }
+    .validate()?;
+
+    let mut tx = pool.begin().await?;
+
+    let series_id = Uuid::new_v4();
+    sqlx::query!(
+        r#"
+        INSERT INTO ot_request_series
+            (id, org_id, classification_id, ot_reason_id, start_time, end_time, hours,
+             location, is_fixed_coverage, notes, days_of_week, interval_weeks,
+             start_date, end_date, skip_holidays, excluded_dates, created_by)
+        VALUES ($1, $2, $3, $4, $5, $6, $7::FLOAT8::NUMERIC, $8, $9, $10, $11, $12,
+                $13, $14, $15, $16, $17)
+        "#,
+        series_id,
+        auth.org_id,
+        req.classification_id,
+        req.ot_reason_id,
+        req.start_time,
+        req.end_time,
+        hours,
+        req.location,
+        req.is_fixed_coverage.unwrap_or(false),
+        req.notes,
+        &days_of_week,
+        interval_weeks,
+        req.start_date,
+        req.end_date,
+        req.skip_holidays.unwrap_or(true),
+        &req.excluded_dates,
+        auth.id,
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    let summary = sync_occurrences(&mut tx, series_id, auth.org_id, auth.id, req.start_date).await?;
+    if summary.created.is_empty() {
+        return Err(AppError::BadRequest(
+            "Recurrence rule does not produce any dates in the given range".into(),
+        ));
+    }
+
+    tx.commit().await?;
+
+    notify_created_occurrences(&pool, series_id, auth.org_id, &summary.created).await;
+
+    let series = fetch_series(&pool, series_id, auth.org_id).await?;
+    Ok(Json(OtRequestSeriesSyncResponse { series, summary }))
+}
+
+// ---------------------------------------------------------------------------
+// Update Series from a date forward (admin/supervisor only)
+// ---------------------------------------------------------------------------
+
+pub async fn update(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(id): Path<Uuid>,
+    Json(req): Json<UpdateOtRequestSeries>,
+) -> Result<Json<OtRequestSeriesSyncResponse>> {
+    use validator::Validate;
+    req.validate()?;
+
+    if !auth.role.can_manage_schedule() {
+        return Err(AppError::Forbidden);
+    }
+
+    // Manual length validation for double-Option fields (derive(Validate) can't reach inner strings)
+    if let Some(Some(ref loc)) = req.location {
+        if loc.len() > 500 {
+            return Err(AppError::BadRequest(
+                "Location too long (max 500 chars)".into(),
+            ));
+        }
+    }
+    if let Some(Some(ref n)) = req.notes {
+        if n.len() > 2000 {
+            return Err(AppError::BadRequest(
+                "Notes too long (max 2000 chars)".into(),
+            ));
+        }
+    }
+    if let Some(Some(reason_id)) = &req.ot_reason_id {
+        org_guard::verify_ot_reason(&pool, *reason_id, auth.org_id).await?;
+    }
+
+    let mut tx = pool.begin().await?;
+
+    let existing = sqlx::query!(
+        r#"
+        SELECT s.classification_id, s.ot_reason_id, s.start_time, s.end_time, s.location,
+               s.is_fixed_coverage, s.notes, s.days_of_week, s.interval_weeks, s.start_date,
+               s.end_date, s.skip_holidays, s.excluded_dates, s.cancelled_at,
+               (SELECT n.id FROM ot_request_series n WHERE n.previous_series_id = s.id)
+                   AS successor_id
+        FROM ot_request_series s
+        WHERE s.id = $1 AND s.org_id = $2
+        FOR UPDATE
+        "#,
+        id,
+        auth.org_id,
+    )
+    .fetch_optional(&mut *tx)
+    .await?
+    .ok_or_else(|| AppError::NotFound("OT request series not found".into()))?;
+
+    if existing.cancelled_at.is_some() {
+        return Err(AppError::Conflict(
+            "Cannot update a cancelled OT request series".into(),
+        ));
+    }
+    if req.effective_from < existing.start_date {
+        return Err(AppError::BadRequest(
+            "effective_from must be on or after the series start_date".into(),
+        ));
+    }
+
+    // A series continued by an earlier split keeps its dates, and only its
+    // successor can be split again
+    let end_date = req.end_date.unwrap_or(existing.end_date);
+    let split = req.effective_from > existing.start_date;
+    if let Some(successor_id) = existing.successor_id {
+        if end_date != existing.end_date {
+            return Err(AppError::Conflict(format!(
+                "This series continues as series {successor_id}; its end_date cannot change"
+            )));
+        }
+        if split {
+            return Err(AppError::Conflict(format!(
+                "This series continues as series {successor_id}; edit that series from a later date"
+            )));
+        }
+    }
+
+    let start_time = req.start_time.unwrap_or(existing.start_time);
+    let end_time = req.end_time.unwrap_or(existing.end_time);
+    let hours = ot_request_hours(start_time, end_time)?;
+    let interval_weeks = req.interval_weeks.unwrap_or(existing.interval_weeks);
+    let mut days_of_week = req.days_of_week.clone().unwrap_or(existing.days_of_week);
+    days_of_week.sort_unstable();
+    days_of_week.dedup();
+    let ot_reason_id = req.ot_reason_id.unwrap_or(existing.ot_reason_id);
+    let location = req.location.unwrap_or(existing.location);
+    let notes = req.notes.unwrap_or(existing.notes);
+    let is_fixed_coverage = req.is_fixed_coverage.unwrap_or(existing.is_fixed_coverage);
+    let skip_holidays = req.skip_holidays.unwrap_or(existing.skip_holidays);
+
+    // From the first day the whole series changes; otherwise the current rule
+    // keeps the dates before the cut-off and a successor takes over from it
+    let (start_date, excluded_dates) = if split {
+        let later: Vec<time::Date> = existing
+            .excluded_dates
+            .iter()
+            .copied()
+            .filter(|d| *d >= req.effective_from)
+            .collect();
+        (req.effective_from, later)
+    } else {
+        (existing.start_date, existing.excluded_dates.clone())
+    };
+
+    RecurrenceRule {
+        days_of_week: &days_of_week,
+        interval_weeks,
+        start_date,
+        end_date,
+        excluded_dates: &excluded_dates,
+    }
+    .validate()?;
+
+    let series_id = if split {
+        let previous_end = existing.end_date.min(req.effective_from - time::Duration::days(1));
+        sqlx::query!(
+            "UPDATE ot_request_series SET end_date = $3, updated_at = NOW() WHERE id = $1 AND org_id = $2",
+            id,
+            auth.org_id,
+            previous_end,
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        let successor_id = Uuid::new_v4();
+        sqlx::query!(
+            r#"
+            INSERT INTO ot_request_series
+                (id, org_id, classification_id, ot_reason_id, start_time, end_time, hours,
+                 location, is_fixed_coverage, notes, days_of_week, interval_weeks,
+                 start_date, end_date, skip_holidays, excluded_dates, previous_series_id,
+                 created_by)
+            VALUES ($1, $2, $3, $4, $5, $6, $7::FLOAT8::NUMERIC, $8, $9, $10, $11, $12,
+                    $13, $14, $15, $16, $17, $18)
+            "#,
+            successor_id,
+            auth.org_id,
+            existing.classification_id,
+            ot_reason_id,
+            start_time,
+            end_time,
+            hours,
+            location,
+            is_fixed_coverage,
+            notes,
+            &days_of_week,
+            interval_weeks,
+            start_date,
+            end_date,
+            skip_holidays,
+            &excluded_dates,
+            id,
+            auth.id,
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        // The successor owns every occurrence from the cut-off on
+        sqlx::query!(
+            "UPDATE ot_requests SET series_id = $3 WHERE series_id = $1 AND org_id = $2 AND date >= $4",
+            id,
+            auth.org_id,
+            successor_id,
+            req.effective_from,
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        successor_id
+    } else {
+        sqlx::query!(
+            r#"
+            UPDATE ot_request_series SET
+                start_time = $3,
+                end_time = $4,
+                hours = $5::FLOAT8::NUMERIC,
+                ot_reason_id = $6,
+                location = $7,
+                is_fixed_coverage = $8,
+                notes = $9,
+                days_of_week = $10,
+                interval_weeks = $11,
+                end_date = $12,
+                skip_holidays = $13,
+                updated_at = NOW()
+            WHERE id = $1 AND org_id = $2
+            "#,
+            id,
+            auth.org_id,
+            start_time,
+            end_time,
+            hours,
+            ot_reason_id,
+            location,
+            is_fixed_coverage,
+            notes,
+            &days_of_week,
+            interval_weeks,
+            end_date,
+            skip_holidays,
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        id
+    };
+
+    let summary =
+        sync_occurrences(&mut tx, series_id, auth.org_id, auth.id, req.effective_from).await?;
+
+    tx.commit().await?;
+
+    notify_created_occurrences(&pool, series_id, auth.org_id, &summary.created).await;
+
+    let series = fetch_series(&pool, series_id, auth.org_id).await?;
+    Ok(Json(OtRequestSeriesSyncResponse { series, summary }))
+}
+
+// ---------------------------------------------------------------------------
+// Cancel Series from a date forward (admin/supervisor only)
+// ---------------------------------------------------------------------------
+
+pub async fn cancel(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(id): Path<Uuid>,
+    Json(req): Json<CancelOtRequestSeries>,
+) -> Result<Json<OtRequestSeriesSyncResponse>> {
+    if !auth.role.can_manage_schedule() {
+        return Err(AppError::Forbidden);
+    }
+
+    let mut tx = pool.begin().await?;
+
+    // The series and every successor split off it by later edits, in date order
+    let chain = sqlx::query!(
+        r#"
+        WITH RECURSIVE chain AS (
+            SELECT id FROM ot_request_series WHERE id = $1 AND org_id = $2
+            UNION ALL
+            SELECT n.id FROM ot_request_series n JOIN chain c ON n.previous_series_id = c.id
+        )
+        SELECT s.id, s.start_date, s.end_date, s.cancelled_at
+        FROM ot_request_series s
+        WHERE s.id IN (SELECT id FROM chain)
+        ORDER BY s.start_date
+        FOR UPDATE
+        "#,
+        id,
+        auth.org_id,
+    )
+    .fetch_all(&mut *tx)
+    .await?;
+
+    let existing = chain
+        .iter()
+        .find(|s| s.id == id)
+        .ok_or_else(|| AppError::NotFound("OT request series not found".into()))?;
+    if existing.cancelled_at.is_some() {
+        return Err(AppError::Conflict(
+            "OT request series is already cancelled".into(),
+        ));
+    }
+    let last_end = chain
+        .iter()
+        .filter(|s| s.cancelled_at.is_none())
+        .map(|s| s.end_date)
+        .max()
+        .unwrap_or(existing.end_date);
+    if req.effective_from > last_end {
+        return Err(AppError::BadRequest(
+            "effective_from is after the series end_date".into(),
+        ));
+    }
+
+    let mut summary = SeriesSyncSummary::default();
+    for series in chain
+        .iter()
+        .filter(|s| s.cancelled_at.is_none() && s.end_date >= req.effective_from)
+    {
+        if req.effective_from <= series.start_date {
+            // Nothing left before the cut-off: cancel the series as a whole
+            sqlx::query!(
+                r#"
+                UPDATE ot_request_series
+                SET cancelled_at = NOW(), cancelled_by = $3, updated_at = NOW()
+                WHERE id = $1 AND org_id = $2
+                "#,
+                series.id,
+                auth.org_id,
+                auth.id,
+            )
+            .execute(&mut *tx)
+            .await?;
+        } else {
+            // Truncate the series so it ends the day before the cut-off
+            let new_end = req.effective_from - time::Duration::days(1);
+            sqlx::query!(
+                "UPDATE ot_request_series SET end_date = $3, updated_at = NOW() WHERE id = $1 AND org_id = $2",
+                series.id,
+                auth.org_id,
+                new_end,
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        let synced =
+            sync_occurrences(&mut tx, series.id, auth.org_id, auth.id, req.effective_from).await?;
+        summary.created.extend(synced.created);
+        summary.updated.extend(synced.updated);
+        summary.cancelled.extend(synced.cancelled);
+        summary.preserved.extend(synced.preserved);
+    }
+
+    tx.commit().await?;
+
+    let series = fetch_series(&pool, id, auth.org_id).await?;
+    Ok(Json(OtRequestSeriesSyncResponse { series, summary }))
+}
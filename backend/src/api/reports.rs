@@ -13,9 +13,10 @@ use crate::{
     error::{AppError, Result},
     models::report::{
         CoverageReport, LeaveReportQuery, LeaveSummaryReport, OtByPeriodEntry, OtByPeriodQuery,
-        OtByPeriodReport, OtReportQuery, OtSummaryReport, ReportQuery, WorkSummaryQuery,
-        WorkSummaryReport,
+        OtByPeriodReport, OtReportQuery, OtSummaryReport, QualificationExpiryEntry,
+        QualificationExpiryQuery, ReportQuery, WorkSummaryQuery, WorkSummaryReport,
     },
+    services::timezone::org_today,
 };
 
 pub async fn coverage(
@@ -371,3 +372,73 @@ pub async fn work_summary(
 
     Ok(Json(result))
 }
+
+/// Qualification grants expiring within `within_days` (default 90), lapsed ones
+/// included, grouped by the holder's team in the current schedule period.
+pub async fn qualification_expirations(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Query(q): Query<QualificationExpiryQuery>,
+) -> Result<Json<Vec<QualificationExpiryEntry>>> {
+    if !auth.role.is_admin() {
+        return Err(AppError::Forbidden);
+    }
+
+    let within_days = q.within_days.unwrap_or(90);
+    if !(0..=730).contains(&within_days) {
+        return Err(AppError::BadRequest("within_days must be between 0 and 730".into()));
+    }
+    let today = org_today(&auth.org_timezone);
+    let horizon = today + time::Duration::days(within_days);
+
+    let rows = sqlx::query!(
+        r#"
+        SELECT team.id AS "team_id?", team.name AS "team_name?",
+               u.id AS user_id, u.first_name, u.last_name,
+               q.id AS qualification_id, q.name AS qualification_name,
+               uq.issued_on, uq.expires_on AS "expires_on!"
+        FROM user_qualifications uq
+        JOIN qualifications q ON q.id = uq.qualification_id
+        JOIN users u ON u.id = uq.user_id
+        LEFT JOIN LATERAL (
+            SELECT t.id, t.name
+            FROM slot_assignments sa
+            JOIN schedule_periods sp ON sp.id = sa.period_id
+            JOIN shift_slots sl ON sl.id = sa.slot_id
+            JOIN teams t ON t.id = sl.team_id
+            WHERE sa.user_id = u.id AND sp.start_date <= $2
+            ORDER BY sp.start_date DESC
+            LIMIT 1
+        ) team ON true
+        WHERE q.org_id = $1
+          AND u.is_active = true
+          AND uq.expires_on <= $3
+          AND ($4::uuid IS NULL OR team.id = $4)
+        ORDER BY team.name NULLS LAST, uq.expires_on, u.last_name, u.first_name, q.name
+        "#,
+        auth.org_id,
+        today,
+        horizon,
+        q.team_id,
+    )
+    .fetch_all(&pool)
+    .await?;
+
+    let entries = rows
+        .into_iter()
+        .map(|r| QualificationExpiryEntry {
+            team_id: r.team_id,
+            team_name: r.team_name,
+            user_id: r.user_id,
+            first_name: r.first_name,
+            last_name: r.last_name,
+            qualification_id: r.qualification_id,
+            qualification_name: r.qualification_name,
+            issued_on: r.issued_on,
+            days_remaining: (r.expires_on - today).whole_days(),
+            expires_on: r.expires_on,
+        })
+        .collect();
+
+    Ok(Json(entries))
+}
@@ -234,7 +234,7 @@ pub async fn list_periods(
         SELECT id, org_id, name, start_date, end_date, is_active,
                status AS "status: BidPeriodStatus",
                bid_opens_at, bid_closes_at,
-               bargaining_unit,
+               bargaining_unit, seniority_list_id,
                created_at, updated_at
         FROM schedule_periods
         WHERE org_id = $1
@@ -262,7 +262,7 @@ pub async fn get_period(
         SELECT id, org_id, name, start_date, end_date, is_active,
                status AS "status: BidPeriodStatus",
                bid_opens_at, bid_closes_at,
-               bargaining_unit,
+               bargaining_unit, seniority_list_id,
                created_at, updated_at
         FROM schedule_periods
         WHERE id = $1 AND org_id = $2
@@ -297,7 +297,7 @@ pub async fn create_period(
         RETURNING id, org_id, name, start_date, end_date, is_active,
                   status AS "status: BidPeriodStatus",
                   bid_opens_at, bid_closes_at,
-                  bargaining_unit,
+                  bargaining_unit, seniority_list_id,
                   created_at, updated_at
         "#,
         Uuid::new_v4(),
@@ -387,7 +387,7 @@ pub async fn update_period(
         RETURNING id, org_id, name, start_date, end_date, is_active,
                   status AS "status: BidPeriodStatus",
                   bid_opens_at, bid_closes_at,
-                  bargaining_unit,
+                  bargaining_unit, seniority_list_id,
                   created_at, updated_at
         "#,
         id,
@@ -423,12 +423,24 @@ pub async fn assign_slot(
     org_guard::verify_user(&pool, req.user_id, auth.org_id).await?;
     org_guard::verify_period(&pool, period_id, auth.org_id).await?;
 
+    let mut tx = pool.begin().await?;
+
+    // Assigning the whole slot replaces a job-share pair's split of it
+    sqlx::query!(
+        "DELETE FROM slot_assignments WHERE slot_id = $1 AND period_id = $2 AND days_of_week IS NOT NULL",
+        req.slot_id,
+        period_id,
+    )
+    .execute(&mut *tx)
+    .await?;
+
     let row = sqlx::query_as!(
         SlotAssignment,
         r#"
         INSERT INTO slot_assignments (id, slot_id, user_id, period_id)
         VALUES ($1, $2, $3, $4)
-        ON CONFLICT (slot_id, period_id) DO UPDATE SET user_id = EXCLUDED.user_id, updated_at = NOW()
+        ON CONFLICT (slot_id, period_id) WHERE days_of_week IS NULL
+        DO UPDATE SET user_id = EXCLUDED.user_id, updated_at = NOW()
         RETURNING id, slot_id, user_id, period_id, created_at, updated_at
         "#,
         Uuid::new_v4(),
@@ -436,9 +448,11 @@ pub async fn assign_slot(
         req.user_id,
         period_id,
     )
-    .fetch_one(&pool)
+    .fetch_one(&mut *tx)
     .await?;
 
+    tx.commit().await?;
+
     Ok(Json(row))
 }
 
@@ -471,7 +485,8 @@ pub async fn list_period_assignments(
             sa.id                   AS "assignment_id?",
             sa.user_id              AS "user_id?",
             u.first_name            AS "user_first_name?",
-            u.last_name             AS "user_last_name?"
+            u.last_name             AS "user_last_name?",
+            sa.days_of_week         AS "assigned_days_of_week?"
         FROM shift_slots sl
         JOIN teams t ON t.id = sl.team_id
         JOIN shift_templates st ON st.id = sl.shift_template_id
@@ -481,7 +496,7 @@ pub async fn list_period_assignments(
         WHERE t.org_id = $1
           AND t.is_active = true
           AND sl.is_active = true
-        ORDER BY t.name, st.start_time, cl.abbreviation
+        ORDER BY t.name, st.start_time, cl.abbreviation, sa.days_of_week NULLS FIRST
         "#,
         auth.org_id,
         period_id,
@@ -508,6 +523,7 @@ pub async fn list_period_assignments(
             user_id: r.user_id,
             user_first_name: r.user_first_name,
             user_last_name: r.user_last_name,
+            assigned_days_of_week: r.assigned_days_of_week,
         })
         .collect();
 
@@ -0,0 +1,697 @@
+use axum::{
+    extract::{Path, Query, State},
+    response::Response,
+    Json,
+};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{
+    api::{
+        helpers::{ensure_rows_affected, export_response, json_ok},
+        notifications::{create_notification, CreateNotificationParams},
+    },
+    auth::AuthUser,
+    error::{AppError, Result},
+    models::seniority::{
+        CreateSeniorityDisputeRequest, CreateStatusIntervalRequest, EmploymentStatusInterval,
+        PinSeniorityListRequest, PublishSeniorityListRequest, RecomputeSeniorityRequest,
+        ResolveSeniorityDisputeRequest, SeniorityAuditEntry, SeniorityDispute,
+        SeniorityDisputeQuery, SeniorityDisputeStatus, SeniorityExplanation, SeniorityList,
+        SeniorityListDetail, SeniorityListExportQuery, SeniorityListQuery, SeniorityListType,
+        UpdateStatusIntervalRequest,
+    },
+    models::user::EmployeeStatus,
+    org_guard,
+    services::{seniority, timezone::org_today},
+};
+
+/// GET /api/seniority-lists?list_type=
+/// Published lists, newest first. Visible to everyone in the org.
+pub async fn list(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Query(q): Query<SeniorityListQuery>,
+) -> Result<Json<Vec<SeniorityList>>> {
+    Ok(Json(seniority::list(&pool, auth.org_id, &q).await?))
+}
+
+/// POST /api/seniority-lists
+/// Admin only. Publishes a snapshot of the current seniority order.
+pub async fn publish(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Json(req): Json<PublishSeniorityListRequest>,
+) -> Result<Json<SeniorityListDetail>> {
+    use validator::Validate;
+    req.validate()?;
+
+    if !auth.role.is_admin() {
+        return Err(AppError::Forbidden);
+    }
+
+    let today = org_today(&auth.org_timezone);
+    let id = seniority::publish(&pool, auth.org_id, auth.id, &req, today).await?;
+    Ok(Json(seniority::detail(&pool, auth.org_id, id).await?))
+}
+
+/// GET /api/seniority-lists/:id?format=json|csv|pdf
+pub async fn get_one(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(id): Path<Uuid>,
+    Query(q): Query<SeniorityListExportQuery>,
+) -> Result<Response> {
+    let detail = seniority::detail(&pool, auth.org_id, id).await?;
+    let filename = format!("seniority-list-{}", detail.list.published_at.date());
+    Ok(export_response(q.format, detail, seniority::to_table, &filename))
+}
+
+async fn fetch_disputes(
+    pool: &PgPool,
+    org_id: Uuid,
+    id: Option<Uuid>,
+    user_id: Option<Uuid>,
+    q: &SeniorityDisputeQuery,
+) -> Result<Vec<SeniorityDispute>> {
+    let rows = sqlx::query_as!(
+        SeniorityDispute,
+        r#"
+        SELECT d.id, d.list_id, l.title AS list_title,
+               l.list_type AS "list_type: SeniorityListType",
+               d.user_id, e.first_name, e.last_name, e.rank,
+               e.seniority_date AS listed_date,
+               d.reason, d.requested_date,
+               d.status AS "status: SeniorityDisputeStatus",
+               d.corrected_date, d.resolution_notes, d.resolved_by, d.resolved_at, d.created_at
+        FROM seniority_disputes d
+        JOIN seniority_lists l         ON l.id = d.list_id
+        JOIN seniority_list_entries e  ON e.list_id = d.list_id AND e.user_id = d.user_id
+        WHERE d.org_id = $1
+          AND ($2::UUID IS NULL OR d.id = $2)
+          AND ($3::UUID IS NULL OR d.user_id = $3)
+          AND ($4::seniority_dispute_status IS NULL OR d.status = $4)
+          AND ($5::UUID IS NULL OR d.list_id = $5)
+        ORDER BY d.created_at DESC
+        "#,
+        org_id,
+        id,
+        user_id,
+        q.status as Option<SeniorityDisputeStatus>,
+        q.list_id,
+    )
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+async fn fetch_dispute(pool: &PgPool, org_id: Uuid, id: Uuid) -> Result<SeniorityDispute> {
+    let q = SeniorityDisputeQuery { status: None, list_id: None };
+    fetch_disputes(pool, org_id, Some(id), None, &q)
+        .await?
+        .pop()
+        .ok_or_else(|| AppError::NotFound("Seniority dispute not found".into()))
+}
+
+/// POST /api/seniority-lists/:id/disputes
+/// An employee disputes their own placement on a published list.
+pub async fn create_dispute(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(list_id): Path<Uuid>,
+    Json(req): Json<CreateSeniorityDisputeRequest>,
+) -> Result<Json<SeniorityDispute>> {
+    use validator::Validate;
+    req.validate()?;
+
+    let on_list = sqlx::query_scalar!(
+        r#"
+        SELECT EXISTS (
+            SELECT 1 FROM seniority_list_entries e
+            JOIN seniority_lists l ON l.id = e.list_id
+            WHERE e.list_id = $1 AND l.org_id = $2 AND e.user_id = $3
+        ) AS "on_list!"
+        "#,
+        list_id,
+        auth.org_id,
+        auth.id,
+    )
+    .fetch_one(&pool)
+    .await?;
+    if !on_list {
+        return Err(AppError::NotFound("You are not on this seniority list".into()));
+    }
+
+    let id = sqlx::query_scalar!(
+        r#"
+        INSERT INTO seniority_disputes (org_id, list_id, user_id, reason, requested_date)
+        VALUES ($1, $2, $3, $4, $5)
+        RETURNING id
+        "#,
+        auth.org_id,
+        list_id,
+        auth.id,
+        req.reason,
+        req.requested_date,
+    )
+    .fetch_one(&pool)
+    .await?;
+
+    Ok(Json(fetch_dispute(&pool, auth.org_id, id).await?))
+}
+
+/// GET /api/seniority-disputes?status=&list_id=
+/// Managers see every dispute; employees see their own.
+pub async fn list_disputes(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Query(q): Query<SeniorityDisputeQuery>,
+) -> Result<Json<Vec<SeniorityDispute>>> {
+    let user_id = (!auth.role.can_manage_schedule()).then_some(auth.id);
+    Ok(Json(fetch_disputes(&pool, auth.org_id, None, user_id, &q).await?))
+}
+
+/// POST /api/seniority-disputes/:id/resolve
+/// Admin only. Upholding corrects the employee's seniority record to
+/// `corrected_date` (the published list itself is never changed; the
+/// correction shows on the next one) and audits the change.
+pub async fn resolve_dispute(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(id): Path<Uuid>,
+    Json(req): Json<ResolveSeniorityDisputeRequest>,
+) -> Result<Json<SeniorityDispute>> {
+    use validator::Validate;
+    req.validate()?;
+
+    if !auth.role.is_admin() {
+        return Err(AppError::Forbidden);
+    }
+
+    let corrected_date = match (&req.status, req.corrected_date) {
+        (SeniorityDisputeStatus::Upheld, Some(date)) => Some(date),
+        (SeniorityDisputeStatus::Upheld, None) => {
+            return Err(AppError::BadRequest(
+                "corrected_date is required to uphold a dispute".into(),
+            ))
+        }
+        (SeniorityDisputeStatus::Denied, _) => None,
+        _ => {
+            return Err(AppError::BadRequest(
+                "A dispute is resolved as upheld or denied".into(),
+            ))
+        }
+    };
+
+    let mut tx = pool.begin().await?;
+
+    let resolved = sqlx::query!(
+        r#"
+        UPDATE seniority_disputes d
+        SET status = $3, corrected_date = $4, resolution_notes = $5,
+            resolved_by = $6, resolved_at = NOW()
+        FROM seniority_lists l
+        WHERE d.id = $1 AND d.org_id = $2 AND d.status = 'open' AND l.id = d.list_id
+        RETURNING d.user_id, l.list_type AS "list_type: SeniorityListType"
+        "#,
+        id,
+        auth.org_id,
+        req.status as SeniorityDisputeStatus,
+        corrected_date,
+        req.notes,
+        auth.id,
+    )
+    .fetch_optional(&mut *tx)
+    .await?;
+
+    let Some(resolved) = resolved else {
+        // Distinguish a dispute that's already been resolved from one that doesn't exist
+        fetch_dispute(&pool, auth.org_id, id).await?;
+        return Err(AppError::Conflict("This dispute has already been closed".into()));
+    };
+
+    if let Some(date) = corrected_date {
+        let before = seniority::current_dates(&mut tx, resolved.user_id).await?;
+        seniority::set_date(&mut tx, auth.org_id, resolved.user_id, resolved.list_type, Some(date))
+            .await?;
+        seniority::audit_changes(
+            &mut tx,
+            auth.org_id,
+            resolved.user_id,
+            &before,
+            "dispute",
+            Some(id),
+            Some(auth.id),
+        )
+        .await?;
+    }
+
+    tx.commit().await?;
+
+    let dispute = fetch_dispute(&pool, auth.org_id, id).await?;
+
+    // Let the employee know (best-effort, after commit)
+    let message = match dispute.corrected_date {
+        Some(date) => format!(
+            "Your dispute of \"{}\" was upheld; your seniority date is now {}",
+            dispute.list_title, date,
+        ),
+        None => format!("Your dispute of \"{}\" was denied", dispute.list_title),
+    };
+    let _ = create_notification(
+        &pool,
+        CreateNotificationParams {
+            org_id: auth.org_id,
+            user_id: dispute.user_id,
+            notification_type: "seniority_dispute_resolved",
+            title: "Seniority dispute resolved",
+            message: &message,
+            link: Some("/seniority"),
+            source_type: Some("seniority_dispute"),
+            source_id: Some(id),
+        },
+    )
+    .await;
+
+    Ok(Json(dispute))
+}
+
+/// POST /api/seniority-disputes/:id/withdraw
+/// The employee who filed an open dispute withdraws it.
+pub async fn withdraw_dispute(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(id): Path<Uuid>,
+) -> Result<Json<serde_json::Value>> {
+    let rows = sqlx::query!(
+        r#"
+        UPDATE seniority_disputes
+        SET status = 'withdrawn', resolved_at = NOW()
+        WHERE id = $1 AND org_id = $2 AND user_id = $3 AND status = 'open'
+        "#,
+        id,
+        auth.org_id,
+        auth.id,
+    )
+    .execute(&pool)
+    .await?
+    .rows_affected();
+
+    ensure_rows_affected(rows, "Open seniority dispute")?;
+    Ok(json_ok())
+}
+
+/// GET /api/users/:id/seniority-history
+/// Every change to the employee's seniority dates, oldest first.
+pub async fn history(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(user_id): Path<Uuid>,
+) -> Result<Json<Vec<SeniorityAuditEntry>>> {
+    if !auth.role.can_manage_schedule() && auth.id != user_id {
+        return Err(AppError::Forbidden);
+    }
+    org_guard::verify_user(&pool, user_id, auth.org_id).await?;
+
+    let rows = sqlx::query_as!(
+        SeniorityAuditEntry,
+        r#"
+        SELECT a.id, a.seniority_type AS "seniority_type: SeniorityListType",
+               a.old_date, a.new_date, a.source, a.dispute_id, a.changed_by,
+               (cb.first_name || ' ' || cb.last_name) AS "changed_by_name?",
+               a.created_at
+        FROM seniority_audit a
+        LEFT JOIN users cb ON cb.id = a.changed_by
+        WHERE a.user_id = $1 AND a.org_id = $2
+        ORDER BY a.created_at, a.id
+        "#,
+        user_id,
+        auth.org_id,
+    )
+    .fetch_all(&pool)
+    .await?;
+
+    Ok(Json(rows))
+}
+
+/// Check a list to be pinned is the org's.
+async fn verify_list(pool: &PgPool, list_id: Option<Uuid>, org_id: Uuid) -> Result<()> {
+    let Some(list_id) = list_id else { return Ok(()) };
+    let found = sqlx::query_scalar!(
+        r#"SELECT EXISTS (SELECT 1 FROM seniority_lists WHERE id = $1 AND org_id = $2) AS "found!""#,
+        list_id,
+        org_id,
+    )
+    .fetch_one(pool)
+    .await?;
+    if !found {
+        return Err(AppError::NotFound("Seniority list not found".into()));
+    }
+    Ok(())
+}
+
+/// PUT /api/schedule/periods/:id/seniority-list
+/// Admin only, draft periods only. Bid windows are then ordered by the
+/// pinned list's ranks; employees not on it follow, by live seniority.
+pub async fn pin_schedule_period(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(period_id): Path<Uuid>,
+    Json(req): Json<PinSeniorityListRequest>,
+) -> Result<Json<serde_json::Value>> {
+    if !auth.role.is_admin() {
+        return Err(AppError::Forbidden);
+    }
+    verify_list(&pool, req.seniority_list_id, auth.org_id).await?;
+
+    let rows = sqlx::query!(
+        r#"
+        UPDATE schedule_periods SET seniority_list_id = $3, updated_at = NOW()
+        WHERE id = $1 AND org_id = $2 AND status = 'draft'
+        "#,
+        period_id,
+        auth.org_id,
+        req.seniority_list_id,
+    )
+    .execute(&pool)
+    .await?
+    .rows_affected();
+
+    ensure_rows_affected(rows, "Draft schedule period")?;
+    Ok(json_ok())
+}
+
+/// PUT /api/vacation-bids/periods/:id/seniority-list
+/// Admin only, draft periods only. As for schedule periods.
+pub async fn pin_vacation_period(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(period_id): Path<Uuid>,
+    Json(req): Json<PinSeniorityListRequest>,
+) -> Result<Json<serde_json::Value>> {
+    if !auth.role.is_admin() {
+        return Err(AppError::Forbidden);
+    }
+    verify_list(&pool, req.seniority_list_id, auth.org_id).await?;
+
+    let rows = sqlx::query!(
+        r#"
+        UPDATE vacation_bid_periods SET seniority_list_id = $3
+        WHERE id = $1 AND org_id = $2 AND status = 'draft'
+        "#,
+        period_id,
+        auth.org_id,
+        req.seniority_list_id,
+    )
+    .execute(&pool)
+    .await?
+    .rows_affected();
+
+    ensure_rows_affected(rows, "Draft vacation bid period")?;
+    Ok(json_ok())
+}
+
+/// GET /api/users/:id/status-history
+/// The employee's recorded LOA, LWOP and layoff absences, oldest first.
+pub async fn status_history(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(user_id): Path<Uuid>,
+) -> Result<Json<Vec<EmploymentStatusInterval>>> {
+    if !auth.role.can_manage_schedule() && auth.id != user_id {
+        return Err(AppError::Forbidden);
+    }
+    org_guard::verify_user(&pool, user_id, auth.org_id).await?;
+
+    let mut conn = pool.acquire().await?;
+    Ok(Json(seniority::status_history(&mut conn, user_id).await?))
+}
+
+/// Check an absence is one that pauses seniority and doesn't overlap another
+/// of the employee's absences.
+async fn validate_interval(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    user_id: Uuid,
+    id: Option<Uuid>,
+    status: EmployeeStatus,
+    starts_on: time::Date,
+    ends_on: Option<time::Date>,
+) -> Result<()> {
+    if !matches!(
+        status,
+        EmployeeStatus::UnpaidLoa | EmployeeStatus::Lwop | EmployeeStatus::Layoff
+    ) {
+        return Err(AppError::BadRequest(
+            "Status history records unpaid_loa, lwop and layoff absences".into(),
+        ));
+    }
+    if ends_on.is_some_and(|end| end <= starts_on) {
+        return Err(AppError::BadRequest("ends_on must be after starts_on".into()));
+    }
+
+    let overlaps = sqlx::query_scalar!(
+        r#"
+        SELECT EXISTS (
+            SELECT 1 FROM employment_status_history
+            WHERE user_id = $1
+              AND ($2::UUID IS NULL OR id <> $2)
+              AND daterange(starts_on, ends_on) && daterange($3, $4)
+        ) AS "overlaps!"
+        "#,
+        user_id,
+        id,
+        starts_on,
+        ends_on,
+    )
+    .fetch_one(&mut **tx)
+    .await?;
+    if overlaps {
+        return Err(AppError::Conflict(
+            "This absence overlaps another recorded for the employee".into(),
+        ));
+    }
+    Ok(())
+}
+
+/// Recompute after a status history edit and audit any change it made.
+async fn recompute_and_audit(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    org_id: Uuid,
+    user_id: Uuid,
+    changed_by: Uuid,
+) -> Result<()> {
+    let before = seniority::current_dates(tx, user_id).await?;
+    seniority::recompute(tx, org_id, user_id).await?;
+    seniority::audit_changes(tx, org_id, user_id, &before, "status_change", None, Some(changed_by))
+        .await
+}
+
+/// POST /api/users/:id/status-history
+/// Admin only. Record a past absence (one from before the system, say) and
+/// recompute the employee's seniority.
+pub async fn create_status_interval(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(user_id): Path<Uuid>,
+    Json(req): Json<CreateStatusIntervalRequest>,
+) -> Result<Json<EmploymentStatusInterval>> {
+    use validator::Validate;
+    req.validate()?;
+
+    if !auth.role.is_admin() {
+        return Err(AppError::Forbidden);
+    }
+    org_guard::verify_user(&pool, user_id, auth.org_id).await?;
+
+    let mut tx = pool.begin().await?;
+    validate_interval(&mut tx, user_id, None, req.status, req.starts_on, Some(req.ends_on)).await?;
+
+    let row = sqlx::query_as!(
+        EmploymentStatusInterval,
+        r#"
+        INSERT INTO employment_status_history (org_id, user_id, status, starts_on, ends_on,
+                                               is_exception, notes, recorded_by)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+        RETURNING id, user_id, status AS "status: EmployeeStatus", starts_on, ends_on,
+                  is_exception, notes, recorded_by, created_at
+        "#,
+        auth.org_id,
+        user_id,
+        req.status as EmployeeStatus,
+        req.starts_on,
+        req.ends_on,
+        req.is_exception.unwrap_or(false),
+        req.notes,
+        auth.id,
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+
+    recompute_and_audit(&mut tx, auth.org_id, user_id, auth.id).await?;
+    tx.commit().await?;
+
+    Ok(Json(row))
+}
+
+/// PATCH /api/users/:id/status-history/:interval_id
+/// Admin only. Correct a recorded absence and recompute the employee's seniority.
+pub async fn update_status_interval(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path((user_id, interval_id)): Path<(Uuid, Uuid)>,
+    Json(req): Json<UpdateStatusIntervalRequest>,
+) -> Result<Json<EmploymentStatusInterval>> {
+    use validator::Validate;
+    req.validate()?;
+
+    if !auth.role.is_admin() {
+        return Err(AppError::Forbidden);
+    }
+
+    let mut tx = pool.begin().await?;
+    let current = sqlx::query!(
+        r#"
+        SELECT status AS "status: EmployeeStatus", starts_on, ends_on
+        FROM employment_status_history
+        WHERE id = $1 AND user_id = $2 AND org_id = $3
+        FOR UPDATE
+        "#,
+        interval_id,
+        user_id,
+        auth.org_id,
+    )
+    .fetch_optional(&mut *tx)
+    .await?
+    .ok_or_else(|| AppError::NotFound("Status history entry not found".into()))?;
+
+    if req.ends_on.is_some() && current.ends_on.is_none() {
+        return Err(AppError::BadRequest(
+            "An ongoing absence ends when the employee returns to active".into(),
+        ));
+    }
+    let status = req.status.unwrap_or(current.status);
+    let starts_on = req.starts_on.unwrap_or(current.starts_on);
+    let ends_on = req.ends_on.or(current.ends_on);
+    validate_interval(&mut tx, user_id, Some(interval_id), status, starts_on, ends_on).await?;
+
+    let row = sqlx::query_as!(
+        EmploymentStatusInterval,
+        r#"
+        UPDATE employment_status_history
+        SET status       = $2,
+            starts_on    = $3,
+            ends_on      = $4,
+            is_exception = COALESCE($5, is_exception),
+            notes        = COALESCE($6, notes),
+            recorded_by  = $7
+        WHERE id = $1
+        RETURNING id, user_id, status AS "status: EmployeeStatus", starts_on, ends_on,
+                  is_exception, notes, recorded_by, created_at
+        "#,
+        interval_id,
+        status as EmployeeStatus,
+        starts_on,
+        ends_on,
+        req.is_exception,
+        req.notes,
+        auth.id,
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+
+    recompute_and_audit(&mut tx, auth.org_id, user_id, auth.id).await?;
+    tx.commit().await?;
+
+    Ok(Json(row))
+}
+
+/// DELETE /api/users/:id/status-history/:interval_id
+/// Admin only. Remove an absence recorded in error (an ongoing one can't be
+/// removed while the employee's status says they're away).
+pub async fn delete_status_interval(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path((user_id, interval_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<serde_json::Value>> {
+    if !auth.role.is_admin() {
+        return Err(AppError::Forbidden);
+    }
+
+    let mut tx = pool.begin().await?;
+    let rows = sqlx::query!(
+        r#"
+        DELETE FROM employment_status_history
+        WHERE id = $1 AND user_id = $2 AND org_id = $3 AND ends_on IS NOT NULL
+        "#,
+        interval_id,
+        user_id,
+        auth.org_id,
+    )
+    .execute(&mut *tx)
+    .await?
+    .rows_affected();
+    ensure_rows_affected(rows, "Completed status history entry")?;
+
+    recompute_and_audit(&mut tx, auth.org_id, user_id, auth.id).await?;
+    tx.commit().await?;
+
+    Ok(json_ok())
+}
+
+/// GET /api/users/:id/seniority-explanation
+/// How each of the employee's seniority dates is derived from the date it
+/// counts from and their recorded absences.
+pub async fn explanation(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(user_id): Path<Uuid>,
+) -> Result<Json<SeniorityExplanation>> {
+    if !auth.role.can_manage_schedule() && auth.id != user_id {
+        return Err(AppError::Forbidden);
+    }
+    org_guard::verify_user(&pool, user_id, auth.org_id).await?;
+
+    let mut conn = pool.acquire().await?;
+    Ok(Json(seniority::explain(&mut conn, user_id).await?))
+}
+
+/// POST /api/seniority/recompute
+/// Admin only. Recompute seniority dates from status history for one
+/// employee, or everyone in the org, auditing each date that changes.
+pub async fn recompute(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Json(req): Json<RecomputeSeniorityRequest>,
+) -> Result<Json<serde_json::Value>> {
+    if !auth.role.is_admin() {
+        return Err(AppError::Forbidden);
+    }
+
+    let user_ids = match req.user_id {
+        Some(user_id) => {
+            org_guard::verify_user(&pool, user_id, auth.org_id).await?;
+            vec![user_id]
+        }
+        None => sqlx::query_scalar!("SELECT id FROM users WHERE org_id = $1 ORDER BY id", auth.org_id)
+            .fetch_all(&pool)
+            .await?,
+    };
+
+    let mut tx = pool.begin().await?;
+    let mut changed = 0;
+    for &user_id in &user_ids {
+        let before = seniority::current_dates(&mut tx, user_id).await?;
+        if seniority::recompute(&mut tx, auth.org_id, user_id).await? {
+            seniority::audit_changes(&mut tx, auth.org_id, user_id, &before, "recompute", None, Some(auth.id))
+                .await?;
+            changed += 1;
+        }
+    }
+    tx.commit().await?;
+
+    Ok(Json(serde_json::json!({
+        "ok": true,
+        "employees": user_ids.len(),
+        "changed": changed,
+    })))
+}
@@ -263,8 +263,7 @@ pub async fn cycle(
     .await?
     .ok_or_else(|| AppError::NotFound("Shift pattern not found".into()))?;
 
-    let days_diff = (q.date - pattern.anchor_date).whole_days();
-    let cycle_day = (days_diff.rem_euclid(pattern.pattern_days as i64) + 1) as i32;
+    let cycle_day = pattern.cycle_day(q.date);
     let is_work_day = pattern.is_work_day(cycle_day);
 
     Ok(Json(CycleInfo {
@@ -8,17 +8,21 @@ use crate::{
     error::{AppError, Result},
     models::{
         callout::{CalloutListEntry, CalloutStatus},
+        mandatory_ot::MandatoryOtOrderEntry,
         ot::CalloutStep,
     },
+    org_guard,
 };
 
-use crate::services::availability::compute_available_employees;
+use crate::services::{availability::compute_available_employees, call_forecast, erlang_c};
 
 #[derive(Debug, Deserialize)]
 pub struct AvailableQuery {
     pub date: time::Date,
     pub shift_template_id: Uuid,
     pub classification_id: Uuid,
+    /// Set when filling a qualification gap: holders are listed first.
+    pub qualification_id: Option<Uuid>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -27,6 +31,8 @@ pub struct BlockAvailableQuery {
     pub classification_id: Uuid,
     pub block_start: String,
     pub block_end: String,
+    /// Set when filling a qualification gap: holders are listed first.
+    pub qualification_id: Option<Uuid>,
 }
 
 #[derive(Debug, Serialize)]
@@ -179,6 +185,9 @@ pub async fn available_employees(
     if !auth.role.can_manage_schedule() {
         return Err(AppError::Forbidden);
     }
+    if let Some(qual_id) = params.qualification_id {
+        org_guard::verify_qualification(&pool, qual_id, auth.org_id).await?;
+    }
 
     // Look up the scheduled shift and shift template info.
     // If no scheduled_shift exists yet for this template+date, auto-create one
@@ -228,24 +237,27 @@ pub async fn available_employees(
         }
     };
 
-    // Compute the available employees list
-    let employees = compute_available_employees(
+    let existing_callout = fetch_callout_summary(
         &pool,
-        auth.org_id,
-        &auth.org_timezone,
         shift_info.scheduled_shift_id,
         params.classification_id,
-        params.date,
-        shift_info.start_time,
-        shift_info.duration_minutes,
+        auth.org_id,
     )
     .await?;
 
-    let existing_callout = fetch_callout_summary(
+    // Compute the available employees list (ordered by the open callout's step, if any)
+    let employees = compute_available_employees(
         &pool,
+        auth.org_id,
+        &auth.org_timezone,
         shift_info.scheduled_shift_id,
         params.classification_id,
-        auth.org_id,
+        params.date,
+        shift_info.start_time,
+        shift_info.duration_minutes,
+        existing_callout.as_ref().map(|c| c.id),
+        existing_callout.as_ref().and_then(|c| c.current_step.as_ref()),
+        params.qualification_id,
     )
     .await?;
 
@@ -278,6 +290,9 @@ pub async fn block_available(
     if !auth.role.can_manage_schedule() {
         return Err(AppError::Forbidden);
     }
+    if let Some(qual_id) = params.qualification_id {
+        org_guard::verify_qualification(&pool, qual_id, auth.org_id).await?;
+    }
 
     let fmt = time::format_description::parse("[hour]:[minute]")
         .map_err(|_| AppError::BadRequest("invalid time format".into()))?;
@@ -363,23 +378,26 @@ pub async fn block_available(
         }
     };
 
-    let employees = compute_available_employees(
+    let existing_callout = fetch_callout_summary(
         &pool,
-        auth.org_id,
-        &auth.org_timezone,
         scheduled_shift_id,
         params.classification_id,
-        params.date,
-        start_time,
-        duration_minutes,
+        auth.org_id,
     )
     .await?;
 
-    let existing_callout = fetch_callout_summary(
+    let employees = compute_available_employees(
         &pool,
+        auth.org_id,
+        &auth.org_timezone,
         scheduled_shift_id,
         params.classification_id,
-        auth.org_id,
+        params.date,
+        start_time,
+        duration_minutes,
+        existing_callout.as_ref().map(|c| c.id),
+        existing_callout.as_ref().and_then(|c| c.current_step.as_ref()),
+        params.qualification_id,
     )
     .await?;
 
@@ -407,20 +425,24 @@ pub async fn block_available(
 #[derive(Debug, Deserialize)]
 pub struct MandatoryOtOrderQuery {
     pub classification_id: Uuid,
-}
-
-#[derive(Debug, Serialize)]
-pub struct MandatoryOtOrderEntry {
-    pub user_id: Uuid,
-    pub last_mandatory_at: Option<String>,
+    /// Date of the forced OT; exemptions and leave are checked against it.
+    /// Defaults to today in the org's timezone.
+    pub date: Option<time::Date>,
+    /// Optional OT window; when both ends are given, employees who would
+    /// violate the 10-hour rest rule are marked ineligible.
+    #[serde(default, with = "crate::models::common::time_format_option")]
+    pub start_time: Option<time::Time>,
+    #[serde(default, with = "crate::models::common::time_format_option")]
+    pub end_time: Option<time::Time>,
 }
 
 /// GET /api/staffing/mandatory-ot-order
 ///
 /// CBA (CBA Article 15): Mandatory OT distributed in inverse seniority order
-/// (least senior first). Least senior = most recent bargaining_unit_seniority_date
-/// = called first. NULL seniority date = no date = least senior = called first.
-/// Used by MandatoryOTDialog to order the employee dropdown.
+/// (least senior first), rotating so that an employee who has been forced goes
+/// to the back of the list. Employees who cannot be forced (medical exemption,
+/// time-bound exemption, approved leave, rest rule) keep their place but are
+/// flagged ineligible. Used by MandatoryOTDialog to order the employee dropdown.
 pub async fn mandatory_ot_order(
     State(pool): State<PgPool>,
     auth: AuthUser,
@@ -430,38 +452,202 @@ pub async fn mandatory_ot_order(
         return Err(AppError::Forbidden);
     }
 
-    let rows = sqlx::query!(
-        r#"
-        SELECT
-            u.id AS user_id,
-            MAX(ora.assigned_at)::TEXT AS "last_mandatory_at?"
-        FROM users u
-        LEFT JOIN seniority_records sr ON sr.user_id = u.id
-        LEFT JOIN ot_request_assignments ora ON ora.user_id = u.id
-            AND ora.ot_type = 'mandatory'
-            AND ora.cancelled_at IS NULL
-        LEFT JOIN ot_requests otr ON otr.id = ora.ot_request_id
-            AND otr.org_id = $1
-        WHERE u.org_id = $1
-          AND u.is_active = true
-          AND u.classification_id = $2
-        GROUP BY u.id, sr.bargaining_unit_seniority_date
-        -- CBA: Mandatory OT distributed in inverse seniority order (least senior first)
-        ORDER BY sr.bargaining_unit_seniority_date DESC NULLS FIRST
-        "#,
+    let date = params
+        .date
+        .unwrap_or_else(|| crate::services::timezone::org_today(&auth.org_timezone));
+    let window = params.start_time.zip(params.end_time);
+
+    let entries = crate::services::mandatory_ot::build_rotation(
+        &pool,
         auth.org_id,
         params.classification_id,
+        date,
+        window,
     )
-    .fetch_all(&pool)
     .await?;
 
-    let entries: Vec<MandatoryOtOrderEntry> = rows
-        .into_iter()
-        .map(|r| MandatoryOtOrderEntry {
-            user_id: r.user_id,
-            last_mandatory_at: r.last_mandatory_at,
-        })
-        .collect();
-
     Ok(Json(entries))
 }
+
+// ── Erlang C staffing calculator ──────────────────────────────────────────────
+
+#[derive(Debug, Deserialize)]
+pub struct IntervalVolume {
+    pub slot_index: i16,
+    pub calls: f64,
+}
+
+fn default_service_level() -> f64 {
+    0.9
+}
+
+fn default_answer_time() -> f64 {
+    15.0
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ErlangCRequest {
+    /// Call volume per half-hour slot. Exactly one of `intervals` / `csv` / `forecast_date`.
+    pub intervals: Option<Vec<IntervalVolume>>,
+    /// CSV text with `slot,calls` rows (see `services::erlang_c::parse_volume_csv`).
+    pub csv: Option<String>,
+    /// Use the call-volume forecast for this date.
+    pub forecast_date: Option<time::Date>,
+    pub aht_seconds: f64,
+    #[serde(default = "default_service_level")]
+    pub service_level: f64,
+    #[serde(default = "default_answer_time")]
+    pub answer_time_seconds: f64,
+    /// Fraction of scheduled time lost to breaks, training, etc. (0–0.9).
+    #[serde(default)]
+    pub shrinkage: f64,
+    pub max_occupancy: Option<f64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ErlangCInterval {
+    pub slot_index: i16,
+    pub start_time: String,
+    pub calls: f64,
+    pub traffic_erlangs: f64,
+    /// Call-takers on position needed to meet the service goal.
+    pub required_agents: i32,
+    /// `required_agents` grossed up for shrinkage.
+    pub recommended_headcount: i32,
+    pub service_level: f64,
+    pub asa_seconds: f64,
+    pub occupancy: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ErlangCResponse {
+    pub aht_seconds: f64,
+    pub service_level: f64,
+    pub answer_time_seconds: f64,
+    pub shrinkage: f64,
+    pub peak_required_agents: i32,
+    pub peak_recommended_headcount: i32,
+    pub intervals: Vec<ErlangCInterval>,
+}
+
+/// POST /api/staffing/erlang-c
+///
+/// Computes required call-takers per half-hour slot from call volume with
+/// Erlang C. Nothing is stored; results can be applied to a coverage plan via
+/// `POST /api/coverage-plans/:id/slots/apply-staffing`.
+pub async fn erlang_c(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Json(req): Json<ErlangCRequest>,
+) -> Result<Json<ErlangCResponse>> {
+    if !auth.role.can_manage_schedule() {
+        return Err(AppError::Forbidden);
+    }
+
+    if !(req.aht_seconds > 0.0 && req.aht_seconds <= 7200.0) {
+        return Err(AppError::BadRequest(
+            "aht_seconds must be between 0 and 7200".into(),
+        ));
+    }
+    if !(req.service_level > 0.0 && req.service_level < 1.0) {
+        return Err(AppError::BadRequest(
+            "service_level must be between 0 and 1 (exclusive)".into(),
+        ));
+    }
+    if req.answer_time_seconds < 0.0 {
+        return Err(AppError::BadRequest(
+            "answer_time_seconds must be non-negative".into(),
+        ));
+    }
+    if !(0.0..=0.9).contains(&req.shrinkage) {
+        return Err(AppError::BadRequest(
+            "shrinkage must be between 0 and 0.9".into(),
+        ));
+    }
+    if req.max_occupancy.is_some_and(|o| !(o > 0.0 && o <= 1.0)) {
+        return Err(AppError::BadRequest(
+            "max_occupancy must be between 0 and 1".into(),
+        ));
+    }
+
+    let volumes: Vec<(i16, f64)> = match (req.intervals, req.csv, req.forecast_date) {
+        (Some(intervals), None, None) => {
+            let mut seen = std::collections::HashSet::new();
+            for iv in &intervals {
+                if !(0..=47).contains(&iv.slot_index) {
+                    return Err(AppError::BadRequest(format!(
+                        "slot_index {} out of range 0-47",
+                        iv.slot_index
+                    )));
+                }
+                if !erlang_c::valid_calls(iv.calls) {
+                    return Err(AppError::BadRequest(format!(
+                        "Call counts must be between 0 and {}",
+                        erlang_c::MAX_CALLS
+                    )));
+                }
+                if !seen.insert(iv.slot_index) {
+                    return Err(AppError::BadRequest(format!(
+                        "Duplicate slot_index {}",
+                        iv.slot_index
+                    )));
+                }
+            }
+            let mut v: Vec<(i16, f64)> = intervals.into_iter().map(|iv| (iv.slot_index, iv.calls)).collect();
+            v.sort_by_key(|(slot, _)| *slot);
+            v
+        }
+        (None, Some(csv), None) => erlang_c::parse_volume_csv(&csv)?,
+        (None, None, Some(date)) => {
+            let forecast =
+                call_forecast::forecast(&pool, auth.org_id, &auth.org_timezone, &[date]).await?;
+            let day = forecast.into_iter().next().filter(|f| f.sample_days > 0).ok_or_else(|| {
+                AppError::BadRequest(format!("No call-volume history to forecast {}", date))
+            })?;
+            day.slots.into_iter().map(|s| (s.slot_index, s.calls)).collect()
+        }
+        _ => {
+            return Err(AppError::BadRequest(
+                "Provide exactly one of `intervals`, `csv` or `forecast_date`".into(),
+            ))
+        }
+    };
+
+    let goal = erlang_c::ServiceGoal {
+        aht_seconds: req.aht_seconds,
+        service_level: req.service_level,
+        answer_time_seconds: req.answer_time_seconds,
+        max_occupancy: req.max_occupancy,
+    };
+
+    let mut intervals = Vec::with_capacity(volumes.len());
+    for (slot_index, calls) in volumes {
+        let s = erlang_c::required_agents(calls, &goal)?;
+        let recommended = (s.agents as f64 / (1.0 - req.shrinkage)).ceil() as i32;
+        intervals.push(ErlangCInterval {
+            slot_index,
+            start_time: format!("{:02}:{:02}", slot_index / 2, (slot_index % 2) * 30),
+            calls,
+            traffic_erlangs: s.traffic_erlangs,
+            required_agents: s.agents as i32,
+            recommended_headcount: recommended,
+            service_level: s.service_level,
+            asa_seconds: s.asa_seconds,
+            occupancy: s.occupancy,
+        });
+    }
+
+    Ok(Json(ErlangCResponse {
+        aht_seconds: req.aht_seconds,
+        service_level: req.service_level,
+        answer_time_seconds: req.answer_time_seconds,
+        shrinkage: req.shrinkage,
+        peak_required_agents: intervals.iter().map(|i| i.required_agents).max().unwrap_or(0),
+        peak_recommended_headcount: intervals
+            .iter()
+            .map(|i| i.recommended_headcount)
+            .max()
+            .unwrap_or(0),
+        intervals,
+    }))
+}
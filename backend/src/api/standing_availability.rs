@@ -0,0 +1,210 @@
+use axum::{
+    extract::{Path, Query, State},
+    Json,
+};
+use sqlx::PgPool;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    auth::AuthUser,
+    error::{AppError, Result},
+    models::standing_availability::{
+        CreateStandingAvailability, StandingAvailability, StandingAvailabilityQuery,
+    },
+    services::standing_availability::AvailabilityRule,
+};
+
+async fn fetch_availability(
+    pool: &PgPool,
+    id: Uuid,
+    org_id: Uuid,
+) -> Result<StandingAvailability> {
+    let r = sqlx::query!(
+        r#"
+        SELECT
+            a.id, a.user_id, (u.first_name || ' ' || u.last_name) AS "user_name!",
+            a.start_date, a.end_date, a.days_of_week, a.start_time, a.end_time,
+            a.notes, a.created_at, a.cancelled_at
+        FROM ot_standing_availability a
+        JOIN users u ON u.id = a.user_id
+        WHERE a.id = $1 AND a.org_id = $2
+        "#,
+        id,
+        org_id,
+    )
+    .fetch_optional(pool)
+    .await?
+    .ok_or_else(|| AppError::NotFound("Availability not found".into()))?;
+
+    Ok(StandingAvailability {
+        id: r.id,
+        user_id: r.user_id,
+        user_name: r.user_name,
+        start_date: r.start_date,
+        end_date: r.end_date,
+        days_of_week: r.days_of_week,
+        start_time: r.start_time,
+        end_time: r.end_time,
+        notes: r.notes,
+        created_at: r.created_at,
+        cancelled_at: r.cancelled_at,
+    })
+}
+
+// ---------------------------------------------------------------------------
+// List
+// ---------------------------------------------------------------------------
+
+/// Employees see their own sign-ups; supervisors see everyone's (optionally
+/// filtered by user).
+pub async fn list(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Query(params): Query<StandingAvailabilityQuery>,
+) -> Result<Json<Vec<StandingAvailability>>> {
+    let user_filter = if auth.role.can_manage_schedule() {
+        params.user_id
+    } else {
+        Some(auth.id)
+    };
+
+    let rows = sqlx::query!(
+        r#"
+        SELECT
+            a.id, a.user_id, (u.first_name || ' ' || u.last_name) AS "user_name!",
+            a.start_date, a.end_date, a.days_of_week, a.start_time, a.end_time,
+            a.notes, a.created_at, a.cancelled_at
+        FROM ot_standing_availability a
+        JOIN users u ON u.id = a.user_id
+        WHERE a.org_id = $1
+          AND ($2::UUID IS NULL OR a.user_id = $2)
+          AND ($3::DATE IS NULL OR (a.start_date <= $3 AND a.end_date >= $3))
+          AND ($4 OR a.cancelled_at IS NULL)
+        ORDER BY a.start_date, u.last_name, u.first_name
+        "#,
+        auth.org_id,
+        user_filter,
+        params.date,
+        params.include_cancelled.unwrap_or(false),
+    )
+    .fetch_all(&pool)
+    .await?;
+
+    let results = rows
+        .into_iter()
+        .map(|r| StandingAvailability {
+            id: r.id,
+            user_id: r.user_id,
+            user_name: r.user_name,
+            start_date: r.start_date,
+            end_date: r.end_date,
+            days_of_week: r.days_of_week,
+            start_time: r.start_time,
+            end_time: r.end_time,
+            notes: r.notes,
+            created_at: r.created_at,
+            cancelled_at: r.cancelled_at,
+        })
+        .collect();
+
+    Ok(Json(results))
+}
+
+// ---------------------------------------------------------------------------
+// Create
+// ---------------------------------------------------------------------------
+
+pub async fn create(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Json(req): Json<CreateStandingAvailability>,
+) -> Result<Json<StandingAvailability>> {
+    req.validate()?;
+
+    let user_id = match req.user_id {
+        Some(uid) if uid != auth.id => {
+            if !auth.role.can_manage_schedule() {
+                return Err(AppError::Forbidden);
+            }
+            let exists = sqlx::query_scalar!(
+                r#"SELECT EXISTS(SELECT 1 FROM users WHERE id = $1 AND org_id = $2 AND is_active = true) AS "exists!""#,
+                uid,
+                auth.org_id,
+            )
+            .fetch_one(&pool)
+            .await?;
+            if !exists {
+                return Err(AppError::NotFound("User not found".into()));
+            }
+            uid
+        }
+        _ => auth.id,
+    };
+
+    let mut days_of_week = req.days_of_week.clone();
+    days_of_week.sort_unstable();
+    days_of_week.dedup();
+
+    AvailabilityRule {
+        start_date: req.start_date,
+        end_date: req.end_date,
+        days_of_week: &days_of_week,
+        start_time: req.start_time,
+        end_time: req.end_time,
+    }
+    .validate()?;
+
+    let id = sqlx::query_scalar!(
+        r#"
+        INSERT INTO ot_standing_availability
+            (org_id, user_id, start_date, end_date, days_of_week, start_time, end_time, notes)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+        RETURNING id
+        "#,
+        auth.org_id,
+        user_id,
+        req.start_date,
+        req.end_date,
+        &days_of_week,
+        req.start_time,
+        req.end_time,
+        req.notes,
+    )
+    .fetch_one(&pool)
+    .await?;
+
+    Ok(Json(fetch_availability(&pool, id, auth.org_id).await?))
+}
+
+// ---------------------------------------------------------------------------
+// Cancel
+// ---------------------------------------------------------------------------
+
+pub async fn cancel(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(id): Path<Uuid>,
+) -> Result<Json<StandingAvailability>> {
+    let existing = fetch_availability(&pool, id, auth.org_id).await?;
+    if existing.user_id != auth.id && !auth.role.can_manage_schedule() {
+        return Err(AppError::Forbidden);
+    }
+    if existing.cancelled_at.is_some() {
+        return Err(AppError::Conflict("Availability is already cancelled".into()));
+    }
+
+    sqlx::query!(
+        r#"
+        UPDATE ot_standing_availability
+        SET cancelled_at = NOW(), updated_at = NOW()
+        WHERE id = $1 AND org_id = $2 AND cancelled_at IS NULL
+        "#,
+        id,
+        auth.org_id,
+    )
+    .execute(&pool)
+    .await?;
+
+    Ok(Json(fetch_availability(&pool, id, auth.org_id).await?))
+}
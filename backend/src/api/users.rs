@@ -16,6 +16,7 @@ use crate::{
         CreateUserRequest, EmployeeStatus, EmployeeType, UpdateUserRequest, UserProfile,
     },
     org_guard,
+    services::seniority,
 };
 
 /// Intermediate struct used to construct UserProfile from query rows.
@@ -147,9 +148,9 @@ pub(crate) async fn fetch_user_profile(
 }
 
 /// Handle side effects of employee status changes:
-/// - Pause seniority accrual on separation/leave-without-pay/layoff
-/// - Resume accrual on return to active, advancing seniority dates by the days paused
-/// - Pause/resume leave accrual in parallel
+/// - Record LOA/LWOP/layoff in the employment status history, ending the absence on
+///   return to active or separation, and recompute seniority from it
+/// - Pause/resume leave accrual in parallel (exception absences keep accruing)
 async fn handle_employee_status_change(
     tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
     user_id: uuid::Uuid,
@@ -157,32 +158,25 @@ async fn handle_employee_status_change(
     new_status: &EmployeeStatus,
     is_exception: bool,
     org_today: time::Date,
+    changed_by: uuid::Uuid,
 ) -> Result<()> {
     let is_pausing = matches!(
         new_status,
         EmployeeStatus::UnpaidLoa | EmployeeStatus::Lwop | EmployeeStatus::Layoff
     );
 
+    seniority::record_status_change(
+        tx,
+        org_id,
+        user_id,
+        *new_status,
+        is_exception,
+        org_today,
+        changed_by,
+    )
+    .await?;
+
     if is_pausing && !is_exception {
-        // Start a new seniority pause (only if not already paused — preserve original start date)
-        sqlx::query!(
-            r#"
-            INSERT INTO seniority_records (user_id, org_id, accrual_pause_started_at)
-            VALUES ($1, $2, $3)
-            ON CONFLICT (user_id) DO UPDATE SET
-                accrual_pause_started_at = CASE
-                    WHEN seniority_records.accrual_pause_started_at IS NULL
-                    THEN $3
-                    ELSE seniority_records.accrual_pause_started_at
-                END,
-                updated_at = NOW()
-            "#,
-            user_id,
-            org_id,
-            org_today,
-        )
-        .execute(&mut **tx)
-        .await?;
         // Pause leave accrual
         sqlx::query!(
             "UPDATE users SET leave_accrual_paused_at = $2
@@ -193,39 +187,6 @@ async fn handle_employee_status_change(
         .execute(&mut **tx)
         .await?;
     } else if matches!(new_status, EmployeeStatus::Active) {
-        // Resume: advance seniority dates by the days paused, then clear the pause marker
-        sqlx::query!(
-            r#"
-            UPDATE seniority_records SET
-                overall_seniority_date = CASE
-                    WHEN accrual_pause_started_at IS NOT NULL AND overall_seniority_date IS NOT NULL
-                    THEN overall_seniority_date + ($2 - accrual_pause_started_at)::INT
-                    ELSE overall_seniority_date
-                END,
-                bargaining_unit_seniority_date = CASE
-                    WHEN accrual_pause_started_at IS NOT NULL AND bargaining_unit_seniority_date IS NOT NULL
-                    THEN bargaining_unit_seniority_date + ($2 - accrual_pause_started_at)::INT
-                    ELSE bargaining_unit_seniority_date
-                END,
-                classification_seniority_date = CASE
-                    WHEN accrual_pause_started_at IS NOT NULL AND classification_seniority_date IS NOT NULL
-                    THEN classification_seniority_date + ($2 - accrual_pause_started_at)::INT
-                    ELSE classification_seniority_date
-                END,
-                accrual_paused_days_total = accrual_paused_days_total + CASE
-                    WHEN accrual_pause_started_at IS NOT NULL
-                    THEN ($2 - accrual_pause_started_at)::INT
-                    ELSE 0
-                END,
-                accrual_pause_started_at = NULL,
-                updated_at = NOW()
-            WHERE user_id = $1
-            "#,
-            user_id,
-            org_today,
-        )
-        .execute(&mut **tx)
-        .await?;
         // Resume leave accrual
         sqlx::query!(
             "UPDATE users SET leave_accrual_paused_at = NULL WHERE id = $1",
@@ -449,31 +410,30 @@ pub async fn create(
     .fetch_one(&pool)
     .await?;
 
-    // Upsert seniority_records if any seniority date was provided
+    // Seniority dates count from the dates provided; overall defaults to the hire date
     let (s_overall, s_bu, s_class) = (
         req.overall_seniority_date,
         req.bargaining_unit_seniority_date,
         req.classification_seniority_date,
     );
-    if s_overall.is_some() || s_bu.is_some() || s_class.is_some() {
-        sqlx::query!(
-            r#"
-            INSERT INTO seniority_records (user_id, org_id, overall_seniority_date,
-                                           bargaining_unit_seniority_date, classification_seniority_date)
-            VALUES ($1, $2, $3, $4, $5)
-            ON CONFLICT (user_id) DO UPDATE SET
-                overall_seniority_date         = EXCLUDED.overall_seniority_date,
-                bargaining_unit_seniority_date = EXCLUDED.bargaining_unit_seniority_date,
-                classification_seniority_date  = EXCLUDED.classification_seniority_date,
-                updated_at                     = NOW()
-            "#,
+    if r.hire_date.is_some() || s_overall.is_some() || s_bu.is_some() || s_class.is_some() {
+        let mut conn = pool.acquire().await?;
+        seniority::set_profile_dates(
+            &mut conn,
+            auth.org_id,
             r.id,
+            [s_overall.map(Some), s_bu.map(Some), s_class.map(Some)],
+        )
+        .await?;
+        seniority::audit_changes(
+            &mut conn,
             auth.org_id,
-            s_overall,
-            s_bu,
-            s_class,
+            r.id,
+            &Default::default(),
+            "profile",
+            None,
+            Some(auth.id),
         )
-        .execute(&pool)
         .await?;
     }
 
@@ -656,47 +616,25 @@ pub async fn update(
     .await?
     .ok_or_else(|| AppError::NotFound("User not found".into()))?;
 
-    // Upsert seniority_records if any seniority date was provided
-    let overall_provided = req.overall_seniority_date.is_some();
-    let overall_val = req.overall_seniority_date.flatten();
-    let bu_provided = req.bargaining_unit_seniority_date.is_some();
-    let bu_val = req.bargaining_unit_seniority_date.flatten();
-    let class_s_provided = req.classification_seniority_date.is_some();
-    let class_s_val = req.classification_seniority_date.flatten();
-
-    if overall_provided || bu_provided || class_s_provided {
-        sqlx::query!(
-            r#"
-            INSERT INTO seniority_records (user_id, org_id, overall_seniority_date,
-                                           bargaining_unit_seniority_date, classification_seniority_date)
-            VALUES ($1, $2,
-                CASE WHEN $3 THEN $4::DATE ELSE NULL END,
-                CASE WHEN $5 THEN $6::DATE ELSE NULL END,
-                CASE WHEN $7 THEN $8::DATE ELSE NULL END
-            )
-            ON CONFLICT (user_id) DO UPDATE SET
-                overall_seniority_date         = CASE WHEN $3 THEN $4::DATE ELSE seniority_records.overall_seniority_date END,
-                bargaining_unit_seniority_date = CASE WHEN $5 THEN $6::DATE ELSE seniority_records.bargaining_unit_seniority_date END,
-                classification_seniority_date  = CASE WHEN $7 THEN $8::DATE ELSE seniority_records.classification_seniority_date END,
-                updated_at                     = NOW()
-            "#,
-            r.id,
-            auth.org_id,
-            overall_provided,
-            overall_val,
-            bu_provided,
-            bu_val,
-            class_s_provided,
-            class_s_val,
-        )
-        .execute(&mut *tx)
-        .await?;
+    // Seniority dates provided are set as they should read today; a new hire date
+    // moves an overall date that counts from it
+    let seniority_dates = [
+        req.overall_seniority_date,
+        req.bargaining_unit_seniority_date,
+        req.classification_seniority_date,
+    ];
+    if hire_provided || seniority_dates.iter().any(Option::is_some) {
+        let before = seniority::current_dates(&mut tx, r.id).await?;
+        seniority::set_profile_dates(&mut tx, auth.org_id, r.id, seniority_dates).await?;
+        seniority::audit_changes(&mut tx, auth.org_id, r.id, &before, "profile", None, Some(auth.id))
+            .await?;
     }
 
     // Seniority and leave accrual pause / resume logic
     if let Some(new_status) = &req.employee_status {
         let is_exception = req.seniority_pause_exception.unwrap_or(false);
         let org_today = crate::services::timezone::org_today(&auth.org_timezone);
+        let before = seniority::current_dates(&mut tx, r.id).await?;
         handle_employee_status_change(
             &mut tx,
             r.id,
@@ -704,8 +642,11 @@ pub async fn update(
             new_status,
             is_exception,
             org_today,
+            auth.id,
         )
         .await?;
+        seniority::audit_changes(&mut tx, auth.org_id, r.id, &before, "status_change", None, Some(auth.id))
+            .await?;
     }
 
     // M324: Separation handling — auto-cancel or flag pending trades
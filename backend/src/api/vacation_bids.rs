@@ -13,10 +13,20 @@ use crate::{
     api::notifications::{create_notification, CreateNotificationParams},
     auth::AuthUser,
     error::{AppError, Result},
+    models::bid_processing::{BidProcessingRun, RollbackBidsRequest, SkipReason, VacationAward, VacationAwardTable},
+    models::bidding::BidStatusSummary,
     models::vacation_bid::{
         CreateVacationBidPeriodRequest, OpenVacationBiddingRequest, SubmitVacationBidRequest,
         VacationBid, VacationBidPeriod, VacationBidPeriodQuery, VacationBidWindow,
-        VacationWindowDetail,
+        VacationPick, VacationWindowDetail,
+    },
+    services::{
+        bid_processing::{list_runs, plan_vacation_awards, record_run},
+        bid_proxy::{is_proxy_for, notify_submitted, BidPeriod, ProxyAction, ProxySubmission, Trigger},
+        bidding::vacation_bid_status,
+        holiday_rotation::{self, Lockout},
+        leave::adjust_leave_balance,
+        work_schedule::WorkSchedule,
     },
 };
 
@@ -25,18 +35,57 @@ use crate::{
 // ---------------------------------------------------------------------------
 
 /// Configuration for calculating vacation hours charged.
-/// Fetched from org_settings once, then passed to `calculate_hours`.
-struct VacationHoursConfig {
+/// Fetched once for the bidders involved, then used through `VacationHoursConfig::hours`.
+pub(crate) struct VacationHoursConfig {
     hours_lookup: Option<serde_json::Value>,
     default_hours_per_day: f64,
+    schedules: HashMap<Uuid, WorkSchedule>,
+}
+
+impl VacationHoursConfig {
+    /// Hours charged to `user_id` for a vacation from `start_date` to `end_date`.
+    ///
+    /// Days covered by the employee's schedule (shift pattern and slot
+    /// assignments) charge the hours actually scheduled, so days off cost
+    /// nothing and 10 or 12 hour shifts cost their full length. Runs of days
+    /// with no schedule fall back to `calculate_hours`.
+    pub(crate) fn hours(&self, user_id: Uuid, start_date: time::Date, end_date: time::Date) -> f64 {
+        let schedule = self.schedules.get(&user_id);
+        let mut total = 0.0;
+        let mut unscheduled: Option<(time::Date, time::Date)> = None;
+        let mut day = start_date;
+        while day <= end_date {
+            match schedule.and_then(|s| s.hours_on(day, self.default_hours_per_day)) {
+                Some(hours) => {
+                    if let Some((from, to)) = unscheduled.take() {
+                        total += calculate_hours(from, to, &self.hours_lookup, self.default_hours_per_day);
+                    }
+                    total += hours;
+                }
+                None => {
+                    unscheduled = Some((unscheduled.map_or(day, |(from, _)| from), day));
+                }
+            }
+            day = match day.next_day() {
+                Some(d) => d,
+                None => break,
+            };
+        }
+        if let Some((from, to)) = unscheduled {
+            total += calculate_hours(from, to, &self.hours_lookup, self.default_hours_per_day);
+        }
+        total
+    }
 }
 
-/// Fetch the vacation hours configuration (non-linear lookup table + default rate).
-/// Used by `get_window`, `submit_bid`, and `process_bids`.
-async fn fetch_vacation_hours_config(
+/// Fetch the vacation hours configuration (non-linear lookup table + default rate)
+/// and the schedules of `user_ids`.
+/// Used by `get_window`, `submit_bid`, `process_bids`, and proxy plan filing.
+pub(crate) async fn fetch_vacation_hours_config(
     pool: &PgPool,
     org_id: Uuid,
-) -> VacationHoursConfig {
+    user_ids: &[Uuid],
+) -> Result<VacationHoursConfig> {
     let hours_lookup: Option<serde_json::Value> = sqlx::query_scalar!(
         "SELECT value FROM org_settings WHERE org_id = $1 AND key = 'vacation_hours_charged_sep_feb'",
         org_id,
@@ -49,86 +98,142 @@ async fn fetch_vacation_hours_config(
         pool, org_id, "default_hours_per_vacation_day", 8,
     ).await as f64;
 
-    VacationHoursConfig {
+    Ok(VacationHoursConfig {
         hours_lookup,
         default_hours_per_day,
-    }
+        schedules: WorkSchedule::load(pool, org_id, user_ids).await?,
+    })
 }
 
 // ---------------------------------------------------------------------------
 // process_bids helpers
 // ---------------------------------------------------------------------------
 
-/// Check if any date in [start, end] would reach or exceed `max_concurrent` awarded vacations.
-///
-/// Returns `Ok(true)` if there is a conflict (bid should be skipped), `Ok(false)` otherwise.
-/// Returns an error only if a date arithmetic overflow is encountered.
-fn check_date_conflicts(
-    awarded_dates: &HashMap<time::Date, u32>,
-    start: time::Date,
-    end: time::Date,
-    max_concurrent: u32,
-) -> Result<bool> {
-    let mut d = start;
-    while d <= end {
-        let count = awarded_dates.get(&d).copied().unwrap_or(0);
-        if count >= max_concurrent {
-            return Ok(true);
+/// Validate `user_id`'s ranked list of vacation picks against the round's rules:
+/// full weeks in round 1, no picks entirely on days off, the minimum block size,
+/// the round allowance, holiday rotation (`lockouts` are the bidder's barred
+/// holidays), and sequential ranks.
+pub(crate) fn validate_picks(
+    picks: &[VacationPick],
+    user_id: Uuid,
+    round: i32,
+    allowance_hours: Option<i32>,
+    min_block_hours: Option<i32>,
+    hours_config: &VacationHoursConfig,
+    lockouts: &[Lockout],
+) -> Result<()> {
+    if picks.is_empty() {
+        return Err(AppError::BadRequest("At least one pick is required".into()));
+    }
+
+    if picks.len() > 20 {
+        return Err(AppError::BadRequest(
+            "Maximum 20 vacation picks allowed".into(),
+        ));
+    }
+
+    for pick in picks {
+        if pick.end_date < pick.start_date {
+            return Err(AppError::BadRequest(
+                "end_date must be >= start_date for each pick".into(),
+            ));
+        }
+
+        // CBA: Round 1 vacation bids must be full weeks (Monday-Sunday).
+        // Round 2 allows single-day or partial-week picks.
+        if round == 1 {
+            let start_weekday = pick.start_date.weekday();
+            let end_weekday = pick.end_date.weekday();
+            let days = (pick.end_date - pick.start_date).whole_days() + 1;
+
+            if start_weekday != time::Weekday::Monday {
+                return Err(AppError::BadRequest(
+                    "Round 1 picks must start on Monday".into(),
+                ));
+            }
+            if end_weekday != time::Weekday::Sunday {
+                return Err(AppError::BadRequest(
+                    "Round 1 picks must end on Sunday".into(),
+                ));
+            }
+            if days % 7 != 0 {
+                return Err(AppError::BadRequest(
+                    "Round 1 picks must be full weeks (7 days)".into(),
+                ));
+            }
         }
-        d = d
-            .next_day()
-            .ok_or(AppError::BadRequest("Date range exceeds maximum date".into()))?;
     }
-    Ok(false)
-}
 
-/// Increment the concurrent-vacation count for every date in [start, end].
-///
-/// Returns an error only if a date arithmetic overflow is encountered.
-fn mark_awarded_dates(
-    awarded_dates: &mut HashMap<time::Date, u32>,
-    start: time::Date,
-    end: time::Date,
-) -> Result<()> {
-    let mut d = start;
-    while d <= end {
-        *awarded_dates.entry(d).or_insert(0) += 1;
-        d = d
-            .next_day()
-            .ok_or(AppError::BadRequest("Date range exceeds maximum date".into()))?;
+    // Hours come from the bidder's schedule, consistent with process_bids.
+    for pick in picks {
+        let hours = hours_config.hours(user_id, pick.start_date, pick.end_date);
+        if hours <= 0.0 {
+            return Err(AppError::BadRequest(format!(
+                "Pick {} to {} falls entirely on scheduled days off",
+                pick.start_date, pick.end_date,
+            )));
+        }
+
+        // Enforce min_block_hours: each pick must cover at least min_block_hours.
+        if let Some(min_block) = min_block_hours {
+            if hours < min_block as f64 {
+                return Err(AppError::BadRequest(format!(
+                    "Each vacation block must be at least {} hours; got {:.0} hours",
+                    min_block, hours,
+                )));
+            }
+        }
+    }
+
+    // Enforce allowance_hours: total hours across all picks must not exceed the limit.
+    if let Some(allowance) = allowance_hours {
+        let total_hours: f64 = picks
+            .iter()
+            .map(|p| hours_config.hours(user_id, p.start_date, p.end_date))
+            .sum();
+        if total_hours > allowance as f64 {
+            return Err(AppError::BadRequest(format!(
+                "Total vacation hours ({:.0}) exceeds round allowance of {} hours",
+                total_hours, allowance,
+            )));
+        }
     }
+
+    holiday_rotation::check_picks(picks, lockouts, round)?;
+
+    // Validate ranks are sequential starting from 1
+    let ranks: Vec<i32> = picks.iter().map(|p| p.preference_rank).collect();
+    validate_sequential_ranks(&ranks)?;
+
+
     Ok(())
 }
 
-/// Award a single vacation bid: mark it awarded, create an approved leave request,
-/// and deduct the corresponding leave balance.
+/// Award a single vacation bid: mark it awarded, create an approved leave request
+/// for the hours the award table charged, link it to the bid, and deduct the
+/// corresponding leave balance.
 ///
 /// If `leave_type_id` is `None`, the leave request and balance deduction are skipped
 /// (bid is still marked awarded).
 async fn award_vacation_bid(
     tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
-    bid_id: Uuid,
-    start_date: time::Date,
-    end_date: time::Date,
+    award: &VacationAward,
     user_id: Uuid,
     org_id: Uuid,
     reviewer_id: Uuid,
     leave_type_id: Option<Uuid>,
-    hours_lookup: &Option<serde_json::Value>,
     org_timezone: &str,
-    default_hours_per_day: f64,
 ) -> Result<()> {
     // Mark the bid as awarded
     sqlx::query!(
         "UPDATE vacation_bids SET awarded = true WHERE id = $1",
-        bid_id,
+        award.bid_id,
     )
     .execute(&mut **tx)
     .await?;
 
     // Create approved leave request and deduct balance if a vacation leave type exists
     if let Some(lt_id) = leave_type_id {
-        let hours = calculate_hours(start_date, end_date, hours_lookup, default_hours_per_day);
         let leave_request_id = Uuid::new_v4();
 
         sqlx::query!(
@@ -140,21 +245,29 @@ async fn award_vacation_bid(
             user_id,
             org_id,
             lt_id,
-            start_date,
-            end_date,
-            hours,
+            award.start_date,
+            award.end_date,
+            award.hours,
             reviewer_id,
         )
         .execute(&mut **tx)
         .await?;
 
+        sqlx::query!(
+            "UPDATE vacation_bids SET leave_request_id = $2 WHERE id = $1",
+            award.bid_id,
+            leave_request_id,
+        )
+        .execute(&mut **tx)
+        .await?;
+
         // Deduct leave balance for the awarded vacation bid
         crate::services::leave::deduct_leave_balance(
             tx,
             org_id,
             user_id,
             lt_id,
-            hours,
+            award.hours,
             leave_request_id,
             reviewer_id,
             org_timezone,
@@ -176,7 +289,7 @@ pub async fn list_periods(
         SELECT id, org_id, year, round, status,
                opens_at, closes_at, created_at,
                allowance_hours, min_block_hours,
-               bargaining_unit
+               bargaining_unit, seniority_list_id
         FROM vacation_bid_periods
         WHERE org_id = $1
           AND ($2::INT IS NULL OR year = $2)
@@ -212,7 +325,7 @@ pub async fn create_period(
         RETURNING id, org_id, year, round, status,
                   opens_at, closes_at, created_at,
                   allowance_hours, min_block_hours,
-                  bargaining_unit
+                  bargaining_unit, seniority_list_id
         "#,
         Uuid::new_v4(),
         auth.org_id,
@@ -278,7 +391,7 @@ pub async fn cancel_period(
         RETURNING id, org_id, year, round, status,
                   opens_at, closes_at, created_at,
                   allowance_hours, min_block_hours,
-                  bargaining_unit
+                  bargaining_unit, seniority_list_id
         "#,
         id,
         auth.org_id,
@@ -326,18 +439,24 @@ pub async fn open_bidding(
 
     // CBA: Bidding windows are assigned in strict seniority order — most senior employee
     // bids first. Each window is sequential so senior employees' picks take precedence.
+    // A pinned seniority list fixes the order; anyone not on it follows by live seniority.
     let users = sqlx::query!(
         r#"
         SELECT u.id, u.first_name, u.last_name
         FROM users u
+        JOIN vacation_bid_periods p ON p.id = $3
         LEFT JOIN seniority_records sr ON sr.user_id = u.id
+        LEFT JOIN seniority_list_entries sle
+               ON sle.list_id = p.seniority_list_id AND sle.user_id = u.id
         WHERE u.org_id = $1
           AND u.is_active = true
           AND ($2::TEXT IS NULL OR u.bargaining_unit = $2)
-        ORDER BY sr.overall_seniority_date ASC NULLS LAST, u.last_name, u.first_name
+        ORDER BY sle.rank ASC NULLS LAST, sr.overall_seniority_date ASC NULLS LAST,
+                 u.last_name, u.first_name
         "#,
         auth.org_id,
         period.bargaining_unit,
+        period.id,
     )
     .fetch_all(&pool)
     .await?;
@@ -423,7 +542,7 @@ pub async fn open_bidding(
         RETURNING id, org_id, year, round, status,
                   opens_at, closes_at, created_at,
                   allowance_hours, min_block_hours,
-                  bargaining_unit
+                  bargaining_unit, seniority_list_id
         "#,
         id,
         period_opens,
@@ -493,6 +612,32 @@ pub async fn list_windows(
     Ok(Json(windows))
 }
 
+/// GET /api/vacation-bids/periods/:id/bid-status
+/// Live progress of the bid for the bid administrator.
+pub async fn bid_status(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(period_id): Path<Uuid>,
+) -> Result<Json<BidStatusSummary>> {
+    if !auth.role.can_manage_schedule() {
+        return Err(AppError::Forbidden);
+    }
+
+    let exists = sqlx::query_scalar!(
+        "SELECT EXISTS(SELECT 1 FROM vacation_bid_periods WHERE id = $1 AND org_id = $2)",
+        period_id,
+        auth.org_id,
+    )
+    .fetch_one(&pool)
+    .await?;
+
+    if !exists.unwrap_or(false) {
+        return Err(AppError::NotFound("Vacation bid period not found".into()));
+    }
+
+    Ok(Json(vacation_bid_status(&pool, period_id).await?))
+}
+
 pub async fn get_window(
     State(pool): State<PgPool>,
     auth: AuthUser,
@@ -520,8 +665,11 @@ pub async fn get_window(
         return Err(AppError::NotFound("Vacation bid window not found".into()));
     }
 
-    // Non-manager can only see own window
-    if !auth.role.can_manage_schedule() && w.user_id != auth.id {
+    // Non-manager can only see own window, or one they're the proxy bidder for
+    if !auth.role.can_manage_schedule()
+        && w.user_id != auth.id
+        && !is_proxy_for(&pool, BidPeriod::Vacation(w.vacation_bid_period_id), w.user_id, auth.id).await?
+    {
         return Err(AppError::Forbidden);
     }
 
@@ -573,11 +721,11 @@ pub async fn get_window(
         submitted_at: w.submitted_at,
     };
 
-    let hours_config = fetch_vacation_hours_config(&pool, auth.org_id).await;
+    let hours_config = fetch_vacation_hours_config(&pool, auth.org_id, &[window.user_id]).await?;
 
     let hours_used: f64 = bids
         .iter()
-        .map(|b| calculate_hours(b.start_date, b.end_date, &hours_config.hours_lookup, hours_config.default_hours_per_day))
+        .map(|b| hours_config.hours(window.user_id, b.start_date, b.end_date))
         .sum();
 
     Ok(Json(VacationWindowDetail {
@@ -603,8 +751,8 @@ pub async fn submit_bid(
     // Fetch window with FOR UPDATE to prevent concurrent bid submissions
     let w = sqlx::query!(
         r#"
-        SELECT w.id, w.user_id, w.opens_at, w.closes_at,
-               p.round, p.org_id, p.status AS period_status,
+        SELECT w.id, w.vacation_bid_period_id, w.user_id, w.opens_at, w.closes_at,
+               p.round, p.year, p.org_id, p.status AS period_status,
                p.allowance_hours, p.min_block_hours
         FROM vacation_bid_windows w
         JOIN vacation_bid_periods p ON p.id = w.vacation_bid_period_id
@@ -621,11 +769,13 @@ pub async fn submit_bid(
         return Err(AppError::NotFound("Vacation bid window not found".into()));
     }
 
-    if w.user_id != auth.id {
+    let period = BidPeriod::Vacation(w.vacation_bid_period_id);
+    let by_proxy = w.user_id != auth.id;
+    if by_proxy && !is_proxy_for(&pool, period, w.user_id, auth.id).await? {
         return Err(AppError::Forbidden);
     }
 
-    let hours_config = fetch_vacation_hours_config(&pool, w.org_id).await;
+    let hours_config = fetch_vacation_hours_config(&pool, w.org_id, &[w.user_id]).await?;
 
     if w.period_status != "open" {
         return Err(AppError::BadRequest("Bidding period is not open".into()));
@@ -644,82 +794,19 @@ pub async fn submit_bid(
         ));
     }
 
-    // Validate picks
-    if body.picks.is_empty() {
-        return Err(AppError::BadRequest("At least one pick is required".into()));
-    }
-
-    if body.picks.len() > 20 {
-        return Err(AppError::BadRequest(
-            "Maximum 20 vacation picks allowed".into(),
-        ));
-    }
-
-    for pick in &body.picks {
-        if pick.end_date < pick.start_date {
-            return Err(AppError::BadRequest(
-                "end_date must be >= start_date for each pick".into(),
-            ));
-        }
-
-        // CBA: Round 1 vacation bids must be full weeks (Monday-Sunday).
-        // Round 2 allows single-day or partial-week picks.
-        if w.round == 1 {
-            let start_weekday = pick.start_date.weekday();
-            let end_weekday = pick.end_date.weekday();
-            let days = (pick.end_date - pick.start_date).whole_days() + 1;
-
-            if start_weekday != time::Weekday::Monday {
-                return Err(AppError::BadRequest(
-                    "Round 1 picks must start on Monday".into(),
-                ));
-            }
-            if end_weekday != time::Weekday::Sunday {
-                return Err(AppError::BadRequest(
-                    "Round 1 picks must end on Sunday".into(),
-                ));
-            }
-            if days % 7 != 0 {
-                return Err(AppError::BadRequest(
-                    "Round 1 picks must be full weeks (7 days)".into(),
-                ));
-            }
-        }
-    }
-
-    // Enforce min_block_hours: each pick must cover at least min_block_hours.
-    // Uses calculate_hours for consistency with process_bids (non-linear Sep-Feb table).
-    if let Some(min_block) = w.min_block_hours {
-        for pick in &body.picks {
-            let hours = calculate_hours(pick.start_date, pick.end_date, &hours_config.hours_lookup, hours_config.default_hours_per_day);
-            if hours < min_block as f64 {
-                return Err(AppError::BadRequest(format!(
-                    "Each vacation block must be at least {} hours; got {:.0} hours",
-                    min_block, hours,
-                )));
-            }
-        }
-    }
-
-    // Enforce allowance_hours: total hours across all picks must not exceed the limit.
-    // Uses calculate_hours for consistency with process_bids.
-    if let Some(allowance) = w.allowance_hours {
-        let total_hours: f64 = body
-            .picks
-            .iter()
-            .map(|p| calculate_hours(p.start_date, p.end_date, &hours_config.hours_lookup, hours_config.default_hours_per_day))
-            .sum();
-        if total_hours > allowance as f64 {
-            return Err(AppError::BadRequest(format!(
-                "Total vacation hours ({:.0}) exceeds round allowance of {} hours",
-                total_hours, allowance,
-            )));
-        }
-    }
-
-    // Validate ranks are sequential starting from 1
-    let ranks: Vec<i32> = body.picks.iter().map(|p| p.preference_rank).collect();
-    validate_sequential_ranks(&ranks)?;
+    let lockouts = holiday_rotation::lockouts(&mut tx, w.org_id, &[w.user_id], w.year, w.round)
+        .await?
+        .remove(&w.user_id)
+        .unwrap_or_default();
+    validate_picks(
+        &body.picks,
+        w.user_id,
+        w.round,
+        w.allowance_hours,
+        w.min_block_hours,
+        &hours_config,
+        &lockouts,
+    )?;
 
     // Delete previous submissions
     sqlx::query!(
@@ -754,8 +841,37 @@ pub async fn submit_bid(
     .execute(&mut *tx)
     .await?;
 
+    let proxy_submission = if by_proxy {
+        ProxyAction {
+            org_id: auth.org_id,
+            user_id: w.user_id,
+            actor_id: Some(auth.id),
+            period,
+            window_id,
+            trigger: Trigger::Proxy,
+            submitted: serde_json::to_value(&body.picks).unwrap_or_default(),
+            unavailable: serde_json::json!([]),
+        }
+        .record(&mut tx)
+        .await?;
+        Some(ProxySubmission {
+            org_id: auth.org_id,
+            user_id: w.user_id,
+            window_id,
+            period,
+            trigger: Trigger::Proxy,
+            count: body.picks.len(),
+        })
+    } else {
+        None
+    };
+
     tx.commit().await?;
 
+    if let Some(submission) = proxy_submission {
+        notify_submitted(&pool, &submission).await;
+    }
+
     // Return the saved bids
     let bids = sqlx::query_as!(
         VacationBid,
@@ -774,29 +890,26 @@ pub async fn submit_bid(
     Ok(Json(bids))
 }
 
-pub async fn process_bids(
-    State(pool): State<PgPool>,
-    auth: AuthUser,
-    Path(period_id): Path<Uuid>,
-) -> Result<Json<VacationBidPeriod>> {
-    if !auth.role.is_admin() {
-        return Err(AppError::Forbidden);
-    }
-
-    let mut tx = pool.begin().await?;
-
+/// Lock an open vacation bid period and work out its award table. Returns the
+/// table and the org's vacation leave type, if it has one.
+async fn plan_open_period(
+    pool: &PgPool,
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    org_id: Uuid,
+    period_id: Uuid,
+) -> Result<(VacationAwardTable, Option<Uuid>)> {
     // Lock period row inside transaction to prevent concurrent processing
     let period = sqlx::query!(
         r#"
-        SELECT status, year
+        SELECT status
         FROM vacation_bid_periods
         WHERE id = $1 AND org_id = $2
         FOR UPDATE
         "#,
         period_id,
-        auth.org_id,
+        org_id,
     )
-    .fetch_optional(&mut *tx)
+    .fetch_optional(&mut **tx)
     .await?
     .ok_or_else(|| AppError::NotFound("Vacation bid period not found".into()))?;
 
@@ -806,164 +919,98 @@ pub async fn process_bids(
         ));
     }
 
-    // Get all windows in seniority order
-    let windows = sqlx::query!(
-        r#"
-        SELECT w.id, w.user_id
-        FROM vacation_bid_windows w
-        WHERE w.vacation_bid_period_id = $1
-        ORDER BY w.seniority_rank
-        "#,
-        period_id,
-    )
-    .fetch_all(&mut *tx)
-    .await?;
-
     // Find the vacation leave type for the org by category (not hardcoded code)
-    let leave_type = sqlx::query!(
+    let leave_type_id = sqlx::query_scalar!(
         r#"
         SELECT id FROM leave_types
         WHERE org_id = $1 AND category = 'vacation' AND is_active = true
         LIMIT 1
         "#,
-        auth.org_id,
+        org_id,
     )
-    .fetch_optional(&mut *tx)
+    .fetch_optional(&mut **tx)
     .await?;
 
-    let leave_type_id = leave_type.map(|lt| lt.id);
-
-    let hours_config = fetch_vacation_hours_config(&pool, auth.org_id).await;
-
-    // CBA: Pre-seed with dates already awarded in earlier rounds of the same year so that
-    // round 2 cannot double-book dates already granted in round 1 (seniority-based protection).
-    let prior_dates: Vec<time::Date> = sqlx::query_scalar!(
-        r#"
-        SELECT DISTINCT d::DATE AS "date!"
-        FROM vacation_bids vb
-        JOIN vacation_bid_windows vw ON vw.id = vb.vacation_bid_window_id
-        JOIN vacation_bid_periods vp ON vp.id = vw.vacation_bid_period_id
-        CROSS JOIN generate_series(vb.start_date, vb.end_date, '1 day'::interval) AS d
-        WHERE vp.org_id = $1
-          AND vp.year = $2
-          AND vp.id != $3
-          AND vb.awarded = true
-        "#,
-        auth.org_id,
-        period.year,
+    let bidders = sqlx::query_scalar!(
+        "SELECT user_id FROM vacation_bid_windows WHERE vacation_bid_period_id = $1",
         period_id,
     )
-    .fetch_all(&mut *tx)
+    .fetch_all(&mut **tx)
     .await?;
-
-    let mut awarded_dates: HashMap<time::Date, u32> = HashMap::new();
-    for d in prior_dates {
-        *awarded_dates.entry(d).or_insert(0) += 1;
-    }
-
-    // Max concurrent vacations per date — configurable via org_settings, default 3
-    let max_concurrent_val: Option<serde_json::Value> = sqlx::query_scalar!(
-        "SELECT value FROM org_settings WHERE org_id = $1 AND key = 'max_concurrent_vacation'",
-        auth.org_id,
-    )
-    .fetch_optional(&mut *tx)
-    .await?;
-    let max_concurrent: u32 = max_concurrent_val
-        .and_then(|v| v.as_u64().map(|n| n as u32))
-        .unwrap_or(3);
-
-    // Collect notifications to send after transaction commits
-    struct PendingNotification {
-        user_id: Uuid,
-        message: String,
-    }
-    let mut pending_notifications: Vec<PendingNotification> = Vec::new();
+    let hours_config = fetch_vacation_hours_config(pool, org_id, &bidders).await?;
 
     // CBA: Process bids in seniority order — most senior employee's picks are awarded
     // first. If dates conflict (concurrent count >= max_concurrent_vacation), the bid is
     // skipped (not awarded). Leave balance is checked before awarding to prevent overdraft.
-    for window in &windows {
-        // Get bids ordered by preference
-        let bids = sqlx::query!(
-            r#"
-            SELECT id, start_date, end_date, preference_rank
-            FROM vacation_bids
-            WHERE vacation_bid_window_id = $1
-            ORDER BY preference_rank
-            "#,
-            window.id,
-        )
-        .fetch_all(&mut *tx)
-        .await?;
+    let table = plan_vacation_awards(tx, org_id, period_id, leave_type_id, |user_id, start, end| {
+        hours_config.hours(user_id, start, end)
+    })
+    .await?;
 
-        for bid in &bids {
-            // Check if any date in this bid's range would exceed concurrent vacation limit
-            if check_date_conflicts(&awarded_dates, bid.start_date, bid.end_date, max_concurrent)? {
-                continue; // Skip this bid, dates already taken
-            }
+    Ok((table, leave_type_id))
+}
 
-            // Check leave balance BEFORE awarding — if insufficient, skip
-            // without marking the bid as awarded or blocking the dates.
-            if let Some(lt_id) = leave_type_id {
-                let hours = calculate_hours(bid.start_date, bid.end_date, &hours_config.hours_lookup, hours_config.default_hours_per_day);
-
-                // Verify sufficient balance before awarding (FOR UPDATE to prevent
-                // concurrent modifications from causing negative balances).
-                let balance: f64 = sqlx::query_scalar!(
-                    r#"
-                    SELECT COALESCE(SUM(locked.bal), 0.0) AS "total!"
-                    FROM (
-                        SELECT CAST(lb.balance_hours AS FLOAT8) AS bal
-                        FROM leave_balances lb
-                        WHERE lb.user_id = $1 AND lb.org_id = $2 AND lb.leave_type_id = $3
-                        FOR UPDATE OF lb
-                    ) locked
-                    "#,
-                    window.user_id,
-                    auth.org_id,
-                    lt_id,
-                )
-                .fetch_one(&mut *tx)
-                .await?;
+/// GET /api/vacation-bids/periods/:id/process-bids/preview
+/// The award table `process_bids` would apply right now, without applying it.
+pub async fn preview_bids(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(period_id): Path<Uuid>,
+) -> Result<Json<VacationAwardTable>> {
+    if !auth.role.is_admin() {
+        return Err(AppError::Forbidden);
+    }
 
-                if balance < hours {
-                    tracing::warn!(
-                        "Skipping vacation bid for user {} — insufficient balance ({:.1} hrs available, {:.1} needed)",
-                        window.user_id, balance, hours
-                    );
-                    // Notify employee their bid was skipped due to insufficient balance
-                    pending_notifications.push(PendingNotification {
-                        user_id: window.user_id,
-                        message: format!(
-                            "Your vacation bid for {} to {} was not awarded due to insufficient leave balance",
-                            bid.start_date, bid.end_date
-                        ),
-                    });
-                    continue;
-                }
-            }
+    let mut tx = pool.begin().await?;
+    let (table, _) = plan_open_period(&pool, &mut tx, auth.org_id, period_id).await?;
+    tx.rollback().await?;
+
+    Ok(Json(table))
+}
 
-            // Award the bid, create leave request, and deduct balance
+pub async fn process_bids(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(period_id): Path<Uuid>,
+) -> Result<Json<VacationBidPeriod>> {
+    if !auth.role.is_admin() {
+        return Err(AppError::Forbidden);
+    }
+
+    let mut tx = pool.begin().await?;
+
+    let (table, leave_type_id) = plan_open_period(&pool, &mut tx, auth.org_id, period_id).await?;
+
+    // Award each bid, create its leave request, and deduct balance
+    for row in &table.rows {
+        for award in &row.awards {
             award_vacation_bid(
                 &mut tx,
-                bid.id,
-                bid.start_date,
-                bid.end_date,
-                window.user_id,
+                award,
+                row.user_id,
                 auth.org_id,
                 auth.id,
                 leave_type_id,
-                &hours_config.hours_lookup,
                 &auth.org_timezone,
-                hours_config.default_hours_per_day,
             )
             .await?;
-
-            // Increment concurrent count for all dates in this bid's range
-            mark_awarded_dates(&mut awarded_dates, bid.start_date, bid.end_date)?;
         }
     }
 
+    holiday_rotation::record_awards(&mut tx, auth.org_id, &table).await?;
+
+    record_run(
+        &mut tx,
+        auth.org_id,
+        BidPeriod::Vacation(period_id),
+        "process",
+        auth.id,
+        table.awards_count,
+        None,
+        serde_json::to_value(&table).unwrap_or_default(),
+    )
+    .await?;
+
     // Set period to completed
     let updated = sqlx::query_as!(
         VacationBidPeriod,
@@ -974,7 +1021,7 @@ pub async fn process_bids(
         RETURNING id, org_id, year, round, status,
                   opens_at, closes_at, created_at,
                   allowance_hours, min_block_hours,
-                  bargaining_unit
+                  bargaining_unit, seniority_list_id
         "#,
         period_id,
     )
@@ -983,16 +1030,188 @@ pub async fn process_bids(
 
     tx.commit().await?;
 
-    // Send notifications after transaction commits (best-effort)
-    for notif in &pending_notifications {
+    // Tell employees about picks skipped for insufficient balance (best-effort)
+    for row in &table.rows {
+        for pick in row.skipped.iter().filter(|p| p.reason == SkipReason::InsufficientBalance) {
+            let message = format!(
+                "Your vacation bid for {} to {} was not awarded due to insufficient leave balance",
+                pick.start_date, pick.end_date
+            );
+            let _ = create_notification(
+                &pool,
+                CreateNotificationParams {
+                    org_id: auth.org_id,
+                    user_id: row.user_id,
+                    notification_type: "vacation_bid_skipped",
+                    title: "Vacation bid not awarded",
+                    message: &message,
+                    link: Some("/vacation-bids"),
+                    source_type: Some("vacation_bid_period"),
+                    source_id: Some(period_id),
+                },
+            )
+            .await;
+        }
+    }
+
+    Ok(Json(updated))
+}
+
+/// POST /api/vacation-bids/periods/:id/rollback-bids
+/// Undo a processed period: delete the awarded leave requests, refund their
+/// hours, and reopen the period for processing. Refused while a later round of
+/// the same year is still processed.
+pub async fn rollback_bids(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(period_id): Path<Uuid>,
+    Json(req): Json<RollbackBidsRequest>,
+) -> Result<Json<serde_json::Value>> {
+    if !auth.role.is_admin() {
+        return Err(AppError::Forbidden);
+    }
+
+    let mut tx = pool.begin().await?;
+
+    let period = sqlx::query!(
+        "SELECT status, year, round FROM vacation_bid_periods WHERE id = $1 AND org_id = $2 FOR UPDATE",
+        period_id,
+        auth.org_id,
+    )
+    .fetch_optional(&mut *tx)
+    .await?
+    .ok_or_else(|| AppError::NotFound("Vacation bid period not found".into()))?;
+
+    if period.status != "completed" {
+        return Err(AppError::BadRequest(
+            "Only processed periods can be rolled back".into(),
+        ));
+    }
+
+    let later_round_processed = sqlx::query_scalar!(
+        r#"
+        SELECT EXISTS(
+            SELECT 1 FROM vacation_bid_periods
+            WHERE org_id = $1 AND year = $2 AND round > $3 AND status = 'completed'
+        ) AS "exists!"
+        "#,
+        auth.org_id,
+        period.year,
+        period.round,
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+    if later_round_processed {
+        return Err(AppError::Conflict(
+            "A later round this year has been processed; roll it back first".into(),
+        ));
+    }
+
+    let awards = sqlx::query!(
+        r#"
+        SELECT vb.id, w.user_id, vb.start_date, vb.end_date,
+               lr.id AS "leave_request_id?", lr.leave_type_id AS "leave_type_id?",
+               lr.hours::FLOAT8 AS "hours?", (lr.status = 'approved') AS "approved?"
+        FROM vacation_bids vb
+        JOIN vacation_bid_windows w ON w.id = vb.vacation_bid_window_id
+        LEFT JOIN leave_requests lr ON lr.id = vb.leave_request_id
+        WHERE w.vacation_bid_period_id = $1 AND vb.awarded = true
+        ORDER BY w.seniority_rank, vb.preference_rank
+        "#,
+        period_id,
+    )
+    .fetch_all(&mut *tx)
+    .await?;
+
+    let mut hours_refunded = 0.0;
+    let mut undone = Vec::with_capacity(awards.len());
+    for award in &awards {
+        let mut refund = 0.0;
+        if let Some(leave_request_id) = award.leave_request_id {
+            // A request the employee already cancelled was refunded then
+            if let (Some(true), Some(lt_id), Some(hours)) = (award.approved, award.leave_type_id, award.hours) {
+                adjust_leave_balance(
+                    &mut tx,
+                    auth.org_id,
+                    award.user_id,
+                    lt_id,
+                    hours,
+                    "adjustment",
+                    Some("Refund: vacation bid award rolled back"),
+                    Some(leave_request_id),
+                    auth.id,
+                    &auth.org_timezone,
+                )
+                .await?;
+                refund = hours;
+            }
+            sqlx::query!("DELETE FROM leave_requests WHERE id = $1", leave_request_id)
+                .execute(&mut *tx)
+                .await?;
+        }
+        hours_refunded += refund;
+        undone.push(serde_json::json!({
+            "bid_id": award.id,
+            "user_id": award.user_id,
+            "start_date": award.start_date.to_string(),
+            "end_date": award.end_date.to_string(),
+            "leave_request_id": award.leave_request_id,
+            "hours_refunded": refund,
+        }));
+    }
+
+    let bid_ids: Vec<Uuid> = awards.iter().map(|a| a.id).collect();
+    sqlx::query!(
+        "UPDATE vacation_bids SET awarded = false, leave_request_id = NULL WHERE id = ANY($1)",
+        &bid_ids,
+    )
+    .execute(&mut *tx)
+    .await?;
+    sqlx::query!(
+        "DELETE FROM holiday_awards WHERE vacation_bid_id = ANY($1)",
+        &bid_ids,
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query!(
+        "UPDATE vacation_bid_periods SET status = 'open' WHERE id = $1",
+        period_id,
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    let run_id = record_run(
+        &mut tx,
+        auth.org_id,
+        BidPeriod::Vacation(period_id),
+        "rollback",
+        auth.id,
+        awards.len(),
+        req.reason.as_deref(),
+        serde_json::json!({ "awards": undone, "hours_refunded": hours_refunded }),
+    )
+    .await?;
+
+    tx.commit().await?;
+
+    // Tell each affected employee once (best-effort)
+    let mut users: Vec<Uuid> = awards.iter().map(|a| a.user_id).collect();
+    users.sort();
+    users.dedup();
+    let message = format!(
+        "Your {} round {} vacation bid awards were rolled back and will be processed again.",
+        period.year, period.round,
+    );
+    for user_id in users {
         let _ = create_notification(
             &pool,
             CreateNotificationParams {
                 org_id: auth.org_id,
-                user_id: notif.user_id,
-                notification_type: "vacation_bid_skipped",
-                title: "Vacation bid not awarded",
-                message: &notif.message,
+                user_id,
+                notification_type: "vacation_bid_rolled_back",
+                title: "Vacation bid awards rolled back",
+                message: &message,
                 link: Some("/vacation-bids"),
                 source_type: Some("vacation_bid_period"),
                 source_id: Some(period_id),
@@ -1001,10 +1220,39 @@ pub async fn process_bids(
         .await;
     }
 
-    Ok(Json(updated))
+    Ok(Json(serde_json::json!({
+        "ok": true,
+        "run_id": run_id,
+        "awards_rolled_back": awards.len(),
+        "hours_refunded": hours_refunded,
+    })))
+}
+
+/// GET /api/vacation-bids/periods/:id/bid-runs
+pub async fn list_bid_runs(
+    State(pool): State<PgPool>,
+    auth: AuthUser,
+    Path(period_id): Path<Uuid>,
+) -> Result<Json<Vec<BidProcessingRun>>> {
+    if !auth.role.can_manage_schedule() {
+        return Err(AppError::Forbidden);
+    }
+
+    let exists = sqlx::query_scalar!(
+        r#"SELECT EXISTS(SELECT 1 FROM vacation_bid_periods WHERE id = $1 AND org_id = $2) AS "exists!""#,
+        period_id,
+        auth.org_id,
+    )
+    .fetch_one(&pool)
+    .await?;
+    if !exists {
+        return Err(AppError::NotFound("Vacation bid period not found".into()));
+    }
+
+    Ok(Json(list_runs(&pool, auth.org_id, BidPeriod::Vacation(period_id)).await?))
 }
 
-/// Calculate hours charged for a vacation bid.
+/// Calculate hours charged for vacation days the employee's schedule doesn't cover.
 /// For Sep-Feb months, uses a non-linear lookup table from org_settings if available.
 /// Falls back to flat 8 hours/day.
 ///
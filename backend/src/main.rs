@@ -145,6 +145,138 @@ async fn main() -> anyhow::Result<()> {
         }
     });
 
+    // Background OT auto-post runner (hourly; only orgs with ot_autopost_mode enabled).
+    let autopost_pool = state.pool.clone();
+    tokio::spawn(async move {
+        loop {
+            match tokio::spawn(
+                timeshift_backend::services::ot_autopost::background_autopost_task(
+                    autopost_pool.clone(),
+                ),
+            )
+            .await
+            {
+                Ok(()) => {
+                    tracing::warn!(
+                        "OT auto-post background task exited unexpectedly, restarting in 60s"
+                    );
+                }
+                Err(e) => {
+                    tracing::error!(
+                        "OT auto-post background task panicked: {e}, restarting in 60s"
+                    );
+                }
+            }
+            tokio::time::sleep(Duration::from_secs(60)).await;
+        }
+    });
+
+    // Background bump deadline sweep (every 15 minutes; expires or auto-approves
+    // pending bump requests past their review deadline).
+    let bump_pool = state.pool.clone();
+    tokio::spawn(async move {
+        loop {
+            match tokio::spawn(timeshift_backend::services::bump::background_bump_task(
+                bump_pool.clone(),
+            ))
+            .await
+            {
+                Ok(()) => {
+                    tracing::warn!(
+                        "bump deadline background task exited unexpectedly, restarting in 60s"
+                    );
+                }
+                Err(e) => {
+                    tracing::error!(
+                        "bump deadline background task panicked: {e}, restarting in 60s"
+                    );
+                }
+            }
+            tokio::time::sleep(Duration::from_secs(60)).await;
+        }
+    });
+
+    // Background coverage-gap monitor (checks every minute; scans orgs with
+    // gap_alert_enabled when staffing changed or their scan interval elapsed).
+    let gap_pool = state.pool.clone();
+    let gap_twilio = state.twilio.clone();
+    tokio::spawn(async move {
+        loop {
+            match tokio::spawn(timeshift_backend::services::gap_monitor::background_gap_monitor_task(
+                gap_pool.clone(),
+                gap_twilio.clone(),
+            ))
+            .await
+            {
+                Ok(()) => {
+                    tracing::warn!(
+                        "coverage gap monitor task exited unexpectedly, restarting in 60s"
+                    );
+                }
+                Err(e) => {
+                    tracing::error!(
+                        "coverage gap monitor task panicked: {e}, restarting in 60s"
+                    );
+                }
+            }
+            tokio::time::sleep(Duration::from_secs(60)).await;
+        }
+    });
+
+    // Background bid window scheduler (every minute): advances expired shift and
+    // vacation bid windows and sends open/close notices to bidders.
+    let bid_pool = state.pool.clone();
+    let bid_twilio = state.twilio.clone();
+    tokio::spawn(async move {
+        loop {
+            match tokio::spawn(timeshift_backend::services::bid_scheduler::background_bid_scheduler_task(
+                bid_pool.clone(),
+                bid_twilio.clone(),
+            ))
+            .await
+            {
+                Ok(()) => {
+                    tracing::warn!(
+                        "bid window scheduler task exited unexpectedly, restarting in 60s"
+                    );
+                }
+                Err(e) => {
+                    tracing::error!(
+                        "bid window scheduler task panicked: {e}, restarting in 60s"
+                    );
+                }
+            }
+            tokio::time::sleep(Duration::from_secs(60)).await;
+        }
+    });
+
+    // Background qualification expiry reminders (hourly; 90/30/7 days out,
+    // each sent once per expiry date).
+    let qual_pool = state.pool.clone();
+    tokio::spawn(async move {
+        loop {
+            match tokio::spawn(
+                timeshift_backend::services::qualification_expiry::background_qualification_reminder_task(
+                    qual_pool.clone(),
+                ),
+            )
+            .await
+            {
+                Ok(()) => {
+                    tracing::warn!(
+                        "qualification reminder task exited unexpectedly, restarting in 60s"
+                    );
+                }
+                Err(e) => {
+                    tracing::error!(
+                        "qualification reminder task panicked: {e}, restarting in 60s"
+                    );
+                }
+            }
+            tokio::time::sleep(Duration::from_secs(60)).await;
+        }
+    });
+
     // Periodic cleanup: expired refresh tokens and old audit logs
     {
         let pool = state.pool.clone();
@@ -0,0 +1,194 @@
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::models::bidding::BidPeriodStatus;
+
+/// Why a choice (or a whole bidder) went unawarded.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SkipReason {
+    /// The bidder is no longer in the period's bargaining unit.
+    BargainingUnit,
+    /// The bidder submitted nothing.
+    NoSubmission,
+    /// The slot went to someone more senior or was assigned beforehand.
+    Taken,
+    /// The classification's flex slots were already used up.
+    FlexCap,
+    /// A date in the range is at the org's concurrent vacation limit.
+    DateConflict,
+    /// Not enough vacation leave balance left for the range.
+    InsufficientBalance,
+    /// The bidder's job-share partner is already off on a date in the range.
+    PartnerOff,
+    /// The bid round had already awarded as many slots as it allows.
+    RoundLimit,
+    /// The range covers a rotating holiday the bidder received too recently to
+    /// bid in this round.
+    HolidayRotation,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShiftAward {
+    pub slot_id: Uuid,
+    pub slot_name: String,
+    pub preference_rank: i32,
+    /// Job-share awards only: the days each partner works.
+    pub days_of_week: Option<Vec<i32>>,
+    pub partner_days_of_week: Option<Vec<i32>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkippedSlot {
+    pub slot_id: Uuid,
+    pub slot_name: String,
+    pub preference_rank: i32,
+    pub reason: SkipReason,
+}
+
+/// One bidder's outcome, in seniority order.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShiftAwardRow {
+    pub window_id: Uuid,
+    pub user_id: Uuid,
+    pub first_name: String,
+    pub last_name: String,
+    pub seniority_rank: i32,
+    pub partner_user_id: Option<Uuid>,
+    pub award: Option<ShiftAward>,
+    /// Higher-ranked choices passed over, with why.
+    pub skipped: Vec<SkippedSlot>,
+    /// Set when nothing was awarded.
+    pub reason: Option<SkipReason>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShiftAwardTable {
+    pub period_id: Uuid,
+    pub total_bidders: usize,
+    pub awards_count: usize,
+    pub rows: Vec<ShiftAwardRow>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct VacationAward {
+    pub bid_id: Uuid,
+    pub start_date: time::Date,
+    pub end_date: time::Date,
+    pub preference_rank: i32,
+    pub hours: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SkippedPick {
+    pub bid_id: Uuid,
+    pub start_date: time::Date,
+    pub end_date: time::Date,
+    pub preference_rank: i32,
+    pub reason: SkipReason,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct VacationAwardRow {
+    pub window_id: Uuid,
+    pub user_id: Uuid,
+    pub first_name: String,
+    pub last_name: String,
+    pub seniority_rank: i32,
+    pub awards: Vec<VacationAward>,
+    pub skipped: Vec<SkippedPick>,
+    /// Set when nothing was awarded.
+    pub reason: Option<SkipReason>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct VacationAwardTable {
+    pub period_id: Uuid,
+    pub total_bidders: usize,
+    pub awards_count: usize,
+    pub rows: Vec<VacationAwardRow>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct RollbackBidsRequest {
+    pub reason: Option<String>,
+}
+
+/// A processing or rollback of a bid period, for the audit trail.
+#[derive(Debug, Serialize)]
+pub struct BidProcessingRun {
+    pub id: Uuid,
+    /// `process` or `rollback`.
+    pub action: String,
+    pub run_by: Uuid,
+    pub run_by_first_name: String,
+    pub run_by_last_name: String,
+    pub awards_count: i32,
+    pub reason: Option<String>,
+    pub detail: serde_json::Value,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+}
+
+/// One ranked choice on the results sheet.
+#[derive(Debug, Serialize)]
+pub struct BidResultsPreference {
+    pub preference_rank: i32,
+    pub slot_id: Uuid,
+    pub slot_name: String,
+    pub partner_days_of_week: Option<Vec<i32>>,
+    pub awarded: bool,
+    /// Why this choice was passed over, when it was.
+    pub skip_reason: Option<SkipReason>,
+}
+
+/// One bid window on the results sheet, in seniority order.
+#[derive(Debug, Serialize)]
+pub struct BidResultsRow {
+    pub window_id: Uuid,
+    pub seniority_rank: i32,
+    pub user_id: Uuid,
+    pub first_name: String,
+    pub last_name: String,
+    pub partner_user_id: Option<Uuid>,
+    pub partner_first_name: Option<String>,
+    pub partner_last_name: Option<String>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub opens_at: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339")]
+    pub closes_at: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub submitted_at: Option<OffsetDateTime>,
+    /// Set when the window closed without a bid and bidding moved on.
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub auto_advanced_at: Option<OffsetDateTime>,
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub approved_at: Option<OffsetDateTime>,
+    pub approved_by: Option<Uuid>,
+    pub approved_by_first_name: Option<String>,
+    pub approved_by_last_name: Option<String>,
+    pub preferences: Vec<BidResultsPreference>,
+    pub award: Option<ShiftAward>,
+    /// Set when the period was processed and nothing was awarded.
+    pub reason: Option<SkipReason>,
+}
+
+/// The posted results sheet for a shift bid period.
+#[derive(Debug, Serialize)]
+pub struct BidResultsReport {
+    pub period_id: Uuid,
+    pub period_name: String,
+    pub start_date: time::Date,
+    pub end_date: time::Date,
+    pub status: BidPeriodStatus,
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub processed_at: Option<OffsetDateTime>,
+    pub processed_by_first_name: Option<String>,
+    pub processed_by_last_name: Option<String>,
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub published_at: Option<OffsetDateTime>,
+    pub published_by_first_name: Option<String>,
+    pub published_by_last_name: Option<String>,
+    pub rows: Vec<BidResultsRow>,
+}
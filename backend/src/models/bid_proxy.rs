@@ -0,0 +1,98 @@
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::models::{bidding::BidPreference, vacation_bid::VacationPick};
+
+/// When the system bids from a pre-filed plan.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ProxySubmitOn {
+    /// As soon as the window opens.
+    Open,
+    /// Only if the window closes without a submission.
+    #[default]
+    Expiry,
+}
+
+impl ProxySubmitOn {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Open => "open",
+            Self::Expiry => "expiry",
+        }
+    }
+
+    pub fn from_db(s: &str) -> Self {
+        match s {
+            "open" => Self::Open,
+            _ => Self::Expiry,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ShiftProxyPlanRequest {
+    #[serde(default)]
+    pub submit_on: ProxySubmitOn,
+    pub proxy_user_id: Option<Uuid>,
+    #[serde(default)]
+    pub preferences: Vec<BidPreference>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct VacationProxyPlanRequest {
+    #[serde(default)]
+    pub submit_on: ProxySubmitOn,
+    pub proxy_user_id: Option<Uuid>,
+    #[serde(default)]
+    pub picks: Vec<VacationPick>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ShiftProxyPlan {
+    pub id: Uuid,
+    pub period_id: Uuid,
+    pub user_id: Uuid,
+    pub submit_on: ProxySubmitOn,
+    pub proxy_user_id: Option<Uuid>,
+    pub proxy_first_name: Option<String>,
+    pub proxy_last_name: Option<String>,
+    pub preferences: Vec<BidPreference>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub updated_at: OffsetDateTime,
+}
+
+#[derive(Debug, Serialize)]
+pub struct VacationProxyPlan {
+    pub id: Uuid,
+    pub vacation_bid_period_id: Uuid,
+    pub user_id: Uuid,
+    pub submit_on: ProxySubmitOn,
+    pub proxy_user_id: Option<Uuid>,
+    pub proxy_first_name: Option<String>,
+    pub proxy_last_name: Option<String>,
+    pub picks: Vec<VacationPick>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub updated_at: OffsetDateTime,
+}
+
+/// A bid placed by the system from a pre-filed plan, or by a proxy coworker.
+#[derive(Debug, Serialize)]
+pub struct BidProxyAction {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub first_name: String,
+    pub last_name: String,
+    /// `None` when the system bid from the pre-filed list.
+    pub actor_id: Option<Uuid>,
+    pub actor_first_name: Option<String>,
+    pub actor_last_name: Option<String>,
+    pub window_id: Uuid,
+    /// `window_open`, `window_expired` or `proxy`.
+    pub trigger: String,
+    pub submitted: serde_json::Value,
+    pub unavailable: serde_json::Value,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+}
@@ -40,6 +40,13 @@ pub struct BidWindow {
         with = "time::serde::rfc3339::option"
     )]
     pub auto_advanced_at: Option<OffsetDateTime>,
+    /// Set on a job-share pair's joint window: the junior partner, who bids with
+    /// the window's owner.
+    pub partner_user_id: Option<Uuid>,
+    pub partner_first_name: Option<String>,
+    pub partner_last_name: Option<String>,
+    /// The bid round this window belongs to, when the period bids in rounds.
+    pub round_id: Option<Uuid>,
 }
 
 #[derive(Debug, Serialize)]
@@ -50,25 +57,72 @@ pub struct BidSubmissionView {
     pub team_name: String,
     pub classification_name: String,
     pub days_of_week: Vec<i32>,
+    pub partner_days_of_week: Option<Vec<i32>>,
     pub preference_rank: i32,
     pub awarded: bool,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct OpenBiddingRequest {
+    /// Ignored when the period bids in rounds; each round has its own.
     pub window_duration_hours: i64,
     pub start_at: Option<String>,
 }
 
+/// One round of a multi-round shift bid. Empty restrictions (`None`) mean
+/// anyone not placed in an earlier round may bid, on any slot.
+#[derive(Debug, Serialize)]
+pub struct ShiftBidRound {
+    pub id: Uuid,
+    pub period_id: Uuid,
+    pub round_number: i32,
+    pub name: String,
+    pub classification_ids: Option<Vec<Uuid>>,
+    pub bargaining_units: Option<Vec<String>>,
+    pub slot_ids: Option<Vec<Uuid>>,
+    pub window_duration_hours: i32,
+    /// Flex slots each classification may be awarded in this round.
+    pub flex_cap: i32,
+    /// Slots the round awards in total, if limited.
+    pub max_awards: Option<i32>,
+    pub window_count: i64,
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub opened_at: Option<OffsetDateTime>,
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub closed_at: Option<OffsetDateTime>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ShiftBidRoundInput {
+    pub name: String,
+    pub classification_ids: Option<Vec<Uuid>>,
+    pub bargaining_units: Option<Vec<String>>,
+    pub slot_ids: Option<Vec<Uuid>>,
+    pub window_duration_hours: i32,
+    pub flex_cap: Option<i32>,
+    pub max_awards: Option<i32>,
+}
+
+/// Replaces a draft period's rounds, numbered in list order. An empty list
+/// makes the period bid in a single pass.
+#[derive(Debug, Deserialize)]
+pub struct PutShiftBidRoundsRequest {
+    pub rounds: Vec<ShiftBidRoundInput>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct SubmitBidRequest {
     pub preferences: Vec<BidPreference>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BidPreference {
     pub slot_id: Uuid,
     pub preference_rank: i32,
+    /// Joint job-share windows only: the slot's days the partner works. The
+    /// window's owner works the rest.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub partner_days: Option<Vec<i32>>,
 }
 
 #[derive(Debug, Serialize)]
@@ -94,3 +148,62 @@ pub struct BidWindowDetail {
     pub available_slots: Vec<AvailableSlot>,
     pub submissions: Vec<BidSubmissionView>,
 }
+
+/// Where a bidder's window stands right now. Shared by shift and vacation bidding;
+/// vacation windows are never `Waiting` or `Approved`.
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum BidWindowState {
+    /// Scheduled to open later.
+    Upcoming,
+    /// Its time has come but the previous bid hasn't been approved yet.
+    Waiting,
+    Open,
+    /// Submitted; for shift bids, awaiting approval.
+    Submitted,
+    Approved,
+    /// Closed without a submission and passed over.
+    Skipped,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BidStatusWindow {
+    pub window_id: Uuid,
+    pub user_id: Uuid,
+    pub first_name: String,
+    pub last_name: String,
+    pub seniority_rank: i32,
+    #[serde(with = "time::serde::rfc3339")]
+    pub opens_at: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339")]
+    pub closes_at: OffsetDateTime,
+    pub state: BidWindowState,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct BidStateCounts {
+    pub upcoming: i64,
+    pub waiting: i64,
+    pub open: i64,
+    pub submitted: i64,
+    pub approved: i64,
+    pub skipped: i64,
+}
+
+/// Live progress of a bid period for the bid administrator.
+#[derive(Debug, Serialize)]
+pub struct BidStatusSummary {
+    pub period_id: Uuid,
+    pub status: String,
+    pub total_windows: i64,
+    pub counts: BidStateCounts,
+    /// The window bidding is on now (open, or waiting on an approval).
+    pub current: Option<BidStatusWindow>,
+    /// The next window still to bid after `current`.
+    pub next: Option<BidStatusWindow>,
+    /// When the last window still to bid is scheduled to close.
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub expected_finish_at: Option<OffsetDateTime>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub as_of: OffsetDateTime,
+}
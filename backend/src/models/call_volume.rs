@@ -0,0 +1,50 @@
+// models/call_volume.rs
+use serde::{Deserialize, Serialize};
+use time::Date;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CallVolumeRow {
+    pub date: Date,
+    pub slot_index: i16,
+    pub calls: i32,
+}
+
+/// CAD/ALI export as CSV text. Rows are `date,time,calls` (pre-aggregated) or
+/// `date,time` / `timestamp` (one row per call); times are bucketed into
+/// half-hour slots. Every date in the file replaces any earlier import of it.
+#[derive(Debug, Deserialize)]
+pub struct ImportCallVolumeRequest {
+    pub csv: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ImportCallVolumeResult {
+    pub dates_imported: i64,
+    pub slots_imported: i64,
+    pub total_calls: i64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CallVolumeQuery {
+    pub start_date: Date,
+    pub end_date: Date,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ForecastSlot {
+    pub slot_index: i16,
+    pub calls: f64,
+}
+
+/// Predicted call volume for one date.
+#[derive(Debug, Clone, Serialize)]
+pub struct CallForecastDay {
+    pub date: Date,
+    pub is_holiday: bool,
+    /// Multiplier applied on holidays, learned from past holidays in the history.
+    pub holiday_factor: f64,
+    /// Number of same-weekday history dates averaged; 0 means no forecast.
+    pub sample_days: i32,
+    pub total_calls: f64,
+    pub slots: Vec<ForecastSlot>,
+}
@@ -82,6 +82,11 @@ pub struct CalloutListEntry {
     /// True when this employee is from a different classification than the primary OT list.
     /// Only possible when the shift is within the org's cross-class eligibility window.
     pub is_cross_class: bool,
+    /// True when the employee's standing OT availability covers the whole shift.
+    pub has_standing_availability: bool,
+    /// Whether the employee holds the qualification being filled; None when the
+    /// list wasn't built for a qualification gap.
+    pub has_qualification: Option<bool>,
 }
 
 #[derive(Debug, Deserialize, Validate)]
@@ -124,6 +129,15 @@ pub struct BumpRequest {
     )]
     pub reviewed_at: Option<OffsetDateTime>,
     pub reviewed_by: Option<Uuid>,
+    /// When a still-pending request is resolved automatically.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "time::serde::rfc3339::option"
+    )]
+    pub expires_at: Option<OffsetDateTime>,
+    /// True when the deadline sweep resolved the request rather than a supervisor.
+    pub auto_resolved: bool,
 }
 
 #[derive(Debug, Clone, Serialize, sqlx::FromRow)]
@@ -147,6 +161,13 @@ pub struct BumpRequestWithNames {
     )]
     pub reviewed_at: Option<OffsetDateTime>,
     pub reviewed_by: Option<Uuid>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "time::serde::rfc3339::option"
+    )]
+    pub expires_at: Option<OffsetDateTime>,
+    pub auto_resolved: bool,
 }
 
 #[derive(Debug, Deserialize, Validate)]
@@ -162,3 +183,37 @@ pub struct ReviewBumpRequest {
     #[validate(length(max = 2000))]
     pub reason: Option<String>,
 }
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, sqlx::Type)]
+#[sqlx(type_name = "bump_audit_action", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum BumpAuditAction {
+    Submitted,
+    Approved,
+    Denied,
+    AutoApproved,
+    Expired,
+}
+
+/// One entry in a callout event's bump history.
+#[derive(Debug, Clone, Serialize)]
+pub struct BumpAuditEntry {
+    pub id: Uuid,
+    pub bump_request_id: Uuid,
+    pub action: BumpAuditAction,
+    /// `None` when the deadline sweep acted.
+    pub actor_id: Option<Uuid>,
+    pub actor_name: Option<String>,
+    pub requesting_user_name: String,
+    pub displaced_user_name: String,
+    pub notes: Option<String>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+}
+
+/// Result of resolving bump requests past their deadline.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct BumpSweepResult {
+    pub expired: u32,
+    pub auto_approved: u32,
+}
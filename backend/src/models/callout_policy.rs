@@ -0,0 +1,126 @@
+// models/callout_policy.rs
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use uuid::Uuid;
+use validator::Validate;
+
+use super::ot::CalloutStep;
+
+/// Employee attribute a callout step can sort by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CalloutSortField {
+    /// OT hours worked this fiscal year in the callout's classification.
+    OtHours,
+    /// OT hours declined this fiscal year in the callout's classification.
+    OtHoursDeclined,
+    /// Last OT contact (queue position); never contacted sorts as earliest.
+    LastContact,
+    OverallSeniority,
+    BargainingUnitSeniority,
+    ClassificationSeniority,
+    /// Last counted turn in the mandatory OT rotation; never forced sorts as earliest.
+    MandatoryRotation,
+    /// Standing OT availability covering the shift.
+    StandingAvailability,
+    /// Volunteered for this callout.
+    Volunteered,
+}
+
+impl CalloutSortField {
+    /// Direction used when a sort key doesn't give one. Seniority defaults to
+    /// inverse seniority (least senior first); flags put `true` first.
+    pub fn default_direction(self) -> SortDirection {
+        match self {
+            Self::OverallSeniority
+            | Self::BargainingUnitSeniority
+            | Self::ClassificationSeniority
+            | Self::StandingAvailability
+            | Self::Volunteered => SortDirection::Desc,
+            _ => SortDirection::Asc,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SortDirection {
+    Asc,
+    Desc,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CalloutSortKey {
+    pub field: CalloutSortField,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub direction: Option<SortDirection>,
+}
+
+/// Eligibility filters for a step. Employees who fail a filter are left off
+/// the list for that step.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CalloutStepFilters {
+    /// Only employees who volunteered for the callout or have standing availability.
+    pub volunteers_only: bool,
+    /// Exclude cross-classification employees even inside the cross-class window.
+    pub primary_classification_only: bool,
+    /// Exclude employees above this many OT hours for the fiscal year.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_ot_hours: Option<f64>,
+    /// Drop unavailable employees instead of listing them after available ones.
+    pub hide_unavailable: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CalloutPolicyStep {
+    pub step: CalloutStep,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    #[serde(default)]
+    pub sort: Vec<CalloutSortKey>,
+    #[serde(default)]
+    pub filters: CalloutStepFilters,
+}
+
+/// Stored callout policy.
+#[derive(Debug, Clone, Serialize)]
+pub struct CalloutPolicy {
+    pub id: Uuid,
+    pub org_id: Uuid,
+    /// `None` = org-wide default.
+    pub bargaining_unit: Option<String>,
+    pub name: String,
+    pub steps: Vec<CalloutPolicyStep>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339")]
+    pub updated_at: OffsetDateTime,
+}
+
+/// The policy that applies to a classification, after falling back from the
+/// bargaining unit policy to the org default to the built-in policy.
+#[derive(Debug, Clone, Serialize)]
+pub struct EffectiveCalloutPolicy {
+    /// `None` when the built-in policy applies.
+    pub policy_id: Option<Uuid>,
+    pub bargaining_unit: Option<String>,
+    pub name: String,
+    pub steps: Vec<CalloutPolicyStep>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct UpsertCalloutPolicy {
+    /// Omit for the org-wide default.
+    #[validate(length(min = 1, max = 50))]
+    pub bargaining_unit: Option<String>,
+    #[validate(length(min = 1, max = 200))]
+    pub name: String,
+    #[validate(length(min = 1, max = 10))]
+    pub steps: Vec<CalloutPolicyStep>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct EffectiveCalloutPolicyQuery {
+    pub classification_id: Uuid,
+}
@@ -3,6 +3,8 @@ use time::OffsetDateTime;
 use uuid::Uuid;
 use validator::Validate;
 
+use crate::models::common::deserialize_optional_field;
+
 #[derive(Debug, Clone, Serialize, sqlx::FromRow)]
 pub struct Classification {
     pub id: Uuid,
@@ -11,6 +13,8 @@ pub struct Classification {
     pub abbreviation: String,
     pub display_order: i32,
     pub is_active: bool,
+    /// Bargaining unit code whose callout policy applies to this classification.
+    pub bargaining_unit: Option<String>,
     #[serde(with = "time::serde::rfc3339")]
     pub created_at: OffsetDateTime,
 }
@@ -22,6 +26,8 @@ pub struct CreateClassificationRequest {
     #[validate(length(min = 1, max = 20))]
     pub abbreviation: String,
     pub display_order: Option<i32>,
+    #[validate(length(min = 1, max = 50))]
+    pub bargaining_unit: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -30,4 +36,7 @@ pub struct UpdateClassificationRequest {
     pub abbreviation: Option<String>,
     pub display_order: Option<i32>,
     pub is_active: Option<bool>,
+    /// `Some(None)` clears the bargaining unit.
+    #[serde(default, deserialize_with = "deserialize_optional_field")]
+    pub bargaining_unit: Option<Option<String>>,
 }
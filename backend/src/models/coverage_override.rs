@@ -0,0 +1,130 @@
+// models/coverage_override.rs
+use serde::{Deserialize, Serialize};
+use time::{Date, OffsetDateTime};
+use uuid::Uuid;
+use validator::Validate;
+
+/// Which layer a resolved coverage requirement came from. Later variants take
+/// precedence: holiday profiles replace plan rows, date overrides apply last.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CoverageSource {
+    Plan,
+    HolidayProfile,
+    Override,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "coverage_override_mode", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum CoverageOverrideMode {
+    /// Headcounts are deltas on top of the plan / holiday profile.
+    Add,
+    /// Headcounts replace whatever the lower layers require.
+    Set,
+}
+
+/// A sparse, date-specific adjustment to coverage requirements.
+#[derive(Debug, Clone, Serialize)]
+pub struct CoverageDateOverride {
+    pub id: Uuid,
+    pub org_id: Uuid,
+    pub date: Date,
+    pub classification_id: Uuid,
+    pub classification_abbreviation: String,
+    #[serde(with = "crate::models::common::time_format")]
+    pub start_time: time::Time,
+    /// At or before `start_time` means the window runs into the next day.
+    #[serde(with = "crate::models::common::time_format")]
+    pub end_time: time::Time,
+    pub mode: CoverageOverrideMode,
+    pub min_headcount: i16,
+    pub target_headcount: i16,
+    pub max_headcount: Option<i16>,
+    pub notes: Option<String>,
+    pub created_by: Uuid,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct CreateCoverageOverrideRequest {
+    pub date: Date,
+    pub classification_id: Uuid,
+    #[serde(with = "crate::models::common::time_format")]
+    pub start_time: time::Time,
+    #[serde(with = "crate::models::common::time_format")]
+    pub end_time: time::Time,
+    pub mode: CoverageOverrideMode,
+    pub min_headcount: i16,
+    pub target_headcount: i16,
+    pub max_headcount: Option<i16>,
+    #[validate(length(max = 500))]
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CoverageOverrideQuery {
+    pub start_date: Date,
+    pub end_date: Date,
+}
+
+/// Reusable requirement set applied automatically to dates in the holiday calendar.
+#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+pub struct HolidayCoverageProfile {
+    pub id: Uuid,
+    pub org_id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub is_default: bool,
+    pub created_by: Uuid,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339")]
+    pub updated_at: OffsetDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+pub struct HolidayCoverageProfileSlot {
+    pub id: Uuid,
+    pub profile_id: Uuid,
+    pub classification_id: Uuid,
+    pub slot_index: i16,
+    pub min_headcount: i16,
+    pub target_headcount: i16,
+    pub max_headcount: i16,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct CreateHolidayCoverageProfileRequest {
+    #[validate(length(min = 1, max = 100))]
+    pub name: String,
+    #[validate(length(max = 500))]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub is_default: bool,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct UpdateHolidayCoverageProfileRequest {
+    #[validate(length(min = 1, max = 100))]
+    pub name: Option<String>,
+    #[validate(length(max = 500))]
+    pub description: Option<String>,
+    pub is_default: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ProfileSlotEntry {
+    pub classification_id: Uuid,
+    pub slot_index: i16,
+    pub min_headcount: i16,
+    pub target_headcount: i16,
+    pub max_headcount: i16,
+}
+
+/// Replaces the profile's slots for every classification present in `slots`.
+#[derive(Debug, Deserialize)]
+pub struct ReplaceProfileSlotsRequest {
+    pub slots: Vec<ProfileSlotEntry>,
+}
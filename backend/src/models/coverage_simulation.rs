@@ -0,0 +1,61 @@
+// models/coverage_simulation.rs
+use serde::{Deserialize, Serialize};
+use time::Date;
+use uuid::Uuid;
+
+use super::schedule::ClassificationGapBlocks;
+
+/// Take a user off every shift (regular and OT) between two dates, as for
+/// leave, a special assignment or deactivation.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SimRemoveUser {
+    pub user_id: Uuid,
+    pub start_date: Date,
+    pub end_date: Date,
+}
+
+/// Put a user on an extra shift. Counts toward the user's own
+/// classification unless `classification_id` is given.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SimAddShift {
+    pub user_id: Uuid,
+    pub date: Date,
+    pub shift_template_id: Uuid,
+    pub classification_id: Option<Uuid>,
+}
+
+/// Move a user's regular shift on `date` to a different shift template.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SimSwapShift {
+    pub user_id: Uuid,
+    pub date: Date,
+    pub shift_template_id: Uuid,
+}
+
+/// Hypothetical changes, applied in the order remove → swap → add.
+#[derive(Debug, Deserialize)]
+pub struct SimulateCoverageRequest {
+    #[serde(default)]
+    pub remove_users: Vec<SimRemoveUser>,
+    #[serde(default)]
+    pub swap_shifts: Vec<SimSwapShift>,
+    #[serde(default)]
+    pub add_shifts: Vec<SimAddShift>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SimulatedDay {
+    pub date: Date,
+    pub before: Vec<ClassificationGapBlocks>,
+    pub after: Vec<ClassificationGapBlocks>,
+    /// Sum of per-slot shortages below minimum (headcount × half-hours).
+    pub before_shortage: i32,
+    pub after_shortage: i32,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SimulateCoverageResponse {
+    /// True when any affected date ends up with more shortage than today.
+    pub worsens_coverage: bool,
+    pub days: Vec<SimulatedDay>,
+}
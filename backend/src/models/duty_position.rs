@@ -117,6 +117,8 @@ pub struct Qualification {
     pub org_id: Uuid,
     pub name: String,
     pub description: Option<String>,
+    /// How long a grant stays valid; None = never expires.
+    pub renewal_months: Option<i16>,
     #[serde(with = "time::serde::rfc3339")]
     pub created_at: OffsetDateTime,
 }
@@ -127,6 +129,8 @@ pub struct CreateQualificationRequest {
     pub name: String,
     #[validate(length(max = 200))]
     pub description: Option<String>,
+    #[validate(range(min = 1, max = 120))]
+    pub renewal_months: Option<i16>,
 }
 
 #[derive(Debug, Deserialize, Validate)]
@@ -137,6 +141,9 @@ pub struct UpdateQualificationRequest {
     #[serde(default, deserialize_with = "crate::models::common::deserialize_optional_field")]
     #[validate(length(max = 200))]
     pub description: Option<Option<String>>,
+    /// Double-option: None = keep, Some(None) = clear, Some(Some(v)) = set
+    #[serde(default, deserialize_with = "crate::models::common::deserialize_optional_field")]
+    pub renewal_months: Option<Option<i16>>,
 }
 
 // ============================================================
@@ -179,6 +186,8 @@ pub struct DutyBoardResponse {
     pub date: String,
     pub positions: Vec<BoardPosition>,
     pub assignments: Vec<BoardAssignment>,
+    /// Break overlay: who is off their position and who relieves them.
+    pub breaks: Vec<DutyBreakView>,
 }
 
 #[derive(Debug, Serialize)]
@@ -247,6 +256,118 @@ pub struct AvailableEmployee {
     pub already_assigned_position: Option<String>,
 }
 
+// ============================================================
+// Auto-fill
+// ============================================================
+
+#[derive(Debug, Default, Deserialize)]
+pub struct AutoFillRequest {
+    /// Replace existing assignments too; OT-needed markers are always kept.
+    #[serde(default)]
+    pub overwrite: bool,
+    /// Return the plan without writing it.
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AutoFillCell {
+    pub duty_position_id: Uuid,
+    pub position_name: String,
+    pub block_index: i16,
+    pub user_id: Uuid,
+    pub first_name: String,
+    pub last_name: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct UnfilledCell {
+    pub duty_position_id: Uuid,
+    pub position_name: String,
+    pub block_index: i16,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AutoFillResponse {
+    pub assigned: Vec<AutoFillCell>,
+    /// Open cells nobody eligible was free for.
+    pub unfilled: Vec<UnfilledCell>,
+}
+
+// ============================================================
+// Breaks and relief
+// ============================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "duty_break_kind", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum DutyBreakKind {
+    Break,
+    Meal,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BreakCoverage {
+    /// A free, eligible relief is covering the position.
+    Relieved,
+    /// The position is left empty for the break.
+    Uncovered,
+    /// The employee isn't seated during that block, so nothing needs relief.
+    OffBoard,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DutyBreakView {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub first_name: String,
+    pub last_name: String,
+    pub date: time::Date,
+    pub block_index: i16,
+    #[serde(with = "crate::models::common::time_format")]
+    pub start_time: time::Time,
+    #[serde(with = "crate::models::common::time_format")]
+    pub end_time: time::Time,
+    pub kind: DutyBreakKind,
+    /// The position the employee holds during the block.
+    pub duty_position_id: Option<Uuid>,
+    pub position_name: Option<String>,
+    pub relief_user_id: Option<Uuid>,
+    pub relief_first_name: Option<String>,
+    pub relief_last_name: Option<String>,
+    pub coverage: BreakCoverage,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateDutyBreakRequest {
+    pub user_id: Uuid,
+    #[serde(with = "crate::models::common::time_format")]
+    pub start_time: time::Time,
+    #[serde(with = "crate::models::common::time_format")]
+    pub end_time: time::Time,
+    pub kind: Option<DutyBreakKind>,
+    pub relief_user_id: Option<Uuid>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateDutyBreakRequest {
+    pub kind: Option<DutyBreakKind>,
+    /// Double-option: None = keep, Some(None) = clear, Some(Some(v)) = set
+    #[serde(default, deserialize_with = "crate::models::common::deserialize_optional_field")]
+    pub relief_user_id: Option<Option<Uuid>>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct PlanReliefRequest {
+    /// Re-plan breaks that already have a relief too.
+    #[serde(default)]
+    pub replace: bool,
+    /// Return the plan without saving it.
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
 // ============================================================
 // Console hours report
 // ============================================================
@@ -276,6 +397,15 @@ pub struct QualificationMappingRequest {
     pub qualification_id: Uuid,
 }
 
+/// Grant (or recertify) a qualification. `issued_on` defaults to today and
+/// `expires_on` to `issued_on` plus the qualification's renewal period.
+#[derive(Debug, Deserialize)]
+pub struct GrantQualificationRequest {
+    pub qualification_id: Uuid,
+    pub issued_on: Option<time::Date>,
+    pub expires_on: Option<time::Date>,
+}
+
 #[derive(Debug, Clone, Serialize, sqlx::FromRow)]
 pub struct UserQualificationView {
     pub user_id: Uuid,
@@ -283,4 +413,8 @@ pub struct UserQualificationView {
     pub qualification_name: String,
     #[serde(with = "time::serde::rfc3339")]
     pub granted_at: OffsetDateTime,
+    pub issued_on: Option<time::Date>,
+    /// Last day the qualification counts; None = never expires.
+    pub expires_on: Option<time::Date>,
+    pub is_expired: bool,
 }
@@ -0,0 +1,78 @@
+// models/gap_incident.rs
+use serde::{Deserialize, Serialize};
+use time::{Date, OffsetDateTime};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "gap_incident_status", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum GapIncidentStatus {
+    Open,
+    Resolved,
+}
+
+/// A tracked coverage gap, kept open until the window is staffed or has passed.
+#[derive(Debug, Clone, Serialize)]
+pub struct GapIncident {
+    pub id: Uuid,
+    pub org_id: Uuid,
+    pub classification_id: Uuid,
+    pub classification_abbreviation: String,
+    pub date: Date,
+    #[serde(with = "crate::models::common::time_format")]
+    pub start_time: time::Time,
+    /// `end_time <= start_time` means the window ends on the following day.
+    #[serde(with = "crate::models::common::time_format")]
+    pub end_time: time::Time,
+    /// Largest per-slot shortage below minimum as of the last scan.
+    pub shortage: i32,
+    /// 0 = not yet alerted, 1 = in-app alert sent, 2 = escalated by SMS.
+    pub alert_level: i16,
+    pub status: GapIncidentStatus,
+    #[serde(with = "time::serde::rfc3339")]
+    pub detected_at: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub last_alerted_at: Option<OffsetDateTime>,
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub escalated_at: Option<OffsetDateTime>,
+    pub acknowledged_by: Option<Uuid>,
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub acknowledged_at: Option<OffsetDateTime>,
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub resolved_at: Option<OffsetDateTime>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GapAlert {
+    pub id: Uuid,
+    pub alert_level: i16,
+    /// `in_app` or `sms`.
+    pub channel: String,
+    pub shortage: i32,
+    pub recipient_count: i32,
+    #[serde(with = "time::serde::rfc3339")]
+    pub sent_at: OffsetDateTime,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GapIncidentDetail {
+    #[serde(flatten)]
+    pub incident: GapIncident,
+    pub alerts: Vec<GapAlert>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GapIncidentQuery {
+    pub status: Option<GapIncidentStatus>,
+    pub date_from: Option<Date>,
+    pub date_to: Option<Date>,
+}
+
+/// Outcome of one monitor pass over an org.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct GapScanResult {
+    pub opened: u32,
+    pub worsened: u32,
+    pub escalated: u32,
+    pub resolved: u32,
+}
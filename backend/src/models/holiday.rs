@@ -3,6 +3,8 @@ use time::OffsetDateTime;
 use uuid::Uuid;
 use validator::Validate;
 
+use crate::models::{common::deserialize_optional_field, report::ExportFormat};
+
 #[derive(Debug, Clone, Serialize, sqlx::FromRow)]
 pub struct Holiday {
     pub id: Uuid,
@@ -10,6 +12,8 @@ pub struct Holiday {
     pub date: time::Date,
     pub name: String,
     pub is_premium_pay: bool,
+    /// Holiday coverage profile for this date; NULL falls back to the org default profile.
+    pub coverage_profile_id: Option<Uuid>,
     #[serde(with = "time::serde::rfc3339")]
     pub created_at: OffsetDateTime,
 }
@@ -20,6 +24,7 @@ pub struct CreateHolidayRequest {
     #[validate(length(min = 1, max = 100))]
     pub name: String,
     pub is_premium_pay: Option<bool>,
+    pub coverage_profile_id: Option<Uuid>,
 }
 
 #[derive(Debug, Deserialize, Validate)]
@@ -27,9 +32,107 @@ pub struct UpdateHolidayRequest {
     #[validate(length(min = 1, max = 100))]
     pub name: Option<String>,
     pub is_premium_pay: Option<bool>,
+    /// `Some(None)` falls back to the org default coverage profile.
+    #[serde(default, deserialize_with = "deserialize_optional_field")]
+    pub coverage_profile_id: Option<Option<Uuid>>,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct HolidayQuery {
     pub year: Option<i32>,
 }
+
+/// Holidays that rotate, such as every year's Christmas Day. Whoever received
+/// one of the group's holidays in the last `lockout_years` years can't bid the
+/// group's holidays in vacation rounds up to `restricted_through_round`.
+#[derive(Debug, Serialize)]
+pub struct HolidayGroup {
+    pub id: Uuid,
+    pub org_id: Uuid,
+    pub name: String,
+    pub lockout_years: i32,
+    pub restricted_through_round: i32,
+    pub holiday_ids: Vec<Uuid>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+}
+
+/// Creates a holiday group, or replaces one (including its holidays).
+#[derive(Debug, Deserialize, Validate)]
+pub struct HolidayGroupRequest {
+    #[validate(length(min = 1, max = 100))]
+    pub name: String,
+    /// Defaults to 1: last year's recipients sit out.
+    #[validate(range(min = 1, max = 10))]
+    pub lockout_years: Option<i32>,
+    /// Defaults to 1: the rule applies in round 1 only.
+    #[validate(range(min = 1, max = 2))]
+    pub restricted_through_round: Option<i32>,
+    pub holiday_ids: Vec<Uuid>,
+}
+
+/// An employee's receipt of a holiday: awarded through vacation bidding
+/// (`vacation_bid_id`) or entered by an admin (`recorded_by`).
+#[derive(Debug, Serialize)]
+pub struct HolidayAward {
+    pub id: Uuid,
+    pub holiday_id: Uuid,
+    pub holiday_name: String,
+    pub date: time::Date,
+    pub user_id: Uuid,
+    pub first_name: String,
+    pub last_name: String,
+    pub vacation_bid_id: Option<Uuid>,
+    pub recorded_by: Option<Uuid>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct HolidayAwardQuery {
+    pub year: Option<i32>,
+    pub user_id: Option<Uuid>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateHolidayAwardRequest {
+    pub holiday_id: Uuid,
+    pub user_id: Uuid,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct HolidayRotationQuery {
+    /// The bid year eligibility is shown for; defaults to the current year.
+    pub year: Option<i32>,
+    #[serde(default)]
+    pub format: ExportFormat,
+}
+
+/// How often each active employee has received each holiday group, and
+/// whether the rotation keeps them out of restricted rounds in `year`.
+#[derive(Debug, Serialize)]
+pub struct HolidayRotationReport {
+    pub year: i32,
+    pub groups: Vec<HolidayGroup>,
+    pub rows: Vec<HolidayRotationRow>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct HolidayRotationRow {
+    pub user_id: Uuid,
+    pub first_name: String,
+    pub last_name: String,
+    pub seniority_date: Option<time::Date>,
+    /// Every holiday received, grouped or not.
+    pub total_received: i64,
+    /// One entry per group, in the order of `HolidayRotationReport::groups`.
+    pub groups: Vec<HolidayRotationEntry>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct HolidayRotationEntry {
+    pub group_id: Uuid,
+    pub times_received: i64,
+    pub last_received_year: Option<i32>,
+    pub locked_out: bool,
+}
@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+/// Two employees sharing one position. They bid for shifts together in a joint
+/// window at the senior partner's rank.
+#[derive(Debug, Serialize)]
+pub struct JobSharePair {
+    pub id: Uuid,
+    pub user_a_id: Uuid,
+    pub user_a_first_name: String,
+    pub user_a_last_name: String,
+    pub user_b_id: Uuid,
+    pub user_b_first_name: String,
+    pub user_b_last_name: String,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateJobSharePairRequest {
+    pub user_a_id: Uuid,
+    pub user_b_id: Uuid,
+}
@@ -0,0 +1,136 @@
+// models/mandatory_ot.rs
+use serde::{Deserialize, Serialize};
+use time::{Date, OffsetDateTime};
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "mandatory_ot_event_type", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum MandatoryOtEventType {
+    /// Employee worked forced OT (recorded automatically on mandatory assignment).
+    Forced,
+    /// Employee refused a forced OT order.
+    Refused,
+    /// Supervisor override of the rotation (e.g. disciplinary assignment).
+    Override,
+}
+
+/// An entry in the mandatory OT rotation log, with joined display fields.
+#[derive(Debug, Clone, Serialize)]
+pub struct MandatoryOtEvent {
+    pub id: Uuid,
+    pub classification_id: Uuid,
+    pub classification_name: String,
+    pub user_id: Uuid,
+    pub user_name: String,
+    pub event_type: MandatoryOtEventType,
+    pub counts_as_turn: bool,
+    pub ot_request_assignment_id: Option<Uuid>,
+    pub callout_event_id: Option<Uuid>,
+    pub notes: Option<String>,
+    pub recorded_by: Uuid,
+    #[serde(with = "time::serde::rfc3339")]
+    pub occurred_at: OffsetDateTime,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "time::serde::rfc3339::option"
+    )]
+    pub voided_at: Option<OffsetDateTime>,
+    pub voided_by: Option<Uuid>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MandatoryOtEventQuery {
+    pub classification_id: Option<Uuid>,
+    pub user_id: Option<Uuid>,
+    pub event_type: Option<MandatoryOtEventType>,
+    pub include_voided: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct CreateMandatoryOtEvent {
+    pub classification_id: Uuid,
+    pub user_id: Uuid,
+    /// Only `refused` and `override` can be recorded by hand; `forced` events
+    /// come from mandatory OT assignments.
+    pub event_type: MandatoryOtEventType,
+    /// Whether the event uses up the employee's turn. Defaults to false for
+    /// refusals (the employee stays at the front) and true for overrides.
+    pub counts_as_turn: Option<bool>,
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub occurred_at: Option<OffsetDateTime>,
+    #[validate(length(max = 2000))]
+    pub notes: Option<String>,
+}
+
+/// A time-bound exemption from forced OT.
+#[derive(Debug, Clone, Serialize)]
+pub struct MandatoryOtExemption {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub user_name: String,
+    /// `None` means the exemption applies to every classification.
+    pub classification_id: Option<Uuid>,
+    pub start_date: Date,
+    pub end_date: Date,
+    pub reason: String,
+    pub created_by: Uuid,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "time::serde::rfc3339::option"
+    )]
+    pub revoked_at: Option<OffsetDateTime>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MandatoryOtExemptionQuery {
+    pub user_id: Option<Uuid>,
+    /// Only exemptions covering this date.
+    pub active_on: Option<Date>,
+    pub include_revoked: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct CreateMandatoryOtExemption {
+    pub user_id: Uuid,
+    pub classification_id: Option<Uuid>,
+    pub start_date: Date,
+    pub end_date: Date,
+    #[validate(length(min = 1, max = 2000))]
+    pub reason: String,
+}
+
+/// Why an employee is skipped when working down the rotation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MandatoryOtIneligibleReason {
+    /// Standing medical OT exemption on the employee record.
+    MedicalExempt,
+    /// Active time-bound mandatory OT exemption.
+    Exempted,
+    /// Approved leave covering the date.
+    OnLeave,
+    /// Working the window would break the 10-hour rest rule.
+    RestPeriod,
+}
+
+/// One employee's place in the mandatory OT rotation for a classification.
+#[derive(Debug, Clone, Serialize)]
+pub struct MandatoryOtOrderEntry {
+    pub user_id: Uuid,
+    pub first_name: String,
+    pub last_name: String,
+    /// 1-based place in the rotation, counting ineligible employees.
+    pub position: i32,
+    /// When the employee last used up a turn (forced, or an override that counted).
+    pub last_mandatory_at: Option<String>,
+    pub times_forced: i64,
+    pub refusals: i64,
+    pub eligible: bool,
+    pub ineligible_reason: Option<MandatoryOtIneligibleReason>,
+}
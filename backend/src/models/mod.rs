@@ -1,24 +1,37 @@
+pub mod bid_processing;
+pub mod bid_proxy;
 pub mod bidding;
+pub mod call_volume;
 pub mod callout;
+pub mod callout_policy;
 pub mod classification;
 pub mod common;
+pub mod coverage_override;
+pub mod coverage_simulation;
 pub mod duty_position;
 pub mod employee;
+pub mod gap_incident;
 pub mod holiday;
+pub mod job_share;
 pub mod leave;
 pub mod leave_balance;
 pub mod leave_donation;
 pub mod leave_sellback;
+pub mod mandatory_ot;
 pub mod notification;
 pub mod organization;
 pub mod ot;
+pub mod ot_gap_posting;
 pub mod ot_request;
+pub mod ot_request_series;
 pub mod report;
 pub mod saved_filter;
 pub mod schedule;
+pub mod seniority;
 pub mod shift;
 pub mod shift_pattern;
 pub mod special_assignment;
+pub mod standing_availability;
 pub mod team;
 pub mod trade;
 pub mod user;
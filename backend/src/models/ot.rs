@@ -81,8 +81,12 @@ pub struct OtVolunteer {
     pub user_id: Uuid,
     pub first_name: String,
     pub last_name: String,
+    /// When they volunteered; for standing sign-ups, when the sign-up was made.
     #[serde(with = "time::serde::rfc3339")]
     pub volunteered_at: OffsetDateTime,
+    /// True for employees surfaced from standing OT availability rather than
+    /// volunteering for this callout (`id` is then the sign-up id).
+    pub is_standing: bool,
 }
 
 #[derive(Debug, Deserialize)]
@@ -0,0 +1,56 @@
+// models/ot_gap_posting.rs
+use serde::{Deserialize, Serialize};
+use time::{Date, OffsetDateTime};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "ot_gap_posting_status", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum OtGapPostingStatus {
+    Draft,
+    Published,
+    Dismissed,
+}
+
+/// A coverage-gap window picked up by the auto-post job, with joined display fields.
+#[derive(Debug, Clone, Serialize)]
+pub struct OtGapPosting {
+    pub id: Uuid,
+    pub org_id: Uuid,
+    pub classification_id: Uuid,
+    pub classification_name: String,
+    pub date: Date,
+    #[serde(with = "crate::models::common::time_format")]
+    pub start_time: time::Time,
+    #[serde(with = "crate::models::common::time_format")]
+    pub end_time: time::Time,
+    pub hours: f64,
+    /// Largest per-slot shortage inside the window.
+    pub shortage: i16,
+    pub status: OtGapPostingStatus,
+    /// Set once the window has been published as an OT request.
+    pub ot_request_id: Option<Uuid>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+    pub reviewed_by: Option<Uuid>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "time::serde::rfc3339::option"
+    )]
+    pub reviewed_at: Option<OffsetDateTime>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct OtGapPostingQuery {
+    pub status: Option<OtGapPostingStatus>,
+    pub date_from: Option<Date>,
+    pub date_to: Option<Date>,
+    pub classification_id: Option<Uuid>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct OtAutopostRunQuery {
+    /// If true, report the windows that would be posted without writing anything.
+    pub dry_run: Option<bool>,
+}
@@ -48,6 +48,8 @@ pub struct OtRequestRow {
     )]
     pub cancelled_at: Option<OffsetDateTime>,
     pub cancelled_by: Option<Uuid>,
+    /// Set when this request is an occurrence of a recurring series.
+    pub series_id: Option<Uuid>,
     pub volunteer_count: i64,
     pub assignment_count: i64,
     pub user_volunteered: bool,
@@ -86,6 +88,7 @@ pub struct OtRequestDetail {
     )]
     pub cancelled_at: Option<OffsetDateTime>,
     pub cancelled_by: Option<Uuid>,
+    pub series_id: Option<Uuid>,
     pub volunteers: Vec<OtRequestVolunteerRow>,
     pub assignments: Vec<OtRequestAssignmentRow>,
 }
@@ -129,6 +132,7 @@ pub struct OtRequestQuery {
     pub date_from: Option<time::Date>,
     pub date_to: Option<time::Date>,
     pub classification_id: Option<Uuid>,
+    pub series_id: Option<Uuid>,
     /// When true, only return requests where the calling user has an active volunteer entry.
     pub volunteered_by_me: Option<bool>,
     pub limit: Option<i64>,
@@ -0,0 +1,156 @@
+// models/ot_request_series.rs
+use serde::{Deserialize, Serialize};
+use time::{Date, OffsetDateTime};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::models::common::deserialize_optional_field;
+use crate::models::ot_request::OtRequestStatus;
+
+/// Recurring OT request series with joined display fields.
+#[derive(Debug, Clone, Serialize)]
+pub struct OtRequestSeries {
+    pub id: Uuid,
+    pub org_id: Uuid,
+    pub classification_id: Uuid,
+    pub classification_name: String,
+    pub ot_reason_id: Option<Uuid>,
+    pub ot_reason_name: Option<String>,
+    #[serde(with = "crate::models::common::time_format")]
+    pub start_time: time::Time,
+    #[serde(with = "crate::models::common::time_format")]
+    pub end_time: time::Time,
+    pub hours: f64,
+    pub location: Option<String>,
+    pub is_fixed_coverage: bool,
+    pub notes: Option<String>,
+    /// 0=Sunday..6=Saturday
+    pub days_of_week: Vec<i16>,
+    pub interval_weeks: i16,
+    pub start_date: Date,
+    pub end_date: Date,
+    pub skip_holidays: bool,
+    pub excluded_dates: Vec<Date>,
+    /// The series this one continues after an edit from a date forward.
+    pub previous_series_id: Option<Uuid>,
+    pub created_by: Uuid,
+    pub created_by_name: String,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339")]
+    pub updated_at: OffsetDateTime,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "time::serde::rfc3339::option"
+    )]
+    pub cancelled_at: Option<OffsetDateTime>,
+    pub cancelled_by: Option<Uuid>,
+    /// Number of non-cancelled occurrences.
+    pub occurrence_count: i64,
+}
+
+/// One materialized occurrence (an `ot_requests` row) of a series.
+#[derive(Debug, Clone, Serialize)]
+pub struct OtRequestSeriesOccurrence {
+    pub id: Uuid,
+    pub date: Date,
+    #[serde(with = "crate::models::common::time_format")]
+    pub start_time: time::Time,
+    #[serde(with = "crate::models::common::time_format")]
+    pub end_time: time::Time,
+    pub status: OtRequestStatus,
+    pub volunteer_count: i64,
+    pub assignment_count: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct OtRequestSeriesDetail {
+    #[serde(flatten)]
+    pub series: OtRequestSeries,
+    pub occurrences: Vec<OtRequestSeriesOccurrence>,
+}
+
+/// Dates touched when a series' occurrences were (re)generated.
+///
+/// `preserved` lists occurrences that no longer match the rule (or whose times
+/// changed) but were left as-is because they have active volunteers or assignments.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SeriesSyncSummary {
+    pub created: Vec<Date>,
+    pub updated: Vec<Date>,
+    pub cancelled: Vec<Date>,
+    pub preserved: Vec<Date>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct OtRequestSeriesSyncResponse {
+    pub series: OtRequestSeries,
+    #[serde(flatten)]
+    pub summary: SeriesSyncSummary,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct CreateOtRequestSeries {
+    #[serde(with = "crate::models::common::time_format")]
+    pub start_time: time::Time,
+    #[serde(with = "crate::models::common::time_format")]
+    pub end_time: time::Time,
+    pub classification_id: Uuid,
+    pub ot_reason_id: Option<Uuid>,
+    #[validate(length(max = 500))]
+    pub location: Option<String>,
+    pub is_fixed_coverage: Option<bool>,
+    #[validate(length(max = 2000))]
+    pub notes: Option<String>,
+    #[validate(length(min = 1, max = 7))]
+    pub days_of_week: Vec<i16>,
+    #[validate(range(min = 1, max = 52))]
+    pub interval_weeks: Option<i16>,
+    pub start_date: Date,
+    pub end_date: Date,
+    /// Defaults to true: dates in the org's holiday calendar are skipped.
+    pub skip_holidays: Option<bool>,
+    #[serde(default)]
+    pub excluded_dates: Vec<Date>,
+}
+
+/// Edit a series from `effective_from` forward. The series ends the day before
+/// and a successor series carrying the new rule takes over its later
+/// occurrences; those with active volunteers or assignments are preserved.
+/// From the series' own start date the series is edited in place. A series
+/// already continued by a successor can only be edited in place.
+#[derive(Debug, Deserialize, Validate)]
+pub struct UpdateOtRequestSeries {
+    pub effective_from: Date,
+    #[serde(default, with = "crate::models::common::time_format_option")]
+    pub start_time: Option<time::Time>,
+    #[serde(default, with = "crate::models::common::time_format_option")]
+    pub end_time: Option<time::Time>,
+    #[serde(default, deserialize_with = "deserialize_optional_field")]
+    pub ot_reason_id: Option<Option<Uuid>>,
+    #[serde(default, deserialize_with = "deserialize_optional_field")]
+    pub location: Option<Option<String>>,
+    pub is_fixed_coverage: Option<bool>,
+    #[serde(default, deserialize_with = "deserialize_optional_field")]
+    pub notes: Option<Option<String>>,
+    #[validate(length(min = 1, max = 7))]
+    pub days_of_week: Option<Vec<i16>>,
+    #[validate(range(min = 1, max = 52))]
+    pub interval_weeks: Option<i16>,
+    pub end_date: Option<Date>,
+    pub skip_holidays: Option<bool>,
+}
+
+/// Cancel a series from `effective_from` forward.
+#[derive(Debug, Deserialize)]
+pub struct CancelOtRequestSeries {
+    pub effective_from: Date,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct OtRequestSeriesQuery {
+    /// When true, include fully cancelled series.
+    pub include_cancelled: Option<bool>,
+    pub classification_id: Option<Uuid>,
+}
@@ -95,3 +95,41 @@ pub struct WorkSummaryReport {
     pub total_hours: f64,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct QualificationExpiryQuery {
+    /// Look-ahead window; defaults to 90 days.
+    pub within_days: Option<i64>,
+    pub team_id: Option<Uuid>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct QualificationExpiryEntry {
+    /// Team of the holder's slot in the current schedule period.
+    pub team_id: Option<Uuid>,
+    pub team_name: Option<String>,
+    pub user_id: Uuid,
+    pub first_name: String,
+    pub last_name: String,
+    pub qualification_id: Uuid,
+    pub qualification_name: String,
+    pub issued_on: Option<time::Date>,
+    pub expires_on: time::Date,
+    /// Negative once lapsed.
+    pub days_remaining: i64,
+}
+
+/// Download format for exportable reports.
+#[derive(Debug, Clone, Copy, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportFormat {
+    #[default]
+    Json,
+    Csv,
+    Pdf,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ExportQuery {
+    #[serde(default)]
+    pub format: ExportFormat,
+}
@@ -3,6 +3,8 @@ use time::OffsetDateTime;
 use uuid::Uuid;
 use validator::Validate;
 
+use crate::models::coverage_override::CoverageSource;
+
 /// An assignment of a specific employee to a specific scheduled shift.
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
 pub struct Assignment {
@@ -155,6 +157,48 @@ pub struct BulkUpsertSlotsRequest {
     pub slots: Vec<SlotEntry>,
 }
 
+/// "At least `min_count` people holding the qualification" for one plan slot.
+#[derive(Debug, Clone, Serialize)]
+pub struct CoveragePlanQualificationSlot {
+    pub id: Uuid,
+    pub plan_id: Uuid,
+    pub qualification_id: Uuid,
+    pub qualification_name: String,
+    pub day_of_week: i16,
+    pub slot_index: i16,
+    pub min_count: i16,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct QualificationSlotEntry {
+    pub qualification_id: Uuid,
+    pub day_of_week: i16,
+    pub slot_index: i16,
+    pub min_count: i16,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BulkUpsertQualificationSlotsRequest {
+    pub slots: Vec<QualificationSlotEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StaffingSlotEntry {
+    pub slot_index: i16,
+    pub min_headcount: i16,
+    pub target_headcount: i16,
+}
+
+/// Applies calculated staffing (e.g. Erlang C results) as min/target headcounts
+/// for one classification on the selected weekdays. Existing max headcounts are
+/// kept unless the new target exceeds them.
+#[derive(Debug, Deserialize)]
+pub struct ApplyStaffingRequest {
+    pub classification_id: Uuid,
+    pub days_of_week: Vec<i16>,
+    pub slots: Vec<StaffingSlotEntry>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct CreateCoveragePlanAssignmentRequest {
     pub plan_id: Uuid,
@@ -173,6 +217,20 @@ pub struct SlotCoverage {
     pub max_headcount: i16,
     pub actual_headcount: i32,
     pub status: String,
+    /// Layer the requirement came from (plan, holiday profile or date override).
+    pub source: CoverageSource,
+}
+
+/// Qualified headcount for one slot: everyone working it who holds the
+/// qualification, whatever their classification.
+#[derive(Debug, Serialize)]
+pub struct QualificationSlotCoverage {
+    pub slot_index: i16,
+    pub qualification_id: Uuid,
+    pub qualification_name: String,
+    pub min_count: i16,
+    pub actual_count: i32,
+    pub status: String,
 }
 
 // -- Classification Gap (per-shift coverage shortage) --
@@ -205,6 +263,13 @@ pub struct ClassificationGapBlocks {
     pub blocks: Vec<CoverageGapBlock>,
 }
 
+#[derive(Debug, Serialize)]
+pub struct QualificationGapBlocks {
+    pub qualification_id: Uuid,
+    pub qualification_name: String,
+    pub blocks: Vec<CoverageGapBlock>,
+}
+
 // -- Schedule Annotations --
 
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
@@ -301,6 +366,8 @@ pub struct DayGridResponse {
     pub date: String,
     pub classifications: Vec<DayGridClassification>,
     pub blocks: Vec<CoverageBlock>,
+    /// Qualification requirements in effect for the day, one row per qualification.
+    pub qualifications: Vec<DayGridQualification>,
 }
 
 #[derive(Debug, Serialize)]
@@ -319,6 +386,29 @@ pub struct ClassificationBlock {
     pub target: i16,
     pub actual: i32,
     pub status: String,
+    /// Highest-precedence layer among the block's slot requirements; None when
+    /// the block has no requirement.
+    pub source: Option<CoverageSource>,
+    pub employees: Vec<BlockEmployee>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DayGridQualification {
+    pub qualification_id: Uuid,
+    pub name: String,
+    pub blocks: Vec<QualificationBlock>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct QualificationBlock {
+    pub block_index: u8,
+    pub start_time: String,
+    pub end_time: String,
+    pub min: i16,
+    /// Fewest qualified people on duty in any slot of the block.
+    pub actual: i32,
+    pub status: String,
+    /// Qualified people working some part of the block.
     pub employees: Vec<BlockEmployee>,
 }
 
@@ -340,6 +430,8 @@ pub struct CoverageBlock {
     pub total_target: i32,
     pub total_actual: i32,
     pub status: String,
+    /// Forecast call volume for the block; None without call-volume history.
+    pub forecast_calls: Option<f64>,
 }
 
 // -- Dashboard Types --
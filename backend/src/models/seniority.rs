@@ -0,0 +1,252 @@
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::models::{report::ExportFormat, user::EmployeeStatus};
+
+/// Which seniority date a list ranks by, and whom it covers.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, sqlx::Type)]
+#[sqlx(type_name = "seniority_list_type", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum SeniorityListType {
+    /// Everyone, by overall seniority date.
+    Overall,
+    /// One bargaining unit, by bargaining unit seniority date.
+    BargainingUnit,
+    /// One classification, by classification seniority date.
+    Classification,
+}
+
+/// A published seniority list: a snapshot of the order on the day it was
+/// published. Lists are never edited; corrections show on the next one.
+#[derive(Debug, Clone, Serialize)]
+pub struct SeniorityList {
+    pub id: Uuid,
+    pub org_id: Uuid,
+    pub list_type: SeniorityListType,
+    pub bargaining_unit: Option<String>,
+    pub classification_id: Option<Uuid>,
+    pub classification_name: Option<String>,
+    pub title: String,
+    /// Applied in order when two employees' seniority dates are equal.
+    pub tie_break_rules: Vec<String>,
+    pub published_by: Uuid,
+    #[serde(with = "time::serde::rfc3339")]
+    pub published_at: OffsetDateTime,
+    pub entry_count: i64,
+    pub open_disputes: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SeniorityListEntry {
+    pub rank: i32,
+    pub user_id: Uuid,
+    pub employee_id: Option<String>,
+    pub first_name: String,
+    pub last_name: String,
+    pub seniority_date: Option<time::Date>,
+    /// The tie-break rule that placed this employee below the one ranked
+    /// above when their seniority dates were equal.
+    pub tie_broken_by: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SeniorityListDetail {
+    #[serde(flatten)]
+    pub list: SeniorityList,
+    pub entries: Vec<SeniorityListEntry>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct PublishSeniorityListRequest {
+    pub list_type: SeniorityListType,
+    /// Required for bargaining unit lists.
+    pub bargaining_unit: Option<String>,
+    /// Required for classification lists.
+    pub classification_id: Option<Uuid>,
+    /// Defaults to the list type, scope and date.
+    #[validate(length(min = 1, max = 200))]
+    pub title: Option<String>,
+    /// Defaults to the org's `seniority_tie_breakers` setting.
+    pub tie_break_rules: Option<Vec<String>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SeniorityListQuery {
+    pub list_type: Option<SeniorityListType>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SeniorityListExportQuery {
+    #[serde(default)]
+    pub format: ExportFormat,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, sqlx::Type)]
+#[sqlx(type_name = "seniority_dispute_status", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum SeniorityDisputeStatus {
+    Open,
+    /// The employee was right; their seniority date was corrected.
+    Upheld,
+    Denied,
+    Withdrawn,
+}
+
+/// An employee's challenge to their placement on a published list.
+#[derive(Debug, Serialize)]
+pub struct SeniorityDispute {
+    pub id: Uuid,
+    pub list_id: Uuid,
+    pub list_title: String,
+    pub list_type: SeniorityListType,
+    pub user_id: Uuid,
+    pub first_name: String,
+    pub last_name: String,
+    /// The employee's rank and date on the disputed list.
+    pub rank: i32,
+    pub listed_date: Option<time::Date>,
+    pub reason: String,
+    /// The date the employee says is right, if they gave one.
+    pub requested_date: Option<time::Date>,
+    pub status: SeniorityDisputeStatus,
+    pub corrected_date: Option<time::Date>,
+    pub resolution_notes: Option<String>,
+    pub resolved_by: Option<Uuid>,
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub resolved_at: Option<OffsetDateTime>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct CreateSeniorityDisputeRequest {
+    #[validate(length(min = 1, max = 2000))]
+    pub reason: String,
+    pub requested_date: Option<time::Date>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SeniorityDisputeQuery {
+    pub status: Option<SeniorityDisputeStatus>,
+    pub list_id: Option<Uuid>,
+}
+
+/// Resolve a dispute as `upheld` (with the corrected date) or `denied`.
+#[derive(Debug, Deserialize, Validate)]
+pub struct ResolveSeniorityDisputeRequest {
+    pub status: SeniorityDisputeStatus,
+    /// Required when upheld; written to the employee's seniority record.
+    pub corrected_date: Option<time::Date>,
+    #[validate(length(max = 2000))]
+    pub notes: Option<String>,
+}
+
+/// A change to one of an employee's seniority dates.
+#[derive(Debug, Serialize)]
+pub struct SeniorityAuditEntry {
+    pub id: Uuid,
+    pub seniority_type: SeniorityListType,
+    pub old_date: Option<time::Date>,
+    pub new_date: Option<time::Date>,
+    /// `profile`, `status_change`, `dispute` or `recompute`.
+    pub source: String,
+    pub dispute_id: Option<Uuid>,
+    /// NULL = system.
+    pub changed_by: Option<Uuid>,
+    pub changed_by_name: Option<String>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+}
+
+/// Pin a published list to a bid period, or unpin with `null`.
+#[derive(Debug, Deserialize)]
+pub struct PinSeniorityListRequest {
+    pub seniority_list_id: Option<Uuid>,
+}
+
+/// An unpaid absence (LOA, LWOP or layoff) from `starts_on` until the
+/// employee returned on `ends_on`. Completed, non-exception absences push the
+/// employee's seniority dates back by their length.
+#[derive(Debug, Serialize)]
+pub struct EmploymentStatusInterval {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub status: EmployeeStatus,
+    pub starts_on: time::Date,
+    /// NULL while the absence is ongoing.
+    pub ends_on: Option<time::Date>,
+    /// OJI/L&I, pregnancy/maternity or military: seniority keeps accruing.
+    pub is_exception: bool,
+    pub notes: Option<String>,
+    pub recorded_by: Option<Uuid>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+}
+
+/// Record a past absence. Ongoing absences come from status changes.
+#[derive(Debug, Deserialize, Validate)]
+pub struct CreateStatusIntervalRequest {
+    pub status: EmployeeStatus,
+    pub starts_on: time::Date,
+    pub ends_on: time::Date,
+    pub is_exception: Option<bool>,
+    #[validate(length(max = 1000))]
+    pub notes: Option<String>,
+}
+
+/// Correct a recorded absence. `ends_on` can only be changed on a completed
+/// one; an ongoing absence ends when the employee returns to active.
+#[derive(Debug, Deserialize, Validate)]
+pub struct UpdateStatusIntervalRequest {
+    pub status: Option<EmployeeStatus>,
+    pub starts_on: Option<time::Date>,
+    pub ends_on: Option<time::Date>,
+    pub is_exception: Option<bool>,
+    #[validate(length(max = 1000))]
+    pub notes: Option<String>,
+}
+
+/// How each of an employee's seniority dates is derived.
+#[derive(Debug, Serialize)]
+pub struct SeniorityExplanation {
+    pub user_id: Uuid,
+    pub hire_date: Option<time::Date>,
+    pub dates: Vec<SeniorityDerivation>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SeniorityDerivation {
+    pub seniority_type: SeniorityListType,
+    /// The date seniority counts from, before absences.
+    pub base_date: Option<time::Date>,
+    /// `recorded` (set on the profile or by a dispute), `hire_date` or `none`.
+    pub base_source: &'static str,
+    /// Every recorded absence and what it counted against this date.
+    pub absences: Vec<AbsenceAdjustment>,
+    pub paused_days: i64,
+    /// `base_date` plus `paused_days`.
+    pub seniority_date: Option<time::Date>,
+    /// The date currently on the employee's record. Differs from
+    /// `seniority_date` only for records no recompute has touched yet.
+    pub recorded_date: Option<time::Date>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AbsenceAdjustment {
+    pub interval_id: Uuid,
+    pub status: EmployeeStatus,
+    pub starts_on: time::Date,
+    pub ends_on: Option<time::Date>,
+    pub is_exception: bool,
+    pub days_counted: i64,
+    /// Why the absence counted for `days_counted` days.
+    pub reason: String,
+}
+
+/// Recompute one employee's seniority dates, or everyone's in the org.
+#[derive(Debug, Deserialize)]
+pub struct RecomputeSeniorityRequest {
+    pub user_id: Option<Uuid>,
+}
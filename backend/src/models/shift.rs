@@ -65,6 +65,8 @@ pub struct SchedulePeriod {
     #[serde(with = "time::serde::rfc3339::option")]
     pub bid_closes_at: Option<OffsetDateTime>,
     pub bargaining_unit: Option<String>,
+    /// Published seniority list the bid windows are ordered by, if pinned.
+    pub seniority_list_id: Option<Uuid>,
     #[serde(with = "time::serde::rfc3339")]
     pub created_at: OffsetDateTime,
     #[serde(with = "time::serde::rfc3339")]
@@ -109,6 +111,7 @@ pub struct CreateSlotAssignmentRequest {
 }
 
 /// A slot with its assignment info for a period (user_* fields None when unassigned).
+/// A job-shared slot appears once per partner, with the days each works.
 #[derive(Debug, Clone, Serialize)]
 pub struct SlotAssignmentView {
     pub slot_id: Uuid,
@@ -129,6 +132,7 @@ pub struct SlotAssignmentView {
     pub user_id: Option<Uuid>,
     pub user_first_name: Option<String>,
     pub user_last_name: Option<String>,
+    pub assigned_days_of_week: Option<Vec<i32>>,
 }
 
 #[derive(Debug, Deserialize, Validate)]
@@ -26,6 +26,12 @@ pub struct ShiftPattern {
 }
 
 impl ShiftPattern {
+    /// The 1-indexed day of the cycle that `date` falls on, counted from the anchor date.
+    pub fn cycle_day(&self, date: time::Date) -> i32 {
+        let days_diff = (date - self.anchor_date).whole_days();
+        (days_diff.rem_euclid(self.pattern_days as i64) + 1) as i32
+    }
+
     /// Determine if a given cycle_day (1-indexed) is a work day.
     pub fn is_work_day(&self, cycle_day: i32) -> bool {
         if let Some(ref mask) = self.work_days_in_cycle {
@@ -0,0 +1,57 @@
+// models/standing_availability.rs
+use serde::{Deserialize, Serialize};
+use time::{Date, OffsetDateTime};
+use uuid::Uuid;
+use validator::Validate;
+
+/// An employee's standing OT availability, with joined display fields.
+#[derive(Debug, Clone, Serialize)]
+pub struct StandingAvailability {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub user_name: String,
+    pub start_date: Date,
+    pub end_date: Date,
+    /// 0=Sunday..6=Saturday; empty means every day in the range.
+    pub days_of_week: Vec<i16>,
+    /// `None` (with `end_time`) means available all day.
+    #[serde(with = "crate::models::common::time_format_option")]
+    pub start_time: Option<time::Time>,
+    #[serde(with = "crate::models::common::time_format_option")]
+    pub end_time: Option<time::Time>,
+    pub notes: Option<String>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "time::serde::rfc3339::option"
+    )]
+    pub cancelled_at: Option<OffsetDateTime>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StandingAvailabilityQuery {
+    /// Supervisors only; employees always see their own sign-ups.
+    pub user_id: Option<Uuid>,
+    /// Only sign-ups whose date range includes this date.
+    pub date: Option<Date>,
+    pub include_cancelled: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct CreateStandingAvailability {
+    /// Supervisors may sign up on an employee's behalf; defaults to the caller.
+    pub user_id: Option<Uuid>,
+    pub start_date: Date,
+    pub end_date: Date,
+    #[serde(default)]
+    #[validate(length(max = 7))]
+    pub days_of_week: Vec<i16>,
+    #[serde(default, with = "crate::models::common::time_format_option")]
+    pub start_time: Option<time::Time>,
+    #[serde(default, with = "crate::models::common::time_format_option")]
+    pub end_time: Option<time::Time>,
+    #[validate(length(max = 2000))]
+    pub notes: Option<String>,
+}
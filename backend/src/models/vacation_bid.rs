@@ -18,6 +18,8 @@ pub struct VacationBidPeriod {
     pub allowance_hours: Option<i32>,
     pub min_block_hours: Option<i32>,
     pub bargaining_unit: Option<String>,
+    /// Published seniority list the bid windows are ordered by, if pinned.
+    pub seniority_list_id: Option<Uuid>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -68,7 +70,7 @@ pub struct SubmitVacationBidRequest {
     pub picks: Vec<VacationPick>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VacationPick {
     pub start_date: time::Date,
     pub end_date: time::Date,
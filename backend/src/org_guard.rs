@@ -166,6 +166,21 @@ pub async fn verify_coverage_plan(pool: &PgPool, plan_id: Uuid, org_id: Uuid) ->
     check_exists(ok, "Coverage plan")
 }
 
+pub async fn verify_holiday_coverage_profile(
+    pool: &PgPool,
+    profile_id: Uuid,
+    org_id: Uuid,
+) -> Result<()> {
+    let ok = sqlx::query_scalar!(
+        "SELECT EXISTS(SELECT 1 FROM holiday_coverage_profiles WHERE id = $1 AND org_id = $2)",
+        profile_id,
+        org_id
+    )
+    .fetch_one(pool)
+    .await?;
+    check_exists(ok, "Holiday coverage profile")
+}
+
 pub async fn verify_period(pool: &PgPool, period_id: Uuid, org_id: Uuid) -> Result<()> {
     let ok = sqlx::query_scalar!(
         "SELECT EXISTS(SELECT 1 FROM schedule_periods WHERE id = $1 AND org_id = $2)",
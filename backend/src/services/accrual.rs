@@ -11,7 +11,7 @@ use uuid::Uuid;
 use crate::error::Result;
 
 /// Get the first admin user ID for an org (used as the system actor for automated operations).
-async fn first_admin_id(pool: &PgPool, org_id: Uuid) -> Result<Option<Uuid>> {
+pub(crate) async fn first_admin_id(pool: &PgPool, org_id: Uuid) -> Result<Option<Uuid>> {
     Ok(sqlx::query_scalar!(
         "SELECT id FROM users WHERE org_id = $1 AND role = 'admin' AND is_active = true ORDER BY created_at LIMIT 1",
         org_id,
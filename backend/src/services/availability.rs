@@ -7,17 +7,27 @@ use uuid::Uuid;
 
 use crate::error::Result;
 use crate::models::callout::CalloutListEntry;
+use crate::models::ot::CalloutStep;
+use crate::services::callout_policy::{self, SortValues};
 
 /// Compute the ordered list of available (and unavailable) employees for a potential
 /// OT assignment on a given shift.
 ///
-/// CBA (Article 15 — Overtime Distribution):
 /// Employees are ordered by:
-/// 1. Primary classification employees first (same-class before cross-class)
-/// 2. Available employees before unavailable
-/// 3. OT queue position (last_ot_event_at ASC NULLS FIRST = never-called = top priority)
-/// 4. Accumulated OT hours ASC (equalize OT distribution across the bargaining unit)
-/// 5. Overall seniority date DESC NULLS FIRST (tie-breaker: inverse seniority — least senior first)
+/// 1. When `qualification_id` is given (filling a qualification gap), holders of
+///    that qualification first
+/// 2. Primary classification employees first (same-class before cross-class)
+/// 3. Available employees before unavailable
+/// 4. The sort keys of the callout policy step in effect (see `services::callout_policy`);
+///    the built-in policy follows CBA Article 15: standing availability, OT queue
+///    position, accumulated OT hours, then inverse seniority
+/// 5. Name, for a stable order
+///
+/// `callout_event_id` / `current_step` identify the open callout, if any: the step
+/// picks which policy step applies (the policy's first step otherwise) and the event
+/// supplies the volunteer list. The step's eligibility filters are applied before
+/// positions are assigned.
+#[allow(clippy::too_many_arguments)]
 pub async fn compute_available_employees(
     pool: &PgPool,
     org_id: Uuid,
@@ -27,6 +37,9 @@ pub async fn compute_available_employees(
     shift_date: time::Date,
     shift_start: time::Time,
     shift_duration_minutes: i32,
+    callout_event_id: Option<Uuid>,
+    current_step: Option<&CalloutStep>,
+    qualification_id: Option<Uuid>,
 ) -> Result<Vec<CalloutListEntry>> {
     let fiscal_year = crate::services::ot::org_fiscal_year(pool, org_id, shift_date).await;
 
@@ -46,6 +59,12 @@ pub async fn compute_available_employees(
     let days_until_shift = (shift_date - today).whole_days();
     let cross_class_eligible = days_until_shift >= 0 && days_until_shift <= window_days;
 
+    let shift_end = shift_start + time::Duration::minutes(shift_duration_minutes as i64);
+    let standing = crate::services::standing_availability::available_user_ids(
+        pool, org_id, None, shift_date, shift_start, shift_end,
+    )
+    .await?;
+
     // CTE computes blocking conditions once, then the outer SELECT derives
     // is_available and unavailable_reason from those flags. This eliminates
     // the previous triple-duplication of the leave-overlap subquery.
@@ -62,8 +81,25 @@ pub async fn compute_available_employees(
                 u.medical_ot_exempt,
                 cl.abbreviation AS classification_abbreviation,
                 sr.overall_seniority_date,
+                sr.bargaining_unit_seniority_date,
+                sr.classification_seniority_date,
                 COALESCE(ot.hours_worked, 0.0)::FLOAT8 AS ot_hours,
+                COALESCE(ot.hours_declined, 0.0)::FLOAT8 AS ot_hours_declined,
                 oq.last_ot_event_at,
+                (
+                    SELECT MAX(me.occurred_at) FROM mandatory_ot_events me
+                    WHERE me.org_id = $2 AND me.classification_id = $4
+                      AND me.user_id = u.id AND me.counts_as_turn AND me.voided_at IS NULL
+                ) AS last_mandatory_at,
+                EXISTS (
+                    SELECT 1 FROM ot_volunteers v
+                    WHERE v.callout_event_id = $10 AND v.user_id = u.id
+                ) AS volunteered,
+                EXISTS (
+                    SELECT 1 FROM user_qualifications uq
+                    WHERE uq.user_id = u.id AND uq.qualification_id = $11
+                      AND (uq.expires_on IS NULL OR uq.expires_on >= $8::DATE)
+                ) AS has_qualification,
                 -- Blocking: already assigned to this shift
                 EXISTS (
                     SELECT 1 FROM assignments a
@@ -150,26 +186,25 @@ pub async fn compute_available_employees(
             (e.classification_id IS DISTINCT FROM $4) AS "is_cross_class!: bool",
             e.classification_abbreviation AS "classification_abbreviation?",
             e.overall_seniority_date AS "overall_seniority_date?",
-            e.ot_hours,
+            e.bargaining_unit_seniority_date AS "bargaining_unit_seniority_date?",
+            e.classification_seniority_date AS "classification_seniority_date?",
+            e.ot_hours AS "ot_hours!",
+            e.ot_hours_declined AS "ot_hours_declined!",
+            e.last_ot_event_at AS "last_ot_event_at?",
+            e.last_mandatory_at AS "last_mandatory_at?",
+            e.volunteered AS "volunteered!",
             (NOT e.medical_ot_exempt AND NOT e.is_already_scheduled
-                AND NOT e.is_assigned_ot AND NOT e.is_on_leave) AS is_available,
+                AND NOT e.is_assigned_ot AND NOT e.is_on_leave) AS "is_available!",
             CASE
                 WHEN e.medical_ot_exempt THEN 'Medical OT exempt'
                 WHEN e.is_already_scheduled THEN 'Already scheduled'
                 WHEN e.is_assigned_ot THEN 'Assigned to OT'
                 WHEN e.is_on_leave THEN 'On approved leave'
                 ELSE NULL
-            END AS unavailable_reason
+            END AS unavailable_reason,
+            (e.id = ANY($9)) AS "has_standing_availability!: bool",
+            e.has_qualification AS "has_qualification!"
         FROM eligible e
-        ORDER BY
-            (e.classification_id = $4) DESC,
-            (NOT e.medical_ot_exempt AND NOT e.is_already_scheduled
-                AND NOT e.is_assigned_ot AND NOT e.is_on_leave) DESC,
-            e.last_ot_event_at ASC NULLS FIRST,
-            e.ot_hours ASC,
-            -- Tie-breaker: inverse seniority (least senior = most recent hire date first;
-            -- NULL seniority = no date = least senior = highest OT priority)
-            e.overall_seniority_date DESC NULLS FIRST
         "#,
         scheduled_shift_id,   // $1
         org_id,               // $2
@@ -179,14 +214,55 @@ pub async fn compute_available_employees(
         shift_end_mins,       // $6
         shift_start_mins,     // $7
         shift_date,           // $8
+        &standing,            // $9
+        callout_event_id,     // $10
+        qualification_id,     // $11
     )
     .fetch_all(pool)
     .await?;
 
+    let policy = callout_policy::effective_policy(pool, org_id, classification_id).await?;
+    let step = policy.step_for(current_step);
+    let filters = &step.filters;
+
+    let mut rows: Vec<_> = rows
+        .into_iter()
+        .filter(|r| !filters.primary_classification_only || !r.is_cross_class)
+        .filter(|r| !filters.hide_unavailable || r.is_available)
+        .filter(|r| !filters.volunteers_only || r.volunteered || r.has_standing_availability)
+        .filter(|r| filters.max_ot_hours.is_none_or(|max| r.ot_hours <= max))
+        .map(|r| {
+            let values = SortValues {
+                ot_hours: r.ot_hours,
+                ot_hours_declined: r.ot_hours_declined,
+                last_contact: r.last_ot_event_at,
+                overall_seniority: r.overall_seniority_date,
+                bargaining_unit_seniority: r.bargaining_unit_seniority_date,
+                classification_seniority: r.classification_seniority_date,
+                mandatory_rotation: r.last_mandatory_at,
+                standing_availability: r.has_standing_availability,
+                volunteered: r.volunteered,
+            };
+            (r, values)
+        })
+        .collect();
+
+    let prefer_qualified = qualification_id.is_some();
+    rows.sort_by(|(a, av), (b, bv)| {
+        (prefer_qualified && !a.has_qualification)
+            .cmp(&(prefer_qualified && !b.has_qualification))
+            .then(a.is_cross_class.cmp(&b.is_cross_class))
+            .then(b.is_available.cmp(&a.is_available))
+            .then_with(|| callout_policy::compare(av, bv, &step.sort))
+            .then_with(|| a.last_name.cmp(&b.last_name))
+            .then_with(|| a.first_name.cmp(&b.first_name))
+            .then(a.id.cmp(&b.id))
+    });
+
     let entries = rows
         .into_iter()
         .enumerate()
-        .map(|(i, r)| CalloutListEntry {
+        .map(|(i, (r, _))| CalloutListEntry {
             position: i as i32 + 1,
             user_id: r.id,
             employee_id: r.employee_id,
@@ -194,11 +270,13 @@ pub async fn compute_available_employees(
             last_name: r.last_name,
             classification_abbreviation: r.classification_abbreviation,
             overall_seniority_date: r.overall_seniority_date,
-            ot_hours: r.ot_hours.unwrap_or(0.0),
+            ot_hours: r.ot_hours,
             phone: r.phone,
-            is_available: r.is_available.unwrap_or(false),
+            is_available: r.is_available,
             unavailable_reason: r.unavailable_reason,
             is_cross_class: r.is_cross_class,
+            has_standing_availability: r.has_standing_availability,
+            has_qualification: qualification_id.map(|_| r.has_qualification),
         })
         .collect();
 
@@ -0,0 +1,468 @@
+//! Bid award tables, shared by processing and preview, and the run audit trail.
+//!
+//! Awards are worked out in seniority order without writing anything; the
+//! `process_bids` handlers then apply the table inside the same transaction,
+//! and the preview handlers return it and roll back.
+
+use std::collections::{HashMap, HashSet};
+
+use serde_json::Value;
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+
+use crate::{
+    error::{AppError, Result},
+    models::bid_processing::{
+        BidProcessingRun, ShiftAward, ShiftAwardRow, ShiftAwardTable, SkipReason, SkippedPick,
+        SkippedSlot, VacationAward, VacationAwardRow, VacationAwardTable,
+    },
+    services::{bid_proxy::BidPeriod, holiday_rotation},
+};
+
+/// Flex slots a classification may be awarded per bid cycle (or per round,
+/// unless the round sets its own cap).
+pub(crate) const FLEX_CAP: i32 = 2;
+
+/// Work out a shift bid period's awards. Each bidder gets at most one slot: their
+/// highest-ranked choice that is neither taken nor over the flex cap. A job-share
+/// pair's joint window is awarded one slot, split between the partners' days.
+/// Windows in a bid round use the round's flex cap, counted within the round,
+/// and stop being awarded once the round has awarded its `max_awards`.
+pub(crate) async fn plan_shift_awards(conn: &mut PgConnection, period_id: Uuid) -> Result<ShiftAwardTable> {
+    let windows = sqlx::query!(
+        r#"
+        SELECT bw.id, bw.user_id, u.first_name, u.last_name, bw.seniority_rank, bw.partner_user_id,
+               (sp.bargaining_unit IS NULL
+                OR u.bargaining_unit::TEXT = sp.bargaining_unit::TEXT) AS "in_unit!",
+               bw.round_id, r.flex_cap AS "flex_cap?", r.max_awards
+        FROM bid_windows bw
+        JOIN users u ON u.id = bw.user_id
+        JOIN schedule_periods sp ON sp.id = bw.period_id
+        LEFT JOIN shift_bid_rounds r ON r.id = bw.round_id
+        WHERE bw.period_id = $1
+        ORDER BY bw.seniority_rank ASC
+        "#,
+        period_id,
+    )
+    .fetch_all(&mut *conn)
+    .await?;
+
+    let mut submissions: HashMap<Uuid, Vec<_>> = HashMap::new();
+    for sub in sqlx::query!(
+        r#"
+        SELECT bs.bid_window_id, bs.slot_id, bs.preference_rank, bs.partner_days_of_week,
+               ss.is_flex AS "is_flex!", ss.classification_id, ss.days_of_week,
+               CONCAT(t.name, ' - ', st.name) AS "slot_name!"
+        FROM bid_submissions bs
+        JOIN bid_windows bw ON bw.id = bs.bid_window_id
+        JOIN shift_slots ss ON ss.id = bs.slot_id
+        JOIN teams t ON t.id = ss.team_id
+        JOIN shift_templates st ON st.id = ss.shift_template_id
+        WHERE bw.period_id = $1
+        ORDER BY bs.bid_window_id, bs.preference_rank ASC
+        "#,
+        period_id,
+    )
+    .fetch_all(&mut *conn)
+    .await?
+    {
+        submissions.entry(sub.bid_window_id).or_default().push(sub);
+    }
+
+    let mut assigned_slots: HashSet<Uuid> = sqlx::query_scalar!(
+        "SELECT slot_id FROM slot_assignments WHERE period_id = $1",
+        period_id,
+    )
+    .fetch_all(&mut *conn)
+    .await?
+    .into_iter()
+    .collect();
+    // Keyed by (round, classification) and by round; `None` is a period without rounds
+    let mut flex_awards: HashMap<(Option<Uuid>, Uuid), i32> = HashMap::new();
+    let mut round_awards: HashMap<Option<Uuid>, i32> = HashMap::new();
+
+    let mut rows = Vec::with_capacity(windows.len());
+    for win in windows {
+        let mut row = ShiftAwardRow {
+            window_id: win.id,
+            user_id: win.user_id,
+            first_name: win.first_name,
+            last_name: win.last_name,
+            seniority_rank: win.seniority_rank,
+            partner_user_id: win.partner_user_id,
+            award: None,
+            skipped: Vec::new(),
+            reason: None,
+        };
+        let subs = submissions.remove(&win.id).unwrap_or_default();
+        if !win.in_unit {
+            row.reason = Some(SkipReason::BargainingUnit);
+        } else if subs.is_empty() {
+            row.reason = Some(SkipReason::NoSubmission);
+        } else if win
+            .max_awards
+            .is_some_and(|max| round_awards.get(&win.round_id).copied().unwrap_or(0) >= max)
+        {
+            row.reason = Some(SkipReason::RoundLimit);
+        } else {
+            let flex_cap = win.flex_cap.unwrap_or(FLEX_CAP);
+            for sub in subs {
+                let flex_key = (win.round_id, sub.classification_id);
+                let flex_count = flex_awards.get(&flex_key).copied().unwrap_or(0);
+                let reason = if sub.is_flex && flex_count >= flex_cap {
+                    Some(SkipReason::FlexCap)
+                } else if assigned_slots.contains(&sub.slot_id) {
+                    Some(SkipReason::Taken)
+                } else {
+                    None
+                };
+                if let Some(reason) = reason {
+                    row.skipped.push(SkippedSlot {
+                        slot_id: sub.slot_id,
+                        slot_name: sub.slot_name,
+                        preference_rank: sub.preference_rank,
+                        reason,
+                    });
+                    continue;
+                }
+
+                assigned_slots.insert(sub.slot_id);
+                if sub.is_flex {
+                    *flex_awards.entry(flex_key).or_insert(0) += 1;
+                }
+                *round_awards.entry(win.round_id).or_insert(0) += 1;
+                let partner_days = sub.partner_days_of_week.filter(|_| win.partner_user_id.is_some());
+                row.award = Some(ShiftAward {
+                    slot_id: sub.slot_id,
+                    slot_name: sub.slot_name,
+                    preference_rank: sub.preference_rank,
+                    days_of_week: partner_days.as_ref().map(|partner| {
+                        sub.days_of_week.iter().copied().filter(|d| !partner.contains(d)).collect()
+                    }),
+                    partner_days_of_week: partner_days,
+                });
+                break;
+            }
+            if row.award.is_none() {
+                row.reason = row.skipped.first().map(|s| s.reason);
+            }
+        }
+        rows.push(row);
+    }
+
+    Ok(ShiftAwardTable {
+        period_id,
+        total_bidders: rows.len(),
+        awards_count: rows.iter().filter(|r| r.award.is_some()).count(),
+        rows,
+    })
+}
+
+/// Work out a vacation bid period's awards. Every pick is tried in order: it's
+/// awarded unless it covers a holiday the bidder's holiday rotation bars this
+/// round, a date is already at `max_concurrent_vacation` (counting awards from
+/// earlier rounds of the year) or, when the org has a vacation leave type, the
+/// bidder's remaining balance can't cover the `hours` it charges them (given the
+/// bidder's id and the pick's dates). A job-share partner's pick is also skipped
+/// if the other partner is already off on any of its dates, so the shared
+/// position stays covered.
+///
+/// Balances are read `FOR UPDATE` so a processing run can't overdraw them.
+pub(crate) async fn plan_vacation_awards(
+    conn: &mut PgConnection,
+    org_id: Uuid,
+    period_id: Uuid,
+    leave_type_id: Option<Uuid>,
+    hours: impl Fn(Uuid, time::Date, time::Date) -> f64,
+) -> Result<VacationAwardTable> {
+    let windows = sqlx::query!(
+        r#"
+        SELECT w.id, w.user_id, u.first_name, u.last_name, w.seniority_rank,
+               (vp.bargaining_unit IS NULL
+                OR u.bargaining_unit::TEXT = vp.bargaining_unit::TEXT) AS "in_unit!",
+               jp.partner_id AS "partner_id?"
+        FROM vacation_bid_windows w
+        JOIN users u ON u.id = w.user_id
+        JOIN vacation_bid_periods vp ON vp.id = w.vacation_bid_period_id
+        LEFT JOIN LATERAL (
+            SELECT CASE WHEN p.user_a_id = w.user_id THEN p.user_b_id ELSE p.user_a_id END AS partner_id
+            FROM job_share_pairs p
+            WHERE p.user_a_id = w.user_id OR p.user_b_id = w.user_id
+        ) jp ON true
+        WHERE w.vacation_bid_period_id = $1
+        ORDER BY w.seniority_rank
+        "#,
+        period_id,
+    )
+    .fetch_all(&mut *conn)
+    .await?;
+
+    let period = sqlx::query!("SELECT year, round FROM vacation_bid_periods WHERE id = $1", period_id)
+        .fetch_one(&mut *conn)
+        .await?;
+    let bidders: Vec<Uuid> = windows.iter().map(|w| w.user_id).collect();
+    let lockouts = holiday_rotation::lockouts(conn, org_id, &bidders, period.year, period.round).await?;
+
+    let mut bids: HashMap<Uuid, Vec<_>> = HashMap::new();
+    for bid in sqlx::query!(
+        r#"
+        SELECT vb.id, vb.vacation_bid_window_id, vb.start_date, vb.end_date, vb.preference_rank
+        FROM vacation_bids vb
+        JOIN vacation_bid_windows w ON w.id = vb.vacation_bid_window_id
+        WHERE w.vacation_bid_period_id = $1
+        ORDER BY vb.vacation_bid_window_id, vb.preference_rank
+        "#,
+        period_id,
+    )
+    .fetch_all(&mut *conn)
+    .await?
+    {
+        bids.entry(bid.vacation_bid_window_id).or_default().push(bid);
+    }
+
+    // CBA: dates already awarded in earlier rounds of the same year count toward
+    // the limit, so round 2 can't double-book round 1 (seniority protection).
+    let prior_dates: Vec<time::Date> = sqlx::query_scalar!(
+        r#"
+        SELECT DISTINCT d::DATE AS "date!"
+        FROM vacation_bids vb
+        JOIN vacation_bid_windows vw ON vw.id = vb.vacation_bid_window_id
+        JOIN vacation_bid_periods vp ON vp.id = vw.vacation_bid_period_id
+        JOIN vacation_bid_periods cur ON cur.id = $2
+        CROSS JOIN generate_series(vb.start_date, vb.end_date, '1 day'::interval) AS d
+        WHERE vp.org_id = $1
+          AND vp.year = cur.year
+          AND vp.id != cur.id
+          AND vb.awarded = true
+        "#,
+        org_id,
+        period_id,
+    )
+    .fetch_all(&mut *conn)
+    .await?;
+    let mut awarded_dates: HashMap<time::Date, u32> = HashMap::new();
+    for d in prior_dates {
+        *awarded_dates.entry(d).or_insert(0) += 1;
+    }
+
+    // Job-share partners' time off already awarded this year, by employee
+    let mut time_off: HashMap<Uuid, Vec<(time::Date, time::Date)>> = HashMap::new();
+    for r in sqlx::query!(
+        r#"
+        SELECT vw.user_id, vb.start_date, vb.end_date
+        FROM vacation_bids vb
+        JOIN vacation_bid_windows vw ON vw.id = vb.vacation_bid_window_id
+        JOIN vacation_bid_periods vp ON vp.id = vw.vacation_bid_period_id
+        JOIN vacation_bid_periods cur ON cur.id = $2
+        JOIN job_share_pairs p ON vw.user_id IN (p.user_a_id, p.user_b_id)
+        WHERE vp.org_id = $1
+          AND vp.year = cur.year
+          AND vp.id != cur.id
+          AND vb.awarded = true
+        "#,
+        org_id,
+        period_id,
+    )
+    .fetch_all(&mut *conn)
+    .await?
+    {
+        time_off.entry(r.user_id).or_default().push((r.start_date, r.end_date));
+    }
+
+    // Max concurrent vacations per date — configurable via org_settings, default 3
+    let max_concurrent: u32 = sqlx::query_scalar!(
+        "SELECT value FROM org_settings WHERE org_id = $1 AND key = 'max_concurrent_vacation'",
+        org_id,
+    )
+    .fetch_optional(&mut *conn)
+    .await?
+    .and_then(|v| v.as_u64().map(|n| n as u32))
+    .unwrap_or(3);
+
+    let mut rows = Vec::with_capacity(windows.len());
+    for win in windows {
+        let mut row = VacationAwardRow {
+            window_id: win.id,
+            user_id: win.user_id,
+            first_name: win.first_name,
+            last_name: win.last_name,
+            seniority_rank: win.seniority_rank,
+            awards: Vec::new(),
+            skipped: Vec::new(),
+            reason: None,
+        };
+        let picks = bids.remove(&win.id).unwrap_or_default();
+        if !win.in_unit {
+            row.reason = Some(SkipReason::BargainingUnit);
+            rows.push(row);
+            continue;
+        }
+        if picks.is_empty() {
+            row.reason = Some(SkipReason::NoSubmission);
+            rows.push(row);
+            continue;
+        }
+
+        let mut balance = match leave_type_id {
+            Some(lt_id) => Some(
+                sqlx::query_scalar!(
+                    r#"
+                    SELECT COALESCE(SUM(locked.bal), 0.0) AS "total!"
+                    FROM (
+                        SELECT CAST(lb.balance_hours AS FLOAT8) AS bal
+                        FROM leave_balances lb
+                        WHERE lb.user_id = $1 AND lb.org_id = $2 AND lb.leave_type_id = $3
+                        FOR UPDATE OF lb
+                    ) locked
+                    "#,
+                    win.user_id,
+                    org_id,
+                    lt_id,
+                )
+                .fetch_one(&mut *conn)
+                .await?,
+            ),
+            None => None,
+        };
+
+        for pick in picks {
+            let pick_hours = hours(win.user_id, pick.start_date, pick.end_date);
+            let partner_off = win.partner_id.and_then(|p| time_off.get(&p)).is_some_and(|ranges| {
+                ranges.iter().any(|&(start, end)| start <= pick.end_date && pick.start_date <= end)
+            });
+            let barred = lockouts
+                .get(&win.user_id)
+                .is_some_and(|l| holiday_rotation::barred(l, pick.start_date, pick.end_date).is_some());
+            let reason = if barred {
+                Some(SkipReason::HolidayRotation)
+            } else if dates_at_limit(&awarded_dates, pick.start_date, pick.end_date, max_concurrent)? {
+                Some(SkipReason::DateConflict)
+            } else if partner_off {
+                Some(SkipReason::PartnerOff)
+            } else if balance.is_some_and(|b| b < pick_hours) {
+                Some(SkipReason::InsufficientBalance)
+            } else {
+                None
+            };
+            if let Some(reason) = reason {
+                row.skipped.push(SkippedPick {
+                    bid_id: pick.id,
+                    start_date: pick.start_date,
+                    end_date: pick.end_date,
+                    preference_rank: pick.preference_rank,
+                    reason,
+                });
+                continue;
+            }
+
+            if let Some(b) = balance.as_mut() {
+                *b -= pick_hours;
+            }
+            if win.partner_id.is_some() {
+                time_off.entry(win.user_id).or_default().push((pick.start_date, pick.end_date));
+            }
+            let mut d = pick.start_date;
+            while d <= pick.end_date {
+                *awarded_dates.entry(d).or_insert(0) += 1;
+                d = d
+                    .next_day()
+                    .ok_or(AppError::BadRequest("Date range exceeds maximum date".into()))?;
+            }
+            row.awards.push(VacationAward {
+                bid_id: pick.id,
+                start_date: pick.start_date,
+                end_date: pick.end_date,
+                preference_rank: pick.preference_rank,
+                hours: pick_hours,
+            });
+        }
+        if row.awards.is_empty() {
+            row.reason = row.skipped.first().map(|s| s.reason);
+        }
+        rows.push(row);
+    }
+
+    Ok(VacationAwardTable {
+        period_id,
+        total_bidders: rows.len(),
+        awards_count: rows.iter().map(|r| r.awards.len()).sum(),
+        rows,
+    })
+}
+
+/// Whether any date in [start, end] is already at `max_concurrent` awarded vacations.
+fn dates_at_limit(
+    awarded_dates: &HashMap<time::Date, u32>,
+    start: time::Date,
+    end: time::Date,
+    max_concurrent: u32,
+) -> Result<bool> {
+    let mut d = start;
+    while d <= end {
+        if awarded_dates.get(&d).copied().unwrap_or(0) >= max_concurrent {
+            return Ok(true);
+        }
+        d = d
+            .next_day()
+            .ok_or(AppError::BadRequest("Date range exceeds maximum date".into()))?;
+    }
+    Ok(false)
+}
+
+/// Record a processing or rollback run.
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn record_run(
+    conn: &mut PgConnection,
+    org_id: Uuid,
+    period: BidPeriod,
+    action: &str,
+    run_by: Uuid,
+    awards_count: usize,
+    reason: Option<&str>,
+    detail: Value,
+) -> Result<Uuid> {
+    let (schedule_period_id, vacation_bid_period_id) = period.ids();
+    let id = sqlx::query_scalar!(
+        r#"
+        INSERT INTO bid_processing_runs
+            (org_id, schedule_period_id, vacation_bid_period_id, action, run_by, awards_count, reason, detail)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+        RETURNING id
+        "#,
+        org_id,
+        schedule_period_id,
+        vacation_bid_period_id,
+        action,
+        run_by,
+        awards_count as i32,
+        reason,
+        detail,
+    )
+    .fetch_one(conn)
+    .await?;
+    Ok(id)
+}
+
+/// Runs for a period, newest first.
+pub(crate) async fn list_runs(pool: &PgPool, org_id: Uuid, period: BidPeriod) -> Result<Vec<BidProcessingRun>> {
+    let (schedule_period_id, vacation_bid_period_id) = period.ids();
+    let runs = sqlx::query_as!(
+        BidProcessingRun,
+        r#"
+        SELECT r.id, r.action, r.run_by,
+               u.first_name AS run_by_first_name, u.last_name AS run_by_last_name,
+               r.awards_count, r.reason, r.detail, r.created_at
+        FROM bid_processing_runs r
+        JOIN users u ON u.id = r.run_by
+        WHERE r.org_id = $1
+          AND (r.schedule_period_id = $2 OR r.vacation_bid_period_id = $3)
+        ORDER BY r.created_at DESC
+        "#,
+        org_id,
+        schedule_period_id,
+        vacation_bid_period_id,
+    )
+    .fetch_all(pool)
+    .await?;
+    Ok(runs)
+}
@@ -0,0 +1,507 @@
+//! Bidding on an employee's behalf from a pre-filed plan.
+//!
+//! A plan ranks shift slots or vacation date ranges ahead of time. When the
+//! owner's window opens (`submit_on = 'open'`) or closes unsubmitted, the
+//! system submits every choice still available, in the plan's order, re-ranked
+//! from 1. A shift slot is unavailable once awarded for the period or
+//! deactivated; a date range once any of its dates is at the org's concurrent
+//! vacation limit, or when it covers a holiday the owner's holiday rotation
+//! bars in the round.
+//!
+//! Every such bid, and every bid a designated proxy coworker submits, is
+//! recorded in `bid_proxy_actions`.
+
+use serde_json::{json, Value};
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+
+use crate::{
+    api::notifications::{create_notification, CreateNotificationParams},
+    error::Result,
+    services::holiday_rotation,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Trigger {
+    WindowOpen,
+    WindowExpired,
+    /// Submitted by the designated proxy coworker.
+    Proxy,
+}
+
+impl Trigger {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::WindowOpen => "window_open",
+            Self::WindowExpired => "window_expired",
+            Self::Proxy => "proxy",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum BidPeriod {
+    Shift(Uuid),
+    Vacation(Uuid),
+}
+
+impl BidPeriod {
+    /// (schedule_period_id, vacation_bid_period_id)
+    pub(crate) fn ids(self) -> (Option<Uuid>, Option<Uuid>) {
+        match self {
+            Self::Shift(id) => (Some(id), None),
+            Self::Vacation(id) => (None, Some(id)),
+        }
+    }
+}
+
+/// One entry in the proxy audit trail.
+pub(crate) struct ProxyAction {
+    pub org_id: Uuid,
+    pub user_id: Uuid,
+    pub actor_id: Option<Uuid>,
+    pub period: BidPeriod,
+    pub window_id: Uuid,
+    pub trigger: Trigger,
+    pub submitted: Value,
+    pub unavailable: Value,
+}
+
+impl ProxyAction {
+    pub(crate) async fn record(&self, conn: &mut PgConnection) -> Result<()> {
+        let (schedule_period_id, vacation_bid_period_id) = self.period.ids();
+        sqlx::query!(
+            r#"
+            INSERT INTO bid_proxy_actions
+                (org_id, user_id, actor_id, schedule_period_id, vacation_bid_period_id,
+                 window_id, trigger, submitted, unavailable)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            "#,
+            self.org_id,
+            self.user_id,
+            self.actor_id,
+            schedule_period_id,
+            vacation_bid_period_id,
+            self.window_id,
+            self.trigger.as_str(),
+            self.submitted,
+            self.unavailable,
+        )
+        .execute(conn)
+        .await?;
+        Ok(())
+    }
+}
+
+/// A window the system bid on from its owner's plan. The owner is told once
+/// the surrounding transaction commits.
+pub(crate) struct ProxySubmission {
+    pub org_id: Uuid,
+    pub user_id: Uuid,
+    pub window_id: Uuid,
+    pub period: BidPeriod,
+    pub trigger: Trigger,
+    pub count: usize,
+}
+
+/// Whether `actor_id` is the designated proxy for `user_id` in `period`.
+pub(crate) async fn is_proxy_for(pool: &PgPool, period: BidPeriod, user_id: Uuid, actor_id: Uuid) -> Result<bool> {
+    let (schedule_period_id, vacation_bid_period_id) = period.ids();
+    let found = sqlx::query_scalar!(
+        r#"
+        SELECT EXISTS(
+            SELECT 1 FROM bid_proxy_plans
+            WHERE user_id = $1 AND proxy_user_id = $2
+              AND (schedule_period_id = $3 OR vacation_bid_period_id = $4)
+        ) AS "exists!"
+        "#,
+        user_id,
+        actor_id,
+        schedule_period_id,
+        vacation_bid_period_id,
+    )
+    .fetch_one(pool)
+    .await?;
+    Ok(found)
+}
+
+/// Bid on a shift window from its owner's plan. Returns `None` (after recording
+/// the attempt) when nothing on the plan is still available, or when the owner
+/// filed no ranked slots. Joint job-share windows are never bid from a plan,
+/// since a plan doesn't split slots' days between the partners.
+pub(crate) async fn submit_shift_from_plan(
+    conn: &mut PgConnection,
+    window_id: Uuid,
+    period_id: Uuid,
+    user_id: Uuid,
+    trigger: Trigger,
+) -> Result<Option<ProxySubmission>> {
+    let Some(plan) = sqlx::query!(
+        r#"
+        SELECT p.id, p.org_id
+        FROM bid_proxy_plans p
+        JOIN bid_windows bw ON bw.period_id = p.schedule_period_id AND bw.user_id = p.user_id
+        WHERE p.user_id = $1 AND p.schedule_period_id = $2 AND bw.partner_user_id IS NULL
+        "#,
+        user_id,
+        period_id,
+    )
+    .fetch_optional(&mut *conn)
+    .await?
+    else {
+        return Ok(None);
+    };
+
+    let choices = sqlx::query!(
+        r#"
+        SELECT c.slot_id, c.preference_rank,
+               (ss.is_active AND t.is_active AND NOT EXISTS (
+                   SELECT 1 FROM slot_assignments sa
+                   WHERE sa.slot_id = c.slot_id AND sa.period_id = $2
+               ) AND NOT EXISTS (
+                   SELECT 1 FROM bid_windows bw
+                   JOIN shift_bid_rounds r ON r.id = bw.round_id
+                   WHERE bw.id = $3 AND r.slot_ids IS NOT NULL AND NOT c.slot_id = ANY(r.slot_ids)
+               )) AS "available!"
+        FROM bid_proxy_slot_choices c
+        JOIN shift_slots ss ON ss.id = c.slot_id
+        JOIN teams t ON t.id = ss.team_id
+        WHERE c.plan_id = $1
+        ORDER BY c.preference_rank
+        "#,
+        plan.id,
+        period_id,
+        window_id,
+    )
+    .fetch_all(&mut *conn)
+    .await?;
+    if choices.is_empty() {
+        return Ok(None);
+    }
+
+    let (available, unavailable): (Vec<_>, Vec<_>) = choices.into_iter().partition(|c| c.available);
+    ProxyAction {
+        org_id: plan.org_id,
+        user_id,
+        actor_id: None,
+        period: BidPeriod::Shift(period_id),
+        window_id,
+        trigger,
+        submitted: available
+            .iter()
+            .enumerate()
+            .map(|(i, c)| json!({ "slot_id": c.slot_id, "preference_rank": i + 1 }))
+            .collect(),
+        unavailable: unavailable
+            .iter()
+            .map(|c| json!({ "slot_id": c.slot_id, "preference_rank": c.preference_rank }))
+            .collect(),
+    }
+    .record(conn)
+    .await?;
+    if available.is_empty() {
+        return Ok(None);
+    }
+
+    sqlx::query!("DELETE FROM bid_submissions WHERE bid_window_id = $1", window_id)
+        .execute(&mut *conn)
+        .await?;
+    for (i, choice) in available.iter().enumerate() {
+        sqlx::query!(
+            "INSERT INTO bid_submissions (bid_window_id, slot_id, preference_rank) VALUES ($1, $2, $3)",
+            window_id,
+            choice.slot_id,
+            i as i32 + 1,
+        )
+        .execute(&mut *conn)
+        .await?;
+    }
+    sqlx::query!("UPDATE bid_windows SET submitted_at = NOW() WHERE id = $1", window_id)
+        .execute(&mut *conn)
+        .await?;
+
+    Ok(Some(ProxySubmission {
+        org_id: plan.org_id,
+        user_id,
+        window_id,
+        period: BidPeriod::Shift(period_id),
+        trigger,
+        count: available.len(),
+    }))
+}
+
+/// Bid on a vacation window from its owner's plan; see [`submit_shift_from_plan`].
+pub(crate) async fn submit_vacation_from_plan(
+    conn: &mut PgConnection,
+    window_id: Uuid,
+    period_id: Uuid,
+    user_id: Uuid,
+    trigger: Trigger,
+) -> Result<Option<ProxySubmission>> {
+    let Some(plan) = sqlx::query!(
+        r#"
+        SELECT p.id, p.org_id, vp.year, vp.round
+        FROM bid_proxy_plans p
+        JOIN vacation_bid_periods vp ON vp.id = p.vacation_bid_period_id
+        WHERE p.user_id = $1 AND p.vacation_bid_period_id = $2
+        "#,
+        user_id,
+        period_id,
+    )
+    .fetch_optional(&mut *conn)
+    .await?
+    else {
+        return Ok(None);
+    };
+
+    // Same limit process_bids awards against
+    let max_concurrent: i64 = sqlx::query_scalar!(
+        "SELECT value FROM org_settings WHERE org_id = $1 AND key = 'max_concurrent_vacation'",
+        plan.org_id,
+    )
+    .fetch_optional(&mut *conn)
+    .await?
+    .and_then(|v| v.as_i64())
+    .unwrap_or(3);
+
+    let mut choices = sqlx::query!(
+        r#"
+        SELECT c.start_date, c.end_date, c.preference_rank,
+               NOT EXISTS (
+                   SELECT 1
+                   FROM generate_series(c.start_date, c.end_date, '1 day'::interval) AS d
+                   WHERE (
+                       SELECT COUNT(*)
+                       FROM vacation_bids vb
+                       JOIN vacation_bid_windows vw ON vw.id = vb.vacation_bid_window_id
+                       JOIN vacation_bid_periods vp ON vp.id = vw.vacation_bid_period_id
+                       WHERE vp.org_id = $2 AND vp.year = $3 AND vb.awarded = true
+                         AND d::DATE BETWEEN vb.start_date AND vb.end_date
+                   ) >= $4
+               ) AS "available!"
+        FROM bid_proxy_date_choices c
+        WHERE c.plan_id = $1
+        ORDER BY c.preference_rank
+        "#,
+        plan.id,
+        plan.org_id,
+        plan.year,
+        max_concurrent,
+    )
+    .fetch_all(&mut *conn)
+    .await?;
+    if choices.is_empty() {
+        return Ok(None);
+    }
+    let lockouts = holiday_rotation::lockouts(conn, plan.org_id, &[user_id], plan.year, plan.round)
+        .await?
+        .remove(&user_id)
+        .unwrap_or_default();
+    for c in &mut choices {
+        c.available &= holiday_rotation::barred(&lockouts, c.start_date, c.end_date).is_none();
+    }
+
+    let (available, unavailable): (Vec<_>, Vec<_>) = choices.into_iter().partition(|c| c.available);
+    let range = |start: time::Date, end: time::Date, rank: usize| {
+        json!({ "start_date": start.to_string(), "end_date": end.to_string(), "preference_rank": rank })
+    };
+    ProxyAction {
+        org_id: plan.org_id,
+        user_id,
+        actor_id: None,
+        period: BidPeriod::Vacation(period_id),
+        window_id,
+        trigger,
+        submitted: available
+            .iter()
+            .enumerate()
+            .map(|(i, c)| range(c.start_date, c.end_date, i + 1))
+            .collect(),
+        unavailable: unavailable
+            .iter()
+            .map(|c| range(c.start_date, c.end_date, c.preference_rank as usize))
+            .collect(),
+    }
+    .record(conn)
+    .await?;
+    if available.is_empty() {
+        return Ok(None);
+    }
+
+    sqlx::query!("DELETE FROM vacation_bids WHERE vacation_bid_window_id = $1", window_id)
+        .execute(&mut *conn)
+        .await?;
+    for (i, choice) in available.iter().enumerate() {
+        sqlx::query!(
+            r#"
+            INSERT INTO vacation_bids (vacation_bid_window_id, start_date, end_date, preference_rank)
+            VALUES ($1, $2, $3, $4)
+            "#,
+            window_id,
+            choice.start_date,
+            choice.end_date,
+            i as i32 + 1,
+        )
+        .execute(&mut *conn)
+        .await?;
+    }
+    sqlx::query!("UPDATE vacation_bid_windows SET submitted_at = NOW() WHERE id = $1", window_id)
+        .execute(&mut *conn)
+        .await?;
+
+    Ok(Some(ProxySubmission {
+        org_id: plan.org_id,
+        user_id,
+        window_id,
+        period: BidPeriod::Vacation(period_id),
+        trigger,
+        count: available.len(),
+    }))
+}
+
+/// Tell the owner a bid was placed for them. Best-effort: a failure is logged.
+pub(crate) async fn notify_submitted(pool: &PgPool, submission: &ProxySubmission) {
+    let (link, source_type) = match submission.period {
+        BidPeriod::Shift(_) => (format!("/bid/{}", submission.window_id), "bid_window"),
+        BidPeriod::Vacation(_) => (format!("/vacation-bid/{}", submission.window_id), "vacation_bid_window"),
+    };
+    let choices = format!(
+        "{} choice{}",
+        submission.count,
+        if submission.count == 1 { "" } else { "s" },
+    );
+    let were = if submission.count == 1 { "was" } else { "were" };
+    let (title, message) = match submission.trigger {
+        Trigger::Proxy => (
+            "Your proxy submitted your bid",
+            format!("Your proxy bidder submitted {choices} for you."),
+        ),
+        Trigger::WindowOpen => (
+            "Your bid was submitted from your preferences",
+            format!(
+                "{choices} from your pre-filed preferences {were} submitted for you when your \
+                 window opened."
+            ),
+        ),
+        Trigger::WindowExpired => (
+            "Your bid was submitted from your preferences",
+            format!(
+                "{choices} from your pre-filed preferences {were} submitted for you after your \
+                 window closed without a submission."
+            ),
+        ),
+    };
+    let sent = create_notification(
+        pool,
+        CreateNotificationParams {
+            org_id: submission.org_id,
+            user_id: submission.user_id,
+            notification_type: "bid_proxy_submitted",
+            title,
+            message: &message,
+            link: Some(&link),
+            source_type: Some(source_type),
+            source_id: Some(submission.window_id),
+        },
+    )
+    .await;
+    if let Err(e) = sent {
+        tracing::warn!(window_id = %submission.window_id, error = %e, "Failed to notify proxy bid owner");
+    }
+}
+
+/// Bid for every window in the org that just opened and whose owner asked for
+/// `submit_on = 'open'`. Each window is tried once. Returns how many were submitted.
+pub async fn submit_open_windows(pool: &PgPool, org_id: Uuid) -> Result<u32> {
+    let shift = sqlx::query!(
+        r#"
+        SELECT bw.id, bw.period_id, bw.user_id
+        FROM bid_windows bw
+        JOIN schedule_periods sp ON sp.id = bw.period_id
+        JOIN bid_proxy_plans p ON p.user_id = bw.user_id AND p.schedule_period_id = bw.period_id
+        WHERE sp.org_id = $1
+          AND sp.status = 'open'
+          AND p.submit_on = 'open'
+          AND EXISTS (SELECT 1 FROM bid_proxy_slot_choices c WHERE c.plan_id = p.id)
+          AND bw.submitted_at IS NULL
+          AND bw.auto_advanced_at IS NULL
+          AND bw.unlocked_at IS NOT NULL
+          AND bw.opens_at <= NOW()
+          AND bw.closes_at > NOW()
+          AND NOT EXISTS (
+              SELECT 1 FROM bid_proxy_actions a
+              WHERE a.window_id = bw.id AND a.trigger = 'window_open'
+          )
+        ORDER BY bw.seniority_rank
+        "#,
+        org_id,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let vacation = sqlx::query!(
+        r#"
+        SELECT w.id, w.vacation_bid_period_id AS period_id, w.user_id
+        FROM vacation_bid_windows w
+        JOIN vacation_bid_periods vp ON vp.id = w.vacation_bid_period_id
+        JOIN bid_proxy_plans p ON p.user_id = w.user_id AND p.vacation_bid_period_id = w.vacation_bid_period_id
+        WHERE vp.org_id = $1
+          AND vp.status = 'open'
+          AND p.submit_on = 'open'
+          AND EXISTS (SELECT 1 FROM bid_proxy_date_choices c WHERE c.plan_id = p.id)
+          AND w.submitted_at IS NULL
+          AND w.auto_advanced_at IS NULL
+          AND w.opens_at <= NOW()
+          AND w.closes_at > NOW()
+          AND NOT EXISTS (
+              SELECT 1 FROM bid_proxy_actions a
+              WHERE a.window_id = w.id AND a.trigger = 'window_open'
+          )
+        ORDER BY w.seniority_rank
+        "#,
+        org_id,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let mut submitted = 0;
+    for w in shift {
+        let mut tx = pool.begin().await?;
+        let still_open = sqlx::query_scalar!(
+            "SELECT submitted_at IS NULL AS \"open!\" FROM bid_windows WHERE id = $1 FOR UPDATE",
+            w.id,
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+        let submission = if still_open {
+            submit_shift_from_plan(&mut tx, w.id, w.period_id, w.user_id, Trigger::WindowOpen).await?
+        } else {
+            None
+        };
+        tx.commit().await?;
+        if let Some(s) = submission {
+            notify_submitted(pool, &s).await;
+            submitted += 1;
+        }
+    }
+    for w in vacation {
+        let mut tx = pool.begin().await?;
+        let still_open = sqlx::query_scalar!(
+            "SELECT submitted_at IS NULL AS \"open!\" FROM vacation_bid_windows WHERE id = $1 FOR UPDATE",
+            w.id,
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+        let submission = if still_open {
+            submit_vacation_from_plan(&mut tx, w.id, w.period_id, w.user_id, Trigger::WindowOpen).await?
+        } else {
+            None
+        };
+        tx.commit().await?;
+        if let Some(s) = submission {
+            notify_submitted(pool, &s).await;
+            submitted += 1;
+        }
+    }
+    Ok(submitted)
+}
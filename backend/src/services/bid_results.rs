@@ -0,0 +1,286 @@
+//! The posted results sheet for a shift bid period: every window in seniority
+//! order with what was bid, what was awarded and why higher choices were passed
+//! over, so stewards can check that seniority was honored.
+
+use std::collections::HashMap;
+
+use sqlx::PgPool;
+use time::{macros::format_description, OffsetDateTime};
+use uuid::Uuid;
+
+use crate::{
+    error::Result,
+    models::bid_processing::{
+        BidResultsPreference, BidResultsReport, BidResultsRow, ShiftAwardTable, SkipReason,
+    },
+    models::bidding::BidPeriodStatus,
+    services::report_export::ReportTable,
+};
+
+/// Build the results report. Awards and skip reasons come from the latest
+/// processing run while the period stands processed; before that (or after a
+/// rollback) the report lists the windows and their bids only.
+pub async fn load(pool: &PgPool, period_id: Uuid) -> Result<BidResultsReport> {
+    let period = sqlx::query!(
+        r#"
+        SELECT sp.name, sp.start_date, sp.end_date, sp.status AS "status: BidPeriodStatus",
+               sp.bid_results_published_at,
+               pu.first_name AS "published_by_first_name?", pu.last_name AS "published_by_last_name?"
+        FROM schedule_periods sp
+        LEFT JOIN users pu ON pu.id = sp.bid_results_published_by
+        WHERE sp.id = $1
+        "#,
+        period_id,
+    )
+    .fetch_one(pool)
+    .await?;
+
+    let run = if period.status == BidPeriodStatus::Completed {
+        sqlx::query!(
+            r#"
+            SELECT r.detail, r.created_at, u.first_name, u.last_name
+            FROM bid_processing_runs r
+            JOIN users u ON u.id = r.run_by
+            WHERE r.schedule_period_id = $1 AND r.action = 'process'
+            ORDER BY r.created_at DESC
+            LIMIT 1
+            "#,
+            period_id,
+        )
+        .fetch_optional(pool)
+        .await?
+    } else {
+        None
+    };
+    let mut outcomes: HashMap<Uuid, _> = run
+        .as_ref()
+        .and_then(|r| serde_json::from_value::<ShiftAwardTable>(r.detail.clone()).ok())
+        .map(|table| table.rows.into_iter().map(|row| (row.window_id, row)).collect())
+        .unwrap_or_default();
+
+    let windows = sqlx::query!(
+        r#"
+        SELECT bw.id, bw.seniority_rank, bw.user_id, u.first_name, u.last_name,
+               bw.partner_user_id, pu.first_name AS "partner_first_name?", pu.last_name AS "partner_last_name?",
+               bw.opens_at, bw.closes_at, bw.submitted_at, bw.auto_advanced_at,
+               bw.approved_at, bw.approved_by,
+               au.first_name AS "approved_by_first_name?", au.last_name AS "approved_by_last_name?"
+        FROM bid_windows bw
+        JOIN users u ON u.id = bw.user_id
+        LEFT JOIN users pu ON pu.id = bw.partner_user_id
+        LEFT JOIN users au ON au.id = bw.approved_by
+        WHERE bw.period_id = $1
+        ORDER BY bw.seniority_rank ASC
+        "#,
+        period_id,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let mut preferences: HashMap<Uuid, Vec<BidResultsPreference>> = HashMap::new();
+    for sub in sqlx::query!(
+        r#"
+        SELECT bs.bid_window_id, bs.preference_rank, bs.slot_id, bs.partner_days_of_week, bs.awarded,
+               CONCAT(t.name, ' - ', st.name) AS "slot_name!"
+        FROM bid_submissions bs
+        JOIN bid_windows bw ON bw.id = bs.bid_window_id
+        JOIN shift_slots ss ON ss.id = bs.slot_id
+        JOIN teams t ON t.id = ss.team_id
+        JOIN shift_templates st ON st.id = ss.shift_template_id
+        WHERE bw.period_id = $1
+        ORDER BY bs.bid_window_id, bs.preference_rank ASC
+        "#,
+        period_id,
+    )
+    .fetch_all(pool)
+    .await?
+    {
+        preferences.entry(sub.bid_window_id).or_default().push(BidResultsPreference {
+            preference_rank: sub.preference_rank,
+            slot_id: sub.slot_id,
+            slot_name: sub.slot_name,
+            partner_days_of_week: sub.partner_days_of_week,
+            awarded: sub.awarded,
+            skip_reason: None,
+        });
+    }
+
+    let rows = windows
+        .into_iter()
+        .map(|w| {
+            let outcome = outcomes.remove(&w.id);
+            let mut prefs = preferences.remove(&w.id).unwrap_or_default();
+            if let Some(outcome) = &outcome {
+                for pref in &mut prefs {
+                    pref.skip_reason = outcome
+                        .skipped
+                        .iter()
+                        .find(|s| s.slot_id == pref.slot_id)
+                        .map(|s| s.reason);
+                }
+            }
+            let (award, reason) = outcome.map_or((None, None), |o| (o.award, o.reason));
+            BidResultsRow {
+                window_id: w.id,
+                seniority_rank: w.seniority_rank,
+                user_id: w.user_id,
+                first_name: w.first_name,
+                last_name: w.last_name,
+                partner_user_id: w.partner_user_id,
+                partner_first_name: w.partner_first_name,
+                partner_last_name: w.partner_last_name,
+                opens_at: w.opens_at,
+                closes_at: w.closes_at,
+                submitted_at: w.submitted_at,
+                auto_advanced_at: w.auto_advanced_at,
+                approved_at: w.approved_at,
+                approved_by: w.approved_by,
+                approved_by_first_name: w.approved_by_first_name,
+                approved_by_last_name: w.approved_by_last_name,
+                preferences: prefs,
+                award,
+                reason,
+            }
+        })
+        .collect();
+
+    Ok(BidResultsReport {
+        period_id,
+        period_name: period.name,
+        start_date: period.start_date,
+        end_date: period.end_date,
+        status: period.status,
+        processed_at: run.as_ref().map(|r| r.created_at),
+        processed_by_first_name: run.as_ref().map(|r| r.first_name.clone()),
+        processed_by_last_name: run.map(|r| r.last_name),
+        published_at: period.bid_results_published_at,
+        published_by_first_name: period.published_by_first_name,
+        published_by_last_name: period.published_by_last_name,
+        rows,
+    })
+}
+
+/// The report as a table for CSV/PDF export: one line per preference, or a
+/// single line for a window with no bids.
+pub fn to_table(report: &BidResultsReport) -> ReportTable {
+    let name = |first: &Option<String>, last: &Option<String>| match (first, last) {
+        (Some(first), Some(last)) => format!("{last}, {first}"),
+        _ => String::new(),
+    };
+    let mut summary = vec![format!(
+        "Period: {} to {} (times UTC)",
+        report.start_date, report.end_date
+    )];
+    if let Some(at) = report.processed_at {
+        summary.push(format!(
+            "Processed {} by {}",
+            timestamp(Some(at)),
+            name(&report.processed_by_first_name, &report.processed_by_last_name)
+        ));
+    } else {
+        summary.push("Not processed".into());
+    }
+    if let Some(at) = report.published_at {
+        summary.push(format!(
+            "Published {} by {}",
+            timestamp(Some(at)),
+            name(&report.published_by_first_name, &report.published_by_last_name)
+        ));
+    }
+
+    let mut rows = Vec::new();
+    for row in &report.rows {
+        let window = vec![
+            row.seniority_rank.to_string(),
+            format!("{}, {}", row.last_name, row.first_name),
+            name(&row.partner_first_name, &row.partner_last_name),
+            timestamp(Some(row.opens_at)),
+            timestamp(Some(row.closes_at)),
+            timestamp(row.submitted_at),
+            timestamp(row.auto_advanced_at),
+            name(&row.approved_by_first_name, &row.approved_by_last_name),
+            timestamp(row.approved_at),
+        ];
+        if row.preferences.is_empty() {
+            let result = row
+                .reason
+                .map(|reason| format!("Not awarded: {}", reason_label(reason)))
+                .unwrap_or_default();
+            rows.push([window, vec![String::new(), String::new(), String::new(), result]].concat());
+            continue;
+        }
+        for pref in &row.preferences {
+            let result = if pref.awarded {
+                "Awarded".to_string()
+            } else if let Some(reason) = pref.skip_reason {
+                format!("Skipped: {}", reason_label(reason))
+            } else {
+                String::new()
+            };
+            rows.push(
+                [
+                    window.clone(),
+                    vec![
+                        pref.preference_rank.to_string(),
+                        pref.slot_name.clone(),
+                        pref.partner_days_of_week.as_deref().map(weekdays).unwrap_or_default(),
+                        result,
+                    ],
+                ]
+                .concat(),
+            );
+        }
+    }
+
+    ReportTable {
+        title: format!("Shift bid results: {}", report.period_name),
+        summary,
+        headers: vec![
+            "Rank",
+            "Employee",
+            "Job-share partner",
+            "Window opens",
+            "Window closes",
+            "Submitted",
+            "Auto-advanced",
+            "Approved by",
+            "Approved at",
+            "Choice",
+            "Slot",
+            "Partner days",
+            "Result",
+        ],
+        rows,
+    }
+}
+
+fn timestamp(at: Option<OffsetDateTime>) -> String {
+    at.and_then(|at| {
+        at.format(format_description!("[year]-[month]-[day] [hour]:[minute]"))
+            .ok()
+    })
+    .unwrap_or_default()
+}
+
+fn weekdays(days: &[i32]) -> String {
+    const NAMES: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+    days.iter()
+        .filter_map(|d| NAMES.get(*d as usize))
+        .copied()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn reason_label(reason: SkipReason) -> &'static str {
+    match reason {
+        SkipReason::BargainingUnit => "outside bargaining unit",
+        SkipReason::NoSubmission => "no submission",
+        SkipReason::Taken => "taken by senior bidder",
+        SkipReason::FlexCap => "flex slot cap reached",
+        SkipReason::DateConflict => "date at vacation limit",
+        SkipReason::InsufficientBalance => "insufficient balance",
+        SkipReason::PartnerOff => "job-share partner off",
+        SkipReason::RoundLimit => "round award limit reached",
+        SkipReason::HolidayRotation => "holiday rotation",
+    }
+}
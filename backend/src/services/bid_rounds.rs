@@ -0,0 +1,261 @@
+//! Shift bid windows and bid rounds. Bidding opens round 1 (or the whole period
+//! when it has no rounds); each later round opens once every window of the
+//! round before has been submitted or passed over.
+
+use std::collections::{HashMap, HashSet};
+
+use sqlx::{PgConnection, PgPool};
+use time::{Duration, OffsetDateTime};
+use uuid::Uuid;
+
+use crate::{
+    error::Result,
+    models::bidding::{BidPeriodStatus, BidWindow},
+};
+
+/// Who gets a window. `None` restrictions let anyone in.
+#[derive(Default)]
+pub(crate) struct Eligibility<'a> {
+    pub round_id: Option<Uuid>,
+    pub classification_ids: Option<&'a [Uuid]>,
+    pub bargaining_units: Option<&'a [String]>,
+}
+
+/// Create seniority-ordered windows for the eligible active employees (within
+/// the period's bargaining unit, if set) who don't have one in the period yet.
+/// Ranks start at `first_rank`; windows follow one another from `start_at`,
+/// and only the first is unlocked. A seniority list pinned to the period
+/// fixes the order; employees not on it follow by live seniority.
+///
+/// Unpaired job_share employees go last. A job-share pair gets one joint
+/// window at the senior partner's rank; a partner who isn't eligible leaves
+/// the employee bidding alone.
+pub(crate) async fn create_windows(
+    conn: &mut PgConnection,
+    org_id: Uuid,
+    period_id: Uuid,
+    eligibility: &Eligibility<'_>,
+    first_rank: i32,
+    start_at: OffsetDateTime,
+    duration: Duration,
+) -> Result<Vec<BidWindow>> {
+    let users = sqlx::query!(
+        r#"
+        SELECT u.id, u.first_name, u.last_name,
+               (u.employee_type::TEXT = 'job_share') AS "is_job_share!",
+               jp.partner_id AS "partner_id?"
+        FROM users u
+        JOIN schedule_periods sp ON sp.id = $2
+        LEFT JOIN seniority_records sr ON sr.user_id = u.id
+        LEFT JOIN seniority_list_entries sle
+               ON sle.list_id = sp.seniority_list_id AND sle.user_id = u.id
+        LEFT JOIN LATERAL (
+            SELECT CASE WHEN p.user_a_id = u.id THEN p.user_b_id ELSE p.user_a_id END AS partner_id
+            FROM job_share_pairs p
+            WHERE p.user_a_id = u.id OR p.user_b_id = u.id
+        ) jp ON true
+        WHERE u.org_id = $1
+          AND u.is_active = true
+          AND (sp.bargaining_unit IS NULL OR u.bargaining_unit = sp.bargaining_unit)
+          AND ($3::UUID[] IS NULL OR u.classification_id = ANY($3))
+          AND ($4::TEXT[] IS NULL OR u.bargaining_unit = ANY($4))
+          AND NOT EXISTS (
+              SELECT 1 FROM bid_windows bw
+              WHERE bw.period_id = $2 AND (bw.user_id = u.id OR bw.partner_user_id = u.id)
+          )
+        ORDER BY
+          (u.employee_type::TEXT = 'job_share' AND jp.partner_id IS NULL) ASC,
+          sle.rank ASC NULLS LAST,
+          sr.overall_seniority_date ASC NULLS LAST,
+          u.last_name, u.first_name
+        "#,
+        org_id,
+        period_id,
+        eligibility.classification_ids,
+        eligibility.bargaining_units,
+    )
+    .fetch_all(&mut *conn)
+    .await?;
+
+    let names: HashMap<Uuid, (&str, &str)> = users
+        .iter()
+        .map(|u| (u.id, (u.first_name.as_str(), u.last_name.as_str())))
+        .collect();
+    let mut windowed: HashSet<Uuid> = HashSet::new();
+    let mut windows = Vec::new();
+
+    for user in &users {
+        if windowed.contains(&user.id) {
+            continue;
+        }
+        let partner = user.partner_id.filter(|p| names.contains_key(p));
+        windowed.insert(user.id);
+        windowed.extend(partner);
+
+        let i = windows.len();
+        let rank = first_rank + i as i32;
+        let opens = start_at + duration * i as u32;
+        let closes = opens + duration;
+
+        // M2: Only unlock the first window immediately; others wait for approval cascade
+        let unlocked = if i == 0 { Some(opens) } else { None };
+        let (partner_first_name, partner_last_name) = partner
+            .and_then(|p| names.get(&p))
+            .map_or((None, None), |(f, l)| (Some(*f), Some(*l)));
+
+        let row = sqlx::query_as!(
+            BidWindow,
+            r#"
+            INSERT INTO bid_windows (id, period_id, user_id, seniority_rank, opens_at, closes_at, unlocked_at, partner_user_id, round_id)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $11, $14)
+            RETURNING id, period_id, user_id,
+                      $8::text AS "first_name!",
+                      $9::text AS "last_name!",
+                      seniority_rank, opens_at, closes_at, submitted_at,
+                      unlocked_at, approved_at,
+                      approved_by AS "approved_by?",
+                      $10::bool AS "is_job_share!",
+                      auto_advanced_at,
+                      partner_user_id,
+                      $12::text AS "partner_first_name?",
+                      $13::text AS "partner_last_name?",
+                      round_id
+            "#,
+            Uuid::new_v4(),
+            period_id,
+            user.id,
+            rank,
+            opens,
+            closes,
+            unlocked,
+            user.first_name,
+            user.last_name,
+            user.is_job_share || partner.is_some(),
+            partner,
+            partner_first_name,
+            partner_last_name,
+            eligibility.round_id,
+        )
+        .fetch_one(&mut *conn)
+        .await?;
+
+        windows.push(row);
+    }
+
+    Ok(windows)
+}
+
+/// Close the period's current round once all its windows are submitted or
+/// passed over, and open the next one at `now`. Rounds nobody is eligible for
+/// open and close straight away. Returns the windows opened.
+pub(crate) async fn open_due_rounds(
+    conn: &mut PgConnection,
+    org_id: Uuid,
+    period_id: Uuid,
+    now: OffsetDateTime,
+) -> Result<Vec<BidWindow>> {
+    let mut opened = Vec::new();
+    loop {
+        let current = sqlx::query!(
+            r#"
+            SELECT r.id,
+                   NOT EXISTS (
+                       SELECT 1 FROM bid_windows bw
+                       WHERE bw.round_id = r.id AND bw.submitted_at IS NULL AND bw.auto_advanced_at IS NULL
+                   ) AS "finished!"
+            FROM shift_bid_rounds r
+            WHERE r.period_id = $1 AND r.opened_at IS NOT NULL AND r.closed_at IS NULL
+            "#,
+            period_id,
+        )
+        .fetch_optional(&mut *conn)
+        .await?;
+        if let Some(current) = current {
+            if !current.finished {
+                break;
+            }
+            sqlx::query!("UPDATE shift_bid_rounds SET closed_at = $2 WHERE id = $1", current.id, now)
+                .execute(&mut *conn)
+                .await?;
+        }
+
+        let Some(next) = sqlx::query!(
+            r#"
+            SELECT id, classification_ids, bargaining_units, window_duration_hours
+            FROM shift_bid_rounds
+            WHERE period_id = $1 AND opened_at IS NULL
+            ORDER BY round_number
+            LIMIT 1
+            "#,
+            period_id,
+        )
+        .fetch_optional(&mut *conn)
+        .await?
+        else {
+            break;
+        };
+        sqlx::query!("UPDATE shift_bid_rounds SET opened_at = $2 WHERE id = $1", next.id, now)
+            .execute(&mut *conn)
+            .await?;
+
+        // Ranks carry on from the earlier rounds, so awards follow round order
+        let first_rank = sqlx::query_scalar!(
+            r#"SELECT COALESCE(MAX(seniority_rank), 0) + 1 AS "rank!" FROM bid_windows WHERE period_id = $1"#,
+            period_id,
+        )
+        .fetch_one(&mut *conn)
+        .await?;
+        let eligibility = Eligibility {
+            round_id: Some(next.id),
+            classification_ids: next.classification_ids.as_deref(),
+            bargaining_units: next.bargaining_units.as_deref(),
+        };
+        opened.extend(
+            create_windows(
+                conn,
+                org_id,
+                period_id,
+                &eligibility,
+                first_rank,
+                now,
+                Duration::hours(next.window_duration_hours as i64),
+            )
+            .await?,
+        );
+    }
+
+    if !opened.is_empty() {
+        sqlx::query!(
+            r#"
+            UPDATE schedule_periods
+            SET bid_closes_at = (SELECT MAX(closes_at) FROM bid_windows WHERE period_id = $1)
+            WHERE id = $1
+            "#,
+            period_id,
+        )
+        .execute(&mut *conn)
+        .await?;
+    }
+    Ok(opened)
+}
+
+/// Open whichever rounds of an open period are due. Returns how many windows
+/// were opened.
+pub async fn advance_rounds(pool: &PgPool, period_id: Uuid) -> Result<usize> {
+    let mut tx = pool.begin().await?;
+
+    let period = sqlx::query!(
+        r#"SELECT org_id, status AS "status: BidPeriodStatus" FROM schedule_periods WHERE id = $1 FOR UPDATE"#,
+        period_id,
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+    if period.status != BidPeriodStatus::Open {
+        return Ok(0);
+    }
+
+    let opened = open_due_rounds(&mut tx, period.org_id, period_id, OffsetDateTime::now_utc()).await?;
+    tx.commit().await?;
+
+    Ok(opened.len())
+}
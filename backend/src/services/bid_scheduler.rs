@@ -0,0 +1,412 @@
+//! Background bid window scheduler.
+//!
+//! Every minute, for each open shift and vacation bid period:
+//! - expired, unsubmitted windows are advanced (the next shift bidder is
+//!   unlocked) without waiting for someone to open the bidding page;
+//! - a shift bid round whose windows are all done opens the next round
+//!   (see `bid_rounds`);
+//! - windows that just opened are bid from pre-filed plans that ask for it
+//!   (see `bid_proxy`);
+//! - each bidder who hasn't submitted is told their window opens within the
+//!   hour, is open, and closes within 30 minutes — in-app, and by SMS when
+//!   they've opted in and Twilio is configured.
+//!
+//! `notice_stage` on the window records the furthest notice sent, so each goes
+//! out once. A bidder first seen partway through gets the current notice only.
+
+use sqlx::PgPool;
+use time::{Duration, OffsetDateTime};
+use uuid::Uuid;
+
+use crate::{
+    api::notifications::{create_notification, CreateNotificationParams},
+    error::Result,
+    services::{
+        bid_proxy, bid_rounds,
+        bidding::{advance_expired_vacation_windows, advance_expired_windows},
+        sms::{self, TwilioConfig},
+    },
+};
+
+/// How long before a window opens the bidder is told.
+pub const OPENING_LEAD: Duration = Duration::hours(1);
+/// How long before a window closes the bidder is reminded.
+pub const CLOSING_LEAD: Duration = Duration::minutes(30);
+
+/// Notices in the order they go out; the value is stored in `notice_stage`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Notice {
+    OpensSoon = 1,
+    OpenNow = 2,
+    ClosesSoon = 3,
+}
+
+/// The notice a window is due as of `now`, if any. A shift window that hasn't
+/// been unlocked isn't open yet even once its time comes.
+fn due_notice(
+    now: OffsetDateTime,
+    opens_at: OffsetDateTime,
+    closes_at: OffsetDateTime,
+    unlocked: bool,
+) -> Option<Notice> {
+    if now >= closes_at {
+        None
+    } else if now >= opens_at {
+        match (unlocked, closes_at - now <= CLOSING_LEAD) {
+            (false, _) => None,
+            (true, true) => Some(Notice::ClosesSoon),
+            (true, false) => Some(Notice::OpenNow),
+        }
+    } else if opens_at - now <= OPENING_LEAD {
+        Some(Notice::OpensSoon)
+    } else {
+        None
+    }
+}
+
+/// "1 hour", "30 minutes", "2 hours 15 minutes" — rounded up to the minute.
+fn describe(d: Duration) -> String {
+    let minutes = (d.whole_seconds() + 59) / 60;
+    let plural = |n: i64, unit: &str| format!("{n} {unit}{}", if n == 1 { "" } else { "s" });
+    match (minutes / 60, minutes % 60) {
+        (0, m) => plural(m, "minute"),
+        (h, 0) => plural(h, "hour"),
+        (h, m) => format!("{} {}", plural(h, "hour"), plural(m, "minute")),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BidKind {
+    Shift,
+    Vacation,
+}
+
+struct PendingWindow {
+    kind: BidKind,
+    id: Uuid,
+    user_id: Uuid,
+    /// Set only when the bidder has opted in to SMS.
+    sms_phone: Option<String>,
+    label: String,
+    opens_at: OffsetDateTime,
+    closes_at: OffsetDateTime,
+    unlocked: bool,
+    notice_stage: i16,
+}
+
+impl PendingWindow {
+    fn link(&self) -> String {
+        match self.kind {
+            BidKind::Shift => format!("/bid/{}", self.id),
+            BidKind::Vacation => format!("/vacation-bid/{}", self.id),
+        }
+    }
+
+    fn source_type(&self) -> &'static str {
+        match self.kind {
+            BidKind::Shift => "bid_window",
+            BidKind::Vacation => "vacation_bid_window",
+        }
+    }
+
+    fn text(&self, notice: Notice, now: OffsetDateTime) -> (String, String) {
+        match notice {
+            Notice::OpensSoon => {
+                let lead = describe(self.opens_at - now);
+                (
+                    format!("Your bid window opens in {lead}"),
+                    format!(
+                        "Your window for {} opens in {lead} and stays open for {}.",
+                        self.label,
+                        describe(self.closes_at - self.opens_at),
+                    ),
+                )
+            }
+            Notice::OpenNow => (
+                "Your bid window is open now".to_string(),
+                format!(
+                    "Your window for {} is open and closes in {}.",
+                    self.label,
+                    describe(self.closes_at - now),
+                ),
+            ),
+            Notice::ClosesSoon => {
+                let left = describe(self.closes_at - now);
+                (
+                    format!("Your bid window closes in {left}"),
+                    format!(
+                        "Your window for {} closes in {left}. Submit before then or your turn \
+                         passes to the next bidder.",
+                        self.label,
+                    ),
+                )
+            }
+        }
+    }
+}
+
+/// What one sweep did.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SweepResult {
+    pub advanced: u64,
+    /// Windows created by bid rounds opening.
+    pub opened: u64,
+    pub proxy_bids: u32,
+    pub notices: u32,
+    pub texts: u32,
+}
+
+/// Windows in the org's open periods that may still be owed a notice.
+async fn pending_windows(pool: &PgPool, org_id: Uuid, horizon: OffsetDateTime) -> Result<Vec<PendingWindow>> {
+    let shift = sqlx::query!(
+        r#"
+        SELECT bw.id, bw.user_id, u.phone, COALESCE(ep.notification_sms, false) AS "sms!",
+               sp.name AS period_name, bw.opens_at, bw.closes_at,
+               bw.unlocked_at IS NOT NULL AS "unlocked!", bw.notice_stage
+        FROM bid_windows bw
+        JOIN schedule_periods sp ON sp.id = bw.period_id
+        JOIN users u ON u.id = bw.user_id
+        LEFT JOIN employee_preferences ep ON ep.user_id = u.id
+        WHERE sp.org_id = $1
+          AND sp.status = 'open'
+          AND bw.submitted_at IS NULL
+          AND bw.auto_advanced_at IS NULL
+          AND bw.notice_stage < 3
+          AND bw.opens_at <= $2
+          AND bw.closes_at > NOW()
+        "#,
+        org_id,
+        horizon,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let vacation = sqlx::query!(
+        r#"
+        SELECT w.id, w.user_id, u.phone, COALESCE(ep.notification_sms, false) AS "sms!",
+               p.year, p.round, w.opens_at, w.closes_at, w.notice_stage
+        FROM vacation_bid_windows w
+        JOIN vacation_bid_periods p ON p.id = w.vacation_bid_period_id
+        JOIN users u ON u.id = w.user_id
+        LEFT JOIN employee_preferences ep ON ep.user_id = u.id
+        WHERE p.org_id = $1
+          AND p.status = 'open'
+          AND w.submitted_at IS NULL
+          AND w.auto_advanced_at IS NULL
+          AND w.notice_stage < 3
+          AND w.opens_at <= $2
+          AND w.closes_at > NOW()
+        "#,
+        org_id,
+        horizon,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let sms_phone = |sms: bool, phone: Option<String>| phone.filter(|p| sms && !p.is_empty());
+    let mut windows: Vec<PendingWindow> = shift
+        .into_iter()
+        .map(|r| PendingWindow {
+            kind: BidKind::Shift,
+            id: r.id,
+            user_id: r.user_id,
+            sms_phone: sms_phone(r.sms, r.phone),
+            label: format!("the {} shift bid", r.period_name),
+            opens_at: r.opens_at,
+            closes_at: r.closes_at,
+            unlocked: r.unlocked,
+            notice_stage: r.notice_stage,
+        })
+        .collect();
+    windows.extend(vacation.into_iter().map(|r| PendingWindow {
+        kind: BidKind::Vacation,
+        id: r.id,
+        user_id: r.user_id,
+        sms_phone: sms_phone(r.sms, r.phone),
+        label: format!("the {} vacation bid (round {})", r.year, r.round),
+        opens_at: r.opens_at,
+        closes_at: r.closes_at,
+        unlocked: true,
+        notice_stage: r.notice_stage,
+    }));
+    Ok(windows)
+}
+
+/// Record `notice` as sent. Returns false if another sweep got there first.
+async fn claim_notice(pool: &PgPool, window: &PendingWindow, notice: Notice) -> Result<bool> {
+    let stage = notice as i16;
+    let result = match window.kind {
+        BidKind::Shift => {
+            sqlx::query!(
+                "UPDATE bid_windows SET notice_stage = $2 WHERE id = $1 AND notice_stage < $2",
+                window.id,
+                stage,
+            )
+            .execute(pool)
+            .await?
+        }
+        BidKind::Vacation => {
+            sqlx::query!(
+                "UPDATE vacation_bid_windows SET notice_stage = $2 WHERE id = $1 AND notice_stage < $2",
+                window.id,
+                stage,
+            )
+            .execute(pool)
+            .await?
+        }
+    };
+    Ok(result.rows_affected() > 0)
+}
+
+/// Text the bidder and log it. Returns whether the text was accepted.
+async fn send_text(
+    pool: &PgPool,
+    twilio: &TwilioConfig,
+    org_id: Uuid,
+    sender: Uuid,
+    window: &PendingWindow,
+    phone: &str,
+    title: &str,
+) -> Result<bool> {
+    let body = format!("{title}: {}. Reply STOP to opt out.", window.label);
+    let outcome = sms::send_sms(twilio, phone, &body).await;
+    let (status, error_detail) = match &outcome {
+        Ok(()) => ("sent", None),
+        Err(e) => ("failed", Some(e.as_str())),
+    };
+    sqlx::query!(
+        r#"
+        INSERT INTO sms_log (org_id, sent_by, recipient_user_id, to_number, message_body, status, error_detail)
+        VALUES ($1, $2, $3, $4, $5, $6, $7)
+        "#,
+        org_id,
+        sender,
+        window.user_id,
+        phone,
+        body,
+        status,
+        error_detail,
+    )
+    .execute(pool)
+    .await?;
+    Ok(outcome.is_ok())
+}
+
+/// Advance expired windows and send due notices for one org.
+pub async fn sweep_org(pool: &PgPool, twilio: Option<&TwilioConfig>, org_id: Uuid) -> Result<SweepResult> {
+    let mut result = SweepResult::default();
+
+    let shift_periods = sqlx::query_scalar!(
+        "SELECT id FROM schedule_periods WHERE org_id = $1 AND status = 'open'",
+        org_id,
+    )
+    .fetch_all(pool)
+    .await?;
+    for &period_id in &shift_periods {
+        result.advanced += advance_expired_windows(pool, period_id).await?;
+        result.opened += bid_rounds::advance_rounds(pool, period_id).await? as u64;
+    }
+    let vacation_periods = sqlx::query_scalar!(
+        "SELECT id FROM vacation_bid_periods WHERE org_id = $1 AND status = 'open'",
+        org_id,
+    )
+    .fetch_all(pool)
+    .await?;
+    for period_id in vacation_periods {
+        result.advanced += advance_expired_vacation_windows(pool, period_id).await?;
+    }
+    result.proxy_bids = bid_proxy::submit_open_windows(pool, org_id).await?;
+
+    let now = OffsetDateTime::now_utc();
+    let sender = match twilio {
+        Some(_) => crate::services::accrual::first_admin_id(pool, org_id).await?,
+        None => None,
+    };
+
+    for window in pending_windows(pool, org_id, now + OPENING_LEAD).await? {
+        let Some(notice) = due_notice(now, window.opens_at, window.closes_at, window.unlocked) else {
+            continue;
+        };
+        if notice as i16 <= window.notice_stage || !claim_notice(pool, &window, notice).await? {
+            continue;
+        }
+
+        let (title, message) = window.text(notice, now);
+        let link = window.link();
+        create_notification(
+            pool,
+            CreateNotificationParams {
+                org_id,
+                user_id: window.user_id,
+                notification_type: "bid_window",
+                title: &title,
+                message: &message,
+                link: Some(&link),
+                source_type: Some(window.source_type()),
+                source_id: Some(window.id),
+            },
+        )
+        .await?;
+        result.notices += 1;
+
+        if let (Some(twilio), Some(sender), Some(phone)) = (twilio, sender, window.sms_phone.as_deref()) {
+            if send_text(pool, twilio, org_id, sender, &window, phone, &title).await? {
+                result.texts += 1;
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+/// Sweep every org with an open shift or vacation bid period.
+pub async fn run_all_orgs(pool: &PgPool, twilio: Option<&TwilioConfig>) -> Result<SweepResult> {
+    let orgs = sqlx::query_scalar!(
+        r#"
+        SELECT org_id AS "org_id!" FROM schedule_periods WHERE status = 'open'
+        UNION
+        SELECT org_id AS "org_id!" FROM vacation_bid_periods WHERE status = 'open'
+        "#
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let mut total = SweepResult::default();
+    for org_id in orgs {
+        match sweep_org(pool, twilio, org_id).await {
+            Ok(r) => {
+                total.advanced += r.advanced;
+                total.opened += r.opened;
+                total.proxy_bids += r.proxy_bids;
+                total.notices += r.notices;
+                total.texts += r.texts;
+            }
+            Err(e) => {
+                tracing::error!(org_id = %org_id, error = %e, "Bid window sweep failed for org");
+            }
+        }
+    }
+    Ok(total)
+}
+
+/// Background task: sweeps every minute so windows advance and notices go out on time.
+pub async fn background_bid_scheduler_task(pool: PgPool, twilio: Option<TwilioConfig>) {
+    let mut interval = tokio::time::interval(std::time::Duration::from_secs(60));
+
+    loop {
+        interval.tick().await;
+
+        match run_all_orgs(&pool, twilio.as_ref()).await {
+            Ok(r) if r.advanced + r.opened + (r.proxy_bids + r.notices) as u64 > 0 => tracing::info!(
+                advanced = r.advanced,
+                opened = r.opened,
+                proxy_bids = r.proxy_bids,
+                notices = r.notices,
+                texts = r.texts,
+                "Bid window sweep advanced windows, opened rounds, placed proxy bids or sent notices"
+            ),
+            Ok(_) => {}
+            Err(e) => tracing::error!(error = %e, "Background bid window sweep failed"),
+        }
+    }
+}
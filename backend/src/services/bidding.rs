@@ -1,23 +1,30 @@
 //! Bid window auto-advance logic: expired windows get marked and next window unlocked.
+//! Also builds the live status summary shown to the bid administrator.
 
 use sqlx::PgPool;
 use time::OffsetDateTime;
 use uuid::Uuid;
 
-use crate::error::Result;
+use crate::{
+    error::Result,
+    models::bidding::{BidStateCounts, BidStatusSummary, BidStatusWindow, BidWindowState},
+    services::bid_proxy::{self, Trigger},
+};
 
 /// Check for expired bid windows and auto-advance them.
 /// An expired window is one where closes_at has passed, the user never submitted,
-/// and it hasn't already been auto-advanced. For each, we mark auto_advanced_at
-/// and unlock the next seniority rank window (same cascade as approve).
-pub async fn advance_expired_windows(pool: &PgPool, period_id: Uuid) -> Result<()> {
+/// and it hasn't already been auto-advanced. For each, we bid from the owner's
+/// pre-filed plan if they have one, otherwise mark auto_advanced_at; either way
+/// the next seniority rank window is unlocked (same cascade as approve).
+/// Returns how many windows were advanced.
+pub async fn advance_expired_windows(pool: &PgPool, period_id: Uuid) -> Result<u64> {
     let now = OffsetDateTime::now_utc();
 
     let mut tx = pool.begin().await?;
 
     let expired = sqlx::query!(
         r#"
-        SELECT id, period_id, seniority_rank
+        SELECT id, period_id, user_id, seniority_rank
         FROM bid_windows
         WHERE period_id = $1
           AND submitted_at IS NULL
@@ -33,17 +40,25 @@ pub async fn advance_expired_windows(pool: &PgPool, period_id: Uuid) -> Result<(
     .await?;
 
     if expired.is_empty() {
-        return Ok(());
+        return Ok(0);
     }
 
+    let mut submitted = Vec::new();
     for w in &expired {
-        sqlx::query!(
-            "UPDATE bid_windows SET auto_advanced_at = $2 WHERE id = $1",
-            w.id,
-            now,
-        )
-        .execute(&mut *tx)
-        .await?;
+        match bid_proxy::submit_shift_from_plan(&mut tx, w.id, w.period_id, w.user_id, Trigger::WindowExpired)
+            .await?
+        {
+            Some(submission) => submitted.push(submission),
+            None => {
+                sqlx::query!(
+                    "UPDATE bid_windows SET auto_advanced_at = $2 WHERE id = $1",
+                    w.id,
+                    now,
+                )
+                .execute(&mut *tx)
+                .await?;
+            }
+        }
 
         // Unlock next seniority rank window if not already unlocked
         sqlx::query!(
@@ -61,5 +76,204 @@ pub async fn advance_expired_windows(pool: &PgPool, period_id: Uuid) -> Result<(
 
     tx.commit().await?;
 
-    Ok(())
+    for submission in &submitted {
+        bid_proxy::notify_submitted(pool, submission).await;
+    }
+
+    Ok(expired.len() as u64)
+}
+
+/// Handle expired, unsubmitted vacation windows: bid from the owner's pre-filed
+/// plan if they have one, otherwise mark the window passed over. Vacation
+/// windows open on time alone, so there is no next window to unlock.
+pub async fn advance_expired_vacation_windows(pool: &PgPool, period_id: Uuid) -> Result<u64> {
+    let mut tx = pool.begin().await?;
+
+    let expired = sqlx::query!(
+        r#"
+        SELECT id, user_id
+        FROM vacation_bid_windows
+        WHERE vacation_bid_period_id = $1
+          AND submitted_at IS NULL
+          AND auto_advanced_at IS NULL
+          AND closes_at < NOW()
+        ORDER BY seniority_rank ASC
+        FOR UPDATE
+        "#,
+        period_id,
+    )
+    .fetch_all(&mut *tx)
+    .await?;
+
+    let mut submitted = Vec::new();
+    for w in &expired {
+        match bid_proxy::submit_vacation_from_plan(&mut tx, w.id, period_id, w.user_id, Trigger::WindowExpired)
+            .await?
+        {
+            Some(submission) => submitted.push(submission),
+            None => {
+                sqlx::query!(
+                    "UPDATE vacation_bid_windows SET auto_advanced_at = NOW() WHERE id = $1",
+                    w.id,
+                )
+                .execute(&mut *tx)
+                .await?;
+            }
+        }
+    }
+
+    tx.commit().await?;
+
+    for submission in &submitted {
+        bid_proxy::notify_submitted(pool, submission).await;
+    }
+
+    Ok(expired.len() as u64)
+}
+
+struct StatusRow {
+    id: Uuid,
+    user_id: Uuid,
+    first_name: String,
+    last_name: String,
+    seniority_rank: i32,
+    opens_at: OffsetDateTime,
+    closes_at: OffsetDateTime,
+    unlocked_at: Option<OffsetDateTime>,
+    submitted_at: Option<OffsetDateTime>,
+    approved_at: Option<OffsetDateTime>,
+    auto_advanced_at: Option<OffsetDateTime>,
+}
+
+impl StatusRow {
+    fn state(&self, now: OffsetDateTime) -> BidWindowState {
+        if self.auto_advanced_at.is_some() {
+            BidWindowState::Skipped
+        } else if self.approved_at.is_some() {
+            BidWindowState::Approved
+        } else if self.submitted_at.is_some() {
+            BidWindowState::Submitted
+        } else if now < self.opens_at {
+            BidWindowState::Upcoming
+        } else if now > self.closes_at {
+            BidWindowState::Skipped
+        } else if self.unlocked_at.is_none() {
+            BidWindowState::Waiting
+        } else {
+            BidWindowState::Open
+        }
+    }
+
+    fn view(&self, state: BidWindowState) -> BidStatusWindow {
+        BidStatusWindow {
+            window_id: self.id,
+            user_id: self.user_id,
+            first_name: self.first_name.clone(),
+            last_name: self.last_name.clone(),
+            seniority_rank: self.seniority_rank,
+            opens_at: self.opens_at,
+            closes_at: self.closes_at,
+            state,
+        }
+    }
+}
+
+/// Summarize windows given in seniority order.
+fn summarize(period_id: Uuid, status: String, rows: &[StatusRow]) -> BidStatusSummary {
+    let now = OffsetDateTime::now_utc();
+    let mut counts = BidStateCounts::default();
+    let mut to_bid = Vec::new();
+    for row in rows {
+        let state = row.state(now);
+        match state {
+            BidWindowState::Upcoming => counts.upcoming += 1,
+            BidWindowState::Waiting => counts.waiting += 1,
+            BidWindowState::Open => counts.open += 1,
+            BidWindowState::Submitted => counts.submitted += 1,
+            BidWindowState::Approved => counts.approved += 1,
+            BidWindowState::Skipped => counts.skipped += 1,
+        }
+        if matches!(
+            state,
+            BidWindowState::Upcoming | BidWindowState::Waiting | BidWindowState::Open
+        ) {
+            to_bid.push((row, state));
+        }
+    }
+
+    let mut remaining = to_bid.iter().map(|(row, state)| row.view(*state));
+    let (current, next) = match to_bid.first() {
+        Some((_, BidWindowState::Upcoming)) => (None, remaining.next()),
+        Some(_) => (remaining.next(), remaining.next()),
+        None => (None, None),
+    };
+
+    BidStatusSummary {
+        period_id,
+        status,
+        total_windows: rows.len() as i64,
+        counts,
+        current,
+        next,
+        expected_finish_at: to_bid.iter().map(|(row, _)| row.closes_at).max(),
+        as_of: now,
+    }
+}
+
+/// Live status of a shift bid period. Advances expired windows first.
+pub async fn shift_bid_status(pool: &PgPool, period_id: Uuid) -> Result<BidStatusSummary> {
+    advance_expired_windows(pool, period_id).await?;
+
+    let status = sqlx::query_scalar!(
+        r#"SELECT status::TEXT AS "status!" FROM schedule_periods WHERE id = $1"#,
+        period_id,
+    )
+    .fetch_one(pool)
+    .await?;
+    let rows = sqlx::query_as!(
+        StatusRow,
+        r#"
+        SELECT bw.id, bw.user_id, u.first_name, u.last_name, bw.seniority_rank,
+               bw.opens_at, bw.closes_at, bw.unlocked_at, bw.submitted_at,
+               bw.approved_at, bw.auto_advanced_at
+        FROM bid_windows bw
+        JOIN users u ON u.id = bw.user_id
+        WHERE bw.period_id = $1
+        ORDER BY bw.seniority_rank
+        "#,
+        period_id,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(summarize(period_id, status, &rows))
+}
+
+/// Live status of a vacation bid period. Advances expired windows first.
+pub async fn vacation_bid_status(pool: &PgPool, period_id: Uuid) -> Result<BidStatusSummary> {
+    advance_expired_vacation_windows(pool, period_id).await?;
+
+    let status = sqlx::query_scalar!(
+        "SELECT status FROM vacation_bid_periods WHERE id = $1",
+        period_id,
+    )
+    .fetch_one(pool)
+    .await?;
+    let rows = sqlx::query_as!(
+        StatusRow,
+        r#"
+        SELECT w.id, w.user_id, u.first_name, u.last_name, w.seniority_rank,
+               w.opens_at, w.closes_at, w.opens_at AS "unlocked_at?", w.submitted_at,
+               NULL::TIMESTAMPTZ AS "approved_at?", w.auto_advanced_at
+        FROM vacation_bid_windows w
+        JOIN users u ON u.id = w.user_id
+        WHERE w.vacation_bid_period_id = $1
+        ORDER BY w.seniority_rank
+        "#,
+        period_id,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(summarize(period_id, status, &rows))
 }
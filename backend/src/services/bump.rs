@@ -0,0 +1,430 @@
+//! Bump request resolution: the assignment swap shared by supervisor review and
+//! the deadline sweep, the per-event audit trail, and outcome notifications.
+//!
+//! Pending bump requests carry an `expires_at` deadline (`bump_review_hours`
+//! after submission, capped one sweep before shift start). Requests still pending at the
+//! deadline expire, unless the org's `bump_auto_approve` setting is on — then
+//! the bumper wins by default and the swap is performed as if approved.
+
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::api::notifications::{create_notification, CreateNotificationParams};
+use crate::error::{AppError, Result};
+use crate::models::callout::{BumpAuditAction, BumpRequest, BumpSweepResult, CalloutStatus};
+
+/// Hours a bump request waits for review before the deadline sweep resolves it.
+pub async fn review_hours(pool: &PgPool, org_id: Uuid) -> i64 {
+    crate::services::org_settings::get_i64(pool, org_id, "bump_review_hours", 12)
+        .await
+        .max(1)
+}
+
+/// How often the background sweep resolves overdue bump requests.
+pub const SWEEP_INTERVAL: time::Duration = time::Duration::minutes(15);
+
+/// Deadline for a bump request submitted at `now`: the review window, cut short
+/// to one sweep interval before shift start so the sweep reaches the request
+/// while the swap can still be made. Inside that interval it is due at once.
+pub fn review_deadline(
+    now: time::OffsetDateTime,
+    review_hours: i64,
+    shift_start: time::OffsetDateTime,
+) -> time::OffsetDateTime {
+    (now + time::Duration::hours(review_hours))
+        .min(shift_start - SWEEP_INTERVAL)
+        .max(now)
+}
+
+/// Append an entry to the event's bump history.
+pub async fn record_audit(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    br: &BumpRequest,
+    action: BumpAuditAction,
+    actor_id: Option<Uuid>,
+    notes: Option<&str>,
+) -> Result<()> {
+    sqlx::query!(
+        r#"
+        INSERT INTO bump_request_audit (org_id, bump_request_id, event_id, action, actor_id, notes)
+        VALUES ($1, $2, $3, $4, $5, $6)
+        "#,
+        br.org_id,
+        br.id,
+        br.event_id,
+        action as BumpAuditAction,
+        actor_id,
+        notes,
+    )
+    .execute(&mut **tx)
+    .await?;
+    Ok(())
+}
+
+/// Swap the displaced employee's OT assignment to the requester and move OT
+/// hours and queue positions accordingly. `actor_id` is recorded as the new
+/// assignment's creator. Returns `Conflict` when the swap is no longer possible.
+pub async fn apply_approval(
+    pool: &PgPool,
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    br: &BumpRequest,
+    org_timezone: &str,
+    actor_id: Uuid,
+) -> Result<()> {
+    // Verify shift hasn't started and event is still filled; also fetch classification
+    // and duration for OT hour accounting on bump approval.
+    let shift_check = sqlx::query!(
+        r#"
+        SELECT ce.scheduled_shift_id, ss.date AS shift_date, st.start_time,
+               ce.status AS "status: CalloutStatus",
+               ce.classification_id,
+               st.duration_minutes
+        FROM callout_events ce
+        JOIN scheduled_shifts ss ON ss.id = ce.scheduled_shift_id
+        JOIN shift_templates st ON st.id = ss.shift_template_id
+        WHERE ce.id = $1
+        "#,
+        br.event_id
+    )
+    .fetch_one(&mut **tx)
+    .await?;
+    let scheduled_shift_id = shift_check.scheduled_shift_id;
+
+    if shift_check.status != CalloutStatus::Filled {
+        return Err(AppError::Conflict(
+            "Callout event is no longer filled".into(),
+        ));
+    }
+    let shift_start = crate::services::timezone::local_to_utc(
+        shift_check.shift_date,
+        shift_check.start_time,
+        org_timezone,
+    );
+    if time::OffsetDateTime::now_utc() >= shift_start {
+        return Err(AppError::Conflict(
+            "Cannot approve bump after the shift has started".into(),
+        ));
+    }
+
+    // Re-verify requesting user is still active in this org.
+    let requester_ok = sqlx::query_scalar!(
+        "SELECT EXISTS(SELECT 1 FROM users WHERE id = $1 AND org_id = $2 AND is_active = true)",
+        br.requesting_user_id,
+        br.org_id,
+    )
+    .fetch_one(&mut **tx)
+    .await?
+    .unwrap_or(false);
+
+    if !requester_ok {
+        return Err(AppError::Conflict(
+            "Requesting user is no longer active".into(),
+        ));
+    }
+
+    // Fetch ot_type from displaced user's assignment, then cancel it.
+    let displaced_ot_type: Option<String> = sqlx::query_scalar!(
+        r#"
+        SELECT ot_type FROM assignments
+        WHERE scheduled_shift_id = $1 AND user_id = $2
+          AND is_overtime = true AND cancelled_at IS NULL
+        "#,
+        scheduled_shift_id,
+        br.displaced_user_id,
+    )
+    .fetch_optional(&mut **tx)
+    .await?
+    .flatten();
+
+    let cancelled = sqlx::query!(
+        r#"
+        UPDATE assignments SET cancelled_at = NOW()
+        WHERE scheduled_shift_id = $1 AND user_id = $2
+          AND is_overtime = true AND cancelled_at IS NULL
+        "#,
+        scheduled_shift_id,
+        br.displaced_user_id,
+    )
+    .execute(&mut **tx)
+    .await?;
+
+    if cancelled.rows_affected() == 0 {
+        return Err(AppError::Conflict(
+            "Displaced user's OT assignment is no longer active".into(),
+        ));
+    }
+
+    // Insert new OT assignment for requester, copying ot_type from displaced.
+    let inserted = sqlx::query!(
+        r#"
+        INSERT INTO assignments (id, scheduled_shift_id, user_id, is_overtime, created_by, ot_type)
+        VALUES (gen_random_uuid(), $1, $2, true, $3, $4)
+        ON CONFLICT (scheduled_shift_id, user_id) WHERE cancelled_at IS NULL DO NOTHING
+        "#,
+        scheduled_shift_id,
+        br.requesting_user_id,
+        actor_id,
+        displaced_ot_type,
+    )
+    .execute(&mut **tx)
+    .await?;
+
+    if inserted.rows_affected() == 0 {
+        return Err(AppError::Conflict(
+            "Requesting user already has an assignment for this shift".into(),
+        ));
+    }
+
+    // Update OT hours and queue positions for the bump swap.
+    let shift_hours = shift_check.duration_minutes as f64 / 60.0;
+    let fiscal_year =
+        crate::services::ot::org_fiscal_year(pool, br.org_id, shift_check.shift_date).await;
+
+    // Revert displaced user's OT hours (they're no longer working this shift).
+    crate::services::ot::revert_ot_hours_worked(
+        tx, br.displaced_user_id, fiscal_year,
+        Some(shift_check.classification_id), shift_hours,
+    ).await?;
+
+    // Credit requesting user's OT hours (they're taking over the shift).
+    crate::services::ot::upsert_ot_hours_worked(
+        tx, br.requesting_user_id, fiscal_year,
+        shift_check.classification_id, shift_hours,
+    ).await?;
+
+    // Stamp requesting user's queue position (they were contacted/assigned OT).
+    crate::services::ot::stamp_ot_queue(
+        tx, br.org_id, shift_check.classification_id,
+        br.requesting_user_id, fiscal_year,
+    ).await?;
+
+    // Restore displaced user's queue position — they were bumped off, not
+    // contacted for new OT, so their last_ot_event_at should be reset.
+    sqlx::query!(
+        r#"
+        UPDATE ot_queue_positions
+        SET last_ot_event_at = NULL, updated_at = NOW()
+        WHERE user_id = $1 AND classification_id = $2 AND org_id = $3 AND fiscal_year = $4
+        "#,
+        br.displaced_user_id,
+        shift_check.classification_id,
+        br.org_id,
+        fiscal_year,
+    )
+    .execute(&mut **tx)
+    .await?;
+
+    Ok(())
+}
+
+/// Tell both the requesting and the displaced employee how a bump request ended.
+/// Best-effort: notification failures never fail the resolution.
+pub async fn notify_outcome(pool: &PgPool, br: &BumpRequest, action: BumpAuditAction) {
+    let shift_date = sqlx::query_scalar!(
+        r#"
+        SELECT ss.date FROM callout_events ce
+        JOIN scheduled_shifts ss ON ss.id = ce.scheduled_shift_id
+        WHERE ce.id = $1
+        "#,
+        br.event_id,
+    )
+    .fetch_optional(pool)
+    .await
+    .ok()
+    .flatten();
+    let on = shift_date.map(|d| format!(" on {d}")).unwrap_or_default();
+
+    let (requester, displaced) = match action {
+        BumpAuditAction::Approved | BumpAuditAction::AutoApproved => (
+            (
+                "Bump approved",
+                format!("Your bump request was approved; you now hold the OT shift{on}."),
+            ),
+            (
+                "You were bumped from OT",
+                format!("A bump request was approved and you were removed from the OT shift{on}."),
+            ),
+        ),
+        BumpAuditAction::Denied => (
+            ("Bump denied", format!("Your bump request for the OT shift{on} was denied.")),
+            (
+                "Bump request denied",
+                format!("A bump request against your OT shift{on} was denied; you keep the shift."),
+            ),
+        ),
+        BumpAuditAction::Expired => (
+            (
+                "Bump request expired",
+                format!("Your bump request for the OT shift{on} was not reviewed in time and expired."),
+            ),
+            (
+                "Bump request expired",
+                format!("A bump request against your OT shift{on} expired; you keep the shift."),
+            ),
+        ),
+        BumpAuditAction::Submitted => return,
+    };
+
+    for (user_id, (title, message)) in [
+        (br.requesting_user_id, requester),
+        (br.displaced_user_id, displaced),
+    ] {
+        let _ = create_notification(
+            pool,
+            CreateNotificationParams {
+                org_id: br.org_id,
+                user_id,
+                notification_type: "bump_resolved",
+                title,
+                message: &message,
+                link: Some("/schedule"),
+                source_type: Some("bump_request"),
+                source_id: Some(br.id),
+            },
+        )
+        .await;
+    }
+}
+
+/// Mark a pending request expired and record why.
+async fn expire(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    br: &BumpRequest,
+    notes: Option<&str>,
+) -> Result<()> {
+    sqlx::query!(
+        r#"
+        UPDATE bump_requests
+        SET status = 'expired', reviewed_at = NOW(), auto_resolved = true
+        WHERE id = $1
+        "#,
+        br.id,
+    )
+    .execute(&mut **tx)
+    .await?;
+    record_audit(tx, br, BumpAuditAction::Expired, None, notes).await
+}
+
+/// Resolve the org's pending bump requests whose deadline has passed.
+///
+/// With `bump_auto_approve` on, the swap is attempted first; requests whose swap
+/// is no longer possible (shift started, assignment gone) expire instead.
+pub async fn resolve_expired(
+    pool: &PgPool,
+    org_id: Uuid,
+    org_timezone: &str,
+) -> Result<BumpSweepResult> {
+    let auto_approve =
+        crate::services::org_settings::get_bool(pool, org_id, "bump_auto_approve", false).await;
+
+    let due = sqlx::query!(
+        r#"
+        SELECT br.id, ce.initiated_by
+        FROM bump_requests br
+        JOIN callout_events ce ON ce.id = br.event_id
+        WHERE br.org_id = $1 AND br.status = 'pending' AND br.expires_at <= NOW()
+        ORDER BY br.expires_at
+        "#,
+        org_id,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let mut result = BumpSweepResult::default();
+    for row in due {
+        let mut tx = pool.begin().await?;
+        let br = sqlx::query_as!(
+            BumpRequest,
+            r#"
+            SELECT id, org_id, event_id, requesting_user_id, displaced_user_id, status,
+                   reason, created_at, reviewed_at, reviewed_by, expires_at, auto_resolved
+            FROM bump_requests WHERE id = $1 FOR UPDATE
+            "#,
+            row.id,
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+        if br.status != "pending" {
+            continue;
+        }
+
+        let mut failure = None;
+        if auto_approve {
+            match apply_approval(pool, &mut tx, &br, org_timezone, row.initiated_by).await {
+                Ok(()) => {
+                    sqlx::query!(
+                        r#"
+                        UPDATE bump_requests
+                        SET status = 'approved', reviewed_at = NOW(), auto_resolved = true
+                        WHERE id = $1
+                        "#,
+                        br.id,
+                    )
+                    .execute(&mut *tx)
+                    .await?;
+                    record_audit(&mut tx, &br, BumpAuditAction::AutoApproved, None, None).await?;
+                    tx.commit().await?;
+                    notify_outcome(pool, &br, BumpAuditAction::AutoApproved).await;
+                    result.auto_approved += 1;
+                    continue;
+                }
+                Err(AppError::Conflict(msg)) => {
+                    // Undo any partial swap before expiring
+                    tx.rollback().await?;
+                    tx = pool.begin().await?;
+                    sqlx::query!("SELECT id FROM bump_requests WHERE id = $1 FOR UPDATE", br.id)
+                        .fetch_one(&mut *tx)
+                        .await?;
+                    failure = Some(msg);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        expire(&mut tx, &br, failure.as_deref()).await?;
+        tx.commit().await?;
+        notify_outcome(pool, &br, BumpAuditAction::Expired).await;
+        result.expired += 1;
+    }
+
+    Ok(result)
+}
+
+/// Run the deadline sweep for every org.
+pub async fn run_all_orgs(pool: &PgPool) -> Result<()> {
+    let orgs = sqlx::query!("SELECT id, name, timezone FROM organizations")
+        .fetch_all(pool)
+        .await?;
+
+    for org in orgs {
+        match resolve_expired(pool, org.id, &org.timezone).await {
+            Ok(r) if r.expired + r.auto_approved > 0 => {
+                tracing::info!(
+                    org = %org.name,
+                    expired = r.expired,
+                    auto_approved = r.auto_approved,
+                    "Bump deadline sweep complete"
+                );
+            }
+            Ok(_) => {}
+            Err(e) => {
+                tracing::error!(org_id = %org.id, error = %e, "Bump deadline sweep failed for org");
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Background task: resolves overdue bump requests every `SWEEP_INTERVAL`.
+pub async fn background_bump_task(pool: PgPool) {
+    let mut interval = tokio::time::interval(SWEEP_INTERVAL.unsigned_abs());
+
+    loop {
+        interval.tick().await;
+
+        tracing::debug!("Background bump deadline sweep starting");
+        if let Err(e) = run_all_orgs(&pool).await {
+            tracing::error!(error = %e, "Background bump deadline sweep failed");
+        }
+    }
+}
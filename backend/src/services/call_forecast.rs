@@ -0,0 +1,281 @@
+//! Call-volume history import and seasonal forecast.
+//!
+//! The forecast for a date is the per-slot average of the same weekday over
+//! the last `call_forecast_lookback_weeks` weeks of history (holidays
+//! excluded). On holiday_calendar dates the result is scaled by how past
+//! holidays compared with their own weekday baseline.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use sqlx::PgPool;
+use time::{Date, Duration};
+use uuid::Uuid;
+
+use crate::{
+    error::{AppError, Result},
+    models::call_volume::{CallForecastDay, ForecastSlot},
+    services::{org_settings, timezone},
+};
+
+const DATE_FMT: &[time::format_description::FormatItem<'static>] =
+    time::macros::format_description!("[year]-[month]-[day]");
+
+/// Half-hour slot for `HH:MM[:SS]`, rounding down.
+fn time_to_slot(s: &str) -> Option<i16> {
+    let mut parts = s.split(':');
+    let hour: i16 = parts.next()?.trim().parse().ok()?;
+    let minute: i16 = parts.next()?.trim().parse().ok()?;
+    if !(0..24).contains(&hour) || !(0..60).contains(&minute) {
+        return None;
+    }
+    Some(hour * 2 + minute / 30)
+}
+
+/// Parse a CAD/ALI export into call counts per (date, slot).
+///
+/// Accepted row shapes: `date,time,calls`, `date,time` (one call) and
+/// `timestamp[,calls]` where the timestamp is `YYYY-MM-DD HH:MM[:SS]` or
+/// `YYYY-MM-DDTHH:MM[:SS]`. A header row is skipped.
+pub fn parse_import_csv(csv: &str) -> Result<BTreeMap<(Date, i16), i64>> {
+    let mut counts: BTreeMap<(Date, i16), i64> = BTreeMap::new();
+
+    for (line_no, line) in csv.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let fields: Vec<&str> = line.split(',').map(|f| f.trim().trim_matches('"')).collect();
+
+        // Split a combined timestamp into date + time fields
+        let (date_str, time_str, rest) = match fields[0].split_once([' ', 'T']) {
+            Some((d, t)) => (d, Some(t), &fields[1..]),
+            None => (fields[0], fields.get(1).copied(), fields.get(2..).unwrap_or(&[])),
+        };
+
+        let date = match Date::parse(date_str, DATE_FMT) {
+            Ok(d) => d,
+            Err(_) if line_no == 0 => continue,
+            Err(_) => {
+                return Err(AppError::BadRequest(format!(
+                    "CSV line {}: invalid date `{}` (use YYYY-MM-DD)",
+                    line_no + 1,
+                    date_str
+                )))
+            }
+        };
+        let slot = time_str.and_then(time_to_slot).ok_or_else(|| {
+            AppError::BadRequest(format!("CSV line {}: invalid or missing time", line_no + 1))
+        })?;
+        let calls = match rest.first().filter(|c| !c.is_empty()) {
+            Some(c) => c.parse::<i64>().ok().filter(|c| *c >= 0).ok_or_else(|| {
+                AppError::BadRequest(format!(
+                    "CSV line {}: invalid call count `{}`",
+                    line_no + 1,
+                    c
+                ))
+            })?,
+            None => 1,
+        };
+
+        *counts.entry((date, slot)).or_insert(0) += calls;
+    }
+
+    if counts.is_empty() {
+        return Err(AppError::BadRequest("CSV contains no call volume rows".into()));
+    }
+
+    Ok(counts)
+}
+
+/// Replace stored history for every date present in `counts`.
+pub async fn import(
+    pool: &PgPool,
+    org_id: Uuid,
+    imported_by: Uuid,
+    counts: &BTreeMap<(Date, i16), i64>,
+) -> Result<()> {
+    let dates: Vec<Date> = counts
+        .keys()
+        .map(|(d, _)| *d)
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+    let row_dates: Vec<Date> = counts.keys().map(|(d, _)| *d).collect();
+    let slots: Vec<i16> = counts.keys().map(|(_, s)| *s).collect();
+    let calls: Vec<i32> = counts
+        .values()
+        .map(|c| i32::try_from(*c).unwrap_or(i32::MAX))
+        .collect();
+
+    let mut tx = pool.begin().await?;
+
+    sqlx::query!(
+        "DELETE FROM call_volume_history WHERE org_id = $1 AND date = ANY($2)",
+        org_id,
+        &dates,
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query!(
+        r#"
+        INSERT INTO call_volume_history (org_id, date, slot_index, calls, imported_by)
+        SELECT $1, u.date, u.slot, u.calls, $2
+        FROM UNNEST($3::date[], $4::smallint[], $5::int[]) AS u(date, slot, calls)
+        "#,
+        org_id,
+        imported_by,
+        &row_dates,
+        &slots,
+        &calls,
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+    Ok(())
+}
+
+/// Same-weekday, non-holiday history dates in `[anchor - weeks, anchor)`.
+fn sample_dates(
+    anchor: Date,
+    weekday: time::Weekday,
+    lookback_weeks: i64,
+    daily_totals: &HashMap<Date, i64>,
+    holidays: &HashSet<Date>,
+) -> Vec<Date> {
+    // Nearest matching weekday strictly before the anchor, then whole weeks back
+    let offset = (anchor.weekday().number_days_from_sunday() as i64
+        - weekday.number_days_from_sunday() as i64
+        + 7)
+        % 7;
+    let offset = if offset == 0 { 7 } else { offset };
+    (0..lookback_weeks)
+        .filter_map(|w| anchor.checked_sub(Duration::days(offset + w * 7)))
+        .filter(|d| daily_totals.contains_key(d) && !holidays.contains(d))
+        .collect()
+}
+
+/// Forecast call volume for each date (in input order).
+pub async fn forecast(
+    pool: &PgPool,
+    org_id: Uuid,
+    org_timezone: &str,
+    dates: &[Date],
+) -> Result<Vec<CallForecastDay>> {
+    if dates.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let lookback_weeks =
+        org_settings::get_i64(pool, org_id, "call_forecast_lookback_weeks", 8).await.clamp(1, 52);
+    let today = timezone::org_today(org_timezone);
+
+    let daily_totals: HashMap<Date, i64> = sqlx::query!(
+        r#"
+        SELECT date, SUM(calls)::BIGINT AS "total!"
+        FROM call_volume_history
+        WHERE org_id = $1
+        GROUP BY date
+        "#,
+        org_id,
+    )
+    .fetch_all(pool)
+    .await?
+    .into_iter()
+    .map(|r| (r.date, r.total))
+    .collect();
+
+    let holidays: HashSet<Date> = sqlx::query_scalar!(
+        "SELECT date FROM holiday_calendar WHERE org_id = $1",
+        org_id,
+    )
+    .fetch_all(pool)
+    .await?
+    .into_iter()
+    .collect();
+
+    // Holiday factor: mean ratio of past holiday volume to its weekday baseline
+    let mut ratios = Vec::new();
+    for h in holidays.iter().filter(|h| **h < today) {
+        let Some(&total) = daily_totals.get(h) else {
+            continue;
+        };
+        let samples = sample_dates(*h, h.weekday(), lookback_weeks, &daily_totals, &holidays);
+        if samples.is_empty() {
+            continue;
+        }
+        let baseline =
+            samples.iter().map(|d| daily_totals[d]).sum::<i64>() as f64 / samples.len() as f64;
+        if baseline > 0.0 {
+            ratios.push(total as f64 / baseline);
+        }
+    }
+    let holiday_factor = if ratios.is_empty() {
+        1.0
+    } else {
+        ratios.iter().sum::<f64>() / ratios.len() as f64
+    };
+
+    // Per-date sample sets, then one query for all slot rows they need
+    let per_date: Vec<(Date, Vec<Date>)> = dates
+        .iter()
+        .map(|&d| {
+            let anchor = d.min(today);
+            (d, sample_dates(anchor, d.weekday(), lookback_weeks, &daily_totals, &holidays))
+        })
+        .collect();
+    let needed: Vec<Date> = per_date
+        .iter()
+        .flat_map(|(_, s)| s.iter().copied())
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+
+    let mut slot_calls: HashMap<(Date, i16), i32> = HashMap::new();
+    if !needed.is_empty() {
+        let rows = sqlx::query!(
+            "SELECT date, slot_index, calls FROM call_volume_history
+             WHERE org_id = $1 AND date = ANY($2)",
+            org_id,
+            &needed,
+        )
+        .fetch_all(pool)
+        .await?;
+        for r in rows {
+            slot_calls.insert((r.date, r.slot_index), r.calls);
+        }
+    }
+
+    Ok(per_date
+        .into_iter()
+        .map(|(date, samples)| {
+            let is_holiday = holidays.contains(&date);
+            let factor = if is_holiday { holiday_factor } else { 1.0 };
+            let slots: Vec<ForecastSlot> = if samples.is_empty() {
+                vec![]
+            } else {
+                (0..48i16)
+                    .map(|slot| {
+                        let sum: i64 = samples
+                            .iter()
+                            .map(|d| slot_calls.get(&(*d, slot)).copied().unwrap_or(0) as i64)
+                            .sum();
+                        ForecastSlot {
+                            slot_index: slot,
+                            calls: sum as f64 / samples.len() as f64 * factor,
+                        }
+                    })
+                    .collect()
+            };
+            CallForecastDay {
+                date,
+                is_holiday,
+                holiday_factor: factor,
+                sample_days: samples.len() as i32,
+                total_calls: slots.iter().map(|s| s.calls).sum(),
+                slots,
+            }
+        })
+        .collect())
+}
@@ -0,0 +1,202 @@
+//! Callout policy resolution and interpretation.
+//!
+//! A policy lists the callout steps in order; each step carries the sort keys
+//! and eligibility filters used to order the callout list while the callout is
+//! on that step. The built-in policy reproduces the Article 15 ordering used
+//! before policies were configurable.
+
+use std::cmp::Ordering;
+
+use sqlx::PgPool;
+use time::{Date, OffsetDateTime};
+use uuid::Uuid;
+
+use crate::error::{AppError, Result};
+use crate::models::callout_policy::{
+    CalloutPolicyStep, CalloutSortField, CalloutSortKey, CalloutStepFilters,
+    EffectiveCalloutPolicy, SortDirection,
+};
+use crate::models::ot::CalloutStep;
+
+/// CBA (Article 15 — Overtime Distribution): the five steps all order the list
+/// by standing availability, OT queue position, accumulated OT hours, and
+/// inverse overall seniority.
+pub fn builtin_steps() -> Vec<CalloutPolicyStep> {
+    let sort = vec![
+        CalloutSortKey { field: CalloutSortField::StandingAvailability, direction: None },
+        CalloutSortKey { field: CalloutSortField::LastContact, direction: None },
+        CalloutSortKey { field: CalloutSortField::OtHours, direction: None },
+        CalloutSortKey { field: CalloutSortField::OverallSeniority, direction: None },
+    ];
+    [
+        CalloutStep::Volunteers,
+        CalloutStep::LowOtHours,
+        CalloutStep::InverseSeniority,
+        CalloutStep::EqualOtHours,
+        CalloutStep::Mandatory,
+    ]
+    .into_iter()
+    .map(|step| CalloutPolicyStep {
+        step,
+        label: None,
+        sort: sort.clone(),
+        filters: CalloutStepFilters::default(),
+    })
+    .collect()
+}
+
+/// Check a step list before storing it.
+pub fn validate_steps(steps: &[CalloutPolicyStep]) -> Result<()> {
+    if steps.is_empty() {
+        return Err(AppError::BadRequest("A policy needs at least one step".into()));
+    }
+    let mut seen = Vec::new();
+    for s in steps {
+        if seen.contains(&s.step) {
+            return Err(AppError::BadRequest(format!(
+                "Step {:?} appears more than once",
+                s.step
+            )));
+        }
+        seen.push(s.step.clone());
+
+        if s.label.as_deref().is_some_and(|l| l.trim().is_empty() || l.len() > 100) {
+            return Err(AppError::BadRequest(
+                "Step labels must be 1-100 characters".into(),
+            ));
+        }
+        let mut fields = Vec::new();
+        for k in &s.sort {
+            if fields.contains(&k.field) {
+                return Err(AppError::BadRequest(format!(
+                    "Sort key {:?} appears more than once in a step",
+                    k.field
+                )));
+            }
+            fields.push(k.field);
+        }
+        if s.filters.max_ot_hours.is_some_and(|h| !(0.0..=10_000.0).contains(&h)) {
+            return Err(AppError::BadRequest(
+                "max_ot_hours must be between 0 and 10000".into(),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Decode a stored `callout_policies.steps` value.
+pub fn parse_steps(steps: serde_json::Value) -> Result<Vec<CalloutPolicyStep>> {
+    serde_json::from_value(steps)
+        .map_err(|e| AppError::Internal(anyhow::anyhow!("Invalid stored callout policy: {e}")))
+}
+
+/// Resolve the policy for a classification: its bargaining unit's policy, else
+/// the org default, else the built-in policy.
+pub async fn effective_policy(
+    pool: &PgPool,
+    org_id: Uuid,
+    classification_id: Uuid,
+) -> Result<EffectiveCalloutPolicy> {
+    let row = sqlx::query!(
+        r#"
+        SELECT p.id, p.bargaining_unit, p.name, p.steps
+        FROM classifications c
+        JOIN callout_policies p ON p.org_id = c.org_id
+            AND (p.bargaining_unit = c.bargaining_unit OR p.bargaining_unit IS NULL)
+        WHERE c.id = $1 AND c.org_id = $2
+        -- Bargaining unit policy wins over the org default
+        ORDER BY (p.bargaining_unit IS NULL)
+        LIMIT 1
+        "#,
+        classification_id,
+        org_id,
+    )
+    .fetch_optional(pool)
+    .await?;
+
+    match row {
+        Some(r) => {
+            Ok(EffectiveCalloutPolicy {
+                policy_id: Some(r.id),
+                bargaining_unit: r.bargaining_unit,
+                name: r.name,
+                steps: parse_steps(r.steps)?,
+            })
+        }
+        None => Ok(EffectiveCalloutPolicy {
+            policy_id: None,
+            bargaining_unit: None,
+            name: "Article 15 (built-in)".into(),
+            steps: builtin_steps(),
+        }),
+    }
+}
+
+impl EffectiveCalloutPolicy {
+    /// The policy step for the callout's current step. Callouts that haven't
+    /// started a step, or whose step isn't in the policy, use the first step.
+    pub fn step_for(&self, current: Option<&CalloutStep>) -> &CalloutPolicyStep {
+        current
+            .and_then(|c| self.steps.iter().find(|s| &s.step == c))
+            .unwrap_or(&self.steps[0])
+    }
+
+    pub fn contains(&self, step: &CalloutStep) -> bool {
+        self.steps.iter().any(|s| &s.step == step)
+    }
+}
+
+/// Per-employee values read by sort keys.
+pub struct SortValues {
+    pub ot_hours: f64,
+    pub ot_hours_declined: f64,
+    pub last_contact: Option<OffsetDateTime>,
+    pub overall_seniority: Option<Date>,
+    pub bargaining_unit_seniority: Option<Date>,
+    pub classification_seniority: Option<Date>,
+    pub mandatory_rotation: Option<OffsetDateTime>,
+    pub standing_availability: bool,
+    pub volunteered: bool,
+}
+
+/// Missing seniority dates sort as the most recent date (least senior).
+fn cmp_seniority(a: Option<Date>, b: Option<Date>) -> Ordering {
+    (a.is_none(), a).cmp(&(b.is_none(), b))
+}
+
+/// Compare two employees by a step's sort keys, in order.
+pub fn compare(a: &SortValues, b: &SortValues, keys: &[CalloutSortKey]) -> Ordering {
+    for key in keys {
+        let ord = match key.field {
+            CalloutSortField::OtHours => a.ot_hours.partial_cmp(&b.ot_hours).unwrap_or(Ordering::Equal),
+            CalloutSortField::OtHoursDeclined => a
+                .ot_hours_declined
+                .partial_cmp(&b.ot_hours_declined)
+                .unwrap_or(Ordering::Equal),
+            // Never contacted / never forced (None) sorts as earliest
+            CalloutSortField::LastContact => a.last_contact.cmp(&b.last_contact),
+            CalloutSortField::MandatoryRotation => a.mandatory_rotation.cmp(&b.mandatory_rotation),
+            CalloutSortField::OverallSeniority => {
+                cmp_seniority(a.overall_seniority, b.overall_seniority)
+            }
+            CalloutSortField::BargainingUnitSeniority => {
+                cmp_seniority(a.bargaining_unit_seniority, b.bargaining_unit_seniority)
+            }
+            CalloutSortField::ClassificationSeniority => {
+                cmp_seniority(a.classification_seniority, b.classification_seniority)
+            }
+            CalloutSortField::StandingAvailability => {
+                a.standing_availability.cmp(&b.standing_availability)
+            }
+            CalloutSortField::Volunteered => a.volunteered.cmp(&b.volunteered),
+        };
+        let ord = match key.direction.unwrap_or_else(|| key.field.default_direction()) {
+            SortDirection::Asc => ord,
+            SortDirection::Desc => ord.reverse(),
+        };
+        if ord != Ordering::Equal {
+            return ord;
+        }
+    }
+    Ordering::Equal
+}
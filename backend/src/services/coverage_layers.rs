@@ -0,0 +1,471 @@
+//! Layered resolution of per-slot coverage requirements.
+//!
+//! For each date the requirement set is built bottom-up:
+//! 1. the coverage plan slots for the date's weekday (assignment range, else org default plan),
+//! 2. the holiday coverage profile when the date is in `holiday_calendar` — it
+//!    replaces the plan rows of every classification it defines,
+//! 3. date-specific overrides, applied in creation order. Windows that cross
+//!    midnight contribute to the next day's early slots.
+//!
+//! Qualification requirements ("at least one EMD-certified per slot") come from
+//! the plan layer only.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use sqlx::PgPool;
+use time::Date;
+use uuid::Uuid;
+
+use crate::{
+    api::helpers::{overnight_end_slot, time_to_slot_range},
+    error::Result,
+    models::coverage_override::{CoverageOverrideMode, CoverageSource},
+};
+
+/// One resolved (classification, slot) requirement and the layer it came from.
+#[derive(Debug, Clone)]
+pub(crate) struct RequiredSlot {
+    pub slot_index: i16,
+    pub classification_id: Uuid,
+    pub classification_abbreviation: String,
+    pub min_headcount: i16,
+    pub target_headcount: i16,
+    pub max_headcount: i16,
+    pub source: CoverageSource,
+}
+
+struct OverrideRow {
+    date: Date,
+    classification_id: Uuid,
+    classification_abbreviation: String,
+    start_time: time::Time,
+    end_time: time::Time,
+    mode: CoverageOverrideMode,
+    min_headcount: i16,
+    target_headcount: i16,
+    max_headcount: Option<i16>,
+}
+
+impl OverrideRow {
+    fn crosses_midnight(&self) -> bool {
+        self.end_time <= self.start_time
+    }
+
+    fn apply(&self, base: Option<&RequiredSlot>, slot_index: i16) -> RequiredSlot {
+        let (min, target, max) = match self.mode {
+            CoverageOverrideMode::Add => {
+                let (b_min, b_target, b_max) = base
+                    .map(|b| (b.min_headcount, b.target_headcount, b.max_headcount))
+                    .unwrap_or((0, 0, 0));
+                let min = (b_min + self.min_headcount).max(0);
+                let target = (b_target + self.target_headcount).max(min);
+                let max = (b_max + self.max_headcount.unwrap_or(self.target_headcount)).max(target);
+                (min, target, max)
+            }
+            CoverageOverrideMode::Set => {
+                let b_max = base.map(|b| b.max_headcount).unwrap_or(0);
+                let target = self.target_headcount;
+                (self.min_headcount, target, self.max_headcount.unwrap_or(b_max).max(target))
+            }
+        };
+        RequiredSlot {
+            slot_index,
+            classification_id: self.classification_id,
+            classification_abbreviation: base
+                .map(|b| b.classification_abbreviation.clone())
+                .unwrap_or_else(|| self.classification_abbreviation.clone()),
+            min_headcount: min,
+            target_headcount: target,
+            max_headcount: max,
+            source: CoverageSource::Override,
+        }
+    }
+}
+
+/// The coverage plan in effect on each date: the most recent active assignment
+/// covering it, else the org's default plan. Dates with neither are absent.
+async fn plans_for_dates(pool: &PgPool, org_id: Uuid, dates: &[Date]) -> Result<HashMap<Date, Uuid>> {
+    let min_date = *dates.iter().min().unwrap();
+    let max_date = *dates.iter().max().unwrap();
+
+    let plan_assignments = sqlx::query!(
+        r#"
+        SELECT cpa.plan_id, cpa.start_date, cpa.end_date
+        FROM coverage_plan_assignments cpa
+        JOIN coverage_plans cp ON cp.id = cpa.plan_id
+        WHERE cpa.org_id = $1
+          AND cpa.start_date <= $3
+          AND (cpa.end_date IS NULL OR cpa.end_date >= $2)
+          AND cp.is_active = TRUE
+        ORDER BY cpa.start_date DESC
+        "#,
+        org_id,
+        min_date,
+        max_date,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let default_plan_id = sqlx::query_scalar!(
+        r#"
+        SELECT id AS "id!"
+        FROM coverage_plans
+        WHERE org_id = $1 AND is_default = TRUE AND is_active = TRUE
+        LIMIT 1
+        "#,
+        org_id,
+    )
+    .fetch_optional(pool)
+    .await?;
+
+    // First matching assignment wins due to ORDER BY start_date DESC
+    let mut plan_per_date: HashMap<Date, Uuid> = HashMap::new();
+    for &d in dates {
+        if let Some(pa) = plan_assignments
+            .iter()
+            .find(|pa| pa.start_date <= d && pa.end_date.is_none_or(|ed| ed >= d))
+        {
+            plan_per_date.insert(d, pa.plan_id);
+        } else if let Some(id) = default_plan_id {
+            plan_per_date.insert(d, id);
+        }
+    }
+    Ok(plan_per_date)
+}
+
+/// Resolve coverage requirements for each date. Dates with no requirement in
+/// any layer are absent from the map. Rows are ordered by classification, then slot.
+pub(crate) async fn resolve_requirements(
+    pool: &PgPool,
+    org_id: Uuid,
+    dates: &[Date],
+) -> Result<HashMap<Date, Vec<RequiredSlot>>> {
+    if dates.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    let min_date = *dates.iter().min().unwrap();
+    let max_date = *dates.iter().max().unwrap();
+    let prev_min = min_date.previous_day().unwrap_or(min_date);
+
+    // ── Layer 1: coverage plan ───────────────────────────────────────────────
+    let plan_per_date = plans_for_dates(pool, org_id, dates).await?;
+
+    let needed_plan_ids: Vec<Uuid> = plan_per_date
+        .values()
+        .copied()
+        .collect::<HashSet<Uuid>>()
+        .into_iter()
+        .collect();
+    let needed_dows: Vec<i16> = dates
+        .iter()
+        .map(|d| d.weekday().number_days_from_sunday() as i16)
+        .collect::<HashSet<i16>>()
+        .into_iter()
+        .collect();
+
+    let plan_slots = if needed_plan_ids.is_empty() {
+        vec![]
+    } else {
+        sqlx::query!(
+            r#"
+            SELECT
+                cps.plan_id,
+                cps.slot_index,
+                cps.day_of_week,
+                cps.classification_id,
+                cl.abbreviation AS classification_abbreviation,
+                cps.min_headcount,
+                cps.target_headcount,
+                cps.max_headcount
+            FROM coverage_plan_slots cps
+            JOIN classifications cl ON cl.id = cps.classification_id
+            WHERE cps.plan_id = ANY($1)
+              AND cps.day_of_week = ANY($2)
+            "#,
+            &needed_plan_ids as &[Uuid],
+            &needed_dows as &[i16],
+        )
+        .fetch_all(pool)
+        .await?
+    };
+
+    let mut slots_by_plan_dow: HashMap<(Uuid, i16), Vec<_>> = HashMap::new();
+    for s in &plan_slots {
+        slots_by_plan_dow
+            .entry((s.plan_id, s.day_of_week))
+            .or_default()
+            .push(s);
+    }
+
+    // ── Layer 2: holiday coverage profiles ───────────────────────────────────
+    let holidays = sqlx::query!(
+        r#"
+        SELECT
+            hc.date,
+            COALESCE(
+                hc.coverage_profile_id,
+                (SELECT id FROM holiday_coverage_profiles
+                 WHERE org_id = $1 AND is_default = TRUE)
+            ) AS profile_id
+        FROM holiday_calendar hc
+        WHERE hc.org_id = $1 AND hc.date = ANY($2)
+        "#,
+        org_id,
+        dates,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let profile_per_date: HashMap<Date, Uuid> = holidays
+        .iter()
+        .filter_map(|h| h.profile_id.map(|p| (h.date, p)))
+        .collect();
+    let needed_profile_ids: Vec<Uuid> = profile_per_date
+        .values()
+        .copied()
+        .collect::<HashSet<Uuid>>()
+        .into_iter()
+        .collect();
+
+    let profile_slots = if needed_profile_ids.is_empty() {
+        vec![]
+    } else {
+        sqlx::query!(
+            r#"
+            SELECT
+                hps.profile_id,
+                hps.slot_index,
+                hps.classification_id,
+                cl.abbreviation AS classification_abbreviation,
+                hps.min_headcount,
+                hps.target_headcount,
+                hps.max_headcount
+            FROM holiday_coverage_profile_slots hps
+            JOIN classifications cl ON cl.id = hps.classification_id
+            WHERE hps.profile_id = ANY($1)
+            "#,
+            &needed_profile_ids as &[Uuid],
+        )
+        .fetch_all(pool)
+        .await?
+    };
+
+    let mut slots_by_profile: HashMap<Uuid, Vec<_>> = HashMap::new();
+    for s in &profile_slots {
+        slots_by_profile.entry(s.profile_id).or_default().push(s);
+    }
+
+    // ── Layer 3: date overrides (previous day included for overnight windows) ──
+    let overrides = sqlx::query_as!(
+        OverrideRow,
+        r#"
+        SELECT
+            cdo.date,
+            cdo.classification_id,
+            cl.abbreviation AS classification_abbreviation,
+            cdo.start_time,
+            cdo.end_time,
+            cdo.mode AS "mode: CoverageOverrideMode",
+            cdo.min_headcount,
+            cdo.target_headcount,
+            cdo.max_headcount
+        FROM coverage_date_overrides cdo
+        JOIN classifications cl ON cl.id = cdo.classification_id
+        WHERE cdo.org_id = $1 AND cdo.date BETWEEN $2 AND $3
+        ORDER BY cdo.created_at, cdo.id
+        "#,
+        org_id,
+        prev_min,
+        max_date,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let mut result: HashMap<Date, Vec<RequiredSlot>> = HashMap::new();
+
+    for &d in dates {
+        let mut resolved: BTreeMap<(Uuid, i16), RequiredSlot> = BTreeMap::new();
+
+        let dow = d.weekday().number_days_from_sunday() as i16;
+        if let Some(slots) = plan_per_date
+            .get(&d)
+            .and_then(|plan_id| slots_by_plan_dow.get(&(*plan_id, dow)))
+        {
+            for s in slots {
+                resolved.insert(
+                    (s.classification_id, s.slot_index),
+                    RequiredSlot {
+                        slot_index: s.slot_index,
+                        classification_id: s.classification_id,
+                        classification_abbreviation: s.classification_abbreviation.clone(),
+                        min_headcount: s.min_headcount,
+                        target_headcount: s.target_headcount,
+                        max_headcount: s.max_headcount,
+                        source: CoverageSource::Plan,
+                    },
+                );
+            }
+        }
+
+        if let Some(slots) = profile_per_date
+            .get(&d)
+            .and_then(|profile_id| slots_by_profile.get(profile_id))
+        {
+            let profile_classes: HashSet<Uuid> = slots.iter().map(|s| s.classification_id).collect();
+            resolved.retain(|(class_id, _), _| !profile_classes.contains(class_id));
+            for s in slots {
+                resolved.insert(
+                    (s.classification_id, s.slot_index),
+                    RequiredSlot {
+                        slot_index: s.slot_index,
+                        classification_id: s.classification_id,
+                        classification_abbreviation: s.classification_abbreviation.clone(),
+                        min_headcount: s.min_headcount,
+                        target_headcount: s.target_headcount,
+                        max_headcount: s.max_headcount,
+                        source: CoverageSource::HolidayProfile,
+                    },
+                );
+            }
+        }
+
+        let prev = d.previous_day();
+        for o in &overrides {
+            let range = if o.date == d {
+                Some(time_to_slot_range(o.start_time, o.end_time, o.crosses_midnight()))
+            } else if Some(o.date) == prev && o.crosses_midnight() {
+                overnight_end_slot(o.end_time).map(|end| (0, end))
+            } else {
+                None
+            };
+            let Some((start_slot, end_slot)) = range else {
+                continue;
+            };
+            for slot in start_slot..=end_slot {
+                let key = (o.classification_id, slot);
+                let applied = o.apply(resolved.get(&key), slot);
+                resolved.insert(key, applied);
+            }
+        }
+
+        if !resolved.is_empty() {
+            result.insert(d, resolved.into_values().collect());
+        }
+    }
+
+    Ok(result)
+}
+
+/// One "at least `min_count` people holding the qualification" slot requirement.
+#[derive(Debug, Clone)]
+pub(crate) struct RequiredQualificationSlot {
+    pub slot_index: i16,
+    pub qualification_id: Uuid,
+    pub qualification_name: String,
+    pub min_count: i16,
+}
+
+/// Resolve qualification requirements for each date from the plan in effect.
+/// Holiday profiles and date overrides don't touch them. Dates without any are
+/// absent; rows are ordered by qualification name, then slot.
+pub(crate) async fn resolve_qualification_requirements(
+    pool: &PgPool,
+    org_id: Uuid,
+    dates: &[Date],
+) -> Result<HashMap<Date, Vec<RequiredQualificationSlot>>> {
+    if dates.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    let plan_per_date = plans_for_dates(pool, org_id, dates).await?;
+    let needed_plan_ids: Vec<Uuid> = plan_per_date
+        .values()
+        .copied()
+        .collect::<HashSet<Uuid>>()
+        .into_iter()
+        .collect();
+    if needed_plan_ids.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    let rows = sqlx::query!(
+        r#"
+        SELECT
+            qs.plan_id,
+            qs.day_of_week,
+            qs.slot_index,
+            qs.qualification_id,
+            q.name AS qualification_name,
+            qs.min_count
+        FROM coverage_plan_qualification_slots qs
+        JOIN qualifications q ON q.id = qs.qualification_id
+        WHERE qs.plan_id = ANY($1) AND qs.min_count > 0
+        ORDER BY q.name, qs.qualification_id, qs.slot_index
+        "#,
+        &needed_plan_ids as &[Uuid],
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let mut result: HashMap<Date, Vec<RequiredQualificationSlot>> = HashMap::new();
+    for &d in dates {
+        let Some(plan_id) = plan_per_date.get(&d) else {
+            continue;
+        };
+        let dow = d.weekday().number_days_from_sunday() as i16;
+        let slots: Vec<RequiredQualificationSlot> = rows
+            .iter()
+            .filter(|r| r.plan_id == *plan_id && r.day_of_week == dow)
+            .map(|r| RequiredQualificationSlot {
+                slot_index: r.slot_index,
+                qualification_id: r.qualification_id,
+                qualification_name: r.qualification_name.clone(),
+                min_count: r.min_count,
+            })
+            .collect();
+        if !slots.is_empty() {
+            result.insert(d, slots);
+        }
+    }
+
+    Ok(result)
+}
+
+/// user → qualification → last day the grant counts (None = never expires).
+pub(crate) type HeldQualifications = HashMap<Uuid, HashMap<Uuid, Option<Date>>>;
+
+/// Qualifications held by each user in the org, with their expiry.
+pub(crate) async fn user_qualifications(pool: &PgPool, org_id: Uuid) -> Result<HeldQualifications> {
+    let rows = sqlx::query!(
+        r#"
+        SELECT uq.user_id, uq.qualification_id, uq.expires_on
+        FROM user_qualifications uq
+        JOIN users u ON u.id = uq.user_id
+        WHERE u.org_id = $1
+        "#,
+        org_id,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let mut held: HeldQualifications = HashMap::new();
+    for r in rows {
+        held.entry(r.user_id).or_default().insert(r.qualification_id, r.expires_on);
+    }
+    Ok(held)
+}
+
+/// The qualifications in `held` that haven't expired by `date`.
+pub(crate) fn valid_on(held: &HeldQualifications, date: Date) -> HashMap<Uuid, HashSet<Uuid>> {
+    held.iter()
+        .map(|(user_id, quals)| {
+            let valid = quals
+                .iter()
+                .filter(|(_, expires)| expires.is_none_or(|e| e >= date))
+                .map(|(q, _)| *q)
+                .collect();
+            (*user_id, valid)
+        })
+        .collect()
+}
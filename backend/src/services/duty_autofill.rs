@@ -0,0 +1,366 @@
+//! Duty board auto-fill: places on-shift staff into the open, unstaffed blocks
+//! of every duty position for a date.
+//!
+//! Blocks are filled in time order. Within a block the positions with the fewest
+//! eligible people go first, and each picks, in order of preference:
+//! 1. someone who hasn't already sat at that position for
+//!    `duty_rotation_max_consecutive_blocks` blocks in a row (rotation),
+//! 2. someone whose shift covers the whole block,
+//! 3. the fewest blocks at that position this month, today included (the
+//!    same measure `available_staff` sorts by),
+//! 4. the fewest blocks anywhere on the board today, then name.
+//!
+//! Existing cells (manual assignments and OT-needed markers) are left alone
+//! unless the caller asks to overwrite assignments.
+
+use std::collections::{HashMap, HashSet};
+
+use sqlx::PgPool;
+use time::Date;
+use uuid::Uuid;
+
+use crate::{api::duty_board::compute_open_blocks, error::Result};
+
+pub(crate) struct PositionSpec {
+    pub id: Uuid,
+    pub name: String,
+    pub sort_order: i32,
+    pub classification_id: Option<Uuid>,
+    pub required_qualifications: Vec<Uuid>,
+    pub open_blocks: Vec<bool>,
+}
+
+impl PositionSpec {
+    /// Classification and qualifications allow `c` at this position.
+    pub fn admits(&self, c: &Candidate) -> bool {
+        self.classification_id.is_none_or(|cl| c.classification_id == Some(cl))
+            && self.required_qualifications.iter().all(|q| c.qualifications.contains(q))
+    }
+}
+
+pub(crate) struct Candidate {
+    pub user_id: Uuid,
+    pub first_name: String,
+    pub last_name: String,
+    pub classification_id: Option<Uuid>,
+    pub qualifications: HashSet<Uuid>,
+    /// Per block: None = not on shift (or on leave), Some(true) = the shift
+    /// covers the whole block, Some(false) = part of it.
+    pub blocks: [Option<bool>; 12],
+    /// Minutes of the date each of their shifts covers, as (start, end).
+    pub shifts: Vec<(i32, i32)>,
+}
+
+pub(crate) struct FillInput {
+    pub positions: Vec<PositionSpec>,
+    pub candidates: Vec<Candidate>,
+    /// Cells kept as they are: (position, block) → assigned user, None for an OT-needed marker.
+    pub existing: HashMap<(Uuid, i16), Option<Uuid>>,
+    /// (user, position) → blocks worked there earlier this month.
+    pub history: HashMap<(Uuid, Uuid), i64>,
+    pub max_consecutive: i64,
+}
+
+pub(crate) struct PlannedCell {
+    pub position_id: Uuid,
+    pub block_index: i16,
+    /// None when nobody eligible was free.
+    pub user_id: Option<Uuid>,
+}
+
+/// Plan the fill. Only cells that are open and not in `existing` appear in the result.
+pub(crate) fn plan(input: &FillInput) -> Vec<PlannedCell> {
+    let mut board: HashMap<(Uuid, i16), Uuid> = input
+        .existing
+        .iter()
+        .filter_map(|(k, u)| u.map(|u| (*k, u)))
+        .collect();
+    let mut position_blocks = input.history.clone();
+    let mut day_blocks: HashMap<Uuid, i64> = HashMap::new();
+    for ((position_id, _), user_id) in &board {
+        *day_blocks.entry(*user_id).or_insert(0) += 1;
+        *position_blocks.entry((*user_id, *position_id)).or_insert(0) += 1;
+    }
+
+    let mut planned = Vec::new();
+
+    for block in 0..12i16 {
+        let b = block as usize;
+        let mut busy: HashSet<Uuid> = board
+            .iter()
+            .filter(|((_, blk), _)| *blk == block)
+            .map(|(_, u)| *u)
+            .collect();
+
+        let eligible = |p: &PositionSpec, c: &Candidate| c.blocks[b].is_some() && p.admits(c);
+
+        let mut cells: Vec<&PositionSpec> = input
+            .positions
+            .iter()
+            .filter(|p| p.open_blocks.get(b).copied().unwrap_or(false))
+            .filter(|p| !input.existing.contains_key(&(p.id, block)))
+            .collect();
+        cells.sort_by_key(|p| {
+            let n = input
+                .candidates
+                .iter()
+                .filter(|c| !busy.contains(&c.user_id) && eligible(p, c))
+                .count();
+            (n, p.sort_order, p.name.clone())
+        });
+
+        for p in cells {
+            let streak = |user_id: Uuid| {
+                (0..block)
+                    .rev()
+                    .take_while(|prev| board.get(&(p.id, *prev)) == Some(&user_id))
+                    .count() as i64
+            };
+            let pick = input
+                .candidates
+                .iter()
+                .filter(|c| !busy.contains(&c.user_id) && eligible(p, c))
+                .min_by_key(|c| {
+                    (
+                        streak(c.user_id) >= input.max_consecutive,
+                        c.blocks[b] != Some(true),
+                        position_blocks.get(&(c.user_id, p.id)).copied().unwrap_or(0),
+                        day_blocks.get(&c.user_id).copied().unwrap_or(0),
+                        c.last_name.clone(),
+                        c.first_name.clone(),
+                        c.user_id,
+                    )
+                })
+                .map(|c| c.user_id);
+
+            if let Some(user_id) = pick {
+                busy.insert(user_id);
+                board.insert((p.id, block), user_id);
+                *position_blocks.entry((user_id, p.id)).or_insert(0) += 1;
+                *day_blocks.entry(user_id).or_insert(0) += 1;
+            }
+            planned.push(PlannedCell {
+                position_id: p.id,
+                block_index: block,
+                user_id: pick,
+            });
+        }
+    }
+
+    planned
+}
+
+/// Load everything `plan` needs for one org and date. With `overwrite`,
+/// existing assignments are treated as empty cells; OT-needed markers are
+/// always kept.
+pub(crate) async fn load_input(
+    pool: &PgPool,
+    org_id: Uuid,
+    date: Date,
+    overwrite: bool,
+) -> Result<FillInput> {
+    let dow = date.weekday().number_days_from_sunday() as i16;
+    let prev_date = date.previous_day().unwrap_or(date);
+
+    let position_rows = sqlx::query!(
+        r#"
+        SELECT dp.id, dp.name, dp.sort_order, dp.classification_id,
+               h.open_time AS "open_time?", h.close_time AS "close_time?",
+               h.crosses_midnight AS "crosses_midnight?",
+               COALESCE(
+                   ARRAY(SELECT dpq.qualification_id FROM duty_position_qualifications dpq
+                         WHERE dpq.duty_position_id = dp.id),
+                   '{}'
+               ) AS "required_qualifications!"
+        FROM duty_positions dp
+        LEFT JOIN duty_position_hours h ON h.duty_position_id = dp.id AND h.day_of_week = $3
+        WHERE dp.org_id = $1 AND dp.is_active = true
+          AND (dp.board_date IS NULL OR dp.board_date = $2)
+        ORDER BY dp.sort_order, dp.name
+        "#,
+        org_id,
+        date,
+        dow,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let positions = position_rows
+        .into_iter()
+        .map(|r| PositionSpec {
+            open_blocks: compute_open_blocks(r.open_time, r.close_time, r.crosses_midnight.unwrap_or(false)),
+            id: r.id,
+            name: r.name,
+            sort_order: r.sort_order,
+            classification_id: r.classification_id,
+            required_qualifications: r.required_qualifications,
+        })
+        .collect();
+
+    // Regular assignments on the date, plus overnight shifts spilling over from the day before
+    let shift_rows = sqlx::query!(
+        r#"
+        SELECT a.user_id, u.first_name, u.last_name, u.classification_id,
+               ss.date, st.start_time, st.end_time, st.crosses_midnight
+        FROM assignments a
+        JOIN scheduled_shifts ss ON ss.id = a.scheduled_shift_id
+        JOIN shift_templates st ON st.id = ss.shift_template_id
+        JOIN users u ON u.id = a.user_id
+        WHERE ss.org_id = $1
+          AND a.cancelled_at IS NULL
+          AND u.is_active = true
+          AND (ss.date = $2 OR (ss.date = $3 AND st.crosses_midnight))
+        "#,
+        org_id,
+        date,
+        prev_date,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    fn mins(t: time::Time) -> i32 {
+        t.hour() as i32 * 60 + t.minute() as i32
+    }
+
+    let mut candidates: HashMap<Uuid, Candidate> = HashMap::new();
+    for r in shift_rows {
+        // Minutes of `date` the shift covers
+        let (start, end) = if r.date == date {
+            let end = if r.crosses_midnight { 1440 } else { mins(r.end_time) };
+            (mins(r.start_time), end)
+        } else {
+            (0, mins(r.end_time))
+        };
+        let c = candidates.entry(r.user_id).or_insert_with(|| Candidate {
+            user_id: r.user_id,
+            first_name: r.first_name.clone(),
+            last_name: r.last_name.clone(),
+            classification_id: r.classification_id,
+            qualifications: HashSet::new(),
+            blocks: [None; 12],
+            shifts: Vec::new(),
+        });
+        c.shifts.push((start, end));
+        for (b, slot) in c.blocks.iter_mut().enumerate() {
+            let (bs, be) = (b as i32 * 120, b as i32 * 120 + 120);
+            if start < be && end > bs {
+                let full = start <= bs && end >= be;
+                *slot = Some(slot.unwrap_or(false) || full);
+            }
+        }
+    }
+
+    let user_ids: Vec<Uuid> = candidates.keys().copied().collect();
+
+    // Approved leave on the date: whole-day requests, or lines overlapping a block
+    let leave_rows = sqlx::query!(
+        r#"
+        SELECT lr.user_id, lrl.id AS "line_id?", lrl.start_time AS "start_time?", lrl.end_time AS "end_time?"
+        FROM leave_requests lr
+        LEFT JOIN leave_request_lines lrl ON lrl.leave_request_id = lr.id
+        WHERE lr.user_id = ANY($1)
+          AND lr.status = 'approved'
+          AND (
+            (lr.start_date <= $2 AND lr.end_date >= $2 AND lrl.id IS NULL)
+            OR lrl.date = $2
+          )
+        "#,
+        &user_ids,
+        date,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    for r in leave_rows {
+        let Some(c) = candidates.get_mut(&r.user_id) else {
+            continue;
+        };
+        let window = match (r.line_id, r.start_time, r.end_time) {
+            (Some(_), Some(s), Some(e)) => Some((mins(s), mins(e))),
+            _ => None,
+        };
+        for (b, slot) in c.blocks.iter_mut().enumerate() {
+            let (bs, be) = (b as i32 * 120, b as i32 * 120 + 120);
+            if window.is_none_or(|(s, e)| s < be && e > bs) {
+                *slot = None;
+            }
+        }
+    }
+
+    let qual_rows = sqlx::query!(
+        r#"
+        SELECT user_id, qualification_id FROM user_qualifications
+        WHERE user_id = ANY($1) AND (expires_on IS NULL OR expires_on >= $2)
+        "#,
+        &user_ids,
+        date,
+    )
+    .fetch_all(pool)
+    .await?;
+    for r in qual_rows {
+        if let Some(c) = candidates.get_mut(&r.user_id) {
+            c.qualifications.insert(r.qualification_id);
+        }
+    }
+
+    let existing = sqlx::query!(
+        r#"
+        SELECT duty_position_id, block_index AS "block_index!", user_id, status
+        FROM duty_assignments
+        WHERE org_id = $1 AND date = $2 AND block_index IS NOT NULL
+        "#,
+        org_id,
+        date,
+    )
+    .fetch_all(pool)
+    .await?
+    .into_iter()
+    .filter(|r| !(overwrite && r.status == "assigned"))
+    .map(|r| {
+        let user = if r.status == "assigned" { r.user_id } else { None };
+        ((r.duty_position_id, r.block_index), user)
+    })
+    .collect();
+
+    // Month-to-date console time per position, as shown by `available_staff`
+    let month_start = date.replace_day(1).unwrap_or(date);
+    let history = sqlx::query!(
+        r#"
+        SELECT user_id AS "user_id!", duty_position_id, COUNT(*) AS "blocks!"
+        FROM duty_assignments
+        WHERE org_id = $1
+          AND date >= $2 AND date < $3
+          AND status = 'assigned'
+          AND user_id IS NOT NULL
+        GROUP BY user_id, duty_position_id
+        "#,
+        org_id,
+        month_start,
+        date,
+    )
+    .fetch_all(pool)
+    .await?
+    .into_iter()
+    .map(|r| ((r.user_id, r.duty_position_id), r.blocks))
+    .collect();
+
+    let max_consecutive = crate::services::org_settings::get_i64(
+        pool,
+        org_id,
+        "duty_rotation_max_consecutive_blocks",
+        2,
+    )
+    .await
+    .max(1);
+
+    let mut candidates: Vec<Candidate> = candidates.into_values().collect();
+    candidates.sort_by_key(|c| c.user_id);
+
+    Ok(FillInput {
+        positions,
+        candidates,
+        existing,
+        history,
+        max_consecutive,
+    })
+}
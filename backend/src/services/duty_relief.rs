@@ -0,0 +1,279 @@
+//! Break and meal relief on the duty board.
+//!
+//! A break sits inside one of the employee's assigned blocks; the position they
+//! hold in that block is the one that needs relief. The planner gives each such
+//! break a relief who:
+//! - is on shift for the whole break and meets the position's classification
+//!   and qualifications,
+//! - isn't seated anywhere on the board during that block,
+//! - isn't on a break of their own or relieving another overlapping break,
+//!
+//! preferring whoever has done the fewest reliefs today, then the least time at
+//! that position this month, then name.
+
+use std::collections::HashMap;
+
+use sqlx::PgPool;
+use time::Date;
+use uuid::Uuid;
+
+use crate::{
+    error::Result,
+    models::duty_position::{BreakCoverage, DutyBreakKind, DutyBreakView},
+    services::duty_autofill::FillInput,
+};
+
+pub(crate) struct BreakSpec {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub block_index: i16,
+    /// Minutes after midnight.
+    pub start: i32,
+    pub end: i32,
+    /// The position the employee holds during the block, if seated.
+    pub position_id: Option<Uuid>,
+    pub relief_user_id: Option<Uuid>,
+}
+
+fn overlaps(a: &BreakSpec, b: &BreakSpec) -> bool {
+    a.start < b.end && b.start < a.end
+}
+
+pub(crate) fn mins(t: time::Time) -> i32 {
+    t.hour() as i32 * 60 + t.minute() as i32
+}
+
+/// Whether `relief` can cover `brk`: not seated in the block, not on an
+/// overlapping break, and not relieving some other overlapping break.
+fn relief_is_free(
+    relief: Uuid,
+    brk: &BreakSpec,
+    breaks: &[BreakSpec],
+    seated: &HashMap<(Uuid, i16), Uuid>,
+) -> bool {
+    !seated.contains_key(&(relief, brk.block_index))
+        && !breaks.iter().any(|other| {
+            other.id != brk.id
+                && overlaps(other, brk)
+                && (other.user_id == relief || other.relief_user_id == Some(relief))
+        })
+}
+
+/// (user, block) → position for everyone seated on the board.
+pub(crate) fn seating(input: &FillInput) -> HashMap<(Uuid, i16), Uuid> {
+    input
+        .existing
+        .iter()
+        .filter_map(|((position_id, block), user)| user.map(|u| ((u, *block), *position_id)))
+        .collect()
+}
+
+/// Coverage of each break as things stand.
+pub(crate) fn coverage(
+    breaks: &[BreakSpec],
+    seated: &HashMap<(Uuid, i16), Uuid>,
+) -> HashMap<Uuid, BreakCoverage> {
+    breaks
+        .iter()
+        .map(|b| {
+            let state = match (b.position_id, b.relief_user_id) {
+                (None, _) => BreakCoverage::OffBoard,
+                (Some(_), Some(relief)) if relief_is_free(relief, b, breaks, seated) => BreakCoverage::Relieved,
+                (Some(_), _) => BreakCoverage::Uncovered,
+            };
+            (b.id, state)
+        })
+        .collect()
+}
+
+/// Fill in reliefs for seated breaks that have none (every seated break with
+/// `replace`). Breaks are updated in place; returns the ids that changed.
+pub(crate) fn plan_relief(input: &FillInput, breaks: &mut [BreakSpec], replace: bool) -> Vec<Uuid> {
+    let seated = seating(input);
+    let positions: HashMap<Uuid, _> = input.positions.iter().map(|p| (p.id, p)).collect();
+
+    if replace {
+        for b in breaks.iter_mut().filter(|b| b.position_id.is_some()) {
+            b.relief_user_id = None;
+        }
+    }
+
+    let mut reliefs_today: HashMap<Uuid, i64> = HashMap::new();
+    for b in breaks.iter() {
+        if let Some(relief) = b.relief_user_id {
+            *reliefs_today.entry(relief).or_insert(0) += 1;
+        }
+    }
+
+    let mut order: Vec<usize> = (0..breaks.len()).collect();
+    order.sort_by_key(|&i| (breaks[i].start, breaks[i].end, breaks[i].id));
+
+    let mut changed = Vec::new();
+    for i in order {
+        let brk = &breaks[i];
+        let Some(position) = brk.position_id.and_then(|id| positions.get(&id)) else {
+            continue;
+        };
+        if brk.relief_user_id.is_some() {
+            continue;
+        }
+        let b = brk.block_index as usize;
+
+        let pick = input
+            .candidates
+            .iter()
+            .filter(|c| c.user_id != brk.user_id)
+            .filter(|c| c.blocks[b].is_some() && position.admits(c))
+            .filter(|c| c.shifts.iter().any(|(s, e)| *s <= brk.start && *e >= brk.end))
+            .filter(|c| relief_is_free(c.user_id, brk, breaks, &seated))
+            .min_by_key(|c| {
+                (
+                    reliefs_today.get(&c.user_id).copied().unwrap_or(0),
+                    input.history.get(&(c.user_id, position.id)).copied().unwrap_or(0),
+                    c.last_name.clone(),
+                    c.first_name.clone(),
+                    c.user_id,
+                )
+            })
+            .map(|c| c.user_id);
+
+        if let Some(relief) = pick {
+            *reliefs_today.entry(relief).or_insert(0) += 1;
+            breaks[i].relief_user_id = Some(relief);
+            changed.push(breaks[i].id);
+        }
+    }
+
+    changed
+}
+
+#[derive(sqlx::FromRow)]
+pub(crate) struct BreakRow {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub first_name: String,
+    pub last_name: String,
+    pub date: Date,
+    pub block_index: i16,
+    pub start_time: time::Time,
+    pub end_time: time::Time,
+    pub kind: DutyBreakKind,
+    pub duty_position_id: Option<Uuid>,
+    pub position_name: Option<String>,
+    pub relief_user_id: Option<Uuid>,
+}
+
+impl BreakRow {
+    pub(crate) fn spec(&self) -> BreakSpec {
+        BreakSpec {
+            id: self.id,
+            user_id: self.user_id,
+            block_index: self.block_index,
+            start: mins(self.start_time),
+            end: mins(self.end_time),
+            position_id: self.duty_position_id,
+            relief_user_id: self.relief_user_id,
+        }
+    }
+}
+
+/// Every break on the date, with the position the employee holds in that block.
+pub(crate) async fn load_breaks(pool: &PgPool, org_id: Uuid, date: Date) -> Result<Vec<BreakRow>> {
+    let rows = sqlx::query_as!(
+        BreakRow,
+        r#"
+        SELECT DISTINCT ON (db.start_time, db.id)
+               db.id, db.user_id, u.first_name, u.last_name, db.date, db.block_index,
+               db.start_time, db.end_time, db.kind AS "kind: DutyBreakKind",
+               dp.id AS "duty_position_id?", dp.name AS "position_name?",
+               db.relief_user_id
+        FROM duty_breaks db
+        JOIN users u ON u.id = db.user_id
+        LEFT JOIN duty_assignments da
+               ON da.org_id = db.org_id AND da.date = db.date
+              AND da.block_index = db.block_index AND da.user_id = db.user_id
+              AND da.status = 'assigned'
+        LEFT JOIN duty_positions dp ON dp.id = da.duty_position_id
+        WHERE db.org_id = $1 AND db.date = $2
+        ORDER BY db.start_time, db.id, dp.sort_order, dp.name
+        "#,
+        org_id,
+        date,
+    )
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+/// Build the views for `rows`, with `specs` (same order) giving the relief
+/// each break should show — the stored one, or a planned one for a dry run.
+pub(crate) async fn views(
+    pool: &PgPool,
+    rows: Vec<BreakRow>,
+    specs: &[BreakSpec],
+    seated: &HashMap<(Uuid, i16), Uuid>,
+) -> Result<Vec<DutyBreakView>> {
+    let coverage = coverage(specs, seated);
+
+    let relief_ids: Vec<Uuid> = specs.iter().filter_map(|s| s.relief_user_id).collect();
+    let names: HashMap<Uuid, (String, String)> = sqlx::query!(
+        "SELECT id, first_name, last_name FROM users WHERE id = ANY($1)",
+        &relief_ids,
+    )
+    .fetch_all(pool)
+    .await?
+    .into_iter()
+    .map(|r| (r.id, (r.first_name, r.last_name)))
+    .collect();
+
+    Ok(rows
+        .into_iter()
+        .zip(specs)
+        .map(|(r, s)| {
+            let relief = s.relief_user_id.and_then(|id| names.get(&id));
+            DutyBreakView {
+                coverage: coverage.get(&r.id).copied().unwrap_or(BreakCoverage::Uncovered),
+                id: r.id,
+                user_id: r.user_id,
+                first_name: r.first_name,
+                last_name: r.last_name,
+                date: r.date,
+                block_index: r.block_index,
+                start_time: r.start_time,
+                end_time: r.end_time,
+                kind: r.kind,
+                duty_position_id: r.duty_position_id,
+                position_name: r.position_name,
+                relief_user_id: s.relief_user_id,
+                relief_first_name: relief.map(|n| n.0.clone()),
+                relief_last_name: relief.map(|n| n.1.clone()),
+            }
+        })
+        .collect())
+}
+
+/// (user, block) → position for everyone seated on the board on `date`.
+pub(crate) async fn load_seating(pool: &PgPool, org_id: Uuid, date: Date) -> Result<HashMap<(Uuid, i16), Uuid>> {
+    let rows = sqlx::query!(
+        r#"
+        SELECT user_id AS "user_id!", block_index AS "block_index!", duty_position_id
+        FROM duty_assignments
+        WHERE org_id = $1 AND date = $2 AND status = 'assigned' AND user_id IS NOT NULL
+        "#,
+        org_id,
+        date,
+    )
+    .fetch_all(pool)
+    .await?;
+    Ok(rows
+        .into_iter()
+        .map(|r| ((r.user_id, r.block_index), r.duty_position_id))
+        .collect())
+}
+
+/// The breaks on `date` as stored, with their coverage.
+pub(crate) async fn load_views(pool: &PgPool, org_id: Uuid, date: Date) -> Result<Vec<DutyBreakView>> {
+    let (rows, seated) = tokio::try_join!(load_breaks(pool, org_id, date), load_seating(pool, org_id, date))?;
+    let specs: Vec<BreakSpec> = rows.iter().map(BreakRow::spec).collect();
+    views(pool, rows, &specs, &seated).await
+}
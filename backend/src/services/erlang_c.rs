@@ -0,0 +1,187 @@
+//! Erlang C staffing math for half-hour call-volume intervals.
+//!
+//! Given a call count per interval, an average handle time (AHT) and a
+//! service-level goal ("X% of calls answered within T seconds", e.g. the NENA
+//! 90%/15s standard), finds the smallest number of call-takers meeting the goal.
+
+use crate::error::{AppError, Result};
+
+/// Length of one coverage slot.
+pub const INTERVAL_SECONDS: f64 = 1800.0;
+
+/// Upper bound on agents per interval; anything beyond is treated as bad input.
+const MAX_AGENTS: u32 = 2000;
+
+/// Upper bound on calls in one interval accepted as input.
+pub const MAX_CALLS: f64 = 100_000.0;
+
+/// Whether `calls` is a usable call count for one interval.
+pub fn valid_calls(calls: f64) -> bool {
+    calls.is_finite() && (0.0..=MAX_CALLS).contains(&calls)
+}
+
+/// Staffing goal shared by every interval in a calculation.
+#[derive(Debug, Clone, Copy)]
+pub struct ServiceGoal {
+    pub aht_seconds: f64,
+    /// Fraction of calls to answer within `answer_time_seconds` (0–1).
+    pub service_level: f64,
+    pub answer_time_seconds: f64,
+    /// Optional cap on agent occupancy (0–1).
+    pub max_occupancy: Option<f64>,
+}
+
+/// Result for a single interval.
+#[derive(Debug, Clone, Copy)]
+pub struct IntervalStaffing {
+    pub traffic_erlangs: f64,
+    pub agents: u32,
+    pub service_level: f64,
+    pub asa_seconds: f64,
+    pub occupancy: f64,
+}
+
+/// Probability that a call waits (Erlang C) with `agents` servers and
+/// `traffic` Erlangs offered. Requires `agents > traffic`.
+fn probability_of_wait(agents: u32, traffic: f64) -> f64 {
+    // Erlang B by recursion, then convert to Erlang C — numerically stable
+    // for large agent counts where the factorial form overflows.
+    let mut b = 1.0;
+    for k in 1..=agents {
+        b = traffic * b / (k as f64 + traffic * b);
+    }
+    let n = agents as f64;
+    n * b / (n - traffic * (1.0 - b))
+}
+
+/// Fraction of calls answered within `answer_time_seconds`.
+fn service_level(agents: u32, traffic: f64, goal: &ServiceGoal) -> f64 {
+    let n = agents as f64;
+    let pw = probability_of_wait(agents, traffic);
+    1.0 - pw * (-(n - traffic) * goal.answer_time_seconds / goal.aht_seconds).exp()
+}
+
+/// Smallest agent count meeting the service goal for `calls` in one interval.
+pub fn required_agents(calls: f64, goal: &ServiceGoal) -> Result<IntervalStaffing> {
+    let traffic = calls * goal.aht_seconds / INTERVAL_SECONDS;
+    if traffic <= 0.0 {
+        return Ok(IntervalStaffing {
+            traffic_erlangs: 0.0,
+            agents: 0,
+            service_level: 1.0,
+            asa_seconds: 0.0,
+            occupancy: 0.0,
+        });
+    }
+
+    // Queue is only stable with more agents than offered traffic. Check the
+    // floor against the ceiling before casting so huge inputs can't saturate.
+    let floor = match goal.max_occupancy {
+        Some(max_occ) => (traffic.floor() + 1.0).max((traffic / max_occ).ceil()),
+        None => traffic.floor() + 1.0,
+    };
+    if !floor.is_finite() || floor > MAX_AGENTS as f64 {
+        return Err(too_many_agents(calls));
+    }
+    let mut agents = floor as u32;
+
+    while agents <= MAX_AGENTS {
+        let sl = service_level(agents, traffic, goal);
+        if sl >= goal.service_level {
+            let n = agents as f64;
+            let asa = probability_of_wait(agents, traffic) * goal.aht_seconds / (n - traffic);
+            return Ok(IntervalStaffing {
+                traffic_erlangs: traffic,
+                agents,
+                service_level: sl,
+                asa_seconds: asa,
+                occupancy: traffic / n,
+            });
+        }
+        agents += 1;
+    }
+
+    Err(too_many_agents(calls))
+}
+
+fn too_many_agents(calls: f64) -> AppError {
+    AppError::BadRequest(format!(
+        "{} calls per interval needs more than {} agents",
+        calls, MAX_AGENTS
+    ))
+}
+
+/// Parse a call-volume CSV into (slot_index, calls) pairs.
+///
+/// Each row is `slot,calls` where `slot` is a half-hour start time (`HH:MM`,
+/// `HH:MM:SS`) or a slot index 0–47. A leading header row is skipped. Rows for
+/// the same slot (e.g. several days of history) are averaged.
+pub fn parse_volume_csv(csv: &str) -> Result<Vec<(i16, f64)>> {
+    let mut totals: std::collections::BTreeMap<i16, (f64, u32)> = std::collections::BTreeMap::new();
+
+    for (line_no, line) in csv.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let fields: Vec<&str> = line.split(',').map(|f| f.trim().trim_matches('"')).collect();
+        if fields.len() < 2 {
+            return Err(AppError::BadRequest(format!(
+                "CSV line {}: expected `slot,calls`",
+                line_no + 1
+            )));
+        }
+        let calls = match fields[1].parse::<f64>() {
+            Ok(c) if valid_calls(c) => c,
+            Ok(_) => {
+                return Err(AppError::BadRequest(format!(
+                    "CSV line {}: call count must be between 0 and {}",
+                    line_no + 1,
+                    MAX_CALLS
+                )))
+            }
+            // Header row
+            Err(_) if line_no == 0 => continue,
+            Err(_) => {
+                return Err(AppError::BadRequest(format!(
+                    "CSV line {}: invalid call count `{}`",
+                    line_no + 1,
+                    fields[1]
+                )))
+            }
+        };
+        let slot = parse_slot(fields[0]).ok_or_else(|| {
+            AppError::BadRequest(format!(
+                "CSV line {}: invalid slot `{}` (use HH:MM or 0-47)",
+                line_no + 1,
+                fields[0]
+            ))
+        })?;
+        let entry = totals.entry(slot).or_insert((0.0, 0));
+        entry.0 += calls;
+        entry.1 += 1;
+    }
+
+    if totals.is_empty() {
+        return Err(AppError::BadRequest("CSV contains no call volume rows".into()));
+    }
+
+    Ok(totals
+        .into_iter()
+        .map(|(slot, (sum, n))| (slot, sum / n as f64))
+        .collect())
+}
+
+/// `HH:MM[:SS]` on a half-hour boundary, or a bare slot index.
+pub fn parse_slot(s: &str) -> Option<i16> {
+    if let Ok(idx) = s.parse::<i16>() {
+        return (0..=47).contains(&idx).then_some(idx);
+    }
+    let mut parts = s.split(':');
+    let hour: i16 = parts.next()?.parse().ok()?;
+    let minute: i16 = parts.next()?.parse().ok()?;
+    if !(0..24).contains(&hour) || (minute != 0 && minute != 30) {
+        return None;
+    }
+    Some(hour * 2 + minute / 30)
+}
@@ -0,0 +1,573 @@
+//! Proactive coverage-gap monitor.
+//!
+//! Re-evaluates coverage from now through `gap_alert_hours_ahead` and keeps one
+//! `coverage_gap_incidents` row per contiguous understaffed window. A new gap
+//! sends an in-app alert to every supervisor; a gap that gets worse alerts
+//! again. If nobody acknowledges an incident within
+//! `gap_alert_escalation_minutes` it escalates to SMS (and in-app) for the
+//! supervisors currently on duty. Incidents resolve once the window is staffed
+//! or has passed.
+//!
+//! The background task scans orgs with `gap_alert_enabled` whenever triggers
+//! have marked staffing as changed (`coverage_monitor_state.dirty_at`) and
+//! otherwise every `gap_alert_scan_minutes`.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use sqlx::PgPool;
+use time::{Date, OffsetDateTime};
+use uuid::Uuid;
+
+use crate::{
+    api::notifications::{create_notification, CreateNotificationParams},
+    error::Result,
+    models::gap_incident::GapScanResult,
+    services::{
+        org_settings,
+        ot_autopost::{slot_time, SlotRange, SLOTS_PER_DAY},
+        sms::{self, TwilioConfig},
+        timezone,
+    },
+};
+
+/// One contiguous understaffed window found by a scan, in absolute slots
+/// counted from midnight of the scan's first day.
+#[derive(Debug, Clone)]
+struct DetectedGap {
+    classification_id: Uuid,
+    abbreviation: String,
+    range: SlotRange,
+    shortage: i32,
+}
+
+/// What an alert is about, for message text.
+struct AlertSubject<'a> {
+    incident_id: Uuid,
+    abbreviation: &'a str,
+    date: Date,
+    start_time: time::Time,
+    end_time: time::Time,
+    shortage: i32,
+}
+
+impl AlertSubject<'_> {
+    fn describe(&self) -> String {
+        format!(
+            "{} short {} on {} {:02}:{:02}–{:02}:{:02}",
+            self.abbreviation,
+            self.shortage,
+            self.date,
+            self.start_time.hour(),
+            self.start_time.minute(),
+            self.end_time.hour(),
+            self.end_time.minute(),
+        )
+    }
+}
+
+/// Merge per-slot shortages into runs per classification, limited to `[from, to)`.
+/// Runs longer than a day are split at 24 hours.
+fn detect_gaps(
+    first: Date,
+    coverage: &HashMap<Date, Vec<crate::models::schedule::SlotCoverage>>,
+    from: i64,
+    to: i64,
+) -> Vec<DetectedGap> {
+    let mut shortages: HashMap<Uuid, BTreeMap<i64, i32>> = HashMap::new();
+    let mut abbreviations: HashMap<Uuid, String> = HashMap::new();
+    for (date, slots) in coverage {
+        let day = (*date - first).whole_days() * SLOTS_PER_DAY;
+        for sc in slots {
+            let abs = day + sc.slot_index as i64;
+            let shortage = sc.min_headcount as i32 - sc.actual_headcount;
+            if shortage > 0 && abs >= from && abs < to {
+                shortages.entry(sc.classification_id).or_default().insert(abs, shortage);
+                abbreviations
+                    .entry(sc.classification_id)
+                    .or_insert_with(|| sc.classification_abbreviation.clone());
+            }
+        }
+    }
+
+    let mut gaps = Vec::new();
+    for (class_id, slots) in shortages {
+        let mut current: Option<DetectedGap> = None;
+        for (slot, shortage) in slots {
+            match current.as_mut() {
+                // Windows are stored as date + times, so cap each at one day
+                Some(g) if g.range.end == slot && g.range.end - g.range.start < SLOTS_PER_DAY => {
+                    g.range.end = slot + 1;
+                    g.shortage = g.shortage.max(shortage);
+                }
+                _ => {
+                    gaps.extend(current.take());
+                    current = Some(DetectedGap {
+                        classification_id: class_id,
+                        abbreviation: abbreviations[&class_id].clone(),
+                        range: SlotRange {
+                            start: slot,
+                            end: slot + 1,
+                        },
+                        shortage,
+                    });
+                }
+            }
+        }
+        gaps.extend(current);
+    }
+    gaps.sort_by_key(|g| (g.range.start, g.abbreviation.clone()));
+    gaps
+}
+
+/// Scan one org, updating incidents and sending alerts.
+pub async fn scan_org(
+    pool: &PgPool,
+    twilio: Option<&TwilioConfig>,
+    org_id: Uuid,
+    org_timezone: &str,
+) -> Result<GapScanResult> {
+    let hours_ahead = org_settings::get_i64(pool, org_id, "gap_alert_hours_ahead", 24)
+        .await
+        .clamp(1, 168);
+    let escalation_minutes =
+        org_settings::get_i64(pool, org_id, "gap_alert_escalation_minutes", 30)
+            .await
+            .clamp(0, 24 * 60);
+
+    let scan_started = OffsetDateTime::now_utc();
+    let local_now = timezone::org_now(org_timezone);
+    let first = local_now.date();
+    let from = local_now.hour() as i64 * 2 + local_now.minute() as i64 / 30;
+    let to = from + hours_ahead * 2;
+    let dates: Vec<Date> = (0..=(to - 1) / SLOTS_PER_DAY)
+        .map(|d| first + time::Duration::days(d))
+        .collect();
+
+    let coverage =
+        crate::api::coverage_plans::compute_slot_coverage_batch(pool, org_id, &dates).await?;
+    let detected = detect_gaps(first, &coverage, from, to);
+
+    let open = sqlx::query!(
+        r#"
+        SELECT id, classification_id, date, start_time, end_time, alerted_shortage, alert_level
+        FROM coverage_gap_incidents
+        WHERE org_id = $1 AND status = 'open'
+        ORDER BY detected_at
+        "#,
+        org_id,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let mut result = GapScanResult::default();
+    let mut matched: HashSet<Uuid> = HashSet::new();
+    let supervisors = supervisor_ids(pool, org_id).await?;
+
+    for gap in &detected {
+        let date = first + time::Duration::days(gap.range.start.div_euclid(SLOTS_PER_DAY));
+        let start_time = slot_time(gap.range.start);
+        let end_time = slot_time(gap.range.end);
+
+        let existing = open.iter().find(|inc| {
+            if inc.classification_id != gap.classification_id || matched.contains(&inc.id) {
+                return false;
+            }
+            let r = SlotRange::from_times(first, inc.date, inc.start_time, inc.end_time);
+            r.start < gap.range.end && gap.range.start < r.end
+        });
+
+        match existing {
+            Some(inc) => {
+                matched.insert(inc.id);
+                let worsened = gap.shortage > inc.alerted_shortage;
+                sqlx::query!(
+                    r#"
+                    UPDATE coverage_gap_incidents
+                    SET date = $2, start_time = $3, end_time = $4, shortage = $5,
+                        updated_at = NOW()
+                    WHERE id = $1
+                    "#,
+                    inc.id,
+                    date,
+                    start_time,
+                    end_time,
+                    gap.shortage,
+                )
+                .execute(pool)
+                .await?;
+
+                if worsened {
+                    let subject = AlertSubject {
+                        incident_id: inc.id,
+                        abbreviation: &gap.abbreviation,
+                        date,
+                        start_time,
+                        end_time,
+                        shortage: gap.shortage,
+                    };
+                    let level = inc.alert_level.max(1);
+                    send_in_app(pool, org_id, &subject, level, "Coverage gap worsened", &supervisors)
+                        .await?;
+                    if level >= 2 {
+                        send_sms_alert(pool, twilio, org_id, org_timezone, &subject).await?;
+                    }
+                    mark_alerted(pool, inc.id, level, gap.shortage).await?;
+                    result.worsened += 1;
+                }
+            }
+            None => {
+                let id = sqlx::query_scalar!(
+                    r#"
+                    INSERT INTO coverage_gap_incidents
+                        (org_id, classification_id, date, start_time, end_time, shortage)
+                    VALUES ($1, $2, $3, $4, $5, $6)
+                    RETURNING id
+                    "#,
+                    org_id,
+                    gap.classification_id,
+                    date,
+                    start_time,
+                    end_time,
+                    gap.shortage,
+                )
+                .fetch_one(pool)
+                .await?;
+                matched.insert(id);
+
+                let subject = AlertSubject {
+                    incident_id: id,
+                    abbreviation: &gap.abbreviation,
+                    date,
+                    start_time,
+                    end_time,
+                    shortage: gap.shortage,
+                };
+                send_in_app(pool, org_id, &subject, 1, "Coverage gap detected", &supervisors).await?;
+                mark_alerted(pool, id, 1, gap.shortage).await?;
+                result.opened += 1;
+            }
+        }
+    }
+
+    // Anything not seen this scan has been staffed or has passed
+    let stale: Vec<Uuid> = open
+        .iter()
+        .map(|inc| inc.id)
+        .filter(|id| !matched.contains(id))
+        .collect();
+    if !stale.is_empty() {
+        result.resolved = sqlx::query!(
+            r#"
+            UPDATE coverage_gap_incidents
+            SET status = 'resolved', resolved_at = NOW(), updated_at = NOW()
+            WHERE id = ANY($1)
+            "#,
+            &stale,
+        )
+        .execute(pool)
+        .await?
+        .rows_affected() as u32;
+    }
+
+    // Escalate unacknowledged incidents that have sat at the first tier too long
+    let escalate = sqlx::query!(
+        r#"
+        SELECT i.id, cl.abbreviation, i.date, i.start_time, i.end_time, i.shortage
+        FROM coverage_gap_incidents i
+        JOIN classifications cl ON cl.id = i.classification_id
+        WHERE i.org_id = $1 AND i.status = 'open'
+          AND i.alert_level = 1 AND i.acknowledged_at IS NULL
+          AND i.last_alerted_at <= NOW() - make_interval(mins => $2)
+        "#,
+        org_id,
+        escalation_minutes as i32,
+    )
+    .fetch_all(pool)
+    .await?;
+    if !escalate.is_empty() {
+        let on_duty = on_duty_supervisors(pool, org_id, org_timezone).await?;
+        let on_duty_ids: Vec<Uuid> = on_duty.iter().map(|(id, _)| *id).collect();
+        for inc in &escalate {
+            let subject = AlertSubject {
+                incident_id: inc.id,
+                abbreviation: &inc.abbreviation,
+                date: inc.date,
+                start_time: inc.start_time,
+                end_time: inc.end_time,
+                shortage: inc.shortage,
+            };
+            send_in_app(pool, org_id, &subject, 2, "Coverage gap escalated", &on_duty_ids).await?;
+            send_sms_alert(pool, twilio, org_id, org_timezone, &subject).await?;
+            sqlx::query!(
+                r#"
+                UPDATE coverage_gap_incidents
+                SET alert_level = 2, escalated_at = NOW(), updated_at = NOW()
+                WHERE id = $1
+                "#,
+                inc.id,
+            )
+            .execute(pool)
+            .await?;
+            result.escalated += 1;
+        }
+    }
+
+    sqlx::query!(
+        r#"
+        INSERT INTO coverage_monitor_state (org_id, last_scan_at)
+        VALUES ($1, $2)
+        ON CONFLICT (org_id) DO UPDATE SET last_scan_at = EXCLUDED.last_scan_at
+        "#,
+        org_id,
+        scan_started,
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(result)
+}
+
+async fn mark_alerted(pool: &PgPool, incident_id: Uuid, level: i16, shortage: i32) -> Result<()> {
+    sqlx::query!(
+        r#"
+        UPDATE coverage_gap_incidents
+        SET alert_level = GREATEST(alert_level, $2), alerted_shortage = $3,
+            last_alerted_at = NOW(), updated_at = NOW()
+        WHERE id = $1
+        "#,
+        incident_id,
+        level,
+        shortage,
+    )
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+async fn record_alert(
+    pool: &PgPool,
+    incident_id: Uuid,
+    level: i16,
+    channel: &str,
+    shortage: i32,
+    recipient_count: i32,
+) -> Result<()> {
+    sqlx::query!(
+        r#"
+        INSERT INTO coverage_gap_alerts (incident_id, alert_level, channel, shortage, recipient_count)
+        VALUES ($1, $2, $3, $4, $5)
+        "#,
+        incident_id,
+        level,
+        channel,
+        shortage,
+        recipient_count,
+    )
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+async fn supervisor_ids(pool: &PgPool, org_id: Uuid) -> Result<Vec<Uuid>> {
+    Ok(sqlx::query_scalar!(
+        r#"
+        SELECT id FROM users
+        WHERE org_id = $1 AND is_active = true AND role IN ('admin', 'supervisor')
+        "#,
+        org_id,
+    )
+    .fetch_all(pool)
+    .await?)
+}
+
+/// Admins/supervisors working a shift right now, with their phone numbers.
+/// Falls back to every active supervisor when nobody is on duty.
+async fn on_duty_supervisors(
+    pool: &PgPool,
+    org_id: Uuid,
+    org_timezone: &str,
+) -> Result<Vec<(Uuid, Option<String>)>> {
+    let now = timezone::org_now(org_timezone);
+    let on_duty = sqlx::query!(
+        r#"
+        SELECT DISTINCT u.id, u.phone
+        FROM users u
+        JOIN assignments      a  ON a.user_id = u.id AND a.cancelled_at IS NULL
+        JOIN scheduled_shifts ss ON ss.id = a.scheduled_shift_id
+        JOIN shift_templates  st ON st.id = ss.shift_template_id
+        WHERE u.org_id = $1 AND u.is_active = true AND u.role IN ('admin', 'supervisor')
+          AND (
+            (ss.date = $2 AND st.start_time <= $3 AND (st.crosses_midnight OR st.end_time > $3))
+            OR (ss.date = $2 - 1 AND st.crosses_midnight AND st.end_time > $3)
+          )
+        "#,
+        org_id,
+        now.date(),
+        now.time(),
+    )
+    .fetch_all(pool)
+    .await?;
+    if !on_duty.is_empty() {
+        return Ok(on_duty.into_iter().map(|r| (r.id, r.phone)).collect());
+    }
+
+    Ok(sqlx::query!(
+        r#"
+        SELECT id, phone FROM users
+        WHERE org_id = $1 AND is_active = true AND role IN ('admin', 'supervisor')
+        "#,
+        org_id,
+    )
+    .fetch_all(pool)
+    .await?
+    .into_iter()
+    .map(|r| (r.id, r.phone))
+    .collect())
+}
+
+async fn send_in_app(
+    pool: &PgPool,
+    org_id: Uuid,
+    subject: &AlertSubject<'_>,
+    level: i16,
+    title: &str,
+    recipients: &[Uuid],
+) -> Result<()> {
+    let message = subject.describe();
+    let mut sent = 0;
+    for &user_id in recipients {
+        let ok = create_notification(
+            pool,
+            CreateNotificationParams {
+                org_id,
+                user_id,
+                notification_type: "coverage_gap",
+                title,
+                message: &message,
+                link: Some("/coverage/gap-incidents"),
+                source_type: Some("coverage_gap_incident"),
+                source_id: Some(subject.incident_id),
+            },
+        )
+        .await;
+        if ok.is_ok() {
+            sent += 1;
+        }
+    }
+    record_alert(pool, subject.incident_id, level, "in_app", subject.shortage, sent).await
+}
+
+/// Text the on-duty supervisors. A no-op when SMS is not configured.
+async fn send_sms_alert(
+    pool: &PgPool,
+    twilio: Option<&TwilioConfig>,
+    org_id: Uuid,
+    org_timezone: &str,
+    subject: &AlertSubject<'_>,
+) -> Result<()> {
+    let Some(twilio) = twilio else {
+        return Ok(());
+    };
+    let Some(sender) = crate::services::accrual::first_admin_id(pool, org_id).await? else {
+        return Ok(());
+    };
+
+    let body = format!("Coverage gap: {}. Reply STOP to opt out.", subject.describe());
+    let mut sent = 0;
+    for (user_id, phone) in on_duty_supervisors(pool, org_id, org_timezone).await? {
+        let Some(phone) = phone.filter(|p| !p.is_empty()) else {
+            continue;
+        };
+        let outcome = sms::send_sms(twilio, &phone, &body).await;
+        let (status, error_detail) = match &outcome {
+            Ok(()) => {
+                sent += 1;
+                ("sent", None)
+            }
+            Err(e) => ("failed", Some(e.as_str())),
+        };
+        sqlx::query!(
+            r#"
+            INSERT INTO sms_log (org_id, sent_by, recipient_user_id, to_number, message_body, status, error_detail)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            "#,
+            org_id,
+            sender,
+            user_id,
+            phone,
+            body,
+            status,
+            error_detail,
+        )
+        .execute(pool)
+        .await?;
+    }
+    record_alert(pool, subject.incident_id, 2, "sms", subject.shortage, sent).await
+}
+
+/// Scan every org with `gap_alert_enabled` that is due: staffing changed since
+/// the last scan, or the last scan is older than `gap_alert_scan_minutes`.
+pub async fn run_due_orgs(pool: &PgPool, twilio: Option<&TwilioConfig>) -> Result<()> {
+    let orgs = sqlx::query!(
+        r#"
+        SELECT o.id AS "id!", o.name AS "name!", o.timezone AS "timezone!",
+               s.dirty_at AS "dirty_at?", s.last_scan_at AS "last_scan_at?"
+        FROM organizations o
+        LEFT JOIN coverage_monitor_state s ON s.org_id = o.id
+        "#
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let now = OffsetDateTime::now_utc();
+    for org in orgs {
+        if !org_settings::get_bool(pool, org.id, "gap_alert_enabled", false).await {
+            continue;
+        }
+        let scan_minutes = org_settings::get_i64(pool, org.id, "gap_alert_scan_minutes", 15)
+            .await
+            .clamp(1, 24 * 60);
+        let due = match (org.last_scan_at, org.dirty_at) {
+            (None, _) => true,
+            (Some(last), dirty) => {
+                dirty.is_some_and(|d| d > last)
+                    || now - last >= time::Duration::minutes(scan_minutes)
+            }
+        };
+        if !due {
+            continue;
+        }
+
+        match scan_org(pool, twilio, org.id, &org.timezone).await {
+            Ok(result) => {
+                if result.opened + result.worsened + result.escalated > 0 {
+                    tracing::info!(
+                        org = %org.name,
+                        opened = result.opened,
+                        worsened = result.worsened,
+                        escalated = result.escalated,
+                        resolved = result.resolved,
+                        "Coverage gap scan raised alerts"
+                    );
+                }
+            }
+            Err(e) => {
+                tracing::error!(org_id = %org.id, error = %e, "Coverage gap scan failed for org");
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Background task: checks every minute which orgs are due for a gap scan.
+pub async fn background_gap_monitor_task(pool: PgPool, twilio: Option<TwilioConfig>) {
+    let mut interval = tokio::time::interval(std::time::Duration::from_secs(60));
+
+    loop {
+        interval.tick().await;
+
+        if let Err(e) = run_due_orgs(&pool, twilio.as_ref()).await {
+            tracing::error!(error = %e, "Background coverage gap monitor run failed");
+        }
+    }
+}
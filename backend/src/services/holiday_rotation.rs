@@ -0,0 +1,297 @@
+//! Holiday rotation in vacation bidding. Holidays in the same group (every
+//! year's Christmas Day, say) rotate between employees: whoever received one in
+//! the group's last `lockout_years` can't bid the group's holidays this year in
+//! rounds up to `restricted_through_round`. Vacation bid processing records
+//! who received each holiday, which feeds the next year's rules.
+
+use std::collections::HashMap;
+
+use sqlx::{PgConnection, PgPool};
+use time::Date;
+use uuid::Uuid;
+
+use crate::{
+    error::{AppError, Result},
+    models::bid_processing::VacationAwardTable,
+    models::holiday::{HolidayGroup, HolidayRotationEntry, HolidayRotationReport, HolidayRotationRow},
+    models::vacation_bid::VacationPick,
+    services::report_export::ReportTable,
+};
+
+/// A holiday an employee can't bid this round, and the receipt that bars it.
+pub(crate) struct Lockout {
+    pub date: Date,
+    pub holiday_name: String,
+    pub group_name: String,
+    pub received_year: i32,
+}
+
+/// The holidays of `year` barred to each of `user_ids` in vacation round
+/// `round`. Users with none are absent from the map.
+pub(crate) async fn lockouts(
+    conn: &mut PgConnection,
+    org_id: Uuid,
+    user_ids: &[Uuid],
+    year: i32,
+    round: i32,
+) -> Result<HashMap<Uuid, Vec<Lockout>>> {
+    let rows = sqlx::query!(
+        r#"
+        SELECT DISTINCT ON (ha.user_id, cur.date)
+               ha.user_id, cur.date, cur.name AS holiday_name, g.name AS group_name,
+               EXTRACT(YEAR FROM prev.date)::INT AS "received_year!"
+        FROM holiday_groups g
+        JOIN holiday_group_members cm ON cm.group_id = g.id
+        JOIN holiday_calendar cur     ON cur.id = cm.holiday_id
+        JOIN holiday_group_members pm ON pm.group_id = g.id
+        JOIN holiday_calendar prev    ON prev.id = pm.holiday_id
+        JOIN holiday_awards ha        ON ha.holiday_id = prev.id
+        WHERE g.org_id = $1
+          AND ha.user_id = ANY($2)
+          AND EXTRACT(YEAR FROM cur.date)::INT = $3::INT
+          AND EXTRACT(YEAR FROM prev.date)::INT BETWEEN $3::INT - g.lockout_years AND $3::INT - 1
+          AND $4::INT <= g.restricted_through_round
+        ORDER BY ha.user_id, cur.date, prev.date DESC
+        "#,
+        org_id,
+        user_ids,
+        year,
+        round,
+    )
+    .fetch_all(&mut *conn)
+    .await?;
+
+    let mut lockouts: HashMap<Uuid, Vec<Lockout>> = HashMap::new();
+    for r in rows {
+        lockouts.entry(r.user_id).or_default().push(Lockout {
+            date: r.date,
+            holiday_name: r.holiday_name,
+            group_name: r.group_name,
+            received_year: r.received_year,
+        });
+    }
+    Ok(lockouts)
+}
+
+/// The first barred holiday falling from `start` to `end`, if any.
+pub(crate) fn barred(lockouts: &[Lockout], start: Date, end: Date) -> Option<&Lockout> {
+    lockouts.iter().find(|l| start <= l.date && l.date <= end)
+}
+
+/// Refuse picks covering a holiday the bidder can't bid in `round`.
+pub(crate) fn check_picks(picks: &[VacationPick], lockouts: &[Lockout], round: i32) -> Result<()> {
+    for pick in picks {
+        if let Some(l) = barred(lockouts, pick.start_date, pick.end_date) {
+            return Err(AppError::BadRequest(format!(
+                "You received {} in {}, so {} ({}) can't be bid in round {}",
+                l.group_name, l.received_year, l.holiday_name, l.date, round,
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Record every holiday covered by the table's awards as received.
+pub(crate) async fn record_awards(
+    conn: &mut PgConnection,
+    org_id: Uuid,
+    table: &VacationAwardTable,
+) -> Result<()> {
+    for row in &table.rows {
+        for award in &row.awards {
+            sqlx::query!(
+                r#"
+                INSERT INTO holiday_awards (holiday_id, user_id, vacation_bid_id)
+                SELECT id, $2, $3
+                FROM holiday_calendar
+                WHERE org_id = $1 AND date BETWEEN $4 AND $5
+                ON CONFLICT (holiday_id, user_id) DO NOTHING
+                "#,
+                org_id,
+                row.user_id,
+                award.bid_id,
+                award.start_date,
+                award.end_date,
+            )
+            .execute(&mut *conn)
+            .await?;
+        }
+    }
+    Ok(())
+}
+
+/// The org's holiday groups, by name.
+pub(crate) async fn list_groups(pool: &PgPool, org_id: Uuid) -> Result<Vec<HolidayGroup>> {
+    let groups = sqlx::query_as!(
+        HolidayGroup,
+        r#"
+        SELECT g.id, g.org_id, g.name, g.lockout_years, g.restricted_through_round,
+               COALESCE(ARRAY_AGG(m.holiday_id) FILTER (WHERE m.holiday_id IS NOT NULL), '{}') AS "holiday_ids!",
+               g.created_at
+        FROM holiday_groups g
+        LEFT JOIN holiday_group_members m ON m.group_id = g.id
+        WHERE g.org_id = $1
+        GROUP BY g.id
+        ORDER BY g.name
+        "#,
+        org_id,
+    )
+    .fetch_all(pool)
+    .await?;
+    Ok(groups)
+}
+
+/// Build the rotation report for bid year `year`, counting holidays received
+/// up to and including that year. Active employees are listed in seniority order.
+pub async fn report(pool: &PgPool, org_id: Uuid, year: i32) -> Result<HolidayRotationReport> {
+    let groups = list_groups(pool, org_id).await?;
+
+    let users = sqlx::query!(
+        r#"
+        SELECT u.id, u.first_name, u.last_name, sr.overall_seniority_date AS "seniority_date?"
+        FROM users u
+        LEFT JOIN seniority_records sr ON sr.user_id = u.id
+        WHERE u.org_id = $1 AND u.is_active = true
+        ORDER BY sr.overall_seniority_date ASC NULLS LAST, u.last_name, u.first_name
+        "#,
+        org_id,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let totals: HashMap<Uuid, i64> = sqlx::query!(
+        r#"
+        SELECT ha.user_id, COUNT(*) AS "count!"
+        FROM holiday_awards ha
+        JOIN holiday_calendar hc ON hc.id = ha.holiday_id
+        WHERE hc.org_id = $1 AND EXTRACT(YEAR FROM hc.date)::INT <= $2::INT
+        GROUP BY ha.user_id
+        "#,
+        org_id,
+        year,
+    )
+    .fetch_all(pool)
+    .await?
+    .into_iter()
+    .map(|r| (r.user_id, r.count))
+    .collect();
+
+    let mut received: HashMap<(Uuid, Uuid), HolidayRotationEntry> = HashMap::new();
+    for r in sqlx::query!(
+        r#"
+        SELECT ha.user_id, m.group_id,
+               COUNT(*) AS "times_received!",
+               MAX(EXTRACT(YEAR FROM hc.date)::INT) AS "last_received_year!",
+               BOOL_OR(EXTRACT(YEAR FROM hc.date)::INT BETWEEN $2::INT - g.lockout_years AND $2::INT - 1) AS "locked_out!"
+        FROM holiday_awards ha
+        JOIN holiday_calendar hc      ON hc.id = ha.holiday_id
+        JOIN holiday_group_members m  ON m.holiday_id = hc.id
+        JOIN holiday_groups g         ON g.id = m.group_id
+        WHERE g.org_id = $1 AND EXTRACT(YEAR FROM hc.date)::INT <= $2::INT
+        GROUP BY ha.user_id, m.group_id
+        "#,
+        org_id,
+        year,
+    )
+    .fetch_all(pool)
+    .await?
+    {
+        received.insert(
+            (r.user_id, r.group_id),
+            HolidayRotationEntry {
+                group_id: r.group_id,
+                times_received: r.times_received,
+                last_received_year: Some(r.last_received_year),
+                locked_out: r.locked_out,
+            },
+        );
+    }
+
+    let rows = users
+        .into_iter()
+        .map(|u| HolidayRotationRow {
+            total_received: totals.get(&u.id).copied().unwrap_or(0),
+            groups: groups
+                .iter()
+                .map(|g| {
+                    received.remove(&(u.id, g.id)).unwrap_or(HolidayRotationEntry {
+                        group_id: g.id,
+                        times_received: 0,
+                        last_received_year: None,
+                        locked_out: false,
+                    })
+                })
+                .collect(),
+            user_id: u.id,
+            first_name: u.first_name,
+            last_name: u.last_name,
+            seniority_date: u.seniority_date,
+        })
+        .collect();
+
+    Ok(HolidayRotationReport { year, groups, rows })
+}
+
+/// Lay the report out for CSV/PDF export: one line per employee and group.
+pub fn to_table(report: &HolidayRotationReport) -> ReportTable {
+    let summary = report
+        .groups
+        .iter()
+        .map(|g| {
+            format!(
+                "{}: {} holiday(s); received in the last {} year(s) bars rounds through {}",
+                g.name,
+                g.holiday_ids.len(),
+                g.lockout_years,
+                g.restricted_through_round,
+            )
+        })
+        .collect();
+
+    let mut rows = Vec::new();
+    for row in &report.rows {
+        let employee = vec![
+            format!("{}, {}", row.last_name, row.first_name),
+            row.seniority_date.map(|d| d.to_string()).unwrap_or_default(),
+            row.total_received.to_string(),
+        ];
+        if report.groups.is_empty() {
+            rows.push([employee, vec![String::new(); 4]].concat());
+            continue;
+        }
+        for (group, entry) in report.groups.iter().zip(&row.groups) {
+            let status = if entry.locked_out {
+                format!("Locked out through round {}", group.restricted_through_round)
+            } else {
+                "Eligible".to_string()
+            };
+            rows.push(
+                [
+                    employee.clone(),
+                    vec![
+                        group.name.clone(),
+                        entry.times_received.to_string(),
+                        entry.last_received_year.map(|y| y.to_string()).unwrap_or_default(),
+                        status,
+                    ],
+                ]
+                .concat(),
+            );
+        }
+    }
+
+    ReportTable {
+        title: format!("Holiday rotation: {}", report.year),
+        summary,
+        headers: vec![
+            "Employee",
+            "Seniority date",
+            "Holidays received",
+            "Holiday group",
+            "Times received",
+            "Last received",
+            "Bid status",
+        ],
+        rows,
+    }
+}
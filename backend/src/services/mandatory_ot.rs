@@ -0,0 +1,276 @@
+//! Forced (mandatory) OT rotation.
+//!
+//! The rotation is derived from `mandatory_ot_events`: employees who have never
+//! used a turn come first in inverse bargaining-unit seniority order, everyone
+//! else follows in order of their most recent counted turn. Recording a forced
+//! event therefore moves the employee to the back of the list.
+
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::error::Result;
+use crate::models::mandatory_ot::{
+    MandatoryOtEventType, MandatoryOtIneligibleReason, MandatoryOtOrderEntry,
+};
+
+/// Where an event came from, so cancelling the source can void it.
+#[derive(Debug, Clone, Copy)]
+pub enum EventSource {
+    OtRequestAssignment(Uuid),
+    CalloutEvent(Uuid),
+    Manual,
+}
+
+pub struct NewMandatoryOtEvent<'a> {
+    pub org_id: Uuid,
+    pub classification_id: Uuid,
+    pub user_id: Uuid,
+    pub event_type: MandatoryOtEventType,
+    pub counts_as_turn: bool,
+    pub source: EventSource,
+    pub notes: Option<&'a str>,
+    pub recorded_by: Uuid,
+    pub occurred_at: Option<time::OffsetDateTime>,
+}
+
+/// Append an event to the rotation log.
+pub async fn record_event(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    event: NewMandatoryOtEvent<'_>,
+) -> Result<Uuid> {
+    let (assignment_id, callout_event_id) = match event.source {
+        EventSource::OtRequestAssignment(id) => (Some(id), None),
+        EventSource::CalloutEvent(id) => (None, Some(id)),
+        EventSource::Manual => (None, None),
+    };
+
+    let id = sqlx::query_scalar!(
+        r#"
+        INSERT INTO mandatory_ot_events
+            (org_id, classification_id, user_id, event_type, counts_as_turn,
+             ot_request_assignment_id, callout_event_id, notes, recorded_by, occurred_at)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, COALESCE($10, NOW()))
+        RETURNING id
+        "#,
+        event.org_id,
+        event.classification_id,
+        event.user_id,
+        event.event_type as MandatoryOtEventType,
+        event.counts_as_turn,
+        assignment_id,
+        callout_event_id,
+        event.notes,
+        event.recorded_by,
+        event.occurred_at,
+    )
+    .fetch_one(&mut **tx)
+    .await?;
+
+    Ok(id)
+}
+
+/// Void the forced events recorded for an OT request's assignments, optionally
+/// limited to one employee. Used when the assignment or the request is cancelled.
+pub async fn void_for_ot_request(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ot_request_id: Uuid,
+    user_id: Option<Uuid>,
+    voided_by: Uuid,
+) -> Result<()> {
+    sqlx::query!(
+        r#"
+        UPDATE mandatory_ot_events e
+        SET voided_at = NOW(), voided_by = $3
+        FROM ot_request_assignments a
+        WHERE e.ot_request_assignment_id = a.id
+          AND a.ot_request_id = $1
+          AND ($2::UUID IS NULL OR a.user_id = $2)
+          AND e.voided_at IS NULL
+        "#,
+        ot_request_id,
+        user_id,
+        voided_by,
+    )
+    .execute(&mut **tx)
+    .await?;
+    Ok(())
+}
+
+/// Void the forced event recorded when `user_id` accepted a callout at the
+/// mandatory step.
+pub async fn void_for_callout(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    callout_event_id: Uuid,
+    user_id: Uuid,
+    voided_by: Uuid,
+) -> Result<()> {
+    sqlx::query!(
+        r#"
+        UPDATE mandatory_ot_events
+        SET voided_at = NOW(), voided_by = $3
+        WHERE callout_event_id = $1
+          AND user_id = $2
+          AND event_type = 'forced'
+          AND voided_at IS NULL
+        "#,
+        callout_event_id,
+        user_id,
+        voided_by,
+    )
+    .execute(&mut **tx)
+    .await?;
+    Ok(())
+}
+
+/// Reason of the active exemption covering `date`, if any.
+pub async fn active_exemption(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    org_id: Uuid,
+    user_id: Uuid,
+    classification_id: Uuid,
+    date: time::Date,
+) -> Result<Option<String>> {
+    let reason = sqlx::query_scalar!(
+        r#"
+        SELECT reason
+        FROM mandatory_ot_exemptions
+        WHERE org_id = $1 AND user_id = $2
+          AND (classification_id IS NULL OR classification_id = $3)
+          AND start_date <= $4 AND end_date >= $4
+          AND revoked_at IS NULL
+        ORDER BY end_date DESC
+        LIMIT 1
+        "#,
+        org_id,
+        user_id,
+        classification_id,
+        date,
+    )
+    .fetch_optional(&mut **tx)
+    .await?;
+    Ok(reason)
+}
+
+/// Build the rotation for a classification as of `date`. When a time window is
+/// given, employees who would violate the 10-hour rest rule are marked ineligible.
+pub async fn build_rotation(
+    pool: &PgPool,
+    org_id: Uuid,
+    classification_id: Uuid,
+    date: time::Date,
+    window: Option<(time::Time, time::Time)>,
+) -> Result<Vec<MandatoryOtOrderEntry>> {
+    let rows = sqlx::query!(
+        r#"
+        SELECT
+            u.id AS user_id,
+            u.first_name,
+            u.last_name,
+            u.medical_ot_exempt,
+            turns.last_turn_at::TEXT AS "last_mandatory_at?",
+            COALESCE(turns.times_forced, 0) AS "times_forced!",
+            COALESCE(turns.refusals, 0) AS "refusals!",
+            EXISTS(
+                SELECT 1 FROM mandatory_ot_exemptions x
+                WHERE x.org_id = $1 AND x.user_id = u.id
+                  AND (x.classification_id IS NULL OR x.classification_id = $2)
+                  AND x.start_date <= $3 AND x.end_date >= $3
+                  AND x.revoked_at IS NULL
+            ) AS "exempted!",
+            EXISTS(
+                SELECT 1 FROM leave_requests lr
+                WHERE lr.user_id = u.id
+                  AND lr.status = 'approved'
+                  AND lr.start_date <= $3 AND lr.end_date >= $3
+            ) AS "on_leave!"
+        FROM users u
+        LEFT JOIN seniority_records sr ON sr.user_id = u.id
+        LEFT JOIN LATERAL (
+            SELECT
+                MAX(e.occurred_at) FILTER (WHERE e.counts_as_turn) AS last_turn_at,
+                COUNT(*) FILTER (WHERE e.event_type = 'forced') AS times_forced,
+                COUNT(*) FILTER (WHERE e.event_type = 'refused') AS refusals
+            FROM mandatory_ot_events e
+            WHERE e.org_id = $1 AND e.classification_id = $2
+              AND e.user_id = u.id AND e.voided_at IS NULL
+        ) turns ON true
+        WHERE u.org_id = $1
+          AND u.is_active = true
+          AND u.classification_id = $2
+        -- CBA: Mandatory OT distributed in inverse seniority order (least senior first);
+        -- once forced, an employee goes to the back until everyone else has had a turn.
+        ORDER BY turns.last_turn_at ASC NULLS FIRST,
+                 sr.bargaining_unit_seniority_date DESC NULLS FIRST,
+                 u.last_name, u.first_name, u.id
+        "#,
+        org_id,
+        classification_id,
+        date,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    // Each candidate's next regular shift after the window, for the rest rule
+    let next_shifts: std::collections::HashMap<Uuid, (time::Date, time::Time)> = match window {
+        Some((start, end)) if !rows.is_empty() => {
+            let user_ids: Vec<Uuid> = rows.iter().map(|r| r.user_id).collect();
+            sqlx::query!(
+                r#"
+                SELECT DISTINCT ON (a.user_id)
+                    a.user_id, ss.date AS "shift_date!", st.start_time
+                FROM assignments a
+                JOIN scheduled_shifts ss ON ss.id = a.scheduled_shift_id
+                JOIN shift_templates st ON st.id = ss.shift_template_id
+                WHERE a.user_id = ANY($1)
+                  AND ss.org_id = $2
+                  AND a.cancelled_at IS NULL
+                  AND a.is_overtime = false
+                  AND (ss.date > $3 OR (ss.date = $3 AND st.start_time > $4))
+                ORDER BY a.user_id, ss.date ASC, st.start_time ASC
+                "#,
+                &user_ids,
+                org_id,
+                crate::services::ot::rest_search_date(date, start, end),
+                end,
+            )
+            .fetch_all(pool)
+            .await?
+            .into_iter()
+            .map(|n| (n.user_id, (n.shift_date, n.start_time)))
+            .collect()
+        }
+        _ => std::collections::HashMap::new(),
+    };
+
+    let mut entries = Vec::with_capacity(rows.len());
+    for (idx, r) in rows.into_iter().enumerate() {
+        let ineligible_reason = if r.medical_ot_exempt {
+            Some(MandatoryOtIneligibleReason::MedicalExempt)
+        } else if r.exempted {
+            Some(MandatoryOtIneligibleReason::Exempted)
+        } else if r.on_leave {
+            Some(MandatoryOtIneligibleReason::OnLeave)
+        } else if let (Some((start, end)), Some(&(next_date, next_start))) =
+            (window, next_shifts.get(&r.user_id))
+        {
+            let gap = crate::services::ot::rest_gap_minutes(date, start, end, next_date, next_start);
+            (gap < crate::services::ot::MIN_REST_MINUTES)
+                .then_some(MandatoryOtIneligibleReason::RestPeriod)
+        } else {
+            None
+        };
+
+        entries.push(MandatoryOtOrderEntry {
+            user_id: r.user_id,
+            first_name: r.first_name,
+            last_name: r.last_name,
+            position: idx as i32 + 1,
+            last_mandatory_at: r.last_mandatory_at,
+            times_forced: r.times_forced,
+            refusals: r.refusals,
+            eligible: ineligible_reason.is_none(),
+            ineligible_reason,
+        });
+    }
+    Ok(entries)
+}
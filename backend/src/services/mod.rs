@@ -1,9 +1,31 @@
 pub mod accrual;
 pub mod availability;
+pub mod bid_processing;
+pub mod bid_proxy;
+pub mod bid_results;
+pub mod bid_rounds;
+pub mod bid_scheduler;
 pub mod bidding;
+pub mod bump;
+pub mod call_forecast;
+pub mod callout_policy;
+pub mod coverage_layers;
+pub mod duty_autofill;
+pub mod duty_relief;
+pub mod erlang_c;
+pub mod gap_monitor;
+pub mod holiday_rotation;
 pub mod leave;
+pub mod mandatory_ot;
 pub mod org_settings;
 pub mod ot;
+pub mod ot_autopost;
+pub mod ot_series;
+pub mod qualification_expiry;
+pub mod report_export;
+pub mod seniority;
 pub mod sms;
+pub mod standing_availability;
 pub mod timezone;
 pub mod trade;
+pub mod work_schedule;
@@ -53,6 +53,18 @@ pub async fn seed_defaults(pool: &PgPool, org_id: Uuid) -> Result<(), AppError>
         ("sellback_periods", serde_json::Value::String("june,december".into())),
         ("default_hours_per_vacation_day", serde_json::Value::String("8".into())),
         ("enable_bump_requests", serde_json::Value::String("true".into())),
+        ("ot_autopost_mode", serde_json::Value::String("off".into())),
+        ("ot_autopost_days_ahead", serde_json::Value::String("7".into())),
+        ("ot_autopost_min_hours", serde_json::Value::String("2".into())),
+        ("ot_autopost_max_hours", serde_json::Value::String("12".into())),
+        ("bump_review_hours", serde_json::Value::String("12".into())),
+        ("bump_auto_approve", serde_json::Value::String("false".into())),
+        ("call_forecast_lookback_weeks", serde_json::Value::String("8".into())),
+        ("gap_alert_enabled", serde_json::Value::String("false".into())),
+        ("gap_alert_hours_ahead", serde_json::Value::String("24".into())),
+        ("gap_alert_escalation_minutes", serde_json::Value::String("30".into())),
+        ("gap_alert_scan_minutes", serde_json::Value::String("15".into())),
+        ("duty_rotation_max_consecutive_blocks", serde_json::Value::String("2".into())),
     ];
     for (key, value) in defaults {
         sqlx::query!(
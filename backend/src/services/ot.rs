@@ -164,6 +164,34 @@ pub async fn stamp_ot_queue(
     Ok(())
 }
 
+/// CBA (§ 4.4.3): minimum rest between an OT shift and the next regular shift.
+pub const MIN_REST_MINUTES: i64 = 10 * 60;
+
+/// Date from which to look for the next regular shift after an OT window;
+/// the day after `ot_date` when the window crosses midnight.
+pub fn rest_search_date(ot_date: time::Date, ot_start_time: time::Time, ot_end_time: time::Time) -> time::Date {
+    if ot_end_time < ot_start_time {
+        ot_date.next_day().unwrap_or(ot_date)
+    } else {
+        ot_date
+    }
+}
+
+/// Minutes between the end of an OT window and the start of the next shift.
+pub fn rest_gap_minutes(
+    ot_date: time::Date,
+    ot_start_time: time::Time,
+    ot_end_time: time::Time,
+    next_date: time::Date,
+    next_start_time: time::Time,
+) -> i64 {
+    let ot_end_day_offset: i64 = if ot_end_time < ot_start_time { 1 } else { 0 };
+    let days_between = (next_date - ot_date).whole_days();
+    let ot_end_mins = ot_end_time.hour() as i64 * 60 + ot_end_time.minute() as i64;
+    let next_start_mins = next_start_time.hour() as i64 * 60 + next_start_time.minute() as i64;
+    (days_between - ot_end_day_offset) * 1440 + next_start_mins - ot_end_mins
+}
+
 /// CBA (§ 4.4.3): Verify at least 10 hours of rest between the end of an
 /// OT shift and the employee's next regular shift.
 ///
@@ -176,12 +204,7 @@ pub async fn check_10_hour_rest_gap(
     ot_start_time: time::Time,
     ot_end_time: time::Time,
 ) -> Result<()> {
-    let ot_crosses_midnight = ot_end_time < ot_start_time;
-    let search_date = if ot_crosses_midnight {
-        ot_date.next_day().unwrap_or(ot_date)
-    } else {
-        ot_date
-    };
+    let search_date = rest_search_date(ot_date, ot_start_time, ot_end_time);
 
     let next_shift = sqlx::query!(
         r#"
@@ -209,15 +232,10 @@ pub async fn check_10_hour_rest_gap(
     .await?;
 
     if let Some(next) = next_shift {
-        let ot_end_day_offset: i64 = if ot_crosses_midnight { 1 } else { 0 };
-        let days_between = (next.shift_date - ot_date).whole_days();
-        let ot_end_mins = ot_end_time.hour() as i64 * 60 + ot_end_time.minute() as i64;
-        let next_start_mins =
-            next.start_time.hour() as i64 * 60 + next.start_time.minute() as i64;
         let gap_minutes =
-            (days_between - ot_end_day_offset) * 1440 + next_start_mins - ot_end_mins;
+            rest_gap_minutes(ot_date, ot_start_time, ot_end_time, next.shift_date, next.start_time);
 
-        if gap_minutes < 10 * 60 {
+        if gap_minutes < MIN_REST_MINUTES {
             return Err(AppError::BadRequest(format!(
                 "OT would leave less than 10 hours before the employee's \
                  next scheduled shift on {} (CBA § 4.4.3).",
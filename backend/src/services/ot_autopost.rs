@@ -0,0 +1,566 @@
+//! Auto-posting OT requests from detected coverage gaps.
+//!
+//! Scans the days after today for half-hour slots below `min_headcount`, merges
+//! adjacent understaffed slots per classification into windows (continuing
+//! across midnight), splits long windows to the org's maximum shift length and
+//! drops fragments shorter than the minimum. Slots already covered by an open
+//! OT request, an open callout event, or an earlier posting are not re-posted;
+//! a dismissed posting stops counting only once its gap has grown.
+//!
+//! Depending on `ot_autopost_mode` each window is recorded as a draft in
+//! `ot_gap_postings` for a supervisor to publish, or published straight away as
+//! an `ot_requests` row.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use serde::Serialize;
+use sqlx::PgPool;
+use time::Date;
+use uuid::Uuid;
+
+use crate::api::notifications::{create_notification, CreateNotificationParams};
+use crate::error::Result;
+
+pub(crate) const SLOTS_PER_DAY: i64 = 48;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AutopostMode {
+    Off,
+    Draft,
+    Publish,
+}
+
+impl AutopostMode {
+    /// Parse the `ot_autopost_mode` setting; unknown values are treated as off.
+    pub fn from_setting(value: &str) -> Self {
+        match value {
+            "draft" => Self::Draft,
+            "publish" => Self::Publish,
+            _ => Self::Off,
+        }
+    }
+}
+
+/// One contiguous understaffed window for a classification.
+#[derive(Debug, Clone, Serialize)]
+pub struct GapWindow {
+    pub classification_id: Uuid,
+    pub classification_abbreviation: String,
+    pub date: Date,
+    #[serde(with = "crate::models::common::time_format")]
+    pub start_time: time::Time,
+    /// `end_time <= start_time` means the window ends on the following day.
+    #[serde(with = "crate::models::common::time_format")]
+    pub end_time: time::Time,
+    pub hours: f64,
+    pub shortage: i16,
+}
+
+/// Summary of one auto-post run for one org.
+#[derive(Debug, Clone, Serialize)]
+pub struct AutopostRunResult {
+    pub org_id: Uuid,
+    pub mode: AutopostMode,
+    pub scanned_from: Date,
+    pub scanned_to: Date,
+    /// Windows drafted or published (or that would be, in dry-run mode).
+    pub windows: Vec<GapWindow>,
+    /// Understaffed runs shorter than `ot_autopost_min_hours`.
+    pub skipped_short: u32,
+}
+
+/// Absolute half-hour slot range `[start, end)` counted from midnight of the
+/// first scanned day.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct SlotRange {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl SlotRange {
+    /// Convert a date + wall-clock window into absolute slots. `end <= start`
+    /// crosses midnight; partial slots are widened to whole slots.
+    pub(crate) fn from_times(first: Date, date: Date, start: time::Time, end: time::Time) -> Self {
+        let day = (date - first).whole_days() * SLOTS_PER_DAY;
+        let start_min = start.hour() as i64 * 60 + start.minute() as i64;
+        let mut end_min = end.hour() as i64 * 60 + end.minute() as i64;
+        if end_min <= start_min {
+            end_min += 24 * 60;
+        }
+        Self {
+            start: day + start_min / 30,
+            end: day + (end_min + 29) / 30,
+        }
+    }
+}
+
+pub(crate) fn slot_time(abs_slot: i64) -> time::Time {
+    let slot = abs_slot.rem_euclid(SLOTS_PER_DAY);
+    time::Time::from_hms((slot / 2) as u8, ((slot % 2) * 30) as u8, 0)
+        .expect("slot index within a day is a valid time")
+}
+
+/// Merge understaffed slots into windows per classification.
+///
+/// `shortages` maps classification → absolute slot → shortage; slots listed in
+/// `covered` are treated as already handled. Runs shorter than `min_slots` are
+/// dropped and counted; runs longer than `max_slots` are split into near-equal
+/// pieces no longer than `max_slots`.
+fn build_windows(
+    first: Date,
+    shortages: &HashMap<Uuid, BTreeMap<i64, i32>>,
+    covered: &HashMap<Uuid, BTreeSet<i64>>,
+    abbreviations: &HashMap<Uuid, String>,
+    min_slots: i64,
+    max_slots: i64,
+) -> (Vec<GapWindow>, u32) {
+    let empty = BTreeSet::new();
+    let mut windows = Vec::new();
+    let mut skipped_short = 0u32;
+
+    for (class_id, slots) in shortages {
+        let covered = covered.get(class_id).unwrap_or(&empty);
+
+        // Contiguous runs of (slot, shortage) that nobody is handling yet
+        let mut runs: Vec<Vec<(i64, i32)>> = Vec::new();
+        for (&slot, &shortage) in slots {
+            if covered.contains(&slot) {
+                continue;
+            }
+            match runs.last_mut() {
+                Some(run) if run.last().is_some_and(|&(prev, _)| prev + 1 == slot) => {
+                    run.push((slot, shortage))
+                }
+                _ => runs.push(vec![(slot, shortage)]),
+            }
+        }
+
+        for run in runs {
+            let len = run.len() as i64;
+            if len < min_slots {
+                skipped_short += 1;
+                continue;
+            }
+            let pieces = (len + max_slots - 1) / max_slots;
+            let base = len / pieces;
+            let extra = len % pieces;
+            let mut offset = 0usize;
+            for i in 0..pieces {
+                let piece_len = (base + i64::from(i < extra)) as usize;
+                let piece = &run[offset..offset + piece_len];
+                offset += piece_len;
+
+                let start = piece[0].0;
+                let end = start + piece_len as i64;
+                windows.push(GapWindow {
+                    classification_id: *class_id,
+                    classification_abbreviation: abbreviations
+                        .get(class_id)
+                        .cloned()
+                        .unwrap_or_default(),
+                    date: first + time::Duration::days(start.div_euclid(SLOTS_PER_DAY)),
+                    start_time: slot_time(start),
+                    end_time: slot_time(end),
+                    hours: piece_len as f64 / 2.0,
+                    shortage: piece.iter().map(|&(_, s)| s).max().unwrap_or(0) as i16,
+                });
+            }
+        }
+    }
+
+    windows.sort_by(|a, b| {
+        (a.date, a.start_time, &a.classification_abbreviation).cmp(&(
+            b.date,
+            b.start_time,
+            &b.classification_abbreviation,
+        ))
+    });
+    (windows, skipped_short)
+}
+
+/// Scan upcoming coverage for one org and draft or publish OT requests for the gaps.
+///
+/// `mode` decides whether windows are stored as drafts or published; `Off` makes
+/// this a no-op. `actor_id` is recorded as the creator of published requests.
+/// With `dry_run` the windows are computed and returned without writing anything.
+pub async fn run_org_autopost(
+    pool: &PgPool,
+    org_id: Uuid,
+    org_timezone: &str,
+    actor_id: Uuid,
+    mode: AutopostMode,
+    dry_run: bool,
+) -> Result<AutopostRunResult> {
+    use crate::services::org_settings::get_i64;
+
+    let today = crate::services::timezone::org_today(org_timezone);
+    let days_ahead = get_i64(pool, org_id, "ot_autopost_days_ahead", 7).await.clamp(1, 60);
+    let min_hours = get_i64(pool, org_id, "ot_autopost_min_hours", 2).await.clamp(1, 24);
+    let max_hours = get_i64(pool, org_id, "ot_autopost_max_hours", 12)
+        .await
+        .clamp(min_hours, 24);
+
+    // Today's gaps are already being worked live; start with tomorrow.
+    let first = today + time::Duration::days(1);
+    let last = today + time::Duration::days(days_ahead);
+
+    let mut result = AutopostRunResult {
+        org_id,
+        mode,
+        scanned_from: first,
+        scanned_to: last,
+        windows: Vec::new(),
+        skipped_short: 0,
+    };
+    if mode == AutopostMode::Off {
+        return Ok(result);
+    }
+
+    let dates: Vec<Date> = (0..days_ahead)
+        .map(|i| first + time::Duration::days(i))
+        .collect();
+    let coverage =
+        crate::api::coverage_plans::compute_slot_coverage_batch(pool, org_id, &dates).await?;
+
+    let mut shortages: HashMap<Uuid, BTreeMap<i64, i32>> = HashMap::new();
+    let mut abbreviations: HashMap<Uuid, String> = HashMap::new();
+    for (date, slots) in &coverage {
+        let day = (*date - first).whole_days() * SLOTS_PER_DAY;
+        for sc in slots {
+            let shortage = sc.min_headcount as i32 - sc.actual_headcount;
+            if shortage > 0 {
+                shortages
+                    .entry(sc.classification_id)
+                    .or_default()
+                    .insert(day + sc.slot_index as i64, shortage);
+                abbreviations
+                    .entry(sc.classification_id)
+                    .or_insert_with(|| sc.classification_abbreviation.clone());
+            }
+        }
+    }
+    if shortages.is_empty() {
+        return Ok(result);
+    }
+
+    // Slots already being handled: open OT requests, open callouts, earlier postings.
+    // Start a day early so overnight windows spilling into `first` are included.
+    let lookback = first - time::Duration::days(1);
+    let mut handled: Vec<(Option<Uuid>, SlotRange)> = Vec::new();
+
+    let open_requests = sqlx::query!(
+        r#"
+        SELECT classification_id, date, start_time, end_time
+        FROM ot_requests
+        WHERE org_id = $1 AND date BETWEEN $2 AND $3
+          AND status IN ('open', 'partially_filled')
+        "#,
+        org_id,
+        lookback,
+        last,
+    )
+    .fetch_all(pool)
+    .await?;
+    for r in open_requests {
+        handled.push((
+            Some(r.classification_id),
+            SlotRange::from_times(first, r.date, r.start_time, r.end_time),
+        ));
+    }
+
+    let open_callouts = sqlx::query!(
+        r#"
+        SELECT ce.classification_id AS "classification_id?", ss.date, st.start_time, st.end_time
+        FROM callout_events ce
+        JOIN scheduled_shifts ss ON ss.id = ce.scheduled_shift_id
+        JOIN shift_templates  st ON st.id = ss.shift_template_id
+        WHERE ss.org_id = $1 AND ss.date BETWEEN $2 AND $3
+          AND ce.status = 'open'
+        "#,
+        org_id,
+        lookback,
+        last,
+    )
+    .fetch_all(pool)
+    .await?;
+    for c in open_callouts {
+        // A callout without a classification is treated as covering every classification.
+        handled.push((
+            c.classification_id,
+            SlotRange::from_times(first, c.date, c.start_time, c.end_time),
+        ));
+    }
+
+    let postings = sqlx::query!(
+        r#"
+        SELECT classification_id, date, start_time, end_time, shortage,
+               status = 'dismissed' AS "dismissed!"
+        FROM ot_gap_postings
+        WHERE org_id = $1 AND date BETWEEN $2 AND $3
+        "#,
+        org_id,
+        lookback,
+        last,
+    )
+    .fetch_all(pool)
+    .await?;
+    for p in postings {
+        let range = SlotRange::from_times(first, p.date, p.start_time, p.end_time);
+        // A dismissal stands until the gap grows past the shortage it was dismissed at
+        if p.dismissed {
+            let worst = shortages
+                .get(&p.classification_id)
+                .and_then(|slots| slots.range(range.start..range.end).map(|(_, s)| *s).max())
+                .unwrap_or(0);
+            if worst > i32::from(p.shortage) {
+                continue;
+            }
+        }
+        handled.push((Some(p.classification_id), range));
+    }
+
+    let mut covered: HashMap<Uuid, BTreeSet<i64>> = HashMap::new();
+    for class_id in shortages.keys() {
+        let set = covered.entry(*class_id).or_default();
+        for (handled_class, range) in &handled {
+            if handled_class.is_none_or(|c| c == *class_id) {
+                set.extend(range.start..range.end);
+            }
+        }
+    }
+
+    let (windows, skipped_short) = build_windows(
+        first,
+        &shortages,
+        &covered,
+        &abbreviations,
+        min_hours * 2,
+        max_hours * 2,
+    );
+    result.skipped_short = skipped_short;
+
+    if dry_run || windows.is_empty() {
+        result.windows = windows;
+        return Ok(result);
+    }
+
+    let staffing_reason = sqlx::query_scalar!(
+        "SELECT id FROM ot_reasons WHERE org_id = $1 AND code = 'staffing' AND is_active = true",
+        org_id,
+    )
+    .fetch_optional(pool)
+    .await?;
+
+    let mut published = Vec::new();
+    let mut tx = pool.begin().await?;
+    for w in windows {
+        let posting_id = Uuid::new_v4();
+        let inserted = sqlx::query!(
+            r#"
+            INSERT INTO ot_gap_postings
+                (id, org_id, classification_id, date, start_time, end_time, hours, shortage)
+            VALUES ($1, $2, $3, $4, $5, $6, $7::FLOAT8::NUMERIC, $8)
+            ON CONFLICT (org_id, classification_id, date, start_time, end_time)
+                WHERE status <> 'dismissed' DO NOTHING
+            "#,
+            posting_id,
+            org_id,
+            w.classification_id,
+            w.date,
+            w.start_time,
+            w.end_time,
+            w.hours,
+            w.shortage,
+        )
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+        if inserted == 0 {
+            continue;
+        }
+
+        if mode == AutopostMode::Publish {
+            published.push(
+                publish_posting(&mut tx, posting_id, org_id, actor_id, staffing_reason).await?,
+            );
+        }
+        result.windows.push(w);
+    }
+    tx.commit().await?;
+
+    if !result.windows.is_empty() {
+        notify_supervisors(pool, org_id, &result).await;
+    }
+    crate::services::standing_availability::notify_matching_requests(pool, org_id, &published)
+        .await;
+
+    Ok(result)
+}
+
+/// Create the OT request for a draft posting and mark the posting published.
+///
+/// Returns the new OT request id.
+pub async fn publish_posting(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    posting_id: Uuid,
+    org_id: Uuid,
+    actor_id: Uuid,
+    ot_reason_id: Option<Uuid>,
+) -> Result<Uuid> {
+    let posting = sqlx::query!(
+        r#"
+        SELECT classification_id, date, start_time, end_time,
+               CAST(hours AS FLOAT8) AS "hours!", shortage
+        FROM ot_gap_postings
+        WHERE id = $1 AND org_id = $2
+        FOR UPDATE
+        "#,
+        posting_id,
+        org_id,
+    )
+    .fetch_one(&mut **tx)
+    .await?;
+
+    let request_id = Uuid::new_v4();
+    let notes = format!(
+        "Auto-posted from coverage gap ({} below minimum)",
+        posting.shortage
+    );
+    sqlx::query!(
+        r#"
+        INSERT INTO ot_requests
+            (id, org_id, date, start_time, end_time, hours, classification_id,
+             ot_reason_id, notes, status, created_by)
+        VALUES ($1, $2, $3, $4, $5, $6::FLOAT8::NUMERIC, $7, $8, $9, 'open', $10)
+        "#,
+        request_id,
+        org_id,
+        posting.date,
+        posting.start_time,
+        posting.end_time,
+        posting.hours,
+        posting.classification_id,
+        ot_reason_id,
+        notes,
+        actor_id,
+    )
+    .execute(&mut **tx)
+    .await?;
+
+    sqlx::query!(
+        r#"
+        UPDATE ot_gap_postings
+        SET status = 'published', ot_request_id = $2
+        WHERE id = $1
+        "#,
+        posting_id,
+        request_id,
+    )
+    .execute(&mut **tx)
+    .await?;
+
+    Ok(request_id)
+}
+
+/// Tell every active admin/supervisor what the run created (best-effort).
+async fn notify_supervisors(pool: &PgPool, org_id: Uuid, result: &AutopostRunResult) {
+    let recipients = match sqlx::query_scalar!(
+        r#"
+        SELECT id FROM users
+        WHERE org_id = $1 AND is_active = true AND role IN ('admin', 'supervisor')
+        "#,
+        org_id,
+    )
+    .fetch_all(pool)
+    .await
+    {
+        Ok(ids) => ids,
+        Err(e) => {
+            tracing::warn!(org_id = %org_id, error = %e, "Failed to load auto-post notification recipients");
+            return;
+        }
+    };
+
+    let count = result.windows.len();
+    let (notification_type, title, verb) = match result.mode {
+        AutopostMode::Publish => ("ot_autopost_published", "OT requests auto-posted", "Posted"),
+        _ => ("ot_autopost_drafted", "OT request drafts ready", "Drafted"),
+    };
+    let message = format!(
+        "{verb} {count} OT request{} for coverage gaps between {} and {}",
+        if count == 1 { "" } else { "s" },
+        result.scanned_from,
+        result.scanned_to,
+    );
+
+    for user_id in recipients {
+        let _ = create_notification(
+            pool,
+            CreateNotificationParams {
+                org_id,
+                user_id,
+                notification_type,
+                title,
+                message: &message,
+                link: Some("/ot-requests/auto-posts"),
+                source_type: Some("ot_gap_posting"),
+                source_id: None,
+            },
+        )
+        .await;
+    }
+}
+
+/// Run auto-post for every org that has it enabled.
+pub async fn run_all_orgs(pool: &PgPool) -> Result<Vec<AutopostRunResult>> {
+    let orgs = sqlx::query!("SELECT id, name, timezone FROM organizations")
+        .fetch_all(pool)
+        .await?;
+
+    let mut results = Vec::new();
+    for org in orgs {
+        let mode = AutopostMode::from_setting(
+            &crate::services::org_settings::get_str(pool, org.id, "ot_autopost_mode", "off").await,
+        );
+        if mode == AutopostMode::Off {
+            continue;
+        }
+        let Some(actor_id) = crate::services::accrual::first_admin_id(pool, org.id).await? else {
+            continue;
+        };
+
+        match run_org_autopost(pool, org.id, &org.timezone, actor_id, mode, false).await {
+            Ok(result) => {
+                if !result.windows.is_empty() {
+                    tracing::info!(
+                        org = %org.name,
+                        windows = result.windows.len(),
+                        "OT auto-post run complete"
+                    );
+                }
+                results.push(result);
+            }
+            Err(e) => {
+                tracing::error!(org_id = %org.id, error = %e, "OT auto-post run failed for org");
+            }
+        }
+    }
+
+    Ok(results)
+}
+
+/// Background task: scans for coverage gaps hourly. Postings are keyed by
+/// window, so repeated runs never duplicate a request.
+pub async fn background_autopost_task(pool: PgPool) {
+    let mut interval = tokio::time::interval(std::time::Duration::from_secs(3600));
+
+    loop {
+        interval.tick().await;
+
+        tracing::debug!("Background OT auto-post check starting");
+        if let Err(e) = run_all_orgs(&pool).await {
+            tracing::error!(error = %e, "Background OT auto-post run failed");
+        }
+    }
+}
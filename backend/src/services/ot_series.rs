@@ -0,0 +1,264 @@
+//! Recurring OT request series: recurrence expansion and occurrence maintenance.
+//!
+//! A series owns one `ot_requests` row per matching date. `sync_occurrences`
+//! reconciles the materialized rows with the series rule from a given date
+//! forward, never touching occurrences that already have active volunteers or
+//! assignments beyond their descriptive fields.
+
+use std::collections::{BTreeSet, HashSet};
+
+use time::Date;
+use uuid::Uuid;
+
+use crate::error::{AppError, Result};
+use crate::models::ot_request_series::SeriesSyncSummary;
+
+/// The date-generating part of a series.
+pub struct RecurrenceRule<'a> {
+    /// 0=Sunday..6=Saturday
+    pub days_of_week: &'a [i16],
+    pub interval_weeks: i16,
+    pub start_date: Date,
+    pub end_date: Date,
+    pub excluded_dates: &'a [Date],
+}
+
+impl RecurrenceRule<'_> {
+    /// Validate weekday values and the interval.
+    pub fn validate(&self) -> Result<()> {
+        if self.days_of_week.is_empty() {
+            return Err(AppError::BadRequest(
+                "days_of_week must contain at least one day".into(),
+            ));
+        }
+        if let Some(d) = self.days_of_week.iter().find(|d| !(0..=6).contains(*d)) {
+            return Err(AppError::BadRequest(format!(
+                "day_of_week {d} out of range 0-6"
+            )));
+        }
+        if !(1..=52).contains(&self.interval_weeks) {
+            return Err(AppError::BadRequest(
+                "interval_weeks must be between 1 and 52".into(),
+            ));
+        }
+        crate::api::helpers::validate_date_range(self.start_date, self.end_date, Some(366))
+    }
+
+    /// Expand the rule into concrete dates on or after `from`, skipping excluded
+    /// dates and any date in `holidays`.
+    ///
+    /// Week intervals are counted from the Sunday of the week containing
+    /// `start_date`, so "every 2 weeks on Mon/Fri" keeps both days in the same week.
+    pub fn dates_from(&self, from: Date, holidays: &HashSet<Date>) -> Vec<Date> {
+        let anchor = self.start_date
+            - time::Duration::days(self.start_date.weekday().number_days_from_sunday() as i64);
+        let mut day = from.max(self.start_date);
+        let mut dates = Vec::new();
+        while day <= self.end_date {
+            let dow = day.weekday().number_days_from_sunday() as i16;
+            let week = (day - anchor).whole_days() / 7;
+            if self.days_of_week.contains(&dow)
+                && week % self.interval_weeks as i64 == 0
+                && !self.excluded_dates.contains(&day)
+                && !holidays.contains(&day)
+            {
+                dates.push(day);
+            }
+            match day.next_day() {
+                Some(next) => day = next,
+                None => break,
+            }
+        }
+        dates
+    }
+}
+
+/// Reconcile a series' occurrences with its current rule for dates on or after `from`.
+///
+/// - Matching dates without an occurrence are created.
+/// - Occurrences without active volunteers/assignments are updated to the series
+///   values, or cancelled when their date no longer matches.
+/// - Occurrences with active volunteers/assignments only receive descriptive
+///   changes (reason, location, notes) and are reported as `preserved` when
+///   their date or times no longer match the rule.
+///
+/// A cancelled series (`cancelled_at` set) matches no dates.
+pub async fn sync_occurrences(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    series_id: Uuid,
+    org_id: Uuid,
+    actor_id: Uuid,
+    from: Date,
+) -> Result<SeriesSyncSummary> {
+    let series = sqlx::query!(
+        r#"
+        SELECT start_time, end_time, CAST(hours AS FLOAT8) AS "hours!",
+               classification_id, ot_reason_id, location, is_fixed_coverage, notes,
+               days_of_week, interval_weeks, start_date, end_date, skip_holidays,
+               excluded_dates, cancelled_at
+        FROM ot_request_series
+        WHERE id = $1 AND org_id = $2
+        FOR UPDATE
+        "#,
+        series_id,
+        org_id,
+    )
+    .fetch_optional(&mut **tx)
+    .await?
+    .ok_or_else(|| AppError::NotFound("OT request series not found".into()))?;
+
+    let mut desired: BTreeSet<Date> = if series.cancelled_at.is_some() {
+        BTreeSet::new()
+    } else {
+        let holidays: HashSet<Date> = if series.skip_holidays {
+            sqlx::query_scalar!(
+                "SELECT date FROM holiday_calendar WHERE org_id = $1 AND date BETWEEN $2 AND $3",
+                org_id,
+                series.start_date,
+                series.end_date,
+            )
+            .fetch_all(&mut **tx)
+            .await?
+            .into_iter()
+            .collect()
+        } else {
+            HashSet::new()
+        };
+        let rule = RecurrenceRule {
+            days_of_week: &series.days_of_week,
+            interval_weeks: series.interval_weeks,
+            start_date: series.start_date,
+            end_date: series.end_date,
+            excluded_dates: &series.excluded_dates,
+        };
+        rule.dates_from(from, &holidays).into_iter().collect()
+    };
+
+    let existing = sqlx::query!(
+        r#"
+        SELECT r.id, r.date, r.start_time, r.end_time, r.is_fixed_coverage,
+               (EXISTS(SELECT 1 FROM ot_request_volunteers v
+                       WHERE v.ot_request_id = r.id AND v.withdrawn_at IS NULL)
+                OR EXISTS(SELECT 1 FROM ot_request_assignments a
+                          WHERE a.ot_request_id = r.id AND a.cancelled_at IS NULL)) AS "engaged!"
+        FROM ot_requests r
+        WHERE r.series_id = $1 AND r.org_id = $2 AND r.date >= $3
+          AND r.status != 'cancelled'
+        ORDER BY r.date
+        FOR UPDATE OF r
+        "#,
+        series_id,
+        org_id,
+        from,
+    )
+    .fetch_all(&mut **tx)
+    .await?;
+
+    let mut summary = SeriesSyncSummary::default();
+
+    for occ in &existing {
+        let wanted = desired.remove(&occ.date);
+
+        if occ.engaged {
+            // Volunteers signed up for (and assignees are tracked against) this
+            // exact date/time, so only descriptive fields follow the series.
+            let rows = sqlx::query!(
+                r#"
+                UPDATE ot_requests SET
+                    ot_reason_id = $2, location = $3, notes = $4, updated_at = NOW()
+                WHERE id = $1
+                  AND (ot_reason_id, location, notes) IS DISTINCT FROM ($2, $3, $4)
+                "#,
+                occ.id,
+                series.ot_reason_id,
+                series.location,
+                series.notes,
+            )
+            .execute(&mut **tx)
+            .await?
+            .rows_affected();
+
+            let schedule_matches = occ.start_time == series.start_time
+                && occ.end_time == series.end_time
+                && occ.is_fixed_coverage == series.is_fixed_coverage;
+            if !wanted || !schedule_matches {
+                summary.preserved.push(occ.date);
+            } else if rows > 0 {
+                summary.updated.push(occ.date);
+            }
+            continue;
+        }
+
+        if wanted {
+            let rows = sqlx::query!(
+                r#"
+                UPDATE ot_requests SET
+                    start_time = $2, end_time = $3, hours = $4::FLOAT8::NUMERIC,
+                    ot_reason_id = $5, location = $6, is_fixed_coverage = $7, notes = $8,
+                    updated_at = NOW()
+                WHERE id = $1
+                  AND (start_time, end_time, CAST(hours AS FLOAT8), ot_reason_id,
+                       location, is_fixed_coverage, notes)
+                      IS DISTINCT FROM ($2, $3, $4, $5, $6, $7, $8)
+                "#,
+                occ.id,
+                series.start_time,
+                series.end_time,
+                series.hours,
+                series.ot_reason_id,
+                series.location,
+                series.is_fixed_coverage,
+                series.notes,
+            )
+            .execute(&mut **tx)
+            .await?
+            .rows_affected();
+            if rows > 0 {
+                summary.updated.push(occ.date);
+            }
+        } else {
+            sqlx::query!(
+                r#"
+                UPDATE ot_requests
+                SET status = 'cancelled', cancelled_at = NOW(), cancelled_by = $2,
+                    updated_at = NOW()
+                WHERE id = $1
+                "#,
+                occ.id,
+                actor_id,
+            )
+            .execute(&mut **tx)
+            .await?;
+            summary.cancelled.push(occ.date);
+        }
+    }
+
+    for date in desired {
+        sqlx::query!(
+            r#"
+            INSERT INTO ot_requests
+                (id, org_id, date, start_time, end_time, hours, classification_id,
+                 ot_reason_id, location, is_fixed_coverage, notes, status, created_by, series_id)
+            VALUES ($1, $2, $3, $4, $5, $6::FLOAT8::NUMERIC, $7, $8, $9, $10, $11, 'open', $12, $13)
+            "#,
+            Uuid::new_v4(),
+            org_id,
+            date,
+            series.start_time,
+            series.end_time,
+            series.hours,
+            series.classification_id,
+            series.ot_reason_id,
+            series.location,
+            series.is_fixed_coverage,
+            series.notes,
+            actor_id,
+            series_id,
+        )
+        .execute(&mut **tx)
+        .await?;
+        summary.created.push(date);
+    }
+
+    Ok(summary)
+}
@@ -0,0 +1,128 @@
+//! Qualification expiry reminders.
+//!
+//! Holders get an in-app reminder as their grant comes within 90, 30 and 7
+//! days of `expires_on`. Each threshold is sent once per expiry date:
+//! `user_qualifications.reminded_days` records the smallest one sent, and a
+//! recertification clears it. A grant first seen inside a window (say, 20 days
+//! out) gets that window's reminder only, not the larger ones it skipped.
+
+use sqlx::PgPool;
+use time::Date;
+use uuid::Uuid;
+
+use crate::{
+    api::notifications::{create_notification, CreateNotificationParams},
+    error::Result,
+    services::timezone::org_today,
+};
+
+/// Days before expiry at which reminders go out, largest first.
+pub const REMINDER_DAYS: [i16; 3] = [90, 30, 7];
+
+/// The reminder threshold `days_left` falls in, if any.
+fn threshold(days_left: i64) -> Option<i16> {
+    REMINDER_DAYS
+        .iter()
+        .rev()
+        .copied()
+        .find(|&t| days_left <= t as i64)
+}
+
+/// Send any reminders due in one org as of `today`. Returns how many were sent.
+pub async fn send_org_reminders(pool: &PgPool, org_id: Uuid, today: Date) -> Result<u32> {
+    let horizon = today + time::Duration::days(REMINDER_DAYS[0] as i64);
+    let grants = sqlx::query!(
+        r#"
+        SELECT uq.user_id, uq.qualification_id, q.name AS qualification_name,
+               uq.expires_on AS "expires_on!", uq.reminded_days
+        FROM user_qualifications uq
+        JOIN qualifications q ON q.id = uq.qualification_id
+        JOIN users u ON u.id = uq.user_id
+        WHERE q.org_id = $1
+          AND u.is_active = true
+          AND uq.expires_on BETWEEN $2 AND $3
+        "#,
+        org_id,
+        today,
+        horizon,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let mut sent = 0;
+    for g in grants {
+        let days_left = (g.expires_on - today).whole_days();
+        let Some(t) = threshold(days_left) else {
+            continue;
+        };
+        if g.reminded_days.is_some_and(|r| r <= t) {
+            continue;
+        }
+
+        let title = format!("{} expires in {} days", g.qualification_name, days_left);
+        let message = format!(
+            "Your {} qualification expires on {}. Recertify before then to stay eligible \
+             for positions and coverage that require it.",
+            g.qualification_name, g.expires_on,
+        );
+        create_notification(
+            pool,
+            CreateNotificationParams {
+                org_id,
+                user_id: g.user_id,
+                notification_type: "qualification_expiring",
+                title: &title,
+                message: &message,
+                link: None,
+                source_type: Some("qualification"),
+                source_id: Some(g.qualification_id),
+            },
+        )
+        .await?;
+
+        sqlx::query!(
+            "UPDATE user_qualifications SET reminded_days = $3 WHERE user_id = $1 AND qualification_id = $2",
+            g.user_id,
+            g.qualification_id,
+            t,
+        )
+        .execute(pool)
+        .await?;
+        sent += 1;
+    }
+
+    Ok(sent)
+}
+
+/// Send due reminders for every org, each as of its own local date.
+pub async fn run_all_orgs(pool: &PgPool) -> Result<u32> {
+    let orgs = sqlx::query!("SELECT id, timezone FROM organizations")
+        .fetch_all(pool)
+        .await?;
+
+    let mut total = 0;
+    for org in orgs {
+        match send_org_reminders(pool, org.id, org_today(&org.timezone)).await {
+            Ok(sent) => total += sent,
+            Err(e) => {
+                tracing::error!(org_id = %org.id, error = %e, "Qualification reminder run failed for org");
+            }
+        }
+    }
+    Ok(total)
+}
+
+/// Background task: checks hourly; the per-grant threshold makes re-runs idempotent.
+pub async fn background_qualification_reminder_task(pool: PgPool) {
+    let mut interval = tokio::time::interval(std::time::Duration::from_secs(3600));
+
+    loop {
+        interval.tick().await;
+
+        match run_all_orgs(&pool).await {
+            Ok(sent) if sent > 0 => tracing::info!(sent, "Qualification expiry reminders sent"),
+            Ok(_) => {}
+            Err(e) => tracing::error!(error = %e, "Background qualification reminder run failed"),
+        }
+    }
+}
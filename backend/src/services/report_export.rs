@@ -0,0 +1,188 @@
+//! Plain CSV and PDF renderings of tabular reports, for downloads that get
+//! posted or handed to stewards. Both are written by hand: a report is a title,
+//! a few header lines and a table of text cells.
+
+/// A report ready for export.
+pub struct ReportTable {
+    pub title: String,
+    /// Lines printed under the title in the PDF (period, who processed it, ...).
+    pub summary: Vec<String>,
+    pub headers: Vec<&'static str>,
+    pub rows: Vec<Vec<String>>,
+}
+
+/// RFC 4180 CSV: header row then one line per row, CRLF line endings.
+pub fn to_csv(table: &ReportTable) -> String {
+    let mut out = String::new();
+    let headers: Vec<String> = table.headers.iter().map(|h| h.to_string()).collect();
+    for row in std::iter::once(&headers).chain(&table.rows) {
+        let cells: Vec<String> = row.iter().map(|c| csv_cell(c)).collect();
+        out.push_str(&cells.join(","));
+        out.push_str("\r\n");
+    }
+    out
+}
+
+fn csv_cell(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+// US Letter landscape in Courier, which advances a fixed 0.6em per character.
+const PAGE_WIDTH: f64 = 792.0;
+const PAGE_HEIGHT: f64 = 612.0;
+const MARGIN: f64 = 36.0;
+const MAX_FONT_SIZE: f64 = 8.0;
+const MIN_FONT_SIZE: f64 = 5.0;
+const MAX_COLUMN: usize = 32;
+
+/// A single-font PDF with the table laid out in fixed-width columns, the font
+/// shrunk (down to 5pt) until the widest line fits. Cells longer than a column
+/// are cut short; the CSV carries the full text.
+pub fn to_pdf(table: &ReportTable) -> Vec<u8> {
+    let widths: Vec<usize> = table
+        .headers
+        .iter()
+        .enumerate()
+        .map(|(i, h)| {
+            table
+                .rows
+                .iter()
+                .filter_map(|r| r.get(i))
+                .map(|c| c.chars().count())
+                .chain(std::iter::once(h.len()))
+                .max()
+                .unwrap_or(0)
+                .min(MAX_COLUMN)
+        })
+        .collect();
+    let table_chars = widths.iter().sum::<usize>() + 2 * widths.len().saturating_sub(1);
+    let font_size = ((PAGE_WIDTH - 2.0 * MARGIN) / (table_chars.max(1) as f64 * 0.6))
+        .clamp(MIN_FONT_SIZE, MAX_FONT_SIZE);
+    let font_size = (font_size * 2.0).floor() / 2.0;
+    let line_height = font_size * 1.25;
+    let line_chars = ((PAGE_WIDTH - 2.0 * MARGIN) / (font_size * 0.6)) as usize;
+    let lines_per_page = ((PAGE_HEIGHT - 2.0 * MARGIN) / line_height) as usize;
+
+    let format_row = |cells: &[String]| -> String {
+        let line: Vec<String> = widths
+            .iter()
+            .enumerate()
+            .map(|(i, w)| {
+                let cell: String = cells.get(i).map_or("", |c| c.as_str()).chars().take(*w).collect();
+                format!("{cell:<w$}")
+            })
+            .collect();
+        line.join("  ").trim_end().chars().take(line_chars).collect()
+    };
+    let header_line = format_row(&table.headers.iter().map(|h| h.to_string()).collect::<Vec<_>>());
+    let rule = "-".repeat(header_line.chars().count());
+
+    let mut preamble = vec![table.title.clone()];
+    preamble.extend(table.summary.iter().cloned());
+    preamble.push(String::new());
+
+    let mut pages: Vec<Vec<String>> = Vec::new();
+    let mut page = preamble;
+    let mut rows = table.rows.iter().peekable();
+    loop {
+        page.push(header_line.clone());
+        page.push(rule.clone());
+        while page.len() < lines_per_page {
+            match rows.next() {
+                Some(row) => page.push(format_row(row)),
+                None => break,
+            }
+        }
+        pages.push(std::mem::take(&mut page));
+        if rows.peek().is_none() {
+            break;
+        }
+    }
+
+    let page_count = pages.len();
+    let mut objects: Vec<Vec<u8>> = vec![
+        b"<< /Type /Catalog /Pages 2 0 R >>".to_vec(),
+        Vec::new(), // page tree, filled in once the page ids are known
+        b"<< /Type /Font /Subtype /Type1 /BaseFont /Courier /Encoding /WinAnsiEncoding >>".to_vec(),
+    ];
+    let mut kids = Vec::new();
+    for (n, lines) in pages.iter().enumerate() {
+        let mut content = format!(
+            "BT /F1 {font_size} Tf {line_height} TL {MARGIN} {} Td\n",
+            PAGE_HEIGHT - MARGIN - font_size
+        )
+        .into_bytes();
+        for line in lines {
+            content.push(b'(');
+            content.extend(pdf_text(line));
+            content.extend(b") Tj T*\n");
+        }
+        let footer = format!("Page {} of {}", n + 1, page_count);
+        content.extend(
+            format!(
+                "ET BT /F1 {font_size} Tf {} {} Td ({footer}) Tj ET",
+                PAGE_WIDTH - MARGIN - footer.len() as f64 * font_size * 0.6,
+                MARGIN / 2.0
+            )
+            .into_bytes(),
+        );
+
+        // Object ids are 1-based: this page is the next object, its content the one after
+        let page_id = objects.len() + 1;
+        let content_id = page_id + 1;
+        let mut stream = format!("<< /Length {} >>\nstream\n", content.len()).into_bytes();
+        stream.extend(content);
+        stream.extend(b"\nendstream");
+        kids.push(format!("{page_id} 0 R"));
+        objects.push(
+            format!(
+                "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {PAGE_WIDTH} {PAGE_HEIGHT}] \
+                 /Resources << /Font << /F1 3 0 R >> >> /Contents {content_id} 0 R >>"
+            )
+            .into_bytes(),
+        );
+        objects.push(stream);
+    }
+    objects[1] = format!("<< /Type /Pages /Kids [{}] /Count {page_count} >>", kids.join(" ")).into_bytes();
+
+    let mut out = b"%PDF-1.4\n".to_vec();
+    let mut offsets = Vec::with_capacity(objects.len());
+    for (i, body) in objects.iter().enumerate() {
+        offsets.push(out.len());
+        out.extend(format!("{} 0 obj\n", i + 1).into_bytes());
+        out.extend(body);
+        out.extend(b"\nendobj\n");
+    }
+    let xref = out.len();
+    out.extend(format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1).into_bytes());
+    for offset in offsets {
+        out.extend(format!("{offset:010} 00000 n \n").into_bytes());
+    }
+    out.extend(
+        format!("trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{xref}\n%%EOF\n", objects.len() + 1)
+            .into_bytes(),
+    );
+    out
+}
+
+/// A PDF string literal body: Latin-1 text with `\`, `(` and `)` escaped and
+/// anything outside Latin-1 replaced by `?`.
+fn pdf_text(text: &str) -> Vec<u8> {
+    let mut out = Vec::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' | '(' | ')' => {
+                out.push(b'\\');
+                out.push(c as u8);
+            }
+            c if (c as u32) < 0x20 => out.push(b' '),
+            c if (c as u32) < 0x100 => out.push(c as u32 as u8),
+            _ => out.push(b'?'),
+        }
+    }
+    out
+}
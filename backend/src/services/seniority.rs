@@ -0,0 +1,801 @@
+//! Seniority dates, published seniority lists and the seniority audit trail.
+//!
+//! Each seniority date in `seniority_records` is computed: the date seniority
+//! counts from (its base; overall falls back to the hire date) plus the days
+//! of every completed, non-exception absence in `employment_status_history`
+//! since then. Publishing a list snapshots the order the dates give (with the
+//! tie-break rules applied) so bidding, disputes and grievances can refer back
+//! to exactly what employees were shown. Every change to a seniority date is
+//! recorded in `seniority_audit`.
+
+use std::cmp::Ordering;
+
+use sqlx::{PgConnection, PgPool};
+use time::{Date, Duration};
+use uuid::Uuid;
+
+use crate::{
+    error::{AppError, Result},
+    models::seniority::{
+        AbsenceAdjustment, EmploymentStatusInterval, PublishSeniorityListRequest,
+        SeniorityDerivation, SeniorityExplanation, SeniorityList, SeniorityListDetail,
+        SeniorityListEntry, SeniorityListQuery, SeniorityListType,
+    },
+    models::user::EmployeeStatus,
+    services::{org_settings, report_export::ReportTable},
+};
+
+/// Rules a list can break ties with. Dates rank earliest first, employee IDs
+/// lowest first (numerically when both are numbers), names alphabetically.
+pub const TIE_BREAK_RULES: &[&str] = &[
+    "overall_seniority_date",
+    "bargaining_unit_seniority_date",
+    "classification_seniority_date",
+    "hire_date",
+    "employee_id",
+    "last_name",
+];
+
+/// Used when the org has no `seniority_tie_breakers` setting.
+const DEFAULT_TIE_BREAKERS: &str = "overall_seniority_date,hire_date,employee_id";
+
+/// The seniority date a list type ranks by.
+fn date_field(list_type: SeniorityListType) -> &'static str {
+    match list_type {
+        SeniorityListType::Overall => "overall_seniority_date",
+        SeniorityListType::BargainingUnit => "bargaining_unit_seniority_date",
+        SeniorityListType::Classification => "classification_seniority_date",
+    }
+}
+
+/// The tie-break rules for a new list: the requested ones, else the org's
+/// setting. The list's own date is dropped since it's already been compared.
+async fn resolve_rules(
+    pool: &PgPool,
+    org_id: Uuid,
+    list_type: SeniorityListType,
+    requested: Option<&[String]>,
+) -> Result<Vec<String>> {
+    let configured: Vec<String> = match requested {
+        Some(rules) => rules.to_vec(),
+        None => org_settings::get_str(pool, org_id, "seniority_tie_breakers", DEFAULT_TIE_BREAKERS)
+            .await
+            .split(',')
+            .map(|r| r.trim().to_string())
+            .filter(|r| !r.is_empty())
+            .collect(),
+    };
+
+    let mut rules: Vec<String> = Vec::new();
+    for rule in configured {
+        if !TIE_BREAK_RULES.contains(&rule.as_str()) {
+            return Err(AppError::BadRequest(format!(
+                "Unknown tie-break rule '{}'. Use one of: {}",
+                rule,
+                TIE_BREAK_RULES.join(", "),
+            )));
+        }
+        if rules.contains(&rule) {
+            return Err(AppError::BadRequest(format!(
+                "Tie-break rule '{}' is listed more than once",
+                rule
+            )));
+        }
+        if rule != date_field(list_type) {
+            rules.push(rule);
+        }
+    }
+    Ok(rules)
+}
+
+struct Candidate {
+    user_id: Uuid,
+    employee_id: Option<String>,
+    first_name: String,
+    last_name: String,
+    hire_date: Option<Date>,
+    overall: Option<Date>,
+    bargaining_unit: Option<Date>,
+    classification: Option<Date>,
+}
+
+impl Candidate {
+    fn date(&self, field: &str) -> Option<Date> {
+        match field {
+            "overall_seniority_date" => self.overall,
+            "bargaining_unit_seniority_date" => self.bargaining_unit,
+            "classification_seniority_date" => self.classification,
+            "hire_date" => self.hire_date,
+            _ => None,
+        }
+    }
+}
+
+/// Earliest first, missing last.
+fn earliest_first(a: Option<Date>, b: Option<Date>) -> Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) => a.cmp(&b),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
+
+fn compare_by(rule: &str, a: &Candidate, b: &Candidate) -> Ordering {
+    match rule {
+        "employee_id" => match (&a.employee_id, &b.employee_id) {
+            (Some(x), Some(y)) => match (x.parse::<u64>(), y.parse::<u64>()) {
+                (Ok(x), Ok(y)) => x.cmp(&y),
+                _ => x.cmp(y),
+            },
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        },
+        "last_name" => a
+            .last_name
+            .cmp(&b.last_name)
+            .then_with(|| a.first_name.cmp(&b.first_name)),
+        date => earliest_first(a.date(date), b.date(date)),
+    }
+}
+
+/// Order candidates by the list's date, then each tie-break rule, then name
+/// (so the order is stable even if every rule ties). Returns the entries
+/// with each tie recorded against the rule that broke it.
+fn rank(
+    mut candidates: Vec<Candidate>,
+    list_type: SeniorityListType,
+    rules: &[String],
+) -> Vec<SeniorityListEntry> {
+    let primary = date_field(list_type);
+    let chain: Vec<&str> = rules
+        .iter()
+        .map(String::as_str)
+        .chain((!rules.iter().any(|r| r == "last_name")).then_some("last_name"))
+        .collect();
+
+    candidates.sort_by(|a, b| {
+        chain
+            .iter()
+            .fold(compare_by(primary, a, b), |ord, rule| ord.then_with(|| compare_by(rule, a, b)))
+            .then_with(|| a.user_id.cmp(&b.user_id))
+    });
+
+    let mut entries: Vec<SeniorityListEntry> = Vec::with_capacity(candidates.len());
+    for (i, c) in candidates.iter().enumerate() {
+        let tie_broken_by = i
+            .checked_sub(1)
+            .map(|p| &candidates[p])
+            .filter(|prev| compare_by(primary, prev, c) == Ordering::Equal)
+            .and_then(|prev| chain.iter().find(|rule| compare_by(rule, prev, c) != Ordering::Equal))
+            .map(|rule| rule.to_string());
+        entries.push(SeniorityListEntry {
+            rank: i as i32 + 1,
+            user_id: c.user_id,
+            employee_id: c.employee_id.clone(),
+            first_name: c.first_name.clone(),
+            last_name: c.last_name.clone(),
+            seniority_date: c.date(primary),
+            tie_broken_by,
+        });
+    }
+    entries
+}
+
+/// Snapshot the current order into a new published list. Covers active
+/// employees who haven't separated, scoped to the list's unit or classification.
+pub async fn publish(
+    pool: &PgPool,
+    org_id: Uuid,
+    published_by: Uuid,
+    req: &PublishSeniorityListRequest,
+    today: Date,
+) -> Result<Uuid> {
+    let (bargaining_unit, classification_id) = match req.list_type {
+        SeniorityListType::Overall => (None, None),
+        SeniorityListType::BargainingUnit => match &req.bargaining_unit {
+            Some(bu) if !bu.trim().is_empty() => (Some(bu.trim().to_string()), None),
+            _ => {
+                return Err(AppError::BadRequest(
+                    "bargaining_unit is required for a bargaining unit list".into(),
+                ))
+            }
+        },
+        SeniorityListType::Classification => match req.classification_id {
+            Some(cid) => (None, Some(cid)),
+            None => {
+                return Err(AppError::BadRequest(
+                    "classification_id is required for a classification list".into(),
+                ))
+            }
+        },
+    };
+
+    let classification_name = match classification_id {
+        Some(cid) => Some(
+            sqlx::query_scalar!(
+                "SELECT name FROM classifications WHERE id = $1 AND org_id = $2",
+                cid,
+                org_id,
+            )
+            .fetch_optional(pool)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Classification not found".into()))?,
+        ),
+        None => None,
+    };
+
+    let rules = resolve_rules(pool, org_id, req.list_type, req.tie_break_rules.as_deref()).await?;
+
+    let title = req.title.clone().unwrap_or_else(|| {
+        let scope = match (&bargaining_unit, &classification_name) {
+            (Some(bu), _) => format!("Bargaining unit {bu}"),
+            (_, Some(name)) => name.clone(),
+            _ => "Overall".to_string(),
+        };
+        format!("{scope} seniority list as of {today}")
+    });
+
+    let mut tx = pool.begin().await?;
+
+    let candidates = sqlx::query!(
+        r#"
+        SELECT u.id, u.employee_id, u.first_name, u.last_name, u.hire_date,
+               sr.overall_seniority_date         AS "overall?",
+               sr.bargaining_unit_seniority_date AS "bargaining_unit?",
+               sr.classification_seniority_date  AS "classification?"
+        FROM users u
+        LEFT JOIN seniority_records sr ON sr.user_id = u.id
+        WHERE u.org_id = $1
+          AND u.is_active = true
+          AND u.employee_status <> 'separated'
+          AND ($2::TEXT IS NULL OR u.bargaining_unit = $2)
+          AND ($3::UUID IS NULL OR u.classification_id = $3)
+        "#,
+        org_id,
+        bargaining_unit,
+        classification_id,
+    )
+    .fetch_all(&mut *tx)
+    .await?
+    .into_iter()
+    .map(|r| Candidate {
+        user_id: r.id,
+        employee_id: r.employee_id,
+        first_name: r.first_name,
+        last_name: r.last_name,
+        hire_date: r.hire_date,
+        overall: r.overall,
+        bargaining_unit: r.bargaining_unit,
+        classification: r.classification,
+    })
+    .collect();
+
+    let entries = rank(candidates, req.list_type, &rules);
+
+    let list_id = sqlx::query_scalar!(
+        r#"
+        INSERT INTO seniority_lists (org_id, list_type, bargaining_unit, classification_id,
+                                     title, tie_break_rules, published_by)
+        VALUES ($1, $2, $3, $4, $5, $6, $7)
+        RETURNING id
+        "#,
+        org_id,
+        req.list_type as SeniorityListType,
+        bargaining_unit,
+        classification_id,
+        title,
+        &rules,
+        published_by,
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+
+    for e in &entries {
+        sqlx::query!(
+            r#"
+            INSERT INTO seniority_list_entries (list_id, rank, user_id, employee_id, first_name,
+                                                last_name, seniority_date, tie_broken_by)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            "#,
+            list_id,
+            e.rank,
+            e.user_id,
+            e.employee_id,
+            e.first_name,
+            e.last_name,
+            e.seniority_date,
+            e.tie_broken_by,
+        )
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    tx.commit().await?;
+    Ok(list_id)
+}
+
+/// The org's published lists, newest first.
+pub async fn list(pool: &PgPool, org_id: Uuid, q: &SeniorityListQuery) -> Result<Vec<SeniorityList>> {
+    fetch_lists(pool, org_id, None, q.list_type).await
+}
+
+async fn fetch_lists(
+    pool: &PgPool,
+    org_id: Uuid,
+    id: Option<Uuid>,
+    list_type: Option<SeniorityListType>,
+) -> Result<Vec<SeniorityList>> {
+    let lists = sqlx::query_as!(
+        SeniorityList,
+        r#"
+        SELECT l.id, l.org_id, l.list_type AS "list_type: SeniorityListType",
+               l.bargaining_unit, l.classification_id, c.name AS "classification_name?",
+               l.title, l.tie_break_rules, l.published_by, l.published_at,
+               (SELECT COUNT(*) FROM seniority_list_entries e WHERE e.list_id = l.id) AS "entry_count!",
+               (SELECT COUNT(*) FROM seniority_disputes d
+                WHERE d.list_id = l.id AND d.status = 'open') AS "open_disputes!"
+        FROM seniority_lists l
+        LEFT JOIN classifications c ON c.id = l.classification_id
+        WHERE l.org_id = $1
+          AND ($2::UUID IS NULL OR l.id = $2)
+          AND ($3::seniority_list_type IS NULL OR l.list_type = $3)
+        ORDER BY l.published_at DESC
+        "#,
+        org_id,
+        id,
+        list_type as Option<SeniorityListType>,
+    )
+    .fetch_all(pool)
+    .await?;
+    Ok(lists)
+}
+
+/// A published list with its entries in rank order.
+pub async fn detail(pool: &PgPool, org_id: Uuid, id: Uuid) -> Result<SeniorityListDetail> {
+    let list = fetch_lists(pool, org_id, Some(id), None)
+        .await?
+        .pop()
+        .ok_or_else(|| AppError::NotFound("Seniority list not found".into()))?;
+
+    let entries = sqlx::query_as!(
+        SeniorityListEntry,
+        r#"
+        SELECT rank, user_id, employee_id, first_name, last_name, seniority_date, tie_broken_by
+        FROM seniority_list_entries
+        WHERE list_id = $1
+        ORDER BY rank
+        "#,
+        id,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(SeniorityListDetail { list, entries })
+}
+
+/// Lay a list out for CSV/PDF export.
+pub fn to_table(detail: &SeniorityListDetail) -> ReportTable {
+    let list = &detail.list;
+    let mut summary = vec![format!("Published {}", list.published_at.date())];
+    if !list.tie_break_rules.is_empty() {
+        summary.push(format!("Ties broken by: {}", list.tie_break_rules.join(", ")));
+    }
+
+    let rows = detail
+        .entries
+        .iter()
+        .map(|e| {
+            vec![
+                e.rank.to_string(),
+                e.employee_id.clone().unwrap_or_default(),
+                format!("{}, {}", e.last_name, e.first_name),
+                e.seniority_date.map(|d| d.to_string()).unwrap_or_default(),
+                e.tie_broken_by.clone().unwrap_or_default(),
+            ]
+        })
+        .collect();
+
+    ReportTable {
+        title: list.title.clone(),
+        summary,
+        headers: vec!["Rank", "Employee ID", "Employee", "Seniority date", "Tie broken by"],
+        rows,
+    }
+}
+
+/// An employee's three seniority dates, for auditing a change to them.
+#[derive(Default)]
+pub(crate) struct SeniorityDates {
+    overall: Option<Date>,
+    bargaining_unit: Option<Date>,
+    classification: Option<Date>,
+}
+
+impl SeniorityDates {
+    fn get(&self, seniority_type: SeniorityListType) -> Option<Date> {
+        match seniority_type {
+            SeniorityListType::Overall => self.overall,
+            SeniorityListType::BargainingUnit => self.bargaining_unit,
+            SeniorityListType::Classification => self.classification,
+        }
+    }
+}
+
+pub(crate) async fn current_dates(conn: &mut PgConnection, user_id: Uuid) -> Result<SeniorityDates> {
+    let dates = sqlx::query!(
+        r#"
+        SELECT overall_seniority_date, bargaining_unit_seniority_date, classification_seniority_date
+        FROM seniority_records
+        WHERE user_id = $1
+        "#,
+        user_id,
+    )
+    .fetch_optional(&mut *conn)
+    .await?
+    .map(|r| SeniorityDates {
+        overall: r.overall_seniority_date,
+        bargaining_unit: r.bargaining_unit_seniority_date,
+        classification: r.classification_seniority_date,
+    })
+    .unwrap_or_default();
+    Ok(dates)
+}
+
+/// Audit each of the user's seniority dates that differs from `before`.
+/// `source` is `profile`, `status_change`, `dispute` or `recompute`;
+/// `changed_by` None = system.
+pub(crate) async fn audit_changes(
+    conn: &mut PgConnection,
+    org_id: Uuid,
+    user_id: Uuid,
+    before: &SeniorityDates,
+    source: &str,
+    dispute_id: Option<Uuid>,
+    changed_by: Option<Uuid>,
+) -> Result<()> {
+    let after = current_dates(conn, user_id).await?;
+    for seniority_type in SENIORITY_TYPES {
+        let (old_date, new_date) = (before.get(seniority_type), after.get(seniority_type));
+        if old_date == new_date {
+            continue;
+        }
+        sqlx::query!(
+            r#"
+            INSERT INTO seniority_audit (org_id, user_id, seniority_type, old_date, new_date,
+                                         source, dispute_id, changed_by)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            "#,
+            org_id,
+            user_id,
+            seniority_type as SeniorityListType,
+            old_date,
+            new_date,
+            source,
+            dispute_id,
+            changed_by,
+        )
+        .execute(&mut *conn)
+        .await?;
+    }
+    Ok(())
+}
+
+const SENIORITY_TYPES: [SeniorityListType; 3] = [
+    SeniorityListType::Overall,
+    SeniorityListType::BargainingUnit,
+    SeniorityListType::Classification,
+];
+
+/// Everything an employee's seniority dates are computed from.
+struct SeniorityInputs {
+    hire_date: Option<Date>,
+    /// The dates seniority counts from, before absences.
+    bases: SeniorityDates,
+    recorded: SeniorityDates,
+    absences: Vec<EmploymentStatusInterval>,
+}
+
+async fn load_inputs(conn: &mut PgConnection, user_id: Uuid) -> Result<SeniorityInputs> {
+    let hire_date = sqlx::query_scalar!("SELECT hire_date FROM users WHERE id = $1", user_id)
+        .fetch_optional(&mut *conn)
+        .await?
+        .flatten();
+
+    let bases = sqlx::query!(
+        r#"
+        SELECT overall_base_date, bargaining_unit_base_date, classification_base_date
+        FROM seniority_records
+        WHERE user_id = $1
+        "#,
+        user_id,
+    )
+    .fetch_optional(&mut *conn)
+    .await?
+    .map(|r| SeniorityDates {
+        overall: r.overall_base_date,
+        bargaining_unit: r.bargaining_unit_base_date,
+        classification: r.classification_base_date,
+    })
+    .unwrap_or_default();
+
+    let recorded = current_dates(conn, user_id).await?;
+    let absences = status_history(conn, user_id).await?;
+
+    Ok(SeniorityInputs { hire_date, bases, recorded, absences })
+}
+
+/// The employee's recorded absences, oldest first.
+pub(crate) async fn status_history(
+    conn: &mut PgConnection,
+    user_id: Uuid,
+) -> Result<Vec<EmploymentStatusInterval>> {
+    let rows = sqlx::query_as!(
+        EmploymentStatusInterval,
+        r#"
+        SELECT id, user_id, status AS "status: EmployeeStatus", starts_on, ends_on,
+               is_exception, notes, recorded_by, created_at
+        FROM employment_status_history
+        WHERE user_id = $1
+        ORDER BY starts_on
+        "#,
+        user_id,
+    )
+    .fetch_all(&mut *conn)
+    .await?;
+    Ok(rows)
+}
+
+/// Days an absence pushes back a seniority date counting from `base`, and why.
+/// Only completed, non-exception absences count, and only from `base` on.
+fn days_counted(absence: &EmploymentStatusInterval, base: Date) -> (i64, String) {
+    if absence.is_exception {
+        return (0, "Exception absence: seniority kept accruing".into());
+    }
+    let Some(ends_on) = absence.ends_on else {
+        return (0, "Ongoing: counted on return to active".into());
+    };
+    if ends_on <= base {
+        return (0, "Before the date seniority counts from".into());
+    }
+    let from = absence.starts_on.max(base);
+    let days = (ends_on - from).whole_days();
+    if from > absence.starts_on {
+        (days, format!("{days} day(s) from {from}, when seniority starts counting"))
+    } else {
+        (days, format!("{days} day(s) unpaid"))
+    }
+}
+
+/// Derive one seniority date: its base (overall falls back to the hire date)
+/// plus the days of every counted absence.
+fn derive(seniority_type: SeniorityListType, inputs: &SeniorityInputs) -> SeniorityDerivation {
+    let (base_date, base_source) = match (inputs.bases.get(seniority_type), seniority_type) {
+        (Some(base), _) => (Some(base), "recorded"),
+        (None, SeniorityListType::Overall) if inputs.hire_date.is_some() => (inputs.hire_date, "hire_date"),
+        _ => (None, "none"),
+    };
+
+    let absences: Vec<AbsenceAdjustment> = inputs
+        .absences
+        .iter()
+        .map(|a| {
+            let (days_counted, reason) = match base_date {
+                Some(base) => days_counted(a, base),
+                None => (0, "No seniority date to adjust".into()),
+            };
+            AbsenceAdjustment {
+                interval_id: a.id,
+                status: a.status,
+                starts_on: a.starts_on,
+                ends_on: a.ends_on,
+                is_exception: a.is_exception,
+                days_counted,
+                reason,
+            }
+        })
+        .collect();
+    let paused_days = absences.iter().map(|a| a.days_counted).sum();
+
+    SeniorityDerivation {
+        seniority_type,
+        base_date,
+        base_source,
+        absences,
+        paused_days,
+        seniority_date: base_date.map(|b| b + Duration::days(paused_days)),
+        recorded_date: inputs.recorded.get(seniority_type),
+    }
+}
+
+/// How each of the employee's seniority dates is derived.
+pub(crate) async fn explain(conn: &mut PgConnection, user_id: Uuid) -> Result<SeniorityExplanation> {
+    let inputs = load_inputs(conn, user_id).await?;
+    Ok(SeniorityExplanation {
+        user_id,
+        hire_date: inputs.hire_date,
+        dates: SENIORITY_TYPES.into_iter().map(|t| derive(t, &inputs)).collect(),
+    })
+}
+
+/// Recompute the employee's seniority dates from their bases and status
+/// history, and mark the current pause (if any) on their record. Returns
+/// whether any date changed; callers audit the change.
+pub(crate) async fn recompute(conn: &mut PgConnection, org_id: Uuid, user_id: Uuid) -> Result<bool> {
+    let inputs = load_inputs(conn, user_id).await?;
+    let [overall, bargaining_unit, classification] = SENIORITY_TYPES.map(|t| derive(t, &inputs));
+    let changed = [&overall, &bargaining_unit, &classification]
+        .iter()
+        .any(|d| d.seniority_date != d.recorded_date);
+    let paused_since = inputs
+        .absences
+        .iter()
+        .find(|a| a.ends_on.is_none() && !a.is_exception)
+        .map(|a| a.starts_on);
+
+    sqlx::query!(
+        r#"
+        INSERT INTO seniority_records (user_id, org_id, overall_seniority_date,
+                                       bargaining_unit_seniority_date, classification_seniority_date,
+                                       accrual_pause_started_at)
+        VALUES ($1, $2, $3, $4, $5, $6)
+        ON CONFLICT (user_id) DO UPDATE SET
+            overall_seniority_date         = EXCLUDED.overall_seniority_date,
+            bargaining_unit_seniority_date = EXCLUDED.bargaining_unit_seniority_date,
+            classification_seniority_date  = EXCLUDED.classification_seniority_date,
+            accrual_pause_started_at       = EXCLUDED.accrual_pause_started_at,
+            updated_at                     = NOW()
+        "#,
+        user_id,
+        org_id,
+        overall.seniority_date,
+        bargaining_unit.seniority_date,
+        classification.seniority_date,
+        paused_since,
+    )
+    .execute(&mut *conn)
+    .await?;
+
+    Ok(changed)
+}
+
+/// Set one of the employee's seniority dates (a profile edit or an upheld
+/// dispute) and recompute. The date is taken as it should read today, so the
+/// base moves back by the absences already counted against it; setting the
+/// date it already has changes nothing.
+pub(crate) async fn set_date(
+    conn: &mut PgConnection,
+    org_id: Uuid,
+    user_id: Uuid,
+    seniority_type: SeniorityListType,
+    date: Option<Date>,
+) -> Result<()> {
+    let inputs = load_inputs(conn, user_id).await?;
+    let current = derive(seniority_type, &inputs);
+    if date.is_some() && date == current.seniority_date {
+        return recompute(conn, org_id, user_id).await.map(|_| ());
+    }
+
+    let base = date.map(|date| {
+        let from = current.base_date.unwrap_or(date);
+        let paused: i64 = inputs.absences.iter().map(|a| days_counted(a, from).0).sum();
+        date - Duration::days(paused)
+    });
+
+    sqlx::query!(
+        r#"
+        INSERT INTO seniority_records (user_id, org_id) VALUES ($1, $2)
+        ON CONFLICT (user_id) DO NOTHING
+        "#,
+        user_id,
+        org_id,
+    )
+    .execute(&mut *conn)
+    .await?;
+    sqlx::query!(
+        r#"
+        UPDATE seniority_records SET
+            overall_base_date = CASE WHEN $2 = 'overall'::seniority_list_type
+                THEN $3 ELSE overall_base_date END,
+            bargaining_unit_base_date = CASE WHEN $2 = 'bargaining_unit'::seniority_list_type
+                THEN $3 ELSE bargaining_unit_base_date END,
+            classification_base_date = CASE WHEN $2 = 'classification'::seniority_list_type
+                THEN $3 ELSE classification_base_date END,
+            updated_at = NOW()
+        WHERE user_id = $1
+        "#,
+        user_id,
+        seniority_type as SeniorityListType,
+        base,
+    )
+    .execute(&mut *conn)
+    .await?;
+
+    recompute(conn, org_id, user_id).await.map(|_| ())
+}
+
+/// Apply seniority dates from a user profile, in `SENIORITY_TYPES` order
+/// (`None` = unchanged, `Some(None)` = clear), and recompute.
+pub(crate) async fn set_profile_dates(
+    conn: &mut PgConnection,
+    org_id: Uuid,
+    user_id: Uuid,
+    dates: [Option<Option<Date>>; 3],
+) -> Result<()> {
+    for (seniority_type, date) in SENIORITY_TYPES.into_iter().zip(dates) {
+        if let Some(date) = date {
+            set_date(conn, org_id, user_id, seniority_type, date).await?;
+        }
+    }
+    recompute(conn, org_id, user_id).await.map(|_| ())
+}
+
+/// Record a change of employment status in the status history: LOA, LWOP
+/// and layoff start an ongoing absence (ending any other one), and a return
+/// to active or a separation ends it. Seniority is then recomputed.
+pub(crate) async fn record_status_change(
+    conn: &mut PgConnection,
+    org_id: Uuid,
+    user_id: Uuid,
+    new_status: EmployeeStatus,
+    is_exception: bool,
+    today: Date,
+    recorded_by: Uuid,
+) -> Result<()> {
+    let ongoing = sqlx::query!(
+        r#"
+        SELECT id, status AS "status: EmployeeStatus", starts_on, is_exception
+        FROM employment_status_history
+        WHERE user_id = $1 AND ends_on IS NULL
+        FOR UPDATE
+        "#,
+        user_id,
+    )
+    .fetch_optional(&mut *conn)
+    .await?;
+
+    let is_absence = matches!(
+        new_status,
+        EmployeeStatus::UnpaidLoa | EmployeeStatus::Lwop | EmployeeStatus::Layoff
+    );
+    if let Some(o) = ongoing {
+        if is_absence && o.status == new_status && o.is_exception == is_exception {
+            return Ok(());
+        }
+        if o.starts_on >= today {
+            // Started today: nothing to keep, so replace rather than end it
+            sqlx::query!("DELETE FROM employment_status_history WHERE id = $1", o.id)
+                .execute(&mut *conn)
+                .await?;
+        } else {
+            sqlx::query!(
+                "UPDATE employment_status_history SET ends_on = $2 WHERE id = $1",
+                o.id,
+                today,
+            )
+            .execute(&mut *conn)
+            .await?;
+        }
+    }
+
+    if is_absence {
+        sqlx::query!(
+            r#"
+            INSERT INTO employment_status_history (org_id, user_id, status, starts_on,
+                                                   is_exception, recorded_by)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            "#,
+            org_id,
+            user_id,
+            new_status as EmployeeStatus,
+            today,
+            is_exception,
+            recorded_by,
+        )
+        .execute(&mut *conn)
+        .await?;
+    }
+
+    recompute(conn, org_id, user_id).await.map(|_| ())
+}
@@ -0,0 +1,204 @@
+//! Standing OT availability: matching employees' pre-registered availability
+//! against concrete OT windows (callout shifts and OT requests).
+
+use std::collections::BTreeMap;
+
+use sqlx::PgPool;
+use time::Date;
+use uuid::Uuid;
+
+use crate::api::notifications::{create_notification, CreateNotificationParams};
+use crate::error::{AppError, Result};
+
+/// The matching part of a standing availability sign-up.
+pub struct AvailabilityRule<'a> {
+    pub start_date: Date,
+    pub end_date: Date,
+    pub days_of_week: &'a [i16],
+    pub start_time: Option<time::Time>,
+    pub end_time: Option<time::Time>,
+}
+
+fn minutes(t: time::Time) -> i32 {
+    t.hour() as i32 * 60 + t.minute() as i32
+}
+
+impl AvailabilityRule<'_> {
+    pub fn validate(&self) -> Result<()> {
+        if let Some(d) = self.days_of_week.iter().find(|d| !(0..=6).contains(*d)) {
+            return Err(AppError::BadRequest(format!(
+                "day_of_week {d} out of range 0-6"
+            )));
+        }
+        if self.start_time.is_some() != self.end_time.is_some() {
+            return Err(AppError::BadRequest(
+                "start_time and end_time must be given together".into(),
+            ));
+        }
+        if self.start_time.is_some() && self.start_time == self.end_time {
+            return Err(AppError::BadRequest(
+                "start_time and end_time must differ; omit both for all day".into(),
+            ));
+        }
+        crate::api::helpers::validate_date_range(self.start_date, self.end_date, Some(366))
+    }
+
+    /// Whether the employee is available for the whole OT window starting on `date`.
+    /// Windows ending at or before their start time cross midnight.
+    pub fn covers(&self, date: Date, start: time::Time, end: time::Time) -> bool {
+        if date < self.start_date || date > self.end_date {
+            return false;
+        }
+        let dow = date.weekday().number_days_from_sunday() as i16;
+        if !self.days_of_week.is_empty() && !self.days_of_week.contains(&dow) {
+            return false;
+        }
+        let (Some(a_start), Some(a_end)) = (self.start_time, self.end_time) else {
+            return true;
+        };
+
+        let a_start = minutes(a_start);
+        let mut a_end = minutes(a_end);
+        if a_end <= a_start {
+            a_end += 1440;
+        }
+        let o_start = minutes(start);
+        let mut o_end = minutes(end);
+        if o_end <= o_start {
+            o_end += 1440;
+        }
+        a_start <= o_start && o_end <= a_end
+    }
+}
+
+/// Active employees in `classification_id` whose standing availability covers
+/// the OT window. Medically exempt employees are excluded.
+pub async fn available_user_ids(
+    pool: &PgPool,
+    org_id: Uuid,
+    classification_id: Option<Uuid>,
+    date: Date,
+    start: time::Time,
+    end: time::Time,
+) -> Result<Vec<Uuid>> {
+    let rows = sqlx::query!(
+        r#"
+        SELECT a.user_id, a.start_date, a.end_date, a.days_of_week, a.start_time, a.end_time
+        FROM ot_standing_availability a
+        JOIN users u ON u.id = a.user_id
+        WHERE a.org_id = $1
+          AND a.cancelled_at IS NULL
+          AND a.start_date <= $2 AND a.end_date >= $2
+          AND u.is_active = true
+          AND NOT u.medical_ot_exempt
+          AND ($3::UUID IS NULL OR u.classification_id = $3)
+        "#,
+        org_id,
+        date,
+        classification_id,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let mut ids: Vec<Uuid> = rows
+        .into_iter()
+        .filter(|r| {
+            AvailabilityRule {
+                start_date: r.start_date,
+                end_date: r.end_date,
+                days_of_week: &r.days_of_week,
+                start_time: r.start_time,
+                end_time: r.end_time,
+            }
+            .covers(date, start, end)
+        })
+        .map(|r| r.user_id)
+        .collect();
+    ids.sort_unstable();
+    ids.dedup();
+    Ok(ids)
+}
+
+/// Notify employees whose standing availability matches newly posted OT
+/// requests (best-effort). Each employee gets one notification per call.
+pub async fn notify_matching_requests(pool: &PgPool, org_id: Uuid, request_ids: &[Uuid]) {
+    if request_ids.is_empty() {
+        return;
+    }
+
+    let requests = match sqlx::query!(
+        r#"
+        SELECT id, date, start_time, end_time, classification_id
+        FROM ot_requests
+        WHERE org_id = $1 AND id = ANY($2) AND status = 'open'
+        ORDER BY date, start_time
+        "#,
+        org_id,
+        request_ids,
+    )
+    .fetch_all(pool)
+    .await
+    {
+        Ok(rows) => rows,
+        Err(e) => {
+            tracing::warn!(org_id = %org_id, error = %e, "Failed to load OT requests for availability matching");
+            return;
+        }
+    };
+
+    // user_id -> matching requests, in date order
+    let mut matches: BTreeMap<Uuid, Vec<(Uuid, Date)>> = BTreeMap::new();
+    for r in &requests {
+        match available_user_ids(
+            pool,
+            org_id,
+            Some(r.classification_id),
+            r.date,
+            r.start_time,
+            r.end_time,
+        )
+        .await
+        {
+            Ok(users) => {
+                for user_id in users {
+                    matches.entry(user_id).or_default().push((r.id, r.date));
+                }
+            }
+            Err(e) => {
+                tracing::warn!(org_id = %org_id, error = %e, "Failed to match standing OT availability");
+            }
+        }
+    }
+
+    for (user_id, reqs) in matches {
+        let (message, link, source_id) = match reqs.as_slice() {
+            [(id, date)] => (
+                format!("A new OT request on {date} matches your standing availability"),
+                format!("/ot-requests/{id}"),
+                Some(*id),
+            ),
+            _ => (
+                format!(
+                    "{} new OT requests match your standing availability",
+                    reqs.len()
+                ),
+                "/available-ot".to_string(),
+                None,
+            ),
+        };
+        let _ = create_notification(
+            pool,
+            CreateNotificationParams {
+                org_id,
+                user_id,
+                notification_type: "ot_availability_match",
+                title: "OT matches your availability",
+                message: &message,
+                link: Some(&link),
+                source_type: Some("ot_request"),
+                source_id,
+            },
+        )
+        .await;
+    }
+}
@@ -1,6 +1,6 @@
 //! Timezone helpers: convert between org-local wall-clock times and UTC.
 
-use chrono::{Datelike, TimeZone};
+use chrono::{Datelike, TimeZone, Timelike};
 use chrono_tz::Tz;
 
 use crate::error::{AppError, Result};
@@ -24,6 +24,21 @@ pub fn org_today(tz_str: &str) -> time::Date {
     .unwrap()
 }
 
+/// Current wall-clock date and time in the given timezone.
+pub fn org_now(tz_str: &str) -> time::PrimitiveDateTime {
+    let tz = tz_str.parse::<Tz>().unwrap_or(chrono_tz::UTC);
+    let local = chrono::Utc::now().with_timezone(&tz);
+    let date = time::Date::from_calendar_date(
+        local.year(),
+        time::Month::try_from(local.month() as u8).unwrap(),
+        local.day() as u8,
+    )
+    .unwrap();
+    let time = time::Time::from_hms(local.hour() as u8, local.minute() as u8, local.second() as u8)
+        .unwrap();
+    time::PrimitiveDateTime::new(date, time)
+}
+
 /// Compute fiscal year for a given date based on the org's fiscal year start month.
 /// If fiscal year starts in month M (e.g. 7 = July), then dates before July belong
 /// to the previous calendar year's fiscal year.
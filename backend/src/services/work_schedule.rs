@@ -0,0 +1,158 @@
+//! Employees' working schedules resolved from shift pattern assignments and
+//! slot assignments, so leave can be charged for the hours actually scheduled.
+
+use std::collections::HashMap;
+
+use sqlx::PgPool;
+use time::Date;
+use uuid::Uuid;
+
+use crate::error::Result;
+use crate::models::shift_pattern::ShiftPattern;
+
+/// A slot held for a schedule period: the weekdays worked (a job-share partner's
+/// share of them) and its shift length.
+struct SlotSpan {
+    start_date: Date,
+    end_date: Date,
+    days_of_week: Vec<i32>,
+    hours: f64,
+}
+
+/// A shift pattern followed from `effective_from` until `effective_to` (open-ended if NULL).
+struct PatternSpan {
+    effective_from: Date,
+    effective_to: Option<Date>,
+    pattern: ShiftPattern,
+}
+
+/// One employee's schedule, loaded once so hours can be computed per date
+/// without further queries.
+#[derive(Default)]
+pub struct WorkSchedule {
+    slots: Vec<SlotSpan>,
+    patterns: Vec<PatternSpan>,
+}
+
+impl WorkSchedule {
+    /// Load the schedules of `user_ids`. Users with neither a slot assignment
+    /// nor a shift pattern assignment are absent from the map.
+    pub async fn load(
+        pool: &PgPool,
+        org_id: Uuid,
+        user_ids: &[Uuid],
+    ) -> Result<HashMap<Uuid, WorkSchedule>> {
+        let mut schedules: HashMap<Uuid, WorkSchedule> = HashMap::new();
+
+        let slots = sqlx::query!(
+            r#"
+            SELECT sa.user_id, sp.start_date, sp.end_date,
+                   COALESCE(sa.days_of_week, sl.days_of_week) AS "days_of_week!",
+                   st.duration_minutes
+            FROM slot_assignments sa
+            JOIN schedule_periods sp ON sp.id = sa.period_id
+            JOIN shift_slots sl      ON sl.id = sa.slot_id
+            JOIN shift_templates st  ON st.id = sl.shift_template_id
+            WHERE sp.org_id = $1 AND sa.user_id = ANY($2)
+            "#,
+            org_id,
+            user_ids,
+        )
+        .fetch_all(pool)
+        .await?;
+        for r in slots {
+            schedules.entry(r.user_id).or_default().slots.push(SlotSpan {
+                start_date: r.start_date,
+                end_date: r.end_date,
+                days_of_week: r.days_of_week,
+                hours: r.duration_minutes as f64 / 60.0,
+            });
+        }
+
+        let patterns = sqlx::query!(
+            r#"
+            SELECT spa.user_id, spa.effective_from, spa.effective_to,
+                   sp.id, sp.org_id, sp.name, sp.pattern_days, sp.work_days, sp.off_days,
+                   sp.anchor_date, sp.team_id AS "team_id?", sp.is_active,
+                   sp.work_days_in_cycle, sp.created_at, sp.updated_at
+            FROM shift_pattern_assignments spa
+            JOIN shift_patterns sp ON sp.id = spa.pattern_id
+            WHERE spa.org_id = $1 AND spa.user_id = ANY($2) AND sp.is_active = true
+            "#,
+            org_id,
+            user_ids,
+        )
+        .fetch_all(pool)
+        .await?;
+        for r in patterns {
+            schedules.entry(r.user_id).or_default().patterns.push(PatternSpan {
+                effective_from: r.effective_from,
+                effective_to: r.effective_to,
+                pattern: ShiftPattern {
+                    id: r.id,
+                    org_id: r.org_id,
+                    name: r.name,
+                    pattern_days: r.pattern_days,
+                    work_days: r.work_days,
+                    off_days: r.off_days,
+                    anchor_date: r.anchor_date,
+                    team_id: r.team_id,
+                    is_active: r.is_active,
+                    work_days_in_cycle: r.work_days_in_cycle,
+                    created_at: r.created_at,
+                    updated_at: r.updated_at,
+                },
+            });
+        }
+
+        Ok(schedules)
+    }
+
+    /// Hours scheduled on `date`, or `None` when neither a slot assignment nor a
+    /// shift pattern covers it.
+    ///
+    /// A shift pattern decides whether the day is worked (rotations like 4/10 or
+    /// Pitman); the slot held that day gives the shift length, falling back to
+    /// `default_hours` when the employee holds no slot. Without a pattern, the
+    /// slots' weekdays decide. Only the latest-starting schedule period covering
+    /// the date counts, so overlapping periods aren't charged twice.
+    pub fn hours_on(&self, date: Date, default_hours: f64) -> Option<f64> {
+        let latest_start = self
+            .slots
+            .iter()
+            .filter(|s| s.start_date <= date && date <= s.end_date)
+            .map(|s| s.start_date)
+            .max();
+        let slots: Vec<&SlotSpan> = self
+            .slots
+            .iter()
+            .filter(|s| Some(s.start_date) == latest_start && date <= s.end_date)
+            .collect();
+        let dow = date.weekday().number_days_from_sunday() as i32;
+        let on_weekday = slots.iter().find(|s| s.days_of_week.contains(&dow));
+
+        let pattern = self.patterns.iter().find(|p| {
+            p.effective_from <= date && p.effective_to.is_none_or(|to| date <= to)
+        });
+        if let Some(p) = pattern {
+            if !p.pattern.is_work_day(p.pattern.cycle_day(date)) {
+                return Some(0.0);
+            }
+            let hours = on_weekday
+                .or(slots.first())
+                .map_or(default_hours, |s| s.hours);
+            return Some(hours);
+        }
+
+        if slots.is_empty() {
+            return None;
+        }
+        Some(
+            slots
+                .iter()
+                .filter(|s| s.days_of_week.contains(&dow))
+                .map(|s| s.hours)
+                .sum(),
+        )
+    }
+}
@@ -0,0 +1,302 @@
+mod common;
+
+use sqlx::PgPool;
+use time::{Duration, OffsetDateTime};
+use uuid::Uuid;
+
+fn unique_email(prefix: &str) -> String {
+    format!("{}+{}@test.local", prefix, &Uuid::new_v4().to_string()[..8])
+}
+
+async fn count(pool: &PgPool, sql: &str, id: Uuid) -> i64 {
+    sqlx::query_scalar(sql).bind(id).fetch_one(pool).await.unwrap()
+}
+
+#[tokio::test]
+async fn shift_bids_preview_process_and_roll_back() {
+    let (addr, pool) = common::setup_test_app().await;
+    let org_id = common::create_test_org(&pool, "bid-proc").await;
+    let admin_email = unique_email("bid-proc-admin");
+    let (_admin, admin_pw) = common::create_test_user(&pool, org_id, "admin", &admin_email).await;
+    let token = common::get_auth_token(addr, &admin_email, &admin_pw).await;
+    let emp_email = unique_email("bid-proc-emp");
+    let (first, emp_pw) = common::create_test_user(&pool, org_id, "employee", &emp_email).await;
+    let emp_token = common::get_auth_token(addr, &emp_email, &emp_pw).await;
+    let mut bidders = vec![first];
+    for _ in 0..5 {
+        bidders.push(common::create_test_user(&pool, org_id, "employee", &unique_email("bid-proc")).await.0);
+    }
+    sqlx::query("UPDATE users SET bargaining_unit = 'vccea' WHERE org_id = $1")
+        .bind(org_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let template = common::create_test_shift_template(&pool, org_id).await;
+    let class_id = common::create_test_classification(&pool, org_id).await;
+    let team_id = common::create_test_team(&pool, org_id, "Nights").await;
+    let flex = [
+        common::create_test_shift_slot(&pool, team_id, template, class_id, true).await,
+        common::create_test_shift_slot(&pool, team_id, template, class_id, true).await,
+        common::create_test_shift_slot(&pool, team_id, template, class_id, true).await,
+    ];
+    let regular = common::create_test_shift_slot(&pool, team_id, template, class_id, false).await;
+
+    let today = OffsetDateTime::now_utc().date();
+    let period_id = Uuid::new_v4();
+    sqlx::query(
+        "INSERT INTO schedule_periods (id, org_id, name, start_date, end_date, status, bargaining_unit) VALUES ($1, $2, '2027 Q3', $3, $4, 'open', 'vccea')",
+    )
+    .bind(period_id)
+    .bind(org_id)
+    .bind(today + Duration::days(30))
+    .bind(today + Duration::days(120))
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    // 1: first flex; 2: first flex (taken), then second; 3: third flex (cap), then
+    // the regular slot; 4: moved out of the unit; 5: no bid; 6: regular (taken).
+    let choices: [&[Uuid]; 6] = [&[flex[0]], &[flex[0], flex[1]], &[flex[2], regular], &[regular], &[], &[regular]];
+    let opens = OffsetDateTime::now_utc() - Duration::days(2);
+    for (i, (user, slots)) in bidders.iter().zip(choices).enumerate() {
+        let window_id = Uuid::new_v4();
+        sqlx::query(
+            "INSERT INTO bid_windows (id, period_id, user_id, seniority_rank, opens_at, closes_at, submitted_at) VALUES ($1, $2, $3, $4, $5, $6, $6)",
+        )
+        .bind(window_id)
+        .bind(period_id)
+        .bind(user)
+        .bind(i as i32 + 1)
+        .bind(opens)
+        .bind(opens + Duration::hours(1))
+        .execute(&pool)
+        .await
+        .unwrap();
+        for (rank, slot) in slots.iter().enumerate() {
+            sqlx::query("INSERT INTO bid_submissions (bid_window_id, slot_id, preference_rank) VALUES ($1, $2, $3)")
+                .bind(window_id)
+                .bind(slot)
+                .bind(rank as i32 + 1)
+                .execute(&pool)
+                .await
+                .unwrap();
+        }
+    }
+    sqlx::query("UPDATE users SET bargaining_unit = 'vcsg' WHERE id = $1")
+        .bind(bidders[3])
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let preview_path = format!("/api/schedule/periods/{period_id}/process-bids/preview");
+    assert_eq!(common::get(addr, &emp_token, &preview_path).await.status(), 403);
+    let resp = common::get(addr, &token, &preview_path).await;
+    assert_eq!(resp.status(), 200);
+    let table: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(table["awards_count"], 3);
+    let rows = table["rows"].as_array().unwrap();
+    assert_eq!(rows[0]["award"]["slot_id"], flex[0].to_string());
+    assert_eq!(rows[1]["award"]["slot_id"], flex[1].to_string());
+    assert_eq!(rows[1]["skipped"][0]["reason"], "taken");
+    assert_eq!(rows[2]["award"]["slot_id"], regular.to_string());
+    assert_eq!(rows[2]["skipped"][0]["reason"], "flex_cap");
+    assert_eq!(rows[3]["reason"], "bargaining_unit");
+    assert_eq!(rows[4]["reason"], "no_submission");
+    assert!(rows[5]["award"].is_null());
+    assert_eq!(rows[5]["reason"], "taken");
+
+    // Previewing writes nothing
+    let assigned = "SELECT COUNT(*) FROM slot_assignments WHERE period_id = $1";
+    assert_eq!(count(&pool, assigned, period_id).await, 0);
+
+    let process_path = format!("/api/schedule/periods/{period_id}/process-bids");
+    let resp = common::post_json(addr, &token, &process_path, serde_json::json!({})).await;
+    assert_eq!(resp.status(), 200);
+    let body: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(body["awards_count"], 3);
+    assert_eq!(count(&pool, assigned, period_id).await, 3);
+
+    let rollback_path = format!("/api/schedule/periods/{period_id}/rollback-bids");
+    assert_eq!(common::post_json(addr, &emp_token, &rollback_path, serde_json::json!({})).await.status(), 403);
+    let resp = common::post_json(addr, &token, &rollback_path, serde_json::json!({})).await;
+    assert_eq!(resp.status(), 200);
+    let body: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(body["awards_rolled_back"], 3);
+    assert_eq!(count(&pool, assigned, period_id).await, 0);
+    let awarded = "SELECT COUNT(*) FROM bid_submissions bs JOIN bid_windows bw ON bw.id = bs.bid_window_id WHERE bw.period_id = $1 AND bs.awarded";
+    assert_eq!(count(&pool, awarded, period_id).await, 0);
+    let status: String = sqlx::query_scalar("SELECT status::TEXT FROM schedule_periods WHERE id = $1")
+        .bind(period_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(status, "open");
+    assert_eq!(common::post_json(addr, &token, &rollback_path, serde_json::json!({})).await.status(), 400);
+
+    // The period can be processed again, and every run is on record
+    assert_eq!(common::post_json(addr, &token, &process_path, serde_json::json!({})).await.status(), 200);
+    let resp = common::get(addr, &token, &format!("/api/schedule/periods/{period_id}/bid-runs")).await;
+    assert_eq!(resp.status(), 200);
+    let runs: serde_json::Value = resp.json().await.unwrap();
+    let actions: Vec<&str> = runs.as_array().unwrap().iter().map(|r| r["action"].as_str().unwrap()).collect();
+    assert_eq!(actions, vec!["process", "rollback", "process"]);
+    assert_eq!(runs[1]["detail"]["awards"].as_array().unwrap().len(), 3);
+    assert_eq!(runs[2]["detail"]["rows"].as_array().unwrap().len(), 6);
+
+    common::cleanup_test_org(&pool, org_id).await;
+}
+
+#[tokio::test]
+async fn vacation_bids_preview_process_and_roll_back() {
+    let (addr, pool) = common::setup_test_app().await;
+    let org_id = common::create_test_org(&pool, "vac-proc").await;
+    let admin_email = unique_email("vac-proc-admin");
+    let (_admin, admin_pw) = common::create_test_user(&pool, org_id, "admin", &admin_email).await;
+    let token = common::get_auth_token(addr, &admin_email, &admin_pw).await;
+    let senior = common::create_test_user(&pool, org_id, "employee", &unique_email("vac-proc")).await.0;
+    let junior = common::create_test_user(&pool, org_id, "employee", &unique_email("vac-proc")).await.0;
+    let idle = common::create_test_user(&pool, org_id, "employee", &unique_email("vac-proc")).await.0;
+
+    let leave_type = common::create_test_leave_type(&pool, org_id, "vac", "Vacation").await;
+    sqlx::query("UPDATE leave_types SET category = 'vacation' WHERE id = $1")
+        .bind(leave_type)
+        .execute(&pool)
+        .await
+        .unwrap();
+    for (user, hours) in [(senior, 100), (junior, 20)] {
+        sqlx::query(
+            "INSERT INTO leave_balances (id, org_id, user_id, leave_type_id, balance_hours, as_of_date) VALUES ($1, $2, $3, $4, $5, CURRENT_DATE)",
+        )
+        .bind(Uuid::new_v4())
+        .bind(org_id)
+        .bind(user)
+        .bind(leave_type)
+        .bind(hours)
+        .execute(&pool)
+        .await
+        .unwrap();
+    }
+    sqlx::query(
+        "INSERT INTO org_settings (id, org_id, key, value, updated_at) VALUES ($1, $2, 'max_concurrent_vacation', '1'::jsonb, NOW())",
+    )
+    .bind(Uuid::new_v4())
+    .bind(org_id)
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let period_id = Uuid::new_v4();
+    sqlx::query("INSERT INTO vacation_bid_periods (id, org_id, year, round, status) VALUES ($1, $2, 2027, 1, 'open')")
+        .bind(period_id)
+        .bind(org_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let d = |s: &str| time::Date::parse(s, &time::format_description::well_known::Iso8601::DATE).unwrap();
+    let picks: [(Uuid, &[(&str, &str)]); 3] = [
+        (senior, &[("2027-07-05", "2027-07-09"), ("2027-08-02", "2027-08-04")]),
+        // Overlaps the senior pick; then 24 hours against a 20 hour balance; then 16 hours
+        (junior, &[("2027-07-07", "2027-07-08"), ("2027-09-01", "2027-09-03"), ("2027-10-04", "2027-10-05")]),
+        (idle, &[]),
+    ];
+    let opens = OffsetDateTime::now_utc() - Duration::days(2);
+    for (i, (user, ranges)) in picks.iter().enumerate() {
+        let window_id = Uuid::new_v4();
+        sqlx::query(
+            "INSERT INTO vacation_bid_windows (id, vacation_bid_period_id, user_id, seniority_rank, opens_at, closes_at) VALUES ($1, $2, $3, $4, $5, $6)",
+        )
+        .bind(window_id)
+        .bind(period_id)
+        .bind(user)
+        .bind(i as i32 + 1)
+        .bind(opens)
+        .bind(opens + Duration::hours(1))
+        .execute(&pool)
+        .await
+        .unwrap();
+        for (rank, (start, end)) in ranges.iter().enumerate() {
+            sqlx::query(
+                "INSERT INTO vacation_bids (vacation_bid_window_id, start_date, end_date, preference_rank) VALUES ($1, $2, $3, $4)",
+            )
+            .bind(window_id)
+            .bind(d(start))
+            .bind(d(end))
+            .bind(rank as i32 + 1)
+            .execute(&pool)
+            .await
+            .unwrap();
+        }
+    }
+
+    let resp = common::get(addr, &token, &format!("/api/vacation-bids/periods/{period_id}/process-bids/preview")).await;
+    assert_eq!(resp.status(), 200);
+    let table: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(table["awards_count"], 3);
+    let rows = table["rows"].as_array().unwrap();
+    assert_eq!(rows[0]["awards"].as_array().unwrap().len(), 2);
+    assert_eq!(rows[0]["awards"][0]["hours"], 40.0);
+    assert_eq!(rows[1]["skipped"][0]["reason"], "date_conflict");
+    assert_eq!(rows[1]["skipped"][1]["reason"], "insufficient_balance");
+    assert_eq!(rows[1]["awards"][0]["start_date"], "2027-10-04");
+    assert_eq!(rows[2]["reason"], "no_submission");
+
+    let requests = "SELECT COUNT(*) FROM leave_requests WHERE org_id = $1";
+    assert_eq!(count(&pool, requests, org_id).await, 0);
+
+    let balance = |user: Uuid| {
+        let pool = pool.clone();
+        async move {
+            sqlx::query_scalar::<_, f64>("SELECT balance_hours::FLOAT8 FROM leave_balances WHERE user_id = $1")
+                .bind(user)
+                .fetch_one(&pool)
+                .await
+                .unwrap()
+        }
+    };
+
+    let process_path = format!("/api/vacation-bids/periods/{period_id}/process-bids");
+    let resp = common::post_json(addr, &token, &process_path, serde_json::json!({})).await;
+    assert_eq!(resp.status(), 200);
+    assert_eq!(count(&pool, requests, org_id).await, 3);
+    assert_eq!(balance(senior).await, 36.0);
+    assert_eq!(balance(junior).await, 4.0);
+
+    let rollback_path = format!("/api/vacation-bids/periods/{period_id}/rollback-bids");
+    let resp = common::post_json(addr, &token, &rollback_path, serde_json::json!({})).await;
+    assert_eq!(resp.status(), 200);
+    let body: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(body["awards_rolled_back"], 3);
+    assert_eq!(body["hours_refunded"], 80.0);
+    assert_eq!(count(&pool, requests, org_id).await, 0);
+    assert_eq!(balance(senior).await, 100.0);
+    assert_eq!(balance(junior).await, 20.0);
+    let rolled_back: Vec<String> = sqlx::query_scalar(
+        "SELECT title FROM notifications WHERE org_id = $1 AND notification_type = 'vacation_bid_rolled_back'",
+    )
+    .bind(org_id)
+    .fetch_all(&pool)
+    .await
+    .unwrap();
+    assert_eq!(rolled_back.len(), 2);
+
+    // Processed again, it can't be undone once a later round is processed too
+    let resp = common::post_json(addr, &token, &process_path, serde_json::json!({})).await;
+    assert_eq!(resp.status(), 200);
+    sqlx::query("INSERT INTO vacation_bid_periods (org_id, year, round, status) VALUES ($1, 2027, 2, 'completed')")
+        .bind(org_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    let resp = common::post_json(addr, &token, &rollback_path, serde_json::json!({})).await;
+    assert_eq!(resp.status(), 409);
+
+    let resp = common::get(addr, &token, &format!("/api/vacation-bids/periods/{period_id}/bid-runs")).await;
+    let runs: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(runs.as_array().unwrap().len(), 3);
+    assert_eq!(runs[1]["action"], "rollback");
+    assert_eq!(runs[1]["detail"]["hours_refunded"], 80.0);
+
+    common::cleanup_test_org(&pool, org_id).await;
+}
@@ -0,0 +1,262 @@
+mod common;
+
+use sqlx::PgPool;
+use time::{Duration, OffsetDateTime};
+use uuid::Uuid;
+
+use timeshift_backend::services::bid_scheduler::sweep_org;
+
+fn unique_email(prefix: &str) -> String {
+    format!("{}+{}@test.local", prefix, &Uuid::new_v4().to_string()[..8])
+}
+
+async fn proxy_notices(pool: &PgPool, user_id: Uuid) -> Vec<String> {
+    sqlx::query_scalar(
+        "SELECT title FROM notifications WHERE user_id = $1 AND notification_type = 'bid_proxy_submitted' ORDER BY created_at",
+    )
+    .bind(user_id)
+    .fetch_all(pool)
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn shift_plan_submits_on_expiry_and_proxy_can_bid() {
+    let (addr, pool) = common::setup_test_app().await;
+    let org_id = common::create_test_org(&pool, "bid-proxy").await;
+    let sup_email = unique_email("bid-proxy-sup");
+    let (sup, sup_pw) = common::create_test_user(&pool, org_id, "supervisor", &sup_email).await;
+    let sup_token = common::get_auth_token(addr, &sup_email, &sup_pw).await;
+    let away_email = unique_email("bid-proxy-away");
+    let (away, away_pw) = common::create_test_user(&pool, org_id, "employee", &away_email).await;
+    let away_token = common::get_auth_token(addr, &away_email, &away_pw).await;
+    let owner_email = unique_email("bid-proxy-owner");
+    let (owner, owner_pw) = common::create_test_user(&pool, org_id, "employee", &owner_email).await;
+    let owner_token = common::get_auth_token(addr, &owner_email, &owner_pw).await;
+    let proxy_email = unique_email("bid-proxy-proxy");
+    let (proxy, proxy_pw) = common::create_test_user(&pool, org_id, "employee", &proxy_email).await;
+    let proxy_token = common::get_auth_token(addr, &proxy_email, &proxy_pw).await;
+    let other_email = unique_email("bid-proxy-other");
+    let (_other, other_pw) = common::create_test_user(&pool, org_id, "employee", &other_email).await;
+    let other_token = common::get_auth_token(addr, &other_email, &other_pw).await;
+
+    let template = common::create_test_shift_template(&pool, org_id).await;
+    let class_id = common::create_test_classification(&pool, org_id).await;
+    let team_id = common::create_test_team(&pool, org_id, "Days").await;
+    let taken = common::create_test_shift_slot(&pool, team_id, template, class_id, false).await;
+    let second_choice = common::create_test_shift_slot(&pool, team_id, template, class_id, false).await;
+    let third_choice = common::create_test_shift_slot(&pool, team_id, template, class_id, false).await;
+
+    let today = OffsetDateTime::now_utc().date();
+    let period_id = Uuid::new_v4();
+    sqlx::query(
+        "INSERT INTO schedule_periods (id, org_id, name, start_date, end_date, status) VALUES ($1, $2, '2027 Q2', $3, $4, 'open')",
+    )
+    .bind(period_id)
+    .bind(org_id)
+    .bind(today + Duration::days(30))
+    .bind(today + Duration::days(120))
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let plan_path = format!("/api/schedule/periods/{period_id}/proxy-plan");
+    let resp = common::send(reqwest::Method::PUT, addr, &away_token, &plan_path, serde_json::json!({})).await;
+    assert_eq!(resp.status(), 400);
+    let resp = common::send(
+        reqwest::Method::PUT,
+        addr,
+        &away_token,
+        &plan_path,
+        serde_json::json!({ "proxy_user_id": away }),
+    )
+    .await;
+    assert_eq!(resp.status(), 400);
+
+    // Ranked list only, submitted if the window lapses
+    let resp = common::send(
+        reqwest::Method::PUT,
+        addr,
+        &away_token,
+        &plan_path,
+        serde_json::json!({
+            "preferences": [
+                { "slot_id": taken, "preference_rank": 1 },
+                { "slot_id": second_choice, "preference_rank": 2 },
+                { "slot_id": third_choice, "preference_rank": 3 },
+            ],
+        }),
+    )
+    .await;
+    assert_eq!(resp.status(), 200);
+    let plan: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(plan["submit_on"], "expiry");
+    assert_eq!(plan["preferences"].as_array().unwrap().len(), 3);
+
+    // The owner names a proxy coworker instead
+    let resp = common::send(
+        reqwest::Method::PUT,
+        addr,
+        &owner_token,
+        &plan_path,
+        serde_json::json!({ "proxy_user_id": proxy }),
+    )
+    .await;
+    assert_eq!(resp.status(), 200);
+
+    // Someone senior already holds the first choice
+    sqlx::query("INSERT INTO slot_assignments (slot_id, user_id, period_id) VALUES ($1, $2, $3)")
+        .bind(taken)
+        .bind(sup)
+        .bind(period_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    // The away employee's window closed unsubmitted; the owner's is open now
+    let start = OffsetDateTime::now_utc() - Duration::minutes(70);
+    let away_window = Uuid::new_v4();
+    let owner_window = Uuid::new_v4();
+    for (i, (id, user)) in [(away_window, away), (owner_window, owner)].into_iter().enumerate() {
+        let opens = start + Duration::hours(i as i64);
+        sqlx::query(
+            "INSERT INTO bid_windows (id, period_id, user_id, seniority_rank, opens_at, closes_at, unlocked_at) VALUES ($1, $2, $3, $4, $5, $6, $7)",
+        )
+        .bind(id)
+        .bind(period_id)
+        .bind(user)
+        .bind(i as i32 + 1)
+        .bind(opens)
+        .bind(opens + Duration::hours(1))
+        .bind(if i == 0 { Some(opens) } else { None })
+        .execute(&pool)
+        .await
+        .unwrap();
+    }
+
+    let result = sweep_org(&pool, None, org_id).await.unwrap();
+    assert_eq!(result.advanced, 1);
+
+    let submitted: Vec<(Uuid, i32)> = sqlx::query_as(
+        "SELECT slot_id, preference_rank FROM bid_submissions WHERE bid_window_id = $1 ORDER BY preference_rank",
+    )
+    .bind(away_window)
+    .fetch_all(&pool)
+    .await
+    .unwrap();
+    assert_eq!(submitted, vec![(second_choice, 1), (third_choice, 2)]);
+    let (submitted_at, auto_advanced_at): (Option<OffsetDateTime>, Option<OffsetDateTime>) =
+        sqlx::query_as("SELECT submitted_at, auto_advanced_at FROM bid_windows WHERE id = $1")
+            .bind(away_window)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+    assert!(submitted_at.is_some());
+    assert!(auto_advanced_at.is_none());
+    assert_eq!(proxy_notices(&pool, away).await, vec!["Your bid was submitted from your preferences"]);
+
+    // The owner's window is unlocked; only their proxy can bid for them
+    let submit_path = format!("/api/bid-windows/{owner_window}/submit");
+    let bid = serde_json::json!({ "preferences": [{ "slot_id": third_choice, "preference_rank": 1 }] });
+    let resp = common::post_json(addr, &other_token, &submit_path, bid.clone()).await;
+    assert_eq!(resp.status(), 403);
+    let resp = common::get(addr, &proxy_token, &format!("/api/bid-windows/{owner_window}")).await;
+    assert_eq!(resp.status(), 200);
+    let resp = common::post_json(addr, &proxy_token, &submit_path, bid).await;
+    assert_eq!(resp.status(), 200);
+    assert_eq!(proxy_notices(&pool, owner).await, vec!["Your proxy submitted your bid"]);
+
+    let actions_path = format!("/api/schedule/periods/{period_id}/proxy-actions");
+    let resp = common::get(addr, &sup_token, &actions_path).await;
+    assert_eq!(resp.status(), 200);
+    let actions: serde_json::Value = resp.json().await.unwrap();
+    let actions = actions.as_array().unwrap();
+    assert_eq!(actions.len(), 2);
+    assert_eq!(actions[0]["trigger"], "proxy");
+    assert_eq!(actions[0]["actor_id"], proxy.to_string());
+    assert_eq!(actions[1]["trigger"], "window_expired");
+    assert!(actions[1]["actor_id"].is_null());
+    assert_eq!(actions[1]["unavailable"][0]["slot_id"], taken.to_string());
+
+    // Employees only see bids placed for or by them
+    let resp = common::get(addr, &away_token, &actions_path).await;
+    let mine: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(mine.as_array().unwrap().len(), 1);
+    let resp = common::get(addr, &other_token, &actions_path).await;
+    let none: serde_json::Value = resp.json().await.unwrap();
+    assert!(none.as_array().unwrap().is_empty());
+
+    let resp = common::send(reqwest::Method::DELETE, addr, &owner_token, &plan_path, serde_json::json!({})).await;
+    assert_eq!(resp.status(), 200);
+    let resp = common::get(addr, &owner_token, &plan_path).await;
+    assert_eq!(resp.status(), 404);
+
+    common::cleanup_test_org(&pool, org_id).await;
+}
+
+#[tokio::test]
+async fn vacation_plan_submits_when_window_opens() {
+    let (addr, pool) = common::setup_test_app().await;
+    let org_id = common::create_test_org(&pool, "vac-proxy").await;
+    let emp_email = unique_email("vac-proxy-emp");
+    let (emp, emp_pw) = common::create_test_user(&pool, org_id, "employee", &emp_email).await;
+    let emp_token = common::get_auth_token(addr, &emp_email, &emp_pw).await;
+
+    let period_id = Uuid::new_v4();
+    sqlx::query("INSERT INTO vacation_bid_periods (id, org_id, year, round, status) VALUES ($1, $2, 2027, 2, 'open')")
+        .bind(period_id)
+        .bind(org_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let plan_path = format!("/api/vacation-bids/periods/{period_id}/proxy-plan");
+    let resp = common::send(
+        reqwest::Method::PUT,
+        addr,
+        &emp_token,
+        &plan_path,
+        serde_json::json!({
+            "submit_on": "open",
+            "picks": [
+                { "start_date": "2027-07-05", "end_date": "2027-07-09", "preference_rank": 1 },
+                { "start_date": "2027-08-02", "end_date": "2027-08-04", "preference_rank": 2 },
+            ],
+        }),
+    )
+    .await;
+    assert_eq!(resp.status(), 200);
+
+    let window_id = Uuid::new_v4();
+    sqlx::query(
+        "INSERT INTO vacation_bid_windows (id, vacation_bid_period_id, user_id, seniority_rank, opens_at, closes_at) VALUES ($1, $2, $3, 1, $4, $5)",
+    )
+    .bind(window_id)
+    .bind(period_id)
+    .bind(emp)
+    .bind(OffsetDateTime::now_utc() - Duration::minutes(5))
+    .bind(OffsetDateTime::now_utc() + Duration::hours(2))
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let result = sweep_org(&pool, None, org_id).await.unwrap();
+    assert_eq!(result.proxy_bids, 1);
+    // Each window is bid on once, even if the employee later edits it
+    let again = sweep_org(&pool, None, org_id).await.unwrap();
+    assert_eq!(again.proxy_bids, 0);
+
+    let resp = common::get(addr, &emp_token, &format!("/api/vacation-bids/bid-windows/{window_id}")).await;
+    assert_eq!(resp.status(), 200);
+    let detail: serde_json::Value = resp.json().await.unwrap();
+    assert!(detail["window"]["submitted_at"].is_string());
+    assert_eq!(detail["bids"].as_array().unwrap().len(), 2);
+    assert_eq!(detail["bids"][0]["start_date"], "2027-07-05");
+
+    let resp = common::get(addr, &emp_token, &format!("/api/vacation-bids/periods/{period_id}/proxy-actions")).await;
+    let actions: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(actions[0]["trigger"], "window_open");
+    assert_eq!(actions[0]["submitted"].as_array().unwrap().len(), 2);
+
+    common::cleanup_test_org(&pool, org_id).await;
+}
@@ -0,0 +1,284 @@
+mod common;
+
+use std::net::SocketAddr;
+
+use serde_json::{json, Value};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+fn unique_email(prefix: &str) -> String {
+    format!("{}+{}@test.local", prefix, &Uuid::new_v4().to_string()[..8])
+}
+
+/// A shift bid period that has been bid on and processed but not published:
+/// a senior and a junior bidder both wanted days (the junior fell through to
+/// nights) and a third never submitted.
+struct ProcessedPeriod {
+    addr: SocketAddr,
+    pool: PgPool,
+    org_id: Uuid,
+    period_id: Uuid,
+    admin_token: String,
+    supervisor_token: String,
+    employee_token: String,
+    bidders: [Uuid; 3],
+    nights: Uuid,
+}
+
+impl ProcessedPeriod {
+    fn results(&self) -> String {
+        format!("/api/schedule/periods/{}/bid-results", self.period_id)
+    }
+}
+
+async fn processed_period(name: &str) -> ProcessedPeriod {
+    let (addr, pool) = common::setup_test_app().await;
+    let org_id = common::create_test_org(&pool, name).await;
+    let admin_email = unique_email("bid-results-admin");
+    let (admin, admin_pw) = common::create_test_user(&pool, org_id, "admin", &admin_email).await;
+    let admin_token = common::get_auth_token(addr, &admin_email, &admin_pw).await;
+    sqlx::query("UPDATE users SET first_name = 'Avery', last_name = 'Admin' WHERE id = $1")
+        .bind(admin)
+        .execute(&pool)
+        .await
+        .unwrap();
+    let supervisor_email = unique_email("bid-results-supervisor");
+    let (_, supervisor_pw) = common::create_test_user(&pool, org_id, "supervisor", &supervisor_email).await;
+    let supervisor_token = common::get_auth_token(addr, &supervisor_email, &supervisor_pw).await;
+
+    let mut bidders = Vec::new();
+    for (last_name, seniority) in [("Senior", "2005-01-01"), ("Junior", "2010-01-01"), ("Absent", "2015-01-01")] {
+        let email = unique_email("bid-results");
+        let (id, _) = common::create_test_user(&pool, org_id, "employee", &email).await;
+        sqlx::query("UPDATE users SET first_name = 'Sam', last_name = $2 WHERE id = $1")
+            .bind(id)
+            .bind(last_name)
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO seniority_records (user_id, org_id, overall_seniority_date) VALUES ($1, $2, $3::DATE)")
+            .bind(id)
+            .bind(org_id)
+            .bind(seniority)
+            .execute(&pool)
+            .await
+            .unwrap();
+        bidders.push(id);
+    }
+    let (senior, junior) = (bidders[0], bidders[1]);
+    let employee_email = unique_email("bid-results-viewer");
+    let (_viewer, viewer_pw) = common::create_test_user(&pool, org_id, "employee", &employee_email).await;
+    let employee_token = common::get_auth_token(addr, &employee_email, &viewer_pw).await;
+
+    let team = common::create_test_team(&pool, org_id, "Results team").await;
+    let template = common::create_test_shift_template(&pool, org_id).await;
+    let class_id = common::create_test_classification(&pool, org_id).await;
+    let days = common::create_test_shift_slot(&pool, team, template, class_id, false).await;
+    let nights = common::create_test_shift_slot(&pool, team, template, class_id, false).await;
+    let period_id = Uuid::new_v4();
+    sqlx::query(
+        "INSERT INTO schedule_periods (id, org_id, name, start_date, end_date) VALUES ($1, $2, 'Results period', '2027-01-01', '2027-06-30')",
+    )
+    .bind(period_id)
+    .bind(org_id)
+    .execute(&pool)
+    .await
+    .unwrap();
+    let resp = common::post_json(
+        addr,
+        &admin_token,
+        &format!("/api/schedule/periods/{period_id}/open-bidding"),
+        json!({ "window_duration_hours": 24 }),
+    )
+    .await;
+    assert_eq!(resp.status(), 200);
+
+    // Both bidders want the day slot; the junior also ranks nights second
+    let bids: [(Uuid, &[Uuid]); 2] = [(senior, &[days]), (junior, &[days, nights])];
+    for (user, slots) in bids {
+        let window: Uuid = sqlx::query_scalar(
+            "UPDATE bid_windows SET submitted_at = NOW() WHERE period_id = $1 AND user_id = $2 RETURNING id",
+        )
+        .bind(period_id)
+        .bind(user)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        for (rank, slot_id) in slots.iter().enumerate() {
+            sqlx::query("INSERT INTO bid_submissions (bid_window_id, slot_id, preference_rank) VALUES ($1, $2, $3)")
+                .bind(window)
+                .bind(slot_id)
+                .bind(rank as i32 + 1)
+                .execute(&pool)
+                .await
+                .unwrap();
+        }
+        if user == senior {
+            let approve = format!("/api/bid-windows/{window}/approve");
+            assert_eq!(common::post_json(addr, &admin_token, &approve, json!({})).await.status(), 200);
+        }
+    }
+    let process = format!("/api/schedule/periods/{period_id}/process-bids");
+    assert_eq!(common::post_json(addr, &admin_token, &process, json!({})).await.status(), 200);
+
+    ProcessedPeriod {
+        addr,
+        pool,
+        org_id,
+        period_id,
+        admin_token,
+        supervisor_token,
+        employee_token,
+        bidders: [bidders[0], bidders[1], bidders[2]],
+        nights,
+    }
+}
+
+/// The report rows for the fixture's bidders, in sheet order.
+fn bidder_rows<'a>(report: &'a Value, bidders: &[Uuid]) -> Vec<&'a Value> {
+    report["rows"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|r| bidders.contains(&r["user_id"].as_str().unwrap().parse().unwrap()))
+        .collect()
+}
+
+#[tokio::test]
+async fn results_sheet_shows_seniority_bids_awards_and_approvals() {
+    let p = processed_period("bid-results").await;
+    let (addr, results) = (p.addr, p.results());
+    let [senior, _, absent] = p.bidders;
+
+    assert_eq!(common::post_json(addr, &p.admin_token, &format!("{results}/publish"), json!({})).await.status(), 200);
+
+    let report = common::get_json(addr, &p.employee_token, &results).await;
+    assert!(report["published_at"].is_string());
+    assert_eq!(report["processed_by_last_name"], "Admin");
+    assert_eq!(report["published_by_last_name"], "Admin");
+    let rows = bidder_rows(&report, &p.bidders);
+    assert_eq!(rows.len(), 3);
+    assert_eq!(rows[0]["user_id"], json!(senior));
+    assert_eq!(rows[0]["approved_by_last_name"], "Admin");
+    assert_eq!(rows[0]["preferences"][0]["awarded"], true);
+    assert_eq!(rows[1]["preferences"][0]["skip_reason"], "taken");
+    assert_eq!(rows[1]["preferences"][1]["awarded"], true);
+    assert_eq!(rows[1]["award"]["slot_id"], json!(p.nights));
+    assert_eq!(rows[2]["user_id"], json!(absent));
+    assert_eq!(rows[2]["reason"], "no_submission");
+
+    common::cleanup_test_org(&p.pool, p.org_id).await;
+}
+
+#[tokio::test]
+async fn unpublished_results_are_for_schedule_managers_only() {
+    let p = processed_period("bid-results-unpublished").await;
+    let (addr, results) = (p.addr, p.results());
+
+    // Employees are refused in every format until the sheet is posted
+    for format in ["json", "csv", "pdf"] {
+        let resp = common::get(addr, &p.employee_token, &format!("{results}?format={format}")).await;
+        assert_eq!(resp.status(), 403, "{format}");
+    }
+
+    // Supervisors can review it but only an admin posts it
+    let report = common::get_json(addr, &p.supervisor_token, &results).await;
+    assert!(report["published_at"].is_null());
+    assert_eq!(bidder_rows(&report, &p.bidders).len(), 3);
+    let publish = format!("{results}/publish");
+    assert_eq!(common::post_json(addr, &p.employee_token, &publish, json!({})).await.status(), 403);
+    assert_eq!(common::post_json(addr, &p.supervisor_token, &publish, json!({})).await.status(), 403);
+
+    let report = common::get_json(addr, &p.admin_token, &results).await;
+    assert!(report["published_at"].is_null());
+
+    assert_eq!(common::post_json(addr, &p.admin_token, &publish, json!({})).await.status(), 200);
+    let report = common::get_json(addr, &p.employee_token, &results).await;
+    assert!(report["published_at"].is_string());
+
+    common::cleanup_test_org(&p.pool, p.org_id).await;
+}
+
+#[tokio::test]
+async fn rollback_withdraws_published_results() {
+    let p = processed_period("bid-results-rollback").await;
+    let (addr, results) = (p.addr, p.results());
+
+    assert_eq!(common::post_json(addr, &p.admin_token, &format!("{results}/publish"), json!({})).await.status(), 200);
+    assert_eq!(common::get(addr, &p.employee_token, &results).await.status(), 200);
+
+    let rollback = format!("/api/schedule/periods/{}/rollback-bids", p.period_id);
+    assert_eq!(common::post_json(addr, &p.admin_token, &rollback, json!({})).await.status(), 200);
+
+    for format in ["json", "csv", "pdf"] {
+        let resp = common::get(addr, &p.employee_token, &format!("{results}?format={format}")).await;
+        assert_eq!(resp.status(), 403, "{format}");
+    }
+    let report = common::get_json(addr, &p.admin_token, &results).await;
+    assert!(report["processed_at"].is_null());
+    assert!(report["published_at"].is_null());
+    assert!(report["published_by_last_name"].is_null());
+    let rows = bidder_rows(&report, &p.bidders);
+    assert!(rows.iter().all(|r| r["award"].is_null()));
+    assert!(rows[1]["preferences"][0]["skip_reason"].is_null());
+
+    // A rolled-back period is no longer processed, so it can't be reposted
+    let resp = common::post_json(addr, &p.admin_token, &format!("{results}/publish"), json!({})).await;
+    assert_eq!(resp.status(), 400);
+
+    common::cleanup_test_org(&p.pool, p.org_id).await;
+}
+
+#[tokio::test]
+async fn exports_carry_content_types_and_one_row_per_choice() {
+    let p = processed_period("bid-results-export").await;
+    let (addr, results) = (p.addr, p.results());
+    assert_eq!(common::post_json(addr, &p.admin_token, &format!("{results}/publish"), json!({})).await.status(), 200);
+    let report = common::get_json(addr, &p.employee_token, &results).await;
+
+    let csv = common::get(addr, &p.employee_token, &format!("{results}?format=csv")).await;
+    assert_eq!(csv.status(), 200);
+    assert_eq!(csv.headers()["content-type"], "text/csv; charset=utf-8");
+    assert_eq!(
+        csv.headers()["content-disposition"],
+        format!("attachment; filename=\"bid-results-{}.csv\"", p.period_id).as_str()
+    );
+    let csv = csv.text().await.unwrap();
+    let lines: Vec<&str> = csv.split_terminator("\r\n").collect();
+    assert!(lines[0].starts_with("Rank,Employee,Job-share partner,"));
+    assert!(lines[0].ends_with(",Choice,Slot,Partner days,Result"));
+
+    // One line per ranked choice, or a single line for a window with none
+    let expected: usize = report["rows"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|r| r["preferences"].as_array().unwrap().len().max(1))
+        .sum();
+    assert_eq!(lines.len(), expected + 1);
+
+    let bidder_lines: Vec<&str> = lines.iter().copied().filter(|l| l.contains(", Sam\"")).collect();
+    assert_eq!(bidder_lines.len(), 4);
+    assert!(bidder_lines[0].contains("\"Senior, Sam\"") && bidder_lines[0].ends_with(",Awarded"));
+    assert!(bidder_lines[1].contains("\"Junior, Sam\"") && bidder_lines[1].ends_with(",Skipped: taken by senior bidder"));
+    assert!(bidder_lines[2].contains("\"Junior, Sam\"") && bidder_lines[2].ends_with(",Awarded"));
+    assert!(bidder_lines[3].contains("\"Absent, Sam\"") && bidder_lines[3].ends_with(",Not awarded: no submission"));
+
+    let pdf = common::get(addr, &p.employee_token, &format!("{results}?format=pdf")).await;
+    assert_eq!(pdf.status(), 200);
+    assert_eq!(pdf.headers()["content-type"], "application/pdf");
+    assert_eq!(
+        pdf.headers()["content-disposition"],
+        format!("attachment; filename=\"bid-results-{}.pdf\"", p.period_id).as_str()
+    );
+    let pdf = pdf.bytes().await.unwrap();
+    assert!(pdf.starts_with(b"%PDF-"));
+    assert!(pdf.ends_with(b"%%EOF\n"));
+    let text = String::from_utf8_lossy(&pdf);
+    assert!(text.contains("(Shift bid results: Results period) Tj"));
+    for name in ["Senior, Sam", "Junior, Sam", "Absent, Sam"] {
+        assert!(text.contains(name), "{name}");
+    }
+
+    common::cleanup_test_org(&p.pool, p.org_id).await;
+}
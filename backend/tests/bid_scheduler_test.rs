@@ -0,0 +1,196 @@
+mod common;
+
+use sqlx::PgPool;
+use time::{Duration, OffsetDateTime};
+use uuid::Uuid;
+
+use timeshift_backend::services::bid_scheduler::sweep_org;
+
+fn unique_email(prefix: &str) -> String {
+    format!("{}+{}@test.local", prefix, &Uuid::new_v4().to_string()[..8])
+}
+
+/// Bid window notices a user has received, oldest first.
+async fn notices(pool: &PgPool, user_id: Uuid) -> Vec<String> {
+    sqlx::query_scalar(
+        "SELECT title FROM notifications WHERE user_id = $1 AND notification_type = 'bid_window' ORDER BY created_at",
+    )
+    .bind(user_id)
+    .fetch_all(pool)
+    .await
+    .unwrap()
+}
+
+async fn bid_status(addr: std::net::SocketAddr, token: &str, path: &str) -> reqwest::Response {
+    common::http_client()
+        .get(format!("http://{}{}", addr, path))
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn shift_bid_windows_advance_and_notify_without_page_loads() {
+    let (addr, pool) = common::setup_test_app().await;
+    let org_id = common::create_test_org(&pool, "bid-sched").await;
+    let sup_email = unique_email("bid-sched-sup");
+    let (_sup, sup_pw) = common::create_test_user(&pool, org_id, "supervisor", &sup_email).await;
+    let sup_token = common::get_auth_token(addr, &sup_email, &sup_pw).await;
+    let emp_email = unique_email("bid-sched-emp");
+    let (first, emp_pw) = common::create_test_user(&pool, org_id, "employee", &emp_email).await;
+    let emp_token = common::get_auth_token(addr, &emp_email, &emp_pw).await;
+    let second = common::create_test_user(&pool, org_id, "employee", &unique_email("bid-sched")).await.0;
+    let third = common::create_test_user(&pool, org_id, "employee", &unique_email("bid-sched")).await.0;
+    let fourth = common::create_test_user(&pool, org_id, "employee", &unique_email("bid-sched")).await.0;
+
+    let today = OffsetDateTime::now_utc().date();
+    let period_id = Uuid::new_v4();
+    sqlx::query(
+        "INSERT INTO schedule_periods (id, org_id, name, start_date, end_date, status) VALUES ($1, $2, '2027 Q1', $3, $4, 'open')",
+    )
+    .bind(period_id)
+    .bind(org_id)
+    .bind(today + Duration::days(30))
+    .bind(today + Duration::days(120))
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    // Hour-long windows: the first closed unsubmitted five minutes ago, the
+    // second started then, the third opens within the hour, the fourth later.
+    let start = OffsetDateTime::now_utc() - Duration::minutes(65);
+    let mut windows = Vec::new();
+    for (i, user) in [first, second, third, fourth].into_iter().enumerate() {
+        let id = Uuid::new_v4();
+        let opens = start + Duration::hours(i as i64);
+        sqlx::query(
+            "INSERT INTO bid_windows (id, period_id, user_id, seniority_rank, opens_at, closes_at, unlocked_at) VALUES ($1, $2, $3, $4, $5, $6, $7)",
+        )
+        .bind(id)
+        .bind(period_id)
+        .bind(user)
+        .bind(i as i32 + 1)
+        .bind(opens)
+        .bind(opens + Duration::hours(1))
+        .bind(if i == 0 { Some(opens) } else { None })
+        .execute(&pool)
+        .await
+        .unwrap();
+        windows.push(id);
+    }
+
+    let result = sweep_org(&pool, None, org_id).await.unwrap();
+    assert_eq!(result.advanced, 1);
+    assert_eq!(result.notices, 2);
+    assert_eq!(result.texts, 0);
+
+    let unlocked: Option<OffsetDateTime> = sqlx::query_scalar("SELECT unlocked_at FROM bid_windows WHERE id = $1")
+        .bind(windows[1])
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert!(unlocked.is_some());
+    assert!(notices(&pool, first).await.is_empty());
+    assert_eq!(notices(&pool, second).await, vec!["Your bid window is open now"]);
+    let third_notices = notices(&pool, third).await;
+    assert_eq!(third_notices.len(), 1);
+    assert!(third_notices[0].starts_with("Your bid window opens in"));
+    assert!(notices(&pool, fourth).await.is_empty());
+
+    // Nothing new to say on the next sweep
+    let again = sweep_org(&pool, None, org_id).await.unwrap();
+    assert_eq!((again.advanced, again.notices), (0, 0));
+
+    // The second window nears its close
+    sqlx::query("UPDATE bid_windows SET closes_at = NOW() + INTERVAL '20 minutes' WHERE id = $1")
+        .bind(windows[1])
+        .execute(&pool)
+        .await
+        .unwrap();
+    sweep_org(&pool, None, org_id).await.unwrap();
+    let second_notices = notices(&pool, second).await;
+    assert_eq!(second_notices.len(), 2);
+    assert_eq!(second_notices[1], "Your bid window closes in 20 minutes");
+
+    let path = format!("/api/schedule/periods/{period_id}/bid-status");
+    assert_eq!(bid_status(addr, &emp_token, &path).await.status(), 403);
+    let resp = bid_status(addr, &sup_token, &path).await;
+    assert_eq!(resp.status(), 200);
+    let status: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(status["status"], "open");
+    assert_eq!(status["total_windows"], 4);
+    assert_eq!(status["counts"]["skipped"], 1);
+    assert_eq!(status["counts"]["open"], 1);
+    assert_eq!(status["counts"]["upcoming"], 2);
+    assert_eq!(status["current"]["user_id"], second.to_string());
+    assert_eq!(status["current"]["state"], "open");
+    assert_eq!(status["next"]["seniority_rank"], 3);
+    assert!(status["expected_finish_at"].is_string());
+
+    common::cleanup_test_org(&pool, org_id).await;
+}
+
+#[tokio::test]
+async fn vacation_bid_windows_are_swept_too() {
+    let (addr, pool) = common::setup_test_app().await;
+    let org_id = common::create_test_org(&pool, "vac-sched").await;
+    let admin_email = unique_email("vac-sched-admin");
+    let (_admin, admin_pw) = common::create_test_user(&pool, org_id, "admin", &admin_email).await;
+    let token = common::get_auth_token(addr, &admin_email, &admin_pw).await;
+    let first = common::create_test_user(&pool, org_id, "employee", &unique_email("vac-sched")).await.0;
+    let second = common::create_test_user(&pool, org_id, "employee", &unique_email("vac-sched")).await.0;
+    let third = common::create_test_user(&pool, org_id, "employee", &unique_email("vac-sched")).await.0;
+
+    let period_id = Uuid::new_v4();
+    sqlx::query("INSERT INTO vacation_bid_periods (id, org_id, year, round, status) VALUES ($1, $2, 2027, 1, 'open')")
+        .bind(period_id)
+        .bind(org_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    // The first closed unsubmitted, the second has 20 minutes left, the third
+    // opens in 20 minutes.
+    let start = OffsetDateTime::now_utc() - Duration::minutes(100);
+    for (i, user) in [first, second, third].into_iter().enumerate() {
+        let opens = start + Duration::hours(i as i64);
+        sqlx::query(
+            "INSERT INTO vacation_bid_windows (vacation_bid_period_id, user_id, seniority_rank, opens_at, closes_at) VALUES ($1, $2, $3, $4, $5)",
+        )
+        .bind(period_id)
+        .bind(user)
+        .bind(i as i32 + 1)
+        .bind(opens)
+        .bind(opens + Duration::hours(1))
+        .execute(&pool)
+        .await
+        .unwrap();
+    }
+
+    let result = sweep_org(&pool, None, org_id).await.unwrap();
+    assert_eq!(result.advanced, 1);
+    assert_eq!(result.notices, 2);
+
+    // Seen for the first time near its close, the second bidder gets only that notice
+    assert_eq!(notices(&pool, second).await, vec!["Your bid window closes in 20 minutes"]);
+    assert_eq!(notices(&pool, third).await, vec!["Your bid window opens in 20 minutes"]);
+    let link: String = sqlx::query_scalar("SELECT link FROM notifications WHERE user_id = $1")
+        .bind(third)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert!(link.starts_with("/vacation-bid/"));
+
+    let resp = bid_status(addr, &token, &format!("/api/vacation-bids/periods/{period_id}/bid-status")).await;
+    assert_eq!(resp.status(), 200);
+    let status: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(status["counts"]["skipped"], 1);
+    assert_eq!(status["current"]["user_id"], second.to_string());
+    assert_eq!(status["next"]["user_id"], third.to_string());
+
+    let resp = bid_status(addr, &token, &format!("/api/vacation-bids/periods/{}/bid-status", Uuid::new_v4())).await;
+    assert_eq!(resp.status(), 404);
+
+    common::cleanup_test_org(&pool, org_id).await;
+}
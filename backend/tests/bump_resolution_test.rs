@@ -0,0 +1,321 @@
+mod common;
+
+use sqlx::PgPool;
+use time::{Duration, OffsetDateTime};
+use uuid::Uuid;
+
+fn unique_email(prefix: &str) -> String {
+    format!("{}+{}@test.local", prefix, &Uuid::new_v4().to_string()[..8])
+}
+
+struct BumpFixture {
+    addr: std::net::SocketAddr,
+    pool: PgPool,
+    org_id: Uuid,
+    admin_token: String,
+    event_id: Uuid,
+    scheduled_shift_id: Uuid,
+    displaced_id: Uuid,
+    requester_id: Uuid,
+    bump_id: String,
+}
+
+/// Filled callout with the displaced employee on OT and a pending bump request
+/// from a lower-OT-hours requester.
+async fn setup_pending_bump(suffix: &str) -> BumpFixture {
+    let date = OffsetDateTime::now_utc().date() + Duration::days(30);
+    setup_pending_bump_at(suffix, date.with_hms(7, 0, 0).unwrap().assume_utc(), &[]).await
+}
+
+/// As `setup_pending_bump`, for a 12 hour shift starting at `shift_start`
+/// (test orgs are on UTC) and with `settings` applied before the request.
+async fn setup_pending_bump_at(
+    suffix: &str,
+    shift_start: OffsetDateTime,
+    settings: &[(&str, &str)],
+) -> BumpFixture {
+    let (addr, pool) = common::setup_test_app().await;
+    let org_id = common::create_test_org(&pool, suffix).await;
+    for (key, value) in settings {
+        common::set_org_setting(&pool, org_id, key, serde_json::json!(value)).await;
+    }
+    let class_id = common::create_test_classification(&pool, org_id).await;
+
+    let admin_email = unique_email("bump-admin");
+    let (admin_id, admin_pw) = common::create_test_user_with_classification(
+        &pool, org_id, class_id, "admin", &admin_email,
+    )
+    .await;
+    let admin_token = common::get_auth_token(addr, &admin_email, &admin_pw).await;
+
+    let (displaced_id, _) = common::create_test_user_with_classification(
+        &pool,
+        org_id,
+        class_id,
+        "employee",
+        &unique_email("bump-displaced"),
+    )
+    .await;
+    let requester_email = unique_email("bump-requester");
+    let (requester_id, requester_pw) = common::create_test_user_with_classification(
+        &pool, org_id, class_id, "employee", &requester_email,
+    )
+    .await;
+
+    let date = shift_start.date();
+    let template_id = Uuid::new_v4();
+    sqlx::query(
+        "INSERT INTO shift_templates (id, org_id, name, start_time, end_time, duration_minutes) \
+         VALUES ($1, $2, 'Bumped', $3, $4, 720)",
+    )
+    .bind(template_id)
+    .bind(org_id)
+    .bind(shift_start.time())
+    .bind(shift_start.time() + Duration::hours(12))
+    .execute(&pool)
+    .await
+    .unwrap();
+    let scheduled_shift_id =
+        common::create_test_scheduled_shift(&pool, org_id, template_id, date).await;
+    let event_id =
+        common::create_test_callout_event(&pool, scheduled_shift_id, admin_id, class_id).await;
+
+    sqlx::query("UPDATE callout_events SET status = 'filled' WHERE id = $1")
+        .bind(event_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query(
+        "INSERT INTO assignments (id, scheduled_shift_id, user_id, is_overtime, created_by, ot_type) \
+         VALUES ($1, $2, $3, true, $4, 'voluntary')",
+    )
+    .bind(Uuid::new_v4())
+    .bind(scheduled_shift_id)
+    .bind(displaced_id)
+    .bind(admin_id)
+    .execute(&pool)
+    .await
+    .unwrap();
+    sqlx::query(
+        "INSERT INTO ot_hours (id, user_id, fiscal_year, classification_id, hours_worked, hours_declined) \
+         VALUES ($1, $2, $3, $4, 100.0, 0.0)",
+    )
+    .bind(Uuid::new_v4())
+    .bind(displaced_id)
+    .bind(date.year())
+    .bind(class_id)
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let requester_token = common::get_auth_token(addr, &requester_email, &requester_pw).await;
+    let resp = common::http_client()
+        .post(format!("http://{}/api/callout/events/{}/bump", addr, event_id))
+        .header("Authorization", format!("Bearer {}", requester_token))
+        .json(&serde_json::json!({ "displaced_user_id": displaced_id }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let bump: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(bump["status"], "pending");
+    assert!(bump["expires_at"].is_string());
+
+    BumpFixture {
+        addr,
+        pool,
+        org_id,
+        admin_token,
+        event_id,
+        scheduled_shift_id,
+        displaced_id,
+        requester_id,
+        bump_id: bump["id"].as_str().unwrap().to_string(),
+    }
+}
+
+impl BumpFixture {
+    async fn pass_deadline(&self) {
+        sqlx::query("UPDATE bump_requests SET expires_at = NOW() - INTERVAL '1 minute' WHERE id = $1::UUID")
+            .bind(&self.bump_id)
+            .execute(&self.pool)
+            .await
+            .unwrap();
+    }
+
+    async fn sweep(&self) -> serde_json::Value {
+        let resp = common::http_client()
+            .post(format!(
+                "http://{}/api/callout/bump-requests/resolve-expired",
+                self.addr
+            ))
+            .header("Authorization", format!("Bearer {}", self.admin_token))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), 200);
+        resp.json().await.unwrap()
+    }
+
+    async fn audit_actions(&self) -> Vec<serde_json::Value> {
+        let resp = common::http_client()
+            .get(format!(
+                "http://{}/api/callout/events/{}/bump-audit",
+                self.addr, self.event_id
+            ))
+            .header("Authorization", format!("Bearer {}", self.admin_token))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), 200);
+        resp.json().await.unwrap()
+    }
+
+    async fn has_active_assignment(&self, user_id: Uuid) -> bool {
+        sqlx::query_scalar(
+            "SELECT EXISTS(SELECT 1 FROM assignments WHERE scheduled_shift_id = $1 AND user_id = $2 AND cancelled_at IS NULL)",
+        )
+        .bind(self.scheduled_shift_id)
+        .bind(user_id)
+        .fetch_one(&self.pool)
+        .await
+        .unwrap()
+    }
+
+    async fn resolved_notifications(&self, user_id: Uuid) -> i64 {
+        sqlx::query_scalar(
+            "SELECT COUNT(*) FROM notifications WHERE user_id = $1 AND notification_type = 'bump_resolved'",
+        )
+        .bind(user_id)
+        .fetch_one(&self.pool)
+        .await
+        .unwrap()
+    }
+
+    async fn expires_at(&self) -> OffsetDateTime {
+        sqlx::query_scalar("SELECT expires_at FROM bump_requests WHERE id = $1::UUID")
+            .bind(&self.bump_id)
+            .fetch_one(&self.pool)
+            .await
+            .unwrap()
+    }
+
+    async fn status(&self) -> (String, bool) {
+        sqlx::query_as("SELECT status::TEXT, auto_resolved FROM bump_requests WHERE id = $1::UUID")
+            .bind(&self.bump_id)
+            .fetch_one(&self.pool)
+            .await
+            .unwrap()
+    }
+}
+
+#[tokio::test]
+async fn overdue_bump_requests_expire_by_default() {
+    let f = setup_pending_bump("bump-expire").await;
+
+    // Not yet due: nothing happens
+    let result = f.sweep().await;
+    assert_eq!(result["expired"], 0);
+    assert_eq!(f.status().await.0, "pending");
+
+    f.pass_deadline().await;
+    let result = f.sweep().await;
+    assert_eq!(result["expired"], 1);
+    assert_eq!(result["auto_approved"], 0);
+    assert_eq!(f.status().await, ("expired".to_string(), true));
+
+    // Displaced employee keeps the shift; both sides are told
+    assert!(f.has_active_assignment(f.displaced_id).await);
+    assert!(!f.has_active_assignment(f.requester_id).await);
+    assert_eq!(f.resolved_notifications(f.displaced_id).await, 1);
+    assert_eq!(f.resolved_notifications(f.requester_id).await, 1);
+
+    let audit = f.audit_actions().await;
+    let actions: Vec<&str> = audit.iter().map(|a| a["action"].as_str().unwrap()).collect();
+    assert_eq!(actions, vec!["submitted", "expired"]);
+    assert!(audit[1]["actor_id"].is_null());
+
+    common::cleanup_test_org(&f.pool, f.org_id).await;
+}
+
+#[tokio::test]
+async fn overdue_bump_requests_auto_approve_when_configured() {
+    let f = setup_pending_bump("bump-auto").await;
+    sqlx::query(
+        "INSERT INTO org_settings (id, org_id, key, value, updated_at) \
+         VALUES (gen_random_uuid(), $1, 'bump_auto_approve', '\"true\"', NOW())",
+    )
+    .bind(f.org_id)
+    .execute(&f.pool)
+    .await
+    .unwrap();
+
+    f.pass_deadline().await;
+    let result = f.sweep().await;
+    assert_eq!(result["auto_approved"], 1);
+    assert_eq!(f.status().await, ("approved".to_string(), true));
+
+    assert!(!f.has_active_assignment(f.displaced_id).await);
+    assert!(f.has_active_assignment(f.requester_id).await);
+    assert_eq!(f.resolved_notifications(f.displaced_id).await, 1);
+    assert_eq!(f.resolved_notifications(f.requester_id).await, 1);
+
+    let audit = f.audit_actions().await;
+    let actions: Vec<&str> = audit.iter().map(|a| a["action"].as_str().unwrap()).collect();
+    assert_eq!(actions, vec!["submitted", "auto_approved"]);
+
+    common::cleanup_test_org(&f.pool, f.org_id).await;
+}
+
+#[tokio::test]
+async fn bumps_on_shifts_starting_inside_the_review_window_resolve_before_start() {
+    let now = OffsetDateTime::now_utc().replace_nanosecond(0).unwrap();
+
+    // A 48 hour review window on a shift 30 hours out: due one sweep before start
+    let shift_start = now + Duration::hours(30);
+    let f = setup_pending_bump_at("bump-soon", shift_start, &[("bump_review_hours", "48")]).await;
+    assert_eq!(f.expires_at().await, shift_start - Duration::minutes(15));
+    common::cleanup_test_org(&f.pool, f.org_id).await;
+
+    // Starting before the next sweep: due at once, so the sweep can still swap
+    let settings = [("bump_deadline_hours", "0"), ("bump_auto_approve", "true")];
+    let f = setup_pending_bump_at("bump-imminent", now + Duration::minutes(10), &settings).await;
+    assert!(f.expires_at().await <= OffsetDateTime::now_utc());
+    let result = f.sweep().await;
+    assert_eq!(result["auto_approved"], 1);
+    assert_eq!(f.status().await, ("approved".to_string(), true));
+    assert!(f.has_active_assignment(f.requester_id).await);
+
+    common::cleanup_test_org(&f.pool, f.org_id).await;
+}
+
+#[tokio::test]
+async fn supervisor_review_is_audited_and_notified() {
+    let f = setup_pending_bump("bump-review").await;
+
+    let resp = common::http_client()
+        .patch(format!(
+            "http://{}/api/callout/bump-requests/{}/review",
+            f.addr, f.bump_id
+        ))
+        .header("Authorization", format!("Bearer {}", f.admin_token))
+        .json(&serde_json::json!({ "approved": false, "reason": "Staffing needs" }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    assert_eq!(f.status().await, ("denied".to_string(), false));
+
+    // Reviewed requests are no longer swept
+    f.pass_deadline().await;
+    assert_eq!(f.sweep().await["expired"], 0);
+
+    let audit = f.audit_actions().await;
+    let actions: Vec<&str> = audit.iter().map(|a| a["action"].as_str().unwrap()).collect();
+    assert_eq!(actions, vec!["submitted", "denied"]);
+    assert_eq!(audit[1]["notes"], "Staffing needs");
+    assert!(audit[1]["actor_id"].is_string());
+    assert_eq!(f.resolved_notifications(f.requester_id).await, 1);
+
+    common::cleanup_test_org(&f.pool, f.org_id).await;
+}
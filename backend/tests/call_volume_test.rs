@@ -0,0 +1,243 @@
+mod common;
+
+use std::net::SocketAddr;
+
+use time::Duration;
+use uuid::Uuid;
+
+fn unique_email(prefix: &str) -> String {
+    format!("{}+{}@test.local", prefix, &Uuid::new_v4().to_string()[..8])
+}
+
+fn today() -> time::Date {
+    time::OffsetDateTime::now_utc().date()
+}
+
+async fn import(addr: SocketAddr, token: &str, csv: String) -> reqwest::Response {
+    common::http_client()
+        .post(format!("http://{}/api/call-volume/import", addr))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({ "csv": csv }))
+        .send()
+        .await
+        .unwrap()
+}
+
+async fn forecast(addr: SocketAddr, token: &str, date: time::Date) -> serde_json::Value {
+    let resp = common::http_client()
+        .get(format!(
+            "http://{}/api/call-volume/forecast?start_date={}&end_date={}",
+            addr, date, date
+        ))
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let days: Vec<serde_json::Value> = resp.json().await.unwrap();
+    assert_eq!(days.len(), 1);
+    days.into_iter().next().unwrap()
+}
+
+#[tokio::test]
+async fn imported_history_drives_weekday_forecast() {
+    let (addr, pool) = common::setup_test_app().await;
+    let org_id = common::create_test_org(&pool, "callvol").await;
+    let class_id = common::create_test_classification(&pool, org_id).await;
+    let email = unique_email("callvol-sup");
+    let (_uid, password) = common::create_test_user(&pool, org_id, "supervisor", &email).await;
+    let token = common::get_auth_token(addr, &email, &password).await;
+
+    let target = today() + Duration::days(7);
+    let week = |n: i64| today() - Duration::days(7 * n);
+
+    // Three aggregated weeks plus one week of raw per-call rows (3 calls at 08:xx)
+    let csv = format!(
+        "date,time,calls\n{w1},08:00,10\n{w1},08:30,8\n{w2},08:00,20\n{w3},08:00,30\n\
+         {w4} 08:05:00\n{w4}T08:20:00\n{w4} 08:29:59\n",
+        w1 = week(1),
+        w2 = week(2),
+        w3 = week(3),
+        w4 = week(4),
+    );
+    let resp = import(addr, &token, csv).await;
+    assert_eq!(resp.status(), 200);
+    let result: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(result["dates_imported"], 4);
+    assert_eq!(result["slots_imported"], 5);
+    assert_eq!(result["total_calls"], 71);
+
+    let day = forecast(addr, &token, target).await;
+    assert_eq!(day["sample_days"], 4);
+    assert_eq!(day["is_holiday"], false);
+    let slots = day["slots"].as_array().unwrap();
+    assert_eq!(slots.len(), 48);
+    assert_eq!(slots[16]["calls"], 15.75);
+    assert_eq!(slots[17]["calls"], 2.0);
+    assert_eq!(slots[18]["calls"], 0.0);
+
+    // Re-importing a date replaces it
+    let resp = import(addr, &token, format!("{},08:00,50\n", week(1))).await;
+    assert_eq!(resp.status(), 200);
+    let day = forecast(addr, &token, target).await;
+    assert_eq!(day["slots"][16]["calls"], 25.75);
+    assert_eq!(day["slots"][17]["calls"], 0.0);
+
+    let rows: Vec<serde_json::Value> = common::http_client()
+        .get(format!(
+            "http://{}/api/call-volume?start_date={}&end_date={}",
+            addr,
+            week(1),
+            week(1)
+        ))
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0]["calls"], 50);
+
+    // The day grid shows forecast load next to staffing
+    let admin_email = unique_email("callvol-admin");
+    let (_admin, admin_pw) = common::create_test_user(&pool, org_id, "admin", &admin_email).await;
+    let admin_token = common::get_auth_token(addr, &admin_email, &admin_pw).await;
+    let client = common::http_client();
+    let plan: serde_json::Value = client
+        .post(format!("http://{}/api/coverage-plans", addr))
+        .header("Authorization", format!("Bearer {}", admin_token))
+        .json(&serde_json::json!({ "name": "Default", "is_default": true }))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    client
+        .post(format!(
+            "http://{}/api/coverage-plans/{}/slots/bulk",
+            addr,
+            plan["id"].as_str().unwrap()
+        ))
+        .header("Authorization", format!("Bearer {}", admin_token))
+        .json(&serde_json::json!({
+            "slots": [{
+                "classification_id": class_id,
+                "day_of_week": target.weekday().number_days_from_sunday(),
+                "slot_index": 16,
+                "min_headcount": 1, "target_headcount": 1, "max_headcount": 1,
+            }]
+        }))
+        .send()
+        .await
+        .unwrap();
+    let grid: serde_json::Value = client
+        .get(format!("http://{}/api/coverage-plans/day-grid/{}", addr, target))
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(grid["blocks"][4]["forecast_calls"], 25.75);
+    assert_eq!(grid["blocks"][0]["forecast_calls"], 0.0);
+
+    // Erlang C can run straight off the forecast
+    let resp = client
+        .post(format!("http://{}/api/staffing/erlang-c", addr))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({ "forecast_date": target, "aht_seconds": 180 }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let calc: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(calc["intervals"].as_array().unwrap().len(), 48);
+    assert!(calc["intervals"][16]["required_agents"].as_i64().unwrap() > 0);
+
+    common::cleanup_test_org(&pool, org_id).await;
+}
+
+#[tokio::test]
+async fn holiday_forecast_scales_by_past_holidays() {
+    let (addr, pool) = common::setup_test_app().await;
+    let org_id = common::create_test_org(&pool, "callvol-holiday").await;
+    let email = unique_email("callvol-admin");
+    let (_uid, password) = common::create_test_user(&pool, org_id, "admin", &email).await;
+    let token = common::get_auth_token(addr, &email, &password).await;
+
+    let target = today() + Duration::days(7);
+    let week = |n: i64| today() - Duration::days(7 * n);
+
+    // Last week's holiday ran at double the normal load
+    let csv = format!(
+        "{},10:00,20\n{},10:00,10\n{},10:00,10\n",
+        week(1),
+        week(2),
+        week(3)
+    );
+    assert_eq!(import(addr, &token, csv).await.status(), 200);
+
+    // Without holidays: plain average of three weeks
+    let day = forecast(addr, &token, target).await;
+    assert!((day["slots"][20]["calls"].as_f64().unwrap() - 40.0 / 3.0).abs() < 1e-9);
+
+    for (date, name) in [(week(1), "Past holiday"), (target, "Upcoming holiday")] {
+        let resp = common::http_client()
+            .post(format!("http://{}/api/holidays", addr))
+            .header("Authorization", format!("Bearer {}", token))
+            .json(&serde_json::json!({ "date": date, "name": name }))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), 200);
+    }
+
+    // Holidays are excluded from the baseline and scale the holiday forecast
+    let day = forecast(addr, &token, target).await;
+    assert_eq!(day["is_holiday"], true);
+    assert_eq!(day["holiday_factor"], 2.0);
+    assert_eq!(day["sample_days"], 2);
+    assert_eq!(day["slots"][20]["calls"], 20.0);
+
+    // Regular days ignore the factor
+    let day = forecast(addr, &token, target + Duration::days(7)).await;
+    assert_eq!(day["holiday_factor"], 1.0);
+    assert_eq!(day["slots"][20]["calls"], 10.0);
+
+    common::cleanup_test_org(&pool, org_id).await;
+}
+
+#[tokio::test]
+async fn import_validation_and_permissions() {
+    let (addr, pool) = common::setup_test_app().await;
+    let org_id = common::create_test_org(&pool, "callvol-validate").await;
+    let sup_email = unique_email("callvol-sup");
+    let (_sup, sup_pw) = common::create_test_user(&pool, org_id, "supervisor", &sup_email).await;
+    let sup_token = common::get_auth_token(addr, &sup_email, &sup_pw).await;
+    let emp_email = unique_email("callvol-emp");
+    let (_emp, emp_pw) = common::create_test_user(&pool, org_id, "employee", &emp_email).await;
+    let emp_token = common::get_auth_token(addr, &emp_email, &emp_pw).await;
+
+    assert_eq!(import(addr, &emp_token, "2026-01-01,08:00,1".into()).await.status(), 403);
+    for csv in [
+        "",
+        "date,time,calls\n",
+        "2026-01-01,08:00,1\n2026-13-01,08:00,1",
+        "2026-01-01,25:00,1",
+        "2026-01-01,08:00,-4",
+        "2026-01-01",
+    ] {
+        assert_eq!(import(addr, &sup_token, csv.into()).await.status(), 400, "{csv}");
+    }
+
+    // Forecast with no history is empty, not an error
+    let day = forecast(addr, &sup_token, today()).await;
+    assert_eq!(day["sample_days"], 0);
+    assert!(day["slots"].as_array().unwrap().is_empty());
+
+    common::cleanup_test_org(&pool, org_id).await;
+}
@@ -0,0 +1,334 @@
+mod common;
+
+use sqlx::PgPool;
+use uuid::Uuid;
+
+fn unique_email(prefix: &str) -> String {
+    format!("{}+{}@test.local", prefix, &Uuid::new_v4().to_string()[..8])
+}
+
+fn future_date() -> time::Date {
+    time::OffsetDateTime::now_utc().date() + time::Duration::days(30)
+}
+
+async fn set_seniority(pool: &PgPool, org_id: Uuid, user_id: Uuid, date: time::Date) {
+    sqlx::query(
+        "INSERT INTO seniority_records (user_id, org_id, overall_seniority_date) VALUES ($1, $2, $3)
+         ON CONFLICT (user_id) DO UPDATE SET overall_seniority_date = EXCLUDED.overall_seniority_date",
+    )
+    .bind(user_id)
+    .bind(org_id)
+    .bind(date)
+    .execute(pool)
+    .await
+    .unwrap();
+}
+
+async fn put_policy(
+    addr: std::net::SocketAddr,
+    token: &str,
+    body: serde_json::Value,
+) -> reqwest::Response {
+    common::http_client()
+        .put(format!("http://{}/api/callout-policies", addr))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&body)
+        .send()
+        .await
+        .unwrap()
+}
+
+async fn queue_user_ids(addr: std::net::SocketAddr, token: &str, event_id: Uuid) -> Vec<String> {
+    let resp = common::http_client()
+        .get(format!("http://{}/api/callout/events/{}/queue", addr, event_id))
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let queue: Vec<serde_json::Value> = resp.json().await.unwrap();
+    queue
+        .iter()
+        .map(|e| e["user_id"].as_str().unwrap().to_string())
+        .collect()
+}
+
+#[tokio::test]
+async fn org_policy_sort_keys_order_the_callout_list() {
+    let (addr, pool) = common::setup_test_app().await;
+    let org_id = common::create_test_org(&pool, "policy-sort").await;
+    let class_id = common::create_test_classification(&pool, org_id).await;
+
+    let admin_email = unique_email("policy-admin");
+    let (admin_id, admin_pw) = common::create_test_user(&pool, org_id, "admin", &admin_email).await;
+    let token = common::get_auth_token(addr, &admin_email, &admin_pw).await;
+
+    // Senior, middle, junior
+    let mut employees = Vec::new();
+    for year in [2005, 2012, 2020] {
+        let (id, _) = common::create_test_user_with_classification(
+            &pool,
+            org_id,
+            class_id,
+            "employee",
+            &unique_email("policy-emp"),
+        )
+        .await;
+        let date = time::Date::from_calendar_date(year, time::Month::March, 1).unwrap();
+        set_seniority(&pool, org_id, id, date).await;
+        employees.push(id.to_string());
+    }
+
+    let template_id = common::create_test_shift_template(&pool, org_id).await;
+    let shift_id =
+        common::create_test_scheduled_shift(&pool, org_id, template_id, future_date()).await;
+    let event_id = common::create_test_callout_event(&pool, shift_id, admin_id, class_id).await;
+
+    // Built-in policy: inverse seniority (junior first)
+    let order = queue_user_ids(addr, &token, event_id).await;
+    assert_eq!(order, vec![employees[2].clone(), employees[1].clone(), employees[0].clone()]);
+
+    // Org default policy: most senior first
+    let resp = put_policy(
+        addr,
+        &token,
+        serde_json::json!({
+            "name": "Seniority first",
+            "steps": [{
+                "step": "volunteers",
+                "sort": [{ "field": "overall_seniority", "direction": "asc" }],
+            }],
+        }),
+    )
+    .await;
+    assert_eq!(resp.status(), 200);
+    let policy: serde_json::Value = resp.json().await.unwrap();
+    assert!(policy["bargaining_unit"].is_null());
+
+    let order = queue_user_ids(addr, &token, event_id).await;
+    assert_eq!(order, employees);
+
+    // Advancing to a step the policy doesn't define is rejected
+    let resp = common::http_client()
+        .patch(format!("http://{}/api/callout/events/{}/step", addr, event_id))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({ "step": "mandatory" }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 400);
+
+    // Deleting the policy restores the built-in order
+    let resp = common::http_client()
+        .delete(format!(
+            "http://{}/api/callout-policies/{}",
+            addr,
+            policy["id"].as_str().unwrap()
+        ))
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let order = queue_user_ids(addr, &token, event_id).await;
+    assert_eq!(order[0], employees[2]);
+
+    common::cleanup_test_org(&pool, org_id).await;
+}
+
+#[tokio::test]
+async fn bargaining_unit_policy_overrides_org_default() {
+    let (addr, pool) = common::setup_test_app().await;
+    let org_id = common::create_test_org(&pool, "policy-bu").await;
+    let class_id = common::create_test_classification(&pool, org_id).await;
+
+    let admin_email = unique_email("policy-admin");
+    let (admin_id, admin_pw) = common::create_test_user(&pool, org_id, "admin", &admin_email).await;
+    let token = common::get_auth_token(addr, &admin_email, &admin_pw).await;
+
+    let mut employees = Vec::new();
+    for _ in 0..2 {
+        let (id, _) = common::create_test_user_with_classification(
+            &pool,
+            org_id,
+            class_id,
+            "employee",
+            &unique_email("policy-emp"),
+        )
+        .await;
+        employees.push(id);
+    }
+
+    sqlx::query("INSERT INTO bargaining_units (org_id, code, name) VALUES ($1, 'LOCAL9', 'Local 9')")
+        .bind(org_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    let client = common::http_client();
+
+    // Unknown bargaining unit codes are rejected
+    let resp = client
+        .patch(format!("http://{}/api/classifications/{}", addr, class_id))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({ "bargaining_unit": "NOPE" }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 400);
+
+    let resp = client
+        .patch(format!("http://{}/api/classifications/{}", addr, class_id))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({ "bargaining_unit": "LOCAL9" }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let class: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(class["bargaining_unit"], "LOCAL9");
+
+    let resp = put_policy(
+        addr,
+        &token,
+        serde_json::json!({
+            "name": "Org default",
+            "steps": [{ "step": "low_ot_hours", "sort": [{ "field": "ot_hours" }] }],
+        }),
+    )
+    .await;
+    assert_eq!(resp.status(), 200);
+    let resp = put_policy(
+        addr,
+        &token,
+        serde_json::json!({
+            "bargaining_unit": "LOCAL9",
+            "name": "Local 9 volunteers",
+            "steps": [
+                { "step": "volunteers", "filters": { "volunteers_only": true } },
+                { "step": "mandatory", "sort": [{ "field": "mandatory_rotation" }] },
+            ],
+        }),
+    )
+    .await;
+    assert_eq!(resp.status(), 200);
+
+    let resp = client
+        .get(format!(
+            "http://{}/api/callout-policies/effective?classification_id={}",
+            addr, class_id
+        ))
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let effective: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(effective["bargaining_unit"], "LOCAL9");
+    assert_eq!(effective["steps"].as_array().unwrap().len(), 2);
+
+    // Volunteers-only first step: only the volunteer is listed
+    let template_id = common::create_test_shift_template(&pool, org_id).await;
+    let shift_id =
+        common::create_test_scheduled_shift(&pool, org_id, template_id, future_date()).await;
+    let event_id = common::create_test_callout_event(&pool, shift_id, admin_id, class_id).await;
+    sqlx::query("INSERT INTO ot_volunteers (org_id, callout_event_id, user_id) VALUES ($1, $2, $3)")
+        .bind(org_id)
+        .bind(event_id)
+        .bind(employees[1])
+        .execute(&pool)
+        .await
+        .unwrap();
+    let order = queue_user_ids(addr, &token, event_id).await;
+    assert_eq!(order, vec![employees[1].to_string()]);
+
+    // Moving to the mandatory step lists everyone again
+    let resp = client
+        .patch(format!("http://{}/api/callout/events/{}/step", addr, event_id))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({ "step": "mandatory" }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    assert_eq!(queue_user_ids(addr, &token, event_id).await.len(), 2);
+
+    common::cleanup_test_org(&pool, org_id).await;
+}
+
+#[tokio::test]
+async fn policy_validation_and_permissions() {
+    let (addr, pool) = common::setup_test_app().await;
+    let org_id = common::create_test_org(&pool, "policy-validate").await;
+
+    let admin_email = unique_email("policy-admin");
+    let (_admin_id, admin_pw) = common::create_test_user(&pool, org_id, "admin", &admin_email).await;
+    let admin_token = common::get_auth_token(addr, &admin_email, &admin_pw).await;
+
+    let sup_email = unique_email("policy-sup");
+    let (_sup_id, sup_pw) = common::create_test_user(&pool, org_id, "supervisor", &sup_email).await;
+    let sup_token = common::get_auth_token(addr, &sup_email, &sup_pw).await;
+
+    let body = serde_json::json!({
+        "name": "Policy",
+        "steps": [{ "step": "volunteers" }],
+    });
+
+    // Only admins can write policies
+    let resp = put_policy(addr, &sup_token, body.clone()).await;
+    assert_eq!(resp.status(), 403);
+
+    // Duplicate steps
+    let resp = put_policy(
+        addr,
+        &admin_token,
+        serde_json::json!({
+            "name": "Policy",
+            "steps": [{ "step": "volunteers" }, { "step": "volunteers" }],
+        }),
+    )
+    .await;
+    assert_eq!(resp.status(), 400);
+
+    // Unknown bargaining unit
+    let resp = put_policy(
+        addr,
+        &admin_token,
+        serde_json::json!({
+            "bargaining_unit": "NOPE",
+            "name": "Policy",
+            "steps": [{ "step": "volunteers" }],
+        }),
+    )
+    .await;
+    assert_eq!(resp.status(), 400);
+
+    // Upsert replaces the org default in place
+    let first: serde_json::Value = put_policy(addr, &admin_token, body.clone())
+        .await
+        .json()
+        .await
+        .unwrap();
+    let second: serde_json::Value = put_policy(
+        addr,
+        &admin_token,
+        serde_json::json!({ "name": "Renamed", "steps": [{ "step": "mandatory" }] }),
+    )
+    .await
+    .json()
+    .await
+    .unwrap();
+    assert_eq!(first["id"], second["id"]);
+    assert_eq!(second["name"], "Renamed");
+
+    let resp = common::http_client()
+        .get(format!("http://{}/api/callout-policies", addr))
+        .header("Authorization", format!("Bearer {}", sup_token))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let list: Vec<serde_json::Value> = resp.json().await.unwrap();
+    assert_eq!(list.len(), 1);
+
+    common::cleanup_test_org(&pool, org_id).await;
+}
@@ -216,10 +216,56 @@ pub fn http_client() -> reqwest::Client {
     reqwest::Client::new()
 }
 
+/// Send an authenticated JSON request.
+pub async fn send(
+    method: reqwest::Method,
+    addr: SocketAddr,
+    token: &str,
+    path: &str,
+    body: serde_json::Value,
+) -> reqwest::Response {
+    http_client()
+        .request(method, format!("http://{}{}", addr, path))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&body)
+        .send()
+        .await
+        .unwrap()
+}
+
+/// Authenticated JSON POST.
+pub async fn post_json(addr: SocketAddr, token: &str, path: &str, body: serde_json::Value) -> reqwest::Response {
+    send(reqwest::Method::POST, addr, token, path, body).await
+}
+
+/// Authenticated GET.
+pub async fn get(addr: SocketAddr, token: &str, path: &str) -> reqwest::Response {
+    http_client()
+        .get(format!("http://{}{}", addr, path))
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await
+        .unwrap()
+}
+
+/// Authenticated GET that must succeed; returns the JSON body.
+pub async fn get_json(addr: SocketAddr, token: &str, path: &str) -> serde_json::Value {
+    let resp = get(addr, token, path).await;
+    assert_eq!(resp.status(), 200, "GET {path}");
+    resp.json().await.unwrap()
+}
+
 /// Clean up all test data for a given org. Call this at the end of tests.
 pub async fn cleanup_test_org(pool: &PgPool, org_id: Uuid) {
     // Delete in dependency order (child tables first)
     let cleanup_queries = [
+        "DELETE FROM bid_processing_runs WHERE org_id = $1",
+        // Proxy bidding (choices cascade with their plan)
+        "DELETE FROM bid_proxy_actions WHERE org_id = $1",
+        "DELETE FROM bid_proxy_plans WHERE org_id = $1",
+        // Holiday rotation (group members cascade)
+        "DELETE FROM holiday_awards WHERE user_id IN (SELECT id FROM users WHERE org_id = $1)",
+        "DELETE FROM holiday_groups WHERE org_id = $1",
         // Vacation bidding chain
         "DELETE FROM vacation_bids WHERE vacation_bid_window_id IN (SELECT vbw.id FROM vacation_bid_windows vbw JOIN vacation_bid_periods vbp ON vbp.id = vbw.vacation_bid_period_id WHERE vbp.org_id = $1)",
         "DELETE FROM vacation_bid_windows WHERE vacation_bid_period_id IN (SELECT id FROM vacation_bid_periods WHERE org_id = $1)",
@@ -231,15 +277,23 @@ pub async fn cleanup_test_org(pool: &PgPool, org_id: Uuid) {
         "DELETE FROM trade_approvals WHERE org_id = $1",
         "DELETE FROM trade_requests WHERE org_id = $1",
         // Callout chain
+        "DELETE FROM bump_request_audit WHERE org_id = $1",
         "DELETE FROM bump_requests WHERE org_id = $1",
         "DELETE FROM ot_volunteers WHERE callout_event_id IN (SELECT ce.id FROM callout_events ce JOIN scheduled_shifts ss ON ss.id = ce.scheduled_shift_id WHERE ss.org_id = $1)",
         "DELETE FROM callout_attempts WHERE event_id IN (SELECT ce.id FROM callout_events ce JOIN scheduled_shifts ss ON ss.id = ce.scheduled_shift_id WHERE ss.org_id = $1)",
         "DELETE FROM callout_events WHERE scheduled_shift_id IN (SELECT id FROM scheduled_shifts WHERE org_id = $1)",
         // OT requests chain
+        "DELETE FROM ot_standing_availability WHERE org_id = $1",
+        "DELETE FROM callout_policies WHERE org_id = $1",
+        "DELETE FROM mandatory_ot_events WHERE org_id = $1",
+        "DELETE FROM mandatory_ot_exemptions WHERE org_id = $1",
+        "DELETE FROM ot_gap_postings WHERE org_id = $1",
         "DELETE FROM ot_request_assignments WHERE ot_request_id IN (SELECT id FROM ot_requests WHERE org_id = $1)",
         "DELETE FROM ot_request_volunteers WHERE ot_request_id IN (SELECT id FROM ot_requests WHERE org_id = $1)",
         "DELETE FROM ot_requests WHERE org_id = $1",
+        "DELETE FROM ot_request_series WHERE org_id = $1",
         // Schedule chain
+        "DELETE FROM duty_breaks WHERE org_id = $1",
         "DELETE FROM duty_assignments WHERE org_id = $1",
         "DELETE FROM assignments WHERE scheduled_shift_id IN (SELECT id FROM scheduled_shifts WHERE org_id = $1)",
         "DELETE FROM scheduled_shifts WHERE org_id = $1",
@@ -267,12 +321,15 @@ pub async fn cleanup_test_org(pool: &PgPool, org_id: Uuid) {
         "DELETE FROM coverage_plan_slots WHERE coverage_plan_id IN (SELECT id FROM coverage_plans WHERE org_id = $1)",
         "DELETE FROM coverage_plan_assignments WHERE org_id = $1",
         "DELETE FROM coverage_plans WHERE org_id = $1",
+        "DELETE FROM coverage_date_overrides WHERE org_id = $1",
+        "DELETE FROM holiday_coverage_profiles WHERE org_id = $1",
         "DELETE FROM coverage_requirements WHERE org_id = $1",
         "DELETE FROM shift_templates WHERE org_id = $1",
         "DELETE FROM shift_pattern_assignments WHERE org_id = $1",
         "DELETE FROM shift_patterns WHERE org_id = $1",
         // Duty positions
         "DELETE FROM duty_positions WHERE org_id = $1",
+        "DELETE FROM qualifications WHERE org_id = $1",
         // User-related
         "DELETE FROM notifications WHERE org_id = $1",
         "DELETE FROM saved_filters WHERE org_id = $1",
@@ -280,6 +337,13 @@ pub async fn cleanup_test_org(pool: &PgPool, org_id: Uuid) {
         "DELETE FROM refresh_tokens WHERE user_id IN (SELECT id FROM users WHERE org_id = $1)",
         "DELETE FROM login_audit_log WHERE user_id IN (SELECT id FROM users WHERE org_id = $1)",
         "DELETE FROM seniority_records WHERE user_id IN (SELECT id FROM users WHERE org_id = $1)",
+        "DELETE FROM seniority_audit WHERE org_id = $1",
+        "DELETE FROM employment_status_history WHERE org_id = $1",
+        "DELETE FROM seniority_disputes WHERE org_id = $1",
+        "DELETE FROM seniority_lists WHERE org_id = $1",
+        "DELETE FROM job_share_pairs WHERE org_id = $1",
+        "DELETE FROM call_volume_history WHERE org_id = $1",
+        "DELETE FROM coverage_gap_incidents WHERE org_id = $1",
         "DELETE FROM users WHERE org_id = $1",
         // Org-level
         "DELETE FROM holiday_calendar WHERE org_id = $1",
@@ -450,3 +514,108 @@ pub async fn create_test_team(pool: &PgPool, org_id: Uuid, name: &str) -> Uuid {
         .expect("Failed to create test team");
     id
 }
+
+/// Create a Monday–Friday shift slot on a team. Returns the slot ID.
+pub async fn create_test_shift_slot(
+    pool: &PgPool,
+    team_id: Uuid,
+    shift_template_id: Uuid,
+    classification_id: Uuid,
+    is_flex: bool,
+) -> Uuid {
+    let id = Uuid::new_v4();
+    sqlx::query(
+        "INSERT INTO shift_slots (id, team_id, shift_template_id, classification_id, days_of_week, is_flex) \
+         VALUES ($1, $2, $3, $4, '{1,2,3,4,5}', $5)",
+    )
+    .bind(id)
+    .bind(team_id)
+    .bind(shift_template_id)
+    .bind(classification_id)
+    .bind(is_flex)
+    .execute(pool)
+    .await
+    .expect("Failed to create test shift slot");
+    id
+}
+
+/// Insert or replace an org setting.
+pub async fn set_org_setting(pool: &PgPool, org_id: Uuid, key: &str, value: serde_json::Value) {
+    sqlx::query(
+        "INSERT INTO org_settings (id, org_id, key, value, updated_at) VALUES ($1, $2, $3, $4, NOW()) \
+         ON CONFLICT (org_id, key) DO UPDATE SET value = $4",
+    )
+    .bind(Uuid::new_v4())
+    .bind(org_id)
+    .bind(key)
+    .bind(value)
+    .execute(pool)
+    .await
+    .expect("Failed to set org setting");
+}
+
+/// Create a qualification. Returns the qualification ID.
+pub async fn create_test_qualification(pool: &PgPool, org_id: Uuid, name: &str) -> Uuid {
+    let id = Uuid::new_v4();
+    sqlx::query("INSERT INTO qualifications (id, org_id, name) VALUES ($1, $2, $3)")
+        .bind(id)
+        .bind(org_id)
+        .bind(name)
+        .execute(pool)
+        .await
+        .expect("Failed to create test qualification");
+    id
+}
+
+/// Create an employee with an overall seniority date, optionally in a
+/// classification. Returns (user_id, email, password).
+pub async fn create_test_senior_employee(
+    pool: &PgPool,
+    org_id: Uuid,
+    classification_id: Option<Uuid>,
+    overall_seniority_date: &str,
+) -> (Uuid, String, String) {
+    let email = format!("employee+{}@test.local", &Uuid::new_v4().to_string()[..8]);
+    let (id, password) = create_test_user(pool, org_id, "employee", &email).await;
+    sqlx::query("UPDATE users SET classification_id = $2 WHERE id = $1")
+        .bind(id)
+        .bind(classification_id)
+        .execute(pool)
+        .await
+        .expect("Failed to set test employee classification");
+    sqlx::query("INSERT INTO seniority_records (user_id, org_id, overall_seniority_date) VALUES ($1, $2, $3::DATE)")
+        .bind(id)
+        .bind(org_id)
+        .bind(overall_seniority_date)
+        .execute(pool)
+        .await
+        .expect("Failed to create test seniority record");
+    (id, email, password)
+}
+
+/// Create a duty board position. Returns the position ID.
+pub async fn create_test_duty_position(pool: &PgPool, org_id: Uuid, name: &str, sort_order: i32) -> Uuid {
+    let id = Uuid::new_v4();
+    sqlx::query("INSERT INTO duty_positions (id, org_id, name, sort_order) VALUES ($1, $2, $3, $4)")
+        .bind(id)
+        .bind(org_id)
+        .bind(name)
+        .bind(sort_order)
+        .execute(pool)
+        .await
+        .expect("Failed to create test duty position");
+    id
+}
+
+/// Create an employee with the given last name and a unique email. Returns the user ID.
+pub async fn create_test_employee(pool: &PgPool, org_id: Uuid, last_name: &str) -> Uuid {
+    let email = format!("employee+{}@test.local", &Uuid::new_v4().to_string()[..8]);
+    let (id, _) = create_test_user(pool, org_id, "employee", &email).await;
+    sqlx::query("UPDATE users SET last_name = $2 WHERE id = $1")
+        .bind(id)
+        .bind(last_name)
+        .execute(pool)
+        .await
+        .expect("Failed to set test employee name");
+    id
+}
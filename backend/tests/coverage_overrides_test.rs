@@ -0,0 +1,358 @@
+mod common;
+
+use std::net::SocketAddr;
+
+use uuid::Uuid;
+
+fn unique_email(prefix: &str) -> String {
+    format!("{}+{}@test.local", prefix, &Uuid::new_v4().to_string()[..8])
+}
+
+/// Creates a default plan requiring min 1 / target 2 for `class_id` at the
+/// given slots on the given weekday.
+async fn create_default_plan(
+    addr: SocketAddr,
+    token: &str,
+    class_id: Uuid,
+    day_of_week: i16,
+    slots: std::ops::RangeInclusive<i16>,
+) {
+    let client = common::http_client();
+    let plan: serde_json::Value = client
+        .post(format!("http://{}/api/coverage-plans", addr))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({ "name": "Default Plan", "is_default": true }))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let entries: Vec<serde_json::Value> = slots
+        .map(|slot_index| {
+            serde_json::json!({
+                "classification_id": class_id,
+                "day_of_week": day_of_week, "slot_index": slot_index,
+                "min_headcount": 1, "target_headcount": 2, "max_headcount": 3,
+            })
+        })
+        .collect();
+    let resp = client
+        .post(format!(
+            "http://{}/api/coverage-plans/{}/slots/bulk",
+            addr,
+            plan["id"].as_str().unwrap()
+        ))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({ "slots": entries }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+}
+
+async fn resolved(addr: SocketAddr, token: &str, date: &str) -> Vec<serde_json::Value> {
+    let resp = common::http_client()
+        .get(format!("http://{}/api/coverage-plans/resolved/{}", addr, date))
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    resp.json().await.unwrap()
+}
+
+fn slot(slots: &[serde_json::Value], index: i64) -> Option<&serde_json::Value> {
+    slots.iter().find(|s| s["slot_index"] == index)
+}
+
+#[tokio::test]
+async fn date_override_layers_on_plan_and_spills_past_midnight() {
+    let (addr, pool) = common::setup_test_app().await;
+    let org_id = common::create_test_org(&pool, "cov-override").await;
+    let class_id = common::create_test_classification(&pool, org_id).await;
+    let email = unique_email("cov-override");
+    let (_uid, password) = common::create_test_user(&pool, org_id, "admin", &email).await;
+    let token = common::get_auth_token(addr, &email, &password).await;
+    let client = common::http_client();
+
+    // Thursdays 20:00–24:00 need 1 (target 2)
+    create_default_plan(addr, &token, class_id, 4, 40..=47).await;
+
+    // +2 DISP 20:00–03:00 on New Year's Eve
+    let resp = client
+        .post(format!("http://{}/api/coverage-overrides", addr))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({
+            "date": "2026-12-31",
+            "classification_id": class_id,
+            "start_time": "20:00:00",
+            "end_time": "03:00:00",
+            "mode": "add",
+            "min_headcount": 2,
+            "target_headcount": 2,
+            "notes": "New Year's Eve",
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let created: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(created["classification_abbreviation"], "DISP");
+
+    let eve = resolved(addr, &token, "2026-12-31").await;
+    assert_eq!(eve.len(), 8);
+    for s in &eve {
+        assert_eq!(s["min_headcount"], 3);
+        assert_eq!(s["target_headcount"], 4);
+        assert_eq!(s["max_headcount"], 5);
+        assert_eq!(s["source"], "override");
+    }
+
+    // The overnight part lands on Jan 1 even though the plan has no Friday slots
+    let new_year = resolved(addr, &token, "2027-01-01").await;
+    assert_eq!(new_year.len(), 6);
+    assert_eq!(slot(&new_year, 0).unwrap()["min_headcount"], 2);
+    assert_eq!(slot(&new_year, 5).unwrap()["source"], "override");
+    assert!(slot(&new_year, 6).is_none());
+
+    // Day grid reports the layer per block
+    let grid: serde_json::Value = client
+        .get(format!("http://{}/api/coverage-plans/day-grid/2026-12-31", addr))
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    let blocks = grid["classifications"][0]["blocks"].as_array().unwrap();
+    assert_eq!(blocks[10]["source"], "override");
+    assert_eq!(blocks[10]["min"], 3);
+    assert!(blocks[0]["source"].is_null());
+
+    // A 'set' override on top replaces the combined requirement
+    let resp = client
+        .post(format!("http://{}/api/coverage-overrides", addr))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({
+            "date": "2026-12-31",
+            "classification_id": class_id,
+            "start_time": "23:00:00",
+            "end_time": "23:30:00",
+            "mode": "set",
+            "min_headcount": 6,
+            "target_headcount": 6,
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let eve = resolved(addr, &token, "2026-12-31").await;
+    assert_eq!(slot(&eve, 46).unwrap()["min_headcount"], 6);
+    assert_eq!(slot(&eve, 46).unwrap()["max_headcount"], 6);
+    assert_eq!(slot(&eve, 47).unwrap()["min_headcount"], 3);
+
+    let list: Vec<serde_json::Value> = client
+        .get(format!(
+            "http://{}/api/coverage-overrides?start_date=2026-12-01&end_date=2026-12-31",
+            addr
+        ))
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(list.len(), 2);
+
+    // Removing the overrides restores the plan
+    for o in &list {
+        let resp = client
+            .delete(format!(
+                "http://{}/api/coverage-overrides/{}",
+                addr,
+                o["id"].as_str().unwrap()
+            ))
+            .header("Authorization", format!("Bearer {}", token))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), 200);
+    }
+    let eve = resolved(addr, &token, "2026-12-31").await;
+    assert!(eve.iter().all(|s| s["source"] == "plan" && s["min_headcount"] == 1));
+    assert!(resolved(addr, &token, "2027-01-01").await.is_empty());
+
+    common::cleanup_test_org(&pool, org_id).await;
+}
+
+#[tokio::test]
+async fn holiday_profile_replaces_plan_on_holiday_dates() {
+    let (addr, pool) = common::setup_test_app().await;
+    let org_id = common::create_test_org(&pool, "cov-holiday").await;
+    let class_id = common::create_test_classification(&pool, org_id).await;
+    let email = unique_email("cov-holiday");
+    let (_uid, password) = common::create_test_user(&pool, org_id, "admin", &email).await;
+    let token = common::get_auth_token(addr, &email, &password).await;
+    let client = common::http_client();
+
+    // Fridays 08:00–09:00
+    create_default_plan(addr, &token, class_id, 5, 16..=17).await;
+
+    let mut profile_ids = Vec::new();
+    for (name, is_default, min) in [("Skeleton", true, 4), ("Christmas", false, 5)] {
+        let profile: serde_json::Value = client
+            .post(format!("http://{}/api/holiday-coverage-profiles", addr))
+            .header("Authorization", format!("Bearer {}", token))
+            .json(&serde_json::json!({ "name": name, "is_default": is_default }))
+            .send()
+            .await
+            .unwrap()
+            .json()
+            .await
+            .unwrap();
+        let id = profile["id"].as_str().unwrap().to_string();
+        let resp = client
+            .post(format!(
+                "http://{}/api/holiday-coverage-profiles/{}/slots/bulk",
+                addr, id
+            ))
+            .header("Authorization", format!("Bearer {}", token))
+            .json(&serde_json::json!({
+                "slots": [{
+                    "classification_id": class_id, "slot_index": 16,
+                    "min_headcount": min, "target_headcount": min, "max_headcount": min,
+                }]
+            }))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), 200);
+        profile_ids.push(id);
+    }
+
+    // Not a holiday yet: plan applies
+    let day = resolved(addr, &token, "2026-12-25").await;
+    assert_eq!(day.len(), 2);
+    assert!(day.iter().all(|s| s["source"] == "plan"));
+
+    let holiday: serde_json::Value = client
+        .post(format!("http://{}/api/holidays", addr))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({ "date": "2026-12-25", "name": "Christmas Day" }))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert!(holiday["coverage_profile_id"].is_null());
+
+    // Default profile replaces the classification's plan rows entirely
+    let day = resolved(addr, &token, "2026-12-25").await;
+    assert_eq!(day.len(), 1);
+    assert_eq!(day[0]["slot_index"], 16);
+    assert_eq!(day[0]["min_headcount"], 4);
+    assert_eq!(day[0]["source"], "holiday_profile");
+
+    // A holiday can name its own profile
+    let holiday_url = format!(
+        "http://{}/api/holidays/{}",
+        addr,
+        holiday["id"].as_str().unwrap()
+    );
+    let resp = client
+        .patch(&holiday_url)
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({ "coverage_profile_id": profile_ids[1] }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    assert_eq!(resolved(addr, &token, "2026-12-25").await[0]["min_headcount"], 5);
+
+    let grid: serde_json::Value = client
+        .get(format!("http://{}/api/coverage-plans/day-grid/2026-12-25", addr))
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(grid["classifications"][0]["blocks"][4]["source"], "holiday_profile");
+
+    // Clearing it falls back to the default profile
+    let resp = client
+        .patch(&holiday_url)
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({ "coverage_profile_id": null }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    assert_eq!(resolved(addr, &token, "2026-12-25").await[0]["min_headcount"], 4);
+
+    // Other Fridays keep the plan
+    let day = resolved(addr, &token, "2027-01-08").await;
+    assert!(day.iter().all(|s| s["source"] == "plan"));
+
+    common::cleanup_test_org(&pool, org_id).await;
+}
+
+#[tokio::test]
+async fn override_validation_and_permissions() {
+    let (addr, pool) = common::setup_test_app().await;
+    let org_id = common::create_test_org(&pool, "cov-validate").await;
+    let class_id = common::create_test_classification(&pool, org_id).await;
+    let other_org = common::create_test_org(&pool, "cov-validate-other").await;
+    let other_class = common::create_test_classification(&pool, other_org).await;
+
+    let sup_email = unique_email("cov-sup");
+    let (_sup, sup_pw) = common::create_test_user(&pool, org_id, "supervisor", &sup_email).await;
+    let sup_token = common::get_auth_token(addr, &sup_email, &sup_pw).await;
+    let emp_email = unique_email("cov-emp");
+    let (_emp, emp_pw) = common::create_test_user(&pool, org_id, "employee", &emp_email).await;
+    let emp_token = common::get_auth_token(addr, &emp_email, &emp_pw).await;
+
+    let post = |token: String, body: serde_json::Value| async move {
+        common::http_client()
+            .post(format!("http://{}/api/coverage-overrides", addr))
+            .header("Authorization", format!("Bearer {}", token))
+            .json(&body)
+            .send()
+            .await
+            .unwrap()
+            .status()
+    };
+    let body = |class: Uuid, start: &str, end: &str, mode: &str, min: i16, target: i16| {
+        serde_json::json!({
+            "date": "2026-12-31", "classification_id": class,
+            "start_time": start, "end_time": end, "mode": mode,
+            "min_headcount": min, "target_headcount": target,
+        })
+    };
+
+    assert_eq!(post(emp_token.clone(), body(class_id, "20:00:00", "22:00:00", "add", 1, 1)).await, 403);
+    assert_eq!(post(sup_token.clone(), body(class_id, "20:00:00", "20:00:00", "add", 1, 1)).await, 400);
+    assert_eq!(post(sup_token.clone(), body(class_id, "20:00:00", "22:00:00", "set", 3, 2)).await, 400);
+    assert_eq!(post(sup_token.clone(), body(other_class, "20:00:00", "22:00:00", "add", 1, 1)).await, 404);
+    // Negative deltas are fine in add mode
+    assert_eq!(post(sup_token.clone(), body(class_id, "20:00:00", "22:00:00", "add", -1, -1)).await, 200);
+
+    // Profiles are admin-only
+    let resp = common::http_client()
+        .post(format!("http://{}/api/holiday-coverage-profiles", addr))
+        .header("Authorization", format!("Bearer {}", sup_token))
+        .json(&serde_json::json!({ "name": "Skeleton" }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 403);
+
+    common::cleanup_test_org(&pool, org_id).await;
+    common::cleanup_test_org(&pool, other_org).await;
+}
@@ -0,0 +1,234 @@
+mod common;
+
+use std::net::SocketAddr;
+
+use time::Duration;
+use uuid::Uuid;
+
+fn unique_email(prefix: &str) -> String {
+    format!("{}+{}@test.local", prefix, &Uuid::new_v4().to_string()[..8])
+}
+
+async fn simulate(addr: SocketAddr, token: &str, body: serde_json::Value) -> reqwest::Response {
+    common::http_client()
+        .post(format!("http://{}/api/coverage-plans/simulate", addr))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&body)
+        .send()
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn simulation_reports_before_and_after_gaps_without_writing() {
+    let (addr, pool) = common::setup_test_app().await;
+    let org_id = common::create_test_org(&pool, "whatif").await;
+    let class_id = common::create_test_classification(&pool, org_id).await;
+    let day_template = common::create_test_shift_template(&pool, org_id).await;
+    let night_template = Uuid::new_v4();
+    sqlx::query(
+        "INSERT INTO shift_templates (id, org_id, name, start_time, end_time, duration_minutes, color, crosses_midnight) \
+         VALUES ($1, $2, 'Night Shift', '19:00:00', '07:00:00', 720, '#1E3A8A', true)",
+    )
+    .bind(night_template)
+    .bind(org_id)
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let admin_email = unique_email("whatif-admin");
+    let (admin_id, admin_pw) = common::create_test_user(&pool, org_id, "admin", &admin_email).await;
+    let admin_token = common::get_auth_token(addr, &admin_email, &admin_pw).await;
+    let (day_user, _) =
+        common::create_test_user_with_classification(&pool, org_id, class_id, "employee", &unique_email("whatif-a")).await;
+    let (night_user, _) =
+        common::create_test_user_with_classification(&pool, org_id, class_id, "employee", &unique_email("whatif-b")).await;
+    let (spare_user, _) =
+        common::create_test_user_with_classification(&pool, org_id, class_id, "employee", &unique_email("whatif-c")).await;
+
+    let date = time::OffsetDateTime::now_utc().date() + Duration::days(10);
+    let next = date + Duration::days(1);
+
+    // Minimum of one dispatcher 07:00–19:00 on the date and 00:00–07:00 the next morning
+    let client = common::http_client();
+    let plan: serde_json::Value = client
+        .post(format!("http://{}/api/coverage-plans", addr))
+        .header("Authorization", format!("Bearer {}", admin_token))
+        .json(&serde_json::json!({ "name": "Default", "is_default": true }))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    let slot = |dow: u8, idx: i16| {
+        serde_json::json!({
+            "classification_id": class_id, "day_of_week": dow, "slot_index": idx,
+            "min_headcount": 1, "target_headcount": 1, "max_headcount": 2,
+        })
+    };
+    let mut slots: Vec<serde_json::Value> =
+        (14..38).map(|i| slot(date.weekday().number_days_from_sunday(), i)).collect();
+    slots.extend((0..14).map(|i| slot(next.weekday().number_days_from_sunday(), i)));
+    let resp = client
+        .post(format!(
+            "http://{}/api/coverage-plans/{}/slots/bulk",
+            addr,
+            plan["id"].as_str().unwrap()
+        ))
+        .header("Authorization", format!("Bearer {}", admin_token))
+        .json(&serde_json::json!({ "slots": slots }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+
+    let day_shift = common::create_test_scheduled_shift(&pool, org_id, day_template, date).await;
+    let night_shift = common::create_test_scheduled_shift(&pool, org_id, night_template, date).await;
+    common::create_test_assignment(&pool, day_shift, day_user, admin_id).await;
+    common::create_test_assignment(&pool, night_shift, night_user, admin_id).await;
+
+    // Both off on the date: the day gap and the next-morning overnight gap appear
+    let resp = simulate(
+        addr,
+        &admin_token,
+        serde_json::json!({
+            "remove_users": [
+                { "user_id": day_user, "start_date": date, "end_date": date },
+                { "user_id": night_user, "start_date": date, "end_date": date },
+            ]
+        }),
+    )
+    .await;
+    assert_eq!(resp.status(), 200);
+    let result: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(result["worsens_coverage"], true);
+    let days = result["days"].as_array().unwrap();
+    assert_eq!(days.len(), 2);
+    assert_eq!(days[0]["date"], date.to_string());
+    assert_eq!(days[0]["before"].as_array().unwrap().len(), 0);
+    assert_eq!(days[0]["before_shortage"], 0);
+    assert_eq!(days[0]["after_shortage"], 24);
+    let after = &days[0]["after"][0];
+    assert_eq!(after["classification_abbreviation"], "DISP");
+    assert_eq!(after["blocks"][0]["start_time"], "07:00");
+    assert_eq!(after["blocks"][0]["end_time"], "19:00");
+    assert_eq!(after["blocks"][0]["shortage"], 1);
+    assert_eq!(days[1]["after_shortage"], 14);
+    assert_eq!(days[1]["after"][0]["blocks"][0]["start_time"], "00:00");
+    assert_eq!(days[1]["after"][0]["blocks"][0]["end_time"], "07:00");
+
+    // Nothing was written
+    let gaps: Vec<serde_json::Value> = client
+        .get(format!("http://{}/api/coverage-plans/gaps/{}/blocks", addr, date))
+        .header("Authorization", format!("Bearer {}", admin_token))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert!(gaps.is_empty());
+
+    // Swapping the day user to nights opens the day gap but doubles the night
+    let result: serde_json::Value = simulate(
+        addr,
+        &admin_token,
+        serde_json::json!({
+            "swap_shifts": [{ "user_id": day_user, "date": date, "shift_template_id": night_template }]
+        }),
+    )
+    .await
+    .json()
+    .await
+    .unwrap();
+    assert_eq!(result["days"][0]["after_shortage"], 24);
+    assert_eq!(result["days"][1]["after_shortage"], 0);
+
+    // Backfilling the day user with a spare leaves coverage intact
+    let result: serde_json::Value = simulate(
+        addr,
+        &admin_token,
+        serde_json::json!({
+            "remove_users": [{ "user_id": day_user, "start_date": date, "end_date": date }],
+            "add_shifts": [{ "user_id": spare_user, "date": date, "shift_template_id": day_template }],
+        }),
+    )
+    .await
+    .json()
+    .await
+    .unwrap();
+    assert_eq!(result["worsens_coverage"], false);
+    assert_eq!(result["days"][0]["after_shortage"], 0);
+
+    common::cleanup_test_org(&pool, org_id).await;
+}
+
+#[tokio::test]
+async fn simulation_validates_changes_and_permissions() {
+    let (addr, pool) = common::setup_test_app().await;
+    let org_id = common::create_test_org(&pool, "whatif-validate").await;
+    let class_id = common::create_test_classification(&pool, org_id).await;
+    let template = common::create_test_shift_template(&pool, org_id).await;
+
+    let sup_email = unique_email("whatif-sup");
+    let (_sup, sup_pw) = common::create_test_user(&pool, org_id, "supervisor", &sup_email).await;
+    let sup_token = common::get_auth_token(addr, &sup_email, &sup_pw).await;
+    let emp_email = unique_email("whatif-emp");
+    let (emp_id, emp_pw) =
+        common::create_test_user_with_classification(&pool, org_id, class_id, "employee", &emp_email).await;
+    let emp_token = common::get_auth_token(addr, &emp_email, &emp_pw).await;
+
+    let date = time::OffsetDateTime::now_utc().date() + Duration::days(5);
+    let remove = serde_json::json!({
+        "remove_users": [{ "user_id": emp_id, "start_date": date, "end_date": date }]
+    });
+
+    assert_eq!(simulate(addr, &emp_token, remove.clone()).await.status(), 403);
+    assert_eq!(simulate(addr, &sup_token, remove).await.status(), 200);
+    assert_eq!(simulate(addr, &sup_token, serde_json::json!({})).await.status(), 400);
+
+    // Unknown user and unknown template
+    let resp = simulate(
+        addr,
+        &sup_token,
+        serde_json::json!({
+            "add_shifts": [{ "user_id": Uuid::new_v4(), "date": date, "shift_template_id": template }]
+        }),
+    )
+    .await;
+    assert_eq!(resp.status(), 404);
+    let resp = simulate(
+        addr,
+        &sup_token,
+        serde_json::json!({
+            "add_shifts": [{ "user_id": emp_id, "date": date, "shift_template_id": Uuid::new_v4() }]
+        }),
+    )
+    .await;
+    assert_eq!(resp.status(), 404);
+
+    // Swap needs an existing shift; ranges are bounded
+    let resp = simulate(
+        addr,
+        &sup_token,
+        serde_json::json!({
+            "swap_shifts": [{ "user_id": emp_id, "date": date, "shift_template_id": template }]
+        }),
+    )
+    .await;
+    assert_eq!(resp.status(), 400);
+    let resp = simulate(
+        addr,
+        &sup_token,
+        serde_json::json!({
+            "remove_users": [{
+                "user_id": emp_id, "start_date": date, "end_date": date + Duration::days(365)
+            }]
+        }),
+    )
+    .await;
+    assert_eq!(resp.status(), 400);
+
+    common::cleanup_test_org(&pool, org_id).await;
+}
@@ -0,0 +1,223 @@
+mod common;
+
+use std::collections::{HashMap, HashSet};
+use std::net::SocketAddr;
+
+use sqlx::PgPool;
+use time::Duration;
+use uuid::Uuid;
+
+fn unique_email(prefix: &str) -> String {
+    format!("{}+{}@test.local", prefix, &Uuid::new_v4().to_string()[..8])
+}
+
+async fn auto_fill(addr: SocketAddr, token: &str, date: time::Date, body: serde_json::Value) -> reqwest::Response {
+    common::http_client()
+        .post(format!("http://{}/api/duty-board/{}/auto-fill", addr, date))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&body)
+        .send()
+        .await
+        .unwrap()
+}
+
+/// (position, block) → user as stored on the board.
+async fn board(pool: &PgPool, org_id: Uuid, date: time::Date) -> HashMap<(Uuid, i16), Uuid> {
+    sqlx::query_as::<_, (Uuid, i16, Uuid)>(
+        "SELECT duty_position_id, block_index, user_id FROM duty_assignments \
+         WHERE org_id = $1 AND date = $2 AND status = 'assigned'",
+    )
+    .bind(org_id)
+    .bind(date)
+    .fetch_all(pool)
+    .await
+    .unwrap()
+    .into_iter()
+    .map(|(p, b, u)| ((p, b), u))
+    .collect()
+}
+
+#[tokio::test]
+async fn auto_fill_honors_qualifications_hours_rotation_and_fairness() {
+    let (addr, pool) = common::setup_test_app().await;
+    let org_id = common::create_test_org(&pool, "autofill").await;
+    let sup_email = unique_email("autofill-sup");
+    let (sup_id, sup_pw) = common::create_test_user(&pool, org_id, "supervisor", &sup_email).await;
+    let token = common::get_auth_token(addr, &sup_email, &sup_pw).await;
+    let template = common::create_test_shift_template(&pool, org_id).await;
+
+    let able = common::create_test_employee(&pool, org_id, "Able").await;
+    let baker = common::create_test_employee(&pool, org_id, "Baker").await;
+    let cole = common::create_test_employee(&pool, org_id, "Cole").await;
+
+    let radio_a = common::create_test_duty_position(&pool, org_id, "Radio A", 1).await;
+    let radio_b = common::create_test_duty_position(&pool, org_id, "Radio B", 2).await;
+    let ops = common::create_test_duty_position(&pool, org_id, "Ops", 3).await;
+
+    // Ops needs the CTO qualification and is only staffed 08:00–12:00
+    let cto = Uuid::new_v4();
+    sqlx::query("INSERT INTO qualifications (id, org_id, name) VALUES ($1, $2, 'CTO')")
+        .bind(cto)
+        .bind(org_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query("INSERT INTO duty_position_qualifications (duty_position_id, qualification_id) VALUES ($1, $2)")
+        .bind(ops)
+        .bind(cto)
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query("INSERT INTO user_qualifications (user_id, qualification_id) VALUES ($1, $2)")
+        .bind(cole)
+        .bind(cto)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let date = (time::OffsetDateTime::now_utc().date() + Duration::days(40))
+        .replace_day(15)
+        .unwrap();
+    sqlx::query(
+        "INSERT INTO duty_position_hours (duty_position_id, day_of_week, open_time, close_time) \
+         VALUES ($1, $2, '08:00', '12:00')",
+    )
+    .bind(ops)
+    .bind(date.weekday().number_days_from_sunday() as i16)
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    // Able already spent 20 hours on Radio A this month
+    let month_start = date.replace_day(1).unwrap();
+    sqlx::query(
+        "INSERT INTO duty_assignments (org_id, duty_position_id, user_id, date, block_index, status) \
+         SELECT $1, $2, $3, $4, b, 'assigned' FROM generate_series(0, 9) b",
+    )
+    .bind(org_id)
+    .bind(radio_a)
+    .bind(able)
+    .bind(month_start)
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    // Everyone works 07:00–19:00, which touches blocks 3 through 9
+    let shift = common::create_test_scheduled_shift(&pool, org_id, template, date).await;
+    for user in [able, baker, cole] {
+        common::create_test_assignment(&pool, shift, user, sup_id).await;
+    }
+
+    // Dry run: a full plan, nothing written
+    let resp = auto_fill(addr, &token, date, serde_json::json!({ "dry_run": true })).await;
+    assert_eq!(resp.status(), 200);
+    let preview: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(preview["assigned"].as_array().unwrap().len(), 16);
+    assert_eq!(preview["unfilled"].as_array().unwrap().len(), 10);
+    assert!(board(&pool, org_id, date).await.is_empty());
+
+    let resp = auto_fill(addr, &token, date, serde_json::json!({})).await;
+    assert_eq!(resp.status(), 200);
+    let cells = board(&pool, org_id, date).await;
+    assert_eq!(cells.len(), 16);
+
+    // Only the CTO holder staffs Ops, and only while it's open
+    assert_eq!(cells.get(&(ops, 4)), Some(&cole));
+    assert_eq!(cells.get(&(ops, 5)), Some(&cole));
+    assert_eq!(cells.keys().filter(|(p, _)| *p == ops).count(), 2);
+
+    // Able's month-to-date console time sends someone else to Radio A first
+    assert_eq!(cells.get(&(radio_a, 3)), Some(&baker));
+    assert_eq!(cells.get(&(radio_b, 3)), Some(&able));
+
+    for block in 0..12i16 {
+        let people: Vec<Uuid> = cells.iter().filter(|((_, b), _)| *b == block).map(|(_, u)| *u).collect();
+        let distinct: HashSet<Uuid> = people.iter().copied().collect();
+        assert_eq!(people.len(), distinct.len(), "double-booked in block {block}");
+    }
+
+    // Nobody holds a radio console for more than two blocks in a row
+    for position in [radio_a, radio_b] {
+        for block in 2..12i16 {
+            let run: Vec<Option<&Uuid>> = (block - 2..=block).map(|b| cells.get(&(position, b))).collect();
+            let same = run[0].is_some() && run.iter().all(|u| *u == run[0]);
+            assert!(!same, "same person on {position} for blocks {}..={block}", block - 2);
+        }
+    }
+
+    // A manual cell survives a re-run; overwrite replaces it
+    let resp = common::http_client()
+        .post(format!("http://{}/api/duty-board/{}/cells", addr, date))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({
+            "duty_position_id": radio_b, "block_index": 10, "action": "mark_ot"
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let rerun: serde_json::Value = auto_fill(addr, &token, date, serde_json::json!({})).await.json().await.unwrap();
+    assert_eq!(rerun["assigned"].as_array().unwrap().len(), 0);
+    assert_eq!(rerun["unfilled"].as_array().unwrap().len(), 9);
+
+    let rerun: serde_json::Value =
+        auto_fill(addr, &token, date, serde_json::json!({ "overwrite": true })).await.json().await.unwrap();
+    assert_eq!(rerun["assigned"].as_array().unwrap().len(), 16);
+    assert_eq!(board(&pool, org_id, date).await.len(), 16);
+    let ot_marker: String = sqlx::query_scalar(
+        "SELECT status FROM duty_assignments WHERE duty_position_id = $1 AND date = $2 AND block_index = 10",
+    )
+    .bind(radio_b)
+    .bind(date)
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert_eq!(ot_marker, "ot_needed");
+
+    common::cleanup_test_org(&pool, org_id).await;
+}
+
+#[tokio::test]
+async fn auto_fill_skips_staff_on_leave_and_requires_schedule_permission() {
+    let (addr, pool) = common::setup_test_app().await;
+    let org_id = common::create_test_org(&pool, "autofill-leave").await;
+    let sup_email = unique_email("autofill-leave-sup");
+    let (sup_id, sup_pw) = common::create_test_user(&pool, org_id, "supervisor", &sup_email).await;
+    let token = common::get_auth_token(addr, &sup_email, &sup_pw).await;
+    let emp_email = unique_email("autofill-leave-emp");
+    let (_emp, emp_pw) = common::create_test_user(&pool, org_id, "employee", &emp_email).await;
+    let emp_token = common::get_auth_token(addr, &emp_email, &emp_pw).await;
+    let template = common::create_test_shift_template(&pool, org_id).await;
+    let leave_type = common::create_test_leave_type(&pool, org_id, "VAC", "Vacation").await;
+
+    let working = common::create_test_employee(&pool, org_id, "Working").await;
+    let away = common::create_test_employee(&pool, org_id, "Away").await;
+    let console = common::create_test_duty_position(&pool, org_id, "Console", 1).await;
+
+    let date = time::OffsetDateTime::now_utc().date() + Duration::days(5);
+    let shift = common::create_test_scheduled_shift(&pool, org_id, template, date).await;
+    common::create_test_assignment(&pool, shift, working, sup_id).await;
+    common::create_test_assignment(&pool, shift, away, sup_id).await;
+    sqlx::query(
+        "INSERT INTO leave_requests (id, user_id, org_id, leave_type_id, start_date, end_date, status) \
+         VALUES ($1, $2, $3, $4, $5, $5, 'approved')",
+    )
+    .bind(Uuid::new_v4())
+    .bind(away)
+    .bind(org_id)
+    .bind(leave_type)
+    .bind(date)
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    assert_eq!(auto_fill(addr, &emp_token, date, serde_json::json!({})).await.status(), 403);
+
+    let resp = auto_fill(addr, &token, date, serde_json::json!({})).await;
+    assert_eq!(resp.status(), 200);
+    let cells = board(&pool, org_id, date).await;
+    assert_eq!(cells.len(), 7);
+    assert!(cells.iter().all(|((p, _), u)| *p == console && *u == working));
+
+    common::cleanup_test_org(&pool, org_id).await;
+}
@@ -0,0 +1,205 @@
+mod common;
+
+use std::net::SocketAddr;
+
+use time::Duration;
+use uuid::Uuid;
+
+fn unique_email(prefix: &str) -> String {
+    format!("{}+{}@test.local", prefix, &Uuid::new_v4().to_string()[..8])
+}
+
+async fn seat(addr: SocketAddr, token: &str, date: time::Date, position: Uuid, block: i16, user: Uuid) {
+    let resp = common::post_json(
+        addr,
+        token,
+        &format!("/api/duty-board/{date}/cells"),
+        serde_json::json!({ "duty_position_id": position, "block_index": block, "action": "assign", "user_id": user }),
+    )
+    .await;
+    assert_eq!(resp.status(), 200);
+}
+
+async fn breaks(addr: SocketAddr, token: &str, date: time::Date) -> Vec<serde_json::Value> {
+    common::get(addr, token, &format!("/api/duty-board/{date}/breaks"))
+        .await
+        .json()
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn relief_planner_covers_breaks_and_flags_gaps() {
+    let (addr, pool) = common::setup_test_app().await;
+    let org_id = common::create_test_org(&pool, "breaks").await;
+    let sup_email = unique_email("breaks-sup");
+    let (sup_id, sup_pw) = common::create_test_user(&pool, org_id, "supervisor", &sup_email).await;
+    let token = common::get_auth_token(addr, &sup_email, &sup_pw).await;
+    let template = common::create_test_shift_template(&pool, org_id).await;
+
+    let able = common::create_test_employee(&pool, org_id, "Able").await;
+    let baker = common::create_test_employee(&pool, org_id, "Baker").await;
+    let cole = common::create_test_employee(&pool, org_id, "Cole").await;
+    let dunn = common::create_test_employee(&pool, org_id, "Dunn").await;
+    let radio = common::create_test_duty_position(&pool, org_id, "Radio", 1).await;
+    let phones = common::create_test_duty_position(&pool, org_id, "Phones", 2).await;
+    let spare = common::create_test_duty_position(&pool, org_id, "Spare", 3).await;
+
+    let date = time::OffsetDateTime::now_utc().date() + Duration::days(4);
+    let shift = common::create_test_scheduled_shift(&pool, org_id, template, date).await;
+    for user in [able, baker, cole, dunn] {
+        common::create_test_assignment(&pool, shift, user, sup_id).await;
+    }
+    // 10:00–12:00: Able on Radio, Cole on Phones; Baker and Dunn are floating
+    seat(addr, &token, date, radio, 5, able).await;
+    seat(addr, &token, date, phones, 5, cole).await;
+
+    let path = format!("/api/duty-board/{date}/breaks");
+    let resp = common::post_json(
+        addr,
+        &token,
+        &path,
+        serde_json::json!({ "user_id": able, "start_time": "10:00:00", "end_time": "10:30:00", "kind": "meal" }),
+    )
+    .await;
+    assert_eq!(resp.status(), 200);
+    let able_break: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(able_break["block_index"], 5);
+    assert_eq!(able_break["position_name"], "Radio");
+    assert_eq!(able_break["coverage"], "uncovered");
+    let able_break_id = able_break["id"].as_str().unwrap().to_string();
+
+    let resp = common::post_json(
+        addr,
+        &token,
+        &path,
+        serde_json::json!({ "user_id": cole, "start_time": "10:15:00", "end_time": "10:45:00" }),
+    )
+    .await;
+    assert_eq!(resp.status(), 200);
+
+    // Dry run previews without saving
+    let plan_path = format!("/api/duty-board/{date}/breaks/plan-relief");
+    let resp = common::post_json(addr, &token, &plan_path, serde_json::json!({ "dry_run": true })).await;
+    assert_eq!(resp.status(), 200);
+    let preview: Vec<serde_json::Value> = resp.json().await.unwrap();
+    assert!(preview.iter().all(|b| b["coverage"] == "relieved"));
+    assert!(breaks(addr, &token, date).await.iter().all(|b| b["relief_user_id"].is_null()));
+
+    // Overlapping breaks need two different floaters
+    let planned: Vec<serde_json::Value> =
+        common::post_json(addr, &token, &plan_path, serde_json::json!({})).await.json().await.unwrap();
+    assert_eq!(planned[0]["user_id"], able.to_string());
+    assert_eq!(planned[0]["relief_user_id"], baker.to_string());
+    assert_eq!(planned[0]["relief_last_name"], "Baker");
+    assert_eq!(planned[1]["user_id"], cole.to_string());
+    assert_eq!(planned[1]["relief_user_id"], dunn.to_string());
+
+    // The board overlay carries the same information
+    let board: serde_json::Value =
+        common::get(addr, &token, &format!("/api/duty-board/{date}"))
+            .await
+            .json()
+            .await
+            .unwrap();
+    assert_eq!(board["breaks"].as_array().unwrap().len(), 2);
+    assert_eq!(board["breaks"][0]["coverage"], "relieved");
+
+    // Seating the relief elsewhere leaves Radio uncovered during the break
+    seat(addr, &token, date, spare, 5, baker).await;
+    let listed = breaks(addr, &token, date).await;
+    assert_eq!(listed[0]["coverage"], "uncovered");
+    assert_eq!(listed[1]["coverage"], "relieved");
+
+    // Nobody else is free to take it
+    let replanned: Vec<serde_json::Value> =
+        common::post_json(addr, &token, &plan_path, serde_json::json!({ "replace": true }))
+            .await
+            .json()
+            .await
+            .unwrap();
+    let uncovered: Vec<&serde_json::Value> = replanned.iter().filter(|b| b["coverage"] == "uncovered").collect();
+    assert_eq!(uncovered.len(), 1);
+    assert!(uncovered[0]["relief_user_id"].is_null());
+
+    // A manual relief can be set and cleared
+    let update_path = format!("/api/duty-board/breaks/{able_break_id}");
+    let updated: serde_json::Value = common::send(
+        reqwest::Method::PATCH,
+        addr,
+        &token,
+        &update_path,
+        serde_json::json!({ "relief_user_id": null, "kind": "break" }),
+    )
+    .await
+    .json()
+    .await
+    .unwrap();
+    assert!(updated["relief_user_id"].is_null());
+    assert_eq!(updated["kind"], "break");
+
+    // Once Able leaves the board there's nothing to relieve
+    let resp = common::post_json(
+        addr,
+        &token,
+        &format!("/api/duty-board/{date}/cells"),
+        serde_json::json!({ "duty_position_id": radio, "block_index": 5, "action": "clear" }),
+    )
+    .await;
+    assert_eq!(resp.status(), 200);
+    let listed = breaks(addr, &token, date).await;
+    assert_eq!(listed[0]["coverage"], "off_board");
+    assert!(listed[0]["duty_position_id"].is_null());
+
+    let resp = common::send(reqwest::Method::DELETE, addr, &token, &update_path, serde_json::json!({})).await;
+    assert_eq!(resp.status(), 200);
+    assert_eq!(breaks(addr, &token, date).await.len(), 1);
+
+    common::cleanup_test_org(&pool, org_id).await;
+}
+
+#[tokio::test]
+async fn break_must_sit_inside_a_seated_block() {
+    let (addr, pool) = common::setup_test_app().await;
+    let org_id = common::create_test_org(&pool, "breaks-valid").await;
+    let sup_email = unique_email("breaks-valid-sup");
+    let (_sup, sup_pw) = common::create_test_user(&pool, org_id, "supervisor", &sup_email).await;
+    let token = common::get_auth_token(addr, &sup_email, &sup_pw).await;
+    let emp_email = unique_email("breaks-valid-emp");
+    let (emp_id, emp_pw) = common::create_test_user(&pool, org_id, "employee", &emp_email).await;
+    let emp_token = common::get_auth_token(addr, &emp_email, &emp_pw).await;
+    let radio = common::create_test_duty_position(&pool, org_id, "Radio", 1).await;
+
+    let date = time::OffsetDateTime::now_utc().date() + Duration::days(4);
+    seat(addr, &token, date, radio, 5, emp_id).await;
+    let path = format!("/api/duty-board/{date}/breaks");
+    let body = |start: &str, end: &str| serde_json::json!({ "user_id": emp_id, "start_time": start, "end_time": end });
+
+    let resp = common::post_json(addr, &emp_token, &path, body("10:00:00", "10:15:00")).await;
+    assert_eq!(resp.status(), 403);
+
+    // Straddles the 12:00 block boundary
+    let resp = common::post_json(addr, &token, &path, body("11:45:00", "12:15:00")).await;
+    assert_eq!(resp.status(), 400);
+    // Not seated 14:00–16:00
+    let resp = common::post_json(addr, &token, &path, body("14:00:00", "14:15:00")).await;
+    assert_eq!(resp.status(), 400);
+    let resp = common::post_json(addr, &token, &path, body("10:30:00", "10:00:00")).await;
+    assert_eq!(resp.status(), 400);
+
+    let resp = common::post_json(addr, &token, &path, body("10:00:00", "10:15:00")).await;
+    assert_eq!(resp.status(), 200);
+    let resp = common::post_json(addr, &token, &path, body("10:10:00", "10:40:00")).await;
+    assert_eq!(resp.status(), 409);
+
+    let resp = common::post_json(
+        addr,
+        &token,
+        &path,
+        serde_json::json!({ "user_id": emp_id, "start_time": "11:00:00", "end_time": "11:15:00", "relief_user_id": emp_id }),
+    )
+    .await;
+    assert_eq!(resp.status(), 400);
+
+    common::cleanup_test_org(&pool, org_id).await;
+}
@@ -0,0 +1,168 @@
+mod common;
+
+use time::{Date, Duration};
+use uuid::Uuid;
+
+fn unique_email(prefix: &str) -> String {
+    format!("{}+{}@test.local", prefix, &Uuid::new_v4().to_string()[..8])
+}
+
+fn date(value: &serde_json::Value) -> Date {
+    Date::parse(value.as_str().unwrap(), &time::format_description::well_known::Iso8601::DATE).unwrap()
+}
+
+#[tokio::test]
+async fn seniority_is_recomputed_from_status_history() {
+    let (addr, pool) = common::setup_test_app().await;
+    let org_id = common::create_test_org(&pool, "status-history").await;
+    let admin_email = unique_email("status-history-admin");
+    let (_admin, admin_pw) = common::create_test_user(&pool, org_id, "admin", &admin_email).await;
+    let token = common::get_auth_token(addr, &admin_email, &admin_pw).await;
+    let email = unique_email("status-history");
+    let (user, password) = common::create_test_user(&pool, org_id, "employee", &email).await;
+    let user_token = common::get_auth_token(addr, &email, &password).await;
+    let user_path = format!("/api/users/{user}");
+    let history_path = format!("/api/users/{user}/status-history");
+    let overall = || async { common::get_json(addr, &token, &user_path).await["overall_seniority_date"].clone() };
+
+    // With no overall date of its own, overall seniority counts from the hire date
+    let resp = common::send(reqwest::Method::PATCH, addr, &token, &user_path, serde_json::json!({ "hire_date": "2010-01-01" })).await;
+    assert_eq!(resp.status(), 200);
+    assert_eq!(overall().await, "2010-01-01");
+
+    // A 30-day LWOP pushes it back; an exception LOA doesn't
+    let lwop = serde_json::json!({ "status": "lwop", "starts_on": "2015-03-01", "ends_on": "2015-03-31" });
+    let resp = common::send(reqwest::Method::POST, addr, &user_token, &history_path, lwop.clone()).await;
+    assert_eq!(resp.status(), 403);
+    let resp = common::send(reqwest::Method::POST, addr, &token, &history_path, lwop).await;
+    assert_eq!(resp.status(), 200);
+    let lwop: serde_json::Value = resp.json().await.unwrap();
+    let loa = serde_json::json!({ "status": "unpaid_loa", "starts_on": "2016-01-01", "ends_on": "2016-02-01", "is_exception": true, "notes": "Military" });
+    assert_eq!(common::send(reqwest::Method::POST, addr, &token, &history_path, loa).await.status(), 200);
+    assert_eq!(overall().await, "2010-01-31");
+
+    let overlap = serde_json::json!({ "status": "layoff", "starts_on": "2015-03-15", "ends_on": "2015-04-15" });
+    assert_eq!(common::send(reqwest::Method::POST, addr, &token, &history_path, overlap).await.status(), 409);
+    let active = serde_json::json!({ "status": "active", "starts_on": "2017-01-01", "ends_on": "2017-02-01" });
+    assert_eq!(common::send(reqwest::Method::POST, addr, &token, &history_path, active).await.status(), 400);
+
+    // Going on LWOP opens an absence that counts once the employee is back
+    let resp = common::send(reqwest::Method::PATCH, addr, &token, &user_path, serde_json::json!({ "employee_status": "lwop" })).await;
+    assert_eq!(resp.status(), 200);
+    let profile: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(profile["overall_seniority_date"], "2010-01-31");
+    let history = common::get_json(addr, &user_token, &history_path).await;
+    let ongoing = history[2].clone();
+    assert!(ongoing["ends_on"].is_null());
+    assert_eq!(profile["accrual_paused_since"], ongoing["starts_on"]);
+
+    let ongoing_path = format!("{history_path}/{}", ongoing["id"].as_str().unwrap());
+    let resp = common::send(reqwest::Method::PATCH, addr, &token, &ongoing_path, serde_json::json!({ "ends_on": "2099-01-01" })).await;
+    assert_eq!(resp.status(), 400);
+    let resp = common::send(reqwest::Method::DELETE, addr, &token, &ongoing_path, serde_json::json!({})).await;
+    assert_eq!(resp.status(), 404);
+
+    // The absence actually began ten days earlier than recorded
+    let started = date(&ongoing["starts_on"]) - Duration::days(10);
+    let resp = common::send(reqwest::Method::PATCH, addr, &token, &ongoing_path, serde_json::json!({ "starts_on": started })).await;
+    assert_eq!(resp.status(), 200);
+    let profile: serde_json::Value =
+        common::send(reqwest::Method::PATCH, addr, &token, &user_path, serde_json::json!({ "employee_status": "active" }))
+            .await
+            .json()
+            .await
+            .unwrap();
+    assert_eq!(profile["overall_seniority_date"], "2010-02-10");
+    assert!(profile["accrual_paused_since"].is_null());
+
+    let explanation = common::get_json(addr, &user_token, &format!("/api/users/{user}/seniority-explanation")).await;
+    let derivation = &explanation["dates"][0];
+    assert_eq!(derivation["seniority_type"], "overall");
+    assert_eq!(derivation["base_date"], "2010-01-01");
+    assert_eq!(derivation["base_source"], "hire_date");
+    assert_eq!(derivation["paused_days"], 40);
+    assert_eq!(derivation["absences"][1]["days_counted"], 0);
+    assert_eq!(derivation["absences"][2]["days_counted"], 10);
+    assert!(explanation["dates"][1]["seniority_date"].is_null());
+
+    // The 2015 absence was really an on-the-job injury: correcting it recomputes
+    let lwop_path = format!("{history_path}/{}", lwop["id"].as_str().unwrap());
+    let resp = common::send(reqwest::Method::PATCH, addr, &token, &lwop_path, serde_json::json!({ "is_exception": true })).await;
+    assert_eq!(resp.status(), 200);
+    assert_eq!(overall().await, "2010-01-11");
+
+    // A profile edit sets the date as it should read now; re-saving it changes nothing
+    for _ in 0..2 {
+        let resp = common::send(reqwest::Method::PATCH, addr, &token, &user_path, serde_json::json!({ "overall_seniority_date": "2010-03-11" })).await;
+        assert_eq!(resp.status(), 200);
+        assert_eq!(overall().await, "2010-03-11");
+    }
+    let explanation = common::get_json(addr, &token, &format!("/api/users/{user}/seniority-explanation")).await;
+    assert_eq!(explanation["dates"][0]["base_date"], "2010-03-01");
+    assert_eq!(explanation["dates"][0]["base_source"], "recorded");
+
+    // The recompute command repairs a date edited behind its back
+    sqlx::query("UPDATE seniority_records SET overall_seniority_date = '2000-01-01' WHERE user_id = $1")
+        .bind(user)
+        .execute(&pool)
+        .await
+        .unwrap();
+    let resp = common::send(reqwest::Method::POST, addr, &user_token, "/api/seniority/recompute", serde_json::json!({})).await;
+    assert_eq!(resp.status(), 403);
+    let resp = common::send(reqwest::Method::POST, addr, &token, "/api/seniority/recompute", serde_json::json!({})).await;
+    assert_eq!(resp.status(), 200);
+    let result: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(result["employees"], 2);
+    assert_eq!(result["changed"], 1);
+    assert_eq!(overall().await, "2010-03-11");
+
+    let audit = common::get_json(addr, &user_token, &format!("/api/users/{user}/seniority-history")).await;
+    let sources: Vec<&str> = audit.as_array().unwrap().iter().map(|a| a["source"].as_str().unwrap()).collect();
+    assert_eq!(sources.first(), Some(&"profile"));
+    assert!(sources.contains(&"status_change"));
+    assert_eq!(sources.last(), Some(&"recompute"));
+
+    common::cleanup_test_org(&pool, org_id).await;
+}
+
+#[tokio::test]
+async fn separation_ends_the_ongoing_absence() {
+    let (addr, pool) = common::setup_test_app().await;
+    let org_id = common::create_test_org(&pool, "status-separation").await;
+    let admin_email = unique_email("status-separation-admin");
+    let (_admin, admin_pw) = common::create_test_user(&pool, org_id, "admin", &admin_email).await;
+    let token = common::get_auth_token(addr, &admin_email, &admin_pw).await;
+    let (user, _) = common::create_test_user(&pool, org_id, "employee", &unique_email("status-separation")).await;
+    let user_path = format!("/api/users/{user}");
+    let history_path = format!("/api/users/{user}/status-history");
+    let patch = |body: serde_json::Value| {
+        let path = user_path.clone();
+        let token = token.clone();
+        async move { common::send(reqwest::Method::PATCH, addr, &token, &path, body).await }
+    };
+
+    assert_eq!(patch(serde_json::json!({ "hire_date": "2010-01-01" })).await.status(), 200);
+    assert_eq!(patch(serde_json::json!({ "employee_status": "layoff" })).await.status(), 200);
+
+    // Backdate the layoff so it has twenty days behind it
+    let history = common::get_json(addr, &token, &history_path).await;
+    let today = date(&history[0]["starts_on"]);
+    let layoff_path = format!("{history_path}/{}", history[0]["id"].as_str().unwrap());
+    let started = today - Duration::days(20);
+    let resp = common::send(reqwest::Method::PATCH, addr, &token, &layoff_path, serde_json::json!({ "starts_on": started })).await;
+    assert_eq!(resp.status(), 200);
+
+    // Separating ends the layoff today, so it stops pausing seniority
+    let resp = patch(serde_json::json!({ "employee_status": "separated" })).await;
+    assert_eq!(resp.status(), 200);
+    let profile: serde_json::Value = resp.json().await.unwrap();
+    assert!(profile["accrual_paused_since"].is_null());
+    assert_eq!(profile["overall_seniority_date"], "2010-01-21");
+
+    let history = common::get_json(addr, &token, &history_path).await;
+    assert_eq!(history.as_array().unwrap().len(), 1);
+    assert_eq!(history[0]["status"], "layoff");
+    assert_eq!(date(&history[0]["ends_on"]), today);
+
+    common::cleanup_test_org(&pool, org_id).await;
+}
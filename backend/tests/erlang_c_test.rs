@@ -0,0 +1,269 @@
+mod common;
+
+use std::net::SocketAddr;
+
+use uuid::Uuid;
+
+fn unique_email(prefix: &str) -> String {
+    format!("{}+{}@test.local", prefix, &Uuid::new_v4().to_string()[..8])
+}
+
+async fn calculate(addr: SocketAddr, token: &str, body: serde_json::Value) -> reqwest::Response {
+    common::http_client()
+        .post(format!("http://{}/api/staffing/erlang-c", addr))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&body)
+        .send()
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn erlang_c_computes_agents_from_json_and_csv() {
+    let (addr, pool) = common::setup_test_app().await;
+    let org_id = common::create_test_org(&pool, "erlang").await;
+    let email = unique_email("erlang-sup");
+    let (_uid, password) = common::create_test_user(&pool, org_id, "supervisor", &email).await;
+    let token = common::get_auth_token(addr, &email, &password).await;
+
+    // 100 calls in 30 min at 180s AHT = 10 Erlangs; NENA 90%/15s needs 15
+    let resp = calculate(
+        addr,
+        &token,
+        serde_json::json!({
+            "intervals": [
+                { "slot_index": 17, "calls": 0 },
+                { "slot_index": 16, "calls": 100 },
+            ],
+            "aht_seconds": 180,
+            "shrinkage": 0.3,
+        }),
+    )
+    .await;
+    assert_eq!(resp.status(), 200);
+    let result: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(result["service_level"], 0.9);
+    assert_eq!(result["answer_time_seconds"], 15.0);
+    let intervals = result["intervals"].as_array().unwrap();
+    assert_eq!(intervals[0]["slot_index"], 16);
+    assert_eq!(intervals[0]["start_time"], "08:00");
+    assert_eq!(intervals[0]["traffic_erlangs"], 10.0);
+    assert_eq!(intervals[0]["required_agents"], 15);
+    assert_eq!(intervals[0]["recommended_headcount"], 22);
+    assert!(intervals[0]["service_level"].as_f64().unwrap() >= 0.9);
+    assert_eq!(intervals[1]["required_agents"], 0);
+    assert_eq!(result["peak_required_agents"], 15);
+
+    // Looser 80%/20s goal needs one fewer
+    let result: serde_json::Value = calculate(
+        addr,
+        &token,
+        serde_json::json!({
+            "intervals": [{ "slot_index": 16, "calls": 100 }],
+            "aht_seconds": 180,
+            "service_level": 0.8,
+            "answer_time_seconds": 20,
+        }),
+    )
+    .await
+    .json()
+    .await
+    .unwrap();
+    assert_eq!(result["intervals"][0]["required_agents"], 14);
+
+    // CSV history: two days of 08:00 volume are averaged
+    let resp = calculate(
+        addr,
+        &token,
+        serde_json::json!({
+            "csv": "slot,calls\n08:00,80\n08:30,10\n08:00,120\n",
+            "aht_seconds": 180,
+        }),
+    )
+    .await;
+    assert_eq!(resp.status(), 200);
+    let result: serde_json::Value = resp.json().await.unwrap();
+    let intervals = result["intervals"].as_array().unwrap();
+    assert_eq!(intervals.len(), 2);
+    assert_eq!(intervals[0]["calls"], 100.0);
+    assert_eq!(intervals[0]["required_agents"], 15);
+
+    // Bad input
+    for body in [
+        serde_json::json!({ "csv": "08:00,abc\n08:15,5", "aht_seconds": 180 }),
+        serde_json::json!({ "csv": "slot,calls\n08:15,5", "aht_seconds": 180 }),
+        serde_json::json!({ "intervals": [], "csv": "08:00,5", "aht_seconds": 180 }),
+        serde_json::json!({ "intervals": [{ "slot_index": 48, "calls": 5 }], "aht_seconds": 180 }),
+        serde_json::json!({ "intervals": [{ "slot_index": 1, "calls": 5 }], "aht_seconds": 0 }),
+        serde_json::json!({ "intervals": [{ "slot_index": 1, "calls": 5 }], "aht_seconds": 180, "service_level": 1.0 }),
+    ] {
+        assert_eq!(calculate(addr, &token, body).await.status(), 400);
+    }
+
+    common::cleanup_test_org(&pool, org_id).await;
+}
+
+#[tokio::test]
+async fn erlang_c_rejects_unbounded_call_volume() {
+    let (addr, pool) = common::setup_test_app().await;
+    let org_id = common::create_test_org(&pool, "erlang-bounds").await;
+    let email = unique_email("erlang-bounds");
+    let (_uid, password) = common::create_test_user(&pool, org_id, "supervisor", &email).await;
+    let token = common::get_auth_token(addr, &email, &password).await;
+
+    for body in [
+        serde_json::json!({ "csv": "slot,calls\n08:00,inf", "aht_seconds": 180 }),
+        serde_json::json!({ "csv": "slot,calls\n08:00,NaN", "aht_seconds": 180 }),
+        serde_json::json!({ "intervals": [{ "slot_index": 16, "calls": 1e12 }], "aht_seconds": 180 }),
+        serde_json::json!({ "csv": "08:00,1000000000000", "aht_seconds": 180 }),
+        // In range, but the traffic alone needs more agents than the ceiling
+        serde_json::json!({ "intervals": [{ "slot_index": 16, "calls": 100000 }], "aht_seconds": 7200 }),
+        serde_json::json!({
+            "intervals": [{ "slot_index": 16, "calls": 100 }],
+            "aht_seconds": 180,
+            "max_occupancy": 0.001,
+        }),
+    ] {
+        let resp = calculate(addr, &token, body.clone()).await;
+        assert_eq!(resp.status(), 400, "{body}");
+    }
+
+    common::cleanup_test_org(&pool, org_id).await;
+}
+
+#[tokio::test]
+async fn staffing_results_apply_to_selected_plan_days() {
+    let (addr, pool) = common::setup_test_app().await;
+    let org_id = common::create_test_org(&pool, "erlang-apply").await;
+    let class_id = common::create_test_classification(&pool, org_id).await;
+
+    let admin_email = unique_email("erlang-admin");
+    let (_admin, admin_pw) = common::create_test_user(&pool, org_id, "admin", &admin_email).await;
+    let admin_token = common::get_auth_token(addr, &admin_email, &admin_pw).await;
+    let sup_email = unique_email("erlang-sup");
+    let (_sup, sup_pw) = common::create_test_user(&pool, org_id, "supervisor", &sup_email).await;
+    let sup_token = common::get_auth_token(addr, &sup_email, &sup_pw).await;
+    let emp_email = unique_email("erlang-emp");
+    let (_emp, emp_pw) = common::create_test_user(&pool, org_id, "employee", &emp_email).await;
+    let emp_token = common::get_auth_token(addr, &emp_email, &emp_pw).await;
+
+    let client = common::http_client();
+    let plan: serde_json::Value = client
+        .post(format!("http://{}/api/coverage-plans", addr))
+        .header("Authorization", format!("Bearer {}", admin_token))
+        .json(&serde_json::json!({ "name": "Weekday" }))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    let plan_id = plan["id"].as_str().unwrap();
+
+    // Existing Monday 08:00 slot with a generous max, and a 09:00 slot left alone
+    client
+        .post(format!("http://{}/api/coverage-plans/{}/slots/bulk", addr, plan_id))
+        .header("Authorization", format!("Bearer {}", admin_token))
+        .json(&serde_json::json!({
+            "slots": [
+                { "classification_id": class_id, "day_of_week": 1, "slot_index": 16,
+                  "min_headcount": 1, "target_headcount": 2, "max_headcount": 30 },
+                { "classification_id": class_id, "day_of_week": 1, "slot_index": 18,
+                  "min_headcount": 1, "target_headcount": 1, "max_headcount": 1 },
+            ]
+        }))
+        .send()
+        .await
+        .unwrap();
+
+    let result: serde_json::Value = calculate(
+        addr,
+        &sup_token,
+        serde_json::json!({
+            "intervals": [{ "slot_index": 16, "calls": 100 }, { "slot_index": 17, "calls": 10 }],
+            "aht_seconds": 180,
+            "shrinkage": 0.3,
+        }),
+    )
+    .await
+    .json()
+    .await
+    .unwrap();
+    let slots: Vec<serde_json::Value> = result["intervals"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|i| {
+            serde_json::json!({
+                "slot_index": i["slot_index"],
+                "min_headcount": i["required_agents"],
+                "target_headcount": i["recommended_headcount"],
+            })
+        })
+        .collect();
+    let apply_body = serde_json::json!({
+        "classification_id": class_id,
+        "days_of_week": [1, 2],
+        "slots": slots,
+    });
+    let apply_url = format!(
+        "http://{}/api/coverage-plans/{}/slots/apply-staffing",
+        addr, plan_id
+    );
+
+    let resp = client
+        .post(&apply_url)
+        .header("Authorization", format!("Bearer {}", emp_token))
+        .json(&apply_body)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 403);
+    assert_eq!(calculate(addr, &emp_token, serde_json::json!({ "intervals": [], "aht_seconds": 180 })).await.status(), 403);
+
+    let resp = client
+        .post(&apply_url)
+        .header("Authorization", format!("Bearer {}", sup_token))
+        .json(&apply_body)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let applied: Vec<serde_json::Value> = resp.json().await.unwrap();
+    assert_eq!(applied.len(), 4);
+
+    let monday: Vec<serde_json::Value> = client
+        .get(format!(
+            "http://{}/api/coverage-plans/{}/slots?day_of_week=1",
+            addr, plan_id
+        ))
+        .header("Authorization", format!("Bearer {}", sup_token))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    let find = |idx: i64| monday.iter().find(|s| s["slot_index"] == idx).unwrap();
+    assert_eq!(find(16)["min_headcount"], 15);
+    assert_eq!(find(16)["target_headcount"], 22);
+    assert_eq!(find(16)["max_headcount"], 30);
+    assert_eq!(find(17)["max_headcount"], find(17)["target_headcount"]);
+    assert_eq!(find(18)["min_headcount"], 1);
+
+    // Invalid weekday
+    let resp = client
+        .post(&apply_url)
+        .header("Authorization", format!("Bearer {}", sup_token))
+        .json(&serde_json::json!({
+            "classification_id": class_id,
+            "days_of_week": [7],
+            "slots": [{ "slot_index": 16, "min_headcount": 1, "target_headcount": 1 }],
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 400);
+
+    common::cleanup_test_org(&pool, org_id).await;
+}
@@ -0,0 +1,226 @@
+mod common;
+
+use std::net::SocketAddr;
+
+use sqlx::PgPool;
+use uuid::Uuid;
+
+fn unique_email(prefix: &str) -> String {
+    format!("{}+{}@test.local", prefix, &Uuid::new_v4().to_string()[..8])
+}
+
+/// Default plan requiring one person of `class_id` around the clock. Nobody is
+/// scheduled, so the whole monitored window is one gap.
+async fn create_round_the_clock_plan(pool: &PgPool, org_id: Uuid, admin_id: Uuid, class_id: Uuid) -> Uuid {
+    let plan_id = Uuid::new_v4();
+    sqlx::query(
+        "INSERT INTO coverage_plans (id, org_id, name, is_default, created_by) VALUES ($1, $2, '24x7', true, $3)",
+    )
+    .bind(plan_id)
+    .bind(org_id)
+    .bind(admin_id)
+    .execute(pool)
+    .await
+    .expect("Failed to create coverage plan");
+
+    sqlx::query(
+        "INSERT INTO coverage_plan_slots (plan_id, classification_id, day_of_week, slot_index, min_headcount, target_headcount, max_headcount) \
+         SELECT $1, $2, d, s, 1, 2, 3 FROM generate_series(0, 6) d, generate_series(0, 47) s",
+    )
+    .bind(plan_id)
+    .bind(class_id)
+    .execute(pool)
+    .await
+    .expect("Failed to create coverage plan slots");
+    plan_id
+}
+
+async fn set_plan_min(pool: &PgPool, plan_id: Uuid, min: i16) {
+    sqlx::query("UPDATE coverage_plan_slots SET min_headcount = $2 WHERE plan_id = $1")
+        .bind(plan_id)
+        .bind(min)
+        .execute(pool)
+        .await
+        .unwrap();
+}
+
+async fn scan(addr: SocketAddr, token: &str) -> serde_json::Value {
+    let resp = common::http_client()
+        .post(format!("http://{}/api/coverage-gap-incidents/scan", addr))
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    resp.json().await.unwrap()
+}
+
+async fn list(addr: SocketAddr, token: &str) -> Vec<serde_json::Value> {
+    common::http_client()
+        .get(format!("http://{}/api/coverage-gap-incidents", addr))
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn incident_opens_worsens_escalates_and_resolves() {
+    let (addr, pool) = common::setup_test_app().await;
+    let org_id = common::create_test_org(&pool, "gapmon").await;
+    let email = unique_email("gapmon-admin");
+    let (admin_id, password) = common::create_test_user(&pool, org_id, "admin", &email).await;
+    let token = common::get_auth_token(addr, &email, &password).await;
+    let (sup_id, _) = common::create_test_user(&pool, org_id, "supervisor", &unique_email("gapmon-sup")).await;
+    let class_id = common::create_test_classification(&pool, org_id).await;
+    let plan_id = create_round_the_clock_plan(&pool, org_id, admin_id, class_id).await;
+    common::set_org_setting(&pool, org_id, "gap_alert_hours_ahead", serde_json::json!("4")).await;
+
+    let result = scan(addr, &token).await;
+    assert_eq!(result["opened"], 1, "{result}");
+    let incidents = list(addr, &token).await;
+    assert_eq!(incidents.len(), 1);
+    let incident_id = incidents[0]["id"].as_str().unwrap().to_string();
+    assert_eq!(incidents[0]["status"], "open");
+    assert_eq!(incidents[0]["shortage"], 1);
+    assert_eq!(incidents[0]["alert_level"], 1);
+    assert_eq!(incidents[0]["classification_abbreviation"], "DISP");
+
+    // Every supervisor got an in-app alert linked to the incident
+    let alerts: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM notifications WHERE user_id = $1 AND notification_type = 'coverage_gap' AND source_id = $2",
+    )
+    .bind(sup_id)
+    .bind(Uuid::parse_str(&incident_id).unwrap())
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert_eq!(alerts, 1);
+
+    // Unchanged gap: no duplicate alert
+    let result = scan(addr, &token).await;
+    assert_eq!(result["opened"], 0);
+    assert_eq!(result["worsened"], 0);
+
+    // Minimum goes up: same incident, re-alerted
+    set_plan_min(&pool, plan_id, 2).await;
+    let result = scan(addr, &token).await;
+    assert_eq!(result["opened"], 0);
+    assert_eq!(result["worsened"], 1);
+    let incidents = list(addr, &token).await;
+    assert_eq!(incidents.len(), 1);
+    assert_eq!(incidents[0]["shortage"], 2);
+
+    // Nobody acknowledged: escalate
+    common::set_org_setting(&pool, org_id, "gap_alert_escalation_minutes", serde_json::json!("0")).await;
+    let result = scan(addr, &token).await;
+    assert_eq!(result["escalated"], 1);
+    let detail: serde_json::Value = common::http_client()
+        .get(format!("http://{}/api/coverage-gap-incidents/{}", addr, incident_id))
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(detail["alert_level"], 2);
+    assert!(detail["escalated_at"].is_string());
+    let levels: Vec<i64> = detail["alerts"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|a| a["alert_level"].as_i64().unwrap())
+        .collect();
+    assert_eq!(levels, vec![1, 1, 2]);
+
+    // Already escalated: not escalated again
+    assert_eq!(scan(addr, &token).await["escalated"], 0);
+
+    // Gap closes: incident resolves
+    set_plan_min(&pool, plan_id, 0).await;
+    let result = scan(addr, &token).await;
+    assert_eq!(result["resolved"], 1);
+    let incidents = list(addr, &token).await;
+    assert_eq!(incidents[0]["status"], "resolved");
+    assert!(incidents[0]["resolved_at"].is_string());
+
+    common::cleanup_test_org(&pool, org_id).await;
+}
+
+#[tokio::test]
+async fn acknowledgement_stops_escalation_and_staffing_changes_mark_dirty() {
+    let (addr, pool) = common::setup_test_app().await;
+    let org_id = common::create_test_org(&pool, "gapmon-ack").await;
+    let email = unique_email("gapmon-sup");
+    let (sup_id, password) = common::create_test_user(&pool, org_id, "supervisor", &email).await;
+    let token = common::get_auth_token(addr, &email, &password).await;
+    let emp_email = unique_email("gapmon-emp");
+    let (emp_id, emp_pw) = common::create_test_user(&pool, org_id, "employee", &emp_email).await;
+    let emp_token = common::get_auth_token(addr, &emp_email, &emp_pw).await;
+    let class_id = common::create_test_classification(&pool, org_id).await;
+    let plan_id = create_round_the_clock_plan(&pool, org_id, sup_id, class_id).await;
+    common::set_org_setting(&pool, org_id, "gap_alert_hours_ahead", serde_json::json!("2")).await;
+
+    let resp = common::http_client()
+        .post(format!("http://{}/api/coverage-gap-incidents/scan", addr))
+        .header("Authorization", format!("Bearer {}", emp_token))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 403);
+
+    assert_eq!(scan(addr, &token).await["opened"], 1);
+    let incident_id = list(addr, &token).await[0]["id"].as_str().unwrap().to_string();
+    let ack_url = format!(
+        "http://{}/api/coverage-gap-incidents/{}/acknowledge",
+        addr, incident_id
+    );
+    let resp = common::http_client()
+        .post(&ack_url)
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let acked: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(acked["acknowledged_by"], sup_id.to_string());
+
+    common::set_org_setting(&pool, org_id, "gap_alert_escalation_minutes", serde_json::json!("0")).await;
+    assert_eq!(scan(addr, &token).await["escalated"], 0);
+
+    // Any assignment change flags the org for an immediate rescan
+    let template = common::create_test_shift_template(&pool, org_id).await;
+    let today = time::OffsetDateTime::now_utc().date();
+    let shift = common::create_test_scheduled_shift(&pool, org_id, template, today).await;
+    let last_scan: time::OffsetDateTime =
+        sqlx::query_scalar("SELECT last_scan_at FROM coverage_monitor_state WHERE org_id = $1")
+            .bind(org_id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+    common::create_test_assignment(&pool, shift, emp_id, sup_id).await;
+    let dirty: Option<time::OffsetDateTime> =
+        sqlx::query_scalar("SELECT dirty_at FROM coverage_monitor_state WHERE org_id = $1")
+            .bind(org_id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+    assert!(dirty.is_some_and(|d| d >= last_scan));
+
+    // Resolved incidents can't be acknowledged
+    set_plan_min(&pool, plan_id, 0).await;
+    assert_eq!(scan(addr, &token).await["resolved"], 1);
+    let resp = common::http_client()
+        .post(&ack_url)
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 409);
+
+    common::cleanup_test_org(&pool, org_id).await;
+}
@@ -0,0 +1,129 @@
+mod common;
+
+use time::{Duration, OffsetDateTime};
+use uuid::Uuid;
+
+fn unique_email(prefix: &str) -> String {
+    format!("{}+{}@test.local", prefix, &Uuid::new_v4().to_string()[..8])
+}
+
+#[tokio::test]
+async fn last_years_christmas_recipient_sits_out_round_one() {
+    let (addr, pool) = common::setup_test_app().await;
+    let org_id = common::create_test_org(&pool, "holiday-rotation").await;
+    let admin_email = unique_email("holiday-rotation-admin");
+    let (_admin, admin_pw) = common::create_test_user(&pool, org_id, "admin", &admin_email).await;
+    let token = common::get_auth_token(addr, &admin_email, &admin_pw).await;
+    let (senior, email, password) = common::create_test_senior_employee(&pool, org_id, None, "2005-01-01").await;
+    let senior_token = common::get_auth_token(addr, &email, &password).await;
+    let (junior, email, password) = common::create_test_senior_employee(&pool, org_id, None, "2010-01-01").await;
+    let junior_token = common::get_auth_token(addr, &email, &password).await;
+
+    let mut christmas = Vec::new();
+    for date in ["2026-12-25", "2027-12-25"] {
+        let resp = common::post_json(addr, &token, "/api/holidays", serde_json::json!({ "date": date, "name": "Christmas Day" })).await;
+        assert_eq!(resp.status(), 200);
+        let holiday: serde_json::Value = resp.json().await.unwrap();
+        christmas.push(holiday["id"].as_str().unwrap().to_string());
+    }
+    let group = serde_json::json!({ "name": "Christmas", "holiday_ids": christmas });
+    assert_eq!(common::post_json(addr, &junior_token, "/api/holiday-groups", group.clone()).await.status(), 403);
+    let resp = common::post_json(addr, &token, "/api/holiday-groups", group).await;
+    assert_eq!(resp.status(), 200);
+    let group: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(group["lockout_years"], 1);
+    assert_eq!(group["holiday_ids"].as_array().unwrap().len(), 2);
+
+    // The junior had Christmas off last year, entered as history
+    let award = serde_json::json!({ "holiday_id": christmas[0], "user_id": junior });
+    assert_eq!(common::post_json(addr, &token, "/api/holiday-awards", award.clone()).await.status(), 200);
+    assert_eq!(common::post_json(addr, &token, "/api/holiday-awards", award).await.status(), 409);
+
+    let period_id = Uuid::new_v4();
+    sqlx::query("INSERT INTO vacation_bid_periods (id, org_id, year, round, status) VALUES ($1, $2, 2027, 1, 'open')")
+        .bind(period_id)
+        .bind(org_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    let opens = OffsetDateTime::now_utc() - Duration::hours(1);
+    let mut windows = Vec::new();
+    for (rank, user) in [senior, junior].into_iter().enumerate() {
+        let window_id = Uuid::new_v4();
+        sqlx::query(
+            "INSERT INTO vacation_bid_windows (id, vacation_bid_period_id, user_id, seniority_rank, opens_at, closes_at) VALUES ($1, $2, $3, $4, $5, $6)",
+        )
+        .bind(window_id)
+        .bind(period_id)
+        .bind(user)
+        .bind(rank as i32 + 1)
+        .bind(opens)
+        .bind(opens + Duration::days(1))
+        .execute(&pool)
+        .await
+        .unwrap();
+        windows.push(window_id);
+    }
+
+    // Christmas week is barred to the junior in round 1, open to the senior
+    let week = serde_json::json!({ "picks": [{ "start_date": "2027-12-20", "end_date": "2027-12-26", "preference_rank": 1 }] });
+    let submit = |window: Uuid| format!("/api/vacation-bids/bid-windows/{window}/submit");
+    let resp = common::post_json(addr, &junior_token, &submit(windows[1]), week.clone()).await;
+    assert_eq!(resp.status(), 400);
+    let resp = common::post_json(addr, &senior_token, &submit(windows[0]), week).await;
+    assert_eq!(resp.status(), 200);
+
+    // A bid that got in anyway (filed before the history was entered) is skipped
+    sqlx::query(
+        "INSERT INTO vacation_bids (vacation_bid_window_id, start_date, end_date, preference_rank) VALUES ($1, '2027-12-20', '2027-12-26', 1)",
+    )
+    .bind(windows[1])
+    .execute(&pool)
+    .await
+    .unwrap();
+    let table: serde_json::Value = common::get(addr, &token, &format!("/api/vacation-bids/periods/{period_id}/process-bids/preview"))
+        .await
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(table["rows"][1]["skipped"][0]["reason"], "holiday_rotation");
+
+    // Processing records the senior as this year's recipient
+    let resp = common::post_json(addr, &token, &format!("/api/vacation-bids/periods/{period_id}/process-bids"), serde_json::json!({})).await;
+    assert_eq!(resp.status(), 200);
+    let awards: Vec<serde_json::Value> = common::get(addr, &token, "/api/holiday-awards?year=2027").await.json().await.unwrap();
+    assert_eq!(awards.len(), 1);
+    assert_eq!(awards[0]["user_id"], serde_json::json!(senior));
+    assert!(awards[0]["vacation_bid_id"].is_string());
+    let own: Vec<serde_json::Value> = common::get(addr, &junior_token, "/api/holiday-awards").await.json().await.unwrap();
+    assert_eq!(own.len(), 1);
+    assert_eq!(own[0]["date"], "2026-12-25");
+
+    let report_path = "/api/reports/holiday-rotation?year=2027";
+    assert_eq!(common::get(addr, &junior_token, report_path).await.status(), 403);
+    let report: serde_json::Value = common::get(addr, &token, report_path).await.json().await.unwrap();
+    let row = |user: Uuid| {
+        report["rows"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|r| r["user_id"] == serde_json::json!(user))
+            .unwrap()
+            .clone()
+    };
+    assert_eq!(row(senior)["groups"][0]["last_received_year"], 2027);
+    assert_eq!(row(senior)["groups"][0]["locked_out"], false);
+    assert_eq!(row(junior)["groups"][0]["last_received_year"], 2026);
+    assert_eq!(row(junior)["groups"][0]["locked_out"], true);
+    let csv = common::get(addr, &token, &format!("{report_path}&format=csv")).await.text().await.unwrap();
+    assert!(csv.starts_with("Employee,Seniority date,"));
+    assert!(csv.contains("Locked out through round 1"));
+
+    // Rolling back the period takes the senior's receipt back off the record
+    let resp = common::post_json(addr, &token, &format!("/api/vacation-bids/periods/{period_id}/rollback-bids"), serde_json::json!({})).await;
+    assert_eq!(resp.status(), 200);
+    let awards: Vec<serde_json::Value> = common::get(addr, &token, "/api/holiday-awards?year=2027").await.json().await.unwrap();
+    assert!(awards.is_empty());
+
+    common::cleanup_test_org(&pool, org_id).await;
+}
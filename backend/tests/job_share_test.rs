@@ -0,0 +1,200 @@
+mod common;
+
+use time::{Duration, OffsetDateTime};
+use uuid::Uuid;
+
+fn unique_email(prefix: &str) -> String {
+    format!("{}+{}@test.local", prefix, &Uuid::new_v4().to_string()[..8])
+}
+
+#[tokio::test]
+async fn job_share_pair_bids_jointly_and_splits_the_slot() {
+    let (addr, pool) = common::setup_test_app().await;
+    let org_id = common::create_test_org(&pool, "job-share").await;
+    let admin_email = unique_email("job-share-admin");
+    let (admin, admin_pw) = common::create_test_user(&pool, org_id, "admin", &admin_email).await;
+    let token = common::get_auth_token(addr, &admin_email, &admin_pw).await;
+
+    let (senior, ..) = common::create_test_senior_employee(&pool, org_id, None, "2005-01-01").await;
+    let (regular, ..) = common::create_test_senior_employee(&pool, org_id, None, "2010-01-01").await;
+    let (junior, junior_email, junior_pw) = common::create_test_senior_employee(&pool, org_id, None, "2020-01-01").await;
+    let (unpaired, ..) = common::create_test_senior_employee(&pool, org_id, None, "2001-01-01").await;
+    sqlx::query("UPDATE users SET employee_type = 'job_share' WHERE id = ANY($1)")
+        .bind(vec![senior, junior, unpaired])
+        .execute(&pool)
+        .await
+        .unwrap();
+    let junior_token = common::get_auth_token(addr, &junior_email, &junior_pw).await;
+
+    let resp = common::post_json(addr, &token, "/api/job-share-pairs", serde_json::json!({ "user_a_id": junior, "user_b_id": senior })).await;
+    assert_eq!(resp.status(), 200);
+    let resp = common::post_json(addr, &token, "/api/job-share-pairs", serde_json::json!({ "user_a_id": regular, "user_b_id": senior })).await;
+    assert_eq!(resp.status(), 409);
+
+    let team = common::create_test_team(&pool, org_id, "Job share team").await;
+    let template = common::create_test_shift_template(&pool, org_id).await;
+    let class_id = common::create_test_classification(&pool, org_id).await;
+    let slot = Uuid::new_v4();
+    sqlx::query(
+        "INSERT INTO shift_slots (id, team_id, shift_template_id, classification_id, days_of_week) VALUES ($1, $2, $3, $4, '{1,2,3,4}')",
+    )
+    .bind(slot)
+    .bind(team)
+    .bind(template)
+    .bind(class_id)
+    .execute(&pool)
+    .await
+    .unwrap();
+    let period_id = Uuid::new_v4();
+    sqlx::query(
+        "INSERT INTO schedule_periods (id, org_id, name, start_date, end_date) VALUES ($1, $2, 'Job share period', '2027-01-01', '2027-06-30')",
+    )
+    .bind(period_id)
+    .bind(org_id)
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    // One joint window at the senior partner's rank; the unpaired job share still goes last
+    let resp = common::post_json(
+        addr,
+        &token,
+        &format!("/api/schedule/periods/{period_id}/open-bidding"),
+        serde_json::json!({ "window_duration_hours": 24 }),
+    )
+    .await;
+    assert_eq!(resp.status(), 200);
+    let windows: Vec<serde_json::Value> = resp.json().await.unwrap();
+    let order: Vec<(String, serde_json::Value)> = windows
+        .iter()
+        .filter(|w| w["user_id"] != serde_json::json!(admin))
+        .map(|w| (w["user_id"].as_str().unwrap().to_string(), w["partner_user_id"].clone()))
+        .collect();
+    assert_eq!(
+        order,
+        vec![
+            (senior.to_string(), serde_json::json!(junior)),
+            (regular.to_string(), serde_json::Value::Null),
+            (unpaired.to_string(), serde_json::Value::Null),
+        ]
+    );
+    assert_eq!(windows[0]["is_job_share"], true);
+    let joint_window = windows[0]["id"].as_str().unwrap().to_string();
+
+    // The junior partner bids for the pair, splitting the slot's days
+    let submit = format!("/api/bid-windows/{joint_window}/submit");
+    let resp = common::post_json(addr, &junior_token, &submit, serde_json::json!({ "preferences": [{ "slot_id": slot, "preference_rank": 1 }] })).await;
+    assert_eq!(resp.status(), 400);
+    let resp = common::post_json(
+        addr,
+        &junior_token,
+        &submit,
+        serde_json::json!({ "preferences": [{ "slot_id": slot, "preference_rank": 1, "partner_days": [1, 2, 3, 4] }] }),
+    )
+    .await;
+    assert_eq!(resp.status(), 400);
+    let resp = common::post_json(
+        addr,
+        &junior_token,
+        &submit,
+        serde_json::json!({ "preferences": [{ "slot_id": slot, "preference_rank": 1, "partner_days": [4] }] }),
+    )
+    .await;
+    assert_eq!(resp.status(), 200);
+
+    let resp = common::post_json(addr, &token, &format!("/api/schedule/periods/{period_id}/process-bids"), serde_json::json!({})).await;
+    assert_eq!(resp.status(), 200);
+    let mut assignments: Vec<(Uuid, Vec<i32>)> = sqlx::query_as(
+        "SELECT user_id, days_of_week FROM slot_assignments WHERE period_id = $1 AND slot_id = $2 ORDER BY days_of_week",
+    )
+    .bind(period_id)
+    .bind(slot)
+    .fetch_all(&pool)
+    .await
+    .unwrap();
+    assignments.sort_by_key(|(_, days)| days.len());
+    assert_eq!(assignments, vec![(junior, vec![4]), (senior, vec![1, 2, 3])]);
+
+    // Rolling back removes both partners' shares
+    let resp = common::post_json(addr, &token, &format!("/api/schedule/periods/{period_id}/rollback-bids"), serde_json::json!({})).await;
+    assert_eq!(resp.status(), 200);
+    let left: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM slot_assignments WHERE period_id = $1")
+        .bind(period_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(left, 0);
+
+    common::cleanup_test_org(&pool, org_id).await;
+}
+
+#[tokio::test]
+async fn job_share_partners_are_not_both_off() {
+    let (addr, pool) = common::setup_test_app().await;
+    let org_id = common::create_test_org(&pool, "job-share-vac").await;
+    let admin_email = unique_email("job-share-vac-admin");
+    let (_admin, admin_pw) = common::create_test_user(&pool, org_id, "admin", &admin_email).await;
+    let token = common::get_auth_token(addr, &admin_email, &admin_pw).await;
+    let (senior, ..) = common::create_test_senior_employee(&pool, org_id, None, "2005-01-01").await;
+    let (junior, ..) = common::create_test_senior_employee(&pool, org_id, None, "2020-01-01").await;
+    let resp = common::post_json(addr, &token, "/api/job-share-pairs", serde_json::json!({ "user_a_id": senior, "user_b_id": junior })).await;
+    assert_eq!(resp.status(), 200);
+
+    let period_id = Uuid::new_v4();
+    sqlx::query("INSERT INTO vacation_bid_periods (id, org_id, year, round, status) VALUES ($1, $2, 2027, 2, 'open')")
+        .bind(period_id)
+        .bind(org_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    let opens = OffsetDateTime::now_utc() - Duration::days(2);
+    let d = |s: &str| time::Date::parse(s, &time::format_description::well_known::Iso8601::DATE).unwrap();
+    let picks: [(Uuid, &[(&str, &str)]); 2] = [
+        (senior, &[("2027-07-05", "2027-07-09")]),
+        (junior, &[("2027-07-08", "2027-07-12"), ("2027-08-02", "2027-08-03")]),
+    ];
+    for (i, (user, ranges)) in picks.iter().enumerate() {
+        let window_id = Uuid::new_v4();
+        sqlx::query(
+            "INSERT INTO vacation_bid_windows (id, vacation_bid_period_id, user_id, seniority_rank, opens_at, closes_at) VALUES ($1, $2, $3, $4, $5, $6)",
+        )
+        .bind(window_id)
+        .bind(period_id)
+        .bind(user)
+        .bind(i as i32 + 1)
+        .bind(opens)
+        .bind(opens + Duration::hours(1))
+        .execute(&pool)
+        .await
+        .unwrap();
+        for (rank, (start, end)) in ranges.iter().enumerate() {
+            sqlx::query(
+                "INSERT INTO vacation_bids (vacation_bid_window_id, start_date, end_date, preference_rank) VALUES ($1, $2, $3, $4)",
+            )
+            .bind(window_id)
+            .bind(d(start))
+            .bind(d(end))
+            .bind(rank as i32 + 1)
+            .execute(&pool)
+            .await
+            .unwrap();
+        }
+    }
+
+    let table: serde_json::Value = common::http_client()
+        .get(format!("http://{}/api/vacation-bids/periods/{}/process-bids/preview", addr, period_id))
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    let junior_row = &table["rows"][1];
+    assert_eq!(junior_row["user_id"], serde_json::json!(junior));
+    assert_eq!(junior_row["skipped"][0]["reason"], "partner_off");
+    assert_eq!(junior_row["awards"].as_array().unwrap().len(), 1);
+    assert_eq!(junior_row["awards"][0]["start_date"], "2027-08-02");
+
+    common::cleanup_test_org(&pool, org_id).await;
+}
@@ -0,0 +1,320 @@
+mod common;
+
+use sqlx::PgPool;
+use uuid::Uuid;
+
+fn unique_email(prefix: &str) -> String {
+    format!("{}+{}@test.local", prefix, &Uuid::new_v4().to_string()[..8])
+}
+
+fn future_date() -> time::Date {
+    time::OffsetDateTime::now_utc().date() + time::Duration::days(14)
+}
+
+struct Fixture {
+    addr: std::net::SocketAddr,
+    pool: PgPool,
+    org_id: Uuid,
+    token: String,
+    class_id: Uuid,
+    employees: Vec<Uuid>,
+}
+
+async fn setup(name: &str) -> Fixture {
+    let (addr, pool) = common::setup_test_app().await;
+    let org_id = common::create_test_org(&pool, name).await;
+    let email = unique_email("mandatory-sup");
+    let (_sup_id, password) = common::create_test_user(&pool, org_id, "supervisor", &email).await;
+    let token = common::get_auth_token(addr, &email, &password).await;
+    let class_id = common::create_test_classification(&pool, org_id).await;
+
+    let mut employees = Vec::new();
+    for _ in 0..3 {
+        let (id, _) = common::create_test_user_with_classification(
+            &pool,
+            org_id,
+            class_id,
+            "employee",
+            &unique_email("mandatory-emp"),
+        )
+        .await;
+        employees.push(id);
+    }
+
+    Fixture { addr, pool, org_id, token, class_id, employees }
+}
+
+async fn rotation(f: &Fixture, extra: &str) -> Vec<serde_json::Value> {
+    let resp = common::http_client()
+        .get(format!(
+            "http://{}/api/staffing/mandatory-ot-order?classification_id={}{}",
+            f.addr, f.class_id, extra
+        ))
+        .header("Authorization", format!("Bearer {}", f.token))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    resp.json().await.unwrap()
+}
+
+fn order(entries: &[serde_json::Value]) -> Vec<String> {
+    entries
+        .iter()
+        .map(|e| e["user_id"].as_str().unwrap().to_string())
+        .collect()
+}
+
+async fn create_ot_request(f: &Fixture) -> String {
+    let resp = common::http_client()
+        .post(format!("http://{}/api/ot-requests", f.addr))
+        .header("Authorization", format!("Bearer {}", f.token))
+        .json(&serde_json::json!({
+            "date": future_date().to_string(),
+            "start_time": "06:00:00",
+            "end_time": "08:00:00",
+            "classification_id": f.class_id.to_string(),
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let body: serde_json::Value = resp.json().await.unwrap();
+    body["id"].as_str().unwrap().to_string()
+}
+
+async fn assign_mandatory(f: &Fixture, request_id: &str, user_id: &str) -> reqwest::Response {
+    common::http_client()
+        .post(format!("http://{}/api/ot-requests/{}/assign", f.addr, request_id))
+        .header("Authorization", format!("Bearer {}", f.token))
+        .json(&serde_json::json!({ "user_id": user_id, "ot_type": "mandatory" }))
+        .send()
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn forced_assignment_advances_rotation_and_cancel_restores_it() {
+    let f = setup("mandatory-rotation").await;
+
+    let initial = order(&rotation(&f, "").await);
+    assert_eq!(initial.len(), 3);
+
+    let request_id = create_ot_request(&f).await;
+    let resp = assign_mandatory(&f, &request_id, &initial[0]).await;
+    assert_eq!(resp.status(), 200);
+
+    let after = rotation(&f, "").await;
+    assert_eq!(
+        order(&after),
+        vec![initial[1].clone(), initial[2].clone(), initial[0].clone()],
+        "forced employee should move to the back"
+    );
+    assert_eq!(after[2]["times_forced"], 1);
+    assert!(after[2]["last_mandatory_at"].as_str().is_some());
+
+    // Cancelling the assignment voids the turn
+    let resp = common::http_client()
+        .delete(format!(
+            "http://{}/api/ot-requests/{}/assign/{}",
+            f.addr, request_id, initial[0]
+        ))
+        .header("Authorization", format!("Bearer {}", f.token))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    assert_eq!(order(&rotation(&f, "").await), initial);
+
+    common::cleanup_test_org(&f.pool, f.org_id).await;
+}
+
+#[tokio::test]
+async fn exemptions_block_forcing_until_revoked() {
+    let f = setup("mandatory-exempt").await;
+    let client = common::http_client();
+    let date = future_date();
+    let target = f.employees[1];
+
+    let resp = client
+        .post(format!("http://{}/api/ot/mandatory/exemptions", f.addr))
+        .header("Authorization", format!("Bearer {}", f.token))
+        .json(&serde_json::json!({
+            "user_id": target,
+            "start_date": (date - time::Duration::days(1)).to_string(),
+            "end_date": (date + time::Duration::days(1)).to_string(),
+            "reason": "Light duty",
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let exemption: serde_json::Value = resp.json().await.unwrap();
+    let exemption_id = exemption["id"].as_str().unwrap().to_string();
+
+    let entries = rotation(&f, &format!("&date={}", date)).await;
+    let entry = entries
+        .iter()
+        .find(|e| e["user_id"] == target.to_string())
+        .unwrap();
+    assert_eq!(entry["eligible"], false);
+    assert_eq!(entry["ineligible_reason"], "exempted");
+
+    // Outside the exemption window the employee is eligible again
+    let later = date + time::Duration::days(5);
+    let entries = rotation(&f, &format!("&date={}", later)).await;
+    assert!(entries.iter().all(|e| e["eligible"] == true));
+
+    let request_id = create_ot_request(&f).await;
+    let resp = assign_mandatory(&f, &request_id, &target.to_string()).await;
+    assert_eq!(resp.status(), 400);
+
+    let resp = client
+        .patch(format!(
+            "http://{}/api/ot/mandatory/exemptions/{}/revoke",
+            f.addr, exemption_id
+        ))
+        .header("Authorization", format!("Bearer {}", f.token))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+
+    let resp = assign_mandatory(&f, &request_id, &target.to_string()).await;
+    assert_eq!(resp.status(), 200);
+
+    common::cleanup_test_org(&f.pool, f.org_id).await;
+}
+
+#[tokio::test]
+async fn refusals_keep_position_and_overrides_use_a_turn() {
+    let f = setup("mandatory-refusal").await;
+    let client = common::http_client();
+    let initial = order(&rotation(&f, "").await);
+
+    let record = |body: serde_json::Value| {
+        let client = client.clone();
+        let url = format!("http://{}/api/ot/mandatory/events", f.addr);
+        let token = f.token.clone();
+        async move {
+            client
+                .post(url)
+                .header("Authorization", format!("Bearer {}", token))
+                .json(&body)
+                .send()
+                .await
+                .unwrap()
+        }
+    };
+
+    let resp = record(serde_json::json!({
+        "classification_id": f.class_id,
+        "user_id": initial[0],
+        "event_type": "refused",
+        "notes": "Refused order at 0600",
+    }))
+    .await;
+    assert_eq!(resp.status(), 200);
+    let event: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(event["counts_as_turn"], false);
+
+    let after = rotation(&f, "").await;
+    assert_eq!(order(&after), initial, "refusal must not advance the rotation");
+    assert_eq!(after[0]["refusals"], 1);
+
+    let resp = record(serde_json::json!({
+        "classification_id": f.class_id,
+        "user_id": initial[0],
+        "event_type": "override",
+        "notes": "Disciplinary assignment",
+    }))
+    .await;
+    assert_eq!(resp.status(), 200);
+    let override_event: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(override_event["counts_as_turn"], true);
+    assert_eq!(order(&rotation(&f, "").await)[2], initial[0]);
+
+    // Voiding the override restores the order
+    let resp = client
+        .patch(format!(
+            "http://{}/api/ot/mandatory/events/{}/void",
+            f.addr,
+            override_event["id"].as_str().unwrap()
+        ))
+        .header("Authorization", format!("Bearer {}", f.token))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    assert_eq!(order(&rotation(&f, "").await), initial);
+
+    // Forced turns can only come from assignments
+    let resp = record(serde_json::json!({
+        "classification_id": f.class_id,
+        "user_id": initial[1],
+        "event_type": "forced",
+    }))
+    .await;
+    assert_eq!(resp.status(), 400);
+
+    common::cleanup_test_org(&f.pool, f.org_id).await;
+}
+
+#[tokio::test]
+async fn rest_rule_marks_employees_with_an_early_next_shift_ineligible() {
+    let f = setup("mandatory-rest").await;
+    let date = future_date();
+
+    // Two of the three work the 07:00 day shift the morning after
+    let template = common::create_test_shift_template(&f.pool, f.org_id).await;
+    let next_day = common::create_test_scheduled_shift(&f.pool, f.org_id, template, date + time::Duration::days(1)).await;
+    let sup: Uuid = sqlx::query_scalar("SELECT id FROM users WHERE org_id = $1 AND role = 'supervisor'")
+        .bind(f.org_id)
+        .fetch_one(&f.pool)
+        .await
+        .unwrap();
+    for &user in &f.employees[..2] {
+        common::create_test_assignment(&f.pool, next_day, user, sup).await;
+    }
+
+    let reasons = |entries: Vec<serde_json::Value>| {
+        let mut by_user: Vec<(String, serde_json::Value)> = entries
+            .into_iter()
+            .map(|e| (e["user_id"].as_str().unwrap().to_string(), e["ineligible_reason"].clone()))
+            .collect();
+        by_user.sort_by_key(|(u, _)| f.employees.iter().position(|e| e.to_string() == *u));
+        by_user.into_iter().map(|(_, r)| r).collect::<Vec<_>>()
+    };
+
+    // An overnight window ending at 02:00 leaves five hours before 07:00
+    let overnight = rotation(&f, &format!("&date={date}&start_time=20:00:00&end_time=02:00:00")).await;
+    assert_eq!(
+        reasons(overnight),
+        vec![serde_json::json!("rest_period"), serde_json::json!("rest_period"), serde_json::Value::Null]
+    );
+
+    // A morning window leaves a full day
+    let morning = rotation(&f, &format!("&date={date}&start_time=06:00:00&end_time=08:00:00")).await;
+    assert!(morning.iter().all(|e| e["eligible"] == true));
+
+    common::cleanup_test_org(&f.pool, f.org_id).await;
+}
+
+#[tokio::test]
+async fn employee_cannot_manage_mandatory_rotation() {
+    let (addr, pool) = common::setup_test_app().await;
+    let org_id = common::create_test_org(&pool, "mandatory-emp").await;
+    let email = unique_email("mandatory-emp");
+    let (_uid, password) = common::create_test_user(&pool, org_id, "employee", &email).await;
+    let token = common::get_auth_token(addr, &email, &password).await;
+
+    let resp = common::http_client()
+        .get(format!("http://{}/api/ot/mandatory/exemptions", addr))
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 403);
+
+    common::cleanup_test_org(&pool, org_id).await;
+}
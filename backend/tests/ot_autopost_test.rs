@@ -0,0 +1,266 @@
+mod common;
+
+use sqlx::PgPool;
+use uuid::Uuid;
+
+fn unique_email(prefix: &str) -> String {
+    format!("{}+{}@test.local", prefix, &Uuid::new_v4().to_string()[..8])
+}
+
+/// Default coverage plan requiring one person of `class_id` from 08:00 to 12:00
+/// (slots 16..=23) every day. Nobody is scheduled, so every day has a 4h gap.
+async fn create_morning_gap_plan(pool: &PgPool, org_id: Uuid, admin_id: Uuid, class_id: Uuid) {
+    let plan_id = Uuid::new_v4();
+    sqlx::query(
+        "INSERT INTO coverage_plans (id, org_id, name, is_default, created_by) VALUES ($1, $2, 'Autopost', true, $3)",
+    )
+    .bind(plan_id)
+    .bind(org_id)
+    .bind(admin_id)
+    .execute(pool)
+    .await
+    .expect("Failed to create coverage plan");
+
+    sqlx::query(
+        "INSERT INTO coverage_plan_slots (plan_id, classification_id, day_of_week, slot_index, min_headcount, target_headcount, max_headcount) \
+         SELECT $1, $2, d, s, 1, 1, 2 FROM generate_series(0, 6) d, generate_series(16, 23) s",
+    )
+    .bind(plan_id)
+    .bind(class_id)
+    .execute(pool)
+    .await
+    .expect("Failed to create coverage plan slots");
+}
+
+async fn run_autopost(addr: std::net::SocketAddr, token: &str) -> serde_json::Value {
+    let resp = common::http_client()
+        .post(format!("http://{}/api/ot-requests/auto-posts/run", addr))
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200, "auto-post run should succeed");
+    resp.json().await.unwrap()
+}
+
+#[tokio::test]
+async fn publish_mode_splits_gaps_and_does_not_repost() {
+    let (addr, pool) = common::setup_test_app().await;
+    let org_id = common::create_test_org(&pool, "autopost-pub").await;
+    let email = unique_email("autopost-admin");
+    let (admin_id, password) = common::create_test_user(&pool, org_id, "admin", &email).await;
+    let token = common::get_auth_token(addr, &email, &password).await;
+    let class_id = common::create_test_classification(&pool, org_id).await;
+    create_morning_gap_plan(&pool, org_id, admin_id, class_id).await;
+
+    common::set_org_setting(&pool, org_id, "ot_autopost_mode", serde_json::json!("publish")).await;
+    common::set_org_setting(&pool, org_id, "ot_autopost_days_ahead", serde_json::json!("2")).await;
+    common::set_org_setting(&pool, org_id, "ot_autopost_max_hours", serde_json::json!("3")).await;
+
+    let result = run_autopost(addr, &token).await;
+    let windows = result["windows"].as_array().unwrap();
+    // 4h gap per day, max 3h -> two 2h pieces per day, two days
+    assert_eq!(windows.len(), 4, "unexpected windows: {result}");
+    assert_eq!(windows[0]["start_time"], "08:00:00");
+    assert_eq!(windows[0]["end_time"], "10:00:00");
+    assert_eq!(windows[1]["start_time"], "10:00:00");
+    assert_eq!(windows[1]["end_time"], "12:00:00");
+    assert_eq!(windows[0]["date"], result["scanned_from"]);
+
+    let (requests,): (i64,) = sqlx::query_as(
+        "SELECT COUNT(*) FROM ot_requests WHERE org_id = $1 AND status = 'open'",
+    )
+    .bind(org_id)
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert_eq!(requests, 4);
+
+    let (notified,): (i64,) = sqlx::query_as(
+        "SELECT COUNT(*) FROM notifications WHERE org_id = $1 AND user_id = $2 AND notification_type = 'ot_autopost_published'",
+    )
+    .bind(org_id)
+    .bind(admin_id)
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert_eq!(notified, 1);
+
+    // Second run finds the same gaps already covered by open requests
+    let again = run_autopost(addr, &token).await;
+    assert_eq!(again["windows"].as_array().unwrap().len(), 0);
+
+    common::cleanup_test_org(&pool, org_id).await;
+}
+
+#[tokio::test]
+async fn draft_mode_skips_open_requests_and_supports_review() {
+    let (addr, pool) = common::setup_test_app().await;
+    let org_id = common::create_test_org(&pool, "autopost-draft").await;
+    let email = unique_email("autopost-sup");
+    let (admin_id, password) = common::create_test_user(&pool, org_id, "admin", &email).await;
+    let token = common::get_auth_token(addr, &email, &password).await;
+    let class_id = common::create_test_classification(&pool, org_id).await;
+    create_morning_gap_plan(&pool, org_id, admin_id, class_id).await;
+
+    common::set_org_setting(&pool, org_id, "ot_autopost_days_ahead", serde_json::json!("1")).await;
+
+    let client = common::http_client();
+
+    // Dry run to learn the scanned date, then cover 08:00-10:00 with a manual request
+    let resp = client
+        .post(format!("http://{}/api/ot-requests/auto-posts/run?dry_run=true", addr))
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let preview: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(preview["windows"].as_array().unwrap().len(), 1);
+    let date = preview["scanned_from"].as_str().unwrap().to_string();
+
+    let resp = client
+        .post(format!("http://{}/api/ot-requests", addr))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({
+            "date": date,
+            "start_time": "08:00:00",
+            "end_time": "10:00:00",
+            "classification_id": class_id.to_string(),
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+
+    // Mode is "off", so a manual run drafts only the uncovered 10:00-12:00 remainder
+    let result = run_autopost(addr, &token).await;
+    assert_eq!(result["mode"], "draft");
+    let windows = result["windows"].as_array().unwrap();
+    assert_eq!(windows.len(), 1, "unexpected windows: {result}");
+    assert_eq!(windows[0]["start_time"], "10:00:00");
+    assert_eq!(windows[0]["end_time"], "12:00:00");
+
+    let resp = client
+        .get(format!("http://{}/api/ot-requests/auto-posts?status=draft", addr))
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let drafts: Vec<serde_json::Value> = resp.json().await.unwrap();
+    assert_eq!(drafts.len(), 1);
+    let draft_id = drafts[0]["id"].as_str().unwrap().to_string();
+
+    let resp = client
+        .post(format!("http://{}/api/ot-requests/auto-posts/{}/publish", addr, draft_id))
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let published: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(published["status"], "published");
+    assert!(published["ot_request_id"].as_str().is_some());
+
+    // Already published: neither publish nor dismiss applies
+    let resp = client
+        .patch(format!("http://{}/api/ot-requests/auto-posts/{}/dismiss", addr, draft_id))
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 409);
+
+    common::cleanup_test_org(&pool, org_id).await;
+}
+
+#[tokio::test]
+async fn dismissal_holds_until_the_gap_grows() {
+    let (addr, pool) = common::setup_test_app().await;
+    let org_id = common::create_test_org(&pool, "autopost-dismiss").await;
+    let email = unique_email("autopost-dismiss");
+    let (admin_id, password) = common::create_test_user(&pool, org_id, "admin", &email).await;
+    let token = common::get_auth_token(addr, &email, &password).await;
+    let class_id = common::create_test_classification(&pool, org_id).await;
+    create_morning_gap_plan(&pool, org_id, admin_id, class_id).await;
+
+    common::set_org_setting(&pool, org_id, "ot_autopost_days_ahead", serde_json::json!("1")).await;
+
+    let notified = || async {
+        sqlx::query_scalar::<_, i64>(
+            "SELECT COUNT(*) FROM notifications WHERE org_id = $1 AND user_id = $2 AND notification_type = 'ot_autopost_drafted'",
+        )
+        .bind(org_id)
+        .bind(admin_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap()
+    };
+
+    let first = run_autopost(addr, &token).await;
+    assert_eq!(first["windows"].as_array().unwrap().len(), 1, "unexpected windows: {first}");
+    assert_eq!(first["windows"][0]["shortage"], 1);
+    assert_eq!(notified().await, 1);
+
+    let drafts = common::get_json(addr, &token, "/api/ot-requests/auto-posts?status=draft").await;
+    let draft_id = drafts[0]["id"].as_str().unwrap().to_string();
+    let path = format!("/api/ot-requests/auto-posts/{draft_id}/dismiss");
+    let resp = common::send(reqwest::Method::PATCH, addr, &token, &path, serde_json::json!({})).await;
+    assert_eq!(resp.status(), 200);
+
+    // The dismissal holds across later runs while the gap is unchanged
+    for _ in 0..2 {
+        let again = run_autopost(addr, &token).await;
+        assert_eq!(again["windows"].as_array().unwrap().len(), 0, "unexpected windows: {again}");
+    }
+    assert_eq!(notified().await, 1);
+    let drafts = common::get_json(addr, &token, "/api/ot-requests/auto-posts?status=draft").await;
+    assert_eq!(drafts.as_array().unwrap().len(), 0);
+
+    // Once the requirement rises the larger gap is drafted afresh, once
+    sqlx::query(
+        "UPDATE coverage_plan_slots SET min_headcount = 2, target_headcount = 2 \
+         WHERE plan_id IN (SELECT id FROM coverage_plans WHERE org_id = $1)",
+    )
+    .bind(org_id)
+    .execute(&pool)
+    .await
+    .unwrap();
+    let redrafted = run_autopost(addr, &token).await;
+    let windows = redrafted["windows"].as_array().unwrap();
+    assert_eq!(windows.len(), 1, "unexpected windows: {redrafted}");
+    assert_eq!(windows[0]["start_time"], first["windows"][0]["start_time"]);
+    assert_eq!(windows[0]["end_time"], first["windows"][0]["end_time"]);
+    assert_eq!(windows[0]["shortage"], 2);
+    assert_eq!(run_autopost(addr, &token).await["windows"].as_array().unwrap().len(), 0);
+    assert_eq!(notified().await, 2);
+
+    let drafts = common::get_json(addr, &token, "/api/ot-requests/auto-posts?status=draft").await;
+    assert_eq!(drafts.as_array().unwrap().len(), 1);
+    assert_ne!(drafts[0]["id"], draft_id.as_str());
+    let dismissed = common::get_json(addr, &token, "/api/ot-requests/auto-posts?status=dismissed").await;
+    assert_eq!(dismissed.as_array().unwrap().len(), 1);
+    assert_eq!(dismissed[0]["id"], draft_id.as_str());
+
+    common::cleanup_test_org(&pool, org_id).await;
+}
+
+#[tokio::test]
+async fn employee_cannot_run_autopost() {
+    let (addr, pool) = common::setup_test_app().await;
+    let org_id = common::create_test_org(&pool, "autopost-emp").await;
+    let email = unique_email("autopost-emp");
+    let (_uid, password) = common::create_test_user(&pool, org_id, "employee", &email).await;
+    let token = common::get_auth_token(addr, &email, &password).await;
+
+    let resp = common::http_client()
+        .post(format!("http://{}/api/ot-requests/auto-posts/run", addr))
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 403);
+
+    common::cleanup_test_org(&pool, org_id).await;
+}
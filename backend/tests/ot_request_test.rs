@@ -15,6 +15,7 @@ async fn cleanup_ot_request_data(pool: &sqlx::PgPool, org_id: Uuid) {
         // Clear the callout_events FK that points to ot_requests (migration 0030)
         "UPDATE callout_events SET ot_request_id = NULL WHERE ot_request_id IN (SELECT id FROM ot_requests WHERE org_id = $1)",
         "DELETE FROM ot_requests WHERE org_id = $1",
+        "DELETE FROM ot_request_series WHERE org_id = $1",
     ];
     for q in queries {
         let _ = sqlx::query(q).bind(org_id).execute(pool).await;
@@ -1810,3 +1811,354 @@ async fn get_nonexistent_ot_request_returns_404() {
     cleanup_ot_request_data(&pool, org_id).await;
     common::cleanup_test_org(&pool, org_id).await;
 }
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// Recurring Series
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Helper: create a Friday 18:00-02:00 series for June 2026 and return the response body.
+async fn create_friday_series(
+    client: &reqwest::Client,
+    addr: std::net::SocketAddr,
+    token: &str,
+    classification_id: Uuid,
+) -> serde_json::Value {
+    let resp = client
+        .post(format!("http://{}/api/ot-requests/series", addr))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({
+            "classification_id": classification_id.to_string(),
+            "start_time": "18:00:00",
+            "end_time": "02:00:00",
+            "days_of_week": [5],
+            "start_date": "2026-06-01",
+            "end_date": "2026-06-30",
+            "notes": "Summer call-taker",
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200, "create OT request series should succeed");
+    resp.json().await.unwrap()
+}
+
+#[tokio::test]
+async fn series_generates_weekly_occurrences_skipping_holidays() {
+    let (addr, pool) = common::setup_test_app().await;
+    let org_id = common::create_test_org(&pool, "otr-series").await;
+    let class_id = common::create_test_classification(&pool, org_id).await;
+    let email = unique_email("otr-ser");
+    let (_uid, password) = common::create_test_user(&pool, org_id, "admin", &email).await;
+    let token = common::get_auth_token(addr, &email, &password).await;
+
+    sqlx::query("INSERT INTO holiday_calendar (org_id, date, name) VALUES ($1, '2026-06-19', 'Juneteenth')")
+        .bind(org_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let client = common::http_client();
+    let body = create_friday_series(&client, addr, &token, class_id).await;
+
+    // Fridays in June 2026: 5, 12, 19 (holiday), 26
+    let created: Vec<&str> = body["created"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|d| d.as_str().unwrap())
+        .collect();
+    assert_eq!(created, vec!["2026-06-05", "2026-06-12", "2026-06-26"]);
+    assert_eq!(body["series"]["hours"], 8.0);
+    assert_eq!(body["series"]["occurrence_count"], 3);
+
+    let series_id = body["series"]["id"].as_str().unwrap();
+    let resp = client
+        .get(format!("http://{}/api/ot-requests?series_id={}", addr, series_id))
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await
+        .unwrap();
+    let items: Vec<serde_json::Value> = resp.json().await.unwrap();
+    assert_eq!(items.len(), 3);
+    assert!(items.iter().all(|r| r["series_id"] == series_id));
+    assert!(items.iter().all(|r| r["notes"] == "Summer call-taker"));
+
+    cleanup_ot_request_data(&pool, org_id).await;
+    common::cleanup_test_org(&pool, org_id).await;
+}
+
+#[tokio::test]
+async fn series_edit_from_date_preserves_volunteered_occurrences() {
+    let (addr, pool) = common::setup_test_app().await;
+    let org_id = common::create_test_org(&pool, "otr-series-edit").await;
+    let class_id = common::create_test_classification(&pool, org_id).await;
+    let admin_email = unique_email("otr-se-adm");
+    let (_admin_id, admin_pass) =
+        common::create_test_user(&pool, org_id, "admin", &admin_email).await;
+    let admin_token = common::get_auth_token(addr, &admin_email, &admin_pass).await;
+    let emp_email = unique_email("otr-se-emp");
+    let (_emp_id, emp_pass) = common::create_test_user(&pool, org_id, "employee", &emp_email).await;
+    let emp_token = common::get_auth_token(addr, &emp_email, &emp_pass).await;
+
+    let client = common::http_client();
+    let body = create_friday_series(&client, addr, &admin_token, class_id).await;
+    let series_id = body["series"]["id"].as_str().unwrap().to_string();
+
+    // Employee volunteers for the June 19 occurrence
+    let occ_id: Uuid = sqlx::query_scalar(
+        "SELECT id FROM ot_requests WHERE series_id = $1 AND date = '2026-06-19'",
+    )
+    .bind(Uuid::parse_str(&series_id).unwrap())
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    let resp = client
+        .post(format!("http://{}/api/ot-requests/{}/volunteer", addr, occ_id))
+        .header("Authorization", format!("Bearer {}", emp_token))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+
+    // Move the series to Saturdays 20:00-02:00 from June 10 forward
+    let resp = client
+        .patch(format!("http://{}/api/ot-requests/series/{}", addr, series_id))
+        .header("Authorization", format!("Bearer {}", admin_token))
+        .json(&serde_json::json!({
+            "effective_from": "2026-06-10",
+            "days_of_week": [6],
+            "start_time": "20:00:00",
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let result: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(result["cancelled"], serde_json::json!(["2026-06-12", "2026-06-26"]));
+    assert_eq!(result["preserved"], serde_json::json!(["2026-06-19"]));
+    assert_eq!(
+        result["created"],
+        serde_json::json!(["2026-06-13", "2026-06-20", "2026-06-27"])
+    );
+
+    // The original series now ends the day before; a successor holds the new rule
+    let successor_id = result["series"]["id"].as_str().unwrap().to_string();
+    assert_ne!(successor_id, series_id);
+    assert_eq!(result["series"]["previous_series_id"], series_id.as_str());
+    assert_eq!(result["series"]["start_date"], "2026-06-10");
+    assert_eq!(result["series"]["days_of_week"], serde_json::json!([6]));
+    let original: serde_json::Value = client
+        .get(format!("http://{}/api/ot-requests/series/{}", addr, series_id))
+        .header("Authorization", format!("Bearer {}", admin_token))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(original["end_date"], "2026-06-09");
+    assert_eq!(original["days_of_week"], serde_json::json!([5]));
+    assert_eq!(original["occurrences"].as_array().unwrap().len(), 1);
+
+    // June 5 (before effective_from) and the volunteered June 19 keep their original times
+    let rows: Vec<(time::Date, time::Time)> = sqlx::query_as(
+        "SELECT date, start_time FROM ot_requests WHERE series_id IN ($1, $2) AND status != 'cancelled' ORDER BY date",
+    )
+    .bind(Uuid::parse_str(&series_id).unwrap())
+    .bind(Uuid::parse_str(&successor_id).unwrap())
+    .fetch_all(&pool)
+    .await
+    .unwrap();
+    let eighteen = time::Time::from_hms(18, 0, 0).unwrap();
+    let twenty = time::Time::from_hms(20, 0, 0).unwrap();
+    assert_eq!(rows.len(), 5);
+    assert_eq!(rows[0], (time::macros::date!(2026 - 06 - 05), eighteen));
+    assert_eq!(rows[1].1, twenty);
+    assert_eq!(rows[2], (time::macros::date!(2026 - 06 - 19), eighteen));
+
+    // The volunteer entry is untouched
+    let vol_count: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM ot_request_volunteers WHERE ot_request_id = $1 AND withdrawn_at IS NULL",
+    )
+    .bind(occ_id)
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert_eq!(vol_count, 1);
+
+    // The successor can't reach back before its start, and the original can't
+    // stretch into the successor's dates or be split a second time
+    for (id, body, expected) in [
+        (&successor_id, serde_json::json!({ "effective_from": "2026-06-05", "start_time": "19:00:00" }), 400),
+        (&series_id, serde_json::json!({ "effective_from": "2026-06-01", "end_date": "2026-06-30" }), 409),
+        (&series_id, serde_json::json!({ "effective_from": "2026-06-05", "start_time": "19:00:00" }), 409),
+    ] {
+        let resp = client
+            .patch(format!("http://{}/api/ot-requests/series/{}", addr, id))
+            .header("Authorization", format!("Bearer {}", admin_token))
+            .json(&body)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), expected, "{body}");
+    }
+    let saturday_count: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM ot_requests WHERE series_id = $1 AND status != 'cancelled'",
+    )
+    .bind(Uuid::parse_str(&successor_id).unwrap())
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert_eq!(saturday_count, 4);
+
+    // The successor itself can be split again, which continues the chain
+    let resp = client
+        .patch(format!("http://{}/api/ot-requests/series/{}", addr, successor_id))
+        .header("Authorization", format!("Bearer {}", admin_token))
+        .json(&serde_json::json!({ "effective_from": "2026-06-20", "start_time": "21:00:00" }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let result: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(result["series"]["previous_series_id"], successor_id.as_str());
+    assert_eq!(result["updated"], serde_json::json!(["2026-06-20", "2026-06-27"]));
+    let resp = client
+        .patch(format!("http://{}/api/ot-requests/series/{}", addr, successor_id))
+        .header("Authorization", format!("Bearer {}", admin_token))
+        .json(&serde_json::json!({ "effective_from": "2026-06-25", "start_time": "22:00:00" }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 409);
+    let latest_id = result["series"]["id"].as_str().unwrap().to_string();
+
+    // Cancelling the original from June 15 reaches every series split off it
+    let resp = client
+        .patch(format!("http://{}/api/ot-requests/series/{}/cancel", addr, series_id))
+        .header("Authorization", format!("Bearer {}", admin_token))
+        .json(&serde_json::json!({ "effective_from": "2026-06-15" }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let result: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(result["series"]["end_date"], "2026-06-09");
+    assert_eq!(result["cancelled"], serde_json::json!(["2026-06-20", "2026-06-27"]));
+    assert_eq!(result["preserved"], serde_json::json!(["2026-06-19"]));
+    let chain: Vec<(time::Date, bool)> = sqlx::query_as(
+        "SELECT end_date, cancelled_at IS NOT NULL FROM ot_request_series WHERE id = ANY($1) ORDER BY start_date",
+    )
+    .bind(
+        [&series_id, &successor_id, &latest_id]
+            .map(|id| Uuid::parse_str(id).unwrap())
+            .to_vec(),
+    )
+    .fetch_all(&pool)
+    .await
+    .unwrap();
+    assert_eq!(chain[0], (time::macros::date!(2026 - 06 - 09), false));
+    assert_eq!(chain[1], (time::macros::date!(2026 - 06 - 14), false));
+    assert!(chain[2].1);
+    let live: Vec<time::Date> = sqlx::query_scalar(
+        "SELECT date FROM ot_requests WHERE org_id = $1 AND date >= '2026-06-15' AND status != 'cancelled' ORDER BY date",
+    )
+    .bind(org_id)
+    .fetch_all(&pool)
+    .await
+    .unwrap();
+    assert_eq!(live, vec![time::macros::date!(2026 - 06 - 19)]);
+
+    cleanup_ot_request_data(&pool, org_id).await;
+    common::cleanup_test_org(&pool, org_id).await;
+}
+
+#[tokio::test]
+async fn series_cancel_from_date_keeps_assigned_and_skips_exceptions() {
+    let (addr, pool) = common::setup_test_app().await;
+    let org_id = common::create_test_org(&pool, "otr-series-cancel").await;
+    let class_id = common::create_test_classification(&pool, org_id).await;
+    let admin_email = unique_email("otr-sc-adm");
+    let (_admin_id, admin_pass) =
+        common::create_test_user(&pool, org_id, "admin", &admin_email).await;
+    let admin_token = common::get_auth_token(addr, &admin_email, &admin_pass).await;
+    let emp_email = unique_email("otr-sc-emp");
+    let (emp_id, _emp_pass) = common::create_test_user(&pool, org_id, "employee", &emp_email).await;
+
+    let client = common::http_client();
+    let body = create_friday_series(&client, addr, &admin_token, class_id).await;
+    let series_id = body["series"]["id"].as_str().unwrap().to_string();
+    let series_uuid = Uuid::parse_str(&series_id).unwrap();
+
+    let occ = |date: &'static str| {
+        let pool = pool.clone();
+        async move {
+            sqlx::query_scalar::<_, Uuid>(
+                "SELECT id FROM ot_requests WHERE series_id = $1 AND date = $2::DATE AND status != 'cancelled'",
+            )
+            .bind(series_uuid)
+            .bind(date)
+            .fetch_one(&pool)
+            .await
+            .unwrap()
+        }
+    };
+
+    // Cancel the June 12 occurrence individually -> becomes an exception date
+    let june12 = occ("2026-06-12").await;
+    let resp = client
+        .patch(format!("http://{}/api/ot-requests/{}/cancel", addr, june12))
+        .header("Authorization", format!("Bearer {}", admin_token))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+
+    // Assign someone to June 26
+    let june26 = occ("2026-06-26").await;
+    let resp = client
+        .post(format!("http://{}/api/ot-requests/{}/assign", addr, june26))
+        .header("Authorization", format!("Bearer {}", admin_token))
+        .json(&serde_json::json!({ "user_id": emp_id.to_string() }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+
+    // A no-op edit from the start must not regenerate the June 12 exception
+    let resp = client
+        .patch(format!("http://{}/api/ot-requests/series/{}", addr, series_id))
+        .header("Authorization", format!("Bearer {}", admin_token))
+        .json(&serde_json::json!({ "effective_from": "2026-06-01" }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let result: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(result["created"], serde_json::json!([]));
+    assert_eq!(result["series"]["excluded_dates"], serde_json::json!(["2026-06-12"]));
+
+    // Cancel from June 15 forward
+    let resp = client
+        .patch(format!("http://{}/api/ot-requests/series/{}/cancel", addr, series_id))
+        .header("Authorization", format!("Bearer {}", admin_token))
+        .json(&serde_json::json!({ "effective_from": "2026-06-15" }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let result: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(result["cancelled"], serde_json::json!(["2026-06-19"]));
+    assert_eq!(result["preserved"], serde_json::json!(["2026-06-26"]));
+    assert_eq!(result["series"]["end_date"], "2026-06-14");
+
+    let status: String = sqlx::query_scalar("SELECT status::TEXT FROM ot_requests WHERE id = $1")
+        .bind(june26)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(status, "partially_filled");
+
+    cleanup_ot_request_data(&pool, org_id).await;
+    common::cleanup_test_org(&pool, org_id).await;
+}
@@ -0,0 +1,199 @@
+mod common;
+
+use std::net::SocketAddr;
+
+use sqlx::PgPool;
+use time::Duration;
+use uuid::Uuid;
+
+fn unique_email(prefix: &str) -> String {
+    format!("{}+{}@test.local", prefix, &Uuid::new_v4().to_string()[..8])
+}
+
+async fn grant(pool: &PgPool, user_id: Uuid, qualification_id: Uuid) {
+    sqlx::query("INSERT INTO user_qualifications (user_id, qualification_id) VALUES ($1, $2)")
+        .bind(user_id)
+        .bind(qualification_id)
+        .execute(pool)
+        .await
+        .expect("Failed to grant qualification");
+}
+
+async fn get_json(addr: SocketAddr, token: &str, path: &str) -> serde_json::Value {
+    let resp = common::http_client()
+        .get(format!("http://{}{}", addr, path))
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200, "GET {path}");
+    resp.json().await.unwrap()
+}
+
+async fn post_json(addr: SocketAddr, token: &str, path: &str, body: serde_json::Value) -> reqwest::Response {
+    common::http_client()
+        .post(format!("http://{}{}", addr, path))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&body)
+        .send()
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn qualification_minimum_shows_in_gaps_and_day_grid() {
+    let (addr, pool) = common::setup_test_app().await;
+    let org_id = common::create_test_org(&pool, "qualcov").await;
+    let class_id = common::create_test_classification(&pool, org_id).await;
+    let template = common::create_test_shift_template(&pool, org_id).await;
+    let emd = common::create_test_qualification(&pool, org_id, "EMD").await;
+
+    let admin_email = unique_email("qualcov-admin");
+    let (admin_id, admin_pw) = common::create_test_user(&pool, org_id, "admin", &admin_email).await;
+    let token = common::get_auth_token(addr, &admin_email, &admin_pw).await;
+    let emp_email = unique_email("qualcov-emp");
+    let (emp_a, emp_pw) =
+        common::create_test_user_with_classification(&pool, org_id, class_id, "employee", &emp_email).await;
+    let emp_token = common::get_auth_token(addr, &emp_email, &emp_pw).await;
+    let (emp_b, _) =
+        common::create_test_user_with_classification(&pool, org_id, class_id, "employee", &unique_email("qualcov-b")).await;
+
+    let date = time::OffsetDateTime::now_utc().date() + Duration::days(7);
+    let dow = date.weekday().number_days_from_sunday();
+
+    // Two dispatchers 07:00–19:00 meet the headcount of one
+    let plan = post_json(addr, &token, "/api/coverage-plans", serde_json::json!({ "name": "Default", "is_default": true }))
+        .await
+        .json::<serde_json::Value>()
+        .await
+        .unwrap();
+    let plan_id = plan["id"].as_str().unwrap().to_string();
+    let slots: Vec<serde_json::Value> = (14..38)
+        .map(|i| {
+            serde_json::json!({
+                "classification_id": class_id, "day_of_week": dow, "slot_index": i,
+                "min_headcount": 1, "target_headcount": 1, "max_headcount": 2,
+            })
+        })
+        .collect();
+    let resp = post_json(addr, &token, &format!("/api/coverage-plans/{plan_id}/slots/bulk"), serde_json::json!({ "slots": slots })).await;
+    assert_eq!(resp.status(), 200);
+
+    let qual_slots: Vec<serde_json::Value> = (14..38)
+        .map(|i| serde_json::json!({ "qualification_id": emd, "day_of_week": dow, "slot_index": i, "min_count": 1 }))
+        .collect();
+    let qual_path = format!("/api/coverage-plans/{plan_id}/qualification-slots/bulk");
+    let body = serde_json::json!({ "slots": qual_slots });
+    assert_eq!(post_json(addr, &emp_token, &qual_path, body.clone()).await.status(), 403);
+    let resp = post_json(addr, &token, &qual_path, body).await;
+    assert_eq!(resp.status(), 200);
+    let saved: Vec<serde_json::Value> = resp.json().await.unwrap();
+    assert_eq!(saved.len(), 24);
+    assert_eq!(saved[0]["qualification_name"], "EMD");
+
+    let shift = common::create_test_scheduled_shift(&pool, org_id, template, date).await;
+    common::create_test_assignment(&pool, shift, emp_a, admin_id).await;
+    common::create_test_assignment(&pool, shift, emp_b, admin_id).await;
+
+    // Headcount met, but nobody on the floor is EMD-certified
+    let blocks = get_json(addr, &token, &format!("/api/coverage-plans/gaps/{date}/blocks")).await;
+    assert_eq!(blocks.as_array().unwrap().len(), 0);
+    let qual_gaps = get_json(addr, &token, &format!("/api/coverage-plans/gaps/{date}/qualifications")).await;
+    assert_eq!(qual_gaps.as_array().unwrap().len(), 1);
+    assert_eq!(qual_gaps[0]["qualification_name"], "EMD");
+    assert_eq!(qual_gaps[0]["blocks"][0]["start_time"], "07:00");
+    assert_eq!(qual_gaps[0]["blocks"][0]["end_time"], "19:00");
+    assert_eq!(qual_gaps[0]["blocks"][0]["shortage"], 1);
+
+    let grid = get_json(addr, &token, &format!("/api/coverage-plans/day-grid/{date}")).await;
+    assert_eq!(grid["classifications"][0]["blocks"][4]["status"], "green");
+    let emd_row = &grid["qualifications"][0];
+    assert_eq!(emd_row["name"], "EMD");
+    assert_eq!(emd_row["blocks"][4]["min"], 1);
+    assert_eq!(emd_row["blocks"][4]["actual"], 0);
+    assert_eq!(emd_row["blocks"][4]["status"], "red");
+    assert_eq!(emd_row["blocks"][0]["status"], "green");
+
+    // Certify one of them: the gap closes and they're listed in the grid
+    grant(&pool, emp_a, emd).await;
+    let qual_gaps = get_json(addr, &token, &format!("/api/coverage-plans/gaps/{date}/qualifications")).await;
+    assert!(qual_gaps.as_array().unwrap().is_empty());
+    let grid = get_json(addr, &token, &format!("/api/coverage-plans/day-grid/{date}")).await;
+    let block = &grid["qualifications"][0]["blocks"][4];
+    assert_eq!(block["status"], "green");
+    assert_eq!(block["actual"], 1);
+    assert_eq!(block["employees"].as_array().unwrap().len(), 1);
+    assert_eq!(block["employees"][0]["user_id"], emp_a.to_string());
+
+    // A zero minimum clears the slot; unknown qualifications are rejected
+    let resp = post_json(
+        addr,
+        &token,
+        &qual_path,
+        serde_json::json!({ "slots": [{ "qualification_id": emd, "day_of_week": dow, "slot_index": 14, "min_count": 0 }] }),
+    )
+    .await;
+    assert_eq!(resp.status(), 200);
+    let listed = get_json(addr, &token, &format!("/api/coverage-plans/{plan_id}/qualification-slots?qualification_id={emd}")).await;
+    assert_eq!(listed.as_array().unwrap().len(), 0);
+    let resp = post_json(
+        addr,
+        &token,
+        &qual_path,
+        serde_json::json!({ "slots": [{ "qualification_id": Uuid::new_v4(), "day_of_week": dow, "slot_index": 14, "min_count": 1 }] }),
+    )
+    .await;
+    assert_eq!(resp.status(), 404);
+
+    common::cleanup_test_org(&pool, org_id).await;
+}
+
+#[tokio::test]
+async fn available_employees_prefer_qualified_when_filling_a_qualification_gap() {
+    let (addr, pool) = common::setup_test_app().await;
+    let org_id = common::create_test_org(&pool, "qualcov-avail").await;
+    let class_id = common::create_test_classification(&pool, org_id).await;
+    let template = common::create_test_shift_template(&pool, org_id).await;
+    let cto = common::create_test_qualification(&pool, org_id, "CTO").await;
+
+    let sup_email = unique_email("qualcov-sup");
+    let (_sup, sup_pw) = common::create_test_user(&pool, org_id, "supervisor", &sup_email).await;
+    let token = common::get_auth_token(addr, &sup_email, &sup_pw).await;
+    let (adams, _) =
+        common::create_test_user_with_classification(&pool, org_id, class_id, "employee", &unique_email("qualcov-adams")).await;
+    let (zimmer, _) =
+        common::create_test_user_with_classification(&pool, org_id, class_id, "employee", &unique_email("qualcov-zimmer")).await;
+    for (id, last) in [(adams, "Adams"), (zimmer, "Zimmer")] {
+        sqlx::query("UPDATE users SET last_name = $2 WHERE id = $1")
+            .bind(id)
+            .bind(last)
+            .execute(&pool)
+            .await
+            .unwrap();
+    }
+    grant(&pool, zimmer, cto).await;
+
+    let date = time::OffsetDateTime::now_utc().date() + Duration::days(3);
+    common::create_test_scheduled_shift(&pool, org_id, template, date).await;
+    let base = format!(
+        "/api/staffing/available?date={date}&shift_template_id={template}&classification_id={class_id}"
+    );
+
+    let plain = get_json(addr, &token, &base).await;
+    let order: Vec<&str> = plain["employees"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| e["last_name"].as_str().unwrap())
+        .collect();
+    assert_eq!(order, vec!["Adams", "Zimmer"]);
+    assert!(plain["employees"][0]["has_qualification"].is_null());
+
+    let filling = get_json(addr, &token, &format!("{base}&qualification_id={cto}")).await;
+    let employees = filling["employees"].as_array().unwrap();
+    assert_eq!(employees[0]["user_id"], zimmer.to_string());
+    assert_eq!(employees[0]["has_qualification"], true);
+    assert_eq!(employees[1]["has_qualification"], false);
+
+    common::cleanup_test_org(&pool, org_id).await;
+}
@@ -0,0 +1,269 @@
+mod common;
+
+use std::net::SocketAddr;
+
+use sqlx::PgPool;
+use time::Duration;
+use uuid::Uuid;
+
+fn unique_email(prefix: &str) -> String {
+    format!("{}+{}@test.local", prefix, &Uuid::new_v4().to_string()[..8])
+}
+
+async fn grant_expiring(pool: &PgPool, user_id: Uuid, qualification_id: Uuid, expires_on: time::Date) {
+    sqlx::query("INSERT INTO user_qualifications (user_id, qualification_id, expires_on) VALUES ($1, $2, $3)")
+        .bind(user_id)
+        .bind(qualification_id)
+        .bind(expires_on)
+        .execute(pool)
+        .await
+        .expect("Failed to grant qualification");
+}
+
+async fn post_json(addr: SocketAddr, token: &str, path: &str, body: serde_json::Value) -> reqwest::Response {
+    common::http_client()
+        .post(format!("http://{}{}", addr, path))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&body)
+        .send()
+        .await
+        .unwrap()
+}
+
+async fn get_json(addr: SocketAddr, token: &str, path: &str) -> serde_json::Value {
+    let resp = common::http_client()
+        .get(format!("http://{}{}", addr, path))
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200, "GET {path}");
+    resp.json().await.unwrap()
+}
+
+#[tokio::test]
+async fn expired_qualifications_stop_counting_until_recertified() {
+    let (addr, pool) = common::setup_test_app().await;
+    let org_id = common::create_test_org(&pool, "qualexp").await;
+    let class_id = common::create_test_classification(&pool, org_id).await;
+    let template = common::create_test_shift_template(&pool, org_id).await;
+    let admin_email = unique_email("qualexp-admin");
+    let (admin_id, admin_pw) = common::create_test_user(&pool, org_id, "admin", &admin_email).await;
+    let token = common::get_auth_token(addr, &admin_email, &admin_pw).await;
+    let (emp_id, _) =
+        common::create_test_user_with_classification(&pool, org_id, class_id, "employee", &unique_email("qualexp-emp")).await;
+
+    // The renewal period drives the default expiry
+    let resp = post_json(addr, &token, "/api/qualifications", serde_json::json!({ "name": "EMD", "renewal_months": 24 })).await;
+    assert_eq!(resp.status(), 200);
+    let emd: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(emd["renewal_months"], 24);
+    let emd_id = Uuid::parse_str(emd["id"].as_str().unwrap()).unwrap();
+
+    let grant_path = format!("/api/users/{emp_id}/qualifications");
+    let resp = post_json(
+        addr,
+        &token,
+        &grant_path,
+        serde_json::json!({ "qualification_id": emd_id, "issued_on": "2020-08-31", "expires_on": "2020-08-01" }),
+    )
+    .await;
+    assert_eq!(resp.status(), 400);
+    let resp = post_json(addr, &token, &grant_path, serde_json::json!({ "qualification_id": emd_id, "issued_on": "2020-08-31" })).await;
+    assert_eq!(resp.status(), 200);
+    let held = get_json(addr, &token, &grant_path).await;
+    assert_eq!(held[0]["issued_on"], "2020-08-31");
+    assert_eq!(held[0]["expires_on"], "2022-08-31");
+
+    // Seat-able position requiring EMD, and a coverage minimum of one EMD holder
+    let date = time::OffsetDateTime::now_utc().date() + Duration::days(3);
+    let dow = date.weekday().number_days_from_sunday() as i16;
+    let position = Uuid::new_v4();
+    sqlx::query("INSERT INTO duty_positions (id, org_id, name, sort_order) VALUES ($1, $2, 'Medical', 1)")
+        .bind(position)
+        .bind(org_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query("INSERT INTO duty_position_qualifications (duty_position_id, qualification_id) VALUES ($1, $2)")
+        .bind(position)
+        .bind(emd_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    let plan_id = Uuid::new_v4();
+    sqlx::query("INSERT INTO coverage_plans (id, org_id, name, is_default, created_by) VALUES ($1, $2, 'Default', true, $3)")
+        .bind(plan_id)
+        .bind(org_id)
+        .bind(admin_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query(
+        "INSERT INTO coverage_plan_qualification_slots (plan_id, qualification_id, day_of_week, slot_index, min_count) \
+         SELECT $1, $2, $3, s, 1 FROM generate_series(14, 37) s",
+    )
+    .bind(plan_id)
+    .bind(emd_id)
+    .bind(dow)
+    .execute(&pool)
+    .await
+    .unwrap();
+    let shift = common::create_test_scheduled_shift(&pool, org_id, template, date).await;
+    common::create_test_assignment(&pool, shift, emp_id, admin_id).await;
+
+    // The 2020 grant has lapsed: it's flagged and ignored everywhere
+    let held = get_json(addr, &token, &grant_path).await;
+    assert_eq!(held[0]["is_expired"], true);
+    let available = get_json(
+        addr,
+        &token,
+        &format!("/api/duty-board/{date}/available?block_index=5&duty_position_id={position}"),
+    )
+    .await;
+    assert!(available.as_array().unwrap().is_empty());
+    let fill: serde_json::Value = post_json(addr, &token, &format!("/api/duty-board/{date}/auto-fill"), serde_json::json!({ "dry_run": true }))
+        .await
+        .json()
+        .await
+        .unwrap();
+    assert!(fill["assigned"].as_array().unwrap().is_empty());
+    let gaps = get_json(addr, &token, &format!("/api/coverage-plans/gaps/{date}/qualifications")).await;
+    assert_eq!(gaps.as_array().unwrap().len(), 1);
+
+    // Recertify: the new dates replace the old ones
+    let today = time::OffsetDateTime::now_utc().date();
+    let resp = post_json(addr, &token, &grant_path, serde_json::json!({ "qualification_id": emd_id, "issued_on": today })).await;
+    assert_eq!(resp.status(), 200);
+    let held = get_json(addr, &token, &grant_path).await;
+    assert_eq!(held.as_array().unwrap().len(), 1);
+    assert_eq!(held[0]["is_expired"], false);
+    let available = get_json(
+        addr,
+        &token,
+        &format!("/api/duty-board/{date}/available?block_index=5&duty_position_id={position}"),
+    )
+    .await;
+    assert_eq!(available.as_array().unwrap().len(), 1);
+    let gaps = get_json(addr, &token, &format!("/api/coverage-plans/gaps/{date}/qualifications")).await;
+    assert!(gaps.as_array().unwrap().is_empty());
+
+    common::cleanup_test_org(&pool, org_id).await;
+}
+
+#[tokio::test]
+async fn reminders_go_out_once_per_threshold_and_report_lists_by_team() {
+    let (addr, pool) = common::setup_test_app().await;
+    let org_id = common::create_test_org(&pool, "qualexp-remind").await;
+    let admin_email = unique_email("qualexp-remind-admin");
+    let (_admin, admin_pw) = common::create_test_user(&pool, org_id, "admin", &admin_email).await;
+    let token = common::get_auth_token(addr, &admin_email, &admin_pw).await;
+    let sup_email = unique_email("qualexp-remind-sup");
+    let (_sup, sup_pw) = common::create_test_user(&pool, org_id, "supervisor", &sup_email).await;
+    let sup_token = common::get_auth_token(addr, &sup_email, &sup_pw).await;
+    let (emp_id, _) = common::create_test_user(&pool, org_id, "employee", &unique_email("qualexp-remind-emp")).await;
+
+    let today = time::OffsetDateTime::now_utc().date();
+    let far = common::create_test_qualification(&pool, org_id, "CPR").await;
+    let ninety = common::create_test_qualification(&pool, org_id, "EMD").await;
+    let thirty = common::create_test_qualification(&pool, org_id, "CTO").await;
+    let seven = common::create_test_qualification(&pool, org_id, "Fire").await;
+    let lapsed = common::create_test_qualification(&pool, org_id, "Police").await;
+    grant_expiring(&pool, emp_id, far, today + Duration::days(100)).await;
+    grant_expiring(&pool, emp_id, ninety, today + Duration::days(85)).await;
+    grant_expiring(&pool, emp_id, thirty, today + Duration::days(20)).await;
+    grant_expiring(&pool, emp_id, seven, today + Duration::days(5)).await;
+    grant_expiring(&pool, emp_id, lapsed, today - Duration::days(3)).await;
+
+    use timeshift_backend::services::qualification_expiry::send_org_reminders;
+    assert_eq!(send_org_reminders(&pool, org_id, today).await.unwrap(), 3);
+    assert_eq!(send_org_reminders(&pool, org_id, today).await.unwrap(), 0);
+
+    let titles: Vec<String> = sqlx::query_scalar(
+        "SELECT title FROM notifications WHERE user_id = $1 AND notification_type = 'qualification_expiring' ORDER BY title",
+    )
+    .bind(emp_id)
+    .fetch_all(&pool)
+    .await
+    .unwrap();
+    assert_eq!(titles, vec!["CTO expires in 20 days", "EMD expires in 85 days", "Fire expires in 5 days"]);
+
+    // Sixty days on: EMD crosses 30 days, CPR crosses 90
+    assert_eq!(send_org_reminders(&pool, org_id, today + Duration::days(60)).await.unwrap(), 2);
+
+    // Recertifying starts reminders over
+    let resp = post_json(
+        addr,
+        &token,
+        &format!("/api/users/{emp_id}/qualifications"),
+        serde_json::json!({ "qualification_id": ninety, "issued_on": today, "expires_on": today + Duration::days(60) }),
+    )
+    .await;
+    assert_eq!(resp.status(), 200);
+    let reminded: Option<i16> =
+        sqlx::query_scalar("SELECT reminded_days FROM user_qualifications WHERE user_id = $1 AND qualification_id = $2")
+            .bind(emp_id)
+            .bind(ninety)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+    assert_eq!(reminded, None);
+
+    // Put the employee on a team for the current period
+    let template = common::create_test_shift_template(&pool, org_id).await;
+    let class_id = common::create_test_classification(&pool, org_id).await;
+    let team_id = common::create_test_team(&pool, org_id, "A Shift").await;
+    let slot_id = Uuid::new_v4();
+    sqlx::query(
+        "INSERT INTO shift_slots (id, team_id, shift_template_id, classification_id, days_of_week) VALUES ($1, $2, $3, $4, '{1,2,3}')",
+    )
+    .bind(slot_id)
+    .bind(team_id)
+    .bind(template)
+    .bind(class_id)
+    .execute(&pool)
+    .await
+    .unwrap();
+    let period_id = Uuid::new_v4();
+    sqlx::query("INSERT INTO schedule_periods (id, org_id, name, start_date, end_date) VALUES ($1, $2, 'Current', $3, $4)")
+        .bind(period_id)
+        .bind(org_id)
+        .bind(today - Duration::days(10))
+        .bind(today + Duration::days(100))
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query("INSERT INTO slot_assignments (slot_id, user_id, period_id) VALUES ($1, $2, $3)")
+        .bind(slot_id)
+        .bind(emp_id)
+        .bind(period_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let path = "/api/reports/qualification-expirations?within_days=30";
+    let resp = common::http_client()
+        .get(format!("http://{}{}", addr, path))
+        .header("Authorization", format!("Bearer {}", sup_token))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 403);
+
+    let report = get_json(addr, &token, path).await;
+    let names: Vec<&str> = report
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| e["qualification_name"].as_str().unwrap())
+        .collect();
+    assert_eq!(names, vec!["Police", "Fire", "CTO"]);
+    assert_eq!(report[0]["team_name"], "A Shift");
+    assert_eq!(report[0]["days_remaining"], -3);
+    assert_eq!(report[2]["days_remaining"], 20);
+
+    let other_team = get_json(addr, &token, &format!("{path}&team_id={}", Uuid::new_v4())).await;
+    assert!(other_team.as_array().unwrap().is_empty());
+
+    common::cleanup_test_org(&pool, org_id).await;
+}
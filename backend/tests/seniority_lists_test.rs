@@ -0,0 +1,249 @@
+mod common;
+
+use sqlx::PgPool;
+use uuid::Uuid;
+
+fn unique_email(prefix: &str) -> String {
+    format!("{}+{}@test.local", prefix, &Uuid::new_v4().to_string()[..8])
+}
+
+/// An employee whose hire and overall seniority dates the admin sets through
+/// the profile. Returns (id, token).
+async fn employee(
+    addr: std::net::SocketAddr,
+    pool: &PgPool,
+    org_id: Uuid,
+    admin_token: &str,
+    hire: &str,
+    seniority: &str,
+) -> (Uuid, String) {
+    let email = unique_email("seniority-list");
+    let (id, password) = common::create_test_user(pool, org_id, "employee", &email).await;
+    let resp = common::send(
+        reqwest::Method::PATCH,
+        addr,
+        admin_token,
+        &format!("/api/users/{id}"),
+        serde_json::json!({ "hire_date": hire, "overall_seniority_date": seniority }),
+    )
+    .await;
+    assert_eq!(resp.status(), 200);
+    (id, common::get_auth_token(addr, &email, &password).await)
+}
+
+fn ranks(list: &serde_json::Value) -> Vec<(String, i64)> {
+    list["entries"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| (e["user_id"].as_str().unwrap().to_string(), e["rank"].as_i64().unwrap()))
+        .collect()
+}
+
+#[tokio::test]
+async fn published_list_is_a_snapshot_that_disputes_correct_and_bidding_can_pin() {
+    let (addr, pool) = common::setup_test_app().await;
+    let org_id = common::create_test_org(&pool, "seniority-list").await;
+    let admin_email = unique_email("seniority-list-admin");
+    let (admin, admin_pw) = common::create_test_user(&pool, org_id, "admin", &admin_email).await;
+    let token = common::get_auth_token(addr, &admin_email, &admin_pw).await;
+    let (senior, _) = employee(addr, &pool, org_id, &token, "2004-06-01", "2005-01-01").await;
+    let (early_hire, _) = employee(addr, &pool, org_id, &token, "2008-03-01", "2010-01-01").await;
+    let (late_hire, late_token) = employee(addr, &pool, org_id, &token, "2009-03-01", "2010-01-01").await;
+
+    let publish = serde_json::json!({ "list_type": "overall" });
+    let resp = common::send(reqwest::Method::POST, addr, &late_token, "/api/seniority-lists", publish.clone()).await;
+    assert_eq!(resp.status(), 403);
+    let resp = common::send(reqwest::Method::POST, addr, &token, "/api/seniority-lists", publish.clone()).await;
+    assert_eq!(resp.status(), 200);
+    let first: serde_json::Value = resp.json().await.unwrap();
+    let first_id = first["id"].as_str().unwrap().to_string();
+    assert_eq!(
+        first["tie_break_rules"],
+        serde_json::json!(["hire_date", "employee_id"])
+    );
+
+    // The equal seniority dates are settled by hire date; the admin has none and goes last
+    let id = |u: Uuid| u.to_string();
+    assert_eq!(
+        ranks(&first),
+        vec![(id(senior), 1), (id(early_hire), 2), (id(late_hire), 3), (id(admin), 4)]
+    );
+    assert_eq!(first["entries"][2]["tie_broken_by"], "hire_date");
+    assert!(first["entries"][1]["tie_broken_by"].is_null());
+
+    let resp = common::send(
+        reqwest::Method::POST,
+        addr,
+        &token,
+        "/api/seniority-lists",
+        serde_json::json!({ "list_type": "bargaining_unit" }),
+    )
+    .await;
+    assert_eq!(resp.status(), 400);
+
+    // The late hire disputes their date; only one open dispute per list
+    let dispute = serde_json::json!({ "reason": "Prior service was not credited", "requested_date": "2009-06-01" });
+    let path = format!("/api/seniority-lists/{first_id}/disputes");
+    let resp = common::send(reqwest::Method::POST, addr, &late_token, &path, dispute.clone()).await;
+    assert_eq!(resp.status(), 200);
+    let filed: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(filed["status"], "open");
+    assert_eq!(filed["rank"], 3);
+    let resp = common::send(reqwest::Method::POST, addr, &late_token, &path, dispute).await;
+    assert_eq!(resp.status(), 409);
+
+    let dispute_id = filed["id"].as_str().unwrap();
+    let resolve = format!("/api/seniority-disputes/{dispute_id}/resolve");
+    let resp = common::send(reqwest::Method::POST, addr, &token, &resolve, serde_json::json!({ "status": "upheld" })).await;
+    assert_eq!(resp.status(), 400);
+    let upheld = serde_json::json!({ "status": "upheld", "corrected_date": "2009-06-01", "notes": "Credited" });
+    let resp = common::send(reqwest::Method::POST, addr, &token, &resolve, upheld.clone()).await;
+    assert_eq!(resp.status(), 200);
+    let resolved: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(resolved["status"], "upheld");
+    let resp = common::send(reqwest::Method::POST, addr, &token, &resolve, upheld).await;
+    assert_eq!(resp.status(), 409);
+
+    // The change is on the employee's audit trail, after the profile edit
+    let history = common::get_json(addr, &late_token, &format!("/api/users/{late_hire}/seniority-history")).await;
+    let history = history.as_array().unwrap();
+    assert_eq!(history.len(), 2);
+    assert_eq!(history[0]["source"], "profile");
+    assert!(history[0]["old_date"].is_null());
+    assert_eq!(history[1]["source"], "dispute");
+    assert_eq!(history[1]["old_date"], "2010-01-01");
+    assert_eq!(history[1]["new_date"], "2009-06-01");
+    assert_eq!(history[1]["dispute_id"], dispute_id);
+    let own: Vec<serde_json::Value> = serde_json::from_value(common::get_json(addr, &late_token, "/api/seniority-disputes").await).unwrap();
+    assert_eq!(own.len(), 1);
+
+    // The published list is untouched; the next one reflects the correction
+    let again = common::get_json(addr, &token, &format!("/api/seniority-lists/{first_id}")).await;
+    assert_eq!(ranks(&again), ranks(&first));
+    let resp = common::send(reqwest::Method::POST, addr, &token, "/api/seniority-lists", publish).await;
+    let second: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(second["entries"][1]["user_id"], serde_json::json!(late_hire));
+    let lists: Vec<serde_json::Value> = serde_json::from_value(common::get_json(addr, &late_token, "/api/seniority-lists").await).unwrap();
+    assert_eq!(lists.len(), 2);
+
+    let csv = common::http_client()
+        .get(format!("http://{}/api/seniority-lists/{first_id}?format=csv", addr))
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await
+        .unwrap()
+        .text()
+        .await
+        .unwrap();
+    assert!(csv.starts_with("Rank,Employee ID,Employee,Seniority date,Tie broken by"));
+
+    // A vacation period pinned to the first list opens windows in its order
+    let period_id = Uuid::new_v4();
+    sqlx::query("INSERT INTO vacation_bid_periods (id, org_id, year, round) VALUES ($1, $2, 2027, 1)")
+        .bind(period_id)
+        .bind(org_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    let pin = format!("/api/vacation-bids/periods/{period_id}/seniority-list");
+    let resp = common::send(reqwest::Method::PUT, addr, &token, &pin, serde_json::json!({ "seniority_list_id": Uuid::new_v4() })).await;
+    assert_eq!(resp.status(), 404);
+    let resp = common::send(reqwest::Method::PUT, addr, &token, &pin, serde_json::json!({ "seniority_list_id": first_id })).await;
+    assert_eq!(resp.status(), 200);
+    let resp = common::send(
+        reqwest::Method::POST,
+        addr,
+        &token,
+        &format!("/api/vacation-bids/periods/{period_id}/open-bidding"),
+        serde_json::json!({ "window_duration_hours": 24 }),
+    )
+    .await;
+    assert_eq!(resp.status(), 200);
+    let opened: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(opened["seniority_list_id"], serde_json::json!(first_id));
+    let windows = common::get_json(addr, &token, &format!("/api/vacation-bids/periods/{period_id}/bid-windows")).await;
+    let order: Vec<_> = windows.as_array().unwrap().iter().map(|w| w["user_id"].clone()).collect();
+    assert_eq!(
+        order,
+        vec![
+            serde_json::json!(senior),
+            serde_json::json!(early_hire),
+            serde_json::json!(late_hire),
+            serde_json::json!(admin),
+        ]
+    );
+
+    // Once bidding is open the pin is fixed
+    let resp = common::send(reqwest::Method::PUT, addr, &token, &pin, serde_json::json!({ "seniority_list_id": null })).await;
+    assert_eq!(resp.status(), 404);
+
+    common::cleanup_test_org(&pool, org_id).await;
+}
+
+#[tokio::test]
+async fn disputes_tie_breaks_and_pins_are_rejected_outside_their_rules() {
+    let (addr, pool) = common::setup_test_app().await;
+    let org_id = common::create_test_org(&pool, "seniority-reject").await;
+    let admin_email = unique_email("seniority-reject-admin");
+    let (_admin, admin_pw) = common::create_test_user(&pool, org_id, "admin", &admin_email).await;
+    let token = common::get_auth_token(addr, &admin_email, &admin_pw).await;
+    let (_listed, listed_token) = employee(addr, &pool, org_id, &token, "2004-06-01", "2005-01-01").await;
+    let dispute = serde_json::json!({ "reason": "Wrong date" });
+
+    // Unknown or repeated tie-break rules
+    for rules in [
+        serde_json::json!(["hire_date", "badge_number"]),
+        serde_json::json!(["hire_date", "employee_id", "hire_date"]),
+    ] {
+        let body = serde_json::json!({ "list_type": "overall", "tie_break_rules": rules });
+        let resp = common::post_json(addr, &token, "/api/seniority-lists", body).await;
+        assert_eq!(resp.status(), 400, "{rules}");
+    }
+
+    // A bad org default blocks publishing until it is fixed
+    let publish = serde_json::json!({ "list_type": "overall" });
+    common::set_org_setting(&pool, org_id, "seniority_tie_breakers", serde_json::json!("hire_date,hire_date")).await;
+    let resp = common::post_json(addr, &token, "/api/seniority-lists", publish.clone()).await;
+    assert_eq!(resp.status(), 400);
+    common::set_org_setting(&pool, org_id, "seniority_tie_breakers", serde_json::json!("hire_date")).await;
+    let resp = common::post_json(addr, &token, "/api/seniority-lists", publish.clone()).await;
+    assert_eq!(resp.status(), 200);
+    let list: serde_json::Value = resp.json().await.unwrap();
+    let list_id = list["id"].as_str().unwrap();
+
+    // Only a published list the employee is on can be disputed
+    let path = format!("/api/seniority-lists/{}/disputes", Uuid::new_v4());
+    assert_eq!(common::post_json(addr, &listed_token, &path, dispute.clone()).await.status(), 404);
+    let (_late, late_token) = employee(addr, &pool, org_id, &token, "2020-01-01", "2020-01-01").await;
+    let path = format!("/api/seniority-lists/{list_id}/disputes");
+    assert_eq!(common::post_json(addr, &late_token, &path, dispute.clone()).await.status(), 404);
+
+    // Another org's list can be neither disputed nor pinned
+    let other_org = common::create_test_org(&pool, "seniority-reject-other").await;
+    let other_email = unique_email("seniority-reject-other");
+    let (_other_admin, other_pw) = common::create_test_user(&pool, other_org, "admin", &other_email).await;
+    let other_token = common::get_auth_token(addr, &other_email, &other_pw).await;
+    let resp = common::post_json(addr, &other_token, "/api/seniority-lists", publish).await;
+    let foreign: serde_json::Value = resp.json().await.unwrap();
+    let foreign_id = foreign["id"].as_str().unwrap();
+    let path = format!("/api/seniority-lists/{foreign_id}/disputes");
+    assert_eq!(common::post_json(addr, &listed_token, &path, dispute).await.status(), 404);
+    assert_eq!(common::get(addr, &token, &format!("/api/seniority-lists/{foreign_id}")).await.status(), 404);
+
+    let period_id = Uuid::new_v4();
+    sqlx::query("INSERT INTO vacation_bid_periods (id, org_id, year, round) VALUES ($1, $2, 2027, 1)")
+        .bind(period_id)
+        .bind(org_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    let pin = format!("/api/vacation-bids/periods/{period_id}/seniority-list");
+    let resp = common::send(reqwest::Method::PUT, addr, &token, &pin, serde_json::json!({ "seniority_list_id": foreign_id })).await;
+    assert_eq!(resp.status(), 404);
+    let resp = common::send(reqwest::Method::PUT, addr, &token, &pin, serde_json::json!({ "seniority_list_id": list_id })).await;
+    assert_eq!(resp.status(), 200);
+
+    common::cleanup_test_org(&pool, other_org).await;
+    common::cleanup_test_org(&pool, org_id).await;
+}
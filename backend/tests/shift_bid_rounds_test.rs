@@ -0,0 +1,147 @@
+mod common;
+
+use sqlx::PgPool;
+use uuid::Uuid;
+
+fn unique_email(prefix: &str) -> String {
+    format!("{}+{}@test.local", prefix, &Uuid::new_v4().to_string()[..8])
+}
+
+async fn window_of(pool: &PgPool, period_id: Uuid, user: Uuid) -> Option<Uuid> {
+    sqlx::query_scalar("SELECT id FROM bid_windows WHERE period_id = $1 AND user_id = $2")
+        .bind(period_id)
+        .bind(user)
+        .fetch_optional(pool)
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn rounds_open_in_turn_with_their_own_pool_and_limits() {
+    let (addr, pool) = common::setup_test_app().await;
+    let org_id = common::create_test_org(&pool, "bid-rounds").await;
+    let admin_email = unique_email("bid-rounds-admin");
+    let (_admin, admin_pw) = common::create_test_user(&pool, org_id, "admin", &admin_email).await;
+    let token = common::get_auth_token(addr, &admin_email, &admin_pw).await;
+
+    let officers = common::create_test_classification(&pool, org_id).await;
+    let dispatchers: Uuid = sqlx::query_scalar(
+        "INSERT INTO classifications (org_id, name, abbreviation) VALUES ($1, 'Call taker', 'CT') RETURNING id",
+    )
+    .bind(org_id)
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    let (senior, email, password) = common::create_test_senior_employee(&pool, org_id, Some(officers), "2005-01-01").await;
+    let senior_token = common::get_auth_token(addr, &email, &password).await;
+    let (junior, email, password) = common::create_test_senior_employee(&pool, org_id, Some(officers), "2010-01-01").await;
+    let junior_token = common::get_auth_token(addr, &email, &password).await;
+    let (dispatcher, email, password) = common::create_test_senior_employee(&pool, org_id, Some(dispatchers), "2001-01-01").await;
+    let dispatcher_token = common::get_auth_token(addr, &email, &password).await;
+
+    let team = common::create_test_team(&pool, org_id, "Rounds team").await;
+    let template = common::create_test_shift_template(&pool, org_id).await;
+    let patrol = common::create_test_shift_slot(&pool, team, template, officers, false).await;
+    let desk = common::create_test_shift_slot(&pool, team, template, dispatchers, false).await;
+    let period_id = Uuid::new_v4();
+    sqlx::query(
+        "INSERT INTO schedule_periods (id, org_id, name, start_date, end_date) VALUES ($1, $2, 'Rounds period', '2027-01-01', '2027-06-30')",
+    )
+    .bind(period_id)
+    .bind(org_id)
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    // Round 1: officers bid for patrol only, one award; round 2: everyone else
+    let rounds = format!("/api/schedule/periods/{period_id}/bid-rounds");
+    let body = serde_json::json!({ "rounds": [
+        { "name": "Officers", "classification_ids": [officers], "slot_ids": [patrol], "window_duration_hours": 24, "max_awards": 1 },
+        { "name": "Open", "window_duration_hours": 12 },
+    ] });
+    let resp = common::send(reqwest::Method::PUT, addr, &token, &rounds, body.clone()).await;
+    assert_eq!(resp.status(), 200);
+    let saved: Vec<serde_json::Value> = resp.json().await.unwrap();
+    assert_eq!(saved[1]["round_number"], 2);
+    assert_eq!(saved[1]["flex_cap"], 2);
+
+    let resp = common::send(
+        reqwest::Method::POST,
+        addr,
+        &token,
+        &format!("/api/schedule/periods/{period_id}/open-bidding"),
+        serde_json::json!({ "window_duration_hours": 24 }),
+    )
+    .await;
+    assert_eq!(resp.status(), 200);
+    let windows: Vec<serde_json::Value> = resp.json().await.unwrap();
+    let users: Vec<&str> = windows.iter().map(|w| w["user_id"].as_str().unwrap()).collect();
+    assert_eq!(users, vec![senior.to_string(), junior.to_string()]);
+    assert!(window_of(&pool, period_id, dispatcher).await.is_none());
+
+    // Rounds are fixed once bidding opens
+    let resp = common::send(reqwest::Method::PUT, addr, &token, &rounds, body).await;
+    assert_eq!(resp.status(), 400);
+
+    // Only the round's slots can be bid on
+    let senior_window = window_of(&pool, period_id, senior).await.unwrap();
+    let submit = |window: Uuid| format!("/api/bid-windows/{window}/submit");
+    let bid = |slot_id: Uuid| serde_json::json!({ "preferences": [{ "slot_id": slot_id, "preference_rank": 1 }] });
+    let resp = common::send(reqwest::Method::POST, addr, &senior_token, &submit(senior_window), bid(desk)).await;
+    assert_eq!(resp.status(), 400);
+    let resp = common::send(reqwest::Method::POST, addr, &senior_token, &submit(senior_window), bid(patrol)).await;
+    assert_eq!(resp.status(), 200);
+
+    // The last round 1 bid opens round 2, ranked after round 1
+    let junior_window = window_of(&pool, period_id, junior).await.unwrap();
+    sqlx::query("UPDATE bid_windows SET opens_at = NOW() - INTERVAL '1 hour', unlocked_at = NOW() WHERE id = $1")
+        .bind(junior_window)
+        .execute(&pool)
+        .await
+        .unwrap();
+    let resp = common::send(reqwest::Method::POST, addr, &junior_token, &submit(junior_window), bid(patrol)).await;
+    assert_eq!(resp.status(), 200);
+    let dispatcher_window = window_of(&pool, period_id, dispatcher).await.unwrap();
+    let (rank, round_name): (i32, String) = sqlx::query_as(
+        "SELECT bw.seniority_rank, r.name FROM bid_windows bw JOIN shift_bid_rounds r ON r.id = bw.round_id WHERE bw.id = $1",
+    )
+    .bind(dispatcher_window)
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert_eq!((rank, round_name.as_str()), (3, "Open"));
+
+    // Round 2 sees round 1's award as taken
+    let detail: serde_json::Value = common::http_client()
+        .get(format!("http://{}/api/bid-windows/{}", addr, dispatcher_window))
+        .header("Authorization", format!("Bearer {}", dispatcher_token))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    let patrol_slot = detail["available_slots"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|s| s["slot_id"] == serde_json::json!(patrol))
+        .unwrap();
+    assert_eq!(patrol_slot["already_awarded"], true);
+
+    // Round 1 awards only its most senior bidder
+    let table: serde_json::Value = common::http_client()
+        .get(format!("http://{}/api/schedule/periods/{}/process-bids/preview", addr, period_id))
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(table["rows"][0]["award"]["slot_id"], serde_json::json!(patrol));
+    assert_eq!(table["rows"][1]["user_id"], serde_json::json!(junior));
+    assert_eq!(table["rows"][1]["reason"], "round_limit");
+
+    common::cleanup_test_org(&pool, org_id).await;
+}
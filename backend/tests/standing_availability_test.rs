@@ -0,0 +1,227 @@
+mod common;
+
+use sqlx::PgPool;
+use uuid::Uuid;
+
+fn unique_email(prefix: &str) -> String {
+    format!("{}+{}@test.local", prefix, &Uuid::new_v4().to_string()[..8])
+}
+
+/// First Tuesday at least two weeks out.
+fn future_tuesday() -> time::Date {
+    let mut d = time::OffsetDateTime::now_utc().date() + time::Duration::days(14);
+    while d.weekday() != time::Weekday::Tuesday {
+        d = d.next_day().unwrap();
+    }
+    d
+}
+
+async fn match_notifications(pool: &PgPool, user_id: Uuid) -> i64 {
+    let (count,): (i64,) = sqlx::query_as(
+        "SELECT COUNT(*) FROM notifications WHERE user_id = $1 AND notification_type = 'ot_availability_match'",
+    )
+    .bind(user_id)
+    .fetch_one(pool)
+    .await
+    .unwrap();
+    count
+}
+
+async fn post_ot_request(
+    addr: std::net::SocketAddr,
+    token: &str,
+    date: time::Date,
+    start: &str,
+    end: &str,
+    class_id: Uuid,
+) {
+    let resp = common::http_client()
+        .post(format!("http://{}/api/ot-requests", addr))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({
+            "date": date.to_string(),
+            "start_time": start,
+            "end_time": end,
+            "classification_id": class_id.to_string(),
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+}
+
+#[tokio::test]
+async fn matching_ot_requests_notify_standing_volunteers() {
+    let (addr, pool) = common::setup_test_app().await;
+    let org_id = common::create_test_org(&pool, "standing-notify").await;
+    let class_id = common::create_test_classification(&pool, org_id).await;
+
+    let sup_email = unique_email("standing-sup");
+    let (_sup_id, sup_pw) = common::create_test_user(&pool, org_id, "supervisor", &sup_email).await;
+    let sup_token = common::get_auth_token(addr, &sup_email, &sup_pw).await;
+
+    let emp_email = unique_email("standing-emp");
+    let (emp_id, emp_pw) = common::create_test_user_with_classification(
+        &pool, org_id, class_id, "employee", &emp_email,
+    )
+    .await;
+    let emp_token = common::get_auth_token(addr, &emp_email, &emp_pw).await;
+
+    let tuesday = future_tuesday();
+    let resp = common::http_client()
+        .post(format!("http://{}/api/ot/availability", addr))
+        .header("Authorization", format!("Bearer {}", emp_token))
+        .json(&serde_json::json!({
+            "start_date": tuesday.to_string(),
+            "end_date": (tuesday + time::Duration::days(30)).to_string(),
+            "days_of_week": [2, 4],
+            "start_time": "06:00:00",
+            "end_time": "18:00:00",
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let signup: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(signup["user_id"], emp_id.to_string());
+    assert_eq!(signup["days_of_week"], serde_json::json!([2, 4]));
+
+    // Inside the window on a Tuesday -> notified
+    post_ot_request(addr, &sup_token, tuesday, "08:00:00", "10:00:00", class_id).await;
+    assert_eq!(match_notifications(&pool, emp_id).await, 1);
+
+    // Runs past the window's end -> not notified
+    post_ot_request(addr, &sup_token, tuesday, "17:00:00", "20:00:00", class_id).await;
+    // Wrong weekday -> not notified
+    let wednesday = tuesday.next_day().unwrap();
+    post_ot_request(addr, &sup_token, wednesday, "08:00:00", "10:00:00", class_id).await;
+    assert_eq!(match_notifications(&pool, emp_id).await, 1);
+
+    // Cancelled sign-ups stop matching
+    let resp = common::http_client()
+        .patch(format!(
+            "http://{}/api/ot/availability/{}/cancel",
+            addr,
+            signup["id"].as_str().unwrap()
+        ))
+        .header("Authorization", format!("Bearer {}", emp_token))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    post_ot_request(addr, &sup_token, tuesday, "12:00:00", "14:00:00", class_id).await;
+    assert_eq!(match_notifications(&pool, emp_id).await, 1);
+
+    common::cleanup_test_org(&pool, org_id).await;
+}
+
+#[tokio::test]
+async fn standing_volunteers_surface_first_in_callout() {
+    let (addr, pool) = common::setup_test_app().await;
+    let org_id = common::create_test_org(&pool, "standing-callout").await;
+    let class_id = common::create_test_classification(&pool, org_id).await;
+
+    let sup_email = unique_email("standing-sup");
+    let (sup_id, sup_pw) = common::create_test_user(&pool, org_id, "supervisor", &sup_email).await;
+    let sup_token = common::get_auth_token(addr, &sup_email, &sup_pw).await;
+
+    let mut employees = Vec::new();
+    for _ in 0..3 {
+        let (id, _) = common::create_test_user_with_classification(
+            &pool,
+            org_id,
+            class_id,
+            "employee",
+            &unique_email("standing-emp"),
+        )
+        .await;
+        employees.push(id);
+    }
+
+    let date = future_tuesday();
+    let template_id = common::create_test_shift_template(&pool, org_id).await;
+    let shift_id = common::create_test_scheduled_shift(&pool, org_id, template_id, date).await;
+    let event_id = common::create_test_callout_event(&pool, shift_id, sup_id, class_id).await;
+
+    // Supervisor signs the last employee up for the specific date (07:00-19:00 day shift)
+    let standing_user = employees[2];
+    let resp = common::http_client()
+        .post(format!("http://{}/api/ot/availability", addr))
+        .header("Authorization", format!("Bearer {}", sup_token))
+        .json(&serde_json::json!({
+            "user_id": standing_user,
+            "start_date": date.to_string(),
+            "end_date": date.to_string(),
+            "start_time": "06:00:00",
+            "end_time": "20:00:00",
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+
+    let resp = common::http_client()
+        .get(format!("http://{}/api/callout/events/{}/queue", addr, event_id))
+        .header("Authorization", format!("Bearer {}", sup_token))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let queue: Vec<serde_json::Value> = resp.json().await.unwrap();
+    assert_eq!(queue[0]["user_id"], standing_user.to_string());
+    assert_eq!(queue[0]["has_standing_availability"], true);
+    assert!(queue[1..].iter().all(|e| e["has_standing_availability"] == false));
+
+    let resp = common::http_client()
+        .get(format!("http://{}/api/callout/events/{}/volunteers", addr, event_id))
+        .header("Authorization", format!("Bearer {}", sup_token))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let volunteers: Vec<serde_json::Value> = resp.json().await.unwrap();
+    assert_eq!(volunteers.len(), 1);
+    assert_eq!(volunteers[0]["user_id"], standing_user.to_string());
+    assert_eq!(volunteers[0]["is_standing"], true);
+
+    common::cleanup_test_org(&pool, org_id).await;
+}
+
+#[tokio::test]
+async fn availability_validation_and_permissions() {
+    let (addr, pool) = common::setup_test_app().await;
+    let org_id = common::create_test_org(&pool, "standing-validate").await;
+    let email = unique_email("standing-emp");
+    let (_uid, pw) = common::create_test_user(&pool, org_id, "employee", &email).await;
+    let token = common::get_auth_token(addr, &email, &pw).await;
+    let (other_id, _) =
+        common::create_test_user(&pool, org_id, "employee", &unique_email("standing-other")).await;
+    let date = future_tuesday().to_string();
+    let client = common::http_client();
+
+    // Half a time window
+    let resp = client
+        .post(format!("http://{}/api/ot/availability", addr))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({
+            "start_date": date, "end_date": date, "start_time": "06:00:00",
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 400);
+
+    // Employees cannot sign up someone else
+    let resp = client
+        .post(format!("http://{}/api/ot/availability", addr))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({
+            "user_id": other_id, "start_date": date, "end_date": date,
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 403);
+
+    common::cleanup_test_org(&pool, org_id).await;
+}
@@ -0,0 +1,288 @@
+mod common;
+
+use std::net::SocketAddr;
+
+use serde_json::json;
+use sqlx::PgPool;
+use time::{Duration, OffsetDateTime};
+use uuid::Uuid;
+
+fn unique_email(prefix: &str) -> String {
+    format!("{}+{}@test.local", prefix, &Uuid::new_v4().to_string()[..8])
+}
+
+async fn submit(addr: SocketAddr, token: &str, window_id: Uuid, picks: serde_json::Value) -> reqwest::Response {
+    let path = format!("/api/vacation-bids/bid-windows/{window_id}/submit");
+    common::post_json(addr, token, &path, json!({ "picks": picks })).await
+}
+
+/// An open round 1 vacation bid period for 2027 with a 40 hour allowance and
+/// two bidders holding 100 hour balances: `tenner` works a 4/10 rotation
+/// (Monday to Thursday, 10 hours) and `flat` has no schedule at all.
+struct VacationPeriod {
+    addr: SocketAddr,
+    pool: PgPool,
+    org_id: Uuid,
+    period_id: Uuid,
+    admin_token: String,
+    tenner: Uuid,
+    tenner_token: String,
+    flat: Uuid,
+    flat_token: String,
+    windows: Vec<Uuid>,
+}
+
+async fn vacation_period(name: &str) -> VacationPeriod {
+    let (addr, pool) = common::setup_test_app().await;
+    let org_id = common::create_test_org(&pool, name).await;
+    let admin_email = unique_email("vac-hours-admin");
+    let (_admin, admin_pw) = common::create_test_user(&pool, org_id, "admin", &admin_email).await;
+    let admin_token = common::get_auth_token(addr, &admin_email, &admin_pw).await;
+    let tenner_email = unique_email("vac-hours-4x10");
+    let (tenner, tenner_pw) = common::create_test_user(&pool, org_id, "employee", &tenner_email).await;
+    let tenner_token = common::get_auth_token(addr, &tenner_email, &tenner_pw).await;
+    let flat_email = unique_email("vac-hours-flat");
+    let (flat, flat_pw) = common::create_test_user(&pool, org_id, "employee", &flat_email).await;
+    let flat_token = common::get_auth_token(addr, &flat_email, &flat_pw).await;
+
+    // 4/10 rotation: Monday to Thursday on a 10 hour slot, Friday to Sunday off
+    let template = Uuid::new_v4();
+    sqlx::query(
+        "INSERT INTO shift_templates (id, org_id, name, start_time, end_time, duration_minutes) VALUES ($1, $2, 'Ten', '07:00:00', '17:00:00', 600)",
+    )
+    .bind(template)
+    .bind(org_id)
+    .execute(&pool)
+    .await
+    .unwrap();
+    let team = common::create_test_team(&pool, org_id, "Ten hour team").await;
+    let class_id = common::create_test_classification(&pool, org_id).await;
+    let slot = Uuid::new_v4();
+    sqlx::query(
+        "INSERT INTO shift_slots (id, team_id, shift_template_id, classification_id, days_of_week) VALUES ($1, $2, $3, $4, '{0,1,2,3,4,5,6}')",
+    )
+    .bind(slot)
+    .bind(team)
+    .bind(template)
+    .bind(class_id)
+    .execute(&pool)
+    .await
+    .unwrap();
+    let schedule_period = Uuid::new_v4();
+    sqlx::query(
+        "INSERT INTO schedule_periods (id, org_id, name, start_date, end_date) VALUES ($1, $2, '2027', '2027-01-01', '2027-12-31')",
+    )
+    .bind(schedule_period)
+    .bind(org_id)
+    .execute(&pool)
+    .await
+    .unwrap();
+    sqlx::query("INSERT INTO slot_assignments (slot_id, user_id, period_id) VALUES ($1, $2, $3)")
+        .bind(slot)
+        .bind(tenner)
+        .bind(schedule_period)
+        .execute(&pool)
+        .await
+        .unwrap();
+    let pattern = Uuid::new_v4();
+    sqlx::query(
+        "INSERT INTO shift_patterns (id, org_id, name, pattern_days, work_days, off_days, anchor_date) VALUES ($1, $2, '4/10', 7, 4, 3, '2027-01-04')",
+    )
+    .bind(pattern)
+    .bind(org_id)
+    .execute(&pool)
+    .await
+    .unwrap();
+    sqlx::query(
+        "INSERT INTO shift_pattern_assignments (org_id, user_id, pattern_id, effective_from) VALUES ($1, $2, $3, '2027-01-01')",
+    )
+    .bind(org_id)
+    .bind(tenner)
+    .bind(pattern)
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let leave_type = common::create_test_leave_type(&pool, org_id, "vac", "Vacation").await;
+    sqlx::query("UPDATE leave_types SET category = 'vacation' WHERE id = $1")
+        .bind(leave_type)
+        .execute(&pool)
+        .await
+        .unwrap();
+    for user in [tenner, flat] {
+        sqlx::query(
+            "INSERT INTO leave_balances (id, org_id, user_id, leave_type_id, balance_hours, as_of_date) VALUES ($1, $2, $3, $4, 100, CURRENT_DATE)",
+        )
+        .bind(Uuid::new_v4())
+        .bind(org_id)
+        .bind(user)
+        .bind(leave_type)
+        .execute(&pool)
+        .await
+        .unwrap();
+    }
+
+    let period_id = Uuid::new_v4();
+    sqlx::query(
+        "INSERT INTO vacation_bid_periods (id, org_id, year, round, status, allowance_hours) VALUES ($1, $2, 2027, 1, 'open', 40)",
+    )
+    .bind(period_id)
+    .bind(org_id)
+    .execute(&pool)
+    .await
+    .unwrap();
+    let opens = OffsetDateTime::now_utc() - Duration::hours(1);
+    let mut windows = Vec::new();
+    for (rank, user) in [tenner, flat].into_iter().enumerate() {
+        let window_id = Uuid::new_v4();
+        sqlx::query(
+            "INSERT INTO vacation_bid_windows (id, vacation_bid_period_id, user_id, seniority_rank, opens_at, closes_at) VALUES ($1, $2, $3, $4, $5, $6)",
+        )
+        .bind(window_id)
+        .bind(period_id)
+        .bind(user)
+        .bind(rank as i32 + 1)
+        .bind(opens)
+        .bind(opens + Duration::days(1))
+        .execute(&pool)
+        .await
+        .unwrap();
+        windows.push(window_id);
+    }
+
+
+    VacationPeriod {
+        addr,
+        pool,
+        org_id,
+        period_id,
+        admin_token,
+        tenner,
+        tenner_token,
+        flat,
+        flat_token,
+        windows,
+    }
+}
+
+async fn balance(pool: &PgPool, user_id: Uuid) -> f64 {
+    sqlx::query_scalar("SELECT balance_hours::FLOAT8 FROM leave_balances WHERE user_id = $1")
+        .bind(user_id)
+        .fetch_one(pool)
+        .await
+        .unwrap()
+}
+
+async fn process(p: &VacationPeriod) {
+    let path = format!("/api/vacation-bids/periods/{}/process-bids", p.period_id);
+    assert_eq!(common::post_json(p.addr, &p.admin_token, &path, json!({})).await.status(), 200);
+}
+
+#[tokio::test]
+async fn vacation_hours_follow_the_employees_schedule() {
+    let p = vacation_period("vac-hours").await;
+    let addr = p.addr;
+
+    let week = json!([{ "start_date": "2027-07-05", "end_date": "2027-07-11", "preference_rank": 1 }]);
+
+    // The 4/10 employee is charged 40 hours for the week, within the allowance
+    let resp = submit(addr, &p.tenner_token, p.windows[0], week.clone()).await;
+    assert_eq!(resp.status(), 200);
+    let detail = common::get_json(addr, &p.tenner_token, &format!("/api/vacation-bids/bid-windows/{}", p.windows[0])).await;
+    assert_eq!(detail["hours_used"], 40.0);
+
+    // Without a schedule the flat 8 hours a day applies: 56 hours is over the allowance
+    let resp = submit(addr, &p.flat_token, p.windows[1], week).await;
+    assert_eq!(resp.status(), 400);
+
+    // A pick covering only scheduled days off is refused
+    sqlx::query("UPDATE vacation_bid_periods SET round = 2 WHERE id = $1")
+        .bind(p.period_id)
+        .execute(&p.pool)
+        .await
+        .unwrap();
+    let days_off = json!([{ "start_date": "2027-07-16", "end_date": "2027-07-18", "preference_rank": 1 }]);
+    let resp = submit(addr, &p.tenner_token, p.windows[0], days_off).await;
+    assert_eq!(resp.status(), 400);
+
+    // Processing charges the leave request and balance the same scheduled hours
+    process(&p).await;
+    let charged: f64 = sqlx::query_scalar("SELECT hours::FLOAT8 FROM leave_requests WHERE user_id = $1")
+        .bind(p.tenner)
+        .fetch_one(&p.pool)
+        .await
+        .unwrap();
+    assert_eq!(charged, 40.0);
+    assert_eq!(balance(&p.pool, p.tenner).await, 60.0);
+
+    common::cleanup_test_org(&p.pool, p.org_id).await;
+}
+
+#[tokio::test]
+async fn unscheduled_days_charge_the_configured_hours_per_day() {
+    let p = vacation_period("vac-hours-default").await;
+    let addr = p.addr;
+    // Later rounds take picks of any length
+    sqlx::query("UPDATE vacation_bid_periods SET round = 2 WHERE id = $1")
+        .bind(p.period_id)
+        .execute(&p.pool)
+        .await
+        .unwrap();
+    let six_days = json!([{ "start_date": "2027-07-05", "end_date": "2027-07-10", "preference_rank": 1 }]);
+
+    // At the default 8 hours a day, six days (48 hours) is over the allowance
+    let resp = submit(addr, &p.flat_token, p.windows[1], six_days.clone()).await;
+    assert_eq!(resp.status(), 400);
+    let body: serde_json::Value = resp.json().await.unwrap();
+    assert!(body["error"].as_str().unwrap().starts_with("Total vacation hours (48)"));
+
+    // At a configured 6 hours a day the same pick costs 36
+    common::set_org_setting(&p.pool, p.org_id, "default_hours_per_vacation_day", json!("6")).await;
+    let resp = submit(addr, &p.flat_token, p.windows[1], six_days).await;
+    assert_eq!(resp.status(), 200);
+    let detail = common::get_json(addr, &p.flat_token, &format!("/api/vacation-bids/bid-windows/{}", p.windows[1])).await;
+    assert_eq!(detail["hours_used"], 36.0);
+
+    process(&p).await;
+    let charged: f64 = sqlx::query_scalar("SELECT hours::FLOAT8 FROM leave_requests WHERE user_id = $1")
+        .bind(p.flat)
+        .fetch_one(&p.pool)
+        .await
+        .unwrap();
+    assert_eq!(charged, 36.0);
+    assert_eq!(balance(&p.pool, p.flat).await, 64.0);
+
+    common::cleanup_test_org(&p.pool, p.org_id).await;
+}
+
+#[tokio::test]
+async fn rollback_refunds_the_schedule_derived_hours() {
+    let p = vacation_period("vac-hours-rollback").await;
+    let addr = p.addr;
+
+    let week = json!([{ "start_date": "2027-07-05", "end_date": "2027-07-11", "preference_rank": 1 }]);
+    assert_eq!(submit(addr, &p.tenner_token, p.windows[0], week).await.status(), 200);
+    process(&p).await;
+    assert_eq!(balance(&p.pool, p.tenner).await, 60.0);
+
+    // The refund is the 40 scheduled hours charged, not the 56 a flat rate would give
+    let path = format!("/api/vacation-bids/periods/{}/rollback-bids", p.period_id);
+    let resp = common::post_json(addr, &p.admin_token, &path, json!({})).await;
+    assert_eq!(resp.status(), 200);
+    let body: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(body["awards_rolled_back"], 1);
+    assert_eq!(body["hours_refunded"], 40.0);
+    assert_eq!(balance(&p.pool, p.tenner).await, 100.0);
+    let requests: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM leave_requests WHERE user_id = $1")
+        .bind(p.tenner)
+        .fetch_one(&p.pool)
+        .await
+        .unwrap();
+    assert_eq!(requests, 0);
+
+    // Reprocessing charges the same scheduled hours again
+    process(&p).await;
+    assert_eq!(balance(&p.pool, p.tenner).await, 60.0);
+
+    common::cleanup_test_org(&p.pool, p.org_id).await;
+}
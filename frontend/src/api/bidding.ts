@@ -18,6 +18,36 @@ export interface BidWindow {
   approved_by: string | null
   is_job_share: boolean
   auto_advanced_at: string | null
+  partner_user_id: string | null
+  partner_first_name: string | null
+  partner_last_name: string | null
+  round_id: string | null
+}
+
+export interface ShiftBidRound {
+  id: string
+  period_id: string
+  round_number: number
+  name: string
+  classification_ids: string[] | null
+  bargaining_units: string[] | null
+  slot_ids: string[] | null
+  window_duration_hours: number
+  flex_cap: number
+  max_awards: number | null
+  window_count: number
+  opened_at: string | null
+  closed_at: string | null
+}
+
+export interface ShiftBidRoundInput {
+  name: string
+  classification_ids?: string[] | null
+  bargaining_units?: string[] | null
+  slot_ids?: string[] | null
+  window_duration_hours: number
+  flex_cap?: number
+  max_awards?: number | null
 }
 
 export interface AvailableSlot {
@@ -41,6 +71,7 @@ export interface BidSubmissionView {
   team_name: string
   classification_name: string
   days_of_week: number[]
+  partner_days_of_week: number[] | null
   preference_rank: number
   awarded: boolean
 }
@@ -54,12 +85,169 @@ export interface BidWindowDetail {
 export interface BidPreference {
   slot_id: string
   preference_rank: number
+  /** Joint job-share windows only: the slot's days the partner works */
+  partner_days?: number[]
+}
+
+export type BidWindowState = 'upcoming' | 'waiting' | 'open' | 'submitted' | 'approved' | 'skipped'
+
+export interface BidStatusWindow {
+  window_id: string
+  user_id: string
+  first_name: string
+  last_name: string
+  seniority_rank: number
+  opens_at: string
+  closes_at: string
+  state: BidWindowState
+}
+
+export interface BidStatusSummary {
+  period_id: string
+  status: string
+  total_windows: number
+  counts: Record<BidWindowState, number>
+  current: BidStatusWindow | null
+  next: BidStatusWindow | null
+  expected_finish_at: string | null
+  as_of: string
+}
+
+export type ProxySubmitOn = 'open' | 'expiry'
+
+export interface ShiftProxyPlan {
+  id: string
+  period_id: string
+  user_id: string
+  submit_on: ProxySubmitOn
+  proxy_user_id: string | null
+  proxy_first_name: string | null
+  proxy_last_name: string | null
+  preferences: BidPreference[]
+  updated_at: string
+}
+
+export type BidProxyTrigger = 'window_open' | 'window_expired' | 'proxy'
+
+export interface BidProxyAction {
+  id: string
+  user_id: string
+  first_name: string
+  last_name: string
+  actor_id: string | null
+  actor_first_name: string | null
+  actor_last_name: string | null
+  window_id: string
+  trigger: BidProxyTrigger
+  submitted: unknown[]
+  unavailable: unknown[]
+  created_at: string
+}
+
+export type BidSkipReason =
+  | 'bargaining_unit'
+  | 'no_submission'
+  | 'taken'
+  | 'flex_cap'
+  | 'date_conflict'
+  | 'insufficient_balance'
+  | 'partner_off'
+  | 'round_limit'
+  | 'holiday_rotation'
+
+export interface ShiftAwardRow {
+  window_id: string
+  user_id: string
+  first_name: string
+  last_name: string
+  seniority_rank: number
+  partner_user_id: string | null
+  award: {
+    slot_id: string
+    slot_name: string
+    preference_rank: number
+    days_of_week: number[] | null
+    partner_days_of_week: number[] | null
+  } | null
+  skipped: { slot_id: string; slot_name: string; preference_rank: number; reason: BidSkipReason }[]
+  reason: BidSkipReason | null
+}
+
+export interface ShiftAwardTable {
+  period_id: string
+  total_bidders: number
+  awards_count: number
+  rows: ShiftAwardRow[]
+}
+
+export interface BidProcessingRun {
+  id: string
+  action: 'process' | 'rollback'
+  run_by: string
+  run_by_first_name: string
+  run_by_last_name: string
+  awards_count: number
+  reason: string | null
+  detail: Record<string, unknown>
+  created_at: string
+}
+
+export interface BidResultsPreference {
+  preference_rank: number
+  slot_id: string
+  slot_name: string
+  partner_days_of_week: number[] | null
+  awarded: boolean
+  skip_reason: BidSkipReason | null
+}
+
+export interface BidResultsRow {
+  window_id: string
+  seniority_rank: number
+  user_id: string
+  first_name: string
+  last_name: string
+  partner_user_id: string | null
+  partner_first_name: string | null
+  partner_last_name: string | null
+  opens_at: string
+  closes_at: string
+  submitted_at: string | null
+  auto_advanced_at: string | null
+  approved_at: string | null
+  approved_by: string | null
+  approved_by_first_name: string | null
+  approved_by_last_name: string | null
+  preferences: BidResultsPreference[]
+  award: ShiftAwardRow['award']
+  reason: BidSkipReason | null
+}
+
+export interface BidResultsReport {
+  period_id: string
+  period_name: string
+  start_date: string
+  end_date: string
+  status: BidPeriodStatus
+  processed_at: string | null
+  processed_by_first_name: string | null
+  processed_by_last_name: string | null
+  published_at: string | null
+  published_by_first_name: string | null
+  published_by_last_name: string | null
+  rows: BidResultsRow[]
 }
 
 export const biddingApi = {
   openBidding: (periodId: string, body: { window_duration_hours: number; start_at?: string }) =>
     apiClient.post<BidWindow[]>(`/api/schedule/periods/${periodId}/open-bidding`, body),
 
+  getBidRounds: (periodId: string) =>
+    apiClient.get<ShiftBidRound[]>(`/api/schedule/periods/${periodId}/bid-rounds`),
+
+  saveBidRounds: (periodId: string, rounds: ShiftBidRoundInput[]) =>
+    apiClient.put<ShiftBidRound[]>(`/api/schedule/periods/${periodId}/bid-rounds`, { rounds }),
+
   listBidWindows: (periodId: string) =>
     apiClient.get<BidWindow[]>(`/api/schedule/periods/${periodId}/bid-windows`),
 
@@ -69,8 +257,50 @@ export const biddingApi = {
   submitBid: (windowId: string, body: { preferences: BidPreference[] }) =>
     apiClient.post(`/api/bid-windows/${windowId}/submit`, body),
 
+  getBidStatus: (periodId: string) =>
+    apiClient.get<BidStatusSummary>(`/api/schedule/periods/${periodId}/bid-status`),
+
+  getProxyPlan: (periodId: string) =>
+    apiClient.get<ShiftProxyPlan>(`/api/schedule/periods/${periodId}/proxy-plan`),
+
+  saveProxyPlan: (
+    periodId: string,
+    body: { submit_on?: ProxySubmitOn; proxy_user_id?: string | null; preferences?: BidPreference[] },
+  ) =>
+    apiClient.put<ShiftProxyPlan>(`/api/schedule/periods/${periodId}/proxy-plan`, body),
+
+  deleteProxyPlan: (periodId: string) =>
+    apiClient.delete(`/api/schedule/periods/${periodId}/proxy-plan`),
+
+  listProxyActions: (periodId: string) =>
+    apiClient.get<BidProxyAction[]>(`/api/schedule/periods/${periodId}/proxy-actions`),
+
   processBids: (periodId: string) =>
-    apiClient.post<{ awards_count: number; total_bidders: number }>(`/api/schedule/periods/${periodId}/process-bids`),
+    apiClient.post<{ run_id: string; awards_count: number; total_bidders: number }>(`/api/schedule/periods/${periodId}/process-bids`),
+
+  previewBids: (periodId: string) =>
+    apiClient.get<ShiftAwardTable>(`/api/schedule/periods/${periodId}/process-bids/preview`),
+
+  rollbackBids: (periodId: string, body: { reason?: string } = {}) =>
+    apiClient.post<{ run_id: string; awards_rolled_back: number }>(
+      `/api/schedule/periods/${periodId}/rollback-bids`,
+      body,
+    ),
+
+  listBidRuns: (periodId: string) =>
+    apiClient.get<BidProcessingRun[]>(`/api/schedule/periods/${periodId}/bid-runs`),
+
+  getBidResults: (periodId: string) =>
+    apiClient.get<BidResultsReport>(`/api/schedule/periods/${periodId}/bid-results`),
+
+  downloadBidResults: (periodId: string, format: 'csv' | 'pdf') =>
+    apiClient.get<Blob>(`/api/schedule/periods/${periodId}/bid-results`, {
+      params: { format },
+      responseType: 'blob',
+    }),
+
+  publishBidResults: (periodId: string) =>
+    apiClient.post<{ published_at: string }>(`/api/schedule/periods/${periodId}/bid-results/publish`),
 
   approveBidWindow: (windowId: string) =>
     apiClient.post(`/api/bid-windows/${windowId}/approve`),
@@ -0,0 +1,39 @@
+// frontend/src/api/callVolume.ts
+import { apiClient } from './client'
+
+export interface CallVolumeRow {
+  date: string
+  slot_index: number
+  calls: number
+}
+
+export interface ImportCallVolumeResult {
+  dates_imported: number
+  slots_imported: number
+  total_calls: number
+}
+
+export interface ForecastSlot {
+  slot_index: number
+  calls: number
+}
+
+export interface CallForecastDay {
+  date: string
+  is_holiday: boolean
+  holiday_factor: number
+  sample_days: number
+  total_calls: number
+  slots: ForecastSlot[]
+}
+
+export const callVolumeApi = {
+  list: (params: { start_date: string; end_date: string }) =>
+    apiClient.get<CallVolumeRow[]>('/api/call-volume', { params }),
+
+  import: (csv: string) =>
+    apiClient.post<ImportCallVolumeResult>('/api/call-volume/import', { csv }),
+
+  forecast: (params: { start_date: string; end_date: string }) =>
+    apiClient.get<CallForecastDay[]>('/api/call-volume/forecast', { params }),
+}
@@ -37,6 +37,9 @@ export interface CalloutListEntry {
   is_available: boolean
   unavailable_reason: string | null
   is_cross_class: boolean
+  has_standing_availability: boolean
+  /** Set only when the list was built for a qualification gap */
+  has_qualification: boolean | null
 }
 
 export interface CalloutAttempt {
@@ -59,11 +62,27 @@ export interface BumpRequest {
   displaced_user_id: string
   displaced_user_first_name: string
   displaced_user_last_name: string
-  status: 'pending' | 'approved' | 'denied'
+  status: 'pending' | 'approved' | 'denied' | 'expired'
   reason: string | null
   created_at: string
   reviewed_at?: string
   reviewed_by?: string
+  expires_at?: string
+  auto_resolved: boolean
+}
+
+export type BumpAuditAction = 'submitted' | 'approved' | 'denied' | 'auto_approved' | 'expired'
+
+export interface BumpAuditEntry {
+  id: string
+  bump_request_id: string
+  action: BumpAuditAction
+  actor_id: string | null
+  actor_name: string | null
+  requesting_user_name: string
+  displaced_user_name: string
+  notes: string | null
+  created_at: string
 }
 
 export interface CreateBumpRequestPayload {
@@ -76,6 +95,67 @@ export interface ReviewBumpRequestPayload {
   reason?: string
 }
 
+export type CalloutSortField =
+  | 'ot_hours'
+  | 'ot_hours_declined'
+  | 'last_contact'
+  | 'overall_seniority'
+  | 'bargaining_unit_seniority'
+  | 'classification_seniority'
+  | 'mandatory_rotation'
+  | 'standing_availability'
+  | 'volunteered'
+
+export interface CalloutSortKey {
+  field: CalloutSortField
+  direction?: 'asc' | 'desc'
+}
+
+export interface CalloutStepFilters {
+  volunteers_only?: boolean
+  primary_classification_only?: boolean
+  max_ot_hours?: number
+  hide_unavailable?: boolean
+}
+
+export interface CalloutPolicyStep {
+  step: CalloutStep
+  label?: string
+  sort?: CalloutSortKey[]
+  filters?: CalloutStepFilters
+}
+
+export interface CalloutPolicy {
+  id: string
+  org_id: string
+  bargaining_unit: string | null
+  name: string
+  steps: CalloutPolicyStep[]
+  created_at: string
+  updated_at: string
+}
+
+export interface EffectiveCalloutPolicy {
+  policy_id: string | null
+  bargaining_unit: string | null
+  name: string
+  steps: CalloutPolicyStep[]
+}
+
+export const calloutPoliciesApi = {
+  list: () => apiClient.get<CalloutPolicy[]>('/api/callout-policies'),
+
+  upsert: (body: { bargaining_unit?: string; name: string; steps: CalloutPolicyStep[] }) =>
+    apiClient.put<CalloutPolicy>('/api/callout-policies', body),
+
+  delete: (id: string) => apiClient.delete(`/api/callout-policies/${id}`),
+
+  effective: (classificationId: string) =>
+    apiClient.get<EffectiveCalloutPolicy>('/api/callout-policies/effective', {
+      params: { classification_id: classificationId },
+    }),
+}
+
 export const calloutApi = {
   listEvents: (params?: { limit?: number; offset?: number }) =>
     apiClient.get<CalloutEvent[]>('/api/callout/events', { params }),
@@ -111,4 +191,12 @@ export const calloutApi = {
 
   reviewBumpRequest: (requestId: string, payload: ReviewBumpRequestPayload) =>
     apiClient.patch<BumpRequest>(`/api/callout/bump-requests/${requestId}/review`, payload),
+
+  listBumpAudit: (eventId: string) =>
+    apiClient.get<BumpAuditEntry[]>(`/api/callout/events/${eventId}/bump-audit`),
+
+  resolveExpiredBumps: () =>
+    apiClient.post<{ expired: number; auto_approved: number }>(
+      '/api/callout/bump-requests/resolve-expired',
+    ),
 }
@@ -8,6 +8,7 @@ export interface Classification {
   abbreviation: string
   display_order: number
   is_active: boolean
+  bargaining_unit: string | null
   created_at: string
 }
 
@@ -15,9 +16,9 @@ export const classificationsApi = {
   list: (params?: { include_inactive?: boolean }) =>
     apiClient.get<Classification[]>('/api/classifications', { params }),
 
-  create: (body: { name: string; abbreviation: string; display_order?: number }) =>
+  create: (body: { name: string; abbreviation: string; display_order?: number; bargaining_unit?: string }) =>
     apiClient.post<Classification>('/api/classifications', body),
 
-  update: (id: string, body: { name?: string; abbreviation?: string; display_order?: number; is_active?: boolean }) =>
+  update: (id: string, body: { name?: string; abbreviation?: string; display_order?: number; is_active?: boolean; bargaining_unit?: string | null }) =>
     apiClient.patch<Classification>(`/api/classifications/${id}`, body),
 }
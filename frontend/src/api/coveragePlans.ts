@@ -29,6 +29,23 @@ export interface CoveragePlanSlot {
   max_headcount: number
 }
 
+export interface CoveragePlanQualificationSlot {
+  id: string
+  plan_id: string
+  qualification_id: string
+  qualification_name: string
+  day_of_week: number
+  slot_index: number
+  min_count: number
+}
+
+export interface QualificationSlotEntry {
+  qualification_id: string
+  day_of_week: number
+  slot_index: number
+  min_count: number
+}
+
 export interface SlotEntry {
   classification_id: string
   day_of_week: number
@@ -38,6 +55,44 @@ export interface SlotEntry {
   max_headcount: number
 }
 
+export interface CoverageDateOverride {
+  id: string
+  org_id: string
+  date: string
+  classification_id: string
+  classification_abbreviation: string
+  start_time: string
+  end_time: string
+  mode: 'add' | 'set'
+  min_headcount: number
+  target_headcount: number
+  max_headcount: number | null
+  notes: string | null
+  created_by: string
+  created_at: string
+}
+
+export interface HolidayCoverageProfile {
+  id: string
+  org_id: string
+  name: string
+  description: string | null
+  is_default: boolean
+  created_by: string
+  created_at: string
+  updated_at: string
+}
+
+export interface HolidayCoverageProfileSlot {
+  id: string
+  profile_id: string
+  classification_id: string
+  slot_index: number
+  min_headcount: number
+  target_headcount: number
+  max_headcount: number
+}
+
 export interface CoveragePlanAssignment {
   id: string
   org_id: string
@@ -49,6 +104,8 @@ export interface CoveragePlanAssignment {
   created_at: string
 }
 
+export type CoverageSource = 'plan' | 'holiday_profile' | 'override'
+
 export interface SlotCoverage {
   slot_index: number
   classification_id: string
@@ -58,6 +115,7 @@ export interface SlotCoverage {
   max_headcount: number
   actual_headcount: number
   status: 'green' | 'yellow' | 'red'
+  source: CoverageSource
 }
 
 export interface ClassificationGap {
@@ -85,6 +143,33 @@ export interface ClassificationGapBlocks {
   blocks: CoverageGapBlock[]
 }
 
+export interface QualificationGapBlocks {
+  qualification_id: string
+  qualification_name: string
+  blocks: CoverageGapBlock[]
+}
+
+// -- What-if simulation --
+
+export interface SimulateCoverageRequest {
+  remove_users?: { user_id: string; start_date: string; end_date: string }[]
+  swap_shifts?: { user_id: string; date: string; shift_template_id: string }[]
+  add_shifts?: { user_id: string; date: string; shift_template_id: string; classification_id?: string }[]
+}
+
+export interface SimulatedDay {
+  date: string
+  before: ClassificationGapBlocks[]
+  after: ClassificationGapBlocks[]
+  before_shortage: number
+  after_shortage: number
+}
+
+export interface SimulateCoverageResponse {
+  worsens_coverage: boolean
+  days: SimulatedDay[]
+}
+
 // -- Day Grid types --
 
 export interface BlockEmployee {
@@ -106,6 +191,7 @@ export interface ClassificationBlock {
   target: number
   actual: number
   status: 'green' | 'yellow' | 'red'
+  source: CoverageSource | null
   employees: BlockEmployee[]
 }
 
@@ -115,17 +201,35 @@ export interface DayGridClassification {
   blocks: ClassificationBlock[]
 }
 
+export interface QualificationBlock {
+  block_index: number
+  start_time: string
+  end_time: string
+  min: number
+  actual: number
+  status: 'green' | 'red'
+  employees: BlockEmployee[]
+}
+
+export interface DayGridQualification {
+  qualification_id: string
+  name: string
+  blocks: QualificationBlock[]
+}
+
 export interface CoverageBlock {
   block_index: number
   total_target: number
   total_actual: number
   status: 'green' | 'yellow' | 'red'
+  forecast_calls: number | null
 }
 
 export interface DayGridResponse {
   date: string
   classifications: DayGridClassification[]
   blocks: CoverageBlock[]
+  qualifications: DayGridQualification[]
 }
 
 export interface SmsAlertResult {
@@ -155,6 +259,21 @@ export const coveragePlansApi = {
   bulkUpsertSlots: (planId: string, slots: SlotEntry[]) =>
     apiClient.post<CoveragePlanSlot[]>(`/api/coverage-plans/${planId}/slots/bulk`, { slots }),
 
+  applyStaffing: (
+    planId: string,
+    body: {
+      classification_id: string
+      days_of_week: number[]
+      slots: { slot_index: number; min_headcount: number; target_headcount: number }[]
+    },
+  ) => apiClient.post<CoveragePlanSlot[]>(`/api/coverage-plans/${planId}/slots/apply-staffing`, body),
+
+  listQualificationSlots: (planId: string, params?: { qualification_id?: string; day_of_week?: number }) =>
+    apiClient.get<CoveragePlanQualificationSlot[]>(`/api/coverage-plans/${planId}/qualification-slots`, { params }),
+
+  bulkUpsertQualificationSlots: (planId: string, slots: QualificationSlotEntry[]) =>
+    apiClient.post<CoveragePlanQualificationSlot[]>(`/api/coverage-plans/${planId}/qualification-slots/bulk`, { slots }),
+
   listAssignments: () =>
     apiClient.get<CoveragePlanAssignment[]>('/api/coverage-plans/assignments'),
 
@@ -173,9 +292,51 @@ export const coveragePlansApi = {
   getGapBlocks: (date: string) =>
     apiClient.get<ClassificationGapBlocks[]>(`/api/coverage-plans/gaps/${date}/blocks`),
 
+  getQualificationGapBlocks: (date: string) =>
+    apiClient.get<QualificationGapBlocks[]>(`/api/coverage-plans/gaps/${date}/qualifications`),
+
+  simulate: (body: SimulateCoverageRequest) =>
+    apiClient.post<SimulateCoverageResponse>('/api/coverage-plans/simulate', body),
+
   sendSmsAlert: (date: string, body?: { classification_id?: string }) =>
     apiClient.post<SmsAlertResult>(`/api/coverage-plans/gaps/${date}/sms-alert`, body ?? {}),
 
   dayGrid: (date: string) =>
     apiClient.get<DayGridResponse>(`/api/coverage-plans/day-grid/${date}`),
+
+  listOverrides: (params: { start_date: string; end_date: string }) =>
+    apiClient.get<CoverageDateOverride[]>('/api/coverage-overrides', { params }),
+
+  createOverride: (body: {
+    date: string
+    classification_id: string
+    start_time: string
+    end_time: string
+    mode: 'add' | 'set'
+    min_headcount: number
+    target_headcount: number
+    max_headcount?: number | null
+    notes?: string | null
+  }) => apiClient.post<CoverageDateOverride>('/api/coverage-overrides', body),
+
+  deleteOverride: (id: string) =>
+    apiClient.delete(`/api/coverage-overrides/${id}`),
+
+  listHolidayProfiles: () =>
+    apiClient.get<HolidayCoverageProfile[]>('/api/holiday-coverage-profiles'),
+
+  createHolidayProfile: (body: { name: string; description?: string; is_default?: boolean }) =>
+    apiClient.post<HolidayCoverageProfile>('/api/holiday-coverage-profiles', body),
+
+  updateHolidayProfile: (id: string, body: { name?: string; description?: string; is_default?: boolean }) =>
+    apiClient.patch<HolidayCoverageProfile>(`/api/holiday-coverage-profiles/${id}`, body),
+
+  deleteHolidayProfile: (id: string) =>
+    apiClient.delete(`/api/holiday-coverage-profiles/${id}`),
+
+  listHolidayProfileSlots: (id: string) =>
+    apiClient.get<HolidayCoverageProfileSlot[]>(`/api/holiday-coverage-profiles/${id}/slots`),
+
+  replaceHolidayProfileSlots: (id: string, slots: Omit<SlotEntry, 'day_of_week'>[]) =>
+    apiClient.post<HolidayCoverageProfileSlot[]>(`/api/holiday-coverage-profiles/${id}/slots/bulk`, { slots }),
 }
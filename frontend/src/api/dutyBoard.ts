@@ -22,10 +22,33 @@ export interface BoardAssignment {
   status: 'assigned' | 'ot_needed'
 }
 
+export type DutyBreakKind = 'break' | 'meal'
+
+export type BreakCoverage = 'relieved' | 'uncovered' | 'off_board'
+
+export interface DutyBreak {
+  id: string
+  user_id: string
+  first_name: string
+  last_name: string
+  date: string
+  block_index: number
+  start_time: string
+  end_time: string
+  kind: DutyBreakKind
+  duty_position_id: string | null
+  position_name: string | null
+  relief_user_id: string | null
+  relief_first_name: string | null
+  relief_last_name: string | null
+  coverage: BreakCoverage
+}
+
 export interface DutyBoardResponse {
   date: string
   positions: BoardPosition[]
   assignments: BoardAssignment[]
+  breaks: DutyBreak[]
 }
 
 export interface AvailableEmployee {
@@ -54,6 +77,7 @@ export interface Qualification {
   org_id: string
   name: string
   description: string | null
+  renewal_months: number | null
   created_at: string
 }
 
@@ -62,6 +86,9 @@ export interface UserQualificationView {
   qualification_id: string
   qualification_name: string
   granted_at: string
+  issued_on: string | null
+  expires_on: string | null
+  is_expired: boolean
 }
 
 export interface DutyPositionHours {
@@ -73,6 +100,26 @@ export interface DutyPositionHours {
   crosses_midnight: boolean
 }
 
+export interface AutoFillCell {
+  duty_position_id: string
+  position_name: string
+  block_index: number
+  user_id: string
+  first_name: string
+  last_name: string
+}
+
+export interface UnfilledCell {
+  duty_position_id: string
+  position_name: string
+  block_index: number
+}
+
+export interface AutoFillResponse {
+  assigned: AutoFillCell[]
+  unfilled: UnfilledCell[]
+}
+
 export const dutyBoardApi = {
   // Board aggregate endpoint
   getBoard: (date: string) =>
@@ -90,6 +137,31 @@ export const dutyBoardApi = {
   getAvailable: (date: string, params: { block_index: number; duty_position_id: string }) =>
     apiClient.get<AvailableEmployee[]>(`/api/duty-board/${date}/available`, { params }),
 
+  // Auto-fill the open cells for a day (dry_run previews without saving)
+  autoFill: (date: string, body: { overwrite?: boolean; dry_run?: boolean } = {}) =>
+    apiClient.post<AutoFillResponse>(`/api/duty-board/${date}/auto-fill`, body),
+
+  // Breaks and relief
+  listBreaks: (date: string) =>
+    apiClient.get<DutyBreak[]>(`/api/duty-board/${date}/breaks`),
+
+  createBreak: (date: string, body: {
+    user_id: string
+    start_time: string
+    end_time: string
+    kind?: DutyBreakKind
+    relief_user_id?: string
+  }) => apiClient.post<DutyBreak>(`/api/duty-board/${date}/breaks`, body),
+
+  updateBreak: (id: string, body: { kind?: DutyBreakKind; relief_user_id?: string | null }) =>
+    apiClient.patch<DutyBreak>(`/api/duty-board/breaks/${id}`, body),
+
+  deleteBreak: (id: string) =>
+    apiClient.delete(`/api/duty-board/breaks/${id}`),
+
+  planRelief: (date: string, body: { replace?: boolean; dry_run?: boolean } = {}) =>
+    apiClient.post<DutyBreak[]>(`/api/duty-board/${date}/breaks/plan-relief`, body),
+
   // Console hours report
   getConsoleHours: (params: { start_date: string; end_date: string }) =>
     apiClient.get<ConsoleHoursEntry[]>('/api/duty-board/console-hours', { params }),
@@ -98,10 +170,14 @@ export const dutyBoardApi = {
   listQualifications: () =>
     apiClient.get<Qualification[]>('/api/qualifications'),
 
-  createQualification: (body: { name: string; description?: string }) =>
+  createQualification: (body: { name: string; description?: string; renewal_months?: number }) =>
     apiClient.post<Qualification>('/api/qualifications', body),
 
-  updateQualification: (id: string, body: { name?: string; description?: string | null }) =>
+  updateQualification: (id: string, body: {
+    name?: string
+    description?: string | null
+    renewal_months?: number | null
+  }) =>
     apiClient.patch<Qualification>(`/api/qualifications/${id}`, body),
 
   deleteQualification: (id: string) =>
@@ -121,8 +197,13 @@ export const dutyBoardApi = {
   listUserQualifications: (userId: string) =>
     apiClient.get<UserQualificationView[]>(`/api/users/${userId}/qualifications`),
 
-  addUserQualification: (userId: string, qualificationId: string) =>
-    apiClient.post(`/api/users/${userId}/qualifications`, { qualification_id: qualificationId }),
+  // Granting a held qualification again records a recertification
+  addUserQualification: (
+    userId: string,
+    qualificationId: string,
+    dates: { issued_on?: string; expires_on?: string } = {},
+  ) =>
+    apiClient.post(`/api/users/${userId}/qualifications`, { qualification_id: qualificationId, ...dates }),
 
   removeUserQualification: (userId: string, qualificationId: string) =>
     apiClient.delete(`/api/users/${userId}/qualifications/${qualificationId}`),
@@ -0,0 +1,57 @@
+// frontend/src/api/gapIncidents.ts
+import { apiClient } from './client'
+
+export type GapIncidentStatus = 'open' | 'resolved'
+
+export interface GapIncident {
+  id: string
+  org_id: string
+  classification_id: string
+  classification_abbreviation: string
+  date: string
+  start_time: string
+  end_time: string
+  shortage: number
+  alert_level: 0 | 1 | 2
+  status: GapIncidentStatus
+  detected_at: string
+  last_alerted_at: string | null
+  escalated_at: string | null
+  acknowledged_by: string | null
+  acknowledged_at: string | null
+  resolved_at: string | null
+}
+
+export interface GapAlert {
+  id: string
+  alert_level: 1 | 2
+  channel: 'in_app' | 'sms'
+  shortage: number
+  recipient_count: number
+  sent_at: string
+}
+
+export interface GapIncidentDetail extends GapIncident {
+  alerts: GapAlert[]
+}
+
+export interface GapScanResult {
+  opened: number
+  worsened: number
+  escalated: number
+  resolved: number
+}
+
+export const gapIncidentsApi = {
+  list: (params?: { status?: GapIncidentStatus; date_from?: string; date_to?: string }) =>
+    apiClient.get<GapIncident[]>('/api/coverage-gap-incidents', { params }),
+
+  get: (id: string) =>
+    apiClient.get<GapIncidentDetail>(`/api/coverage-gap-incidents/${id}`),
+
+  acknowledge: (id: string) =>
+    apiClient.post<GapIncident>(`/api/coverage-gap-incidents/${id}/acknowledge`),
+
+  scan: () =>
+    apiClient.post<GapScanResult>('/api/coverage-gap-incidents/scan'),
+}
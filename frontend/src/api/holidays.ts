@@ -7,6 +7,37 @@ export interface Holiday {
   date: string
   name: string
   is_premium_pay: boolean
+  coverage_profile_id: string | null
+  created_at: string
+}
+
+export interface HolidayGroup {
+  id: string
+  org_id: string
+  name: string
+  lockout_years: number
+  restricted_through_round: number
+  holiday_ids: string[]
+  created_at: string
+}
+
+export interface HolidayGroupBody {
+  name: string
+  lockout_years?: number
+  restricted_through_round?: number
+  holiday_ids: string[]
+}
+
+export interface HolidayAward {
+  id: string
+  holiday_id: string
+  holiday_name: string
+  date: string
+  user_id: string
+  first_name: string
+  last_name: string
+  vacation_bid_id: string | null
+  recorded_by: string | null
   created_at: string
 }
 
@@ -14,12 +45,33 @@ export const holidaysApi = {
   list: (year?: number) =>
     apiClient.get<Holiday[]>('/api/holidays', { params: year ? { year } : undefined }),
 
-  create: (body: { date: string; name: string; is_premium_pay?: boolean }) =>
+  create: (body: { date: string; name: string; is_premium_pay?: boolean; coverage_profile_id?: string }) =>
     apiClient.post<Holiday>('/api/holidays', body),
 
-  update: (id: string, body: { name?: string; is_premium_pay?: boolean }) =>
+  update: (id: string, body: { name?: string; is_premium_pay?: boolean; coverage_profile_id?: string | null }) =>
     apiClient.patch<Holiday>(`/api/holidays/${id}`, body),
 
   delete: (id: string) =>
     apiClient.delete(`/api/holidays/${id}`),
+
+  listGroups: () =>
+    apiClient.get<HolidayGroup[]>('/api/holiday-groups'),
+
+  createGroup: (body: HolidayGroupBody) =>
+    apiClient.post<HolidayGroup>('/api/holiday-groups', body),
+
+  updateGroup: (id: string, body: HolidayGroupBody) =>
+    apiClient.put<HolidayGroup>(`/api/holiday-groups/${id}`, body),
+
+  deleteGroup: (id: string) =>
+    apiClient.delete(`/api/holiday-groups/${id}`),
+
+  listAwards: (params?: { year?: number; user_id?: string }) =>
+    apiClient.get<HolidayAward[]>('/api/holiday-awards', { params }),
+
+  createAward: (body: { holiday_id: string; user_id: string }) =>
+    apiClient.post<HolidayAward>('/api/holiday-awards', body),
+
+  deleteAward: (id: string) =>
+    apiClient.delete(`/api/holiday-awards/${id}`),
 }
@@ -0,0 +1,24 @@
+// frontend/src/api/jobShares.ts
+import { apiClient } from './client'
+
+export interface JobSharePair {
+  id: string
+  user_a_id: string
+  user_a_first_name: string
+  user_a_last_name: string
+  user_b_id: string
+  user_b_first_name: string
+  user_b_last_name: string
+  created_at: string
+}
+
+export const jobSharesApi = {
+  list: () =>
+    apiClient.get<JobSharePair[]>('/api/job-share-pairs'),
+
+  create: (body: { user_a_id: string; user_b_id: string }) =>
+    apiClient.post<JobSharePair>('/api/job-share-pairs', body),
+
+  delete: (id: string) =>
+    apiClient.delete(`/api/job-share-pairs/${id}`),
+}
@@ -36,6 +36,7 @@ export interface OtVolunteer {
   first_name: string
   last_name: string
   volunteered_at: string
+  is_standing: boolean
 }
 
 export const otApi = {
@@ -1,5 +1,6 @@
 // frontend/src/api/reports.ts
 import { apiClient } from './client'
+import type { HolidayGroup } from './holidays'
 
 export interface CoverageReport {
   date: string
@@ -55,6 +56,41 @@ export interface WorkSummaryReport {
   total_hours: number
 }
 
+export interface QualificationExpiryEntry {
+  team_id: string | null
+  team_name: string | null
+  user_id: string
+  first_name: string
+  last_name: string
+  qualification_id: string
+  qualification_name: string
+  issued_on: string | null
+  expires_on: string
+  days_remaining: number
+}
+
+export interface HolidayRotationEntry {
+  group_id: string
+  times_received: number
+  last_received_year: number | null
+  locked_out: boolean
+}
+
+export interface HolidayRotationRow {
+  user_id: string
+  first_name: string
+  last_name: string
+  seniority_date: string | null
+  total_received: number
+  groups: HolidayRotationEntry[]
+}
+
+export interface HolidayRotationReport {
+  year: number
+  groups: HolidayGroup[]
+  rows: HolidayRotationRow[]
+}
+
 export const reportsApi = {
   coverage: (params: { start_date: string; end_date: string; team_id?: string }) =>
     apiClient.get<CoverageReport[]>('/api/reports/coverage', { params }),
@@ -70,4 +106,16 @@ export const reportsApi = {
 
   workSummary: (params: { start_date: string; end_date: string; user_id?: string }) =>
     apiClient.get<WorkSummaryReport[]>('/api/reports/work-summary', { params }),
+
+  qualificationExpirations: (params?: { within_days?: number; team_id?: string }) =>
+    apiClient.get<QualificationExpiryEntry[]>('/api/reports/qualification-expirations', { params }),
+
+  holidayRotation: (params?: { year?: number }) =>
+    apiClient.get<HolidayRotationReport>('/api/reports/holiday-rotation', { params }),
+
+  downloadHolidayRotation: (format: 'csv' | 'pdf', year?: number) =>
+    apiClient.get<Blob>('/api/reports/holiday-rotation', {
+      params: { format, year },
+      responseType: 'blob',
+    }),
 }
@@ -14,6 +14,8 @@ export interface SchedulePeriod {
   bid_opens_at: string | null
   bid_closes_at: string | null
   bargaining_unit: string | null
+  /** Published seniority list the bid windows are ordered by, if pinned */
+  seniority_list_id: string | null
   created_at: string
 }
 
@@ -43,6 +45,8 @@ export interface SlotAssignmentView {
   user_id: string | null
   user_first_name: string | null
   user_last_name: string | null
+  /** Set on each partner's share of a job-shared slot */
+  assigned_days_of_week: number[] | null
 }
 
 export const schedulePeriodsApi = {
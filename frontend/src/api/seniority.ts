@@ -0,0 +1,211 @@
+// frontend/src/api/seniority.ts
+import { apiClient } from './client'
+import type { EmployeeStatus } from '../store/auth'
+
+export type SeniorityListType = 'overall' | 'bargaining_unit' | 'classification'
+
+export type SeniorityTieBreakRule =
+  | 'overall_seniority_date'
+  | 'bargaining_unit_seniority_date'
+  | 'classification_seniority_date'
+  | 'hire_date'
+  | 'employee_id'
+  | 'last_name'
+
+export interface SeniorityList {
+  id: string
+  org_id: string
+  list_type: SeniorityListType
+  bargaining_unit: string | null
+  classification_id: string | null
+  classification_name: string | null
+  title: string
+  /** Applied in order when two employees' seniority dates are equal */
+  tie_break_rules: SeniorityTieBreakRule[]
+  published_by: string
+  published_at: string
+  entry_count: number
+  open_disputes: number
+}
+
+export interface SeniorityListEntry {
+  rank: number
+  user_id: string
+  employee_id: string | null
+  first_name: string
+  last_name: string
+  seniority_date: string | null
+  /** The rule that placed this employee below the one above on a tied date */
+  tie_broken_by: SeniorityTieBreakRule | null
+}
+
+export interface SeniorityListDetail extends SeniorityList {
+  entries: SeniorityListEntry[]
+}
+
+export interface PublishSeniorityListBody {
+  list_type: SeniorityListType
+  bargaining_unit?: string
+  classification_id?: string
+  title?: string
+  /** Defaults to the org's seniority_tie_breakers setting */
+  tie_break_rules?: SeniorityTieBreakRule[]
+}
+
+export type SeniorityDisputeStatus = 'open' | 'upheld' | 'denied' | 'withdrawn'
+
+export interface SeniorityDispute {
+  id: string
+  list_id: string
+  list_title: string
+  list_type: SeniorityListType
+  user_id: string
+  first_name: string
+  last_name: string
+  rank: number
+  listed_date: string | null
+  reason: string
+  requested_date: string | null
+  status: SeniorityDisputeStatus
+  corrected_date: string | null
+  resolution_notes: string | null
+  resolved_by: string | null
+  resolved_at: string | null
+  created_at: string
+}
+
+export type SeniorityChangeSource = 'profile' | 'status_change' | 'dispute' | 'recompute'
+
+export interface SeniorityAuditEntry {
+  id: string
+  seniority_type: SeniorityListType
+  old_date: string | null
+  new_date: string | null
+  source: SeniorityChangeSource
+  dispute_id: string | null
+  /** null = system */
+  changed_by: string | null
+  changed_by_name: string | null
+  created_at: string
+}
+
+/** An unpaid absence; completed, non-exception ones push seniority dates back */
+export interface EmploymentStatusInterval {
+  id: string
+  user_id: string
+  status: EmployeeStatus
+  starts_on: string
+  /** null while the absence is ongoing */
+  ends_on: string | null
+  /** OJI/L&I, pregnancy/maternity or military: seniority keeps accruing */
+  is_exception: boolean
+  notes: string | null
+  recorded_by: string | null
+  created_at: string
+}
+
+export interface CreateStatusIntervalBody {
+  status: EmployeeStatus
+  starts_on: string
+  ends_on: string
+  is_exception?: boolean
+  notes?: string
+}
+
+export interface UpdateStatusIntervalBody {
+  status?: EmployeeStatus
+  starts_on?: string
+  /** Only for completed absences */
+  ends_on?: string
+  is_exception?: boolean
+  notes?: string
+}
+
+export interface AbsenceAdjustment {
+  interval_id: string
+  status: EmployeeStatus
+  starts_on: string
+  ends_on: string | null
+  is_exception: boolean
+  days_counted: number
+  reason: string
+}
+
+export interface SeniorityDerivation {
+  seniority_type: SeniorityListType
+  /** The date seniority counts from, before absences */
+  base_date: string | null
+  base_source: 'recorded' | 'hire_date' | 'none'
+  absences: AbsenceAdjustment[]
+  paused_days: number
+  /** base_date plus paused_days */
+  seniority_date: string | null
+  /** The date currently on the employee's record */
+  recorded_date: string | null
+}
+
+export interface SeniorityExplanation {
+  user_id: string
+  hire_date: string | null
+  dates: SeniorityDerivation[]
+}
+
+export const seniorityApi = {
+  listLists: (listType?: SeniorityListType) =>
+    apiClient.get<SeniorityList[]>('/api/seniority-lists', {
+      params: listType ? { list_type: listType } : undefined,
+    }),
+
+  getList: (id: string) =>
+    apiClient.get<SeniorityListDetail>(`/api/seniority-lists/${id}`),
+
+  downloadList: (id: string, format: 'csv' | 'pdf') =>
+    apiClient.get<Blob>(`/api/seniority-lists/${id}`, {
+      params: { format },
+      responseType: 'blob',
+    }),
+
+  publish: (body: PublishSeniorityListBody) =>
+    apiClient.post<SeniorityListDetail>('/api/seniority-lists', body),
+
+  fileDispute: (listId: string, body: { reason: string; requested_date?: string }) =>
+    apiClient.post<SeniorityDispute>(`/api/seniority-lists/${listId}/disputes`, body),
+
+  listDisputes: (params?: { status?: SeniorityDisputeStatus; list_id?: string }) =>
+    apiClient.get<SeniorityDispute[]>('/api/seniority-disputes', { params }),
+
+  resolveDispute: (id: string, body: { status: 'upheld' | 'denied'; corrected_date?: string; notes?: string }) =>
+    apiClient.post<SeniorityDispute>(`/api/seniority-disputes/${id}/resolve`, body),
+
+  withdrawDispute: (id: string) =>
+    apiClient.post(`/api/seniority-disputes/${id}/withdraw`),
+
+  history: (userId: string) =>
+    apiClient.get<SeniorityAuditEntry[]>(`/api/users/${userId}/seniority-history`),
+
+  statusHistory: (userId: string) =>
+    apiClient.get<EmploymentStatusInterval[]>(`/api/users/${userId}/status-history`),
+
+  addStatusInterval: (userId: string, body: CreateStatusIntervalBody) =>
+    apiClient.post<EmploymentStatusInterval>(`/api/users/${userId}/status-history`, body),
+
+  updateStatusInterval: (userId: string, intervalId: string, body: UpdateStatusIntervalBody) =>
+    apiClient.patch<EmploymentStatusInterval>(`/api/users/${userId}/status-history/${intervalId}`, body),
+
+  deleteStatusInterval: (userId: string, intervalId: string) =>
+    apiClient.delete(`/api/users/${userId}/status-history/${intervalId}`),
+
+  explanation: (userId: string) =>
+    apiClient.get<SeniorityExplanation>(`/api/users/${userId}/seniority-explanation`),
+
+  recompute: (userId?: string) =>
+    apiClient.post<{ ok: boolean; employees: number; changed: number }>('/api/seniority/recompute', {
+      user_id: userId,
+    }),
+
+  pinSchedulePeriod: (periodId: string, seniorityListId: string | null) =>
+    apiClient.put(`/api/schedule/periods/${periodId}/seniority-list`, { seniority_list_id: seniorityListId }),
+
+  pinVacationPeriod: (periodId: string, seniorityListId: string | null) =>
+    apiClient.put(`/api/vacation-bids/periods/${periodId}/seniority-list`, { seniority_list_id: seniorityListId }),
+}
@@ -29,18 +29,74 @@ export interface StaffingAvailableResponse {
   existing_ot_requests: OtRequestSummary[]
 }
 
+export type MandatoryOtIneligibleReason = 'medical_exempt' | 'exempted' | 'on_leave' | 'rest_period'
+
 export interface MandatoryOtOrderEntry {
   user_id: string
+  first_name: string
+  last_name: string
+  position: number
   last_mandatory_at: string | null
+  times_forced: number
+  refusals: number
+  eligible: boolean
+  ineligible_reason: MandatoryOtIneligibleReason | null
+}
+
+export interface ErlangCRequest {
+  intervals?: { slot_index: number; calls: number }[]
+  csv?: string
+  forecast_date?: string
+  aht_seconds: number
+  service_level?: number
+  answer_time_seconds?: number
+  shrinkage?: number
+  max_occupancy?: number
+}
+
+export interface ErlangCInterval {
+  slot_index: number
+  start_time: string
+  calls: number
+  traffic_erlangs: number
+  required_agents: number
+  recommended_headcount: number
+  service_level: number
+  asa_seconds: number
+  occupancy: number
+}
+
+export interface ErlangCResponse {
+  aht_seconds: number
+  service_level: number
+  answer_time_seconds: number
+  shrinkage: number
+  peak_required_agents: number
+  peak_recommended_headcount: number
+  intervals: ErlangCInterval[]
 }
 
 export const staffingApi = {
-  getAvailable: (params: { date: string; shift_template_id: string; classification_id?: string }) =>
+  getAvailable: (params: {
+    date: string
+    shift_template_id: string
+    classification_id?: string
+    qualification_id?: string
+  }) =>
     apiClient.get<StaffingAvailableResponse>('/api/staffing/available', { params }),
 
-  blockAvailable: (params: { date: string; classification_id: string; block_start: string; block_end: string }) =>
+  blockAvailable: (params: {
+    date: string
+    classification_id: string
+    block_start: string
+    block_end: string
+    qualification_id?: string
+  }) =>
     apiClient.get<StaffingAvailableResponse>('/api/staffing/block-available', { params }),
 
   mandatoryOtOrder: (params: { classification_id: string }) =>
     apiClient.get<MandatoryOtOrderEntry[]>('/api/staffing/mandatory-ot-order', { params }),
+
+  erlangC: (body: ErlangCRequest) =>
+    apiClient.post<ErlangCResponse>('/api/staffing/erlang-c', body),
 }
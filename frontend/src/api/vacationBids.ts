@@ -1,5 +1,6 @@
 // frontend/src/api/vacationBids.ts
 import { apiClient } from './client'
+import type { BidProcessingRun, BidProxyAction, BidSkipReason, BidStatusSummary, ProxySubmitOn } from './bidding'
 
 export type VacationBidPeriodStatus = 'draft' | 'open' | 'in_progress' | 'completed'
 
@@ -14,6 +15,8 @@ export interface VacationBidPeriod {
   allowance_hours: number | null
   min_block_hours: number | null
   bargaining_unit: string | null
+  /** Published seniority list the bid windows are ordered by, if pinned */
+  seniority_list_id: string | null
   created_at: string
 }
 
@@ -56,6 +59,36 @@ export interface VacationPick {
   preference_rank: number
 }
 
+export interface VacationProxyPlan {
+  id: string
+  vacation_bid_period_id: string
+  user_id: string
+  submit_on: ProxySubmitOn
+  proxy_user_id: string | null
+  proxy_first_name: string | null
+  proxy_last_name: string | null
+  picks: VacationPick[]
+  updated_at: string
+}
+
+export interface VacationAwardRow {
+  window_id: string
+  user_id: string
+  first_name: string
+  last_name: string
+  seniority_rank: number
+  awards: { bid_id: string; start_date: string; end_date: string; preference_rank: number; hours: number }[]
+  skipped: { bid_id: string; start_date: string; end_date: string; preference_rank: number; reason: BidSkipReason }[]
+  reason: BidSkipReason | null
+}
+
+export interface VacationAwardTable {
+  period_id: string
+  total_bidders: number
+  awards_count: number
+  rows: VacationAwardRow[]
+}
+
 export const vacationBidsApi = {
   listPeriods: (year?: number) =>
     apiClient.get<VacationBidPeriod[]>('/api/vacation-bids/periods', { params: year ? { year } : undefined }),
@@ -84,6 +117,36 @@ export const vacationBidsApi = {
   submitBid: (windowId: string, body: { picks: VacationPick[] }) =>
     apiClient.post<VacationBid[]>(`/api/vacation-bids/bid-windows/${windowId}/submit`, body),
 
+  getBidStatus: (periodId: string) =>
+    apiClient.get<BidStatusSummary>(`/api/vacation-bids/periods/${periodId}/bid-status`),
+
+  getProxyPlan: (periodId: string) =>
+    apiClient.get<VacationProxyPlan>(`/api/vacation-bids/periods/${periodId}/proxy-plan`),
+
+  saveProxyPlan: (
+    periodId: string,
+    body: { submit_on?: ProxySubmitOn; proxy_user_id?: string | null; picks?: VacationPick[] },
+  ) =>
+    apiClient.put<VacationProxyPlan>(`/api/vacation-bids/periods/${periodId}/proxy-plan`, body),
+
+  deleteProxyPlan: (periodId: string) =>
+    apiClient.delete(`/api/vacation-bids/periods/${periodId}/proxy-plan`),
+
+  listProxyActions: (periodId: string) =>
+    apiClient.get<BidProxyAction[]>(`/api/vacation-bids/periods/${periodId}/proxy-actions`),
+
   processBids: (periodId: string) =>
     apiClient.post<VacationBidPeriod>(`/api/vacation-bids/periods/${periodId}/process-bids`),
+
+  previewBids: (periodId: string) =>
+    apiClient.get<VacationAwardTable>(`/api/vacation-bids/periods/${periodId}/process-bids/preview`),
+
+  rollbackBids: (periodId: string, body: { reason?: string } = {}) =>
+    apiClient.post<{ run_id: string; awards_rolled_back: number; hours_refunded: number }>(
+      `/api/vacation-bids/periods/${periodId}/rollback-bids`,
+      body,
+    ),
+
+  listBidRuns: (periodId: string) =>
+    apiClient.get<BidProcessingRun[]>(`/api/vacation-bids/periods/${periodId}/bid-runs`),
 }